@@ -0,0 +1,9 @@
+from poke_engine import simulate
+
+from example_state import state
+
+
+result = simulate(state, "mcts:100", "random", max_turns=50, seed=1)
+for turn in result.turns:
+    print(f"Turn {turn.turn}: {turn.side_one_move} vs {turn.side_two_move}")
+print(f"Winner: {result.winner}")
@@ -1,4 +1,5 @@
 from dataclasses import dataclass
+from typing import Optional
 
 from .state import (
     State,
@@ -15,6 +16,7 @@ from ._poke_engine import (
     calculate_damage as _calculate_damage,
     mcts as _mcts,
     id as _id,
+    simulate as _simulate,
 )
 
 
@@ -32,12 +34,15 @@ class IterativeDeepeningResult:
     :type matrix: int
     :param depth_searched: The depth that was searched to
     :type depth_searched: int
+    :param pruned_percentage: The average percentage of low-chance branches pruned per move pair
+    :type pruned_percentage: float
     """
 
     side_one: list[str]
     side_two: list[str]
     matrix: list[float]
     depth_searched: int
+    pruned_percentage: float
 
     @classmethod
     def _from_rust(cls, rust_result):
@@ -46,6 +51,7 @@ class IterativeDeepeningResult:
             side_two=rust_result.s2,
             matrix=rust_result.matrix,
             depth_searched=rust_result.depth_searched,
+            pruned_percentage=rust_result.pruned_percentage,
         )
 
     def get_safest_move(self) -> str:
@@ -102,11 +108,14 @@ class MctsResult:
     :type side_two: list[MctsSideResult]
     :param total_visits: Total number of monte carlo iterations
     :type total_visits: int
+    :param pruned_percentage: The average percentage of low-chance branches pruned per move pair
+    :type pruned_percentage: float
     """
 
     side_one: list[MctsSideResult]
     side_two: list[MctsSideResult]
     total_visits: int
+    pruned_percentage: float
 
     @classmethod
     def _from_rust(cls, rust_result):
@@ -128,17 +137,107 @@ class MctsResult:
                 for i in rust_result.s2
             ],
             total_visits=rust_result.iteration_count,
+            pruned_percentage=rust_result.pruned_percentage,
         )
 
 
-def generate_instructions(state: State, side_one_move: str, side_two_move: str):
+@dataclass
+class TurnLog:
     """
-    TODO
+    One step of a simulated battle
+
+    :param turn: The turn number. Forced switches keep the number of the turn they happened in
+    :type turn: int
+    :param forced_switch: Whether the step only replaced a fainted or switched-out pokemon
+    :type forced_switch: bool
+    :param state: The serialized state before the step
+    :type state: str
+    :param side_one_move: The move side one chose
+    :type side_one_move: str
+    :param side_two_move: The move side two chose
+    :type side_two_move: str
+    :param percentage: The chance of the outcome that was sampled
+    :type percentage: float
+    :param instructions: The instructions of the outcome that was sampled
+    :type instructions: list[str]
     """
-    return _gi(state._into_rust_obj(), side_one_move, side_two_move)
 
+    turn: int
+    forced_switch: bool
+    state: str
+    side_one_move: str
+    side_two_move: str
+    percentage: float
+    instructions: list[str]
 
-def monte_carlo_tree_search(state: State, duration_ms: int = 1000) -> MctsResult:
+
+@dataclass
+class BattleResult:
+    """
+    Result of a simulated battle
+
+    :param turns: Every step of the battle
+    :type turns: list[TurnLog]
+    :param final_state: The serialized state at the end of the battle
+    :type final_state: str
+    :param winner: "side_one", "side_two" or None if the turn limit was reached first
+    :type winner: str | None
+    """
+
+    turns: list[TurnLog]
+    final_state: str
+    winner: Optional[str]
+
+    @classmethod
+    def _from_rust(cls, rust_result):
+        return cls(
+            turns=[
+                TurnLog(
+                    turn=i.turn,
+                    forced_switch=i.forced_switch,
+                    state=i.state,
+                    side_one_move=i.side_one_move,
+                    side_two_move=i.side_two_move,
+                    percentage=i.percentage,
+                    instructions=i.instructions,
+                )
+                for i in rust_result.turns
+            ],
+            final_state=rust_result.final_state,
+            winner=rust_result.winner,
+        )
+
+
+def generate_instructions(
+    state: State,
+    side_one_move: str,
+    side_two_move: str,
+    damage_branching: str = "kill-no-kill",
+    prune_threshold: float = 0.0,
+):
+    """
+    TODO
+
+    :param damage_branching: "none", "kill-no-kill", "min-avg-max" or "all-rolls"
+    :type damage_branching: str
+    :param prune_threshold: branches less likely than this percentage are dropped
+    :type prune_threshold: float
+    """
+    return _gi(
+        state._into_rust_obj(),
+        side_one_move,
+        side_two_move,
+        damage_branching,
+        prune_threshold,
+    )
+
+
+def monte_carlo_tree_search(
+    state: State,
+    duration_ms: int = 1000,
+    damage_branching: str = "kill-no-kill,kill-no-kill,none",
+    prune_threshold: float = 0.0,
+) -> MctsResult:
     """
     Perform monte-carlo-tree-search on the given state and for the given duration
 
@@ -146,14 +245,23 @@ def monte_carlo_tree_search(state: State, duration_ms: int = 1000) -> MctsResult
     :type state: State
     :param duration_ms: time in milliseconds to run the search
     :type duration_ms: int
+    :param damage_branching: the damage branching of each ply, comma separated. The last one is used for all deeper plies
+    :type damage_branching: str
+    :param prune_threshold: branches less likely than this percentage are dropped
+    :type prune_threshold: float
     :return: the result of the search
     :rtype: MctsResult
     """
-    return MctsResult._from_rust(_mcts(state._into_rust_obj(), duration_ms))
+    return MctsResult._from_rust(
+        _mcts(state._into_rust_obj(), duration_ms, damage_branching, prune_threshold)
+    )
 
 
 def iterative_deepening_expectiminimax(
-    state: State, duration_ms: int = 1000
+    state: State,
+    duration_ms: int = 1000,
+    damage_branching: str = "none",
+    prune_threshold: float = 0.0,
 ) -> IterativeDeepeningResult:
     """
     Perform an iterative-deepening expectiminimax search on the given state and for the given duration
@@ -162,10 +270,16 @@ def iterative_deepening_expectiminimax(
     :type state: State
     :param duration_ms: time in milliseconds to run the search
     :type duration_ms: int
+    :param damage_branching: the damage branching of each ply, comma separated. The last one is used for all deeper plies
+    :type damage_branching: str
+    :param prune_threshold: branches less likely than this percentage are dropped
+    :type prune_threshold: float
     :return: the result of the search
     :rtype: IterativeDeepeningResult
     """
-    return IterativeDeepeningResult._from_rust(_id(state._into_rust_obj(), duration_ms))
+    return IterativeDeepeningResult._from_rust(
+        _id(state._into_rust_obj(), duration_ms, damage_branching, prune_threshold)
+    )
 
 
 def calculate_damage(
@@ -187,6 +301,36 @@ def calculate_damage(
     return _calculate_damage(state._into_rust_obj(), s1_move, s2_move, s1_moves_first)
 
 
+def simulate(
+    state: State,
+    side_one_agent: str = "random",
+    side_two_agent: str = "random",
+    max_turns: int = 100,
+    seed: int = 0,
+) -> BattleResult:
+    """
+    Play a battle between two agents from the given state
+
+    :param state: the state to start from
+    :type state: State
+    :param side_one_agent: "random", "greedy-damage", "expectiminimax:<depth>", "iterative-deepening:<ms>" or "mcts:<ms>"
+    :type side_one_agent: str
+    :param side_two_agent: the agent for side two, in the same format as side_one_agent
+    :type side_two_agent: str
+    :param max_turns: the number of turns to play before stopping without a winner
+    :type max_turns: int
+    :param seed: the seed used to sample outcomes and for random agents
+    :type seed: int
+    :return: the turn-by-turn log of the battle
+    :rtype: BattleResult
+    """
+    return BattleResult._from_rust(
+        _simulate(
+            state._into_rust_obj(), side_one_agent, side_two_agent, max_turns, seed
+        )
+    )
+
+
 __all__ = [
     "State",
     "Side",
@@ -196,8 +340,11 @@ __all__ = [
     "MctsResult",
     "MctsSideResult",
     "IterativeDeepeningResult",
+    "BattleResult",
+    "TurnLog",
     "generate_instructions",
     "monte_carlo_tree_search",
     "iterative_deepening_expectiminimax",
     "calculate_damage",
+    "simulate",
 ]
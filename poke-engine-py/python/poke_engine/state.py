@@ -9,6 +9,8 @@ from ._poke_engine import (
     Pokemon as _Pokemon,
     Move as _Move,
     state_from_string as _state_from_string,
+    pokemon_from_showdown_team as _pokemon_from_showdown_team,
+    side_to_showdown_team as _side_to_showdown_team,
 )
 
 
@@ -62,6 +64,8 @@ class Pokemon:
     :type nature: str
     :param evs: The evs of the Pokemon
     :type evs: tuple[int, int, int, int, int, int]
+    :param ivs: The ivs of the Pokemon. Hidden Power's type and base power are derived from these
+    :type ivs: tuple[int, int, int, int, int, int]
     :param attack: The attack stat of the Pokemon
     :type attack: int
     :param defense: The defense stat of the Pokemon
@@ -99,6 +103,7 @@ class Pokemon:
     item: str = "none"
     nature: str = "serious"
     evs: tuple[int, int, int, int, int, int] = (85, 85, 85, 85, 85, 85)
+    ivs: tuple[int, int, int, int, int, int] = (31, 31, 31, 31, 31, 31)
     attack: int = 100
     defense: int = 100
     special_attack: int = 100
@@ -137,6 +142,7 @@ class Pokemon:
             item=self.item,
             nature=self.nature,
             evs=self.evs,
+            ivs=self.ivs,
             attack=self.attack,
             defense=self.defense,
             special_attack=self.special_attack,
@@ -331,6 +337,10 @@ class Side:
     :param switch_out_move_second_saved_move: If `slow_uturn_move` is active,
         this is the move that will be executed after the switch
     :type switch_out_move_second_saved_move: str
+    :param mega_evolved: Whether a Pokemon on this side has mega evolved. Only one can per battle
+    :type mega_evolved: bool
+    :param z_move_used: Whether a Pokemon on this side has used a Z-Move. Only one can be used per battle
+    :type z_move_used: bool
     """
 
     active_index: str = "0"
@@ -357,6 +367,54 @@ class Side:
     last_used_move: str = "move:none"
     slow_uturn_move: bool = False
     switch_out_move_second_saved_move: str = "none"
+    mega_evolved: bool = False
+    z_move_used: bool = False
+
+    @classmethod
+    def from_showdown_team(cls, team: str):
+        """
+        Create a Side from a team in Pokemon Showdown's export or packed format.
+        Stats are calculated from each Pokemon's species, level, EVs, IVs and nature
+
+        :param team: the team to import
+        :type team: str
+        :return: a Side with the team's Pokemon
+        :rtype: Side
+        """
+        return cls(
+            pokemon=[
+                Pokemon(
+                    id=p.id,
+                    level=p.level,
+                    types=p.types,
+                    hp=p.hp,
+                    maxhp=p.maxhp,
+                    ability=p.ability,
+                    item=p.item,
+                    nature=p.nature,
+                    evs=p.evs,
+                    ivs=p.ivs,
+                    attack=p.attack,
+                    defense=p.defense,
+                    special_attack=p.special_attack,
+                    special_defense=p.special_defense,
+                    speed=p.speed,
+                    weight_kg=p.weight_kg,
+                    moves=[Move(id=m) for m in p.moves],
+                    tera_type=p.tera_type,
+                )
+                for p in _pokemon_from_showdown_team(team)
+            ]
+        )
+
+    def to_showdown_team(self) -> str:
+        """
+        Export this Side's Pokemon in Pokemon Showdown's export format
+
+        :return: the exported team
+        :rtype: str
+        """
+        return _side_to_showdown_team(self._into_rust_obj())
 
     def _into_rust_obj(self):
         return _Side(
@@ -382,6 +440,8 @@ class Side:
             evasion_boost=self.evasion_boost,
             last_used_move=self.last_used_move,
             switch_out_move_second_saved_move=self.switch_out_move_second_saved_move,
+            mega_evolved=self.mega_evolved,
+            z_move_used=self.z_move_used,
         )
 
 
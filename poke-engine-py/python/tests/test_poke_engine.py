@@ -9,6 +9,7 @@ from poke_engine import (
     generate_instructions,
     calculate_damage,
     iterative_deepening_expectiminimax,
+    simulate,
 )
 
 state = State(
@@ -94,3 +95,35 @@ def test_calculate_damage():
 def test_generate_instructions_errors_when_move_does_not_exist():
     with pytest.raises(ValueError):
         generate_instructions(state, "not_a_move", "ember")
+
+
+def test_side_can_be_imported_from_and_exported_to_a_showdown_team():
+    side = Side.from_showdown_team(
+        "Garchomp @ Choice Scarf\n"
+        "Ability: Rough Skin\n"
+        "EVs: 4 HP / 252 Atk / 252 Spe\n"
+        "Jolly Nature\n"
+        "- Earthquake\n"
+        "- Outrage\n"
+    )
+    assert side.pokemon[0].id == "garchomp"
+    assert side.pokemon[0].speed == 333
+    assert side.pokemon[0].moves[0].id == "earthquake"
+    assert "garchomp @ choicescarf" in side.to_showdown_team()
+
+
+def test_showdown_team_import_errors_when_species_does_not_exist():
+    with pytest.raises(ValueError):
+        Side.from_showdown_team("Not A Pokemon\n- Tackle\n")
+
+
+def test_simulate_plays_until_the_turn_limit_or_a_winner():
+    result = simulate(state, "random", "expectiminimax:1", max_turns=5, seed=1)
+    assert 0 < len(result.turns)
+    assert result.turns[-1].turn <= 5
+    assert result.winner in ("side_one", "side_two", None)
+
+
+def test_simulate_errors_when_player_does_not_exist():
+    with pytest.raises(ValueError):
+        simulate(state, "not_a_player", "random")
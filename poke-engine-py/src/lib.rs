@@ -3,20 +3,22 @@ use pyo3::{pyfunction, pymethods, pymodule, wrap_pyfunction, Bound, PyResult};
 use std::collections::HashSet;
 
 use poke_engine::choices::{Choices, MoveCategory, MOVES};
+use poke_engine::damage_branching::{DamageBranching, DamageBranchingSchedule};
 use poke_engine::engine::abilities::Abilities;
 use poke_engine::engine::generate_instructions::{
     calculate_both_damage_rolls, generate_instructions_from_move_pair,
 };
 use poke_engine::engine::items::Items;
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
-use poke_engine::instruction::{Instruction, StateInstructions};
+use poke_engine::instruction::{Instruction, PruningStats, StateInstructions};
 use poke_engine::mcts::{perform_mcts, MctsResult, MctsSideResult};
 use poke_engine::pokemon::PokemonName;
 use poke_engine::search::iterative_deepen_expectiminimax;
+use poke_engine::simulator::{simulate_battle_from_str, BattleResult, TurnLog};
 use poke_engine::state::{
     LastUsedMove, Move, Pokemon, PokemonIndex, PokemonMoves, PokemonNature, PokemonStatus,
-    PokemonType, Side, SideConditions, SidePokemon, State, StateTerrain, StateTrickRoom,
-    StateWeather, VolatileStatusDurations,
+    PokemonType, Side, SideConditions, SidePokemon, SideReference, State, StateTerrain,
+    StateTrickRoom, StateWeather, VolatileStatusDurations,
 };
 use std::str::FromStr;
 use std::time::Duration;
@@ -68,8 +70,10 @@ impl PyState {
             team_preview,
             use_damage_dealt: false,
             use_last_used_move: false,
+            zobrist_hash: 0,
         };
         state.set_conditional_mechanics();
+        state.refresh_zobrist_hash();
         PyState { state }
     }
 
@@ -126,6 +130,8 @@ impl PySide {
         evasion_boost: i8,
         last_used_move: String,
         switch_out_move_second_saved_move: String,
+        mega_evolved: bool,
+        z_move_used: bool,
     ) -> Self {
         let mut vs_hashset = HashSet::new();
         for vs in volatile_statuses {
@@ -170,6 +176,8 @@ impl PySide {
                     &switch_out_move_second_saved_move,
                 )
                 .unwrap(),
+                mega_evolved,
+                z_move_used,
             },
         }
     }
@@ -305,6 +313,7 @@ impl PyPokemon {
         item: String,
         nature: String,
         evs: (u8, u8, u8, u8, u8, u8),
+        ivs: (u8, u8, u8, u8, u8, u8),
         attack: i16,
         defense: i16,
         special_attack: i16,
@@ -321,44 +330,45 @@ impl PyPokemon {
         while moves.len() < 4 {
             moves.push(PyMove::create_empty_move());
         }
-        PyPokemon {
-            pokemon: Pokemon {
-                id: PokemonName::from_str(&id).unwrap(),
-                level,
-                types: (
-                    PokemonType::from_str(&types[0]).unwrap(),
-                    PokemonType::from_str(&types[1]).unwrap(),
-                ),
-                base_types: (
-                    PokemonType::from_str(&base_types[0]).unwrap(),
-                    PokemonType::from_str(&base_types[1]).unwrap(),
-                ),
-                hp,
-                maxhp,
-                ability: Abilities::from_str(&ability).unwrap(),
-                base_ability: Abilities::from_str(&base_ability).unwrap(),
-                item: Items::from_str(&item).unwrap(),
-                nature: PokemonNature::from_str(&nature).unwrap(),
-                evs: (evs.0, evs.1, evs.2, evs.3, evs.4, evs.5),
-                attack,
-                defense,
-                special_attack,
-                special_defense,
-                speed,
-                status: PokemonStatus::from_str(&status).unwrap(),
-                rest_turns,
-                sleep_turns,
-                weight_kg,
-                moves: PokemonMoves {
-                    m0: moves[0].create_move(),
-                    m1: moves[1].create_move(),
-                    m2: moves[2].create_move(),
-                    m3: moves[3].create_move(),
-                },
-                terastallized,
-                tera_type: PokemonType::from_str(&tera_type).unwrap(),
+        let mut pokemon = Pokemon {
+            id: PokemonName::from_str(&id).unwrap(),
+            level,
+            types: (
+                PokemonType::from_str(&types[0]).unwrap(),
+                PokemonType::from_str(&types[1]).unwrap(),
+            ),
+            base_types: (
+                PokemonType::from_str(&base_types[0]).unwrap(),
+                PokemonType::from_str(&base_types[1]).unwrap(),
+            ),
+            hp,
+            maxhp,
+            ability: Abilities::from_str(&ability).unwrap(),
+            base_ability: Abilities::from_str(&base_ability).unwrap(),
+            item: Items::from_str(&item).unwrap(),
+            nature: PokemonNature::from_str(&nature).unwrap(),
+            evs: (evs.0, evs.1, evs.2, evs.3, evs.4, evs.5),
+            ivs,
+            attack,
+            defense,
+            special_attack,
+            special_defense,
+            speed,
+            status: PokemonStatus::from_str(&status).unwrap(),
+            rest_turns,
+            sleep_turns,
+            weight_kg,
+            moves: PokemonMoves {
+                m0: moves[0].create_move(),
+                m1: moves[1].create_move(),
+                m2: moves[2].create_move(),
+                m3: moves[3].create_move(),
             },
-        }
+            terastallized,
+            tera_type: PokemonType::from_str(&tera_type).unwrap(),
+        };
+        pokemon.refresh_hidden_power();
+        PyPokemon { pokemon }
     }
 }
 
@@ -420,6 +430,7 @@ struct PyMctsResult {
     s1: Vec<PyMctsSideResult>,
     s2: Vec<PyMctsSideResult>,
     iteration_count: i64,
+    pruned_percentage: f32,
 }
 
 impl PyMctsResult {
@@ -436,6 +447,7 @@ impl PyMctsResult {
                 .map(|r| PyMctsSideResult::from_mcts_side_result(r.clone(), &state.side_two))
                 .collect(),
             iteration_count: result.iteration_count,
+            pruned_percentage: result.pruning_stats.average_pruned_percentage(),
         }
     }
 }
@@ -447,11 +459,12 @@ struct PyIterativeDeepeningResult {
     s2: Vec<String>,
     matrix: Vec<f32>,
     depth_searched: i8,
+    pruned_percentage: f32,
 }
 
 impl PyIterativeDeepeningResult {
     fn from_iterative_deepening_result(
-        result: (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8),
+        result: (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8, PruningStats),
         state: &State,
     ) -> Self {
         PyIterativeDeepeningResult {
@@ -467,32 +480,131 @@ impl PyIterativeDeepeningResult {
                 .collect(),
             matrix: result.2,
             depth_searched: result.3,
+            pruned_percentage: result.4.average_pruned_percentage(),
         }
     }
 }
 
 #[pyfunction]
-fn mcts(mut py_state: PyState, duration_ms: u64) -> PyResult<PyMctsResult> {
+fn mcts(
+    mut py_state: PyState,
+    duration_ms: u64,
+    damage_branching: String,
+    prune_threshold: f32,
+) -> PyResult<PyMctsResult> {
     let duration = Duration::from_millis(duration_ms);
+    let damage_branching = DamageBranchingSchedule::from_str(&damage_branching)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
     let (s1_options, s2_options) = py_state.state.root_get_all_options();
-    let mcts_result = perform_mcts(&mut py_state.state, s1_options, s2_options, duration);
+    let mcts_result = perform_mcts(
+        &mut py_state.state,
+        s1_options,
+        s2_options,
+        duration,
+        &damage_branching,
+        prune_threshold,
+    );
 
     let py_mcts_result = PyMctsResult::from_mcts_result(mcts_result, &py_state.state);
     Ok(py_mcts_result)
 }
 
 #[pyfunction]
-fn id(mut py_state: PyState, duration_ms: u64) -> PyResult<PyIterativeDeepeningResult> {
+fn id(
+    mut py_state: PyState,
+    duration_ms: u64,
+    damage_branching: String,
+    prune_threshold: f32,
+) -> PyResult<PyIterativeDeepeningResult> {
     let duration = Duration::from_millis(duration_ms);
+    let damage_branching = DamageBranchingSchedule::from_str(&damage_branching)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
     let (s1_options, s2_options) = py_state.state.root_get_all_options();
-    let id_result =
-        iterative_deepen_expectiminimax(&mut py_state.state, s1_options, s2_options, duration);
+    let id_result = iterative_deepen_expectiminimax(
+        &mut py_state.state,
+        s1_options,
+        s2_options,
+        duration,
+        &damage_branching,
+        prune_threshold,
+    );
 
     let py_id_result =
         PyIterativeDeepeningResult::from_iterative_deepening_result(id_result, &py_state.state);
     Ok(py_id_result)
 }
 
+#[derive(Clone)]
+#[pyclass(get_all)]
+struct PyTurnLog {
+    turn: u16,
+    forced_switch: bool,
+    state: String,
+    side_one_move: String,
+    side_two_move: String,
+    percentage: f32,
+    instructions: Vec<String>,
+}
+
+impl PyTurnLog {
+    fn from_turn_log(turn: &TurnLog) -> Self {
+        PyTurnLog {
+            turn: turn.turn,
+            forced_switch: turn.forced_switch,
+            state: turn.state.serialize(),
+            side_one_move: movechoice_to_string(&turn.state.side_one, &turn.side_one_choice),
+            side_two_move: movechoice_to_string(&turn.state.side_two, &turn.side_two_choice),
+            percentage: turn.percentage,
+            instructions: turn
+                .instructions
+                .iter()
+                .map(|i| format!("{:?}", i))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(get_all)]
+struct PyBattleResult {
+    turns: Vec<PyTurnLog>,
+    final_state: String,
+    winner: Option<String>,
+}
+
+impl PyBattleResult {
+    fn from_battle_result(result: BattleResult) -> Self {
+        PyBattleResult {
+            turns: result.turns.iter().map(PyTurnLog::from_turn_log).collect(),
+            final_state: result.final_state.serialize(),
+            winner: result.winner.map(|w| match w {
+                SideReference::SideOne => "side_one".to_string(),
+                SideReference::SideTwo => "side_two".to_string(),
+            }),
+        }
+    }
+}
+
+#[pyfunction]
+fn simulate(
+    py_state: PyState,
+    side_one_agent: String,
+    side_two_agent: String,
+    max_turns: u16,
+    seed: u64,
+) -> PyResult<PyBattleResult> {
+    match simulate_battle_from_str(
+        py_state.state,
+        &side_one_agent,
+        &side_two_agent,
+        max_turns,
+        seed,
+    ) {
+        Ok(result) => Ok(PyBattleResult::from_battle_result(result)),
+        Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e)),
+    }
+}
+
 #[derive(Clone)]
 #[pyclass(name = "Instruction")]
 struct PyInstruction {
@@ -546,7 +658,11 @@ fn gi(
     mut py_state: PyState,
     side_one_move: String,
     side_two_move: String,
+    damage_branching: String,
+    prune_threshold: f32,
 ) -> PyResult<Vec<PyStateInstructions>> {
+    let damage_branching = DamageBranching::from_str(&damage_branching)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
     let (s1_move, s2_move);
     match MoveChoice::from_string(&side_one_move, &py_state.state.side_one) {
         Some(m) => s1_move = m,
@@ -566,8 +682,13 @@ fn gi(
             )))
         }
     }
-    let instructions =
-        generate_instructions_from_move_pair(&mut py_state.state, &s1_move, &s2_move, true);
+    let instructions = generate_instructions_from_move_pair(
+        &mut py_state.state,
+        &s1_move,
+        &s2_move,
+        damage_branching,
+        prune_threshold,
+    );
     let py_instructions = instructions
         .iter()
         .map(|i| PyStateInstructions::from_state_instructions(i.clone()))
@@ -632,14 +753,94 @@ fn state_from_string(s: String) -> PyResult<PyState> {
     })
 }
 
+#[derive(Clone)]
+#[pyclass(get_all)]
+struct PyShowdownPokemon {
+    id: String,
+    level: i8,
+    types: (String, String),
+    hp: i16,
+    maxhp: i16,
+    ability: String,
+    item: String,
+    nature: String,
+    evs: (u8, u8, u8, u8, u8, u8),
+    ivs: (u8, u8, u8, u8, u8, u8),
+    attack: i16,
+    defense: i16,
+    special_attack: i16,
+    special_defense: i16,
+    speed: i16,
+    weight_kg: f32,
+    moves: Vec<String>,
+    tera_type: String,
+}
+
+impl PyShowdownPokemon {
+    fn from_pokemon(pkmn: &Pokemon) -> Self {
+        PyShowdownPokemon {
+            id: pkmn.id.to_string().to_lowercase(),
+            level: pkmn.level,
+            types: (
+                pkmn.types.0.to_string().to_lowercase(),
+                pkmn.types.1.to_string().to_lowercase(),
+            ),
+            hp: pkmn.hp,
+            maxhp: pkmn.maxhp,
+            ability: pkmn.ability.to_string().to_lowercase(),
+            item: pkmn.item.to_string().to_lowercase(),
+            nature: pkmn.nature.to_string().to_lowercase(),
+            evs: pkmn.evs,
+            ivs: pkmn.ivs,
+            attack: pkmn.attack,
+            defense: pkmn.defense,
+            special_attack: pkmn.special_attack,
+            special_defense: pkmn.special_defense,
+            speed: pkmn.speed,
+            weight_kg: pkmn.weight_kg,
+            moves: pkmn
+                .moves
+                .into_iter()
+                .filter(|m| m.id != Choices::NONE)
+                .map(|m| m.id.to_string().to_lowercase())
+                .collect(),
+            tera_type: pkmn.tera_type.to_string().to_lowercase(),
+        }
+    }
+}
+
+#[pyfunction]
+fn pokemon_from_showdown_team(team: String) -> PyResult<Vec<PyShowdownPokemon>> {
+    match Side::from_showdown_team(&team) {
+        Ok(side) => Ok(side
+            .pokemon
+            .into_iter()
+            .filter(|p| p.id != PokemonName::NONE)
+            .map(PyShowdownPokemon::from_pokemon)
+            .collect()),
+        Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid team: {}",
+            e
+        ))),
+    }
+}
+
+#[pyfunction]
+fn side_to_showdown_team(py_side: PySide) -> PyResult<String> {
+    Ok(py_side.side.to_showdown_team())
+}
+
 #[pymodule]
 #[pyo3(name = "_poke_engine")]
 fn py_poke_engine(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(state_from_string, m)?)?;
+    m.add_function(wrap_pyfunction!(pokemon_from_showdown_team, m)?)?;
+    m.add_function(wrap_pyfunction!(side_to_showdown_team, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_damage, m)?)?;
     m.add_function(wrap_pyfunction!(gi, m)?)?;
     m.add_function(wrap_pyfunction!(id, m)?)?;
     m.add_function(wrap_pyfunction!(mcts, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate, m)?)?;
     m.add_class::<PyState>()?;
     m.add_class::<PySide>()?;
     m.add_class::<PySideConditions>()?;
@@ -0,0 +1,290 @@
+use crate::choices::{Choices, MOVES};
+use crate::damage_branching::DamageBranching;
+use crate::engine::generate_instructions::calculate_damage_rolls;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+use crate::engine::generate_instructions::mega_evolve;
+use crate::engine::state::MoveChoice;
+use crate::instruction::PruningStats;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+use crate::instruction::StateInstructions;
+use crate::mcts::{default_damage_branching, perform_mcts};
+use crate::search::{expectiminimax_search, iterative_deepen_expectiminimax, pick_safest};
+use crate::state::{PokemonMoveIndex, SideReference, State};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Something that plays one side of a battle
+pub trait Agent {
+    /// Chooses one of `options`, the options `side_ref` has in `state`
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        options: &[MoveChoice],
+    ) -> MoveChoice;
+}
+
+impl<F> Agent for F
+where
+    F: FnMut(&State, SideReference, &[MoveChoice]) -> MoveChoice,
+{
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        options: &[MoveChoice],
+    ) -> MoveChoice {
+        self(state, side_ref, options)
+    }
+}
+
+/// Picks one of its options uniformly at random
+pub struct RandomAgent {
+    rng: StdRng,
+}
+
+impl RandomAgent {
+    pub fn new(seed: u64) -> RandomAgent {
+        RandomAgent {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Agent for RandomAgent {
+    fn choose_move(&mut self, _: &State, _: SideReference, options: &[MoveChoice]) -> MoveChoice {
+        *options.choose(&mut self.rng).unwrap_or(&MoveChoice::None)
+    }
+}
+
+/// Picks the move with the highest expected damage against the opponent's active pokemon,
+/// ignoring what the opponent does. Picks its first option when no move does any damage
+pub struct GreedyDamageAgent;
+
+impl GreedyDamageAgent {
+    // terastallizing, mega evolving or using a z-move changes the attacker or the move
+    // before the damage is calculated
+    fn expected_damage(state: &State, side_ref: SideReference, option: &MoveChoice) -> f32 {
+        let move_choice = |state: &State, move_index: &PokemonMoveIndex| {
+            state
+                .get_side_immutable(&side_ref)
+                .get_active_immutable()
+                .moves[move_index]
+                .choice
+                .clone()
+        };
+        let (state, choice) = match option {
+            MoveChoice::Move(move_index) => (state.clone(), move_choice(state, move_index)),
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            MoveChoice::MoveTera(move_index) => {
+                let mut state = state.clone();
+                state.get_side(&side_ref).get_active().terastallized = true;
+                let choice = move_choice(&state, move_index);
+                (state, choice)
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            MoveChoice::MoveMega(move_index) => {
+                let mut state = state.clone();
+                mega_evolve(&mut state, &side_ref, &mut StateInstructions::default());
+                let choice = move_choice(&state, move_index);
+                (state, choice)
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            MoveChoice::MoveZ(move_index) => {
+                let choice = move_choice(state, move_index);
+                let z_move = state
+                    .get_side_immutable(&side_ref)
+                    .get_active_immutable()
+                    .z_move(&choice)
+                    .unwrap_or_else(|| choice.to_z_move());
+                (state.clone(), z_move)
+            }
+            _ => return 0.0,
+        };
+        let accuracy = choice.accuracy / 100.0;
+        match calculate_damage_rolls(state, &side_ref, choice, MOVES.get(&Choices::NONE).unwrap()) {
+            Some(damage) => damage[0] as f32 * accuracy,
+            None => 0.0,
+        }
+    }
+}
+
+impl Agent for GreedyDamageAgent {
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        options: &[MoveChoice],
+    ) -> MoveChoice {
+        let mut best = (MoveChoice::None, f32::MIN);
+        for option in options {
+            let damage = GreedyDamageAgent::expected_damage(state, side_ref, option);
+            if damage > best.1 {
+                best = (*option, damage);
+            }
+        }
+        best.0
+    }
+}
+
+// Scores are from side one's perspective, so side two's safest option is
+// the column with the lowest best case for side one
+fn pick_safest_for_side(
+    result: &Vec<f32>,
+    side_one_options: &[MoveChoice],
+    side_two_options: &[MoveChoice],
+    side_ref: SideReference,
+) -> MoveChoice {
+    let columns = side_two_options.len();
+    match side_ref {
+        SideReference::SideOne => {
+            side_one_options[pick_safest(result, side_one_options.len(), columns).0]
+        }
+        SideReference::SideTwo => {
+            let mut best = (0, f32::MAX);
+            for column in 0..columns {
+                let worst_case = result
+                    .iter()
+                    .skip(column)
+                    .step_by(columns)
+                    .cloned()
+                    .fold(f32::MIN, f32::max);
+                if worst_case < best.1 {
+                    best = (column, worst_case);
+                }
+            }
+            side_two_options[best.0]
+        }
+    }
+}
+
+/// Picks its safest option after an expectiminimax search to a fixed depth
+pub struct ExpectiminimaxAgent {
+    pub depth: i8,
+    pub ab_prune: bool,
+}
+
+impl Agent for ExpectiminimaxAgent {
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        _: &[MoveChoice],
+    ) -> MoveChoice {
+        let mut state = state.clone();
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        let result = expectiminimax_search(
+            &mut state,
+            self.depth,
+            side_one_options.clone(),
+            side_two_options.clone(),
+            // pruning is done from side one's perspective and would leave gaps in side two's
+            // columns of the result
+            self.ab_prune && side_ref == SideReference::SideOne,
+            &Arc::new(Mutex::new(true)),
+            &DamageBranching::None.into(),
+            0.0,
+            &mut PruningStats::default(),
+        );
+        pick_safest_for_side(&result, &side_one_options, &side_two_options, side_ref)
+    }
+}
+
+/// Picks its safest option after searching as deep as it can in `duration`
+pub struct IterativeDeepeningAgent {
+    pub duration: Duration,
+}
+
+impl Agent for IterativeDeepeningAgent {
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        _: &[MoveChoice],
+    ) -> MoveChoice {
+        // iterative deepening always prunes from side one's perspective, so side two searches
+        // the state with the sides swapped
+        let mut state = state.clone();
+        if side_ref == SideReference::SideTwo {
+            std::mem::swap(&mut state.side_one, &mut state.side_two);
+            state.refresh_zobrist_hash();
+        }
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        let (side_one_options, side_two_options, result, _, _) = iterative_deepen_expectiminimax(
+            &mut state,
+            side_one_options,
+            side_two_options,
+            self.duration,
+            &DamageBranching::None.into(),
+            0.0,
+        );
+        side_one_options[pick_safest(&result, side_one_options.len(), side_two_options.len()).0]
+    }
+}
+
+/// Picks its most visited option after a monte carlo tree search for `duration`
+pub struct MctsAgent {
+    pub duration: Duration,
+}
+
+impl Agent for MctsAgent {
+    fn choose_move(
+        &mut self,
+        state: &State,
+        side_ref: SideReference,
+        _: &[MoveChoice],
+    ) -> MoveChoice {
+        let mut state = state.clone();
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        let result = perform_mcts(
+            &mut state,
+            side_one_options,
+            side_two_options,
+            self.duration,
+            &default_damage_branching(),
+            0.0,
+        );
+        let side_results = match side_ref {
+            SideReference::SideOne => result.s1,
+            SideReference::SideTwo => result.s2,
+        };
+        side_results
+            .iter()
+            .max_by_key(|r| r.visits)
+            .map_or(MoveChoice::None, |r| r.move_choice)
+    }
+}
+
+/// Builds an agent from a description like "random", "greedy-damage", "expectiminimax:2",
+/// "iterative-deepening:500" or "mcts:500", where the number is a depth or milliseconds.
+/// `seed` is only used by agents that make random choices
+pub fn agent_from_str(agent: &str, seed: u64) -> Result<Box<dyn Agent>, String> {
+    let (name, amount) = match agent.split_once(':') {
+        Some((name, amount)) => (
+            name,
+            Some(
+                amount
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid agent: {}", agent))?,
+            ),
+        ),
+        None => (agent, None),
+    };
+    match name {
+        "random" => Ok(Box::new(RandomAgent::new(seed))),
+        "greedy-damage" => Ok(Box::new(GreedyDamageAgent)),
+        "expectiminimax" => Ok(Box::new(ExpectiminimaxAgent {
+            depth: amount.unwrap_or(2) as i8,
+            ab_prune: true,
+        })),
+        "iterative-deepening" => Ok(Box::new(IterativeDeepeningAgent {
+            duration: Duration::from_millis(amount.unwrap_or(1000)),
+        })),
+        "mcts" => Ok(Box::new(MctsAgent {
+            duration: Duration::from_millis(amount.unwrap_or(1000)),
+        })),
+        _ => Err(format!("invalid agent: {}", agent)),
+    }
+}
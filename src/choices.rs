@@ -155,6 +155,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::ACIDDOWNPOUR,
+            Choice {
+                move_id: Choices::ACIDDOWNPOUR,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::POISON,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::ACIDSPRAY,
             Choice {
@@ -331,6 +344,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::ALLOUTPUMMELING,
+            Choice {
+                move_id: Choices::ALLOUTPUMMELING,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::FIGHTING,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::ALLURINGVOICE,
             Choice {
@@ -1327,6 +1353,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::BLACKHOLEECLIPSE,
+            Choice {
+                move_id: Choices::BLACKHOLEECLIPSE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::DARK,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::BLASTBURN,
             Choice {
@@ -1504,6 +1543,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::BLOOMDOOM,
+            Choice {
+                move_id: Choices::BLOOMDOOM,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::GRASS,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::BLUEFLARE,
             Choice {
@@ -1774,6 +1826,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::BREAKNECKBLITZ,
+            Choice {
+                move_id: Choices::BREAKNECKBLITZ,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::NORMAL,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::BRICKBREAK,
             Choice {
@@ -2219,6 +2284,20 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::CATASTROPIKA,
+            Choice {
+                move_id: Choices::CATASTROPIKA,
+                base_power: 210.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::ELECTRIC,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::CEASELESSEDGE,
             Choice {
@@ -2545,6 +2624,32 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::CLANGOROUSSOULBLAZE,
+            Choice {
+                move_id: Choices::CLANGOROUSSOULBLAZE,
+                base_power: 185.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::DRAGON,
+                flags: Flags {
+                    sound: true,
+                    ..Default::default()
+                },
+                secondaries: Some(vec![Secondary {
+                    chance: 100.0,
+                    target: MoveTarget::User,
+                    effect: Effect::Boost(StatBoosts {
+                        attack: 1,
+                        defense: 1,
+                        special_attack: 1,
+                        special_defense: 1,
+                        speed: 1,
+                        accuracy: 0,
+                    }),
+                }]),
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::CLEARSMOG,
             Choice {
@@ -2813,6 +2918,19 @@ lazy_static! {
             },
         );
         }
+        moves.insert(
+            Choices::CONTINENTALCRUSH,
+            Choice {
+                move_id: Choices::CONTINENTALCRUSH,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::ROCK,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::CONVERSION,
             Choice {
@@ -2862,6 +2980,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::CORKSCREWCRASH,
+            Choice {
+                move_id: Choices::CORKSCREWCRASH,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::STEEL,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::CORROSIVEGAS,
             Choice {
@@ -3486,6 +3617,19 @@ lazy_static! {
             },
         );
 
+        moves.insert(
+            Choices::DEVASTATINGDRAKE,
+            Choice {
+                move_id: Choices::DEVASTATINGDRAKE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::DRAGON,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") || cfg!(feature = "gen4") || cfg!(feature = "gen5") || cfg!(feature = "gen6") {
             moves.insert(
                 Choices::DIAMONDSTORM,
@@ -4898,6 +5042,29 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::EXTREMEEVOBOOST,
+            Choice {
+                move_id: Choices::EXTREMEEVOBOOST,
+                target: MoveTarget::User,
+                move_type: PokemonType::NORMAL,
+                flags: Flags {
+                    ..Default::default()
+                },
+                boost: Some(Boost {
+                    target: MoveTarget::User,
+                    boosts: StatBoosts {
+                        attack: 2,
+                        defense: 2,
+                        special_attack: 2,
+                        special_defense: 2,
+                        speed: 2,
+                        accuracy: 0,
+                    },
+                }),
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::EXTREMESPEED,
             Choice {
@@ -6353,6 +6520,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::GENESISSUPERNOVA,
+            Choice {
+                move_id: Choices::GENESISSUPERNOVA,
+                base_power: 185.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::PSYCHIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::GEOMANCY,
             Choice {
@@ -6444,6 +6624,19 @@ lazy_static! {
             },
         );
 
+        moves.insert(
+            Choices::GIGAVOLTHAVOC,
+            Choice {
+                move_id: Choices::GIGAVOLTHAVOC,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::ELECTRIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         if cfg!(feature = "gen9") {
             moves.insert(
                 Choices::GLACIALLANCE,
@@ -6825,6 +7018,18 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::GUARDIANOFALOLA,
+            Choice {
+                move_id: Choices::GUARDIANOFALOLA,
+                category: MoveCategory::Special,
+                move_type: PokemonType::FAIRY,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::GUARDSPLIT,
             Choice {
@@ -8133,6 +8338,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::HYDROVORTEX,
+            Choice {
+                move_id: Choices::HYDROVORTEX,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::WATER,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::HYPERBEAM,
             Choice {
@@ -8607,6 +8825,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::INFERNOOVERDRIVE,
+            Choice {
+                move_id: Choices::INFERNOOVERDRIVE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::FIRE,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::INFESTATION,
             Choice {
@@ -9317,6 +9548,20 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::LETSSNUGGLEFOREVER,
+            Choice {
+                move_id: Choices::LETSSNUGGLEFOREVER,
+                base_power: 190.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::FAIRY,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") || cfg!(feature = "gen4") || cfg!(feature = "gen5") {
             moves.insert(
                 Choices::LICK,
@@ -9428,6 +9673,19 @@ lazy_static! {
                 },
             );
         }
+        moves.insert(
+            Choices::LIGHTTHATBURNSTHESKY,
+            Choice {
+                move_id: Choices::LIGHTTHATBURNSTHESKY,
+                base_power: 200.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::PSYCHIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::LIQUIDATION,
             Choice {
@@ -9968,6 +10226,20 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::MALICIOUSMOONSAULT,
+            Choice {
+                move_id: Choices::MALICIOUSMOONSAULT,
+                base_power: 180.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::DARK,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::MALIGNANTCHAIN,
             Choice {
@@ -10164,6 +10436,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::MENACINGMOONRAZEMAELSTROM,
+            Choice {
+                move_id: Choices::MENACINGMOONRAZEMAELSTROM,
+                base_power: 200.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::GHOST,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::METALBURST,
             Choice {
@@ -11058,6 +11343,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::NEVERENDINGNIGHTMARE,
+            Choice {
+                move_id: Choices::NEVERENDINGNIGHTMARE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::GHOST,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::NIGHTDAZE,
             Choice {
@@ -11266,6 +11564,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::OCEANICOPERETTA,
+            Choice {
+                move_id: Choices::OCEANICOPERETTA,
+                base_power: 195.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::WATER,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::OCTAZOOKA,
             Choice {
@@ -12722,6 +13033,20 @@ lazy_static! {
                 },
             );
         }
+        moves.insert(
+            Choices::PULVERIZINGPANCAKE,
+            Choice {
+                move_id: Choices::PULVERIZINGPANCAKE,
+                base_power: 210.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::NORMAL,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::PUNISHMENT,
             Choice {
@@ -13937,6 +14262,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SAVAGESPINOUT,
+            Choice {
+                move_id: Choices::SAVAGESPINOUT,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::BUG,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SCALD,
             Choice {
@@ -14114,6 +14452,20 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SEARINGSUNRAZESMASH,
+            Choice {
+                move_id: Choices::SEARINGSUNRAZESMASH,
+                base_power: 200.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::STEEL,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SECRETPOWER,
             Choice {
@@ -14401,6 +14753,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SHATTEREDPSYCHE,
+            Choice {
+                move_id: Choices::SHATTEREDPSYCHE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::PSYCHIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SHEDTAIL,
             Choice {
@@ -14657,6 +15022,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SINISTERARROWRAID,
+            Choice {
+                move_id: Choices::SINISTERARROWRAID,
+                base_power: 180.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::GHOST,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SIZZLYSLIDE,
             Choice {
@@ -15318,6 +15696,20 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SOULSTEALING7STARSTRIKE,
+            Choice {
+                move_id: Choices::SOULSTEALING7STARSTRIKE,
+                base_power: 195.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::GHOST,
+                flags: Flags {
+                    contact: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SPACIALREND,
             Choice {
@@ -15604,6 +15996,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SPLINTEREDSTORMSHARDS,
+            Choice {
+                move_id: Choices::SPLINTEREDSTORMSHARDS,
+                base_power: 190.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::ROCK,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::SPLISHYSPLASH,
             Choice {
@@ -15837,6 +16242,24 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::STOKEDSPARKSURFER,
+            Choice {
+                move_id: Choices::STOKEDSPARKSURFER,
+                base_power: 175.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::ELECTRIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                secondaries: Some(vec![Secondary {
+                    chance: 100.0,
+                    target: MoveTarget::Opponent,
+                    effect: Effect::Status(PokemonStatus::PARALYZE),
+                }]),
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::STOMP,
             Choice {
@@ -16163,6 +16586,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SUBZEROSLAMMER,
+            Choice {
+                move_id: Choices::SUBZEROSLAMMER,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::ICE,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") || cfg!(feature = "gen4") || cfg!(feature = "gen5") || cfg!(feature = "gen6") {
             moves.insert(
                 Choices::SUCKERPUNCH,
@@ -16301,6 +16737,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::SUPERSONICSKYSTRIKE,
+            Choice {
+                move_id: Choices::SUPERSONICSKYSTRIKE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::FLYING,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") || cfg!(feature = "gen4") || cfg!(feature = "gen5") {
             moves.insert(
                 Choices::SURF,
@@ -16858,6 +17307,19 @@ lazy_static! {
                 },
             );
         }
+        moves.insert(
+            Choices::TECTONICRAGE,
+            Choice {
+                move_id: Choices::TECTONICRAGE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::GROUND,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::TEETERDANCE,
             Choice {
@@ -16937,6 +17399,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::TENMILLIONVOLTTHUNDERBOLT,
+            Choice {
+                move_id: Choices::TENMILLIONVOLTTHUNDERBOLT,
+                base_power: 195.0,
+                category: MoveCategory::Special,
+                move_type: PokemonType::ELECTRIC,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::TERABLAST,
             Choice {
@@ -17731,7 +18206,7 @@ lazy_static! {
             Choice {
                 move_id: Choices::TRIPLEAXEL,
                 accuracy: 90.0,
-                base_power: 40.0,
+                base_power: 20.0,
                 category: MoveCategory::Physical,
                 move_type: PokemonType::ICE,
                 flags: Flags {
@@ -17848,6 +18323,19 @@ lazy_static! {
                 ..Default::default()
             },
         );
+        moves.insert(
+            Choices::TWINKLETACKLE,
+            Choice {
+                move_id: Choices::TWINKLETACKLE,
+                base_power: 1.0,
+                category: MoveCategory::Physical,
+                move_type: PokemonType::FAIRY,
+                flags: Flags {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
         moves.insert(
             Choices::TWISTER,
             Choice {
@@ -19068,14 +19556,23 @@ pub enum Effect {
     RemoveItem,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZMoveEffect {
+    None,
+    Boost(StatBoosts),
+    ClearNegativeBoosts,
+    Heal,
+    CritRatio,
+    HealReplacement,
+    Curse,
+}
+
 #[derive(PartialEq)]
 pub enum MultiHitMove {
     None,
     DoubleHit,
     TripleHit,
     TwoToFiveHits,
-    PopulationBomb,
-    TripleAxel,
 }
 
 #[derive(PartialEq)]
@@ -19085,6 +19582,16 @@ pub enum MultiAccuracyMove {
     TenHits,
 }
 
+impl MultiAccuracyMove {
+    pub fn max_hits(&self) -> i8 {
+        match self {
+            MultiAccuracyMove::None => 1,
+            MultiAccuracyMove::TripleHit => 3,
+            MultiAccuracyMove::TenHits => 10,
+        }
+    }
+}
+
 define_enum_with_from_str! {
     #[repr(u16)]
     #[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
@@ -19974,6 +20481,42 @@ define_enum_with_from_str! {
         ZENHEADBUTT,
         ZINGZAP,
         ZIPPYZAP,
+        // new variants go at the end so the discriminants of existing moves do not change
+        ACIDDOWNPOUR,
+        ALLOUTPUMMELING,
+        BLACKHOLEECLIPSE,
+        BLOOMDOOM,
+        BREAKNECKBLITZ,
+        CATASTROPIKA,
+        CLANGOROUSSOULBLAZE,
+        CONTINENTALCRUSH,
+        CORKSCREWCRASH,
+        DEVASTATINGDRAKE,
+        EXTREMEEVOBOOST,
+        GENESISSUPERNOVA,
+        GIGAVOLTHAVOC,
+        GUARDIANOFALOLA,
+        HYDROVORTEX,
+        INFERNOOVERDRIVE,
+        LETSSNUGGLEFOREVER,
+        LIGHTTHATBURNSTHESKY,
+        MALICIOUSMOONSAULT,
+        MENACINGMOONRAZEMAELSTROM,
+        NEVERENDINGNIGHTMARE,
+        OCEANICOPERETTA,
+        PULVERIZINGPANCAKE,
+        SAVAGESPINOUT,
+        SEARINGSUNRAZESMASH,
+        SHATTEREDPSYCHE,
+        SINISTERARROWRAID,
+        SOULSTEALING7STARSTRIKE,
+        SPLINTEREDSTORMSHARDS,
+        STOKEDSPARKSURFER,
+        SUBZEROSLAMMER,
+        SUPERSONICSKYSTRIKE,
+        TECTONICRAGE,
+        TENMILLIONVOLTTHUNDERBOLT,
+        TWINKLETACKLE,
     },
     default = NONE
 }
@@ -20044,6 +20587,7 @@ impl Choices {
             | Choices::SNIPESHOT
             | Choices::SPACIALREND
             | Choices::STONEEDGE
+            | Choices::TENMILLIONVOLTTHUNDERBOLT
             | Choices::TRIPLEARROWS => true,
             _ => false,
         }
@@ -20058,6 +20602,76 @@ impl Choices {
             _ => false,
         }
     }
+    pub fn is_z_move(&self) -> bool {
+        matches!(
+            self,
+            Choices::ACIDDOWNPOUR
+                | Choices::ALLOUTPUMMELING
+                | Choices::BLACKHOLEECLIPSE
+                | Choices::BLOOMDOOM
+                | Choices::BREAKNECKBLITZ
+                | Choices::CONTINENTALCRUSH
+                | Choices::CORKSCREWCRASH
+                | Choices::DEVASTATINGDRAKE
+                | Choices::GIGAVOLTHAVOC
+                | Choices::HYDROVORTEX
+                | Choices::INFERNOOVERDRIVE
+                | Choices::NEVERENDINGNIGHTMARE
+                | Choices::SAVAGESPINOUT
+                | Choices::SHATTEREDPSYCHE
+                | Choices::SUBZEROSLAMMER
+                | Choices::SUPERSONICSKYSTRIKE
+                | Choices::TECTONICRAGE
+                | Choices::TWINKLETACKLE
+        ) || self.exclusive_z_move_base().is_some()
+    }
+    // the move that a species-exclusive z-move is made from
+    pub fn exclusive_z_move_base(&self) -> Option<Choices> {
+        match self {
+            Choices::CATASTROPIKA => Some(Choices::VOLTTACKLE),
+            Choices::TENMILLIONVOLTTHUNDERBOLT => Some(Choices::THUNDERBOLT),
+            Choices::STOKEDSPARKSURFER => Some(Choices::THUNDERBOLT),
+            Choices::SINISTERARROWRAID => Some(Choices::SPIRITSHACKLE),
+            Choices::MALICIOUSMOONSAULT => Some(Choices::DARKESTLARIAT),
+            Choices::OCEANICOPERETTA => Some(Choices::SPARKLINGARIA),
+            Choices::EXTREMEEVOBOOST => Some(Choices::LASTRESORT),
+            Choices::PULVERIZINGPANCAKE => Some(Choices::GIGAIMPACT),
+            Choices::GENESISSUPERNOVA => Some(Choices::PSYCHIC),
+            Choices::GUARDIANOFALOLA => Some(Choices::NATURESMADNESS),
+            Choices::SOULSTEALING7STARSTRIKE => Some(Choices::SPECTRALTHIEF),
+            Choices::CLANGOROUSSOULBLAZE => Some(Choices::CLANGINGSCALES),
+            Choices::SPLINTEREDSTORMSHARDS => Some(Choices::STONEEDGE),
+            Choices::LETSSNUGGLEFOREVER => Some(Choices::PLAYROUGH),
+            Choices::MENACINGMOONRAZEMAELSTROM => Some(Choices::MOONGEISTBEAM),
+            Choices::SEARINGSUNRAZESMASH => Some(Choices::SUNSTEELSTRIKE),
+            Choices::LIGHTTHATBURNSTHESKY => Some(Choices::PHOTONGEYSER),
+            _ => None,
+        }
+    }
+    // the z-move that damaging moves of this type become
+    pub fn z_move_for_type(move_type: &PokemonType) -> Choices {
+        match move_type {
+            PokemonType::NORMAL => Choices::BREAKNECKBLITZ,
+            PokemonType::FIRE => Choices::INFERNOOVERDRIVE,
+            PokemonType::WATER => Choices::HYDROVORTEX,
+            PokemonType::ELECTRIC => Choices::GIGAVOLTHAVOC,
+            PokemonType::GRASS => Choices::BLOOMDOOM,
+            PokemonType::ICE => Choices::SUBZEROSLAMMER,
+            PokemonType::FIGHTING => Choices::ALLOUTPUMMELING,
+            PokemonType::POISON => Choices::ACIDDOWNPOUR,
+            PokemonType::GROUND => Choices::TECTONICRAGE,
+            PokemonType::FLYING => Choices::SUPERSONICSKYSTRIKE,
+            PokemonType::PSYCHIC => Choices::SHATTEREDPSYCHE,
+            PokemonType::BUG => Choices::SAVAGESPINOUT,
+            PokemonType::ROCK => Choices::CONTINENTALCRUSH,
+            PokemonType::GHOST => Choices::NEVERENDINGNIGHTMARE,
+            PokemonType::DRAGON => Choices::DEVASTATINGDRAKE,
+            PokemonType::DARK => Choices::BLACKHOLEECLIPSE,
+            PokemonType::STEEL => Choices::CORKSCREWCRASH,
+            PokemonType::FAIRY => Choices::TWINKLETACKLE,
+            _ => Choices::BREAKNECKBLITZ,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -20086,6 +20700,9 @@ pub struct Choice {
 
     pub first_move: bool,
     pub sleep_talk_move: bool,
+    pub z_move: bool,
+    // hits more than one target in doubles, which reduces its damage to 0.75x
+    pub spread_hit: bool,
 }
 
 impl fmt::Debug for Choice {
@@ -20095,6 +20712,8 @@ impl fmt::Debug for Choice {
 }
 
 impl Choice {
+    // Every hit of these moves checks accuracy and the move stops at the first miss.
+    // Triple Axel and Triple Kick gain their base power again with every hit
     pub fn multi_accuracy(&self) -> MultiAccuracyMove {
         match self.move_id {
             Choices::TRIPLEAXEL => MultiAccuracyMove::TripleHit,
@@ -20134,13 +20753,253 @@ impl Choice {
             Choices::TWINEEDLE => MultiHitMove::DoubleHit,
             Choices::WATERSHURIKEN => MultiHitMove::TwoToFiveHits,
 
-            // These are multi-accuracy
-            // but until that is implemented we approximate them as multi-hit
-            Choices::POPULATIONBOMB => MultiHitMove::PopulationBomb,
-            Choices::TRIPLEAXEL => MultiHitMove::TripleAxel,
             _ => MultiHitMove::None,
         }
     }
+    // Z-Moves made from damaging moves have their base power converted from the original move.
+    // Moves with variable or fixed damage have their own Z-Move base power
+    pub fn z_move_base_power(&self) -> f32 {
+        match self.move_id {
+            Choices::VCREATE => 220.0,
+            Choices::CRUSHGRIP | Choices::WRINGOUT => 190.0,
+            Choices::FINALGAMBIT | Choices::GEARGRIND => 180.0,
+            Choices::FLYINGPRESS => 170.0,
+            Choices::ELECTROBALL
+            | Choices::ENDEAVOR
+            | Choices::FLAIL
+            | Choices::FRUSTRATION
+            | Choices::GRASSKNOT
+            | Choices::GYROBALL
+            | Choices::HEATCRASH
+            | Choices::HEAVYSLAM
+            | Choices::HEX
+            | Choices::LOWKICK
+            | Choices::POWERTRIP
+            | Choices::PUNISHMENT
+            | Choices::RETURN
+            | Choices::REVERSAL
+            | Choices::STOREDPOWER
+            | Choices::TRUMPCARD
+            | Choices::WEATHERBALL => 160.0,
+            Choices::MAGNITUDE => 140.0,
+            Choices::MEGADRAIN => 120.0,
+            Choices::BEATUP
+            | Choices::COUNTER
+            | Choices::DRAGONRAGE
+            | Choices::FLING
+            | Choices::MIRRORCOAT
+            | Choices::NATURESMADNESS
+            | Choices::NIGHTSHADE
+            | Choices::PRESENT
+            | Choices::PSYWAVE
+            | Choices::SEISMICTOSS
+            | Choices::SONICBOOM
+            | Choices::SPITUP
+            | Choices::SUPERFANG => 100.0,
+            _ => {
+                let mut base_power = self.base_power;
+                if self.multi_hit() == MultiHitMove::TwoToFiveHits {
+                    base_power *= 3.0;
+                }
+                if base_power == 0.0 {
+                    100.0
+                } else if base_power >= 140.0 {
+                    200.0
+                } else if base_power >= 130.0 {
+                    195.0
+                } else if base_power >= 120.0 {
+                    190.0
+                } else if base_power >= 110.0 {
+                    185.0
+                } else if base_power >= 100.0 {
+                    180.0
+                } else if base_power >= 90.0 {
+                    175.0
+                } else if base_power >= 80.0 {
+                    160.0
+                } else if base_power >= 70.0 {
+                    140.0
+                } else if base_power >= 60.0 {
+                    120.0
+                } else {
+                    100.0
+                }
+            }
+        }
+    }
+    // the extra effect a status move gets when it is used as a Z-Move
+    pub fn z_move_effect(&self) -> ZMoveEffect {
+        match self.move_id {
+            Choices::ACIDARMOR
+            | Choices::AGILITY
+            | Choices::AMNESIA
+            | Choices::AUTOTOMIZE
+            | Choices::CALMMIND
+            | Choices::COIL
+            | Choices::COTTONGUARD
+            | Choices::DOUBLETEAM
+            | Choices::DRAGONDANCE
+            | Choices::FOLLOWME
+            | Choices::HELPINGHAND
+            | Choices::IRONDEFENSE
+            | Choices::KINGSSHIELD
+            | Choices::LEECHSEED
+            | Choices::MILKDRINK
+            | Choices::MINIMIZE
+            | Choices::MOONLIGHT
+            | Choices::MORNINGSUN
+            | Choices::NASTYPLOT
+            | Choices::PERISHSONG
+            | Choices::PROTECT
+            | Choices::QUIVERDANCE
+            | Choices::RAGEPOWDER
+            | Choices::RECOVER
+            | Choices::REST
+            | Choices::ROCKPOLISH
+            | Choices::ROOST
+            | Choices::SHELLSMASH
+            | Choices::SHIFTGEAR
+            | Choices::SHOREUP
+            | Choices::SLACKOFF
+            | Choices::SOFTBOILED
+            | Choices::SPORE
+            | Choices::SUBSTITUTE
+            | Choices::SWORDSDANCE
+            | Choices::SYNTHESIS
+            | Choices::TAILGLOW => ZMoveEffect::ClearNegativeBoosts,
+            Choices::SPLASH => ZMoveEffect::Boost(StatBoosts {
+                attack: 3,
+                ..Default::default()
+            }),
+            Choices::BULKUP
+            | Choices::HONECLAWS
+            | Choices::HOWL
+            | Choices::LEER
+            | Choices::SCREECH
+            | Choices::TAILWHIP
+            | Choices::TAUNT
+            | Choices::TOPSYTURVY
+            | Choices::WILLOWISP
+            | Choices::WORKUP => ZMoveEffect::Boost(StatBoosts {
+                attack: 1,
+                ..Default::default()
+            }),
+            Choices::BANEFULBUNKER
+            | Choices::BLOCK
+            | Choices::CHARM
+            | Choices::FEATHERDANCE
+            | Choices::GROWL
+            | Choices::HARDEN
+            | Choices::NOBLEROAR
+            | Choices::PAINSPLIT
+            | Choices::POISONPOWDER
+            | Choices::REFLECT
+            | Choices::SPIKES
+            | Choices::SPIKYSHIELD
+            | Choices::STEALTHROCK
+            | Choices::STRENGTHSAP
+            | Choices::TOXIC
+            | Choices::TOXICSPIKES
+            | Choices::WITHDRAW => ZMoveEffect::Boost(StatBoosts {
+                defense: 1,
+                ..Default::default()
+            }),
+            Choices::CONFUSERAY | Choices::FAKETEARS | Choices::GROWTH | Choices::METALSOUND => {
+                ZMoveEffect::Boost(StatBoosts {
+                    special_attack: 1,
+                    ..Default::default()
+                })
+            }
+            Choices::CHARGE
+            | Choices::COSMICPOWER
+            | Choices::GLARE
+            | Choices::LIGHTSCREEN
+            | Choices::ROAR
+            | Choices::SAFEGUARD
+            | Choices::STUNSPORE
+            | Choices::THUNDERWAVE
+            | Choices::WHIRLWIND
+            | Choices::WISH => ZMoveEffect::Boost(StatBoosts {
+                special_defense: 1,
+                ..Default::default()
+            }),
+            Choices::MAGICCOAT => ZMoveEffect::Boost(StatBoosts {
+                special_defense: 2,
+                ..Default::default()
+            }),
+            Choices::AURORAVEIL
+            | Choices::ELECTRICTERRAIN
+            | Choices::ENCORE
+            | Choices::GRASSYTERRAIN
+            | Choices::HAIL
+            | Choices::HYPNOSIS
+            | Choices::MISTYTERRAIN
+            | Choices::PSYCHICTERRAIN
+            | Choices::RAINDANCE
+            | Choices::SANDSTORM
+            | Choices::SCARYFACE
+            | Choices::SKILLSWAP
+            | Choices::SLEEPPOWDER
+            | Choices::STICKYWEB
+            | Choices::STRINGSHOT
+            | Choices::SUNNYDAY
+            | Choices::TOXICTHREAD
+            | Choices::YAWN => ZMoveEffect::Boost(StatBoosts {
+                speed: 1,
+                ..Default::default()
+            }),
+            Choices::SWITCHEROO | Choices::TRICK => ZMoveEffect::Boost(StatBoosts {
+                speed: 2,
+                ..Default::default()
+            }),
+            Choices::DEFENSECURL | Choices::DEFOG | Choices::FOCUSENERGY | Choices::TRICKROOM => {
+                ZMoveEffect::Boost(StatBoosts {
+                    accuracy: 1,
+                    ..Default::default()
+                })
+            }
+            Choices::CELEBRATE
+            | Choices::CONVERSION
+            | Choices::GEOMANCY
+            | Choices::HAPPYHOUR
+            | Choices::HOLDHANDS => ZMoveEffect::Boost(StatBoosts {
+                attack: 1,
+                defense: 1,
+                special_attack: 1,
+                special_defense: 1,
+                speed: 1,
+                accuracy: 0,
+            }),
+            Choices::AROMATHERAPY
+            | Choices::BELLYDRUM
+            | Choices::HAZE
+            | Choices::HEALBELL
+            | Choices::REFRESH
+            | Choices::STOCKPILE => ZMoveEffect::Heal,
+            Choices::ACUPRESSURE | Choices::SLEEPTALK | Choices::TAILWIND => ZMoveEffect::CritRatio,
+            Choices::MEMENTO | Choices::PARTINGSHOT => ZMoveEffect::HealReplacement,
+            Choices::CURSE => ZMoveEffect::Curse,
+            _ => ZMoveEffect::None,
+        }
+    }
+    // Damaging moves become the Z-Move of their type, keeping their category.
+    // Status moves are used as normal with an extra effect beforehand
+    pub fn to_z_move(&self) -> Choice {
+        let mut z_move = if self.category == MoveCategory::Status {
+            self.clone()
+        } else {
+            let mut z_move = MOVES
+                .get(&Choices::z_move_for_type(&self.move_type))
+                .unwrap()
+                .clone();
+            z_move.category = self.category;
+            z_move.base_power = self.z_move_base_power();
+            z_move.move_index = self.move_index;
+            z_move
+        };
+        z_move.z_move = true;
+        z_move
+    }
     pub fn targets_special_defense(&self) -> bool {
         self.category == MoveCategory::Special
             && !(self.move_id == Choices::PSYSHOCK
@@ -20215,6 +21074,8 @@ impl Default for Choice {
             target: MoveTarget::Opponent,
             first_move: true,
             sleep_talk_move: false,
+            z_move: false,
+            spread_hit: false,
         }
     }
 }
@@ -0,0 +1,157 @@
+/// How the damage rolls of a move are turned into branches when generating instructions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageBranching {
+    // every move does its average roll
+    None,
+    // one branch for the rolls (and crits) that kill the defender and one for the rest
+    KillNoKill,
+    // the min, average and max rolls, each with and without a crit
+    MinAvgMax,
+    // all 16 rolls, each with and without a crit. Rolls that do the same damage are merged
+    AllRolls,
+}
+
+impl std::str::FromStr for DamageBranching {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(DamageBranching::None),
+            "kill-no-kill" => Ok(DamageBranching::KillNoKill),
+            "min-avg-max" => Ok(DamageBranching::MinAvgMax),
+            "all-rolls" => Ok(DamageBranching::AllRolls),
+            _ => Err(format!("invalid damage branching: {}", s)),
+        }
+    }
+}
+
+/// The `DamageBranching` used at each ply of a search. The last one is used for every ply
+/// after it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageBranchingSchedule {
+    plies: Vec<DamageBranching>,
+}
+
+impl DamageBranchingSchedule {
+    pub fn new(plies: Vec<DamageBranching>) -> DamageBranchingSchedule {
+        DamageBranchingSchedule { plies }
+    }
+
+    pub fn at_ply(&self, ply: usize) -> DamageBranching {
+        match self.plies.get(ply) {
+            Some(damage_branching) => *damage_branching,
+            None => *self.plies.last().unwrap_or(&DamageBranching::None),
+        }
+    }
+}
+
+impl From<DamageBranching> for DamageBranchingSchedule {
+    fn from(damage_branching: DamageBranching) -> DamageBranchingSchedule {
+        DamageBranchingSchedule::new(vec![damage_branching])
+    }
+}
+
+// a comma separated list of modes, one per ply. i.e. "kill-no-kill,kill-no-kill,none"
+impl std::str::FromStr for DamageBranchingSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let plies = s
+            .split(',')
+            .map(|mode| mode.trim().parse::<DamageBranching>())
+            .collect::<Result<Vec<DamageBranching>, String>>()?;
+        Ok(DamageBranchingSchedule::new(plies))
+    }
+}
+
+// The 16 damage rolls of a move, from 85% to 100% of `max_damage`
+pub fn damage_multiples(max_damage: i16) -> [f32; 16] {
+    let mut multiples = [0.0; 16];
+    let increment = max_damage as f32 * 0.01;
+    let mut damage = max_damage as f32 * 0.85;
+    for multiple in multiples.iter_mut() {
+        *multiple = damage;
+        damage += increment;
+    }
+    multiples
+}
+
+fn compare_health_with_damage_multiples(max_damage: i16, health: i16) -> (i16, i16) {
+    let health_f32 = health as f32;
+
+    let mut total_less_than = 0;
+    let mut num_less_than = 0;
+    let mut num_greater_than = 0;
+    for damage in damage_multiples(max_damage) {
+        if damage < health_f32 {
+            total_less_than += damage as i16;
+            num_less_than += 1;
+        } else if damage > health_f32 {
+            num_greater_than += 1;
+        }
+    }
+
+    (total_less_than / num_less_than, num_greater_than)
+}
+
+// Adds `chance` to the branch doing `damage`, or a new branch if there isn't one yet.
+// Damage beyond the defender's health is the same as killing it
+fn add_damage_branch(branches: &mut Vec<(i16, f32)>, damage: i16, health: i16, chance: f32) {
+    let damage = damage.min(health);
+    match branches.iter_mut().find(|(d, _)| *d == damage) {
+        Some((_, existing_chance)) => *existing_chance += chance,
+        None => branches.push((damage, chance)),
+    }
+}
+
+/// The damage and chance of each branch a damaging move splits into
+pub fn damage_roll_branches(
+    damage_branching: DamageBranching,
+    max_damage: i16,
+    max_crit_damage: i16,
+    health: i16,
+    crit_rate: f32,
+) -> Vec<(i16, f32)> {
+    let min_damage = (max_damage as f32 * 0.85) as i16;
+    let average_damage = (max_damage as f32 * 0.925) as i16;
+    let mut branches = match damage_branching {
+        DamageBranching::None => vec![(average_damage, 1.0)],
+        DamageBranching::KillNoKill if max_damage >= health && min_damage < health => {
+            let (average_non_kill_damage, num_kill_rolls) =
+                compare_health_with_damage_multiples(max_damage, health);
+
+            // the chance of a kill is the chance of the roll killing + the chance of a crit
+            let kill_chance = ((1.0 - crit_rate) * (num_kill_rolls as f32 / 16.0)) + crit_rate;
+            vec![
+                (average_non_kill_damage, 1.0 - kill_chance),
+                (health, kill_chance),
+            ]
+        }
+        DamageBranching::KillNoKill if max_damage < health => vec![
+            (average_damage, 1.0 - crit_rate),
+            ((max_crit_damage as f32 * 0.925) as i16, crit_rate),
+        ],
+        DamageBranching::KillNoKill => vec![(average_damage, 1.0)],
+        DamageBranching::MinAvgMax => {
+            let mut branches = Vec::with_capacity(6);
+            for (max, chance) in [(max_damage, 1.0 - crit_rate), (max_crit_damage, crit_rate)] {
+                for multiplier in [0.85, 0.925, 1.0] {
+                    let damage = (max as f32 * multiplier) as i16;
+                    add_damage_branch(&mut branches, damage, health, chance / 3.0);
+                }
+            }
+            branches
+        }
+        DamageBranching::AllRolls => {
+            let mut branches = Vec::with_capacity(32);
+            for (max, chance) in [(max_damage, 1.0 - crit_rate), (max_crit_damage, crit_rate)] {
+                for damage in damage_multiples(max) {
+                    add_damage_branch(&mut branches, damage as i16, health, chance / 16.0);
+                }
+            }
+            branches
+        }
+    };
+    branches.retain(|(_, chance)| *chance > 0.0);
+    branches
+}
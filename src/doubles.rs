@@ -0,0 +1,1570 @@
+use crate::choices::{Choice, Choices, MoveCategory, MoveTarget};
+use crate::damage_branching::DamageBranching;
+use crate::engine::generate_instructions::{
+    generate_end_of_turn_instructions, generate_instructions_from_move,
+    generate_instructions_from_move_attempt, generate_instructions_from_move_on_defender,
+    get_effective_speed, modify_choice_against_defender, modify_choice_priority,
+};
+use crate::engine::state::{MoveChoice, PokemonVolatileStatus};
+use crate::instruction::{
+    ApplyVolatileStatusInstruction, ChangeSideConditionInstruction, HealInstruction, Instruction,
+    RemoveVolatileStatusInstruction, StateInstructions,
+};
+use crate::state::{
+    DamageDealt, LastUsedMove, Pokemon, PokemonIndex, PokemonMoveIndex, PokemonSideCondition,
+    PokemonType, Side, SideReference, State, VolatileStatusDurations,
+};
+use crate::zobrist::slot_b_zobrist_key;
+use std::cmp;
+use std::collections::HashSet;
+use std::mem;
+
+/*
+A doubles battle is modelled on top of the singles `State`.
+
+Boosts, volatile statuses and the other fields of a `Side` that describe its active pokemon can
+only hold one pokemon at a time, so `DoublesState` keeps the fields of each side's second active
+pokemon (slot B) in an `ActiveSlot`. Swapping them into the `Side` "focuses" that side on slot B.
+
+Every instruction is generated by the singles engine while the state is focused on the attacker
+and its target, and is stored together with that focus. Applying or reversing a
+`DoublesInstruction` focuses the state the same way first, so instructions stay reversible and
+the singles `Instruction` is reused as-is.
+
+Moves that switch the user out mid-turn (u-turn, volt switch, etc.) are not supported yet. They
+are left out of the options, and generating instructions for one is an error.
+
+The `State`'s zobrist hash covers whichever slot each side is focused on. Toggling the focus swaps
+the keys of the swapped fields in and out of it, and `DoublesState::zobrist_hash` adds the slot B
+that is not focused.
+*/
+
+/// One of the two positions on a side in a doubles battle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoublesSlot {
+    A,
+    B,
+}
+
+impl DoublesSlot {
+    pub fn other(&self) -> DoublesSlot {
+        match self {
+            DoublesSlot::A => DoublesSlot::B,
+            DoublesSlot::B => DoublesSlot::A,
+        }
+    }
+}
+
+const SLOTS: [DoublesSlot; 2] = [DoublesSlot::A, DoublesSlot::B];
+
+/// The position of one of the four active pokemon
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoublesPosition {
+    pub side_ref: SideReference,
+    pub slot: DoublesSlot,
+}
+
+impl DoublesPosition {
+    pub fn new(side_ref: SideReference, slot: DoublesSlot) -> DoublesPosition {
+        DoublesPosition { side_ref, slot }
+    }
+
+    pub fn ally(&self) -> DoublesPosition {
+        DoublesPosition::new(self.side_ref, self.slot.other())
+    }
+
+    pub fn foe(&self, slot: DoublesSlot) -> DoublesPosition {
+        DoublesPosition::new(self.side_ref.get_other_side(), slot)
+    }
+}
+
+const POSITIONS: [DoublesPosition; 4] = [
+    DoublesPosition {
+        side_ref: SideReference::SideOne,
+        slot: DoublesSlot::A,
+    },
+    DoublesPosition {
+        side_ref: SideReference::SideOne,
+        slot: DoublesSlot::B,
+    },
+    DoublesPosition {
+        side_ref: SideReference::SideTwo,
+        slot: DoublesSlot::A,
+    },
+    DoublesPosition {
+        side_ref: SideReference::SideTwo,
+        slot: DoublesSlot::B,
+    },
+];
+
+const PROTECT_VOLATILE_STATUSES: [PokemonVolatileStatus; 5] = [
+    PokemonVolatileStatus::PROTECT,
+    PokemonVolatileStatus::SPIKYSHIELD,
+    PokemonVolatileStatus::BANEFULBUNKER,
+    PokemonVolatileStatus::BURNINGBULWARK,
+    PokemonVolatileStatus::SILKTRAP,
+];
+
+// volatile statuses that only last for the turn they are applied
+const SINGLE_TURN_VOLATILE_STATUSES: [PokemonVolatileStatus; 3] = [
+    PokemonVolatileStatus::FOLLOWME,
+    PokemonVolatileStatus::RAGEPOWDER,
+    PokemonVolatileStatus::HELPINGHAND,
+];
+
+/// The fields of a `Side` that belong to one active pokemon rather than to the whole side
+#[derive(Debug, Clone)]
+pub struct ActiveSlot {
+    pub active_index: PokemonIndex,
+    pub volatile_statuses: HashSet<PokemonVolatileStatus>,
+    pub volatile_status_durations: VolatileStatusDurations,
+    pub substitute_health: i16,
+    pub attack_boost: i8,
+    pub defense_boost: i8,
+    pub special_attack_boost: i8,
+    pub special_defense_boost: i8,
+    pub speed_boost: i8,
+    pub accuracy_boost: i8,
+    pub evasion_boost: i8,
+    pub last_used_move: LastUsedMove,
+    pub damage_dealt: DamageDealt,
+    // the number of turns in a row this pokemon has protected
+    pub protect: i8,
+}
+
+impl ActiveSlot {
+    pub fn new(active_index: PokemonIndex) -> ActiveSlot {
+        ActiveSlot {
+            active_index,
+            volatile_statuses: HashSet::new(),
+            volatile_status_durations: VolatileStatusDurations::default(),
+            substitute_health: 0,
+            attack_boost: 0,
+            defense_boost: 0,
+            special_attack_boost: 0,
+            special_defense_boost: 0,
+            speed_boost: 0,
+            accuracy_boost: 0,
+            evasion_boost: 0,
+            last_used_move: LastUsedMove::None,
+            damage_dealt: DamageDealt::default(),
+            protect: 0,
+        }
+    }
+
+    fn swap_with(&mut self, side: &mut Side) {
+        mem::swap(&mut self.active_index, &mut side.active_index);
+        mem::swap(&mut self.volatile_statuses, &mut side.volatile_statuses);
+        mem::swap(
+            &mut self.volatile_status_durations,
+            &mut side.volatile_status_durations,
+        );
+        mem::swap(&mut self.substitute_health, &mut side.substitute_health);
+        mem::swap(&mut self.attack_boost, &mut side.attack_boost);
+        mem::swap(&mut self.defense_boost, &mut side.defense_boost);
+        mem::swap(
+            &mut self.special_attack_boost,
+            &mut side.special_attack_boost,
+        );
+        mem::swap(
+            &mut self.special_defense_boost,
+            &mut side.special_defense_boost,
+        );
+        mem::swap(&mut self.speed_boost, &mut side.speed_boost);
+        mem::swap(&mut self.accuracy_boost, &mut side.accuracy_boost);
+        mem::swap(&mut self.evasion_boost, &mut side.evasion_boost);
+        mem::swap(&mut self.last_used_move, &mut side.last_used_move);
+        mem::swap(&mut self.damage_dealt, &mut side.damage_dealt);
+        mem::swap(&mut self.protect, &mut side.side_conditions.protect);
+    }
+}
+
+/// The slot each `Side` of the underlying `State` refers to while an instruction is applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlotFocus {
+    pub side_one: DoublesSlot,
+    pub side_two: DoublesSlot,
+}
+
+impl SlotFocus {
+    // focuses `position`, and slot A of the other side
+    fn of(position: DoublesPosition) -> SlotFocus {
+        SlotFocus::between(position, DoublesSlot::A)
+    }
+
+    // focuses the attacker and the foe in `defender_slot`
+    fn between(attacker: DoublesPosition, defender_slot: DoublesSlot) -> SlotFocus {
+        match attacker.side_ref {
+            SideReference::SideOne => SlotFocus {
+                side_one: attacker.slot,
+                side_two: defender_slot,
+            },
+            SideReference::SideTwo => SlotFocus {
+                side_one: defender_slot,
+                side_two: attacker.slot,
+            },
+        }
+    }
+}
+
+/// A singles `Instruction` and the focus it was generated with
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoublesInstruction {
+    pub focus: SlotFocus,
+    pub instruction: Instruction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoublesStateInstructions {
+    pub percentage: f32,
+    pub instruction_list: Vec<DoublesInstruction>,
+}
+
+impl Default for DoublesStateInstructions {
+    fn default() -> DoublesStateInstructions {
+        DoublesStateInstructions {
+            percentage: 100.0,
+            instruction_list: Vec::with_capacity(8),
+        }
+    }
+}
+
+impl DoublesStateInstructions {
+    pub fn update_percentage(&mut self, modifier: f32) {
+        self.percentage *= modifier;
+    }
+
+    fn extend(
+        &self,
+        focus: SlotFocus,
+        instructions: StateInstructions,
+    ) -> DoublesStateInstructions {
+        let mut instruction_list = self.instruction_list.clone();
+        instruction_list.extend(
+            instructions
+                .instruction_list
+                .into_iter()
+                .map(|instruction| DoublesInstruction { focus, instruction }),
+        );
+        DoublesStateInstructions {
+            percentage: instructions.percentage,
+            instruction_list,
+        }
+    }
+}
+
+/// Who a move that hits a single pokemon is aimed at
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DoublesTarget {
+    Foe(DoublesSlot),
+    Ally,
+    // the move picks its own targets: spread moves and moves on the user, its side or the field
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DoublesMoveChoice {
+    Move(PokemonMoveIndex, DoublesTarget),
+    Switch(PokemonIndex),
+    None,
+}
+
+/// The choices of both active pokemon on a side
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoublesSideChoice {
+    pub slot_a: DoublesMoveChoice,
+    pub slot_b: DoublesMoveChoice,
+}
+
+impl DoublesSideChoice {
+    pub fn new(slot_a: DoublesMoveChoice, slot_b: DoublesMoveChoice) -> DoublesSideChoice {
+        DoublesSideChoice { slot_a, slot_b }
+    }
+
+    pub fn get(&self, slot: DoublesSlot) -> DoublesMoveChoice {
+        match slot {
+            DoublesSlot::A => self.slot_a,
+            DoublesSlot::B => self.slot_b,
+        }
+    }
+}
+
+/// Which pokemon a move hits in doubles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DoublesMoveTargeting {
+    // the user, its side or the whole field
+    User,
+    // only the user's ally
+    Ally,
+    // one pokemon chosen by the user
+    SingleTarget,
+    AllAdjacentFoes,
+    // both foes and the user's ally
+    AllAdjacent,
+}
+
+pub fn doubles_move_targeting(choice: &Choice) -> DoublesMoveTargeting {
+    match choice.move_id {
+        Choices::HELPINGHAND | Choices::HEALPULSE | Choices::FLORALHEALING => {
+            DoublesMoveTargeting::Ally
+        }
+        Choices::ACID
+        | Choices::AIRCUTTER
+        | Choices::ASTRALBARRAGE
+        | Choices::BLEAKWINDSTORM
+        | Choices::BLIZZARD
+        | Choices::BREAKINGSWIPE
+        | Choices::DAZZLINGGLEAM
+        | Choices::DIAMONDSTORM
+        | Choices::DRAGONENERGY
+        | Choices::ELECTROWEB
+        | Choices::ERUPTION
+        | Choices::FIERYWRATH
+        | Choices::GLACIALLANCE
+        | Choices::GLACIATE
+        | Choices::HEATWAVE
+        | Choices::HYPERVOICE
+        | Choices::ICYWIND
+        | Choices::MAKEITRAIN
+        | Choices::MATCHAGOTCHA
+        | Choices::MORTALSPIN
+        | Choices::MUDDYWATER
+        | Choices::ORIGINPULSE
+        | Choices::OVERDRIVE
+        | Choices::POWDERSNOW
+        | Choices::PRECIPICEBLADES
+        | Choices::RAZORLEAF
+        | Choices::ROCKSLIDE
+        | Choices::SANDSEARSTORM
+        | Choices::SNARL
+        | Choices::SPRINGTIDESTORM
+        | Choices::STRUGGLEBUG
+        | Choices::SWIFT
+        | Choices::TWISTER
+        | Choices::WATERSPOUT
+        | Choices::WILDBOLTSTORM => DoublesMoveTargeting::AllAdjacentFoes,
+        Choices::BOOMBURST
+        | Choices::BULLDOZE
+        | Choices::DISCHARGE
+        | Choices::EARTHQUAKE
+        | Choices::EXPLOSION
+        | Choices::LAVAPLUME
+        | Choices::MAGNITUDE
+        | Choices::MISTYEXPLOSION
+        | Choices::PARABOLICCHARGE
+        | Choices::PETALBLIZZARD
+        | Choices::SELFDESTRUCT
+        | Choices::SLUDGEWAVE
+        | Choices::SPARKLINGARIA
+        | Choices::SURF => DoublesMoveTargeting::AllAdjacent,
+        _ if choice.target == MoveTarget::User => DoublesMoveTargeting::User,
+        _ => DoublesMoveTargeting::SingleTarget,
+    }
+}
+
+// single target moves that heal the ally instead of damaging it
+fn heals_ally(move_id: Choices) -> bool {
+    matches!(
+        move_id,
+        Choices::POLLENPUFF | Choices::HEALPULSE | Choices::FLORALHEALING
+    )
+}
+
+// u-turn, volt switch, etc. are not supported in doubles
+fn switches_user_out(choice: &Choice) -> bool {
+    choice.flags.pivot || choice.move_id == Choices::SHEDTAIL
+}
+
+/// A doubles battle: a singles `State` holding slot A of each side and everything shared by a
+/// side or the whole field, and the fields that belong to each side's slot B
+#[derive(Debug, Clone)]
+pub struct DoublesState {
+    pub state: State,
+    pub side_one_slot_b: ActiveSlot,
+    pub side_two_slot_b: ActiveSlot,
+}
+
+impl DoublesState {
+    pub fn new(
+        state: State,
+        side_one_slot_b: PokemonIndex,
+        side_two_slot_b: PokemonIndex,
+    ) -> DoublesState {
+        DoublesState {
+            state,
+            side_one_slot_b: ActiveSlot::new(side_one_slot_b),
+            side_two_slot_b: ActiveSlot::new(side_two_slot_b),
+        }
+    }
+
+    fn get_slot_b_immutable(&self, side_ref: &SideReference) -> &ActiveSlot {
+        match side_ref {
+            SideReference::SideOne => &self.side_one_slot_b,
+            SideReference::SideTwo => &self.side_two_slot_b,
+        }
+    }
+
+    // Swaps slot B into every side focused on it. Toggling the same focus again undoes it
+    fn toggle_focus(&mut self, focus: SlotFocus) {
+        if focus.side_one == DoublesSlot::B {
+            self.swap_slot_b(SideReference::SideOne);
+        }
+        if focus.side_two == DoublesSlot::B {
+            self.swap_slot_b(SideReference::SideTwo);
+        }
+    }
+
+    fn swap_slot_b(&mut self, side_ref: SideReference) {
+        let key_before = self.state.active_slot_zobrist_hash(&side_ref);
+        match side_ref {
+            SideReference::SideOne => self.side_one_slot_b.swap_with(&mut self.state.side_one),
+            SideReference::SideTwo => self.side_two_slot_b.swap_with(&mut self.state.side_two),
+        }
+        self.state.zobrist_hash ^= key_before ^ self.state.active_slot_zobrist_hash(&side_ref);
+    }
+
+    /// The zobrist hash of the whole doubles battle: the `State`'s hash and both slot Bs
+    pub fn zobrist_hash(&mut self) -> u64 {
+        let mut hash = self.state.zobrist_hash;
+        for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+            self.swap_slot_b(side_ref);
+            hash ^= slot_b_zobrist_key(&side_ref, self.state.active_slot_zobrist_hash(&side_ref));
+            self.swap_slot_b(side_ref);
+        }
+        hash
+    }
+
+    pub fn active_index(&self, position: DoublesPosition) -> PokemonIndex {
+        match position.slot {
+            DoublesSlot::A => {
+                self.state
+                    .get_side_immutable(&position.side_ref)
+                    .active_index
+            }
+            DoublesSlot::B => self.get_slot_b_immutable(&position.side_ref).active_index,
+        }
+    }
+
+    pub fn get_active_immutable(&self, position: DoublesPosition) -> &Pokemon {
+        &self.state.get_side_immutable(&position.side_ref).pokemon[self.active_index(position)]
+    }
+
+    pub fn volatile_statuses(&self, position: DoublesPosition) -> &HashSet<PokemonVolatileStatus> {
+        match position.slot {
+            DoublesSlot::A => {
+                &self
+                    .state
+                    .get_side_immutable(&position.side_ref)
+                    .volatile_statuses
+            }
+            DoublesSlot::B => {
+                &self
+                    .get_slot_b_immutable(&position.side_ref)
+                    .volatile_statuses
+            }
+        }
+    }
+
+    fn is_alive(&self, position: DoublesPosition) -> bool {
+        self.get_active_immutable(position).hp > 0
+    }
+
+    fn is_protected(&self, position: DoublesPosition) -> bool {
+        let volatile_statuses = self.volatile_statuses(position);
+        PROTECT_VOLATILE_STATUSES
+            .iter()
+            .any(|vs| volatile_statuses.contains(vs))
+    }
+
+    fn wide_guard_active(&self, side_ref: &SideReference) -> bool {
+        self.state
+            .get_side_immutable(side_ref)
+            .side_conditions
+            .wide_guard
+            > 0
+    }
+
+    pub fn battle_is_over(&self) -> f32 {
+        self.state.battle_is_over()
+    }
+
+    pub fn apply_instructions(&mut self, instructions: &[DoublesInstruction]) {
+        for i in instructions {
+            self.toggle_focus(i.focus);
+            self.state.apply_one_instruction(&i.instruction);
+            self.toggle_focus(i.focus);
+        }
+    }
+
+    pub fn reverse_instructions(&mut self, instructions: &[DoublesInstruction]) {
+        for i in instructions.iter().rev() {
+            self.toggle_focus(i.focus);
+            self.state.reverse_one_instruction(&i.instruction);
+            self.toggle_focus(i.focus);
+        }
+    }
+
+    // the alive pokemon on a side that are not active in either slot
+    fn reserve_pokemon(&self, side_ref: &SideReference) -> Vec<PokemonIndex> {
+        let a = self.active_index(DoublesPosition::new(*side_ref, DoublesSlot::A));
+        let b = self.active_index(DoublesPosition::new(*side_ref, DoublesSlot::B));
+        self.state
+            .get_side_immutable(side_ref)
+            .get_alive_pkmn_indices()
+            .into_iter()
+            .filter(|index| *index != a && *index != b)
+            .collect()
+    }
+
+    fn needs_replacement(&self, position: DoublesPosition) -> bool {
+        !self.is_alive(position) && !self.reserve_pokemon(&position.side_ref).is_empty()
+    }
+
+    /// A turn where the only choices are replacing fainted pokemon
+    pub fn is_replacement_turn(&self) -> bool {
+        POSITIONS.iter().any(|p| self.needs_replacement(*p))
+    }
+
+    /// The choices available to both sides.
+    /// When a fainted pokemon has to be replaced, the only choices are the replacements
+    pub fn get_all_options(&mut self) -> (Vec<DoublesSideChoice>, Vec<DoublesSideChoice>) {
+        let replacing = self.is_replacement_turn();
+        (
+            self.get_side_options(SideReference::SideOne, replacing),
+            self.get_side_options(SideReference::SideTwo, replacing),
+        )
+    }
+
+    fn get_side_options(
+        &mut self,
+        side_ref: SideReference,
+        replacing: bool,
+    ) -> Vec<DoublesSideChoice> {
+        let slot_a_options =
+            self.get_slot_options(DoublesPosition::new(side_ref, DoublesSlot::A), replacing);
+        let slot_b_options =
+            self.get_slot_options(DoublesPosition::new(side_ref, DoublesSlot::B), replacing);
+
+        let mut side_options = Vec::with_capacity(slot_a_options.len() * slot_b_options.len());
+        for slot_a in slot_a_options.iter() {
+            for slot_b in slot_b_options.iter() {
+                match (slot_a, slot_b) {
+                    (DoublesMoveChoice::Switch(a), DoublesMoveChoice::Switch(b)) if a == b => {}
+                    _ => side_options.push(DoublesSideChoice::new(*slot_a, *slot_b)),
+                }
+            }
+        }
+        side_options
+    }
+
+    fn get_slot_options(
+        &mut self,
+        position: DoublesPosition,
+        replacing: bool,
+    ) -> Vec<DoublesMoveChoice> {
+        let switches: Vec<DoublesMoveChoice> = self
+            .reserve_pokemon(&position.side_ref)
+            .into_iter()
+            .map(DoublesMoveChoice::Switch)
+            .collect();
+        if replacing {
+            if self.needs_replacement(position) {
+                return switches;
+            }
+            return vec![DoublesMoveChoice::None];
+        }
+        if !self.is_alive(position) {
+            return vec![DoublesMoveChoice::None];
+        }
+
+        let focus = SlotFocus::of(position);
+        self.toggle_focus(focus);
+        let side = self.state.get_side_immutable(&position.side_ref);
+        let mut move_choices = Vec::with_capacity(4);
+        if side
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::MUSTRECHARGE)
+        {
+            move_choices.push(MoveChoice::None);
+        } else if let Some(move_index) = side.active_is_charging_move() {
+            move_choices.push(MoveChoice::Move(move_index));
+        } else {
+            side.get_active_immutable().add_available_moves(
+                &mut move_choices,
+                &side.last_used_move,
+                side.volatile_statuses
+                    .contains(&PokemonVolatileStatus::ENCORE),
+                side.volatile_statuses
+                    .contains(&PokemonVolatileStatus::TAUNT),
+                false,
+                false,
+                false,
+            );
+        }
+        let trapped = SLOTS.iter().any(|slot| {
+            let foe = position.foe(*slot);
+            self.is_alive(foe) && side.trapped(self.get_active_immutable(foe))
+        }) || move_choices.len() == 1 && move_choices[0] == MoveChoice::None;
+        self.toggle_focus(focus);
+
+        let mut options = Vec::with_capacity(12);
+        for move_choice in move_choices {
+            if let MoveChoice::Move(move_index) = move_choice {
+                let choice = &self.get_active_immutable(position).moves[&move_index].choice;
+                if switches_user_out(choice) {
+                    continue;
+                }
+                self.add_move_with_targets(position, move_index, &mut options);
+            }
+        }
+        if !trapped {
+            options.extend(switches);
+        }
+        if options.is_empty() {
+            options.push(DoublesMoveChoice::None);
+        }
+        options
+    }
+
+    fn add_move_with_targets(
+        &self,
+        position: DoublesPosition,
+        move_index: PokemonMoveIndex,
+        options: &mut Vec<DoublesMoveChoice>,
+    ) {
+        let choice = &self.get_active_immutable(position).moves[&move_index].choice;
+        let ally_alive = self.is_alive(position.ally());
+        match doubles_move_targeting(choice) {
+            DoublesMoveTargeting::SingleTarget => {
+                let alive_foes: Vec<DoublesSlot> = SLOTS
+                    .iter()
+                    .copied()
+                    .filter(|slot| self.is_alive(position.foe(*slot)))
+                    .collect();
+                if alive_foes.is_empty() {
+                    options.push(DoublesMoveChoice::Move(
+                        move_index,
+                        DoublesTarget::Foe(DoublesSlot::A),
+                    ));
+                }
+                for slot in alive_foes {
+                    options.push(DoublesMoveChoice::Move(
+                        move_index,
+                        DoublesTarget::Foe(slot),
+                    ));
+                }
+                if ally_alive && heals_ally(choice.move_id) {
+                    options.push(DoublesMoveChoice::Move(move_index, DoublesTarget::Ally));
+                }
+            }
+            DoublesMoveTargeting::Ally => {
+                if ally_alive {
+                    options.push(DoublesMoveChoice::Move(move_index, DoublesTarget::Ally));
+                }
+            }
+            _ => options.push(DoublesMoveChoice::Move(move_index, DoublesTarget::Auto)),
+        }
+    }
+}
+
+struct DoublesAction {
+    position: DoublesPosition,
+    move_choice: DoublesMoveChoice,
+    choice: Choice,
+}
+
+fn get_actions(
+    doubles_state: &mut DoublesState,
+    side_one_choice: &DoublesSideChoice,
+    side_two_choice: &DoublesSideChoice,
+) -> Result<Vec<DoublesAction>, String> {
+    let mut actions = Vec::with_capacity(4);
+    for position in POSITIONS {
+        let move_choice = match position.side_ref {
+            SideReference::SideOne => side_one_choice.get(position.slot),
+            SideReference::SideTwo => side_two_choice.get(position.slot),
+        };
+        let mut choice;
+        match move_choice {
+            DoublesMoveChoice::Move(move_index, _) => {
+                choice = doubles_state.get_active_immutable(position).moves[&move_index]
+                    .choice
+                    .clone();
+                choice.move_index = move_index;
+                if switches_user_out(&choice) {
+                    return Err(format!(
+                        "{:?} switches the user out, which is not supported in doubles",
+                        choice.move_id
+                    ));
+                }
+                let focus = SlotFocus::of(position);
+                doubles_state.toggle_focus(focus);
+                modify_choice_priority(&doubles_state.state, &position.side_ref, &mut choice);
+                doubles_state.toggle_focus(focus);
+            }
+            DoublesMoveChoice::Switch(switch_id) => {
+                choice = Choice::default();
+                choice.switch_id = switch_id;
+                choice.category = MoveCategory::Switch;
+            }
+            DoublesMoveChoice::None => {
+                choice = Choice::default();
+            }
+        }
+        actions.push(DoublesAction {
+            position,
+            move_choice,
+            choice,
+        });
+    }
+    Ok(actions)
+}
+
+fn permutations(items: &[usize]) -> Vec<Vec<usize>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut result = vec![];
+    for (i, item) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(i);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, *item);
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+// Every order the actions can happen in and its chance.
+// Switches go first, then moves by priority and speed. Speed ties are equally likely to go
+// either way
+fn turn_orders(
+    doubles_state: &mut DoublesState,
+    actions: &[DoublesAction],
+) -> Vec<(Vec<usize>, f32)> {
+    let mut keyed_actions = vec![];
+    for (index, action) in actions.iter().enumerate() {
+        if action.move_choice == DoublesMoveChoice::None {
+            continue;
+        }
+        let focus = SlotFocus::of(action.position);
+        doubles_state.toggle_focus(focus);
+        let mut speed = get_effective_speed(&doubles_state.state, &action.position.side_ref);
+        doubles_state.toggle_focus(focus);
+        if doubles_state.state.trick_room.active {
+            speed = -speed;
+        }
+        let is_switch = action.choice.category == MoveCategory::Switch;
+        keyed_actions.push(((is_switch, action.choice.priority, speed), index));
+    }
+    keyed_actions.sort_by_key(|k| cmp::Reverse(k.0));
+
+    let mut orders = vec![(vec![], 1.0)];
+    let mut start = 0;
+    while start < keyed_actions.len() {
+        let mut end = start + 1;
+        while end < keyed_actions.len() && keyed_actions[end].0 == keyed_actions[start].0 {
+            end += 1;
+        }
+        let tied: Vec<usize> = keyed_actions[start..end].iter().map(|k| k.1).collect();
+        let tie_orders = permutations(&tied);
+        let chance = 1.0 / tie_orders.len() as f32;
+        let mut next_orders = Vec::with_capacity(orders.len() * tie_orders.len());
+        for (order, order_chance) in orders.iter() {
+            for tie_order in tie_orders.iter() {
+                let mut next_order: Vec<usize> = order.clone();
+                next_order.extend(tie_order);
+                next_orders.push((next_order, order_chance * chance));
+            }
+        }
+        orders = next_orders;
+        start = end;
+    }
+    orders
+}
+
+// Applies `branch` and `focus`, runs `generate` on the singles state and adds what it generated
+// to `branch`
+fn generate_focused<F>(
+    doubles_state: &mut DoublesState,
+    branch: &DoublesStateInstructions,
+    focus: SlotFocus,
+    generate: F,
+) -> Vec<DoublesStateInstructions>
+where
+    F: FnOnce(&mut State, StateInstructions, &mut Vec<StateInstructions>),
+{
+    doubles_state.apply_instructions(&branch.instruction_list);
+    doubles_state.toggle_focus(focus);
+    let mut state_instructions_vec = Vec::with_capacity(4);
+    generate(
+        &mut doubles_state.state,
+        StateInstructions {
+            percentage: branch.percentage,
+            instruction_list: vec![],
+        },
+        &mut state_instructions_vec,
+    );
+    doubles_state.toggle_focus(focus);
+    doubles_state.reverse_instructions(&branch.instruction_list);
+    state_instructions_vec
+        .into_iter()
+        .map(|state_instructions| branch.extend(focus, state_instructions))
+        .collect()
+}
+
+// The foe a single target move hits: a foe using follow me or rage powder, then the chosen foe,
+// then the other foe if the chosen one has fainted
+fn resolve_foe_target(
+    doubles_state: &DoublesState,
+    attacker: DoublesPosition,
+    chosen_slot: DoublesSlot,
+) -> Option<DoublesSlot> {
+    let attacker_is_grass = doubles_state
+        .get_active_immutable(attacker)
+        .has_type(&PokemonType::GRASS);
+    for slot in SLOTS {
+        let foe = attacker.foe(slot);
+        let volatile_statuses = doubles_state.volatile_statuses(foe);
+        if doubles_state.is_alive(foe)
+            && (volatile_statuses.contains(&PokemonVolatileStatus::FOLLOWME)
+                || (volatile_statuses.contains(&PokemonVolatileStatus::RAGEPOWDER)
+                    && !attacker_is_grass))
+        {
+            return Some(slot);
+        }
+    }
+    [chosen_slot, chosen_slot.other()]
+        .iter()
+        .copied()
+        .find(|slot| doubles_state.is_alive(attacker.foe(*slot)))
+}
+
+fn defender_choice(actions: &[DoublesAction], defender: DoublesPosition) -> Choice {
+    actions
+        .iter()
+        .find(|action| action.position == defender)
+        .map_or(Choice::default(), |action| action.choice.clone())
+}
+
+// whether the attacker moves before the defender does in `order`
+fn moves_before(
+    actions: &[DoublesAction],
+    order: &[usize],
+    attacker: DoublesPosition,
+    defender: DoublesPosition,
+) -> bool {
+    let order_of = |position: DoublesPosition| {
+        order
+            .iter()
+            .position(|index| actions[*index].position == position)
+    };
+    match (order_of(attacker), order_of(defender)) {
+        (Some(attacker_order), Some(defender_order)) => attacker_order < defender_order,
+        _ => true,
+    }
+}
+
+// The side an instruction changes, or None for instructions on the whole field
+fn side_ref_mut(instruction: &mut Instruction) -> Option<&mut SideReference> {
+    match instruction {
+        Instruction::Switch(i) => Some(&mut i.side_ref),
+        Instruction::ApplyVolatileStatus(i) => Some(&mut i.side_ref),
+        Instruction::RemoveVolatileStatus(i) => Some(&mut i.side_ref),
+        Instruction::ChangeStatus(i) => Some(&mut i.side_ref),
+        Instruction::Heal(i) => Some(&mut i.side_ref),
+        Instruction::Damage(i) => Some(&mut i.side_ref),
+        Instruction::Boost(i) => Some(&mut i.side_ref),
+        Instruction::ChangeSideCondition(i) => Some(&mut i.side_ref),
+        Instruction::ChangeVolatileStatusDuration(i) => Some(&mut i.side_ref),
+        Instruction::ChangeType(i) => Some(&mut i.side_ref),
+        Instruction::ChangeAbility(i) => Some(&mut i.side_ref),
+        Instruction::ChangeBaseAbility(i) => Some(&mut i.side_ref),
+        Instruction::ChangeItem(i) => Some(&mut i.side_ref),
+        Instruction::ChangeAttack(i) => Some(&mut i.side_ref),
+        Instruction::ChangeDefense(i) => Some(&mut i.side_ref),
+        Instruction::ChangeSpecialAttack(i) => Some(&mut i.side_ref),
+        Instruction::ChangeSpecialDefense(i) => Some(&mut i.side_ref),
+        Instruction::ChangeSpeed(i) => Some(&mut i.side_ref),
+        Instruction::DisableMove(i) => Some(&mut i.side_ref),
+        Instruction::EnableMove(i) => Some(&mut i.side_ref),
+        Instruction::ChangeWish(i) => Some(&mut i.side_ref),
+        Instruction::DecrementWish(i) => Some(&mut i.side_ref),
+        Instruction::SetFutureSight(i) => Some(&mut i.side_ref),
+        Instruction::DecrementFutureSight(i) => Some(&mut i.side_ref),
+        Instruction::DamageSubstitute(i) => Some(&mut i.side_ref),
+        Instruction::DecrementRestTurns(i) => Some(&mut i.side_ref),
+        Instruction::SetRestTurns(i) => Some(&mut i.side_ref),
+        Instruction::SetSleepTurns(i) => Some(&mut i.side_ref),
+        Instruction::ChangeSubstituteHealth(i) => Some(&mut i.side_ref),
+        Instruction::FormeChange(i) => Some(&mut i.side_ref),
+        Instruction::ToggleBatonPassing(i) => Some(&mut i.side_ref),
+        Instruction::ToggleShedTailing(i) => Some(&mut i.side_ref),
+        Instruction::SetLastUsedMove(i) => Some(&mut i.side_ref),
+        Instruction::ChangeDamageDealtDamage(i) => Some(&mut i.side_ref),
+        Instruction::ChangeDamageDealtMoveCatagory(i) => Some(&mut i.side_ref),
+        Instruction::ToggleDamageDealtHitSubstitute(i) => Some(&mut i.side_ref),
+        Instruction::DecrementPP(i) => Some(&mut i.side_ref),
+        Instruction::ToggleTerastallized(i) => Some(&mut i.side_ref),
+        Instruction::ToggleMegaEvolved(i) => Some(&mut i.side_ref),
+        Instruction::ToggleZMoveUsed(i) => Some(&mut i.side_ref),
+        Instruction::ChangeWeather(_)
+        | Instruction::DecrementWeatherTurnsRemaining
+        | Instruction::ChangeTerrain(_)
+        | Instruction::DecrementTerrainTurnsRemaining
+        | Instruction::SetSideOneMoveSecondSwitchOutMove(_)
+        | Instruction::SetSideTwoMoveSecondSwitchOutMove(_)
+        | Instruction::ToggleTrickRoom(_)
+        | Instruction::DecrementTrickRoomTurnsRemaining
+        | Instruction::ToggleSideOneForceSwitch
+        | Instruction::ToggleSideTwoForceSwitch => None,
+    }
+}
+
+// Moves an instruction on the side standing in for the ally's side back to the ally's side.
+// Returns whether the instruction was moved
+fn move_to_ally_side(instruction: &mut Instruction, stand_in_side: SideReference) -> bool {
+    match instruction {
+        Instruction::ToggleSideOneForceSwitch if stand_in_side == SideReference::SideOne => {
+            *instruction = Instruction::ToggleSideTwoForceSwitch;
+            true
+        }
+        Instruction::ToggleSideTwoForceSwitch if stand_in_side == SideReference::SideTwo => {
+            *instruction = Instruction::ToggleSideOneForceSwitch;
+            true
+        }
+        _ => match side_ref_mut(instruction) {
+            Some(side_ref) if *side_ref == stand_in_side => {
+                *side_ref = stand_in_side.get_other_side();
+                true
+            }
+            _ => false,
+        },
+    }
+}
+
+// A state where the attacker's ally stands in for the active pokemon of the other side, so that
+// the singles engine can use a move on it
+fn ally_as_defender_state(doubles_state: &DoublesState, attacker: DoublesPosition) -> State {
+    let mut attacker_view = doubles_state.clone();
+    attacker_view.toggle_focus(SlotFocus::of(attacker));
+    let mut ally_view = doubles_state.clone();
+    ally_view.toggle_focus(SlotFocus::of(attacker.ally()));
+    let mut state = attacker_view.state;
+    match attacker.side_ref {
+        SideReference::SideOne => state.side_two = ally_view.state.side_one,
+        SideReference::SideTwo => state.side_one = ally_view.state.side_two,
+    }
+    state
+}
+
+// Generates the attacker's move against its ally with the singles engine, then moves the
+// instructions on the side standing in for the ally's back to the ally
+fn generate_instructions_against_ally(
+    doubles_state: &mut DoublesState,
+    branch: &DoublesStateInstructions,
+    attacker: DoublesPosition,
+    choice: &mut Choice,
+    ally_choice: &Choice,
+    damage_branching: DamageBranching,
+) -> Vec<DoublesStateInstructions> {
+    doubles_state.apply_instructions(&branch.instruction_list);
+    let mut state = ally_as_defender_state(doubles_state, attacker);
+    doubles_state.reverse_instructions(&branch.instruction_list);
+
+    modify_choice_against_defender(&state, choice, ally_choice, &attacker.side_ref);
+    let mut state_instructions_vec = Vec::with_capacity(4);
+    generate_instructions_from_move_on_defender(
+        &mut state,
+        choice,
+        ally_choice,
+        attacker.side_ref,
+        StateInstructions {
+            percentage: branch.percentage,
+            instruction_list: vec![],
+        },
+        &mut state_instructions_vec,
+        damage_branching,
+    );
+
+    let stand_in_side = attacker.side_ref.get_other_side();
+    state_instructions_vec
+        .into_iter()
+        .map(|state_instructions| {
+            let mut next_branch = DoublesStateInstructions {
+                percentage: state_instructions.percentage,
+                instruction_list: branch.instruction_list.clone(),
+            };
+            for mut instruction in state_instructions.instruction_list {
+                let focus = if move_to_ally_side(&mut instruction, stand_in_side) {
+                    SlotFocus::of(attacker.ally())
+                } else {
+                    SlotFocus::of(attacker)
+                };
+                next_branch
+                    .instruction_list
+                    .push(DoublesInstruction { focus, instruction });
+            }
+            next_branch
+        })
+        .collect()
+}
+
+// Runs the checks that happen before the attacker uses its move: status conditions, pp, etc.
+// The branches where the move is not used are added to `final_instructions`
+fn attempt_move(
+    doubles_state: &mut DoublesState,
+    branch: &DoublesStateInstructions,
+    focus: SlotFocus,
+    attacking_side: SideReference,
+    choice: &mut Choice,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+) -> Option<DoublesStateInstructions> {
+    doubles_state.apply_instructions(&branch.instruction_list);
+    doubles_state.toggle_focus(focus);
+    let mut not_used = Vec::with_capacity(4);
+    let used = generate_instructions_from_move_attempt(
+        &mut doubles_state.state,
+        choice,
+        &Choice::default(),
+        attacking_side,
+        StateInstructions {
+            percentage: branch.percentage,
+            instruction_list: vec![],
+        },
+        &mut not_used,
+    );
+    if let Some(used) = &used {
+        doubles_state
+            .state
+            .reverse_instructions(&used.instruction_list);
+    }
+    doubles_state.toggle_focus(focus);
+    doubles_state.reverse_instructions(&branch.instruction_list);
+    for state_instructions in not_used {
+        final_instructions.push(branch.extend(focus, state_instructions));
+    }
+    used.map(|used| branch.extend(focus, used))
+}
+
+// A move that hits more than one pokemon. The attacker's checks happen once, then the move
+// hits each target in turn through the singles engine
+#[allow(clippy::too_many_arguments)]
+fn generate_instructions_from_spread_move(
+    doubles_state: &mut DoublesState,
+    actions: &[DoublesAction],
+    order: &[usize],
+    attacker: DoublesPosition,
+    mut choice: Choice,
+    branch: DoublesStateInstructions,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+    damage_branching: DamageBranching,
+) {
+    doubles_state.apply_instructions(&branch.instruction_list);
+    let mut targets: Vec<DoublesPosition> = SLOTS
+        .iter()
+        .copied()
+        .map(|slot| attacker.foe(slot))
+        .filter(|foe| doubles_state.is_alive(*foe))
+        .collect();
+    if doubles_move_targeting(&choice) == DoublesMoveTargeting::AllAdjacent
+        && doubles_state.is_alive(attacker.ally())
+    {
+        targets.push(attacker.ally());
+    }
+    choice.spread_hit = targets.len() > 1;
+    // protected pokemon and pokemon behind wide guard are not hit at all
+    let hit_targets: Vec<DoublesPosition> = targets
+        .into_iter()
+        .filter(|target| {
+            let protected = choice.flags.protect && doubles_state.is_protected(*target);
+            !protected && !doubles_state.wide_guard_active(&target.side_ref)
+        })
+        .collect();
+    doubles_state.reverse_instructions(&branch.instruction_list);
+
+    // the checks before the move are made against the first target, which is hit by the move as
+    // they left it. The other targets are hit by the move as it was before the checks, modified
+    // for each target
+    let mut other_targets_choice = choice.clone();
+    // effects on the user only happen once
+    if other_targets_choice
+        .boost
+        .as_ref()
+        .is_some_and(|boost| boost.target == MoveTarget::User)
+    {
+        other_targets_choice.boost = None;
+    }
+    if other_targets_choice
+        .heal
+        .as_ref()
+        .is_some_and(|heal| heal.target == MoveTarget::User)
+    {
+        other_targets_choice.heal = None;
+    }
+
+    let attempt_focus = match hit_targets.first() {
+        Some(target) if target.side_ref != attacker.side_ref => {
+            SlotFocus::between(attacker, target.slot)
+        }
+        _ => SlotFocus::of(attacker),
+    };
+    let attempt = match attempt_move(
+        doubles_state,
+        &branch,
+        attempt_focus,
+        attacker.side_ref,
+        &mut choice,
+        final_instructions,
+    ) {
+        Some(attempt) => attempt,
+        None => return,
+    };
+
+    let mut branches = vec![attempt];
+    for (i, target) in hit_targets.into_iter().enumerate() {
+        let mut next_branches = Vec::with_capacity(branches.len());
+        let is_attempt_target = i == 0 && target.side_ref != attacker.side_ref;
+        let mut target_choice = if is_attempt_target {
+            choice.clone()
+        } else {
+            other_targets_choice.clone()
+        };
+        target_choice.first_move = moves_before(actions, order, attacker, target);
+        let target_defender_choice = defender_choice(actions, target);
+        if target.side_ref == attacker.side_ref {
+            for branch in branches {
+                next_branches.extend(generate_instructions_against_ally(
+                    doubles_state,
+                    &branch,
+                    attacker,
+                    &mut target_choice.clone(),
+                    &target_defender_choice,
+                    damage_branching,
+                ));
+            }
+            branches = next_branches;
+            continue;
+        }
+
+        for branch in branches {
+            let mut branch_choice = target_choice.clone();
+            next_branches.extend(generate_focused(
+                doubles_state,
+                &branch,
+                SlotFocus::between(attacker, target.slot),
+                |state, incoming_instructions, state_instructions_vec| {
+                    if !is_attempt_target {
+                        modify_choice_against_defender(
+                            state,
+                            &mut branch_choice,
+                            &target_defender_choice,
+                            &attacker.side_ref,
+                        );
+                    }
+                    generate_instructions_from_move_on_defender(
+                        state,
+                        &mut branch_choice,
+                        &target_defender_choice,
+                        attacker.side_ref,
+                        incoming_instructions,
+                        state_instructions_vec,
+                        damage_branching,
+                    )
+                },
+            ));
+        }
+        branches = next_branches;
+    }
+    final_instructions.extend(branches);
+}
+
+// A move used on the attacker's ally: helping hand and moves that heal the ally
+fn generate_instructions_from_ally_move(
+    doubles_state: &mut DoublesState,
+    attacker: DoublesPosition,
+    mut choice: Choice,
+    branch: DoublesStateInstructions,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+) {
+    let mut used = match attempt_move(
+        doubles_state,
+        &branch,
+        SlotFocus::of(attacker),
+        attacker.side_ref,
+        &mut choice,
+        final_instructions,
+    ) {
+        Some(used) => used,
+        None => return,
+    };
+
+    let ally = attacker.ally();
+    doubles_state.apply_instructions(&used.instruction_list);
+    let ally_pokemon = doubles_state.get_active_immutable(ally);
+    let instruction = if ally_pokemon.hp == 0 {
+        None
+    } else if choice.move_id == Choices::HELPINGHAND {
+        if doubles_state
+            .volatile_statuses(ally)
+            .contains(&PokemonVolatileStatus::HELPINGHAND)
+        {
+            None
+        } else {
+            Some(Instruction::ApplyVolatileStatus(
+                ApplyVolatileStatusInstruction {
+                    side_ref: ally.side_ref,
+                    volatile_status: PokemonVolatileStatus::HELPINGHAND,
+                },
+            ))
+        }
+    } else {
+        let heal_amount = cmp::min(ally_pokemon.maxhp / 2, ally_pokemon.maxhp - ally_pokemon.hp);
+        if heal_amount > 0 {
+            Some(Instruction::Heal(HealInstruction {
+                side_ref: ally.side_ref,
+                heal_amount,
+            }))
+        } else {
+            None
+        }
+    };
+    doubles_state.reverse_instructions(&used.instruction_list);
+
+    if let Some(instruction) = instruction {
+        used.instruction_list.push(DoublesInstruction {
+            focus: SlotFocus::of(ally),
+            instruction,
+        });
+    }
+    final_instructions.push(used);
+}
+
+// A move that hits one pokemon. Redirection and fainted targets are resolved when the move is
+// used
+#[allow(clippy::too_many_arguments)]
+fn generate_instructions_from_single_target_move(
+    doubles_state: &mut DoublesState,
+    actions: &[DoublesAction],
+    order: &[usize],
+    attacker: DoublesPosition,
+    target: DoublesTarget,
+    mut choice: Choice,
+    branch: DoublesStateInstructions,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+    damage_branching: DamageBranching,
+) {
+    let chosen_slot = match target {
+        DoublesTarget::Ally => {
+            return generate_instructions_from_ally_move(
+                doubles_state,
+                attacker,
+                choice,
+                branch,
+                final_instructions,
+            );
+        }
+        DoublesTarget::Foe(slot) => slot,
+        DoublesTarget::Auto => DoublesSlot::A,
+    };
+
+    doubles_state.apply_instructions(&branch.instruction_list);
+    let target_slot = resolve_foe_target(doubles_state, attacker, chosen_slot);
+    doubles_state.reverse_instructions(&branch.instruction_list);
+
+    match target_slot {
+        Some(slot) => {
+            let defender = attacker.foe(slot);
+            choice.first_move = moves_before(actions, order, attacker, defender);
+            let defender_choice = defender_choice(actions, defender);
+            final_instructions.extend(generate_focused(
+                doubles_state,
+                &branch,
+                SlotFocus::between(attacker, slot),
+                |state, incoming_instructions, state_instructions_vec| {
+                    generate_instructions_from_move(
+                        state,
+                        &mut choice,
+                        &defender_choice,
+                        attacker.side_ref,
+                        incoming_instructions,
+                        state_instructions_vec,
+                        damage_branching,
+                    )
+                },
+            ));
+        }
+        // both foes have fainted: the move is used but has nothing to hit
+        None => {
+            if let Some(used) = attempt_move(
+                doubles_state,
+                &branch,
+                SlotFocus::of(attacker),
+                attacker.side_ref,
+                &mut choice,
+                final_instructions,
+            ) {
+                final_instructions.push(used);
+            }
+        }
+    }
+}
+
+fn generate_instructions_from_action(
+    doubles_state: &mut DoublesState,
+    actions: &[DoublesAction],
+    order: &[usize],
+    action: &DoublesAction,
+    branch: DoublesStateInstructions,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+    damage_branching: DamageBranching,
+) {
+    let attacker = action.position;
+    let target = match action.move_choice {
+        DoublesMoveChoice::Move(_, target) => target,
+        DoublesMoveChoice::Switch(_) => {
+            let mut choice = action.choice.clone();
+            if let Some(used) = attempt_move(
+                doubles_state,
+                &branch,
+                SlotFocus::of(attacker),
+                attacker.side_ref,
+                &mut choice,
+                final_instructions,
+            ) {
+                final_instructions.push(used);
+            }
+            return;
+        }
+        DoublesMoveChoice::None => {
+            final_instructions.push(branch);
+            return;
+        }
+    };
+
+    doubles_state.apply_instructions(&branch.instruction_list);
+    let fainted = !doubles_state.is_alive(attacker);
+    let helped = doubles_state
+        .volatile_statuses(attacker)
+        .contains(&PokemonVolatileStatus::HELPINGHAND);
+    doubles_state.reverse_instructions(&branch.instruction_list);
+    if fainted {
+        final_instructions.push(branch);
+        return;
+    }
+
+    let mut choice = action.choice.clone();
+    if helped {
+        choice.base_power *= 1.5;
+    }
+    match doubles_move_targeting(&choice) {
+        DoublesMoveTargeting::User => {
+            final_instructions.extend(generate_focused(
+                doubles_state,
+                &branch,
+                SlotFocus::of(attacker),
+                |state, incoming_instructions, state_instructions_vec| {
+                    generate_instructions_from_move(
+                        state,
+                        &mut choice,
+                        &Choice::default(),
+                        attacker.side_ref,
+                        incoming_instructions,
+                        state_instructions_vec,
+                        damage_branching,
+                    )
+                },
+            ));
+        }
+        DoublesMoveTargeting::Ally => generate_instructions_from_ally_move(
+            doubles_state,
+            attacker,
+            choice,
+            branch,
+            final_instructions,
+        ),
+        DoublesMoveTargeting::SingleTarget => generate_instructions_from_single_target_move(
+            doubles_state,
+            actions,
+            order,
+            attacker,
+            target,
+            choice,
+            branch,
+            final_instructions,
+            damage_branching,
+        ),
+        DoublesMoveTargeting::AllAdjacentFoes | DoublesMoveTargeting::AllAdjacent => {
+            generate_instructions_from_spread_move(
+                doubles_state,
+                actions,
+                order,
+                attacker,
+                choice,
+                branch,
+                final_instructions,
+                damage_branching,
+            )
+        }
+    }
+}
+
+// Removes the effects that only last for one turn, then runs the end of turn for both pairs of
+// active pokemon. Weather, terrain and side conditions only advance with the first pair
+fn add_end_of_turn_instructions(
+    doubles_state: &mut DoublesState,
+    mut branch: DoublesStateInstructions,
+    first_move_side: SideReference,
+    final_instructions: &mut Vec<DoublesStateInstructions>,
+) {
+    doubles_state.apply_instructions(&branch.instruction_list);
+    let mut single_turn_effects = vec![];
+    for position in POSITIONS {
+        for volatile_status in SINGLE_TURN_VOLATILE_STATUSES {
+            if doubles_state
+                .volatile_statuses(position)
+                .contains(&volatile_status)
+            {
+                single_turn_effects.push(DoublesInstruction {
+                    focus: SlotFocus::of(position),
+                    instruction: Instruction::RemoveVolatileStatus(
+                        RemoveVolatileStatusInstruction {
+                            side_ref: position.side_ref,
+                            volatile_status,
+                        },
+                    ),
+                });
+            }
+        }
+    }
+    for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+        let wide_guard = doubles_state
+            .state
+            .get_side_immutable(&side_ref)
+            .side_conditions
+            .wide_guard;
+        if wide_guard > 0 {
+            single_turn_effects.push(DoublesInstruction {
+                focus: SlotFocus::of(DoublesPosition::new(side_ref, DoublesSlot::A)),
+                instruction: Instruction::ChangeSideCondition(ChangeSideConditionInstruction {
+                    side_ref,
+                    side_condition: PokemonSideCondition::WideGuard,
+                    amount: -wide_guard,
+                }),
+            });
+        }
+    }
+    doubles_state.reverse_instructions(&branch.instruction_list);
+    branch.instruction_list.extend(single_turn_effects);
+
+    let slot_a_focus = SlotFocus {
+        side_one: DoublesSlot::A,
+        side_two: DoublesSlot::A,
+    };
+    let slot_b_focus = SlotFocus {
+        side_one: DoublesSlot::B,
+        side_two: DoublesSlot::B,
+    };
+    for branch in generate_focused(
+        doubles_state,
+        &branch,
+        slot_a_focus,
+        |state, incoming_instructions, state_instructions_vec| {
+            state_instructions_vec.extend(generate_end_of_turn_instructions(
+                state,
+                incoming_instructions,
+                &first_move_side,
+                true,
+            ))
+        },
+    ) {
+        final_instructions.extend(generate_focused(
+            doubles_state,
+            &branch,
+            slot_b_focus,
+            |state, incoming_instructions, state_instructions_vec| {
+                state_instructions_vec.extend(generate_end_of_turn_instructions(
+                    state,
+                    incoming_instructions,
+                    &first_move_side,
+                    false,
+                ))
+            },
+        ));
+    }
+}
+
+fn combine_duplicate_instructions(list_of_instructions: &mut Vec<DoublesStateInstructions>) {
+    for i in 0..list_of_instructions.len() {
+        let mut j = i + 1;
+        while j < list_of_instructions.len() {
+            if list_of_instructions[i].instruction_list == list_of_instructions[j].instruction_list
+            {
+                list_of_instructions[i].percentage += list_of_instructions[j].percentage;
+                list_of_instructions.remove(j);
+            } else {
+                j += 1;
+            }
+        }
+    }
+}
+
+/// Generates every outcome of a doubles turn where both sides make these choices.
+///
+/// Each branch covers one order the four pokemon can act in: switches go first, then moves by
+/// priority and speed, with speed ties branching on every order.
+/// Moves that switch the user out are not supported and return an error
+pub fn generate_doubles_instructions(
+    doubles_state: &mut DoublesState,
+    side_one_choice: &DoublesSideChoice,
+    side_two_choice: &DoublesSideChoice,
+    damage_branching: DamageBranching,
+) -> Result<Vec<DoublesStateInstructions>, String> {
+    let actions = get_actions(doubles_state, side_one_choice, side_two_choice)?;
+    let zobrist_hash = doubles_state.state.zobrist_hash;
+    let replacement_turn = doubles_state.is_replacement_turn();
+
+    let mut final_instructions = vec![];
+    for (order, chance) in turn_orders(doubles_state, &actions) {
+        let mut branches = vec![DoublesStateInstructions {
+            percentage: 100.0 * chance,
+            instruction_list: vec![],
+        }];
+        for index in order.iter() {
+            let mut next_branches = Vec::with_capacity(branches.len());
+            for branch in branches {
+                generate_instructions_from_action(
+                    doubles_state,
+                    &actions,
+                    &order,
+                    &actions[*index],
+                    branch,
+                    &mut next_branches,
+                    damage_branching,
+                );
+            }
+            branches = next_branches;
+        }
+
+        if replacement_turn {
+            final_instructions.extend(branches);
+            continue;
+        }
+        let first_move_side = order.first().map_or(SideReference::SideOne, |index| {
+            actions[*index].position.side_ref
+        });
+        for branch in branches {
+            add_end_of_turn_instructions(
+                doubles_state,
+                branch,
+                first_move_side,
+                &mut final_instructions,
+            );
+        }
+    }
+
+    combine_duplicate_instructions(&mut final_instructions);
+    doubles_state.state.zobrist_hash = zobrist_hash;
+    Ok(final_instructions)
+}
@@ -8,13 +8,14 @@ use crate::choices::{
     Boost, Choice, Choices, Effect, Heal, MoveCategory, MoveTarget, MultiHitMove, Secondary,
     Status, VolatileStatus,
 };
+use crate::damage_branching::DamageBranching;
 use crate::instruction::{
-    ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
-    ChangeDamageDealtMoveCategoryInstruction, ChangeSideConditionInstruction,
-    ChangeStatusInstruction, DamageInstruction, DecrementPPInstruction,
-    DecrementRestTurnsInstruction, HealInstruction, Instruction, RemoveVolatileStatusInstruction,
-    SetSleepTurnsInstruction, StateInstructions, SwitchInstruction,
-    ToggleDamageDealtHitSubstituteInstruction,
+    prune_state_instructions, ApplyVolatileStatusInstruction, BoostInstruction,
+    ChangeDamageDealtDamageInstruction, ChangeDamageDealtMoveCategoryInstruction,
+    ChangeSideConditionInstruction, ChangeStatusInstruction, DamageInstruction,
+    DecrementPPInstruction, DecrementRestTurnsInstruction, HealInstruction, Instruction,
+    PruningStats, RemoveVolatileStatusInstruction, SetSleepTurnsInstruction, StateInstructions,
+    SwitchInstruction, ToggleDamageDealtHitSubstituteInstruction,
 };
 use crate::state::{
     PokemonBoostableStat, PokemonIndex, PokemonSideCondition, PokemonStatus, PokemonType, Side,
@@ -1018,7 +1019,7 @@ pub fn generate_instructions_from_move(
     attacking_side: SideReference,
     mut incoming_instructions: StateInstructions,
     mut final_instructions: &mut Vec<StateInstructions>,
-    _branch_if_roll_kills: bool,
+    _damage_branching: DamageBranching,
 ) {
     if state.use_damage_dealt {
         reset_damage_dealt(
@@ -1165,25 +1166,14 @@ pub fn generate_instructions_from_move(
     }
 
     // start multi-hit
-    let hit_count;
-    match choice.multi_hit() {
-        MultiHitMove::None => {
-            hit_count = 1;
-        }
-        MultiHitMove::DoubleHit => {
-            hit_count = 2;
-        }
-        MultiHitMove::TripleHit => {
-            hit_count = 3;
-        }
+    let hit_counts: Vec<(i8, f32)> = match choice.multi_hit() {
+        MultiHitMove::None => vec![(1, 1.0)],
+        MultiHitMove::DoubleHit => vec![(2, 1.0)],
+        MultiHitMove::TripleHit => vec![(3, 1.0)],
         MultiHitMove::TwoToFiveHits => {
-            hit_count = 3;
-        }
-        _ => {
-            hit_count = 1;
+            vec![(2, 0.375), (3, 0.375), (4, 0.125), (5, 0.125)]
         }
-    }
-    let mut hit_sub: bool = false;
+    };
     let set_damage_dealt = defender_choice.move_id == Choices::COUNTER
         && (choice.move_type == PokemonType::NORMAL || choice.move_type == PokemonType::FIGHTING);
 
@@ -1213,132 +1203,36 @@ pub fn generate_instructions_from_move(
 
     // Non-Crit
     if incoming_instructions.percentage != 0.0 {
-        for _ in 0..hit_count {
-            if does_damage {
-                hit_sub = generate_instructions_from_damage(
-                    state,
-                    &choice,
-                    average_damage,
-                    &attacking_side,
-                    set_damage_dealt,
-                    &mut incoming_instructions,
-                );
-            }
-            if let Some(volatile_status) = &choice.volatile_status {
-                get_instructions_from_volatile_statuses(
-                    state,
-                    &choice,
-                    volatile_status,
-                    &attacking_side,
-                    &mut incoming_instructions,
-                );
-            }
-            if let Some(status) = &choice.status {
-                get_instructions_from_status_effects(
-                    state,
-                    status,
-                    choice,
-                    &attacking_side,
-                    &mut incoming_instructions,
-                    hit_sub,
-                    false,
-                );
-            }
-            if let Some(heal) = &choice.heal {
-                get_instructions_from_heal(
-                    state,
-                    heal,
-                    &attacking_side,
-                    &mut incoming_instructions,
-                );
-            }
-        }
-        if let Some(boost) = &choice.boost {
-            get_instructions_from_boosts(state, boost, &attacking_side, &mut incoming_instructions);
-        }
-
-        if let Some(secondaries_vec) = &choice.secondaries {
-            state.reverse_instructions(&incoming_instructions.instruction_list);
-            let instructions_vec_after_secondaries = get_instructions_from_secondaries(
-                state,
-                &choice,
-                secondaries_vec,
-                &attacking_side,
-                incoming_instructions,
-                hit_sub,
-            );
-            final_instructions.extend(instructions_vec_after_secondaries);
-        } else {
-            state.reverse_instructions(&incoming_instructions.instruction_list);
-            final_instructions.push(incoming_instructions);
-        }
+        run_move_for_each_hit_count(
+            state,
+            attacking_side,
+            incoming_instructions,
+            &hit_counts,
+            does_damage,
+            average_damage,
+            set_damage_dealt,
+            choice,
+            final_instructions,
+        );
     } else {
         state.reverse_instructions(&incoming_instructions.instruction_list);
     }
 
     // Crit
-    if let Some(mut crit_instructions) = crit_instructions {
+    if let Some(crit_instructions) = crit_instructions {
         if crit_instructions.percentage != 0.0 {
             state.apply_instructions(&crit_instructions.instruction_list);
-            for _ in 0..hit_count {
-                if does_damage {
-                    hit_sub = generate_instructions_from_damage(
-                        state,
-                        &choice,
-                        average_crit_damage,
-                        &attacking_side,
-                        set_damage_dealt,
-                        &mut crit_instructions,
-                    );
-                }
-                if let Some(volatile_status) = &choice.volatile_status {
-                    get_instructions_from_volatile_statuses(
-                        state,
-                        &choice,
-                        volatile_status,
-                        &attacking_side,
-                        &mut crit_instructions,
-                    );
-                }
-                if let Some(status) = &choice.status {
-                    get_instructions_from_status_effects(
-                        state,
-                        status,
-                        choice,
-                        &attacking_side,
-                        &mut crit_instructions,
-                        hit_sub,
-                        false,
-                    );
-                }
-                if let Some(heal) = &choice.heal {
-                    get_instructions_from_heal(
-                        state,
-                        heal,
-                        &attacking_side,
-                        &mut crit_instructions,
-                    );
-                }
-            }
-
-            if let Some(boost) = &choice.boost {
-                get_instructions_from_boosts(state, boost, &attacking_side, &mut crit_instructions);
-            }
-            if let Some(secondaries_vec) = &choice.secondaries {
-                state.reverse_instructions(&crit_instructions.instruction_list);
-                let instructions_vec_after_secondaries = get_instructions_from_secondaries(
-                    state,
-                    &choice,
-                    secondaries_vec,
-                    &attacking_side,
-                    crit_instructions,
-                    hit_sub,
-                );
-                final_instructions.extend(instructions_vec_after_secondaries);
-            } else {
-                state.reverse_instructions(&crit_instructions.instruction_list);
-                final_instructions.push(crit_instructions);
-            }
+            run_move_for_each_hit_count(
+                state,
+                attacking_side,
+                crit_instructions,
+                &hit_counts,
+                does_damage,
+                average_crit_damage,
+                set_damage_dealt,
+                choice,
+                final_instructions,
+            );
         }
     }
 
@@ -1346,6 +1240,120 @@ pub fn generate_instructions_from_move(
     return;
 }
 
+// Runs the move once for every number of hits it can have. `instructions` must already be
+// applied to the state. Outcomes that end up the same, e.g. because the defender fainted before
+// the last hits, are merged later by `combine_duplicate_instructions`
+fn run_move_for_each_hit_count(
+    state: &mut State,
+    attacking_side: SideReference,
+    instructions: StateInstructions,
+    hit_counts: &[(i8, f32)],
+    does_damage: bool,
+    damage_amount: i16,
+    set_damage_dealt: bool,
+    choice: &Choice,
+    final_instructions: &mut Vec<StateInstructions>,
+) {
+    for (index, (hit_count, chance)) in hit_counts.iter().enumerate() {
+        // run_move reverses the instructions it was given, so every other number of hits
+        // has to apply them again
+        if index > 0 {
+            state.apply_instructions(&instructions.instruction_list);
+        }
+        let mut hit_count_instructions = instructions.clone();
+        hit_count_instructions.update_percentage(*chance);
+        run_move(
+            state,
+            attacking_side,
+            hit_count_instructions,
+            *hit_count,
+            does_damage,
+            damage_amount,
+            set_damage_dealt,
+            choice,
+            final_instructions,
+        );
+    }
+}
+
+fn run_move(
+    state: &mut State,
+    attacking_side: SideReference,
+    mut instructions: StateInstructions,
+    hit_count: i8,
+    does_damage: bool,
+    damage_amount: i16,
+    set_damage_dealt: bool,
+    choice: &Choice,
+    final_instructions: &mut Vec<StateInstructions>,
+) {
+    let mut hit_sub = false;
+    for hit in 0..hit_count {
+        // a multi-hit move stops hitting once the defender has fainted
+        if hit > 0
+            && state
+                .get_side_immutable(&attacking_side.get_other_side())
+                .get_active_immutable()
+                .hp
+                <= 0
+        {
+            break;
+        }
+        if does_damage {
+            hit_sub = generate_instructions_from_damage(
+                state,
+                &choice,
+                damage_amount,
+                &attacking_side,
+                set_damage_dealt,
+                &mut instructions,
+            );
+        }
+        if let Some(volatile_status) = &choice.volatile_status {
+            get_instructions_from_volatile_statuses(
+                state,
+                &choice,
+                volatile_status,
+                &attacking_side,
+                &mut instructions,
+            );
+        }
+        if let Some(status) = &choice.status {
+            get_instructions_from_status_effects(
+                state,
+                status,
+                choice,
+                &attacking_side,
+                &mut instructions,
+                hit_sub,
+                false,
+            );
+        }
+        if let Some(heal) = &choice.heal {
+            get_instructions_from_heal(state, heal, &attacking_side, &mut instructions);
+        }
+    }
+    if let Some(boost) = &choice.boost {
+        get_instructions_from_boosts(state, boost, &attacking_side, &mut instructions);
+    }
+
+    if let Some(secondaries_vec) = &choice.secondaries {
+        state.reverse_instructions(&instructions.instruction_list);
+        let instructions_vec_after_secondaries = get_instructions_from_secondaries(
+            state,
+            &choice,
+            secondaries_vec,
+            &attacking_side,
+            instructions,
+            hit_sub,
+        );
+        final_instructions.extend(instructions_vec_after_secondaries);
+    } else {
+        state.reverse_instructions(&instructions.instruction_list);
+        final_instructions.push(instructions);
+    }
+}
+
 fn combine_duplicate_instructions(list_of_instructions: &mut Vec<StateInstructions>) {
     for i in 0..list_of_instructions.len() {
         let mut j = i + 1;
@@ -1580,7 +1588,7 @@ fn handle_both_moves(
     first_move_side_ref: SideReference,
     incoming_instructions: StateInstructions,
     state_instructions_vec: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     generate_instructions_from_move(
         state,
@@ -1589,7 +1597,7 @@ fn handle_both_moves(
         first_move_side_ref,
         incoming_instructions,
         state_instructions_vec,
-        branch_on_damage,
+        damage_branching,
     );
 
     let mut i = 0;
@@ -1604,7 +1612,7 @@ fn handle_both_moves(
             first_move_side_ref.get_other_side(),
             state_instruction,
             state_instructions_vec,
-            branch_on_damage,
+            damage_branching,
         );
         i += 1;
     }
@@ -1614,8 +1622,35 @@ pub fn generate_instructions_from_move_pair(
     state: &mut State,
     side_one_move: &MoveChoice,
     side_two_move: &MoveChoice,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
 ) -> Vec<StateInstructions> {
+    generate_instructions_from_move_pair_with_pruning_stats(
+        state,
+        side_one_move,
+        side_two_move,
+        damage_branching,
+        prune_threshold,
+        &mut PruningStats::default(),
+    )
+}
+
+/// Like `generate_instructions_from_move_pair`, and records the percentage pruned by
+/// `prune_threshold` in `pruning_stats`
+pub fn generate_instructions_from_move_pair_with_pruning_stats(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
+) -> Vec<StateInstructions> {
+    // instruction generation modifies the state directly in many places and relies on
+    // reversing the generated instructions to undo those modifications, which the
+    // incrementally maintained zobrist hash cannot follow.
+    // `state.zobrist_hash` is therefore only valid between calls, and is restored before returning
+    let zobrist_hash = state.zobrist_hash;
+
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -1660,7 +1695,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -1676,7 +1711,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -1697,7 +1732,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 side_one_moves_first_instruction,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -1714,7 +1749,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut side_two_moves_first_si,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in side_two_moves_first_si.iter_mut() {
@@ -1726,6 +1761,11 @@ pub fn generate_instructions_from_move_pair(
             state_instructions_vec.extend(side_two_moves_first_si);
         }
     }
+    state.zobrist_hash = zobrist_hash;
+    pruning_stats.record(prune_state_instructions(
+        &mut state_instructions_vec,
+        prune_threshold,
+    ));
     state_instructions_vec
 }
 
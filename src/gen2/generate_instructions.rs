@@ -3,15 +3,16 @@ use super::choice_effects::{
     choice_special_effect, modify_choice,
 };
 use crate::choices::{
-    Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, Status,
-    VolatileStatus, MOVES,
+    Boost, Choices, Effect, Heal, MoveTarget, MultiAccuracyMove, MultiHitMove, Secondary,
+    SideCondition, Status, VolatileStatus, MOVES,
 };
+use crate::damage_branching::{damage_roll_branches, DamageBranching};
 use crate::instruction::DecrementFutureSightInstruction;
 use crate::instruction::{
-    ApplyVolatileStatusInstruction, BoostInstruction, ChangeItemInstruction,
-    ChangeSideConditionInstruction, ChangeWeather, DecrementRestTurnsInstruction, HealInstruction,
-    RemoveVolatileStatusInstruction, SetSecondMoveSwitchOutMoveInstruction,
-    SetSleepTurnsInstruction, ToggleBatonPassingInstruction,
+    prune_state_instructions, ApplyVolatileStatusInstruction, BoostInstruction,
+    ChangeItemInstruction, ChangeSideConditionInstruction, ChangeWeather,
+    DecrementRestTurnsInstruction, HealInstruction, PruningStats, RemoveVolatileStatusInstruction,
+    SetSecondMoveSwitchOutMoveInstruction, SetSleepTurnsInstruction, ToggleBatonPassingInstruction,
 };
 use crate::instruction::{
     ChangeDamageDealtDamageInstruction, ChangeDamageDealtMoveCategoryInstruction,
@@ -493,28 +494,6 @@ fn get_instructions_from_boosts(
     }
 }
 
-fn compare_health_with_damage_multiples(max_damage: i16, health: i16) -> (i16, i16) {
-    let max_damage_f32 = max_damage as f32;
-    let health_f32 = health as f32;
-
-    let mut total_less_than = 0;
-    let mut num_less_than = 0;
-    let mut num_greater_than = 0;
-    let increment = max_damage as f32 * 0.01;
-    let mut damage = max_damage_f32 * 0.85;
-    for _ in 0..16 {
-        if damage < health_f32 {
-            total_less_than += damage as i16;
-            num_less_than += 1;
-        } else if damage > health_f32 {
-            num_greater_than += 1;
-        }
-        damage += increment;
-    }
-
-    (total_less_than / num_less_than, num_greater_than)
-}
-
 fn get_instructions_from_secondaries(
     state: &mut State,
     attacker_choice: &Choice,
@@ -1302,7 +1281,7 @@ pub fn generate_instructions_from_move(
     attacking_side: SideReference,
     mut incoming_instructions: StateInstructions,
     mut final_instructions: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     if state.use_damage_dealt {
         reset_damage_dealt(
@@ -1479,7 +1458,7 @@ pub fn generate_instructions_from_move(
                 attacking_side,
                 sleep_talk_instructions,
                 &mut final_instructions,
-                false,
+                DamageBranching::None,
             );
         }
         return;
@@ -1519,105 +1498,104 @@ pub fn generate_instructions_from_move(
     }
 
     // start multi-hit
-    let hit_count;
-    match choice.multi_hit() {
-        MultiHitMove::None => {
-            hit_count = 1;
-        }
-        MultiHitMove::DoubleHit => {
-            hit_count = 2;
-        }
-        MultiHitMove::TripleHit => {
-            hit_count = 3;
-        }
-        MultiHitMove::TwoToFiveHits => {
-            hit_count = 3;
-        }
-        _ => {
-            // population bomb checks accuracy each time but lets approximate
-            hit_count = 1;
+    let multi_accuracy = choice.multi_accuracy();
+    let hit_counts: Vec<(i8, f32)> = match multi_accuracy {
+        MultiAccuracyMove::None => match choice.multi_hit() {
+            MultiHitMove::None => vec![(1, 1.0)],
+            MultiHitMove::DoubleHit => vec![(2, 1.0)],
+            MultiHitMove::TripleHit => vec![(3, 1.0)],
+            MultiHitMove::TwoToFiveHits => {
+                vec![(2, 0.375), (3, 0.375), (4, 0.125), (5, 0.125)]
+            }
+        },
+        _ => multi_accuracy_hit_counts(
+            multi_accuracy.max_hits(),
+            (choice.accuracy / 100.0).min(1.0),
+        ),
+    };
+
+    // triple kick gains its base power again with every hit
+    let max_damage_per_hit: Vec<(i16, i16)> = if multi_accuracy == MultiAccuracyMove::TripleHit {
+        (1..=multi_accuracy.max_hits())
+            .map(|hit| {
+                let mut hit_choice = choice.clone();
+                hit_choice.base_power *= hit as f32;
+                calculate_damage(state, &attacking_side, &hit_choice, DamageRolls::Max)
+                    .unwrap_or((0, 0))
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+    let defender_active = state
+        .get_side_immutable(&attacking_side.get_other_side())
+        .get_active_immutable();
+    let defender_hp = defender_active.hp;
+    let crit_rate = if choice.move_id.increased_crit_ratio() {
+        1.0 / 8.0
+    } else {
+        BASE_CRIT_CHANCE
+    };
+    let damage_branches = match damage {
+        Some((max_damage_dealt, max_crit_damage)) => damage_roll_branches(
+            damage_branching,
+            max_damage_dealt,
+            max_crit_damage,
+            defender_hp,
+            crit_rate,
+        ),
+        None => vec![(0, 1.0)],
+    };
+
+    // every hit is rolled like the first, unless it has a different number of damage branches.
+    // Then it is scaled from the damage of the first hit
+    let hit_damage_branches: Vec<Vec<(i16, f32)>> = max_damage_per_hit
+        .iter()
+        .map(|(max_damage, max_crit_damage)| {
+            damage_roll_branches(
+                damage_branching,
+                *max_damage,
+                *max_crit_damage,
+                defender_hp,
+                crit_rate,
+            )
+        })
+        .collect();
+    let damage_per_hit = |index: usize, first_hit_damage: i16| -> Vec<i16> {
+        match max_damage_per_hit.first() {
+            Some((first_hit_max_damage, _)) if *first_hit_max_damage > 0 => max_damage_per_hit
+                .iter()
+                .zip(hit_damage_branches.iter())
+                .map(|((max_damage, _), branches)| {
+                    if branches.len() == damage_branches.len() {
+                        branches[index].0
+                    } else {
+                        (first_hit_damage as f32 * *max_damage as f32
+                            / *first_hit_max_damage as f32) as i16
+                    }
+                })
+                .collect(),
+            _ => vec![first_hit_damage],
         }
-    }
+    };
 
-    let (_attacker_side, defender_side) = state.get_both_sides(&attacking_side);
-    let defender_active = defender_side.get_active();
-    let mut does_damage = false;
-    let (mut branch_damage, mut regular_damage) = (0, 0);
-    let mut branch_instructions: Option<StateInstructions> = None;
-    if let Some((max_damage_dealt, max_crit_damage)) = damage {
-        does_damage = true;
-        let min_damage_dealt = (max_damage_dealt as f32 * 0.85) as i16;
-        if branch_on_damage
-            && max_damage_dealt >= defender_active.hp
-            && min_damage_dealt < defender_active.hp
-        {
-            let (average_non_kill_damage, num_kill_rolls) =
-                compare_health_with_damage_multiples(max_damage_dealt, defender_active.hp);
-
-            // the chance of a kill is the chance of the roll killing + the chance of a crit
-            let crit_rate = if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-            let branch_chance = ((1.0 - crit_rate) * (num_kill_rolls as f32 / 16.0)) + crit_rate;
-
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(branch_chance);
-            branch_instructions = Some(branch_ins);
-            branch_damage = defender_active.hp;
-
-            incoming_instructions.update_percentage(1.0 - branch_chance);
-            regular_damage = average_non_kill_damage;
-        } else if branch_on_damage && max_damage_dealt < defender_active.hp {
-            let crit_rate = if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(crit_rate);
-            branch_instructions = Some(branch_ins);
-            branch_damage = (max_crit_damage as f32 * 0.925) as i16;
-            incoming_instructions.update_percentage(1.0 - crit_rate);
-            regular_damage = (max_damage_dealt as f32 * 0.925) as i16;
-        } else {
-            regular_damage = (max_damage_dealt as f32 * 0.925) as i16;
+    for (index, (damage_dealt, chance)) in damage_branches.iter().enumerate() {
+        let mut branch_instructions = incoming_instructions.clone();
+        if index > 0 {
+            state.apply_instructions(&branch_instructions.instruction_list);
         }
-    }
-
-    if incoming_instructions.percentage != 0.0 {
-        run_move(
+        branch_instructions.update_percentage(*chance);
+        run_move_for_each_hit_count(
             state,
             attacking_side,
-            incoming_instructions,
-            hit_count,
-            does_damage,
-            regular_damage,
+            branch_instructions,
+            &hit_counts,
+            damage.is_some(),
+            &damage_per_hit(index, *damage_dealt),
             choice,
             defender_choice,
             &mut final_instructions,
         );
-    } else {
-        state.reverse_instructions(&incoming_instructions.instruction_list);
-    }
-
-    // A branch representing either a roll that kills the opponent or a crit
-    if let Some(branch_ins) = branch_instructions {
-        if branch_ins.percentage != 0.0 {
-            state.apply_instructions(&branch_ins.instruction_list);
-            run_move(
-                state,
-                attacking_side,
-                branch_ins,
-                hit_count,
-                does_damage,
-                branch_damage,
-                choice,
-                defender_choice,
-                &mut final_instructions,
-            );
-        }
     }
 
     combine_duplicate_instructions(&mut final_instructions);
@@ -2090,20 +2068,84 @@ fn end_of_turn_triggered(side_one_move: &MoveChoice, side_two_move: &MoveChoice)
         && !(side_one_move == &MoveChoice::None && matches!(side_two_move, &MoveChoice::Switch(_)))
 }
 
+// The chance of each number of hits of a move that checks `accuracy` before every hit after
+// the first and stops at the first miss
+fn multi_accuracy_hit_counts(max_hits: i8, accuracy: f32) -> Vec<(i8, f32)> {
+    (1..=max_hits)
+        .map(|hits| {
+            let chance = if hits == max_hits {
+                accuracy.powi(hits as i32 - 1)
+            } else {
+                accuracy.powi(hits as i32 - 1) * (1.0 - accuracy)
+            };
+            (hits, chance)
+        })
+        .filter(|(_, chance)| *chance > 0.0)
+        .collect()
+}
+
+// Runs the move once for every number of hits it can have. `instructions` must already be
+// applied to the state. Outcomes that end up the same, e.g. because the defender fainted before
+// the last hits, are merged later by `combine_duplicate_instructions`
+fn run_move_for_each_hit_count(
+    state: &mut State,
+    attacking_side: SideReference,
+    instructions: StateInstructions,
+    hit_counts: &[(i8, f32)],
+    does_damage: bool,
+    damage_per_hit: &[i16],
+    choice: &Choice,
+    defender_choice: &Choice,
+    final_instructions: &mut Vec<StateInstructions>,
+) {
+    for (index, (hit_count, chance)) in hit_counts.iter().enumerate() {
+        // run_move reverses the instructions it was given, so every other number of hits
+        // has to apply them again
+        if index > 0 {
+            state.apply_instructions(&instructions.instruction_list);
+        }
+        let mut hit_count_instructions = instructions.clone();
+        hit_count_instructions.update_percentage(*chance);
+        run_move(
+            state,
+            attacking_side,
+            hit_count_instructions,
+            *hit_count,
+            does_damage,
+            damage_per_hit,
+            choice,
+            defender_choice,
+            final_instructions,
+        );
+    }
+}
+
 fn run_move(
     state: &mut State,
     attacking_side: SideReference,
     mut instructions: StateInstructions,
     hit_count: i8,
     does_damage: bool,
-    damage_amount: i16,
+    damage_per_hit: &[i16],
     choice: &Choice,
     defender_choice: &Choice,
     final_instructions: &mut Vec<StateInstructions>,
 ) {
     let mut hit_sub = false;
-    for _ in 0..hit_count {
+    for hit in 0..hit_count {
+        // a multi-hit move stops hitting once the defender has fainted
+        if hit > 0
+            && state
+                .get_side_immutable(&attacking_side.get_other_side())
+                .get_active_immutable()
+                .hp
+                <= 0
+        {
+            break;
+        }
         if does_damage {
+            // moves that do the same damage every hit only have the damage of the first hit
+            let damage_amount = damage_per_hit[(hit as usize).min(damage_per_hit.len() - 1)];
             hit_sub = generate_instructions_from_damage(
                 state,
                 &choice,
@@ -2277,7 +2319,7 @@ fn handle_both_moves(
     first_move_side_ref: SideReference,
     incoming_instructions: StateInstructions,
     state_instructions_vec: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     generate_instructions_from_move(
         state,
@@ -2286,7 +2328,7 @@ fn handle_both_moves(
         first_move_side_ref,
         incoming_instructions,
         state_instructions_vec,
-        branch_on_damage,
+        damage_branching,
     );
 
     let mut i = 0;
@@ -2301,7 +2343,7 @@ fn handle_both_moves(
             first_move_side_ref.get_other_side(),
             state_instruction,
             state_instructions_vec,
-            branch_on_damage,
+            damage_branching,
         );
         i += 1;
     }
@@ -2311,8 +2353,35 @@ pub fn generate_instructions_from_move_pair(
     state: &mut State,
     side_one_move: &MoveChoice,
     side_two_move: &MoveChoice,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
 ) -> Vec<StateInstructions> {
+    generate_instructions_from_move_pair_with_pruning_stats(
+        state,
+        side_one_move,
+        side_two_move,
+        damage_branching,
+        prune_threshold,
+        &mut PruningStats::default(),
+    )
+}
+
+/// Like `generate_instructions_from_move_pair`, and records the percentage pruned by
+/// `prune_threshold` in `pruning_stats`
+pub fn generate_instructions_from_move_pair_with_pruning_stats(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
+) -> Vec<StateInstructions> {
+    // instruction generation modifies the state directly in many places and relies on
+    // reversing the generated instructions to undo those modifications, which the
+    // incrementally maintained zobrist hash cannot follow.
+    // `state.zobrist_hash` is therefore only valid between calls, and is restored before returning
+    let zobrist_hash = state.zobrist_hash;
+
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -2357,7 +2426,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -2373,7 +2442,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -2394,7 +2463,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 side_one_moves_first_instruction,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
@@ -2411,7 +2480,7 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut side_two_moves_first_si,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in side_two_moves_first_si.iter_mut() {
@@ -2423,6 +2492,11 @@ pub fn generate_instructions_from_move_pair(
             state_instructions_vec.extend(side_two_moves_first_si);
         }
     }
+    state.zobrist_hash = zobrist_hash;
+    pruning_stats.record(prune_state_instructions(
+        &mut state_instructions_vec,
+        prune_threshold,
+    ));
     state_instructions_vec
 }
 
@@ -7,16 +7,16 @@ use super::choice_effects::{
     choice_special_effect, modify_choice,
 };
 use crate::choices::{
-    Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, Status,
-    VolatileStatus, MOVES,
+    Boost, Choices, Effect, Heal, MoveTarget, MultiAccuracyMove, MultiHitMove, Secondary,
+    SideCondition, Status, VolatileStatus, MOVES,
 };
 use crate::instruction::DecrementFutureSightInstruction;
 use crate::instruction::{
-    ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
-    ChangeDamageDealtMoveCategoryInstruction, ChangeItemInstruction,
-    ChangeSideConditionInstruction, ChangeVolatileStatusDurationInstruction, ChangeWeather,
-    DecrementRestTurnsInstruction, DecrementWishInstruction, HealInstruction,
-    RemoveVolatileStatusInstruction, SetSecondMoveSwitchOutMoveInstruction,
+    prune_state_instructions, ApplyVolatileStatusInstruction, BoostInstruction,
+    ChangeDamageDealtDamageInstruction, ChangeDamageDealtMoveCategoryInstruction,
+    ChangeItemInstruction, ChangeSideConditionInstruction, ChangeVolatileStatusDurationInstruction,
+    ChangeWeather, DecrementRestTurnsInstruction, DecrementWishInstruction, HealInstruction,
+    PruningStats, RemoveVolatileStatusInstruction, SetSecondMoveSwitchOutMoveInstruction,
     SetSleepTurnsInstruction, ToggleBatonPassingInstruction,
     ToggleDamageDealtHitSubstituteInstruction,
 };
@@ -30,6 +30,7 @@ use super::items::{
 };
 use super::state::{MoveChoice, PokemonVolatileStatus, Weather};
 use crate::choices::{Choice, MoveCategory};
+use crate::damage_branching::{damage_roll_branches, DamageBranching};
 use crate::instruction::{
     ChangeStatusInstruction, DamageInstruction, Instruction, StateInstructions, SwitchInstruction,
 };
@@ -574,28 +575,6 @@ fn get_instructions_from_boosts(
     }
 }
 
-fn compare_health_with_damage_multiples(max_damage: i16, health: i16) -> (i16, i16) {
-    let max_damage_f32 = max_damage as f32;
-    let health_f32 = health as f32;
-
-    let mut total_less_than = 0;
-    let mut num_less_than = 0;
-    let mut num_greater_than = 0;
-    let increment = max_damage as f32 * 0.01;
-    let mut damage = max_damage_f32 * 0.85;
-    for _ in 0..16 {
-        if damage < health_f32 {
-            total_less_than += damage as i16;
-            num_less_than += 1;
-        } else if damage > health_f32 {
-            num_greater_than += 1;
-        }
-        damage += increment;
-    }
-
-    (total_less_than / num_less_than, num_greater_than)
-}
-
 fn get_instructions_from_secondaries(
     state: &mut State,
     attacker_choice: &Choice,
@@ -1442,7 +1421,7 @@ pub fn generate_instructions_from_move(
     attacking_side: SideReference,
     mut incoming_instructions: StateInstructions,
     mut final_instructions: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     if state.use_damage_dealt {
         reset_damage_dealt(
@@ -1642,7 +1621,7 @@ pub fn generate_instructions_from_move(
                 attacking_side,
                 sleep_talk_instructions,
                 &mut final_instructions,
-                false,
+                DamageBranching::None,
             );
         }
         return;
@@ -1676,129 +1655,110 @@ pub fn generate_instructions_from_move(
     }
 
     // start multi-hit
-    let hit_count;
-    match choice.multi_hit() {
-        MultiHitMove::None => {
-            hit_count = 1;
-        }
-        MultiHitMove::DoubleHit => {
-            hit_count = 2;
-        }
-        MultiHitMove::TripleHit => {
-            hit_count = 3;
-        }
-        MultiHitMove::TwoToFiveHits => {
-            // too lazy to implement branching here. Average is 3.2 so this is a fine approximation
-            hit_count = 3;
-        }
-        MultiHitMove::PopulationBomb => {
-            // population bomb checks accuracy each time but lets approximate
-            hit_count = if state.get_side(&attacking_side).get_active().item == Items::WIDELENS {
-                9
-            } else {
-                6
-            };
-        }
-        MultiHitMove::TripleAxel => {
-            // triple axel checks accuracy each time but until multi-accuracy is implemented this
-            // is the best we can do
-            hit_count = 3
-        }
-    }
-
-    let (_attacker_side, defender_side) = state.get_both_sides(&attacking_side);
-    let defender_active = defender_side.get_active();
-    let mut does_damage = false;
-    let (mut branch_damage, mut regular_damage) = (0, 0);
-    let mut branch_instructions: Option<StateInstructions> = None;
-    if let Some((max_damage_dealt, max_crit_damage)) = damage {
-        does_damage = true;
-        let avg_damage_dealt = (max_damage_dealt as f32 * 0.925) as i16;
-        let min_damage_dealt = (max_damage_dealt as f32 * 0.85) as i16;
-        if branch_on_damage
-            && max_damage_dealt >= defender_active.hp
-            && min_damage_dealt < defender_active.hp
-        {
-            let (average_non_kill_damage, num_kill_rolls) =
-                compare_health_with_damage_multiples(max_damage_dealt, defender_active.hp);
-
-            let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
-                || defender_active.ability == Abilities::SHELLARMOR
-            {
-                0.0
-            } else if choice.move_id.guaranteed_crit() {
-                1.0
-            } else if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-
-            // the chance of a branch is the chance of the roll killing + the chance of a crit
-            let branch_chance = ((1.0 - crit_rate) * (num_kill_rolls as f32 / 16.0)) + crit_rate;
+    let multi_accuracy = choice.multi_accuracy();
+    let hit_counts: Vec<(i8, f32)> = match multi_accuracy {
+        MultiAccuracyMove::None => match choice.multi_hit() {
+            MultiHitMove::None => vec![(1, 1.0)],
+            MultiHitMove::DoubleHit => vec![(2, 1.0)],
+            MultiHitMove::TripleHit => vec![(3, 1.0)],
+            MultiHitMove::TwoToFiveHits => {
+                vec![(2, 0.375), (3, 0.375), (4, 0.125), (5, 0.125)]
+            }
+        },
+        _ => multi_accuracy_hit_counts(
+            multi_accuracy.max_hits(),
+            (choice.accuracy / 100.0).min(1.0),
+        ),
+    };
 
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(branch_chance);
-            branch_instructions = Some(branch_ins);
-            branch_damage = defender_active.hp;
+    // triple kick gains its base power again with every hit
+    let max_damage_per_hit: Vec<(i16, i16)> = if multi_accuracy == MultiAccuracyMove::TripleHit {
+        (1..=multi_accuracy.max_hits())
+            .map(|hit| {
+                let mut hit_choice = choice.clone();
+                hit_choice.base_power *= hit as f32;
+                calculate_damage(state, &attacking_side, &hit_choice, DamageRolls::Max)
+                    .unwrap_or((0, 0))
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+    let defender_active = state
+        .get_side_immutable(&attacking_side.get_other_side())
+        .get_active_immutable();
+    let defender_hp = defender_active.hp;
+    let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
+        || defender_active.ability == Abilities::SHELLARMOR
+    {
+        0.0
+    } else if choice.move_id.guaranteed_crit() {
+        1.0
+    } else if choice.move_id.increased_crit_ratio() {
+        1.0 / 8.0
+    } else {
+        BASE_CRIT_CHANCE
+    };
+    let damage_branches = match damage {
+        Some((max_damage_dealt, max_crit_damage)) => damage_roll_branches(
+            damage_branching,
+            max_damage_dealt,
+            max_crit_damage,
+            defender_hp,
+            crit_rate,
+        ),
+        None => vec![(0, 1.0)],
+    };
 
-            incoming_instructions.update_percentage(1.0 - branch_chance);
-            regular_damage = average_non_kill_damage;
-        } else if branch_on_damage && max_damage_dealt < defender_active.hp {
-            let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
-                || defender_active.ability == Abilities::SHELLARMOR
-            {
-                0.0
-            } else if choice.move_id.guaranteed_crit() {
-                1.0
-            } else if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(crit_rate);
-            branch_instructions = Some(branch_ins);
-            branch_damage = (max_crit_damage as f32 * 0.925) as i16;
-            incoming_instructions.update_percentage(1.0 - crit_rate);
-            regular_damage = (max_damage_dealt as f32 * 0.925) as i16;
-        } else {
-            regular_damage = avg_damage_dealt;
+    // every hit is rolled like the first, unless it has a different number of damage branches.
+    // Then it is scaled from the damage of the first hit
+    let hit_damage_branches: Vec<Vec<(i16, f32)>> = max_damage_per_hit
+        .iter()
+        .map(|(max_damage, max_crit_damage)| {
+            damage_roll_branches(
+                damage_branching,
+                *max_damage,
+                *max_crit_damage,
+                defender_hp,
+                crit_rate,
+            )
+        })
+        .collect();
+    let damage_per_hit = |index: usize, first_hit_damage: i16| -> Vec<i16> {
+        match max_damage_per_hit.first() {
+            Some((first_hit_max_damage, _)) if *first_hit_max_damage > 0 => max_damage_per_hit
+                .iter()
+                .zip(hit_damage_branches.iter())
+                .map(|((max_damage, _), branches)| {
+                    if branches.len() == damage_branches.len() {
+                        branches[index].0
+                    } else {
+                        (first_hit_damage as f32 * *max_damage as f32
+                            / *first_hit_max_damage as f32) as i16
+                    }
+                })
+                .collect(),
+            _ => vec![first_hit_damage],
         }
-    }
+    };
 
-    if incoming_instructions.percentage != 0.0 {
-        run_move(
+    for (index, (damage_dealt, chance)) in damage_branches.iter().enumerate() {
+        let mut branch_instructions = incoming_instructions.clone();
+        if index > 0 {
+            state.apply_instructions(&branch_instructions.instruction_list);
+        }
+        branch_instructions.update_percentage(*chance);
+        run_move_for_each_hit_count(
             state,
             attacking_side,
-            incoming_instructions,
-            hit_count,
-            does_damage,
-            regular_damage,
+            branch_instructions,
+            &hit_counts,
+            damage.is_some(),
+            &damage_per_hit(index, *damage_dealt),
             choice,
             defender_choice,
             &mut final_instructions,
         );
-    } else {
-        state.reverse_instructions(&incoming_instructions.instruction_list);
-    }
-
-    // A branch representing either a roll that kills the opponent or a crit
-    if let Some(branch_ins) = branch_instructions {
-        if branch_ins.percentage != 0.0 {
-            state.apply_instructions(&branch_ins.instruction_list);
-            run_move(
-                state,
-                attacking_side,
-                branch_ins,
-                hit_count,
-                does_damage,
-                branch_damage,
-                choice,
-                defender_choice,
-                &mut final_instructions,
-            );
-        }
     }
 
     combine_duplicate_instructions(&mut final_instructions);
@@ -1896,6 +1856,154 @@ fn moves_first(
     }
 }
 
+fn end_confusion(side: &mut Side, side_ref: &SideReference, instructions: &mut StateInstructions) {
+    instructions
+        .instruction_list
+        .push(Instruction::ChangeVolatileStatusDuration(
+            ChangeVolatileStatusDurationInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+                amount: -1 * side.volatile_status_durations.confusion,
+            },
+        ));
+    side.volatile_status_durations.confusion = 0;
+    instructions
+        .instruction_list
+        .push(Instruction::RemoveVolatileStatus(
+            RemoveVolatileStatusInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+            },
+        ));
+    side.volatile_statuses
+        .remove(&PokemonVolatileStatus::CONFUSION);
+}
+
+// A locking move ending confuses the pokemon from fatigue. The turn it ends on counts as the
+// first turn of the confusion
+fn end_locked_move(
+    side: &mut Side,
+    side_ref: &SideReference,
+    instructions: &mut StateInstructions,
+) {
+    instructions
+        .instruction_list
+        .push(Instruction::ChangeVolatileStatusDuration(
+            ChangeVolatileStatusDurationInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                amount: -1 * side.volatile_status_durations.lockedmove,
+            },
+        ));
+    side.volatile_status_durations.lockedmove = 0;
+    instructions
+        .instruction_list
+        .push(Instruction::RemoveVolatileStatus(
+            RemoveVolatileStatusInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+            },
+        ));
+    side.volatile_statuses
+        .remove(&PokemonVolatileStatus::LOCKEDMOVE);
+    if !side
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::CONFUSION)
+    {
+        instructions
+            .instruction_list
+            .push(Instruction::ApplyVolatileStatus(
+                ApplyVolatileStatusInstruction {
+                    side_ref: *side_ref,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                },
+            ));
+        side.volatile_statuses
+            .insert(PokemonVolatileStatus::CONFUSION);
+        instructions
+            .instruction_list
+            .push(Instruction::ChangeVolatileStatusDuration(
+                ChangeVolatileStatusDurationInstruction {
+                    side_ref: *side_ref,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                    amount: 1,
+                },
+            ));
+        side.volatile_status_durations.confusion += 1;
+    }
+}
+
+// The chance that a volatile status with a random duration ends this turn, given that it has
+// not ended yet. Locking moves last 2 or 3 turns and confusion lasts 2 to 5 turns
+fn chance_to_end_early(side: &Side, volatile_status: PokemonVolatileStatus) -> f32 {
+    if !side.volatile_statuses.contains(&volatile_status) {
+        return 0.0;
+    }
+    match volatile_status {
+        PokemonVolatileStatus::LOCKEDMOVE => match side.volatile_status_durations.lockedmove {
+            2 => 0.5,
+            _ => 0.0,
+        },
+        PokemonVolatileStatus::CONFUSION => match side.volatile_status_durations.confusion {
+            2 => 1.0 / 4.0,
+            3 => 1.0 / 3.0,
+            4 => 1.0 / 2.0,
+            _ => 0.0,
+        },
+        _ => 0.0,
+    }
+}
+
+// `add_end_of_turn_instructions` lets locking moves and confusion last as long as they can.
+// This splits every outcome where one of them could have ended earlier into a branch where it
+// ends and a branch where it continues
+fn add_random_duration_branches(
+    state: &mut State,
+    state_instructions_vec: &mut Vec<StateInstructions>,
+    first_move_side: &SideReference,
+) {
+    for side_ref in [*first_move_side, first_move_side.get_other_side()] {
+        for volatile_status in [
+            PokemonVolatileStatus::LOCKEDMOVE,
+            PokemonVolatileStatus::CONFUSION,
+        ] {
+            let mut ended_early = vec![];
+            for state_instructions in state_instructions_vec.iter_mut() {
+                state.apply_instructions(&state_instructions.instruction_list);
+                let chance = if state.side_one.force_switch
+                    || state.side_two.force_switch
+                    || state
+                        .get_side_immutable(&side_ref)
+                        .get_active_immutable()
+                        .hp
+                        <= 0
+                {
+                    0.0
+                } else {
+                    chance_to_end_early(state.get_side_immutable(&side_ref), volatile_status)
+                };
+                if chance > 0.0 {
+                    let mut ended_instructions = state_instructions.clone();
+                    let side = state.get_side(&side_ref);
+                    match volatile_status {
+                        PokemonVolatileStatus::LOCKEDMOVE => {
+                            end_locked_move(side, &side_ref, &mut ended_instructions)
+                        }
+                        _ => end_confusion(side, &side_ref, &mut ended_instructions),
+                    }
+                    ended_instructions.update_percentage(chance);
+                    state_instructions.update_percentage(1.0 - chance);
+                    state.reverse_instructions(&ended_instructions.instruction_list);
+                    ended_early.push(ended_instructions);
+                } else {
+                    state.reverse_instructions(&state_instructions.instruction_list);
+                }
+            }
+            state_instructions_vec.extend(ended_early);
+        }
+    }
+}
+
 fn add_end_of_turn_instructions(
     state: &mut State,
     mut incoming_instructions: &mut StateInstructions,
@@ -2224,47 +2332,35 @@ fn add_end_of_turn_instructions(
 
         if side
             .volatile_statuses
-            .contains(&PokemonVolatileStatus::LOCKEDMOVE)
+            .contains(&PokemonVolatileStatus::CONFUSION)
         {
-            // the number says 2 but this is 3 turns of using a locking move
-            // because turn 0 is the first turn the move is used
-            // branching is not implemented here so the engine assumes it always lasts 3 turns
-            if side.volatile_status_durations.lockedmove == 2 {
-                side.volatile_status_durations.lockedmove = 0;
-                side.volatile_statuses
-                    .remove(&PokemonVolatileStatus::LOCKEDMOVE);
+            // confusion lasts at most 5 turns, ending earlier is branched on
+            // in `add_random_duration_branches`
+            if side.volatile_status_durations.confusion == 4 {
+                end_confusion(side, side_ref, incoming_instructions);
+            } else {
+                side.volatile_status_durations.confusion += 1;
                 incoming_instructions.instruction_list.push(
                     Instruction::ChangeVolatileStatusDuration(
                         ChangeVolatileStatusDurationInstruction {
                             side_ref: *side_ref,
-                            volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
-                            amount: -2,
+                            volatile_status: PokemonVolatileStatus::CONFUSION,
+                            amount: 1,
                         },
                     ),
                 );
-                incoming_instructions
-                    .instruction_list
-                    .push(Instruction::RemoveVolatileStatus(
-                        RemoveVolatileStatusInstruction {
-                            side_ref: *side_ref,
-                            volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
-                        },
-                    ));
-                if !side
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::CONFUSION)
-                {
-                    incoming_instructions
-                        .instruction_list
-                        .push(Instruction::ApplyVolatileStatus(
-                            ApplyVolatileStatusInstruction {
-                                side_ref: *side_ref,
-                                volatile_status: PokemonVolatileStatus::CONFUSION,
-                            },
-                        ));
-                    side.volatile_statuses
-                        .insert(PokemonVolatileStatus::CONFUSION);
-                }
+            }
+        }
+
+        if side
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::LOCKEDMOVE)
+        {
+            // the number says 2 but this is 3 turns of using a locking move
+            // because turn 0 is the first turn the move is used.
+            // Ending after 2 turns is branched on in `add_random_duration_branches`
+            if side.volatile_status_durations.lockedmove == 2 {
+                end_locked_move(side, side_ref, incoming_instructions);
             } else {
                 side.volatile_status_durations.lockedmove += 1;
                 incoming_instructions.instruction_list.push(
@@ -2563,20 +2659,84 @@ fn end_of_turn_triggered(side_one_move: &MoveChoice, side_two_move: &MoveChoice)
         && !(side_one_move == &MoveChoice::None && matches!(side_two_move, &MoveChoice::Switch(_)))
 }
 
+// The chance of each number of hits of a move that checks `accuracy` before every hit after
+// the first and stops at the first miss
+fn multi_accuracy_hit_counts(max_hits: i8, accuracy: f32) -> Vec<(i8, f32)> {
+    (1..=max_hits)
+        .map(|hits| {
+            let chance = if hits == max_hits {
+                accuracy.powi(hits as i32 - 1)
+            } else {
+                accuracy.powi(hits as i32 - 1) * (1.0 - accuracy)
+            };
+            (hits, chance)
+        })
+        .filter(|(_, chance)| *chance > 0.0)
+        .collect()
+}
+
+// Runs the move once for every number of hits it can have. `instructions` must already be
+// applied to the state. Outcomes that end up the same, e.g. because the defender fainted before
+// the last hits, are merged later by `combine_duplicate_instructions`
+fn run_move_for_each_hit_count(
+    state: &mut State,
+    attacking_side: SideReference,
+    instructions: StateInstructions,
+    hit_counts: &[(i8, f32)],
+    does_damage: bool,
+    damage_per_hit: &[i16],
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    final_instructions: &mut Vec<StateInstructions>,
+) {
+    for (index, (hit_count, chance)) in hit_counts.iter().enumerate() {
+        // run_move reverses the instructions it was given, so every other number of hits
+        // has to apply them again
+        if index > 0 {
+            state.apply_instructions(&instructions.instruction_list);
+        }
+        let mut hit_count_instructions = instructions.clone();
+        hit_count_instructions.update_percentage(*chance);
+        run_move(
+            state,
+            attacking_side,
+            hit_count_instructions,
+            *hit_count,
+            does_damage,
+            damage_per_hit,
+            choice,
+            defender_choice,
+            final_instructions,
+        );
+    }
+}
+
 fn run_move(
     state: &mut State,
     attacking_side: SideReference,
     mut instructions: StateInstructions,
     hit_count: i8,
     does_damage: bool,
-    damage_amount: i16,
+    damage_per_hit: &[i16],
     choice: &mut Choice,
     defender_choice: &Choice,
     final_instructions: &mut Vec<StateInstructions>,
 ) {
     let mut hit_sub = false;
-    for _ in 0..hit_count {
+    for hit in 0..hit_count {
+        // a multi-hit move stops hitting once the defender has fainted
+        if hit > 0
+            && state
+                .get_side_immutable(&attacking_side.get_other_side())
+                .get_active_immutable()
+                .hp
+                <= 0
+        {
+            break;
+        }
         if does_damage {
+            // moves that do the same damage every hit only have the damage of the first hit
+            let damage_amount = damage_per_hit[(hit as usize).min(damage_per_hit.len() - 1)];
             hit_sub = generate_instructions_from_damage(
                 state,
                 choice,
@@ -2750,7 +2910,7 @@ fn handle_both_moves(
     first_move_side_ref: SideReference,
     incoming_instructions: StateInstructions,
     state_instructions_vec: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     generate_instructions_from_move(
         state,
@@ -2759,7 +2919,7 @@ fn handle_both_moves(
         first_move_side_ref,
         incoming_instructions,
         state_instructions_vec,
-        branch_on_damage,
+        damage_branching,
     );
 
     let mut i = 0;
@@ -2774,7 +2934,7 @@ fn handle_both_moves(
             first_move_side_ref.get_other_side(),
             state_instruction,
             state_instructions_vec,
-            branch_on_damage,
+            damage_branching,
         );
         i += 1;
     }
@@ -2784,8 +2944,35 @@ pub fn generate_instructions_from_move_pair(
     state: &mut State,
     side_one_move: &MoveChoice,
     side_two_move: &MoveChoice,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+) -> Vec<StateInstructions> {
+    generate_instructions_from_move_pair_with_pruning_stats(
+        state,
+        side_one_move,
+        side_two_move,
+        damage_branching,
+        prune_threshold,
+        &mut PruningStats::default(),
+    )
+}
+
+/// Like `generate_instructions_from_move_pair`, and records the percentage pruned by
+/// `prune_threshold` in `pruning_stats`
+pub fn generate_instructions_from_move_pair_with_pruning_stats(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
 ) -> Vec<StateInstructions> {
+    // instruction generation modifies the state directly in many places and relies on
+    // reversing the generated instructions to undo those modifications, which the
+    // incrementally maintained zobrist hash cannot follow.
+    // `state.zobrist_hash` is therefore only valid between calls, and is restored before returning
+    let zobrist_hash = state.zobrist_hash;
+
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -2829,12 +3016,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideOne);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideOne,
+                );
             }
         }
         SideMovesFirst::SideTwo => {
@@ -2845,12 +3037,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideTwo);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideTwo,
+                );
             }
         }
         SideMovesFirst::SpeedTie => {
@@ -2866,12 +3063,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 side_one_moves_first_instruction,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideOne);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideOne,
+                );
             }
 
             // side_two moves first
@@ -2883,18 +3085,28 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut side_two_moves_first_si,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in side_two_moves_first_si.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideTwo);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut side_two_moves_first_si,
+                    &SideReference::SideTwo,
+                );
             }
 
             // combine both vectors into the final vector
             state_instructions_vec.extend(side_two_moves_first_si);
         }
     }
+    state.zobrist_hash = zobrist_hash;
+    pruning_stats.record(prune_state_instructions(
+        &mut state_instructions_vec,
+        prune_threshold,
+    ));
     state_instructions_vec
 }
 
@@ -6,9 +6,10 @@ use crate::instruction::{
     ChangeSideConditionInstruction, ChangeType, ChangeVolatileStatusDurationInstruction,
     Instruction, RemoveVolatileStatusInstruction,
 };
+use crate::pokemon::PokemonName;
 use crate::state::{
-    LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
-    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference, State,
+    pokemon_index_iter, LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex,
+    PokemonMoveIndex, PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference, State,
 };
 use core::panic;
 use std::collections::HashSet;
@@ -49,6 +50,30 @@ impl MoveChoice {
             MoveChoice::None => "No Move".to_string(),
         }
     }
+
+    /// The choice as a Pokemon Showdown `/choose` command, e.g. "move 2" or "switch 4"
+    ///
+    /// Moves and pokemon are numbered by their slot in `side`, which must be in the same
+    /// order as Showdown's request. During team preview a switch chooses the lead and
+    /// the rest of the team keeps its order, e.g. "team 312456"
+    pub fn to_showdown_choice(&self, side: &Side, team_preview: bool) -> String {
+        match self {
+            MoveChoice::Move(index) => format!("move {}", *index as u8 + 1),
+            MoveChoice::Switch(index) if team_preview => {
+                let mut order = vec![*index];
+                order.extend(pokemon_index_iter().filter(|pkmn_index| {
+                    pkmn_index != index && side.pokemon[*pkmn_index].id != PokemonName::NONE
+                }));
+                let order: String = order
+                    .iter()
+                    .map(|pkmn_index| (*pkmn_index as u8 + 1).to_string())
+                    .collect();
+                format!("team {}", order)
+            }
+            MoveChoice::Switch(index) => format!("switch {}", *index as u8 + 1),
+            MoveChoice::None => "default".to_string(),
+        }
+    }
     pub fn from_string(s: &str, side: &Side) -> Option<MoveChoice> {
         let s = s.to_lowercase();
         if s == "none" {
@@ -689,6 +714,19 @@ impl State {
                     side.volatile_status_durations.lockedmove = 0;
                     false
                 }
+                PokemonVolatileStatus::CONFUSION => {
+                    if side.volatile_status_durations.confusion != 0 {
+                        instructions.push(Instruction::ChangeVolatileStatusDuration(
+                            ChangeVolatileStatusDurationInstruction {
+                                side_ref: *side_ref,
+                                volatile_status: *pkmn_volatile_status,
+                                amount: -1 * side.volatile_status_durations.confusion,
+                            },
+                        ));
+                        side.volatile_status_durations.confusion = 0;
+                    }
+                    false
+                }
                 PokemonVolatileStatus::YAWN => {
                     instructions.push(Instruction::ChangeVolatileStatusDuration(
                         ChangeVolatileStatusDurationInstruction {
@@ -2256,6 +2256,9 @@ pub fn ability_modify_attack_against(
         || attacker_choice.move_id == Choices::MOONGEISTBEAM
         || attacker_choice.move_id == Choices::PHOTONGEYSER
         || attacker_choice.move_id == Choices::SUNSTEELSTRIKE
+        || attacker_choice.move_id == Choices::MENACINGMOONRAZEMAELSTROM
+        || attacker_choice.move_id == Choices::LIGHTTHATBURNSTHESKY
+        || attacker_choice.move_id == Choices::SEARINGSUNRAZESMASH
         || (attacking_pkmn.ability == Abilities::MYCELIUMMIGHT
             && attacker_choice.category == MoveCategory::Status)
         || attacking_pkmn.ability == Abilities::TERAVOLT
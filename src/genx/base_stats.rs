@@ -1,21 +1,1611 @@
 use crate::pokemon::PokemonName;
 
 impl PokemonName {
-    /*
-    Base Stats are only required to re-calculate stats when a pokemon changes forme
-    so not every pokemon will be here
-    */
+    // Base stats of the species in the current generation
+    // Stats that were changed in a later generation are gated behind the generation's feature
     pub fn base_stats(&self) -> (i16, i16, i16, i16, i16, i16) {
         match self {
-            PokemonName::MINIOR => (60, 100, 60, 100, 60, 120),
-            PokemonName::MINIORMETEOR => (60, 60, 100, 60, 100, 60),
+            PokemonName::NONE => (0, 0, 0, 0, 0, 0),
+            PokemonName::BULBASAUR => (45, 49, 49, 65, 65, 45),
+            PokemonName::IVYSAUR => (60, 62, 63, 80, 80, 60),
+            PokemonName::VENUSAUR => (80, 82, 83, 100, 100, 80),
+            PokemonName::VENUSAURMEGA => (80, 100, 123, 122, 120, 80),
+            PokemonName::VENUSAURGMAX => (80, 82, 83, 100, 100, 80),
+            PokemonName::CHARMANDER => (39, 52, 43, 60, 50, 65),
+            PokemonName::CHARMELEON => (58, 64, 58, 80, 65, 80),
+            PokemonName::CHARIZARD => (78, 84, 78, 109, 85, 100),
+            PokemonName::CHARIZARDMEGAX => (78, 130, 111, 130, 85, 100),
+            PokemonName::CHARIZARDMEGAY => (78, 104, 78, 159, 115, 100),
+            PokemonName::CHARIZARDGMAX => (78, 84, 78, 109, 85, 100),
+            PokemonName::SQUIRTLE => (44, 48, 65, 50, 64, 43),
+            PokemonName::WARTORTLE => (59, 63, 80, 65, 80, 58),
+            PokemonName::BLASTOISE => (79, 83, 100, 85, 105, 78),
+            PokemonName::BLASTOISEMEGA => (79, 103, 120, 135, 115, 78),
+            PokemonName::BLASTOISEGMAX => (79, 83, 100, 85, 105, 78),
+            PokemonName::CATERPIE => (45, 30, 35, 20, 20, 45),
+            PokemonName::METAPOD => (50, 20, 55, 25, 25, 30),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::BUTTERFREE => (60, 45, 50, 80, 80, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::BUTTERFREE => (60, 45, 50, 90, 80, 70),
+            PokemonName::BUTTERFREEGMAX => (60, 45, 50, 90, 80, 70),
+            PokemonName::WEEDLE => (40, 35, 30, 20, 20, 50),
+            PokemonName::KAKUNA => (45, 25, 50, 25, 25, 35),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::BEEDRILL => (65, 80, 40, 45, 80, 75),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::BEEDRILL => (65, 90, 40, 45, 80, 75),
+            PokemonName::BEEDRILLMEGA => (65, 150, 40, 15, 80, 145),
+            PokemonName::PIDGEY => (40, 45, 40, 35, 35, 56),
+            PokemonName::PIDGEOTTO => (63, 60, 55, 50, 50, 71),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::PIDGEOT => (83, 80, 75, 70, 70, 91),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::PIDGEOT => (83, 80, 75, 70, 70, 101),
+            PokemonName::PIDGEOTMEGA => (83, 80, 80, 135, 80, 121),
+            PokemonName::RATTATA => (30, 56, 35, 25, 35, 72),
+            PokemonName::RATTATAALOLA => (30, 56, 35, 25, 35, 72),
+            PokemonName::RATICATE => (55, 81, 60, 50, 70, 97),
+            PokemonName::RATICATEALOLA => (75, 71, 70, 40, 80, 77),
+            PokemonName::RATICATEALOLATOTEM => (75, 71, 70, 40, 80, 77),
+            PokemonName::SPEAROW => (40, 60, 30, 31, 31, 70),
+            PokemonName::FEAROW => (65, 90, 65, 61, 61, 100),
+            PokemonName::EKANS => (35, 60, 44, 40, 54, 55),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::ARBOK => (60, 85, 69, 65, 79, 80),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::ARBOK => (60, 95, 69, 65, 79, 80),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::PIKACHU => (35, 55, 30, 50, 40, 90),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::PIKACHU => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUCOSPLAY => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUROCKSTAR => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUBELLE => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUPOPSTAR => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUPHD => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHULIBRE => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUORIGINAL => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUHOENN => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUSINNOH => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUUNOVA => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUKALOS => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUALOLA => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUPARTNER => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUSTARTER => (45, 80, 50, 75, 60, 120),
+            PokemonName::PIKACHUGMAX => (35, 55, 40, 50, 50, 90),
+            PokemonName::PIKACHUWORLD => (35, 55, 40, 50, 50, 90),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::RAICHU => (60, 90, 55, 90, 80, 100),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::RAICHU => (60, 90, 55, 90, 80, 110),
+            PokemonName::RAICHUALOLA => (60, 85, 50, 95, 85, 110),
+            PokemonName::SANDSHREW => (50, 75, 85, 20, 30, 40),
+            PokemonName::SANDSHREWALOLA => (50, 75, 90, 10, 35, 40),
+            PokemonName::SANDSLASH => (75, 100, 110, 45, 55, 65),
+            PokemonName::SANDSLASHALOLA => (75, 100, 120, 25, 65, 65),
+            PokemonName::NIDORANF => (55, 47, 52, 40, 40, 41),
+            PokemonName::NIDORINA => (70, 62, 67, 55, 55, 56),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::NIDOQUEEN => (90, 82, 87, 75, 85, 76),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::NIDOQUEEN => (90, 92, 87, 75, 85, 76),
+            PokemonName::NIDORANM => (46, 57, 40, 40, 40, 50),
+            PokemonName::NIDORINO => (61, 72, 57, 55, 55, 65),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::NIDOKING => (81, 92, 77, 85, 75, 85),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::NIDOKING => (81, 102, 77, 85, 75, 85),
+            PokemonName::CLEFAIRY => (70, 45, 48, 60, 65, 35),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::CLEFABLE => (95, 70, 73, 85, 90, 60),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::CLEFABLE => (95, 70, 73, 95, 90, 60),
+            PokemonName::VULPIX => (38, 41, 40, 50, 65, 65),
+            PokemonName::VULPIXALOLA => (38, 41, 40, 50, 65, 65),
+            PokemonName::NINETALES => (73, 76, 75, 81, 100, 100),
+            PokemonName::NINETALESALOLA => (73, 67, 75, 81, 100, 109),
+            PokemonName::JIGGLYPUFF => (115, 45, 20, 45, 25, 20),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::WIGGLYTUFF => (140, 70, 45, 75, 50, 45),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::WIGGLYTUFF => (140, 70, 45, 85, 50, 45),
+            PokemonName::ZUBAT => (40, 45, 35, 30, 40, 55),
+            PokemonName::GOLBAT => (75, 80, 70, 65, 75, 90),
+            PokemonName::ODDISH => (45, 50, 55, 75, 65, 30),
+            PokemonName::GLOOM => (60, 65, 70, 85, 75, 40),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::VILEPLUME => (75, 80, 85, 100, 90, 50),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::VILEPLUME => (75, 80, 85, 110, 90, 50),
+            PokemonName::PARAS => (35, 70, 55, 45, 55, 25),
+            PokemonName::PARASECT => (60, 95, 80, 60, 80, 30),
+            PokemonName::VENONAT => (60, 55, 50, 40, 55, 45),
+            PokemonName::VENOMOTH => (70, 65, 60, 90, 75, 90),
+            PokemonName::DIGLETT => (10, 55, 25, 35, 45, 95),
+            PokemonName::DIGLETTALOLA => (10, 55, 30, 35, 45, 90),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::DUGTRIO => (35, 80, 50, 50, 70, 120),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::DUGTRIO => (35, 100, 50, 50, 70, 120),
+            PokemonName::DUGTRIOALOLA => (35, 100, 60, 50, 70, 110),
+            PokemonName::MEOWTH => (40, 45, 35, 40, 40, 90),
+            PokemonName::MEOWTHALOLA => (40, 35, 35, 50, 40, 90),
+            PokemonName::MEOWTHGALAR => (50, 65, 55, 40, 40, 40),
+            PokemonName::MEOWTHGMAX => (40, 45, 35, 40, 40, 90),
+            PokemonName::PERSIAN => (65, 70, 60, 65, 65, 115),
+            PokemonName::PERSIANALOLA => (65, 60, 60, 75, 65, 115),
+            PokemonName::PSYDUCK => (50, 52, 48, 65, 50, 55),
+            PokemonName::GOLDUCK => (80, 82, 78, 95, 80, 85),
+            PokemonName::MANKEY => (40, 80, 35, 35, 45, 70),
+            PokemonName::PRIMEAPE => (65, 105, 60, 60, 70, 95),
+            PokemonName::GROWLITHE => (55, 70, 45, 70, 50, 60),
+            PokemonName::GROWLITHEHISUI => (60, 75, 45, 65, 50, 55),
+            PokemonName::ARCANINE => (90, 110, 80, 100, 80, 95),
+            PokemonName::ARCANINEHISUI => (95, 115, 80, 95, 80, 90),
+            PokemonName::POLIWAG => (40, 50, 40, 40, 40, 90),
+            PokemonName::POLIWHIRL => (65, 65, 65, 50, 50, 90),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::POLIWRATH => (90, 85, 95, 70, 90, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::POLIWRATH => (90, 95, 95, 70, 90, 70),
+            PokemonName::ABRA => (25, 20, 15, 105, 55, 90),
+            PokemonName::KADABRA => (40, 35, 30, 120, 70, 105),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::ALAKAZAM => (55, 50, 45, 135, 85, 120),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::ALAKAZAM => (55, 50, 45, 135, 95, 120),
+            PokemonName::ALAKAZAMMEGA => (55, 50, 65, 175, 105, 150),
+            PokemonName::MACHOP => (70, 80, 50, 35, 35, 35),
+            PokemonName::MACHOKE => (80, 100, 70, 50, 60, 45),
+            PokemonName::MACHAMP => (90, 130, 80, 65, 85, 55),
+            PokemonName::MACHAMPGMAX => (90, 130, 80, 65, 85, 55),
+            PokemonName::BELLSPROUT => (50, 75, 35, 70, 30, 40),
+            PokemonName::WEEPINBELL => (65, 90, 50, 85, 45, 55),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::VICTREEBEL => (80, 105, 65, 100, 60, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::VICTREEBEL => (80, 105, 65, 100, 70, 70),
+            PokemonName::TENTACOOL => (40, 40, 35, 50, 100, 70),
+            PokemonName::TENTACRUEL => (80, 70, 65, 80, 120, 100),
+            PokemonName::GEODUDE => (40, 80, 100, 30, 30, 20),
+            PokemonName::GEODUDEALOLA => (40, 80, 100, 30, 30, 20),
+            PokemonName::GRAVELER => (55, 95, 115, 45, 45, 35),
+            PokemonName::GRAVELERALOLA => (55, 95, 115, 45, 45, 35),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::GOLEM => (80, 110, 130, 55, 65, 45),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::GOLEM => (80, 120, 130, 55, 65, 45),
+            PokemonName::GOLEMALOLA => (80, 120, 130, 55, 65, 45),
+            PokemonName::PONYTA => (50, 85, 55, 65, 65, 90),
+            PokemonName::PONYTAGALAR => (50, 85, 55, 65, 65, 90),
+            PokemonName::RAPIDASH => (65, 100, 70, 80, 80, 105),
+            PokemonName::RAPIDASHGALAR => (65, 100, 70, 80, 80, 105),
+            PokemonName::SLOWPOKE => (90, 65, 65, 40, 40, 15),
+            PokemonName::SLOWPOKEGALAR => (90, 65, 65, 40, 40, 15),
+            PokemonName::SLOWBRO => (95, 75, 110, 100, 80, 30),
+            PokemonName::SLOWBROMEGA => (95, 75, 180, 130, 80, 30),
+            PokemonName::SLOWBROGALAR => (95, 100, 95, 100, 70, 30),
+            PokemonName::MAGNEMITE => (25, 35, 70, 95, 55, 45),
+            PokemonName::MAGNETON => (50, 60, 95, 120, 70, 70),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::FARFETCHD => (52, 65, 55, 58, 62, 60),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::FARFETCHD => (52, 90, 55, 58, 62, 60),
+            PokemonName::FARFETCHDGALAR => (52, 95, 55, 58, 62, 55),
+            PokemonName::DODUO => (35, 85, 45, 35, 35, 75),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::DODRIO => (60, 110, 70, 60, 60, 100),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::DODRIO => (60, 110, 70, 60, 60, 110),
+            PokemonName::SEEL => (65, 45, 55, 45, 70, 45),
+            PokemonName::DEWGONG => (90, 70, 80, 70, 95, 70),
+            PokemonName::GRIMER => (80, 80, 50, 40, 50, 25),
+            PokemonName::GRIMERALOLA => (80, 80, 50, 40, 50, 25),
+            PokemonName::MUK => (105, 105, 75, 65, 100, 50),
+            PokemonName::MUKALOLA => (105, 105, 75, 65, 100, 50),
+            PokemonName::SHELLDER => (30, 65, 100, 45, 25, 40),
+            PokemonName::CLOYSTER => (50, 95, 180, 85, 45, 70),
+            PokemonName::GASTLY => (30, 35, 30, 100, 35, 80),
+            PokemonName::HAUNTER => (45, 50, 45, 115, 55, 95),
+            PokemonName::GENGAR => (60, 65, 60, 130, 75, 110),
+            PokemonName::GENGARMEGA => (60, 65, 80, 170, 95, 130),
+            PokemonName::GENGARGMAX => (60, 65, 60, 130, 75, 110),
+            PokemonName::ONIX => (35, 45, 160, 30, 45, 70),
+            PokemonName::DROWZEE => (60, 48, 45, 43, 90, 42),
+            PokemonName::HYPNO => (85, 73, 70, 73, 115, 67),
+            PokemonName::KRABBY => (30, 105, 90, 25, 25, 50),
+            PokemonName::KINGLER => (55, 130, 115, 50, 50, 75),
+            PokemonName::KINGLERGMAX => (55, 130, 115, 50, 50, 75),
+            PokemonName::VOLTORB => (40, 30, 50, 55, 55, 100),
+            PokemonName::VOLTORBHISUI => (40, 30, 50, 55, 55, 100),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::ELECTRODE => (60, 50, 70, 80, 80, 140),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::ELECTRODE => (60, 50, 70, 80, 80, 150),
+            PokemonName::ELECTRODEHISUI => (60, 50, 70, 80, 80, 150),
+            PokemonName::EXEGGCUTE => (60, 40, 80, 60, 45, 40),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::EXEGGUTOR => (95, 95, 85, 125, 65, 55),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::EXEGGUTOR => (95, 95, 85, 125, 75, 55),
+            PokemonName::EXEGGUTORALOLA => (95, 105, 85, 125, 75, 45),
+            PokemonName::CUBONE => (50, 50, 95, 40, 50, 35),
+            PokemonName::MAROWAK => (60, 80, 110, 50, 80, 45),
+            PokemonName::MAROWAKALOLA => (60, 80, 110, 50, 80, 45),
+            PokemonName::MAROWAKALOLATOTEM => (60, 80, 110, 50, 80, 45),
+            PokemonName::HITMONLEE => (50, 120, 53, 35, 110, 87),
+            PokemonName::HITMONCHAN => (50, 105, 79, 35, 110, 76),
+            PokemonName::LICKITUNG => (90, 55, 75, 60, 75, 30),
+            PokemonName::KOFFING => (40, 65, 95, 60, 45, 35),
+            PokemonName::WEEZING => (65, 90, 120, 85, 70, 60),
+            PokemonName::WEEZINGGALAR => (65, 90, 120, 85, 70, 60),
+            PokemonName::RHYHORN => (80, 85, 95, 30, 30, 25),
+            PokemonName::RHYDON => (105, 130, 120, 45, 45, 40),
+            PokemonName::CHANSEY => (250, 5, 5, 35, 105, 50),
+            PokemonName::TANGELA => (65, 55, 115, 100, 40, 60),
+            PokemonName::KANGASKHAN => (105, 95, 80, 40, 80, 90),
+            PokemonName::KANGASKHANMEGA => (105, 125, 100, 60, 100, 100),
+            PokemonName::HORSEA => (30, 40, 70, 70, 25, 60),
+            PokemonName::SEADRA => (55, 65, 95, 95, 45, 85),
+            PokemonName::GOLDEEN => (45, 67, 60, 35, 50, 63),
+            PokemonName::SEAKING => (80, 92, 65, 65, 80, 68),
+            PokemonName::STARYU => (30, 45, 55, 70, 55, 85),
+            PokemonName::STARMIE => (60, 75, 85, 100, 85, 115),
+            PokemonName::MRMIME => (40, 45, 65, 100, 120, 90),
+            PokemonName::MRMIMEGALAR => (50, 65, 65, 90, 90, 100),
+            PokemonName::SCYTHER => (70, 110, 80, 55, 80, 105),
+            PokemonName::JYNX => (65, 50, 35, 115, 95, 95),
+            PokemonName::ELECTABUZZ => (65, 83, 57, 95, 85, 105),
+            PokemonName::MAGMAR => (65, 95, 57, 100, 85, 93),
+            PokemonName::PINSIR => (65, 125, 100, 55, 70, 85),
+            PokemonName::PINSIRMEGA => (65, 155, 120, 65, 90, 105),
+            PokemonName::TAUROS => (75, 100, 95, 40, 70, 110),
+            PokemonName::TAUROSPALDEACOMBAT => (75, 110, 105, 30, 70, 100),
+            PokemonName::TAUROSPALDEABLAZE => (75, 110, 105, 30, 70, 100),
+            PokemonName::TAUROSPALDEAAQUA => (75, 110, 105, 30, 70, 100),
+            PokemonName::MAGIKARP => (20, 10, 55, 15, 20, 80),
+            PokemonName::GYARADOS => (95, 125, 79, 60, 100, 81),
+            PokemonName::GYARADOSMEGA => (95, 155, 109, 70, 130, 81),
+            PokemonName::LAPRAS => (130, 85, 80, 85, 95, 60),
+            PokemonName::LAPRASGMAX => (130, 85, 80, 85, 95, 60),
+            PokemonName::DITTO => (48, 48, 48, 48, 48, 48),
+            PokemonName::EEVEE => (55, 55, 50, 45, 65, 55),
+            PokemonName::EEVEESTARTER => (65, 75, 70, 65, 85, 75),
+            PokemonName::EEVEEGMAX => (55, 55, 50, 45, 65, 55),
+            PokemonName::VAPOREON => (130, 65, 60, 110, 95, 65),
+            PokemonName::JOLTEON => (65, 65, 60, 110, 95, 130),
+            PokemonName::FLAREON => (65, 130, 60, 95, 110, 65),
+            PokemonName::PORYGON => (65, 60, 70, 85, 75, 40),
+            PokemonName::OMANYTE => (35, 40, 100, 90, 55, 35),
+            PokemonName::OMASTAR => (70, 60, 125, 115, 70, 55),
+            PokemonName::KABUTO => (30, 80, 90, 55, 45, 55),
+            PokemonName::KABUTOPS => (60, 115, 105, 65, 70, 80),
+            PokemonName::AERODACTYL => (80, 105, 65, 60, 75, 130),
+            PokemonName::AERODACTYLMEGA => (80, 135, 85, 70, 95, 150),
+            PokemonName::SNORLAX => (160, 110, 65, 65, 110, 30),
+            PokemonName::SNORLAXGMAX => (160, 110, 65, 65, 110, 30),
+            PokemonName::ARTICUNO => (90, 85, 100, 95, 125, 85),
+            PokemonName::ARTICUNOGALAR => (90, 85, 85, 125, 100, 95),
+            PokemonName::ZAPDOS => (90, 90, 85, 125, 90, 100),
+            PokemonName::ZAPDOSGALAR => (90, 125, 90, 85, 90, 100),
+            PokemonName::MOLTRES => (90, 100, 90, 125, 85, 90),
+            PokemonName::MOLTRESGALAR => (90, 85, 90, 100, 125, 90),
+            PokemonName::DRATINI => (41, 64, 45, 50, 50, 50),
+            PokemonName::DRAGONAIR => (61, 84, 65, 70, 70, 70),
+            PokemonName::DRAGONITE => (91, 134, 95, 100, 100, 80),
+            PokemonName::MEWTWO => (106, 110, 90, 154, 90, 130),
+            PokemonName::MEWTWOMEGAX => (106, 190, 100, 154, 100, 130),
+            PokemonName::MEWTWOMEGAY => (106, 150, 70, 194, 120, 140),
+            PokemonName::MEW => (100, 100, 100, 100, 100, 100),
+            PokemonName::CHIKORITA => (45, 49, 65, 49, 65, 45),
+            PokemonName::BAYLEEF => (60, 62, 80, 63, 80, 60),
+            PokemonName::MEGANIUM => (80, 82, 100, 83, 100, 80),
+            PokemonName::CYNDAQUIL => (39, 52, 43, 60, 50, 65),
+            PokemonName::QUILAVA => (58, 64, 58, 80, 65, 80),
+            PokemonName::TYPHLOSION => (78, 84, 78, 109, 85, 100),
+            PokemonName::TYPHLOSIONHISUI => (73, 84, 78, 119, 85, 95),
+            PokemonName::TOTODILE => (50, 65, 64, 44, 48, 43),
+            PokemonName::CROCONAW => (65, 80, 80, 59, 63, 58),
+            PokemonName::FERALIGATR => (85, 105, 100, 79, 83, 78),
+            PokemonName::SENTRET => (35, 46, 34, 35, 45, 20),
+            PokemonName::FURRET => (85, 76, 64, 45, 55, 90),
+            PokemonName::HOOTHOOT => (60, 30, 30, 36, 56, 50),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::NOCTOWL => (100, 50, 50, 76, 96, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::NOCTOWL => (100, 50, 50, 86, 96, 70),
+            PokemonName::LEDYBA => (40, 20, 30, 40, 80, 55),
+            PokemonName::LEDIAN => (55, 35, 50, 55, 110, 85),
+            PokemonName::SPINARAK => (40, 60, 40, 40, 40, 30),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::ARIADOS => (70, 90, 70, 60, 60, 40),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::ARIADOS => (70, 90, 70, 60, 70, 40),
+            PokemonName::CROBAT => (85, 90, 80, 70, 80, 130),
+            PokemonName::CHINCHOU => (75, 38, 38, 56, 56, 67),
+            PokemonName::LANTURN => (125, 58, 58, 76, 76, 67),
+            PokemonName::PICHU => (20, 40, 15, 35, 35, 60),
+            PokemonName::PICHUSPIKYEARED => (20, 40, 15, 35, 35, 60),
+            PokemonName::CLEFFA => (50, 25, 28, 45, 55, 15),
+            PokemonName::IGGLYBUFF => (90, 30, 15, 40, 20, 15),
+            PokemonName::TOGEPI => (35, 20, 65, 40, 65, 20),
+            PokemonName::TOGETIC => (55, 40, 85, 80, 105, 40),
+            PokemonName::NATU => (40, 50, 45, 70, 45, 70),
+            PokemonName::XATU => (65, 75, 70, 95, 70, 95),
+            PokemonName::MAREEP => (55, 40, 40, 65, 45, 35),
+            PokemonName::FLAAFFY => (70, 55, 55, 80, 60, 45),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::AMPHAROS => (90, 75, 75, 115, 90, 55),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::AMPHAROS => (90, 75, 85, 115, 90, 55),
+            PokemonName::AMPHAROSMEGA => (90, 95, 105, 165, 110, 45),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::BELLOSSOM => (75, 80, 85, 90, 100, 50),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::BELLOSSOM => (75, 80, 95, 90, 100, 50),
+            PokemonName::MARILL => (70, 20, 50, 20, 50, 40),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::AZUMARILL => (100, 50, 80, 50, 80, 50),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::AZUMARILL => (100, 50, 80, 60, 80, 50),
+            PokemonName::SUDOWOODO => (70, 100, 115, 30, 65, 30),
+            PokemonName::POLITOED => (90, 75, 75, 90, 100, 70),
+            PokemonName::HOPPIP => (35, 35, 40, 35, 55, 50),
+            PokemonName::SKIPLOOM => (55, 45, 50, 45, 65, 80),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::JUMPLUFF => (75, 55, 70, 55, 85, 110),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::JUMPLUFF => (75, 55, 70, 55, 95, 110),
+            PokemonName::AIPOM => (55, 70, 55, 40, 55, 85),
+            PokemonName::SUNKERN => (30, 30, 30, 30, 30, 30),
+            PokemonName::SUNFLORA => (75, 75, 55, 105, 85, 30),
+            PokemonName::YANMA => (65, 65, 45, 75, 45, 95),
+            PokemonName::WOOPER => (55, 45, 45, 25, 25, 15),
+            PokemonName::WOOPERPALDEA => (55, 45, 45, 25, 25, 15),
+            PokemonName::QUAGSIRE => (95, 85, 85, 65, 65, 35),
+            PokemonName::ESPEON => (65, 65, 60, 130, 95, 110),
+            PokemonName::UMBREON => (95, 65, 110, 60, 130, 65),
+            PokemonName::MURKROW => (60, 85, 42, 85, 42, 91),
+            PokemonName::SLOWKING => (95, 75, 80, 100, 110, 30),
+            PokemonName::SLOWKINGGALAR => (95, 65, 80, 110, 110, 30),
+            PokemonName::MISDREAVUS => (60, 60, 60, 85, 85, 85),
+            PokemonName::UNOWN => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNB => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNC => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWND => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNE => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNF => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNG => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNH => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNI => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNJ => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNK => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNL => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNM => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNN => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNO => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNP => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNQ => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNR => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNS => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNT => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNU => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNV => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNW => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNX => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNY => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNZ => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNEXCLAMATION => (48, 72, 48, 72, 48, 48),
+            PokemonName::UNOWNQUESTION => (48, 72, 48, 72, 48, 48),
+            PokemonName::WOBBUFFET => (190, 33, 58, 33, 58, 33),
+            PokemonName::GIRAFARIG => (70, 80, 65, 90, 65, 85),
+            PokemonName::PINECO => (50, 65, 90, 35, 35, 15),
+            PokemonName::FORRETRESS => (75, 90, 140, 60, 60, 40),
+            PokemonName::DUNSPARCE => (100, 70, 70, 65, 65, 45),
+            PokemonName::GLIGAR => (65, 75, 105, 35, 65, 85),
+            PokemonName::STEELIX => (75, 85, 200, 55, 65, 30),
+            PokemonName::STEELIXMEGA => (75, 125, 230, 55, 95, 30),
+            PokemonName::SNUBBULL => (60, 80, 50, 40, 40, 30),
+            PokemonName::GRANBULL => (90, 120, 75, 60, 60, 45),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::QWILFISH => (65, 95, 75, 55, 55, 85),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::QWILFISH => (65, 95, 85, 55, 55, 85),
+            PokemonName::QWILFISHHISUI => (65, 95, 85, 55, 55, 85),
+            PokemonName::SCIZOR => (70, 130, 100, 55, 80, 65),
+            PokemonName::SCIZORMEGA => (70, 150, 140, 65, 100, 75),
+            PokemonName::SHUCKLE => (20, 10, 230, 10, 230, 5),
+            PokemonName::HERACROSS => (80, 125, 75, 40, 95, 85),
+            PokemonName::HERACROSSMEGA => (80, 185, 115, 40, 105, 75),
+            PokemonName::SNEASEL => (55, 95, 55, 35, 75, 115),
+            PokemonName::SNEASELHISUI => (55, 95, 55, 35, 75, 115),
+            PokemonName::TEDDIURSA => (60, 80, 50, 50, 50, 40),
+            PokemonName::URSARING => (90, 130, 75, 75, 75, 55),
+            PokemonName::SLUGMA => (40, 40, 40, 70, 40, 20),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::MAGCARGO => (50, 50, 120, 80, 80, 30),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::MAGCARGO => (60, 50, 120, 90, 80, 30),
+            PokemonName::SWINUB => (50, 50, 40, 30, 30, 50),
+            PokemonName::PILOSWINE => (100, 100, 80, 60, 60, 50),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::CORSOLA => (55, 55, 85, 65, 85, 35),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::CORSOLA => (65, 55, 95, 65, 95, 35),
+            PokemonName::CORSOLAGALAR => (60, 55, 100, 65, 100, 30),
+            PokemonName::REMORAID => (35, 65, 35, 65, 35, 65),
+            PokemonName::OCTILLERY => (75, 105, 75, 105, 75, 45),
+            PokemonName::DELIBIRD => (45, 55, 45, 65, 45, 75),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::MANTINE => (65, 40, 70, 80, 140, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::MANTINE => (85, 40, 70, 80, 140, 70),
+            PokemonName::SKARMORY => (65, 80, 140, 40, 70, 70),
+            PokemonName::HOUNDOUR => (45, 60, 30, 80, 50, 65),
+            PokemonName::HOUNDOOM => (75, 90, 50, 110, 80, 95),
+            PokemonName::HOUNDOOMMEGA => (75, 90, 90, 140, 90, 115),
+            PokemonName::KINGDRA => (75, 95, 95, 95, 95, 85),
+            PokemonName::PHANPY => (90, 60, 60, 40, 40, 40),
+            PokemonName::DONPHAN => (90, 120, 120, 60, 60, 50),
+            PokemonName::PORYGON2 => (85, 80, 90, 105, 95, 60),
+            PokemonName::STANTLER => (73, 95, 62, 85, 65, 85),
+            PokemonName::SMEARGLE => (55, 20, 35, 20, 45, 75),
+            PokemonName::TYROGUE => (35, 35, 35, 35, 35, 35),
+            PokemonName::HITMONTOP => (50, 95, 95, 35, 110, 70),
+            PokemonName::SMOOCHUM => (45, 30, 15, 85, 65, 65),
+            PokemonName::ELEKID => (45, 63, 37, 65, 55, 95),
+            PokemonName::MAGBY => (45, 75, 37, 70, 55, 83),
+            PokemonName::MILTANK => (95, 80, 105, 40, 70, 100),
+            PokemonName::BLISSEY => (255, 10, 10, 75, 135, 55),
+            PokemonName::RAIKOU => (90, 85, 75, 115, 100, 115),
+            PokemonName::ENTEI => (115, 115, 85, 90, 75, 100),
+            PokemonName::SUICUNE => (100, 75, 115, 90, 115, 85),
+            PokemonName::LARVITAR => (50, 64, 50, 45, 50, 41),
+            PokemonName::PUPITAR => (70, 84, 70, 65, 70, 51),
+            PokemonName::TYRANITAR => (100, 134, 110, 95, 100, 61),
+            PokemonName::TYRANITARMEGA => (100, 164, 150, 95, 120, 71),
+            PokemonName::LUGIA => (106, 90, 130, 90, 154, 110),
+            PokemonName::HOOH => (106, 130, 90, 110, 154, 90),
+            PokemonName::CELEBI => (100, 100, 100, 100, 100, 100),
+            PokemonName::TREECKO => (40, 45, 35, 65, 55, 70),
+            PokemonName::GROVYLE => (50, 65, 45, 85, 65, 95),
+            PokemonName::SCEPTILE => (70, 85, 65, 105, 85, 120),
+            PokemonName::SCEPTILEMEGA => (70, 110, 75, 145, 85, 145),
+            PokemonName::TORCHIC => (45, 60, 40, 70, 50, 45),
+            PokemonName::COMBUSKEN => (60, 85, 60, 85, 60, 55),
+            PokemonName::BLAZIKEN => (80, 120, 70, 110, 70, 80),
+            PokemonName::BLAZIKENMEGA => (80, 160, 80, 130, 80, 100),
+            PokemonName::MUDKIP => (50, 70, 50, 50, 50, 40),
+            PokemonName::MARSHTOMP => (70, 85, 70, 60, 70, 50),
+            PokemonName::SWAMPERT => (100, 110, 90, 85, 90, 60),
+            PokemonName::SWAMPERTMEGA => (100, 150, 110, 95, 110, 70),
+            PokemonName::POOCHYENA => (35, 55, 35, 30, 30, 35),
+            PokemonName::MIGHTYENA => (70, 90, 70, 60, 60, 70),
+            PokemonName::ZIGZAGOON => (38, 30, 41, 30, 41, 60),
+            PokemonName::ZIGZAGOONGALAR => (38, 30, 41, 30, 41, 60),
+            PokemonName::LINOONE => (78, 70, 61, 50, 61, 100),
+            PokemonName::LINOONEGALAR => (78, 70, 61, 50, 61, 100),
+            PokemonName::WURMPLE => (45, 45, 35, 20, 30, 20),
+            PokemonName::SILCOON => (50, 35, 55, 25, 25, 15),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::BEAUTIFLY => (60, 70, 50, 90, 50, 65),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::BEAUTIFLY => (60, 70, 50, 100, 50, 65),
+            PokemonName::CASCOON => (50, 35, 55, 25, 25, 15),
+            PokemonName::DUSTOX => (60, 50, 70, 50, 90, 65),
+            PokemonName::LOTAD => (40, 30, 30, 40, 50, 30),
+            PokemonName::LOMBRE => (60, 50, 50, 60, 70, 50),
+            PokemonName::LUDICOLO => (80, 70, 70, 90, 100, 70),
+            PokemonName::SEEDOT => (40, 40, 50, 30, 30, 30),
+            PokemonName::NUZLEAF => (70, 70, 40, 60, 40, 60),
+            PokemonName::SHIFTRY => (90, 100, 60, 90, 60, 80),
+            PokemonName::TAILLOW => (40, 55, 30, 30, 30, 85),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::SWELLOW => (60, 85, 60, 50, 50, 125),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::SWELLOW => (60, 85, 60, 75, 50, 125),
+            PokemonName::WINGULL => (40, 30, 30, 55, 30, 85),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::PELIPPER => (60, 50, 100, 85, 70, 65),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::PELIPPER => (60, 50, 100, 95, 70, 65),
+            PokemonName::RALTS => (28, 25, 25, 45, 35, 40),
+            PokemonName::KIRLIA => (38, 35, 35, 65, 55, 50),
+            PokemonName::GARDEVOIR => (68, 65, 65, 125, 115, 80),
+            PokemonName::GARDEVOIRMEGA => (68, 85, 65, 165, 135, 100),
+            PokemonName::SURSKIT => (40, 30, 32, 50, 52, 65),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::MASQUERAIN => (70, 60, 62, 80, 82, 60),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::MASQUERAIN => (70, 60, 62, 100, 82, 80),
+            PokemonName::SHROOMISH => (60, 40, 60, 40, 60, 35),
+            PokemonName::BRELOOM => (60, 130, 80, 60, 60, 70),
+            PokemonName::SLAKOTH => (60, 60, 60, 35, 35, 30),
+            PokemonName::VIGOROTH => (80, 80, 80, 55, 55, 90),
+            PokemonName::SLAKING => (150, 160, 100, 95, 65, 100),
+            PokemonName::NINCADA => (31, 45, 90, 30, 30, 40),
+            PokemonName::NINJASK => (61, 90, 45, 50, 50, 160),
+            PokemonName::SHEDINJA => (1, 90, 45, 30, 30, 40),
+            PokemonName::WHISMUR => (64, 51, 23, 51, 23, 28),
+            PokemonName::LOUDRED => (84, 71, 43, 71, 43, 48),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::EXPLOUD => (104, 91, 63, 91, 63, 68),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::EXPLOUD => (104, 91, 63, 91, 73, 68),
+            PokemonName::MAKUHITA => (72, 60, 30, 20, 30, 25),
+            PokemonName::HARIYAMA => (144, 120, 60, 40, 60, 50),
+            PokemonName::AZURILL => (50, 20, 40, 20, 40, 20),
+            PokemonName::NOSEPASS => (30, 45, 135, 45, 90, 30),
+            PokemonName::SKITTY => (50, 45, 45, 35, 35, 50),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::DELCATTY => (70, 65, 65, 55, 55, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::DELCATTY => (70, 65, 65, 55, 55, 90),
+            PokemonName::SABLEYE => (50, 75, 75, 65, 65, 50),
+            PokemonName::SABLEYEMEGA => (50, 85, 125, 85, 115, 20),
+            PokemonName::MAWILE => (50, 85, 85, 55, 55, 50),
+            PokemonName::MAWILEMEGA => (50, 105, 125, 55, 95, 50),
+            PokemonName::ARON => (50, 70, 100, 40, 40, 30),
+            PokemonName::LAIRON => (60, 90, 140, 50, 50, 40),
+            PokemonName::AGGRON => (70, 110, 180, 60, 60, 50),
+            PokemonName::AGGRONMEGA => (70, 140, 230, 60, 80, 50),
+            PokemonName::MEDITITE => (30, 40, 55, 40, 55, 60),
+            PokemonName::MEDICHAM => (60, 60, 75, 60, 75, 80),
+            PokemonName::MEDICHAMMEGA => (60, 100, 85, 80, 85, 100),
+            PokemonName::ELECTRIKE => (40, 45, 40, 65, 40, 65),
+            PokemonName::MANECTRIC => (70, 75, 60, 105, 60, 105),
+            PokemonName::MANECTRICMEGA => (70, 75, 80, 135, 80, 135),
+            PokemonName::PLUSLE => (60, 50, 40, 85, 75, 95),
+            PokemonName::MINUN => (60, 40, 50, 75, 85, 95),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::VOLBEAT => (65, 73, 55, 47, 75, 85),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::VOLBEAT => (65, 73, 75, 47, 85, 85),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::ILLUMISE => (65, 47, 55, 73, 75, 85),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::ILLUMISE => (65, 47, 75, 73, 85, 85),
+            PokemonName::ROSELIA => (50, 60, 45, 100, 80, 65),
+            PokemonName::GULPIN => (70, 43, 53, 43, 53, 40),
+            PokemonName::SWALOT => (100, 73, 83, 73, 83, 55),
+            PokemonName::CARVANHA => (45, 90, 20, 65, 20, 65),
+            PokemonName::SHARPEDO => (70, 120, 40, 95, 40, 95),
+            PokemonName::SHARPEDOMEGA => (70, 140, 70, 110, 65, 105),
+            PokemonName::WAILMER => (130, 70, 35, 70, 35, 60),
+            PokemonName::WAILORD => (170, 90, 45, 90, 45, 60),
+            PokemonName::NUMEL => (60, 60, 40, 65, 45, 35),
+            PokemonName::CAMERUPT => (70, 100, 70, 105, 75, 40),
+            PokemonName::CAMERUPTMEGA => (70, 120, 100, 145, 105, 20),
+            PokemonName::TORKOAL => (70, 85, 140, 85, 70, 20),
+            PokemonName::SPOINK => (60, 25, 35, 70, 80, 60),
+            PokemonName::GRUMPIG => (80, 45, 65, 90, 110, 80),
+            PokemonName::SPINDA => (60, 60, 60, 60, 60, 60),
+            PokemonName::TRAPINCH => (45, 100, 45, 45, 45, 10),
+            PokemonName::VIBRAVA => (50, 70, 50, 50, 50, 70),
+            PokemonName::FLYGON => (80, 100, 80, 80, 80, 100),
+            PokemonName::CACNEA => (50, 85, 40, 85, 40, 35),
+            PokemonName::CACTURNE => (70, 115, 60, 115, 60, 55),
+            PokemonName::SWABLU => (45, 40, 60, 40, 75, 50),
+            PokemonName::ALTARIA => (75, 70, 90, 70, 105, 80),
+            PokemonName::ALTARIAMEGA => (75, 110, 110, 110, 105, 80),
+            PokemonName::ZANGOOSE => (73, 115, 60, 60, 60, 90),
+            PokemonName::SEVIPER => (73, 100, 60, 100, 60, 65),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::LUNATONE => (70, 55, 65, 95, 85, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::LUNATONE => (90, 55, 65, 95, 85, 70),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::SOLROCK => (70, 95, 85, 55, 65, 70),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::SOLROCK => (90, 95, 85, 55, 65, 70),
+            PokemonName::BARBOACH => (50, 48, 43, 46, 41, 60),
+            PokemonName::WHISCASH => (110, 78, 73, 76, 71, 60),
+            PokemonName::CORPHISH => (43, 80, 65, 50, 35, 35),
+            PokemonName::CRAWDAUNT => (63, 120, 85, 90, 55, 55),
+            PokemonName::BALTOY => (40, 40, 55, 40, 70, 55),
+            PokemonName::CLAYDOL => (60, 70, 105, 70, 120, 75),
+            PokemonName::LILEEP => (66, 41, 77, 61, 87, 23),
+            PokemonName::CRADILY => (86, 81, 97, 81, 107, 43),
+            PokemonName::ANORITH => (45, 95, 50, 40, 50, 75),
+            PokemonName::ARMALDO => (75, 125, 100, 70, 80, 45),
+            PokemonName::FEEBAS => (20, 15, 20, 10, 55, 80),
+            PokemonName::MILOTIC => (95, 60, 79, 100, 125, 81),
+            PokemonName::CASTFORM => (70, 70, 70, 70, 70, 70),
+            PokemonName::CASTFORMSUNNY => (70, 70, 70, 70, 70, 70),
+            PokemonName::CASTFORMRAINY => (70, 70, 70, 70, 70, 70),
+            PokemonName::CASTFORMSNOWY => (70, 70, 70, 70, 70, 70),
+            PokemonName::KECLEON => (60, 90, 70, 60, 120, 40),
+            PokemonName::SHUPPET => (44, 75, 35, 63, 33, 45),
+            PokemonName::BANETTE => (64, 115, 65, 83, 63, 65),
+            PokemonName::BANETTEMEGA => (64, 165, 75, 93, 83, 75),
+            PokemonName::DUSKULL => (20, 40, 90, 30, 90, 25),
+            PokemonName::DUSCLOPS => (40, 70, 130, 60, 130, 25),
+            PokemonName::TROPIUS => (99, 68, 83, 72, 87, 51),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::CHIMECHO => (65, 50, 70, 95, 80, 65),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::CHIMECHO => (75, 50, 80, 95, 90, 65),
+            PokemonName::ABSOL => (65, 130, 60, 75, 60, 75),
+            PokemonName::ABSOLMEGA => (65, 150, 60, 115, 60, 115),
+            PokemonName::WYNAUT => (95, 23, 48, 23, 48, 23),
+            PokemonName::SNORUNT => (50, 50, 50, 50, 50, 50),
+            PokemonName::GLALIE => (80, 80, 80, 80, 80, 80),
+            PokemonName::GLALIEMEGA => (80, 120, 80, 120, 80, 100),
+            PokemonName::SPHEAL => (70, 40, 50, 55, 50, 25),
+            PokemonName::SEALEO => (90, 60, 70, 75, 70, 45),
+            PokemonName::WALREIN => (110, 80, 90, 95, 90, 65),
+            PokemonName::CLAMPERL => (35, 64, 85, 74, 55, 32),
+            PokemonName::HUNTAIL => (55, 104, 105, 94, 75, 52),
+            PokemonName::GOREBYSS => (55, 84, 105, 114, 75, 52),
+            PokemonName::RELICANTH => (100, 90, 130, 45, 65, 55),
+            PokemonName::LUVDISC => (43, 30, 55, 40, 65, 97),
+            PokemonName::BAGON => (45, 75, 60, 40, 30, 50),
+            PokemonName::SHELGON => (65, 95, 100, 60, 50, 50),
+            PokemonName::SALAMENCE => (95, 135, 80, 110, 80, 100),
+            PokemonName::SALAMENCEMEGA => (95, 145, 130, 120, 90, 120),
+            PokemonName::BELDUM => (40, 55, 80, 35, 60, 30),
+            PokemonName::METANG => (60, 75, 100, 55, 80, 50),
+            PokemonName::METAGROSS => (80, 135, 130, 95, 90, 70),
+            PokemonName::METAGROSSMEGA => (80, 145, 150, 105, 110, 110),
+            PokemonName::REGIROCK => (80, 100, 200, 50, 100, 50),
+            PokemonName::REGICE => (80, 50, 100, 100, 200, 50),
+            PokemonName::REGISTEEL => (80, 75, 150, 75, 150, 50),
+            PokemonName::LATIAS => (80, 80, 90, 110, 130, 110),
+            PokemonName::LATIASMEGA => (80, 100, 120, 140, 150, 110),
+            PokemonName::LATIOS => (80, 90, 80, 130, 110, 110),
+            PokemonName::LATIOSMEGA => (80, 130, 100, 160, 120, 110),
+            PokemonName::KYOGRE => (100, 100, 90, 150, 140, 90),
+            PokemonName::KYOGREPRIMAL => (100, 150, 90, 180, 160, 90),
+            PokemonName::GROUDON => (100, 150, 140, 100, 90, 90),
+            PokemonName::GROUDONPRIMAL => (100, 180, 160, 150, 90, 90),
+            PokemonName::RAYQUAZA => (105, 150, 90, 150, 90, 95),
+            PokemonName::RAYQUAZAMEGA => (105, 180, 100, 180, 100, 115),
+            PokemonName::JIRACHI => (100, 100, 100, 100, 100, 100),
+            PokemonName::DEOXYS => (50, 150, 50, 150, 50, 150),
+            PokemonName::DEOXYSATTACK => (50, 180, 20, 180, 20, 150),
+            PokemonName::DEOXYSDEFENSE => (50, 70, 160, 70, 160, 90),
+            PokemonName::DEOXYSSPEED => (50, 95, 90, 95, 90, 180),
+            PokemonName::TURTWIG => (55, 68, 64, 45, 55, 31),
+            PokemonName::GROTLE => (75, 89, 85, 55, 65, 36),
+            PokemonName::TORTERRA => (95, 109, 105, 75, 85, 56),
+            PokemonName::CHIMCHAR => (44, 58, 44, 58, 44, 61),
+            PokemonName::MONFERNO => (64, 78, 52, 78, 52, 81),
+            PokemonName::INFERNAPE => (76, 104, 71, 104, 71, 108),
+            PokemonName::PIPLUP => (53, 51, 53, 61, 56, 40),
+            PokemonName::PRINPLUP => (64, 66, 68, 81, 76, 50),
+            PokemonName::EMPOLEON => (84, 86, 88, 111, 101, 60),
+            PokemonName::STARLY => (40, 55, 30, 30, 30, 60),
+            PokemonName::STARAVIA => (55, 75, 50, 40, 40, 80),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::STARAPTOR => (85, 120, 70, 50, 50, 100),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::STARAPTOR => (85, 120, 70, 50, 60, 100),
+            PokemonName::BIDOOF => (59, 45, 40, 35, 40, 31),
+            PokemonName::BIBAREL => (79, 85, 60, 55, 60, 71),
+            PokemonName::KRICKETOT => (37, 25, 41, 25, 41, 25),
+            PokemonName::KRICKETUNE => (77, 85, 51, 55, 51, 65),
+            PokemonName::SHINX => (45, 65, 34, 40, 34, 45),
+            PokemonName::LUXIO => (60, 85, 49, 60, 49, 60),
+            PokemonName::LUXRAY => (80, 120, 79, 95, 79, 70),
+            PokemonName::BUDEW => (40, 30, 35, 50, 70, 55),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::ROSERADE => (60, 70, 55, 125, 105, 90),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::ROSERADE => (60, 70, 65, 125, 105, 90),
+            PokemonName::CRANIDOS => (67, 125, 40, 30, 30, 58),
+            PokemonName::RAMPARDOS => (97, 165, 60, 65, 50, 58),
+            PokemonName::SHIELDON => (30, 42, 118, 42, 88, 30),
+            PokemonName::BASTIODON => (60, 52, 168, 47, 138, 30),
+            PokemonName::BURMY => (40, 29, 45, 29, 45, 36),
+            PokemonName::BURMYSANDY => (40, 29, 45, 29, 45, 36),
+            PokemonName::BURMYTRASH => (40, 29, 45, 29, 45, 36),
+            PokemonName::WORMADAM => (60, 59, 85, 79, 105, 36),
+            PokemonName::WORMADAMSANDY => (60, 79, 105, 59, 85, 36),
+            PokemonName::WORMADAMTRASH => (60, 69, 95, 69, 95, 36),
+            PokemonName::MOTHIM => (70, 94, 50, 94, 50, 66),
+            PokemonName::COMBEE => (30, 30, 42, 30, 42, 70),
+            PokemonName::VESPIQUEN => (70, 80, 102, 80, 102, 40),
+            PokemonName::PACHIRISU => (60, 45, 70, 45, 90, 95),
+            PokemonName::BUIZEL => (55, 65, 35, 60, 30, 85),
+            PokemonName::FLOATZEL => (85, 105, 55, 85, 50, 115),
+            PokemonName::CHERUBI => (45, 35, 45, 62, 53, 35),
+            PokemonName::CHERRIM => (70, 60, 70, 87, 78, 85),
+            PokemonName::CHERRIMSUNSHINE => (70, 60, 70, 87, 78, 85),
+            PokemonName::SHELLOS => (76, 48, 48, 57, 62, 34),
+            PokemonName::SHELLOSEAST => (76, 48, 48, 57, 62, 34),
+            PokemonName::GASTRODON => (111, 83, 68, 92, 82, 39),
+            PokemonName::GASTRODONEAST => (111, 83, 68, 92, 82, 39),
+            PokemonName::AMBIPOM => (75, 100, 66, 60, 66, 115),
+            PokemonName::DRIFLOON => (90, 50, 34, 60, 44, 70),
+            PokemonName::DRIFBLIM => (150, 80, 44, 90, 54, 80),
+            PokemonName::BUNEARY => (55, 66, 44, 44, 56, 85),
+            PokemonName::LOPUNNY => (65, 76, 84, 54, 96, 105),
+            PokemonName::LOPUNNYMEGA => (65, 136, 94, 54, 96, 135),
+            PokemonName::MISMAGIUS => (60, 60, 60, 105, 105, 105),
+            PokemonName::HONCHKROW => (100, 125, 52, 105, 52, 71),
+            PokemonName::GLAMEOW => (49, 55, 42, 42, 37, 85),
+            PokemonName::PURUGLY => (71, 82, 64, 64, 59, 112),
+            PokemonName::CHINGLING => (45, 30, 50, 65, 50, 45),
+            PokemonName::STUNKY => (63, 63, 47, 41, 41, 74),
+            PokemonName::SKUNTANK => (103, 93, 67, 71, 61, 84),
+            PokemonName::BRONZOR => (57, 24, 86, 24, 86, 23),
+            PokemonName::BRONZONG => (67, 89, 116, 79, 116, 33),
+            PokemonName::BONSLY => (50, 80, 95, 10, 45, 10),
+            PokemonName::MIMEJR => (20, 25, 45, 70, 90, 60),
+            PokemonName::HAPPINY => (100, 5, 5, 15, 65, 30),
+            PokemonName::CHATOT => (76, 65, 45, 92, 42, 91),
+            PokemonName::SPIRITOMB => (50, 92, 108, 92, 108, 35),
+            PokemonName::GIBLE => (58, 70, 45, 40, 45, 42),
+            PokemonName::GABITE => (68, 90, 65, 50, 55, 82),
+            PokemonName::GARCHOMP => (108, 130, 95, 80, 85, 102),
+            PokemonName::GARCHOMPMEGA => (108, 170, 115, 120, 95, 92),
+            PokemonName::MUNCHLAX => (135, 85, 40, 40, 85, 5),
+            PokemonName::RIOLU => (40, 70, 40, 35, 40, 60),
+            PokemonName::LUCARIO => (70, 110, 70, 115, 70, 90),
+            PokemonName::LUCARIOMEGA => (70, 145, 88, 140, 70, 112),
+            PokemonName::HIPPOPOTAS => (68, 72, 78, 38, 42, 32),
+            PokemonName::HIPPOWDON => (108, 112, 118, 68, 72, 47),
+            PokemonName::SKORUPI => (40, 50, 90, 30, 55, 65),
+            PokemonName::DRAPION => (70, 90, 110, 60, 75, 95),
+            PokemonName::CROAGUNK => (48, 61, 40, 61, 40, 50),
+            PokemonName::TOXICROAK => (83, 106, 65, 86, 65, 85),
+            PokemonName::CARNIVINE => (74, 100, 72, 90, 72, 46),
+            PokemonName::FINNEON => (49, 49, 56, 49, 61, 66),
+            PokemonName::LUMINEON => (69, 69, 76, 69, 86, 91),
+            PokemonName::MANTYKE => (45, 20, 50, 60, 120, 50),
+            PokemonName::SNOVER => (60, 62, 50, 62, 60, 40),
+            PokemonName::ABOMASNOW => (90, 92, 75, 92, 85, 60),
+            PokemonName::ABOMASNOWMEGA => (90, 132, 105, 132, 105, 30),
+            PokemonName::WEAVILE => (70, 120, 65, 45, 85, 125),
+            PokemonName::MAGNEZONE => (70, 70, 115, 130, 90, 60),
+            PokemonName::LICKILICKY => (110, 85, 95, 80, 95, 50),
+            PokemonName::RHYPERIOR => (115, 140, 130, 55, 55, 40),
+            PokemonName::TANGROWTH => (100, 100, 125, 110, 50, 50),
+            PokemonName::ELECTIVIRE => (75, 123, 67, 95, 85, 95),
+            PokemonName::MAGMORTAR => (75, 95, 67, 125, 95, 83),
+            PokemonName::TOGEKISS => (85, 50, 95, 120, 115, 80),
+            PokemonName::YANMEGA => (86, 76, 86, 116, 56, 95),
+            PokemonName::LEAFEON => (65, 110, 130, 60, 65, 95),
+            PokemonName::GLACEON => (65, 60, 110, 130, 95, 65),
+            PokemonName::GLISCOR => (75, 95, 125, 45, 75, 95),
+            PokemonName::MAMOSWINE => (110, 130, 80, 70, 60, 80),
+            PokemonName::PORYGONZ => (85, 80, 70, 135, 75, 90),
+            PokemonName::GALLADE => (68, 125, 65, 65, 115, 80),
+            PokemonName::GALLADEMEGA => (68, 165, 95, 65, 115, 110),
+            PokemonName::PROBOPASS => (60, 55, 145, 75, 150, 40),
+            PokemonName::DUSKNOIR => (45, 100, 135, 65, 135, 45),
+            PokemonName::FROSLASS => (70, 80, 70, 80, 70, 110),
+            PokemonName::ROTOM => (50, 50, 77, 95, 77, 91),
+            PokemonName::ROTOMHEAT => (50, 65, 107, 105, 107, 86),
+            PokemonName::ROTOMWASH => (50, 65, 107, 105, 107, 86),
+            PokemonName::ROTOMFROST => (50, 65, 107, 105, 107, 86),
+            PokemonName::ROTOMFAN => (50, 65, 107, 105, 107, 86),
+            PokemonName::ROTOMMOW => (50, 65, 107, 105, 107, 86),
+            PokemonName::UXIE => (75, 75, 130, 75, 130, 95),
+            PokemonName::MESPRIT => (80, 105, 105, 105, 105, 80),
+            PokemonName::AZELF => (75, 125, 70, 125, 70, 115),
+            PokemonName::DIALGA => (100, 120, 120, 150, 100, 90),
+            PokemonName::DIALGAORIGIN => (100, 100, 120, 150, 120, 90),
+            PokemonName::PALKIA => (90, 120, 100, 150, 120, 100),
+            PokemonName::PALKIAORIGIN => (90, 100, 100, 150, 120, 120),
+            PokemonName::HEATRAN => (91, 90, 106, 130, 106, 77),
+            PokemonName::REGIGIGAS => (110, 160, 110, 80, 110, 100),
+            PokemonName::GIRATINA => (150, 100, 120, 100, 120, 90),
+            PokemonName::GIRATINAORIGIN => (150, 120, 100, 120, 100, 90),
+            PokemonName::CRESSELIA => (120, 70, 110, 75, 120, 85),
+            PokemonName::PHIONE => (80, 80, 80, 80, 80, 80),
+            PokemonName::MANAPHY => (100, 100, 100, 100, 100, 100),
+            PokemonName::DARKRAI => (70, 90, 90, 135, 90, 125),
+            PokemonName::SHAYMIN => (100, 100, 100, 100, 100, 100),
+            PokemonName::SHAYMINSKY => (100, 103, 75, 120, 75, 127),
+            PokemonName::ARCEUS => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSBUG => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSDARK => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSDRAGON => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSELECTRIC => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSFAIRY => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSFIGHTING => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSFIRE => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSFLYING => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSGHOST => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSGRASS => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSGROUND => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSICE => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSPOISON => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSPSYCHIC => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSROCK => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSSTEEL => (120, 120, 120, 120, 120, 120),
+            PokemonName::ARCEUSWATER => (120, 120, 120, 120, 120, 120),
+            PokemonName::VICTINI => (100, 100, 100, 100, 100, 100),
+            PokemonName::SNIVY => (45, 45, 55, 45, 55, 63),
+            PokemonName::SERVINE => (60, 60, 75, 60, 75, 83),
+            PokemonName::SERPERIOR => (75, 75, 95, 75, 95, 113),
+            PokemonName::TEPIG => (65, 63, 45, 45, 45, 45),
+            PokemonName::PIGNITE => (90, 93, 55, 70, 55, 55),
+            PokemonName::EMBOAR => (110, 123, 65, 100, 65, 65),
+            PokemonName::OSHAWOTT => (55, 55, 45, 63, 45, 45),
+            PokemonName::DEWOTT => (75, 75, 60, 83, 60, 60),
+            PokemonName::SAMUROTT => (95, 100, 85, 108, 70, 70),
+            PokemonName::SAMUROTTHISUI => (90, 108, 80, 100, 65, 85),
+            PokemonName::PATRAT => (45, 55, 39, 35, 39, 42),
+            PokemonName::WATCHOG => (60, 85, 69, 60, 69, 77),
+            PokemonName::LILLIPUP => (45, 60, 45, 25, 45, 55),
+            PokemonName::HERDIER => (65, 80, 65, 35, 65, 60),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::STOUTLAND => (85, 100, 90, 45, 90, 80),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::STOUTLAND => (85, 110, 90, 45, 90, 80),
+            PokemonName::PURRLOIN => (41, 50, 37, 50, 37, 66),
+            PokemonName::LIEPARD => (64, 88, 50, 88, 50, 106),
+            PokemonName::PANSAGE => (50, 53, 48, 53, 48, 64),
+            PokemonName::SIMISAGE => (75, 98, 63, 98, 63, 101),
+            PokemonName::PANSEAR => (50, 53, 48, 53, 48, 64),
+            PokemonName::SIMISEAR => (75, 98, 63, 98, 63, 101),
+            PokemonName::PANPOUR => (50, 53, 48, 53, 48, 64),
+            PokemonName::SIMIPOUR => (75, 98, 63, 98, 63, 101),
+            PokemonName::MUNNA => (76, 25, 45, 67, 55, 24),
+            PokemonName::MUSHARNA => (116, 55, 85, 107, 95, 29),
+            PokemonName::PIDOVE => (50, 55, 50, 36, 30, 43),
+            PokemonName::TRANQUILL => (62, 77, 62, 50, 42, 65),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::UNFEZANT => (80, 105, 80, 65, 55, 93),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::UNFEZANT => (80, 115, 80, 65, 55, 93),
+            PokemonName::BLITZLE => (45, 60, 32, 50, 32, 76),
+            PokemonName::ZEBSTRIKA => (75, 100, 63, 80, 63, 116),
+            PokemonName::ROGGENROLA => (55, 75, 85, 25, 25, 15),
+            PokemonName::BOLDORE => (70, 105, 105, 50, 40, 20),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::GIGALITH => (85, 135, 130, 60, 70, 25),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::GIGALITH => (85, 135, 130, 60, 80, 25),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::WOOBAT => (55, 45, 43, 55, 43, 72),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::WOOBAT => (65, 45, 43, 55, 43, 72),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::SWOOBAT => (67, 57, 55, 77, 55, 114),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::SWOOBAT => (77, 57, 55, 77, 55, 114),
+            PokemonName::DRILBUR => (60, 85, 40, 30, 45, 68),
+            PokemonName::EXCADRILL => (110, 135, 60, 50, 65, 88),
+            PokemonName::AUDINO => (103, 60, 86, 60, 86, 50),
+            PokemonName::AUDINOMEGA => (103, 60, 126, 80, 126, 50),
+            PokemonName::TIMBURR => (75, 80, 55, 25, 35, 35),
+            PokemonName::GURDURR => (85, 105, 85, 40, 50, 40),
+            PokemonName::CONKELDURR => (105, 140, 95, 55, 65, 45),
+            PokemonName::TYMPOLE => (50, 50, 40, 50, 40, 64),
+            PokemonName::PALPITOAD => (75, 65, 55, 65, 55, 69),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::SEISMITOAD => (105, 85, 75, 85, 75, 74),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::SEISMITOAD => (105, 95, 75, 85, 75, 74),
+            PokemonName::THROH => (120, 100, 85, 30, 85, 45),
+            PokemonName::SAWK => (75, 125, 75, 30, 75, 85),
+            PokemonName::SEWADDLE => (45, 53, 70, 40, 60, 42),
+            PokemonName::SWADLOON => (55, 63, 90, 50, 80, 42),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::LEAVANNY => (75, 103, 80, 70, 70, 92),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::LEAVANNY => (75, 103, 80, 70, 80, 92),
+            PokemonName::VENIPEDE => (30, 45, 59, 30, 39, 57),
+            PokemonName::WHIRLIPEDE => (40, 55, 99, 40, 79, 47),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::SCOLIPEDE => (60, 90, 89, 55, 69, 112),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::SCOLIPEDE => (60, 100, 89, 55, 69, 112),
+            PokemonName::COTTONEE => (40, 27, 60, 37, 50, 66),
+            PokemonName::WHIMSICOTT => (60, 67, 85, 77, 75, 116),
+            PokemonName::PETILIL => (45, 35, 50, 70, 50, 30),
+            PokemonName::LILLIGANT => (70, 60, 75, 110, 75, 90),
+            PokemonName::LILLIGANTHISUI => (70, 105, 75, 50, 75, 105),
+            PokemonName::BASCULIN => (70, 92, 65, 80, 55, 98),
+            PokemonName::BASCULINBLUESTRIPED => (70, 92, 65, 80, 55, 98),
+            PokemonName::BASCULINWHITESTRIPED => (70, 92, 65, 80, 55, 98),
+            PokemonName::SANDILE => (50, 72, 35, 35, 35, 65),
+            PokemonName::KROKOROK => (60, 82, 45, 45, 45, 74),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::KROOKODILE => (95, 117, 70, 65, 70, 92),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::KROOKODILE => (95, 117, 80, 65, 70, 92),
+            PokemonName::DARUMAKA => (70, 90, 45, 15, 45, 50),
+            PokemonName::DARUMAKAGALAR => (70, 90, 45, 15, 45, 50),
+            PokemonName::DARMANITAN => (105, 140, 55, 30, 55, 95),
+            PokemonName::DARMANITANZEN => (105, 30, 105, 140, 105, 55),
+            PokemonName::DARMANITANGALAR => (105, 140, 55, 30, 55, 95),
+            PokemonName::DARMANITANGALARZEN => (105, 160, 55, 30, 55, 135),
+            PokemonName::MARACTUS => (75, 86, 67, 106, 67, 60),
+            PokemonName::DWEBBLE => (50, 65, 85, 35, 35, 55),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::CRUSTLE => (70, 95, 125, 65, 75, 45),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::CRUSTLE => (70, 105, 125, 65, 75, 45),
+            PokemonName::SCRAGGY => (50, 75, 70, 35, 70, 48),
+            PokemonName::SCRAFTY => (65, 90, 115, 45, 115, 58),
+            PokemonName::SIGILYPH => (72, 58, 80, 103, 80, 97),
+            PokemonName::YAMASK => (38, 30, 85, 55, 65, 30),
+            PokemonName::YAMASKGALAR => (38, 55, 85, 30, 65, 30),
+            PokemonName::COFAGRIGUS => (58, 50, 145, 95, 105, 30),
+            PokemonName::TIRTOUGA => (54, 78, 103, 53, 45, 22),
+            PokemonName::CARRACOSTA => (74, 108, 133, 83, 65, 32),
+            PokemonName::ARCHEN => (55, 112, 45, 74, 45, 70),
+            PokemonName::ARCHEOPS => (75, 140, 65, 112, 65, 110),
+            PokemonName::TRUBBISH => (50, 50, 62, 40, 62, 65),
+            PokemonName::GARBODOR => (80, 95, 82, 60, 82, 75),
+            PokemonName::GARBODORGMAX => (80, 95, 82, 60, 82, 75),
+            PokemonName::ZORUA => (40, 65, 40, 80, 40, 65),
+            PokemonName::ZORUAHISUI => (35, 60, 40, 85, 40, 70),
+            PokemonName::ZOROARK => (60, 105, 60, 120, 60, 105),
+            PokemonName::ZOROARKHISUI => (55, 100, 60, 125, 60, 110),
+            PokemonName::MINCCINO => (55, 50, 40, 40, 40, 75),
+            PokemonName::CINCCINO => (75, 95, 60, 65, 60, 115),
+            PokemonName::GOTHITA => (45, 30, 50, 55, 65, 45),
+            PokemonName::GOTHORITA => (60, 45, 70, 75, 85, 55),
+            PokemonName::GOTHITELLE => (70, 55, 95, 95, 110, 65),
+            PokemonName::SOLOSIS => (45, 30, 40, 105, 50, 20),
+            PokemonName::DUOSION => (65, 40, 50, 125, 60, 30),
+            PokemonName::REUNICLUS => (110, 65, 75, 125, 85, 30),
+            PokemonName::DUCKLETT => (62, 44, 50, 44, 50, 55),
+            PokemonName::SWANNA => (75, 87, 63, 87, 63, 98),
+            PokemonName::VANILLITE => (36, 50, 50, 65, 60, 44),
+            PokemonName::VANILLISH => (51, 65, 65, 80, 75, 59),
+            PokemonName::VANILLUXE => (71, 95, 85, 110, 95, 79),
+            PokemonName::DEERLING => (60, 60, 50, 40, 50, 75),
+            PokemonName::DEERLINGSUMMER => (60, 60, 50, 40, 50, 75),
+            PokemonName::DEERLINGAUTUMN => (60, 60, 50, 40, 50, 75),
+            PokemonName::DEERLINGWINTER => (60, 60, 50, 40, 50, 75),
+            PokemonName::SAWSBUCK => (80, 100, 70, 60, 70, 95),
+            PokemonName::SAWSBUCKSUMMER => (80, 100, 70, 60, 70, 95),
+            PokemonName::SAWSBUCKAUTUMN => (80, 100, 70, 60, 70, 95),
+            PokemonName::SAWSBUCKWINTER => (80, 100, 70, 60, 70, 95),
+            PokemonName::EMOLGA => (55, 75, 60, 75, 60, 103),
+            PokemonName::KARRABLAST => (50, 75, 45, 40, 45, 60),
+            PokemonName::ESCAVALIER => (70, 135, 105, 60, 105, 20),
+            PokemonName::FOONGUS => (69, 55, 45, 55, 55, 15),
+            PokemonName::AMOONGUSS => (114, 85, 70, 85, 80, 30),
+            PokemonName::FRILLISH => (55, 40, 50, 65, 85, 40),
+            PokemonName::JELLICENT => (100, 60, 70, 85, 105, 60),
+            PokemonName::ALOMOMOLA => (165, 75, 80, 40, 45, 65),
+            PokemonName::JOLTIK => (50, 47, 50, 57, 50, 65),
+            PokemonName::GALVANTULA => (70, 77, 60, 97, 60, 108),
+            PokemonName::FERROSEED => (44, 50, 91, 24, 86, 10),
+            PokemonName::FERROTHORN => (74, 94, 131, 54, 116, 20),
+            PokemonName::KLINK => (40, 55, 70, 45, 60, 30),
+            PokemonName::KLANG => (60, 80, 95, 70, 85, 50),
+            PokemonName::KLINKLANG => (60, 100, 115, 70, 85, 90),
+            PokemonName::TYNAMO => (35, 55, 40, 45, 40, 60),
+            PokemonName::EELEKTRIK => (65, 85, 70, 75, 70, 40),
+            PokemonName::EELEKTROSS => (85, 115, 80, 105, 80, 50),
+            PokemonName::ELGYEM => (55, 55, 55, 85, 55, 30),
+            PokemonName::BEHEEYEM => (75, 75, 75, 125, 95, 40),
+            PokemonName::LITWICK => (50, 30, 55, 65, 55, 20),
+            PokemonName::LAMPENT => (60, 40, 60, 95, 60, 55),
+            PokemonName::CHANDELURE => (60, 55, 90, 145, 90, 80),
+            PokemonName::AXEW => (46, 87, 60, 30, 40, 57),
+            PokemonName::FRAXURE => (66, 117, 70, 40, 50, 67),
+            PokemonName::HAXORUS => (76, 147, 90, 60, 70, 97),
+            PokemonName::CUBCHOO => (55, 70, 40, 60, 40, 40),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::BEARTIC => (95, 110, 80, 70, 80, 50),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::BEARTIC => (95, 130, 80, 70, 80, 50),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::CRYOGONAL => (70, 50, 30, 95, 135, 105),
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::CRYOGONAL => (80, 50, 50, 95, 135, 105),
+            PokemonName::SHELMET => (50, 40, 85, 40, 65, 25),
+            PokemonName::ACCELGOR => (80, 70, 40, 100, 60, 145),
+            PokemonName::STUNFISK => (109, 66, 84, 81, 99, 32),
+            PokemonName::STUNFISKGALAR => (109, 81, 99, 66, 84, 32),
+            PokemonName::MIENFOO => (45, 85, 50, 55, 50, 65),
+            PokemonName::MIENSHAO => (65, 125, 60, 95, 60, 105),
+            PokemonName::DRUDDIGON => (77, 120, 90, 60, 90, 48),
+            PokemonName::GOLETT => (59, 74, 50, 35, 50, 35),
+            PokemonName::GOLURK => (89, 124, 80, 55, 80, 55),
+            PokemonName::PAWNIARD => (45, 85, 70, 40, 40, 60),
+            PokemonName::BISHARP => (65, 125, 100, 60, 70, 70),
+            PokemonName::BOUFFALANT => (95, 110, 95, 40, 95, 55),
+            PokemonName::RUFFLET => (70, 83, 50, 37, 50, 60),
+            PokemonName::BRAVIARY => (100, 123, 75, 57, 75, 80),
+            PokemonName::BRAVIARYHISUI => (110, 83, 70, 112, 70, 65),
+            PokemonName::VULLABY => (70, 55, 75, 45, 65, 60),
+            PokemonName::MANDIBUZZ => (110, 65, 105, 55, 95, 80),
+            PokemonName::HEATMOR => (85, 97, 66, 105, 66, 65),
+            PokemonName::DURANT => (58, 109, 112, 48, 48, 109),
+            PokemonName::DEINO => (52, 65, 50, 45, 50, 38),
+            PokemonName::ZWEILOUS => (72, 85, 70, 65, 70, 58),
+            PokemonName::HYDREIGON => (92, 105, 90, 125, 90, 98),
+            PokemonName::LARVESTA => (55, 85, 55, 50, 55, 60),
+            PokemonName::VOLCARONA => (85, 60, 65, 135, 105, 100),
+            PokemonName::COBALION => (91, 90, 129, 90, 72, 108),
+            PokemonName::TERRAKION => (91, 129, 90, 72, 90, 108),
+            PokemonName::VIRIZION => (91, 90, 72, 90, 129, 108),
+            PokemonName::TORNADUS => (79, 115, 70, 125, 80, 111),
+            PokemonName::TORNADUSTHERIAN => (79, 100, 80, 110, 90, 121),
+            PokemonName::THUNDURUS => (79, 115, 70, 125, 80, 111),
+            PokemonName::THUNDURUSTHERIAN => (79, 105, 70, 145, 80, 101),
+            PokemonName::RESHIRAM => (100, 120, 100, 150, 120, 90),
+            PokemonName::ZEKROM => (100, 150, 120, 120, 100, 90),
+            PokemonName::LANDORUS => (89, 125, 90, 115, 80, 101),
+            PokemonName::LANDORUSTHERIAN => (89, 145, 90, 105, 80, 91),
+            PokemonName::KYUREM => (125, 130, 90, 130, 90, 95),
+            PokemonName::KYUREMBLACK => (125, 170, 100, 120, 90, 95),
+            PokemonName::KYUREMWHITE => (125, 120, 90, 170, 100, 95),
+            PokemonName::KELDEO => (91, 72, 90, 129, 90, 108),
+            PokemonName::KELDEORESOLUTE => (91, 72, 90, 129, 90, 108),
+            PokemonName::MELOETTA => (100, 77, 77, 128, 128, 90),
+            PokemonName::MELOETTAPIROUETTE => (100, 128, 90, 77, 77, 128),
+            PokemonName::GENESECT => (71, 120, 95, 120, 95, 99),
+            PokemonName::GENESECTDOUSE => (71, 120, 95, 120, 95, 99),
+            PokemonName::GENESECTSHOCK => (71, 120, 95, 120, 95, 99),
+            PokemonName::GENESECTBURN => (71, 120, 95, 120, 95, 99),
+            PokemonName::GENESECTCHILL => (71, 120, 95, 120, 95, 99),
+            PokemonName::CHESPIN => (56, 61, 65, 48, 45, 38),
+            PokemonName::QUILLADIN => (61, 78, 95, 56, 58, 57),
+            PokemonName::CHESNAUGHT => (88, 107, 122, 74, 75, 64),
+            PokemonName::FENNEKIN => (40, 45, 40, 62, 60, 60),
+            PokemonName::BRAIXEN => (59, 59, 58, 90, 70, 73),
+            PokemonName::DELPHOX => (75, 69, 72, 114, 100, 104),
+            PokemonName::FROAKIE => (41, 56, 40, 62, 44, 71),
+            PokemonName::FROGADIER => (54, 63, 52, 83, 56, 97),
+            PokemonName::GRENINJA => (72, 95, 67, 103, 71, 122),
+            PokemonName::GRENINJABOND => (72, 95, 67, 103, 71, 122),
+            PokemonName::GRENINJAASH => (72, 145, 67, 153, 71, 132),
+            PokemonName::BUNNELBY => (38, 36, 38, 32, 36, 57),
+            PokemonName::DIGGERSBY => (85, 56, 77, 50, 77, 78),
+            PokemonName::FLETCHLING => (45, 50, 43, 40, 38, 62),
+            PokemonName::FLETCHINDER => (62, 73, 55, 56, 52, 84),
+            PokemonName::TALONFLAME => (78, 81, 71, 74, 69, 126),
+            PokemonName::SCATTERBUG => (38, 35, 40, 27, 25, 35),
+            PokemonName::SPEWPA => (45, 22, 60, 27, 30, 29),
+            PokemonName::VIVILLON => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONARCHIPELAGO => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONCONTINENTAL => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONELEGANT => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONGARDEN => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONHIGHPLAINS => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONICYSNOW => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONJUNGLE => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONMARINE => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONMODERN => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONMONSOON => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONOCEAN => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONPOLAR => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONRIVER => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONSANDSTORM => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONSAVANNA => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONSUN => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONTUNDRA => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONFANCY => (80, 52, 50, 90, 50, 89),
+            PokemonName::VIVILLONPOKEBALL => (80, 52, 50, 90, 50, 89),
+            PokemonName::LITLEO => (62, 50, 58, 73, 54, 72),
+            PokemonName::PYROAR => (86, 68, 72, 109, 66, 106),
+            PokemonName::FLABEBE => (44, 38, 39, 61, 79, 42),
+            PokemonName::FLABEBEBLUE => (44, 38, 39, 61, 79, 42),
+            PokemonName::FLABEBEORANGE => (44, 38, 39, 61, 79, 42),
+            PokemonName::FLABEBEWHITE => (44, 38, 39, 61, 79, 42),
+            PokemonName::FLABEBEYELLOW => (44, 38, 39, 61, 79, 42),
+            PokemonName::FLOETTE => (54, 45, 47, 75, 98, 52),
+            PokemonName::FLOETTEBLUE => (54, 45, 47, 75, 98, 52),
+            PokemonName::FLOETTEORANGE => (54, 45, 47, 75, 98, 52),
+            PokemonName::FLOETTEWHITE => (54, 45, 47, 75, 98, 52),
+            PokemonName::FLOETTEYELLOW => (54, 45, 47, 75, 98, 52),
+            PokemonName::FLOETTEETERNAL => (74, 65, 67, 125, 128, 92),
+            PokemonName::FLORGES => (78, 65, 68, 112, 154, 75),
+            PokemonName::FLORGESBLUE => (78, 65, 68, 112, 154, 75),
+            PokemonName::FLORGESORANGE => (78, 65, 68, 112, 154, 75),
+            PokemonName::FLORGESWHITE => (78, 65, 68, 112, 154, 75),
+            PokemonName::FLORGESYELLOW => (78, 65, 68, 112, 154, 75),
+            PokemonName::SKIDDO => (66, 65, 48, 62, 57, 52),
+            PokemonName::GOGOAT => (123, 100, 62, 97, 81, 68),
+            PokemonName::PANCHAM => (67, 82, 62, 46, 48, 43),
+            PokemonName::PANGORO => (95, 124, 78, 69, 71, 58),
+            PokemonName::FURFROU => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUDANDY => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUDEBUTANTE => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUDIAMOND => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUHEART => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUKABUKI => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROULAREINE => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUMATRON => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUPHARAOH => (75, 80, 60, 65, 90, 102),
+            PokemonName::FURFROUSTAR => (75, 80, 60, 65, 90, 102),
+            PokemonName::ESPURR => (62, 48, 54, 63, 60, 68),
+            PokemonName::MEOWSTIC => (74, 48, 76, 83, 81, 104),
+            PokemonName::MEOWSTICF => (74, 48, 76, 83, 81, 104),
+            PokemonName::HONEDGE => (45, 80, 100, 35, 37, 28),
+            PokemonName::DOUBLADE => (59, 110, 150, 45, 49, 35),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6", feature = "gen7"))]
+            PokemonName::AEGISLASH => (60, 50, 150, 50, 150, 60),
+            #[cfg(not(any(
+                feature = "gen4",
+                feature = "gen5",
+                feature = "gen6",
+                feature = "gen7"
+            )))]
+            PokemonName::AEGISLASH => (60, 50, 140, 50, 140, 60),
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6", feature = "gen7"))]
+            PokemonName::AEGISLASHBLADE => (60, 150, 50, 150, 50, 60),
+            #[cfg(not(any(
+                feature = "gen4",
+                feature = "gen5",
+                feature = "gen6",
+                feature = "gen7"
+            )))]
+            PokemonName::AEGISLASHBLADE => (60, 140, 50, 140, 50, 60),
+            PokemonName::SPRITZEE => (78, 52, 60, 63, 65, 23),
+            PokemonName::AROMATISSE => (101, 72, 72, 99, 89, 29),
+            PokemonName::SWIRLIX => (62, 48, 66, 59, 57, 49),
+            PokemonName::SLURPUFF => (82, 80, 86, 85, 75, 72),
+            PokemonName::INKAY => (53, 54, 53, 37, 46, 45),
+            PokemonName::MALAMAR => (86, 92, 88, 68, 75, 73),
+            PokemonName::BINACLE => (42, 52, 67, 39, 56, 50),
+            PokemonName::BARBARACLE => (72, 105, 115, 54, 86, 68),
+            PokemonName::SKRELP => (50, 60, 60, 60, 60, 30),
+            PokemonName::DRAGALGE => (65, 75, 90, 97, 123, 44),
+            PokemonName::CLAUNCHER => (50, 53, 62, 58, 63, 44),
+            PokemonName::CLAWITZER => (71, 73, 88, 120, 89, 59),
+            PokemonName::HELIOPTILE => (44, 38, 33, 61, 43, 70),
+            PokemonName::HELIOLISK => (62, 55, 52, 109, 94, 109),
+            PokemonName::TYRUNT => (58, 89, 77, 45, 45, 48),
+            PokemonName::TYRANTRUM => (82, 121, 119, 69, 59, 71),
+            PokemonName::AMAURA => (77, 59, 50, 67, 63, 46),
+            PokemonName::AURORUS => (123, 77, 72, 99, 92, 58),
+            PokemonName::SYLVEON => (95, 65, 65, 110, 130, 60),
+            PokemonName::HAWLUCHA => (78, 92, 75, 74, 63, 118),
+            PokemonName::DEDENNE => (67, 58, 57, 81, 67, 101),
+            PokemonName::CARBINK => (50, 50, 150, 50, 150, 50),
+            PokemonName::GOOMY => (45, 50, 35, 55, 75, 40),
+            PokemonName::SLIGGOO => (68, 75, 53, 83, 113, 60),
+            PokemonName::SLIGGOOHISUI => (58, 75, 83, 83, 113, 40),
+            PokemonName::GOODRA => (90, 100, 70, 110, 150, 80),
+            PokemonName::GOODRAHISUI => (80, 100, 100, 110, 150, 60),
+            PokemonName::KLEFKI => (57, 80, 91, 80, 87, 75),
+            PokemonName::PHANTUMP => (43, 70, 48, 50, 60, 38),
+            PokemonName::TREVENANT => (85, 110, 76, 65, 82, 56),
+            PokemonName::PUMPKABOO => (49, 66, 70, 44, 55, 51),
+            PokemonName::PUMPKABOOSMALL => (44, 66, 70, 44, 55, 56),
+            PokemonName::PUMPKABOOLARGE => (54, 66, 70, 44, 55, 46),
+            PokemonName::PUMPKABOOSUPER => (59, 66, 70, 44, 55, 41),
+            PokemonName::GOURGEIST => (65, 90, 122, 58, 75, 84),
+            PokemonName::GOURGEISTSMALL => (55, 85, 122, 58, 75, 99),
+            PokemonName::GOURGEISTLARGE => (75, 95, 122, 58, 75, 69),
+            PokemonName::GOURGEISTSUPER => (85, 100, 122, 58, 75, 54),
+            PokemonName::BERGMITE => (55, 69, 85, 32, 35, 28),
+            PokemonName::AVALUGG => (95, 117, 184, 44, 46, 28),
+            PokemonName::AVALUGGHISUI => (95, 127, 184, 34, 36, 38),
+            PokemonName::NOIBAT => (40, 30, 35, 45, 40, 55),
+            PokemonName::NOIVERN => (85, 70, 80, 97, 80, 123),
+            PokemonName::XERNEAS => (126, 131, 95, 131, 98, 99),
+            PokemonName::XERNEASNEUTRAL => (126, 131, 95, 131, 98, 99),
+            PokemonName::YVELTAL => (126, 131, 95, 131, 98, 99),
+            PokemonName::ZYGARDE => (108, 100, 121, 81, 95, 95),
+            PokemonName::ZYGARDE10 => (54, 100, 71, 61, 85, 115),
+            PokemonName::ZYGARDECOMPLETE => (216, 100, 121, 91, 95, 85),
+            PokemonName::DIANCIE => (50, 100, 150, 100, 150, 50),
+            PokemonName::DIANCIEMEGA => (50, 160, 110, 160, 110, 110),
+            PokemonName::HOOPA => (80, 110, 60, 150, 130, 70),
+            PokemonName::HOOPAUNBOUND => (80, 160, 60, 170, 130, 80),
+            PokemonName::VOLCANION => (80, 110, 120, 130, 90, 70),
+            PokemonName::ROWLET => (68, 55, 55, 50, 50, 42),
+            PokemonName::DARTRIX => (78, 75, 75, 70, 70, 52),
+            PokemonName::DECIDUEYE => (78, 107, 75, 100, 100, 70),
+            PokemonName::DECIDUEYEHISUI => (88, 112, 80, 95, 95, 60),
+            PokemonName::LITTEN => (45, 65, 40, 60, 40, 70),
+            PokemonName::TORRACAT => (65, 85, 50, 80, 50, 90),
+            PokemonName::INCINEROAR => (95, 115, 90, 80, 90, 60),
+            PokemonName::POPPLIO => (50, 54, 54, 66, 56, 40),
+            PokemonName::BRIONNE => (60, 69, 69, 91, 81, 50),
+            PokemonName::PRIMARINA => (80, 74, 74, 126, 116, 60),
+            PokemonName::PIKIPEK => (35, 75, 30, 30, 30, 65),
+            PokemonName::TRUMBEAK => (55, 85, 50, 40, 50, 75),
+            PokemonName::TOUCANNON => (80, 120, 75, 75, 75, 60),
+            PokemonName::YUNGOOS => (48, 70, 30, 30, 30, 45),
+            PokemonName::GUMSHOOS => (88, 110, 60, 55, 60, 45),
+            PokemonName::GUMSHOOSTOTEM => (88, 110, 60, 55, 60, 45),
+            PokemonName::GRUBBIN => (47, 62, 45, 55, 45, 46),
+            PokemonName::CHARJABUG => (57, 82, 95, 55, 75, 36),
+            PokemonName::VIKAVOLT => (77, 70, 90, 145, 75, 43),
+            PokemonName::VIKAVOLTTOTEM => (77, 70, 90, 145, 75, 43),
+            PokemonName::CRABRAWLER => (47, 82, 57, 42, 47, 63),
+            PokemonName::CRABOMINABLE => (97, 132, 77, 62, 67, 43),
+            PokemonName::ORICORIO => (75, 70, 70, 98, 70, 93),
+            PokemonName::ORICORIOPOMPOM => (75, 70, 70, 98, 70, 93),
+            PokemonName::ORICORIOPAU => (75, 70, 70, 98, 70, 93),
+            PokemonName::ORICORIOSENSU => (75, 70, 70, 98, 70, 93),
+            PokemonName::CUTIEFLY => (40, 45, 40, 55, 40, 84),
+            PokemonName::RIBOMBEE => (60, 55, 60, 95, 70, 124),
+            PokemonName::RIBOMBEETOTEM => (60, 55, 60, 95, 70, 124),
+            PokemonName::ROCKRUFF => (45, 65, 40, 30, 40, 60),
+            PokemonName::LYCANROC => (75, 115, 65, 55, 65, 112),
+            PokemonName::LYCANROCMIDNIGHT => (85, 115, 75, 55, 75, 82),
+            PokemonName::LYCANROCDUSK => (75, 117, 65, 55, 65, 110),
             PokemonName::WISHIWASHI => (45, 20, 20, 25, 25, 40),
             PokemonName::WISHIWASHISCHOOL => (45, 140, 130, 140, 135, 30),
-            PokemonName::PALAFIN => (100, 70, 72, 53, 62, 100),
-            PokemonName::PALAFINHERO => (100, 160, 97, 106, 87, 100),
+            PokemonName::MAREANIE => (50, 53, 62, 43, 52, 45),
+            PokemonName::TOXAPEX => (50, 63, 152, 53, 142, 35),
+            PokemonName::MUDBRAY => (70, 100, 70, 45, 55, 45),
+            PokemonName::MUDSDALE => (100, 125, 100, 55, 85, 35),
+            PokemonName::DEWPIDER => (38, 40, 52, 40, 72, 27),
+            PokemonName::ARAQUANID => (68, 70, 92, 50, 132, 42),
+            PokemonName::ARAQUANIDTOTEM => (68, 70, 92, 50, 132, 42),
+            PokemonName::FOMANTIS => (40, 55, 35, 50, 35, 35),
+            PokemonName::LURANTIS => (70, 105, 90, 80, 90, 45),
+            PokemonName::LURANTISTOTEM => (70, 105, 90, 80, 90, 45),
+            PokemonName::MORELULL => (40, 35, 55, 65, 75, 15),
+            PokemonName::SHIINOTIC => (60, 45, 80, 90, 100, 30),
+            PokemonName::SALANDIT => (48, 44, 40, 71, 40, 77),
+            PokemonName::SALAZZLE => (68, 64, 60, 111, 60, 117),
+            PokemonName::SALAZZLETOTEM => (68, 64, 60, 111, 60, 117),
+            PokemonName::STUFFUL => (70, 75, 50, 45, 50, 50),
+            PokemonName::BEWEAR => (120, 125, 80, 55, 60, 60),
+            PokemonName::BOUNSWEET => (42, 30, 38, 30, 38, 32),
+            PokemonName::STEENEE => (52, 40, 48, 40, 48, 62),
+            PokemonName::TSAREENA => (72, 120, 98, 50, 98, 72),
+            PokemonName::COMFEY => (51, 52, 90, 82, 110, 100),
+            PokemonName::ORANGURU => (90, 60, 80, 90, 110, 60),
+            PokemonName::PASSIMIAN => (100, 120, 90, 40, 60, 80),
+            PokemonName::WIMPOD => (25, 35, 40, 20, 30, 80),
+            PokemonName::GOLISOPOD => (75, 125, 140, 60, 90, 40),
+            PokemonName::SANDYGAST => (55, 55, 80, 70, 45, 15),
+            PokemonName::PALOSSAND => (85, 75, 110, 100, 75, 35),
+            PokemonName::PYUKUMUKU => (55, 60, 130, 30, 130, 5),
+            PokemonName::TYPENULL => (95, 95, 95, 95, 95, 59),
+            PokemonName::SILVALLY => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYBUG => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYDARK => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYDRAGON => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYELECTRIC => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYFAIRY => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYFIGHTING => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYFIRE => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYFLYING => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYGHOST => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYGRASS => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYGROUND => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYICE => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYPOISON => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYPSYCHIC => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYROCK => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYSTEEL => (95, 95, 95, 95, 95, 95),
+            PokemonName::SILVALLYWATER => (95, 95, 95, 95, 95, 95),
+            PokemonName::MINIOR => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORORANGE => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORYELLOW => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORGREEN => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORBLUE => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORINDIGO => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORVIOLET => (60, 100, 60, 100, 60, 120),
+            PokemonName::MINIORMETEOR => (60, 60, 100, 60, 100, 60),
+            PokemonName::KOMALA => (65, 115, 65, 75, 95, 65),
+            PokemonName::TURTONATOR => (60, 78, 135, 91, 85, 36),
+            PokemonName::TOGEDEMARU => (65, 98, 63, 40, 73, 96),
+            PokemonName::TOGEDEMARUTOTEM => (65, 98, 63, 40, 73, 96),
+            PokemonName::MIMIKYU => (55, 90, 80, 50, 105, 96),
+            PokemonName::MIMIKYUBUSTED => (55, 90, 80, 50, 105, 96),
+            PokemonName::MIMIKYUTOTEM => (55, 90, 80, 50, 105, 96),
+            PokemonName::MIMIKYUBUSTEDTOTEM => (55, 90, 80, 50, 105, 96),
+            PokemonName::BRUXISH => (68, 105, 70, 70, 70, 92),
+            PokemonName::DRAMPA => (78, 60, 85, 135, 91, 36),
+            PokemonName::DHELMISE => (70, 131, 100, 86, 90, 40),
+            PokemonName::JANGMOO => (45, 55, 65, 45, 45, 45),
+            PokemonName::HAKAMOO => (55, 75, 90, 65, 70, 65),
+            PokemonName::KOMMOO => (75, 110, 125, 100, 105, 85),
+            PokemonName::KOMMOOTOTEM => (75, 110, 125, 100, 105, 85),
+            PokemonName::TAPUKOKO => (70, 115, 85, 95, 75, 130),
+            PokemonName::TAPULELE => (70, 85, 75, 130, 115, 95),
+            PokemonName::TAPUBULU => (70, 130, 115, 85, 95, 75),
+            PokemonName::TAPUFINI => (70, 75, 115, 95, 130, 85),
+            PokemonName::COSMOG => (43, 29, 31, 29, 31, 37),
+            PokemonName::COSMOEM => (43, 29, 131, 29, 131, 37),
+            PokemonName::SOLGALEO => (137, 137, 107, 113, 89, 97),
+            PokemonName::LUNALA => (137, 113, 89, 137, 107, 97),
+            PokemonName::NIHILEGO => (109, 53, 47, 127, 131, 103),
+            PokemonName::BUZZWOLE => (107, 139, 139, 53, 53, 79),
+            PokemonName::PHEROMOSA => (71, 137, 37, 137, 37, 151),
+            PokemonName::XURKITREE => (83, 89, 71, 173, 71, 83),
+            PokemonName::CELESTEELA => (97, 101, 103, 107, 101, 61),
+            PokemonName::KARTANA => (59, 181, 131, 59, 31, 109),
+            PokemonName::GUZZLORD => (223, 101, 53, 97, 53, 43),
+            PokemonName::NECROZMA => (97, 107, 101, 127, 89, 79),
+            PokemonName::NECROZMADUSKMANE => (97, 157, 127, 113, 109, 77),
+            PokemonName::NECROZMADAWNWINGS => (97, 113, 109, 157, 127, 77),
+            PokemonName::NECROZMAULTRA => (97, 167, 97, 167, 97, 129),
+            PokemonName::MAGEARNA => (80, 95, 115, 130, 115, 65),
+            PokemonName::MAGEARNAORIGINAL => (80, 95, 115, 130, 115, 65),
+            PokemonName::MARSHADOW => (90, 125, 80, 90, 90, 125),
+            PokemonName::POIPOLE => (67, 73, 67, 73, 67, 73),
+            PokemonName::NAGANADEL => (73, 73, 73, 127, 73, 121),
+            PokemonName::STAKATAKA => (61, 131, 211, 53, 101, 13),
+            PokemonName::BLACEPHALON => (53, 127, 53, 151, 79, 107),
+            PokemonName::ZERAORA => (88, 112, 75, 102, 80, 143),
+            PokemonName::MELTAN => (46, 65, 65, 55, 35, 34),
+            PokemonName::MELMETAL => (135, 143, 143, 80, 65, 34),
+            PokemonName::MELMETALGMAX => (135, 143, 143, 80, 65, 34),
+            PokemonName::GROOKEY => (50, 65, 50, 40, 40, 65),
+            PokemonName::THWACKEY => (70, 85, 70, 55, 60, 80),
+            PokemonName::RILLABOOM => (100, 125, 90, 60, 70, 85),
+            PokemonName::RILLABOOMGMAX => (100, 125, 90, 60, 70, 85),
+            PokemonName::SCORBUNNY => (50, 71, 40, 40, 40, 69),
+            PokemonName::RABOOT => (65, 86, 60, 55, 60, 94),
+            PokemonName::CINDERACE => (80, 116, 75, 65, 75, 119),
+            PokemonName::CINDERACEGMAX => (80, 116, 75, 65, 75, 119),
+            PokemonName::SOBBLE => (50, 40, 40, 70, 40, 70),
+            PokemonName::DRIZZILE => (65, 60, 55, 95, 55, 90),
+            PokemonName::INTELEON => (70, 85, 65, 125, 65, 120),
+            PokemonName::INTELEONGMAX => (70, 85, 65, 125, 65, 120),
+            PokemonName::SKWOVET => (70, 55, 55, 35, 35, 25),
+            PokemonName::GREEDENT => (120, 95, 95, 55, 75, 20),
+            PokemonName::ROOKIDEE => (38, 47, 35, 33, 35, 57),
+            PokemonName::CORVISQUIRE => (68, 67, 55, 43, 55, 77),
+            PokemonName::CORVIKNIGHT => (98, 87, 105, 53, 85, 67),
+            PokemonName::CORVIKNIGHTGMAX => (98, 87, 105, 53, 85, 67),
+            PokemonName::BLIPBUG => (25, 20, 20, 25, 45, 45),
+            PokemonName::DOTTLER => (50, 35, 80, 50, 90, 30),
+            PokemonName::ORBEETLE => (60, 45, 110, 80, 120, 90),
+            PokemonName::ORBEETLEGMAX => (60, 45, 110, 80, 120, 90),
+            PokemonName::NICKIT => (40, 28, 28, 47, 52, 50),
+            PokemonName::THIEVUL => (70, 58, 58, 87, 92, 90),
+            PokemonName::GOSSIFLEUR => (40, 40, 60, 40, 60, 10),
+            PokemonName::ELDEGOSS => (60, 50, 90, 80, 120, 60),
+            PokemonName::WOOLOO => (42, 40, 55, 40, 45, 48),
+            PokemonName::DUBWOOL => (72, 80, 100, 60, 90, 88),
+            PokemonName::CHEWTLE => (50, 64, 50, 38, 38, 44),
+            PokemonName::DREDNAW => (90, 115, 90, 48, 68, 74),
+            PokemonName::DREDNAWGMAX => (90, 115, 90, 48, 68, 74),
+            PokemonName::YAMPER => (59, 45, 50, 40, 50, 26),
+            PokemonName::BOLTUND => (69, 90, 60, 90, 60, 121),
+            PokemonName::ROLYCOLY => (30, 40, 50, 40, 50, 30),
+            PokemonName::CARKOL => (80, 60, 90, 60, 70, 50),
+            PokemonName::COALOSSAL => (110, 80, 120, 80, 90, 30),
+            PokemonName::COALOSSALGMAX => (110, 80, 120, 80, 90, 30),
+            PokemonName::APPLIN => (40, 40, 80, 40, 40, 20),
+            PokemonName::FLAPPLE => (70, 110, 80, 95, 60, 70),
+            PokemonName::FLAPPLEGMAX => (70, 110, 80, 95, 60, 70),
+            PokemonName::APPLETUN => (110, 85, 80, 100, 80, 30),
+            PokemonName::APPLETUNGMAX => (110, 85, 80, 100, 80, 30),
+            PokemonName::SILICOBRA => (52, 57, 75, 35, 50, 46),
+            PokemonName::SANDACONDA => (72, 107, 125, 65, 70, 71),
+            PokemonName::SANDACONDAGMAX => (72, 107, 125, 65, 70, 71),
+            PokemonName::CRAMORANT => (70, 85, 55, 85, 95, 85),
+            PokemonName::CRAMORANTGULPING => (70, 85, 55, 85, 95, 85),
+            PokemonName::CRAMORANTGORGING => (70, 85, 55, 85, 95, 85),
+            PokemonName::ARROKUDA => (41, 63, 40, 40, 30, 66),
+            PokemonName::BARRASKEWDA => (61, 123, 60, 60, 50, 136),
+            PokemonName::TOXEL => (40, 38, 35, 54, 35, 40),
+            PokemonName::TOXTRICITY => (75, 98, 70, 114, 70, 75),
+            PokemonName::TOXTRICITYLOWKEY => (75, 98, 70, 114, 70, 75),
+            PokemonName::TOXTRICITYGMAX => (75, 98, 70, 114, 70, 75),
+            PokemonName::TOXTRICITYLOWKEYGMAX => (75, 98, 70, 114, 70, 75),
+            PokemonName::SIZZLIPEDE => (50, 65, 45, 50, 50, 45),
+            PokemonName::CENTISKORCH => (100, 115, 65, 90, 90, 65),
+            PokemonName::CENTISKORCHGMAX => (100, 115, 65, 90, 90, 65),
+            PokemonName::CLOBBOPUS => (50, 68, 60, 50, 50, 32),
+            PokemonName::GRAPPLOCT => (80, 118, 90, 70, 80, 42),
+            PokemonName::SINISTEA => (40, 45, 45, 74, 54, 50),
+            PokemonName::SINISTEAANTIQUE => (40, 45, 45, 74, 54, 50),
+            PokemonName::POLTEAGEIST => (60, 65, 65, 134, 114, 70),
+            PokemonName::POLTEAGEISTANTIQUE => (60, 65, 65, 134, 114, 70),
+            PokemonName::HATENNA => (42, 30, 45, 56, 53, 39),
+            PokemonName::HATTREM => (57, 40, 65, 86, 73, 49),
+            PokemonName::HATTERENE => (57, 90, 95, 136, 103, 29),
+            PokemonName::HATTERENEGMAX => (57, 90, 95, 136, 103, 29),
+            PokemonName::IMPIDIMP => (45, 45, 30, 55, 40, 50),
+            PokemonName::MORGREM => (65, 60, 45, 75, 55, 70),
+            PokemonName::GRIMMSNARL => (95, 120, 65, 95, 75, 60),
+            PokemonName::GRIMMSNARLGMAX => (95, 120, 65, 95, 75, 60),
+            PokemonName::OBSTAGOON => (93, 90, 101, 60, 81, 95),
+            PokemonName::PERRSERKER => (70, 110, 100, 50, 60, 50),
+            PokemonName::CURSOLA => (60, 95, 50, 145, 130, 30),
+            PokemonName::SIRFETCHD => (62, 135, 95, 68, 82, 65),
+            PokemonName::MRRIME => (80, 85, 75, 110, 100, 70),
+            PokemonName::RUNERIGUS => (58, 95, 145, 50, 105, 30),
+            PokemonName::MILCERY => (45, 40, 40, 50, 61, 34),
+            PokemonName::ALCREMIE => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIERUBYCREAM => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIEMATCHACREAM => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIEMINTCREAM => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIELEMONCREAM => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIESALTEDCREAM => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIERUBYSWIRL => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIECARAMELSWIRL => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIERAINBOWSWIRL => (65, 60, 75, 110, 121, 64),
+            PokemonName::ALCREMIEGMAX => (65, 60, 75, 110, 121, 64),
+            PokemonName::FALINKS => (65, 100, 100, 70, 60, 75),
+            PokemonName::PINCURCHIN => (48, 101, 95, 91, 85, 15),
+            PokemonName::SNOM => (30, 25, 35, 45, 30, 20),
+            PokemonName::FROSMOTH => (70, 65, 60, 125, 90, 65),
+            PokemonName::STONJOURNER => (100, 125, 135, 20, 20, 70),
             PokemonName::EISCUE => (75, 80, 110, 65, 90, 50),
             PokemonName::EISCUENOICE => (75, 80, 70, 65, 50, 130),
-            _ => panic!("Base stats not implemented for {}", self),
+            PokemonName::INDEEDEE => (60, 65, 55, 105, 95, 95),
+            PokemonName::INDEEDEEF => (70, 55, 65, 95, 105, 85),
+            PokemonName::MORPEKO => (58, 95, 58, 70, 58, 97),
+            PokemonName::MORPEKOHANGRY => (58, 95, 58, 70, 58, 97),
+            PokemonName::CUFANT => (72, 80, 49, 40, 49, 40),
+            PokemonName::COPPERAJAH => (122, 130, 69, 80, 69, 30),
+            PokemonName::COPPERAJAHGMAX => (122, 130, 69, 80, 69, 30),
+            PokemonName::DRACOZOLT => (90, 100, 90, 80, 70, 75),
+            PokemonName::ARCTOZOLT => (90, 100, 90, 90, 80, 55),
+            PokemonName::DRACOVISH => (90, 90, 100, 70, 80, 75),
+            PokemonName::ARCTOVISH => (90, 90, 100, 80, 90, 55),
+            PokemonName::DURALUDON => (70, 95, 115, 120, 50, 85),
+            PokemonName::DURALUDONGMAX => (70, 95, 115, 120, 50, 85),
+            PokemonName::DREEPY => (28, 60, 30, 40, 30, 82),
+            PokemonName::DRAKLOAK => (68, 80, 50, 60, 50, 102),
+            PokemonName::DRAGAPULT => (88, 120, 75, 100, 75, 142),
+            PokemonName::ZACIAN => (92, 120, 115, 80, 115, 138),
+            PokemonName::ZACIANCROWNED => (92, 150, 115, 80, 115, 148),
+            PokemonName::ZAMAZENTA => (92, 120, 115, 80, 115, 138),
+            PokemonName::ZAMAZENTACROWNED => (92, 120, 140, 80, 140, 128),
+            PokemonName::ETERNATUS => (140, 85, 95, 145, 95, 130),
+            PokemonName::ETERNATUSETERNAMAX => (255, 115, 250, 125, 250, 130),
+            PokemonName::KUBFU => (60, 90, 60, 53, 50, 72),
+            PokemonName::URSHIFU => (100, 130, 100, 63, 60, 97),
+            PokemonName::URSHIFURAPIDSTRIKE => (100, 130, 100, 63, 60, 97),
+            PokemonName::URSHIFUGMAX => (100, 130, 100, 63, 60, 97),
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => (100, 130, 100, 63, 60, 97),
+            PokemonName::ZARUDE => (105, 120, 105, 70, 95, 105),
+            PokemonName::ZARUDEDADA => (105, 120, 105, 70, 95, 105),
+            PokemonName::REGIELEKI => (80, 100, 50, 100, 50, 200),
+            PokemonName::REGIDRAGO => (200, 100, 50, 100, 50, 80),
+            PokemonName::GLASTRIER => (100, 145, 130, 65, 110, 30),
+            PokemonName::SPECTRIER => (100, 65, 60, 145, 80, 130),
+            PokemonName::CALYREX => (100, 80, 80, 80, 80, 80),
+            PokemonName::CALYREXICE => (100, 165, 150, 85, 130, 50),
+            PokemonName::CALYREXSHADOW => (100, 85, 80, 165, 100, 150),
+            PokemonName::WYRDEER => (103, 105, 72, 105, 75, 65),
+            PokemonName::KLEAVOR => (70, 135, 95, 45, 70, 85),
+            PokemonName::URSALUNA => (130, 140, 105, 45, 80, 50),
+            PokemonName::URSALUNABLOODMOON => (113, 70, 120, 135, 65, 52),
+            PokemonName::BASCULEGION => (120, 112, 65, 80, 75, 78),
+            PokemonName::BASCULEGIONF => (120, 92, 65, 100, 75, 78),
+            PokemonName::SNEASLER => (80, 130, 60, 40, 80, 120),
+            PokemonName::OVERQWIL => (85, 115, 95, 65, 65, 85),
+            PokemonName::ENAMORUS => (74, 115, 70, 135, 80, 106),
+            PokemonName::ENAMORUSTHERIAN => (74, 115, 110, 135, 100, 46),
+            PokemonName::SPRIGATITO => (40, 61, 54, 45, 45, 65),
+            PokemonName::FLORAGATO => (61, 80, 63, 60, 63, 83),
+            PokemonName::MEOWSCARADA => (76, 110, 70, 81, 70, 123),
+            PokemonName::FUECOCO => (67, 45, 59, 63, 40, 36),
+            PokemonName::CROCALOR => (81, 55, 78, 90, 58, 49),
+            PokemonName::SKELEDIRGE => (104, 75, 100, 110, 75, 66),
+            PokemonName::QUAXLY => (55, 65, 45, 50, 45, 50),
+            PokemonName::QUAXWELL => (70, 85, 65, 65, 60, 65),
+            PokemonName::QUAQUAVAL => (85, 120, 80, 85, 75, 85),
+            PokemonName::LECHONK => (54, 45, 40, 35, 45, 35),
+            PokemonName::OINKOLOGNE => (110, 100, 75, 59, 80, 65),
+            PokemonName::OINKOLOGNEF => (115, 90, 70, 59, 90, 65),
+            PokemonName::TAROUNTULA => (35, 41, 45, 29, 40, 20),
+            PokemonName::SPIDOPS => (60, 79, 92, 52, 86, 35),
+            PokemonName::NYMBLE => (33, 46, 40, 21, 25, 45),
+            PokemonName::LOKIX => (71, 102, 78, 52, 55, 92),
+            PokemonName::PAWMI => (45, 50, 20, 40, 25, 60),
+            PokemonName::PAWMO => (60, 75, 40, 50, 40, 85),
+            PokemonName::PAWMOT => (70, 115, 70, 70, 60, 105),
+            PokemonName::TANDEMAUS => (50, 50, 45, 40, 45, 75),
+            PokemonName::MAUSHOLD => (74, 75, 70, 65, 75, 111),
+            PokemonName::MAUSHOLDFOUR => (74, 75, 70, 65, 75, 111),
+            PokemonName::FIDOUGH => (37, 55, 70, 30, 55, 65),
+            PokemonName::DACHSBUN => (57, 80, 115, 50, 80, 95),
+            PokemonName::SMOLIV => (41, 35, 45, 58, 51, 30),
+            PokemonName::DOLLIV => (52, 53, 60, 78, 78, 33),
+            PokemonName::ARBOLIVA => (78, 69, 90, 125, 109, 39),
+            PokemonName::SQUAWKABILLY => (82, 96, 51, 45, 51, 92),
+            PokemonName::SQUAWKABILLYBLUE => (82, 96, 51, 45, 51, 92),
+            PokemonName::SQUAWKABILLYYELLOW => (82, 96, 51, 45, 51, 92),
+            PokemonName::SQUAWKABILLYWHITE => (82, 96, 51, 45, 51, 92),
+            PokemonName::NACLI => (55, 55, 75, 35, 35, 25),
+            PokemonName::NACLSTACK => (60, 60, 100, 35, 65, 35),
+            PokemonName::GARGANACL => (100, 100, 130, 45, 90, 35),
+            PokemonName::CHARCADET => (40, 50, 40, 50, 40, 35),
+            PokemonName::ARMAROUGE => (85, 60, 100, 125, 80, 75),
+            PokemonName::CERULEDGE => (75, 125, 80, 60, 100, 85),
+            PokemonName::TADBULB => (61, 31, 41, 59, 35, 45),
+            PokemonName::BELLIBOLT => (109, 64, 91, 103, 83, 45),
+            PokemonName::WATTREL => (40, 40, 35, 55, 40, 70),
+            PokemonName::KILOWATTREL => (70, 70, 60, 105, 60, 125),
+            PokemonName::MASCHIFF => (60, 78, 60, 40, 51, 51),
+            PokemonName::MABOSSTIFF => (80, 120, 90, 60, 70, 85),
+            PokemonName::SHROODLE => (40, 65, 35, 40, 35, 75),
+            PokemonName::GRAFAIAI => (63, 95, 65, 80, 72, 110),
+            PokemonName::BRAMBLIN => (40, 65, 30, 45, 35, 60),
+            PokemonName::BRAMBLEGHAST => (55, 115, 70, 80, 70, 90),
+            PokemonName::TOEDSCOOL => (40, 40, 35, 50, 100, 70),
+            PokemonName::TOEDSCRUEL => (80, 70, 65, 80, 120, 100),
+            PokemonName::KLAWF => (70, 100, 115, 35, 55, 75),
+            PokemonName::CAPSAKID => (50, 62, 40, 62, 40, 50),
+            PokemonName::SCOVILLAIN => (65, 108, 65, 108, 65, 75),
+            PokemonName::RELLOR => (41, 50, 60, 31, 58, 30),
+            PokemonName::RABSCA => (75, 50, 85, 115, 100, 45),
+            PokemonName::FLITTLE => (30, 35, 30, 55, 30, 75),
+            PokemonName::ESPATHRA => (95, 60, 60, 101, 60, 105),
+            PokemonName::TINKATINK => (50, 45, 45, 35, 64, 58),
+            PokemonName::TINKATUFF => (65, 55, 55, 45, 82, 78),
+            PokemonName::TINKATON => (85, 75, 77, 70, 105, 94),
+            PokemonName::WIGLETT => (10, 55, 25, 35, 25, 95),
+            PokemonName::WUGTRIO => (35, 100, 50, 50, 70, 120),
+            PokemonName::BOMBIRDIER => (70, 103, 85, 60, 85, 82),
+            PokemonName::FINIZEN => (70, 45, 40, 45, 40, 75),
+            PokemonName::PALAFIN => (100, 70, 72, 53, 62, 100),
+            PokemonName::PALAFINHERO => (100, 160, 97, 106, 87, 100),
+            PokemonName::VAROOM => (45, 70, 63, 30, 45, 47),
+            PokemonName::REVAVROOM => (80, 119, 90, 54, 67, 90),
+            PokemonName::CYCLIZAR => (70, 95, 65, 85, 65, 121),
+            PokemonName::ORTHWORM => (70, 85, 145, 60, 55, 65),
+            PokemonName::GLIMMET => (48, 35, 42, 105, 60, 60),
+            PokemonName::GLIMMORA => (83, 55, 90, 130, 81, 86),
+            PokemonName::GREAVARD => (50, 61, 60, 30, 55, 34),
+            PokemonName::HOUNDSTONE => (72, 101, 100, 50, 97, 68),
+            PokemonName::FLAMIGO => (82, 115, 74, 75, 64, 90),
+            PokemonName::CETODDLE => (108, 68, 45, 30, 40, 43),
+            PokemonName::CETITAN => (170, 113, 65, 45, 55, 73),
+            PokemonName::VELUZA => (90, 102, 73, 78, 65, 70),
+            PokemonName::DONDOZO => (150, 100, 115, 65, 65, 35),
+            PokemonName::TATSUGIRI => (68, 50, 60, 120, 95, 82),
+            PokemonName::TATSUGIRIDROOPY => (68, 50, 60, 120, 95, 82),
+            PokemonName::TATSUGIRISTRETCHY => (68, 50, 60, 120, 95, 82),
+            PokemonName::ANNIHILAPE => (110, 115, 80, 50, 90, 90),
+            PokemonName::CLODSIRE => (130, 75, 60, 45, 100, 20),
+            PokemonName::FARIGIRAF => (120, 90, 70, 110, 70, 60),
+            PokemonName::DUDUNSPARCE => (125, 100, 80, 85, 75, 55),
+            PokemonName::DUDUNSPARCETHREESEGMENT => (125, 100, 80, 85, 75, 55),
+            PokemonName::KINGAMBIT => (100, 135, 120, 60, 85, 50),
+            PokemonName::GREATTUSK => (115, 131, 131, 53, 53, 87),
+            PokemonName::SCREAMTAIL => (115, 65, 99, 65, 115, 111),
+            PokemonName::BRUTEBONNET => (111, 127, 99, 79, 99, 55),
+            PokemonName::FLUTTERMANE => (55, 55, 55, 135, 135, 135),
+            PokemonName::SLITHERWING => (85, 135, 79, 85, 105, 81),
+            PokemonName::SANDYSHOCKS => (85, 81, 97, 121, 85, 101),
+            PokemonName::IRONTREADS => (90, 112, 120, 72, 70, 106),
+            PokemonName::IRONBUNDLE => (56, 80, 114, 124, 60, 136),
+            PokemonName::IRONHANDS => (154, 140, 108, 50, 68, 50),
+            PokemonName::IRONJUGULIS => (94, 80, 86, 122, 80, 108),
+            PokemonName::IRONMOTH => (80, 70, 60, 140, 110, 110),
+            PokemonName::IRONTHORNS => (100, 134, 110, 70, 84, 72),
+            PokemonName::FRIGIBAX => (65, 75, 45, 35, 45, 55),
+            PokemonName::ARCTIBAX => (90, 95, 66, 45, 65, 62),
+            PokemonName::BAXCALIBUR => (115, 145, 92, 75, 86, 87),
+            PokemonName::GIMMIGHOUL => (45, 30, 70, 75, 70, 10),
+            PokemonName::GIMMIGHOULROAMING => (45, 30, 25, 75, 45, 80),
+            PokemonName::GHOLDENGO => (87, 60, 95, 133, 91, 84),
+            PokemonName::WOCHIEN => (85, 85, 100, 95, 135, 70),
+            PokemonName::CHIENPAO => (80, 120, 80, 90, 65, 135),
+            PokemonName::TINGLU => (155, 110, 125, 55, 80, 45),
+            PokemonName::CHIYU => (55, 80, 80, 135, 120, 100),
+            PokemonName::ROARINGMOON => (105, 139, 71, 55, 101, 119),
+            PokemonName::IRONVALIANT => (74, 130, 90, 120, 60, 116),
+            PokemonName::KORAIDON => (100, 135, 115, 85, 100, 135),
+            PokemonName::MIRAIDON => (100, 85, 100, 135, 115, 135),
+            PokemonName::WALKINGWAKE => (99, 83, 91, 125, 83, 109),
+            PokemonName::IRONLEAVES => (90, 130, 88, 70, 108, 104),
+            PokemonName::DIPPLIN => (80, 80, 110, 95, 80, 40),
+            PokemonName::POLTCHAGEIST => (40, 45, 45, 74, 54, 50),
+            PokemonName::POLTCHAGEISTARTISAN => (40, 45, 45, 74, 54, 50),
+            PokemonName::SINISTCHA => (71, 60, 106, 121, 80, 70),
+            PokemonName::SINISTCHAMASTERPIECE => (71, 60, 106, 121, 80, 70),
+            PokemonName::OKIDOGI => (88, 128, 115, 58, 86, 80),
+            PokemonName::MUNKIDORI => (88, 75, 66, 130, 90, 106),
+            PokemonName::FEZANDIPITI => (88, 91, 82, 70, 125, 99),
+            PokemonName::OGERPON => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONWELLSPRING => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONHEARTHFLAME => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONCORNERSTONE => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONTEALTERA => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONWELLSPRINGTERA => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONHEARTHFLAMETERA => (80, 120, 84, 60, 96, 110),
+            PokemonName::OGERPONCORNERSTONETERA => (80, 120, 84, 60, 96, 110),
+            PokemonName::ARCHALUDON => (90, 105, 130, 125, 65, 85),
+            PokemonName::HYDRAPPLE => (106, 80, 110, 120, 80, 44),
+            PokemonName::GOUGINGFIRE => (105, 115, 121, 65, 93, 91),
+            PokemonName::RAGINGBOLT => (125, 73, 91, 137, 89, 75),
+            PokemonName::IRONBOULDER => (90, 120, 80, 68, 108, 124),
+            PokemonName::IRONCROWN => (90, 72, 100, 122, 108, 98),
+            PokemonName::TERAPAGOS => (90, 65, 85, 65, 85, 60),
+            PokemonName::TERAPAGOSTERASTAL => (95, 95, 110, 105, 110, 85),
+            PokemonName::TERAPAGOSSTELLAR => (160, 105, 110, 130, 110, 85),
+            PokemonName::PECHARUNT => (88, 88, 160, 88, 88, 88),
         }
     }
 }
@@ -332,7 +332,7 @@ pub fn modify_choice(
                 }
             }
         }
-        Choices::PHOTONGEYSER => {
+        Choices::PHOTONGEYSER | Choices::LIGHTTHATBURNSTHESKY => {
             if attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack)
                 > attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack)
             {
@@ -770,7 +770,21 @@ pub fn choice_after_damage_hit(
                 &mut instructions.instruction_list,
             );
         }
-        Choices::ICESPINNER => {
+        Choices::GENESISSUPERNOVA => {
+            if state.terrain.terrain_type != Terrain::PSYCHICTERRAIN {
+                instructions
+                    .instruction_list
+                    .push(Instruction::ChangeTerrain(ChangeTerrain {
+                        new_terrain: Terrain::PSYCHICTERRAIN,
+                        new_terrain_turns_remaining: 5,
+                        previous_terrain: state.terrain.terrain_type,
+                        previous_terrain_turns_remaining: state.terrain.turns_remaining,
+                    }));
+                state.terrain.terrain_type = Terrain::PSYCHICTERRAIN;
+                state.terrain.turns_remaining = 5;
+            }
+        }
+        Choices::ICESPINNER | Choices::SPLINTEREDSTORMSHARDS => {
             if state.terrain.terrain_type != Terrain::NONE && state.terrain.turns_remaining > 0 {
                 instructions
                     .instruction_list
@@ -1341,6 +1355,20 @@ pub fn choice_special_effect(
                 }));
             state.trick_room.active = !state.trick_room.active;
         }
+        Choices::GUARDIANOFALOLA => {
+            let target_pkmn = defending_side.get_active();
+            let damage_amount = target_pkmn.hp * 3 / 4;
+            if damage_amount == 0 {
+                return;
+            }
+            instructions
+                .instruction_list
+                .push(Instruction::Damage(DamageInstruction {
+                    side_ref: attacking_side_ref.get_other_side(),
+                    damage_amount,
+                }));
+            target_pkmn.hp -= damage_amount;
+        }
         Choices::SUPERFANG | Choices::NATURESMADNESS | Choices::RUINATION => {
             let target_pkmn = defending_side.get_active();
             if target_pkmn.hp == 1 {
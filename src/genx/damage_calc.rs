@@ -614,6 +614,17 @@ pub fn calculate_damage(
     );
     crit_damage *= CRIT_MULTIPLIER;
 
+    if choice.spread_hit {
+        damage *= 0.75;
+        crit_damage *= 0.75;
+    }
+
+    // z-moves are not blocked by protect but only a quarter of their damage gets through
+    if choice.z_move && defending_side.protected() {
+        damage *= 0.25;
+        crit_damage *= 0.25;
+    }
+
     match _damage_rolls {
         DamageRolls::Average => {
             damage = damage.floor() * 0.925;
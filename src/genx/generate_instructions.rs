@@ -8,21 +8,25 @@ use super::choice_effects::{
     choice_special_effect, modify_choice,
 };
 use crate::choices::{
-    Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, StatBoosts,
-    Status, VolatileStatus, MOVES,
+    Boost, Choices, Effect, Heal, MoveTarget, MultiAccuracyMove, MultiHitMove, Secondary,
+    SideCondition, StatBoosts, Status, VolatileStatus, ZMoveEffect, MOVES,
 };
 use crate::instruction::DecrementFutureSightInstruction;
 use crate::instruction::ToggleTerastallizedInstruction;
 use crate::instruction::{
-    ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
-    ChangeDamageDealtMoveCategoryInstruction, ChangeItemInstruction,
-    ChangeSideConditionInstruction, ChangeTerrain, ChangeVolatileStatusDurationInstruction,
-    ChangeWeather, DecrementRestTurnsInstruction, DecrementWishInstruction, HealInstruction,
-    RemoveVolatileStatusInstruction, SetSecondMoveSwitchOutMoveInstruction,
-    SetSleepTurnsInstruction, ToggleBatonPassingInstruction,
+    prune_state_instructions, ApplyVolatileStatusInstruction, BoostInstruction,
+    ChangeDamageDealtDamageInstruction, ChangeDamageDealtMoveCategoryInstruction,
+    ChangeItemInstruction, ChangeSideConditionInstruction, ChangeTerrain,
+    ChangeVolatileStatusDurationInstruction, ChangeWeather, DecrementRestTurnsInstruction,
+    DecrementWishInstruction, HealInstruction, PruningStats, RemoveVolatileStatusInstruction,
+    SetSecondMoveSwitchOutMoveInstruction, SetSleepTurnsInstruction, ToggleBatonPassingInstruction,
     ToggleDamageDealtHitSubstituteInstruction, ToggleShedTailingInstruction,
     ToggleTrickRoomInstruction,
 };
+use crate::instruction::{
+    ChangeAbilityInstruction, ChangeType, FormeChangeInstruction, ToggleMegaEvolvedInstruction,
+    ToggleZMoveUsedInstruction,
+};
 use crate::instruction::{DecrementPPInstruction, SetLastUsedMoveInstruction};
 
 use super::damage_calc::calculate_futuresight_damage;
@@ -33,6 +37,7 @@ use super::items::{
 };
 use super::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
 use crate::choices::{Choice, MoveCategory};
+use crate::damage_branching::{damage_roll_branches, DamageBranching};
 use crate::instruction::{
     ChangeStatusInstruction, DamageInstruction, Instruction, StateInstructions, SwitchInstruction,
 };
@@ -42,9 +47,6 @@ use crate::state::{
 };
 use std::cmp;
 
-#[cfg(feature = "terastallization")]
-use crate::choices::MultiAccuracyMove;
-
 #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
 pub const BASE_CRIT_CHANCE: f32 = 1.0 / 16.0;
 
@@ -861,28 +863,6 @@ fn get_instructions_from_boosts(
     }
 }
 
-fn compare_health_with_damage_multiples(max_damage: i16, health: i16) -> (i16, i16) {
-    let max_damage_f32 = max_damage as f32;
-    let health_f32 = health as f32;
-
-    let mut total_less_than = 0;
-    let mut num_less_than = 0;
-    let mut num_greater_than = 0;
-    let increment = max_damage as f32 * 0.01;
-    let mut damage = max_damage_f32 * 0.85;
-    for _ in 0..16 {
-        if damage < health_f32 {
-            total_less_than += damage as i16;
-            num_less_than += 1;
-        } else if damage > health_f32 {
-            num_greater_than += 1;
-        }
-        damage += increment;
-    }
-
-    (total_less_than / num_less_than, num_greater_than)
-}
-
 fn get_instructions_from_secondaries(
     state: &mut State,
     attacker_choice: &Choice,
@@ -1490,6 +1470,132 @@ fn terastallized_base_power_floor(
     }
 }
 
+// Status Z-Moves give the user an extra effect before the move is used
+fn z_move_effect_before_move(
+    state: &mut State,
+    choice: &Choice,
+    attacking_side_ref: &SideReference,
+    incoming_instructions: &mut StateInstructions,
+) {
+    let mut z_move_effect = choice.z_move_effect();
+    if z_move_effect == ZMoveEffect::Curse {
+        z_move_effect = if state
+            .get_side_immutable(attacking_side_ref)
+            .get_active_immutable()
+            .has_type(&PokemonType::GHOST)
+        {
+            ZMoveEffect::Heal
+        } else {
+            ZMoveEffect::Boost(StatBoosts {
+                attack: 1,
+                ..Default::default()
+            })
+        };
+    }
+    match z_move_effect {
+        ZMoveEffect::Boost(boosts) => {
+            get_instructions_from_boosts(
+                state,
+                &Boost {
+                    target: MoveTarget::User,
+                    boosts,
+                },
+                attacking_side_ref,
+                incoming_instructions,
+            );
+        }
+        ZMoveEffect::ClearNegativeBoosts => {
+            for stat in [
+                PokemonBoostableStat::Attack,
+                PokemonBoostableStat::Defense,
+                PokemonBoostableStat::SpecialAttack,
+                PokemonBoostableStat::SpecialDefense,
+                PokemonBoostableStat::Speed,
+                PokemonBoostableStat::Evasion,
+                PokemonBoostableStat::Accuracy,
+            ] {
+                let current_boost = state
+                    .get_side_immutable(attacking_side_ref)
+                    .get_boost_from_boost_enum(&stat);
+                if current_boost < 0 {
+                    let instruction = Instruction::Boost(BoostInstruction {
+                        side_ref: *attacking_side_ref,
+                        stat,
+                        amount: -current_boost,
+                    });
+                    state.apply_one_instruction(&instruction);
+                    incoming_instructions.instruction_list.push(instruction);
+                }
+            }
+        }
+        ZMoveEffect::Heal => {
+            let active = state.get_side(attacking_side_ref).get_active();
+            let heal_amount = active.maxhp - active.hp;
+            if heal_amount > 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::Heal(HealInstruction {
+                        side_ref: *attacking_side_ref,
+                        heal_amount,
+                    }));
+                active.hp += heal_amount;
+            }
+        }
+        ZMoveEffect::CritRatio => {
+            let side = state.get_side(attacking_side_ref);
+            if !side
+                .volatile_statuses
+                .contains(&PokemonVolatileStatus::FOCUSENERGY)
+            {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ApplyVolatileStatus(
+                        ApplyVolatileStatusInstruction {
+                            side_ref: *attacking_side_ref,
+                            volatile_status: PokemonVolatileStatus::FOCUSENERGY,
+                        },
+                    ));
+                side.volatile_statuses
+                    .insert(PokemonVolatileStatus::FOCUSENERGY);
+            }
+        }
+        ZMoveEffect::HealReplacement => {
+            // the replacement is healed like healing wish
+            let side = state.get_side(attacking_side_ref);
+            if side.side_conditions.healing_wish == 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ChangeSideCondition(
+                        ChangeSideConditionInstruction {
+                            side_ref: *attacking_side_ref,
+                            side_condition: PokemonSideCondition::HealingWish,
+                            amount: 1,
+                        },
+                    ));
+                side.side_conditions.healing_wish = 1;
+            }
+        }
+        ZMoveEffect::Curse | ZMoveEffect::None => {}
+    }
+}
+
+/// Modifies `choice` for the pokemon it is used against: the move's own effects, and the
+/// abilities and items of the attacker and the defender. Does not change the state
+pub fn modify_choice_against_defender(
+    state: &State,
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    attacking_side: &SideReference,
+) {
+    modify_choice(state, choice, defender_choice, attacking_side);
+
+    ability_modify_attack_being_used(state, choice, defender_choice, attacking_side);
+    ability_modify_attack_against(state, choice, defender_choice, attacking_side);
+
+    item_modify_attack_being_used(state, choice, attacking_side);
+    item_modify_attack_against(state, choice, attacking_side);
+}
+
 fn before_move(
     state: &mut State,
     choice: &mut Choice,
@@ -1504,13 +1610,7 @@ fn before_move(
     item_before_move(state, choice, attacking_side, incoming_instructions);
     choice_before_move(state, choice, attacking_side, incoming_instructions);
 
-    modify_choice(state, choice, defender_choice, attacking_side);
-
-    ability_modify_attack_being_used(state, choice, defender_choice, attacking_side);
-    ability_modify_attack_against(state, choice, defender_choice, attacking_side);
-
-    item_modify_attack_being_used(state, choice, attacking_side);
-    item_modify_attack_against(state, choice, attacking_side);
+    modify_choice_against_defender(state, choice, defender_choice, attacking_side);
 
     /*
         TODO: this needs to be here because from_drag is called after the substitute volatilestatus
@@ -1541,23 +1641,7 @@ fn before_move(
     }
 
     // modify choice if defender has protect active
-    if (defending_side
-        .volatile_statuses
-        .contains(&PokemonVolatileStatus::PROTECT)
-        || defending_side
-            .volatile_statuses
-            .contains(&PokemonVolatileStatus::SPIKYSHIELD)
-        || defending_side
-            .volatile_statuses
-            .contains(&PokemonVolatileStatus::BANEFULBUNKER)
-        || defending_side
-            .volatile_statuses
-            .contains(&PokemonVolatileStatus::BURNINGBULWARK)
-        || defending_side
-            .volatile_statuses
-            .contains(&PokemonVolatileStatus::SILKTRAP))
-        && choice.flags.protect
-    {
+    if defending_side.protected() && choice.flags.protect {
         choice.remove_effects_for_protect();
         if choice.crash.is_some() {
             choice.accuracy = 0.0;
@@ -1850,14 +1934,46 @@ fn generate_instructions_from_existing_status_conditions(
 }
 
 pub fn generate_instructions_from_move(
+    state: &mut State,
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    attacking_side: SideReference,
+    incoming_instructions: StateInstructions,
+    final_instructions: &mut Vec<StateInstructions>,
+    damage_branching: DamageBranching,
+) {
+    if let Some(incoming_instructions) = generate_instructions_from_move_attempt(
+        state,
+        choice,
+        defender_choice,
+        attacking_side,
+        incoming_instructions,
+        final_instructions,
+    ) {
+        use_move_on_defender(
+            state,
+            choice,
+            defender_choice,
+            attacking_side,
+            incoming_instructions,
+            final_instructions,
+            damage_branching,
+        );
+    }
+}
+
+/// Generates what happens to the attacker when it tries to use `choice`: switching, status
+/// conditions, encore, taunt, pp, etc. The outcomes where the move is not used are added to
+/// `final_instructions`. Returns the instructions for the move being used, which have been
+/// applied to the state, or `None` if the move is never used
+pub fn generate_instructions_from_move_attempt(
     state: &mut State,
     choice: &mut Choice,
     defender_choice: &Choice,
     attacking_side: SideReference,
     mut incoming_instructions: StateInstructions,
     mut final_instructions: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
-) {
+) -> Option<StateInstructions> {
     if state.use_damage_dealt {
         reset_damage_dealt(
             state.get_side(&attacking_side),
@@ -1874,7 +1990,7 @@ pub fn generate_instructions_from_move(
             &mut incoming_instructions,
         );
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     let attacker_side = state.get_side(&attacking_side);
@@ -1894,7 +2010,7 @@ pub fn generate_instructions_from_move(
                 ));
         }
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     if attacker_side
@@ -1910,13 +2026,13 @@ pub fn generate_instructions_from_move(
                 },
             ));
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     // TODO: test first-turn dragontail missing - it should not trigger this early return
     if !choice.first_move && defender_choice.flags.drag {
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     state.apply_instructions(&incoming_instructions.instruction_list);
@@ -2054,7 +2170,7 @@ pub fn generate_instructions_from_move(
             .switch_out_move_second_saved_move = choice.move_id;
         state.reverse_instructions(&incoming_instructions.instruction_list);
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     if state
@@ -2065,7 +2181,7 @@ pub fn generate_instructions_from_move(
     {
         state.reverse_instructions(&incoming_instructions.instruction_list);
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
     }
 
     // If the move is a charge move, remove the volatile status if damage was done
@@ -2086,7 +2202,11 @@ pub fn generate_instructions_from_move(
     if cannot_use_move(state, &choice, &attacking_side) {
         state.reverse_instructions(&incoming_instructions.instruction_list);
         final_instructions.push(incoming_instructions);
-        return;
+        return None;
+    }
+
+    if choice.z_move && choice.category == MoveCategory::Status {
+        z_move_effect_before_move(state, choice, &attacking_side, &mut incoming_instructions);
     }
 
     before_move(
@@ -2098,7 +2218,7 @@ pub fn generate_instructions_from_move(
     );
     if incoming_instructions.percentage == 0.0 {
         state.reverse_instructions(&incoming_instructions.instruction_list);
-        return;
+        return None;
     }
 
     // most of the time pp decrement doesn't matter and just adds another instruction
@@ -2160,18 +2280,54 @@ pub fn generate_instructions_from_move(
                 attacking_side,
                 sleep_talk_instructions,
                 &mut final_instructions,
-                false,
+                DamageBranching::None,
             );
         }
-        return;
+        return None;
     } else if attacker.status == PokemonStatus::SLEEP && !choice.sleep_talk_move {
         state.reverse_instructions(&incoming_instructions.instruction_list);
         if incoming_instructions.percentage > 0.0 {
             final_instructions.push(incoming_instructions);
         }
-        return;
+        return None;
     }
 
+    Some(incoming_instructions)
+}
+
+/// Generates the instructions for `choice` hitting the defender without the checks the attacker
+/// goes through before using a move. Used for every target of a spread move after the first
+pub fn generate_instructions_from_move_on_defender(
+    state: &mut State,
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    attacking_side: SideReference,
+    incoming_instructions: StateInstructions,
+    final_instructions: &mut Vec<StateInstructions>,
+    damage_branching: DamageBranching,
+) {
+    state.apply_instructions(&incoming_instructions.instruction_list);
+    use_move_on_defender(
+        state,
+        choice,
+        defender_choice,
+        attacking_side,
+        incoming_instructions,
+        final_instructions,
+        damage_branching,
+    );
+}
+
+// `incoming_instructions` must already be applied to the state
+fn use_move_on_defender(
+    state: &mut State,
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    attacking_side: SideReference,
+    mut incoming_instructions: StateInstructions,
+    mut final_instructions: &mut Vec<StateInstructions>,
+    damage_branching: DamageBranching,
+) {
     if move_has_no_effect(state, &choice, &attacking_side) {
         state.reverse_instructions(&incoming_instructions.instruction_list);
         final_instructions.push(incoming_instructions);
@@ -2194,135 +2350,130 @@ pub fn generate_instructions_from_move(
     }
 
     // start multi-hit
-    let hit_count;
-    match choice.multi_hit() {
-        MultiHitMove::None => {
-            hit_count = 1;
-        }
-        MultiHitMove::DoubleHit => {
-            hit_count = 2;
-        }
-        MultiHitMove::TripleHit => {
-            hit_count = 3;
-        }
-        MultiHitMove::TwoToFiveHits => {
-            hit_count =
-                if state.get_side(&attacking_side).get_active().ability == Abilities::SKILLLINK {
-                    5
-                } else if state.get_side(&attacking_side).get_active().item == Items::LOADEDDICE {
-                    4
+    let attacker = state
+        .get_side_immutable(&attacking_side)
+        .get_active_immutable();
+    let multi_accuracy = choice.multi_accuracy();
+    let hit_counts: Vec<(i8, f32)> = match multi_accuracy {
+        MultiAccuracyMove::None => match choice.multi_hit() {
+            MultiHitMove::None => vec![(1, 1.0)],
+            MultiHitMove::DoubleHit => vec![(2, 1.0)],
+            MultiHitMove::TripleHit => vec![(3, 1.0)],
+            MultiHitMove::TwoToFiveHits => {
+                if attacker.ability == Abilities::SKILLLINK {
+                    vec![(5, 1.0)]
+                } else if attacker.item == Items::LOADEDDICE {
+                    vec![(4, 0.5), (5, 0.5)]
                 } else {
-                    3 // too lazy to implement branching here. Average is 3.2 so this is a fine approximation
-                };
+                    two_to_five_hit_counts()
+                }
+            }
+        },
+        MultiAccuracyMove::TenHits if attacker.item == Items::LOADEDDICE => {
+            // loaded dice only checks accuracy once and hits 4 to 10 times
+            (4..=10).map(|hits| (hits, 1.0 / 7.0)).collect()
         }
-        MultiHitMove::PopulationBomb => {
-            // population bomb checks accuracy each time but lets approximate
-            hit_count = if state.get_side(&attacking_side).get_active().item == Items::WIDELENS {
-                9
+        _ => {
+            if attacker.ability == Abilities::SKILLLINK || attacker.item == Items::LOADEDDICE {
+                // only the first hit checks accuracy
+                vec![(multi_accuracy.max_hits(), 1.0)]
             } else {
-                6
-            };
-        }
-        MultiHitMove::TripleAxel => {
-            // triple axel checks accuracy each time but until multi-accuracy is implemented this
-            // is the best we can do
-            hit_count = 3
+                multi_accuracy_hit_counts(
+                    multi_accuracy.max_hits(),
+                    (choice.accuracy / 100.0).min(1.0),
+                )
+            }
         }
-    }
-
-    let (_attacker_side, defender_side) = state.get_both_sides(&attacking_side);
-    let defender_active = defender_side.get_active();
-    let mut does_damage = false;
-    let (mut branch_damage, mut regular_damage) = (0, 0);
-    let mut branch_instructions: Option<StateInstructions> = None;
-    if let Some((max_damage_dealt, max_crit_damage)) = damage {
-        does_damage = true;
-        let avg_damage_dealt = (max_damage_dealt as f32 * 0.925) as i16;
-        let min_damage_dealt = (max_damage_dealt as f32 * 0.85) as i16;
-        if branch_on_damage
-            && max_damage_dealt >= defender_active.hp
-            && min_damage_dealt < defender_active.hp
-        {
-            let (average_non_kill_damage, num_kill_rolls) =
-                compare_health_with_damage_multiples(max_damage_dealt, defender_active.hp);
-
-            let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
-                || defender_active.ability == Abilities::SHELLARMOR
-            {
-                0.0
-            } else if choice.move_id.guaranteed_crit() {
-                1.0
-            } else if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-
-            // the chance of a branch is the chance of the roll killing + the chance of a crit
-            let branch_chance = ((1.0 - crit_rate) * (num_kill_rolls as f32 / 16.0)) + crit_rate;
+    };
 
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(branch_chance);
-            branch_instructions = Some(branch_ins);
-            branch_damage = defender_active.hp;
+    // triple axel and triple kick gain their base power again with every hit
+    let max_damage_per_hit: Vec<(i16, i16)> = if multi_accuracy == MultiAccuracyMove::TripleHit {
+        (1..=multi_accuracy.max_hits())
+            .map(|hit| {
+                let mut hit_choice = choice.clone();
+                hit_choice.base_power *= hit as f32;
+                calculate_damage(state, &attacking_side, &hit_choice, DamageRolls::Max)
+                    .unwrap_or((0, 0))
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+    let defender_active = state
+        .get_side_immutable(&attacking_side.get_other_side())
+        .get_active_immutable();
+    let defender_hp = defender_active.hp;
+    let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
+        || defender_active.ability == Abilities::SHELLARMOR
+    {
+        0.0
+    } else if choice.move_id.guaranteed_crit() {
+        1.0
+    } else if choice.move_id.increased_crit_ratio() {
+        1.0 / 8.0
+    } else {
+        BASE_CRIT_CHANCE
+    };
+    let damage_branches = match damage {
+        Some((max_damage_dealt, max_crit_damage)) => damage_roll_branches(
+            damage_branching,
+            max_damage_dealt,
+            max_crit_damage,
+            defender_hp,
+            crit_rate,
+        ),
+        None => vec![(0, 1.0)],
+    };
 
-            incoming_instructions.update_percentage(1.0 - branch_chance);
-            regular_damage = average_non_kill_damage;
-        } else if branch_on_damage && max_damage_dealt < defender_active.hp {
-            let crit_rate = if defender_active.ability == Abilities::BATTLEARMOR
-                || defender_active.ability == Abilities::SHELLARMOR
-            {
-                0.0
-            } else if choice.move_id.guaranteed_crit() {
-                1.0
-            } else if choice.move_id.increased_crit_ratio() {
-                1.0 / 8.0
-            } else {
-                BASE_CRIT_CHANCE
-            };
-            let mut branch_ins = incoming_instructions.clone();
-            branch_ins.update_percentage(crit_rate);
-            branch_instructions = Some(branch_ins);
-            branch_damage = (max_crit_damage as f32 * 0.925) as i16;
-            incoming_instructions.update_percentage(1.0 - crit_rate);
-            regular_damage = (max_damage_dealt as f32 * 0.925) as i16;
-        } else {
-            regular_damage = avg_damage_dealt;
+    // every hit is rolled like the first, unless it has a different number of damage branches.
+    // Then it is scaled from the damage of the first hit
+    let hit_damage_branches: Vec<Vec<(i16, f32)>> = max_damage_per_hit
+        .iter()
+        .map(|(max_damage, max_crit_damage)| {
+            damage_roll_branches(
+                damage_branching,
+                *max_damage,
+                *max_crit_damage,
+                defender_hp,
+                crit_rate,
+            )
+        })
+        .collect();
+    let damage_per_hit = |index: usize, first_hit_damage: i16| -> Vec<i16> {
+        match max_damage_per_hit.first() {
+            Some((first_hit_max_damage, _)) if *first_hit_max_damage > 0 => max_damage_per_hit
+                .iter()
+                .zip(hit_damage_branches.iter())
+                .map(|((max_damage, _), branches)| {
+                    if branches.len() == damage_branches.len() {
+                        branches[index].0
+                    } else {
+                        (first_hit_damage as f32 * *max_damage as f32
+                            / *first_hit_max_damage as f32) as i16
+                    }
+                })
+                .collect(),
+            _ => vec![first_hit_damage],
         }
-    }
+    };
 
-    if incoming_instructions.percentage != 0.0 {
-        run_move(
+    for (index, (damage_dealt, chance)) in damage_branches.iter().enumerate() {
+        let mut branch_instructions = incoming_instructions.clone();
+        if index > 0 {
+            state.apply_instructions(&branch_instructions.instruction_list);
+        }
+        branch_instructions.update_percentage(*chance);
+        run_move_for_each_hit_count(
             state,
             attacking_side,
-            incoming_instructions,
-            hit_count,
-            does_damage,
-            regular_damage,
+            branch_instructions,
+            &hit_counts,
+            damage.is_some(),
+            &damage_per_hit(index, *damage_dealt),
             choice,
             defender_choice,
             &mut final_instructions,
         );
-    } else {
-        state.reverse_instructions(&incoming_instructions.instruction_list);
-    }
-
-    // A branch representing either a roll that kills the opponent or a crit
-    if let Some(branch_ins) = branch_instructions {
-        if branch_ins.percentage != 0.0 {
-            state.apply_instructions(&branch_ins.instruction_list);
-            run_move(
-                state,
-                attacking_side,
-                branch_ins,
-                hit_count,
-                does_damage,
-                branch_damage,
-                choice,
-                defender_choice,
-                &mut final_instructions,
-            );
-        }
     }
 
     combine_duplicate_instructions(&mut final_instructions);
@@ -2344,7 +2495,7 @@ fn combine_duplicate_instructions(list_of_instructions: &mut Vec<StateInstructio
     }
 }
 
-fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
+pub fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
@@ -2419,7 +2570,7 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     boosted_speed as i16
 }
 
-fn modify_choice_priority(state: &State, side_reference: &SideReference, choice: &mut Choice) {
+pub fn modify_choice_priority(state: &State, side_reference: &SideReference, choice: &mut Choice) {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
@@ -2670,134 +2821,319 @@ fn on_terrain_end(
     }
 }
 
-fn add_end_of_turn_instructions(
-    state: &mut State,
-    mut incoming_instructions: &mut StateInstructions,
-    first_move_side: &SideReference,
+fn end_confusion(side: &mut Side, side_ref: &SideReference, instructions: &mut StateInstructions) {
+    instructions
+        .instruction_list
+        .push(Instruction::ChangeVolatileStatusDuration(
+            ChangeVolatileStatusDurationInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+                amount: -1 * side.volatile_status_durations.confusion,
+            },
+        ));
+    side.volatile_status_durations.confusion = 0;
+    instructions
+        .instruction_list
+        .push(Instruction::RemoveVolatileStatus(
+            RemoveVolatileStatusInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+            },
+        ));
+    side.volatile_statuses
+        .remove(&PokemonVolatileStatus::CONFUSION);
+}
+
+// A locking move ending confuses the pokemon from fatigue. The turn it ends on counts as the
+// first turn of the confusion
+fn end_locked_move(
+    side: &mut Side,
+    side_ref: &SideReference,
+    instructions: &mut StateInstructions,
 ) {
-    state.apply_instructions(&incoming_instructions.instruction_list);
-    if state.side_one.force_switch || state.side_two.force_switch {
-        state.reverse_instructions(&incoming_instructions.instruction_list);
-        return;
+    instructions
+        .instruction_list
+        .push(Instruction::ChangeVolatileStatusDuration(
+            ChangeVolatileStatusDurationInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                amount: -1 * side.volatile_status_durations.lockedmove,
+            },
+        ));
+    side.volatile_status_durations.lockedmove = 0;
+    instructions
+        .instruction_list
+        .push(Instruction::RemoveVolatileStatus(
+            RemoveVolatileStatusInstruction {
+                side_ref: *side_ref,
+                volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+            },
+        ));
+    side.volatile_statuses
+        .remove(&PokemonVolatileStatus::LOCKEDMOVE);
+    if !side
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::CONFUSION)
+    {
+        instructions
+            .instruction_list
+            .push(Instruction::ApplyVolatileStatus(
+                ApplyVolatileStatusInstruction {
+                    side_ref: *side_ref,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                },
+            ));
+        side.volatile_statuses
+            .insert(PokemonVolatileStatus::CONFUSION);
+        instructions
+            .instruction_list
+            .push(Instruction::ChangeVolatileStatusDuration(
+                ChangeVolatileStatusDurationInstruction {
+                    side_ref: *side_ref,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                    amount: 1,
+                },
+            ));
+        side.volatile_status_durations.confusion += 1;
     }
+}
 
-    let sides = [first_move_side, &first_move_side.get_other_side()];
-
-    // Weather decrement / dissipation
-    if state.weather.turns_remaining > 0 && state.weather.weather_type != Weather::NONE {
-        let weather_dissipate_instruction = Instruction::DecrementWeatherTurnsRemaining;
-        incoming_instructions
-            .instruction_list
-            .push(weather_dissipate_instruction);
-        state.weather.turns_remaining -= 1;
-        if state.weather.turns_remaining == 0 {
-            on_weather_end(state, sides, &mut incoming_instructions);
-            let weather_end_instruction = Instruction::ChangeWeather(ChangeWeather {
-                new_weather: Weather::NONE,
-                new_weather_turns_remaining: 0,
-                previous_weather: state.weather.weather_type,
-                previous_weather_turns_remaining: 0,
-            });
-            incoming_instructions
-                .instruction_list
-                .push(weather_end_instruction);
-            state.weather.weather_type = Weather::NONE;
-        }
+// The chance that a volatile status with a random duration ends this turn, given that it has
+// not ended yet. Locking moves last 2 or 3 turns and confusion lasts 2 to 5 turns
+fn chance_to_end_early(side: &Side, volatile_status: PokemonVolatileStatus) -> f32 {
+    if !side.volatile_statuses.contains(&volatile_status) {
+        return 0.0;
+    }
+    match volatile_status {
+        PokemonVolatileStatus::LOCKEDMOVE => match side.volatile_status_durations.lockedmove {
+            2 => 0.5,
+            _ => 0.0,
+        },
+        PokemonVolatileStatus::CONFUSION => match side.volatile_status_durations.confusion {
+            2 => 1.0 / 4.0,
+            3 => 1.0 / 3.0,
+            4 => 1.0 / 2.0,
+            _ => 0.0,
+        },
+        _ => 0.0,
     }
+}
 
-    // Trick Room decrement / dissipation
-    if state.trick_room.turns_remaining > 0 && state.trick_room.active {
-        incoming_instructions
-            .instruction_list
-            .push(Instruction::DecrementTrickRoomTurnsRemaining);
-        state.trick_room.turns_remaining -= 1;
-        if state.trick_room.turns_remaining == 0 {
-            incoming_instructions
-                .instruction_list
-                .push(Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
-                    currently_active: true,
-                    new_trickroom_turns_remaining: 0,
-                    previous_trickroom_turns_remaining: 0,
-                }));
-            state.trick_room.active = false;
+// `add_end_of_turn_instructions` lets locking moves and confusion last as long as they can.
+// This splits every outcome where one of them could have ended earlier into a branch where it
+// ends and a branch where it continues
+fn add_random_duration_branches(
+    state: &mut State,
+    state_instructions_vec: &mut Vec<StateInstructions>,
+    first_move_side: &SideReference,
+) {
+    for side_ref in [*first_move_side, first_move_side.get_other_side()] {
+        for volatile_status in [
+            PokemonVolatileStatus::LOCKEDMOVE,
+            PokemonVolatileStatus::CONFUSION,
+        ] {
+            let mut ended_early = vec![];
+            for state_instructions in state_instructions_vec.iter_mut() {
+                state.apply_instructions(&state_instructions.instruction_list);
+                let chance = if state.side_one.force_switch
+                    || state.side_two.force_switch
+                    || state
+                        .get_side_immutable(&side_ref)
+                        .get_active_immutable()
+                        .hp
+                        <= 0
+                {
+                    0.0
+                } else {
+                    chance_to_end_early(state.get_side_immutable(&side_ref), volatile_status)
+                };
+                if chance > 0.0 {
+                    let mut ended_instructions = state_instructions.clone();
+                    let side = state.get_side(&side_ref);
+                    match volatile_status {
+                        PokemonVolatileStatus::LOCKEDMOVE => {
+                            end_locked_move(side, &side_ref, &mut ended_instructions)
+                        }
+                        _ => end_confusion(side, &side_ref, &mut ended_instructions),
+                    }
+                    ended_instructions.update_percentage(chance);
+                    state_instructions.update_percentage(1.0 - chance);
+                    state.reverse_instructions(&ended_instructions.instruction_list);
+                    ended_early.push(ended_instructions);
+                } else {
+                    state.reverse_instructions(&state_instructions.instruction_list);
+                }
+            }
+            state_instructions_vec.extend(ended_early);
         }
     }
+}
 
-    // Terrain decrement / dissipation
-    if state.terrain.turns_remaining > 0 && state.terrain.terrain_type != Terrain::NONE {
-        let terrain_dissipate_instruction = Instruction::DecrementTerrainTurnsRemaining;
-        incoming_instructions
-            .instruction_list
-            .push(terrain_dissipate_instruction);
-        state.terrain.turns_remaining -= 1;
-        if state.terrain.turns_remaining == 0 {
-            on_terrain_end(state, sides, &mut incoming_instructions);
-            let terrain_end_instruction = Instruction::ChangeTerrain(ChangeTerrain {
-                new_terrain: Terrain::NONE,
-                new_terrain_turns_remaining: 0,
-                previous_terrain: state.terrain.terrain_type,
-                previous_terrain_turns_remaining: 0,
-            });
-            incoming_instructions
-                .instruction_list
-                .push(terrain_end_instruction);
-            state.terrain.terrain_type = Terrain::NONE;
-        }
+fn add_end_of_turn_instructions(
+    state: &mut State,
+    incoming_instructions: &mut StateInstructions,
+    first_move_side: &SideReference,
+) {
+    add_end_of_turn_instructions_with_field_effects(
+        state,
+        incoming_instructions,
+        first_move_side,
+        true,
+    );
+}
+
+/// Generates the end of turn instructions for the active pokemon of both sides followed by the
+/// branches for volatile statuses that can end early.
+/// Weather, terrain, trick room, side conditions, wish and future sight only advance when
+/// `field_effects` is true, so doubles can run this once for each pair of active pokemon
+pub fn generate_end_of_turn_instructions(
+    state: &mut State,
+    incoming_instructions: StateInstructions,
+    first_move_side: &SideReference,
+    field_effects: bool,
+) -> Vec<StateInstructions> {
+    let mut state_instructions_vec = vec![incoming_instructions];
+    add_end_of_turn_instructions_with_field_effects(
+        state,
+        &mut state_instructions_vec[0],
+        first_move_side,
+        field_effects,
+    );
+    add_random_duration_branches(state, &mut state_instructions_vec, first_move_side);
+    state_instructions_vec
+}
+
+fn add_end_of_turn_instructions_with_field_effects(
+    state: &mut State,
+    mut incoming_instructions: &mut StateInstructions,
+    first_move_side: &SideReference,
+    field_effects: bool,
+) {
+    state.apply_instructions(&incoming_instructions.instruction_list);
+    if state.side_one.force_switch || state.side_two.force_switch {
+        state.reverse_instructions(&incoming_instructions.instruction_list);
+        return;
     }
 
-    // Side Condition decrement
-    for side_ref in sides {
-        let side = state.get_side(side_ref);
-        if side.side_conditions.reflect > 0 {
+    let sides = [first_move_side, &first_move_side.get_other_side()];
+
+    // weather, terrain, trick room and side-wide effects only advance once per turn
+    if field_effects {
+        // Weather decrement / dissipation
+        if state.weather.turns_remaining > 0 && state.weather.weather_type != Weather::NONE {
+            let weather_dissipate_instruction = Instruction::DecrementWeatherTurnsRemaining;
             incoming_instructions
                 .instruction_list
-                .push(Instruction::ChangeSideCondition(
-                    ChangeSideConditionInstruction {
-                        side_ref: *side_ref,
-                        side_condition: PokemonSideCondition::Reflect,
-                        amount: -1,
-                    },
-                ));
-            side.side_conditions.reflect -= 1;
+                .push(weather_dissipate_instruction);
+            state.weather.turns_remaining -= 1;
+            if state.weather.turns_remaining == 0 {
+                on_weather_end(state, sides, &mut incoming_instructions);
+                let weather_end_instruction = Instruction::ChangeWeather(ChangeWeather {
+                    new_weather: Weather::NONE,
+                    new_weather_turns_remaining: 0,
+                    previous_weather: state.weather.weather_type,
+                    previous_weather_turns_remaining: 0,
+                });
+                incoming_instructions
+                    .instruction_list
+                    .push(weather_end_instruction);
+                state.weather.weather_type = Weather::NONE;
+            }
         }
-        if side.side_conditions.light_screen > 0 {
+
+        // Trick Room decrement / dissipation
+        if state.trick_room.turns_remaining > 0 && state.trick_room.active {
             incoming_instructions
                 .instruction_list
-                .push(Instruction::ChangeSideCondition(
-                    ChangeSideConditionInstruction {
-                        side_ref: *side_ref,
-                        side_condition: PokemonSideCondition::LightScreen,
-                        amount: -1,
-                    },
-                ));
-            side.side_conditions.light_screen -= 1;
+                .push(Instruction::DecrementTrickRoomTurnsRemaining);
+            state.trick_room.turns_remaining -= 1;
+            if state.trick_room.turns_remaining == 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
+                        currently_active: true,
+                        new_trickroom_turns_remaining: 0,
+                        previous_trickroom_turns_remaining: 0,
+                    }));
+                state.trick_room.active = false;
+            }
         }
-        if side.side_conditions.aurora_veil > 0 {
+
+        // Terrain decrement / dissipation
+        if state.terrain.turns_remaining > 0 && state.terrain.terrain_type != Terrain::NONE {
+            let terrain_dissipate_instruction = Instruction::DecrementTerrainTurnsRemaining;
             incoming_instructions
                 .instruction_list
-                .push(Instruction::ChangeSideCondition(
-                    ChangeSideConditionInstruction {
-                        side_ref: *side_ref,
-                        side_condition: PokemonSideCondition::AuroraVeil,
-                        amount: -1,
-                    },
-                ));
-            side.side_conditions.aurora_veil -= 1;
+                .push(terrain_dissipate_instruction);
+            state.terrain.turns_remaining -= 1;
+            if state.terrain.turns_remaining == 0 {
+                on_terrain_end(state, sides, &mut incoming_instructions);
+                let terrain_end_instruction = Instruction::ChangeTerrain(ChangeTerrain {
+                    new_terrain: Terrain::NONE,
+                    new_terrain_turns_remaining: 0,
+                    previous_terrain: state.terrain.terrain_type,
+                    previous_terrain_turns_remaining: 0,
+                });
+                incoming_instructions
+                    .instruction_list
+                    .push(terrain_end_instruction);
+                state.terrain.terrain_type = Terrain::NONE;
+            }
         }
-        if side.side_conditions.tailwind > 0 {
-            incoming_instructions
-                .instruction_list
-                .push(Instruction::ChangeSideCondition(
-                    ChangeSideConditionInstruction {
-                        side_ref: *side_ref,
-                        side_condition: PokemonSideCondition::Tailwind,
-                        amount: -1,
-                    },
-                ));
-            side.side_conditions.tailwind -= 1;
+
+        // Side Condition decrement
+        for side_ref in sides {
+            let side = state.get_side(side_ref);
+            if side.side_conditions.reflect > 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ChangeSideCondition(
+                        ChangeSideConditionInstruction {
+                            side_ref: *side_ref,
+                            side_condition: PokemonSideCondition::Reflect,
+                            amount: -1,
+                        },
+                    ));
+                side.side_conditions.reflect -= 1;
+            }
+            if side.side_conditions.light_screen > 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ChangeSideCondition(
+                        ChangeSideConditionInstruction {
+                            side_ref: *side_ref,
+                            side_condition: PokemonSideCondition::LightScreen,
+                            amount: -1,
+                        },
+                    ));
+                side.side_conditions.light_screen -= 1;
+            }
+            if side.side_conditions.aurora_veil > 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ChangeSideCondition(
+                        ChangeSideConditionInstruction {
+                            side_ref: *side_ref,
+                            side_condition: PokemonSideCondition::AuroraVeil,
+                            amount: -1,
+                        },
+                    ));
+                side.side_conditions.aurora_veil -= 1;
+            }
+            if side.side_conditions.tailwind > 0 {
+                incoming_instructions
+                    .instruction_list
+                    .push(Instruction::ChangeSideCondition(
+                        ChangeSideConditionInstruction {
+                            side_ref: *side_ref,
+                            side_condition: PokemonSideCondition::Tailwind,
+                            amount: -1,
+                        },
+                    ));
+                side.side_conditions.tailwind -= 1;
+            }
         }
     }
-
     // Weather Damage
     for side_ref in sides {
         if state.weather_is_active(&Weather::HAIL) {
@@ -2846,72 +3182,74 @@ fn add_end_of_turn_instructions(
         }
     }
 
-    // future sight
-    for side_ref in sides {
-        let (attacking_side, defending_side) = state.get_both_sides(side_ref);
-        if attacking_side.future_sight.0 > 0 {
-            let decrement_future_sight_instruction =
-                Instruction::DecrementFutureSight(DecrementFutureSightInstruction {
-                    side_ref: *side_ref,
-                });
-            if attacking_side.future_sight.0 == 1 {
-                let mut damage = calculate_futuresight_damage(
-                    &attacking_side,
-                    &defending_side,
-                    &attacking_side.future_sight.1,
-                );
-                let defender = defending_side.get_active();
-                damage = cmp::min(damage, defender.hp);
-                let future_sight_damage_instruction = Instruction::Damage(DamageInstruction {
-                    side_ref: side_ref.get_other_side(),
-                    damage_amount: damage,
-                });
+    if field_effects {
+        // future sight
+        for side_ref in sides {
+            let (attacking_side, defending_side) = state.get_both_sides(side_ref);
+            if attacking_side.future_sight.0 > 0 {
+                let decrement_future_sight_instruction =
+                    Instruction::DecrementFutureSight(DecrementFutureSightInstruction {
+                        side_ref: *side_ref,
+                    });
+                if attacking_side.future_sight.0 == 1 {
+                    let mut damage = calculate_futuresight_damage(
+                        &attacking_side,
+                        &defending_side,
+                        &attacking_side.future_sight.1,
+                    );
+                    let defender = defending_side.get_active();
+                    damage = cmp::min(damage, defender.hp);
+                    let future_sight_damage_instruction = Instruction::Damage(DamageInstruction {
+                        side_ref: side_ref.get_other_side(),
+                        damage_amount: damage,
+                    });
+                    incoming_instructions
+                        .instruction_list
+                        .push(future_sight_damage_instruction);
+                    defender.hp -= damage;
+                }
+                attacking_side.future_sight.0 -= 1;
                 incoming_instructions
                     .instruction_list
-                    .push(future_sight_damage_instruction);
-                defender.hp -= damage;
+                    .push(decrement_future_sight_instruction);
             }
-            attacking_side.future_sight.0 -= 1;
-            incoming_instructions
-                .instruction_list
-                .push(decrement_future_sight_instruction);
         }
-    }
 
-    // wish
-    for side_ref in sides {
-        let side = state.get_side(side_ref);
-        let side_wish = side.wish;
-        let active_pkmn = side.get_active();
+        // wish
+        for side_ref in sides {
+            let side = state.get_side(side_ref);
+            let side_wish = side.wish;
+            let active_pkmn = side.get_active();
 
-        if side_wish.0 > 0 {
-            let decrement_wish_instruction = Instruction::DecrementWish(DecrementWishInstruction {
-                side_ref: *side_ref,
-            });
-            if side_wish.0 == 1 && 0 < active_pkmn.hp && active_pkmn.hp < active_pkmn.maxhp {
-                #[cfg(not(feature = "gen4"))]
-                let heal_amount = cmp::min(active_pkmn.maxhp - active_pkmn.hp, side_wish.1);
+            if side_wish.0 > 0 {
+                let decrement_wish_instruction =
+                    Instruction::DecrementWish(DecrementWishInstruction {
+                        side_ref: *side_ref,
+                    });
+                if side_wish.0 == 1 && 0 < active_pkmn.hp && active_pkmn.hp < active_pkmn.maxhp {
+                    #[cfg(not(feature = "gen4"))]
+                    let heal_amount = cmp::min(active_pkmn.maxhp - active_pkmn.hp, side_wish.1);
 
-                #[cfg(feature = "gen4")]
-                let heal_amount =
-                    cmp::min(active_pkmn.maxhp - active_pkmn.hp, active_pkmn.maxhp / 2);
+                    #[cfg(feature = "gen4")]
+                    let heal_amount =
+                        cmp::min(active_pkmn.maxhp - active_pkmn.hp, active_pkmn.maxhp / 2);
 
-                let wish_heal_instruction = Instruction::Heal(HealInstruction {
-                    side_ref: *side_ref,
-                    heal_amount: heal_amount,
-                });
+                    let wish_heal_instruction = Instruction::Heal(HealInstruction {
+                        side_ref: *side_ref,
+                        heal_amount: heal_amount,
+                    });
+                    incoming_instructions
+                        .instruction_list
+                        .push(wish_heal_instruction);
+                    active_pkmn.hp += heal_amount;
+                }
+                side.wish.0 -= 1;
                 incoming_instructions
                     .instruction_list
-                    .push(wish_heal_instruction);
-                active_pkmn.hp += heal_amount;
+                    .push(decrement_wish_instruction);
             }
-            side.wish.0 -= 1;
-            incoming_instructions
-                .instruction_list
-                .push(decrement_wish_instruction);
         }
     }
-
     // status damage
     for side_ref in sides {
         let (side, other_side) = state.get_both_sides(side_ref);
@@ -3091,47 +3429,35 @@ fn add_end_of_turn_instructions(
 
         if side
             .volatile_statuses
-            .contains(&PokemonVolatileStatus::LOCKEDMOVE)
+            .contains(&PokemonVolatileStatus::CONFUSION)
         {
-            // the number says 2 but this is 3 turns of using a locking move
-            // because turn 0 is the first turn the move is used
-            // branching is not implemented here so the engine assumes it always lasts 3 turns
-            if side.volatile_status_durations.lockedmove == 2 {
-                side.volatile_status_durations.lockedmove = 0;
-                side.volatile_statuses
-                    .remove(&PokemonVolatileStatus::LOCKEDMOVE);
+            // confusion lasts at most 5 turns, ending earlier is branched on
+            // in `add_random_duration_branches`
+            if side.volatile_status_durations.confusion == 4 {
+                end_confusion(side, side_ref, incoming_instructions);
+            } else {
+                side.volatile_status_durations.confusion += 1;
                 incoming_instructions.instruction_list.push(
                     Instruction::ChangeVolatileStatusDuration(
                         ChangeVolatileStatusDurationInstruction {
                             side_ref: *side_ref,
-                            volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
-                            amount: -2,
+                            volatile_status: PokemonVolatileStatus::CONFUSION,
+                            amount: 1,
                         },
                     ),
                 );
-                incoming_instructions
-                    .instruction_list
-                    .push(Instruction::RemoveVolatileStatus(
-                        RemoveVolatileStatusInstruction {
-                            side_ref: *side_ref,
-                            volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
-                        },
-                    ));
-                if !side
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::CONFUSION)
-                {
-                    incoming_instructions
-                        .instruction_list
-                        .push(Instruction::ApplyVolatileStatus(
-                            ApplyVolatileStatusInstruction {
-                                side_ref: *side_ref,
-                                volatile_status: PokemonVolatileStatus::CONFUSION,
-                            },
-                        ));
-                    side.volatile_statuses
-                        .insert(PokemonVolatileStatus::CONFUSION);
-                }
+            }
+        }
+
+        if side
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::LOCKEDMOVE)
+        {
+            // the number says 2 but this is 3 turns of using a locking move
+            // because turn 0 is the first turn the move is used.
+            // Ending after 2 turns is branched on in `add_random_duration_branches`
+            if side.volatile_status_durations.lockedmove == 2 {
+                end_locked_move(side, side_ref, incoming_instructions);
             } else {
                 side.volatile_status_durations.lockedmove += 1;
                 incoming_instructions.instruction_list.push(
@@ -3425,20 +3751,93 @@ fn end_of_turn_triggered(side_one_move: &MoveChoice, side_two_move: &MoveChoice)
         && !(side_one_move == &MoveChoice::None && matches!(side_two_move, &MoveChoice::Switch(_)))
 }
 
+// The chance of each number of hits of a move that hits 2 to 5 times
+fn two_to_five_hit_counts() -> Vec<(i8, f32)> {
+    if cfg!(feature = "gen4") {
+        vec![(2, 0.375), (3, 0.375), (4, 0.125), (5, 0.125)]
+    } else {
+        vec![(2, 0.35), (3, 0.35), (4, 0.15), (5, 0.15)]
+    }
+}
+
+// The chance of each number of hits of a move that checks `accuracy` before every hit after
+// the first and stops at the first miss
+fn multi_accuracy_hit_counts(max_hits: i8, accuracy: f32) -> Vec<(i8, f32)> {
+    (1..=max_hits)
+        .map(|hits| {
+            let chance = if hits == max_hits {
+                accuracy.powi(hits as i32 - 1)
+            } else {
+                accuracy.powi(hits as i32 - 1) * (1.0 - accuracy)
+            };
+            (hits, chance)
+        })
+        .filter(|(_, chance)| *chance > 0.0)
+        .collect()
+}
+
+// Runs the move once for every number of hits it can have. `instructions` must already be
+// applied to the state. Outcomes that end up the same, e.g. because the defender fainted before
+// the last hits, are merged later by `combine_duplicate_instructions`
+fn run_move_for_each_hit_count(
+    state: &mut State,
+    attacking_side: SideReference,
+    instructions: StateInstructions,
+    hit_counts: &[(i8, f32)],
+    does_damage: bool,
+    damage_per_hit: &[i16],
+    choice: &mut Choice,
+    defender_choice: &Choice,
+    final_instructions: &mut Vec<StateInstructions>,
+) {
+    for (index, (hit_count, chance)) in hit_counts.iter().enumerate() {
+        // run_move reverses the instructions it was given, so every other number of hits
+        // has to apply them again
+        if index > 0 {
+            state.apply_instructions(&instructions.instruction_list);
+        }
+        let mut hit_count_instructions = instructions.clone();
+        hit_count_instructions.update_percentage(*chance);
+        run_move(
+            state,
+            attacking_side,
+            hit_count_instructions,
+            *hit_count,
+            does_damage,
+            damage_per_hit,
+            choice,
+            defender_choice,
+            final_instructions,
+        );
+    }
+}
+
 fn run_move(
     state: &mut State,
     attacking_side: SideReference,
     mut instructions: StateInstructions,
     hit_count: i8,
     does_damage: bool,
-    damage_amount: i16,
+    damage_per_hit: &[i16],
     choice: &mut Choice,
     defender_choice: &Choice,
     final_instructions: &mut Vec<StateInstructions>,
 ) {
     let mut hit_sub = false;
-    for _ in 0..hit_count {
+    for hit in 0..hit_count {
+        // a multi-hit move stops hitting once the defender has fainted
+        if hit > 0
+            && state
+                .get_side_immutable(&attacking_side.get_other_side())
+                .get_active_immutable()
+                .hp
+                <= 0
+        {
+            break;
+        }
         if does_damage {
+            // moves that do the same damage every hit only have the damage of the first hit
+            let damage_amount = damage_per_hit[(hit as usize).min(damage_per_hit.len() - 1)];
             hit_sub = generate_instructions_from_damage(
                 state,
                 choice,
@@ -3636,7 +4035,7 @@ fn handle_both_moves(
     first_move_side_ref: SideReference,
     incoming_instructions: StateInstructions,
     state_instructions_vec: &mut Vec<StateInstructions>,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
 ) {
     generate_instructions_from_move(
         state,
@@ -3645,7 +4044,7 @@ fn handle_both_moves(
         first_move_side_ref,
         incoming_instructions,
         state_instructions_vec,
-        branch_on_damage,
+        damage_branching,
     );
 
     let mut i = 0;
@@ -3660,20 +4059,119 @@ fn handle_both_moves(
             first_move_side_ref.get_other_side(),
             state_instruction,
             state_instructions_vec,
-            branch_on_damage,
+            damage_branching,
         );
         i += 1;
     }
 }
 
+// Mega evolves the active pokemon of `side_ref` into the forme given by its mega stone.
+// The state is modified and the instructions are pushed to `incoming_instructions`
+pub fn mega_evolve(
+    state: &mut State,
+    side_ref: &SideReference,
+    incoming_instructions: &mut StateInstructions,
+) {
+    let side = state.get_side(side_ref);
+    let mega_forme = match side.get_active_immutable().mega_evolution() {
+        Some(mega_forme) => mega_forme,
+        None => return,
+    };
+    incoming_instructions
+        .instruction_list
+        .push(Instruction::ToggleMegaEvolved(
+            ToggleMegaEvolvedInstruction {
+                side_ref: *side_ref,
+            },
+        ));
+    side.mega_evolved = true;
+
+    let active_pkmn = side.get_active();
+    incoming_instructions
+        .instruction_list
+        .push(Instruction::FormeChange(FormeChangeInstruction {
+            side_ref: *side_ref,
+            name_change: mega_forme as i16 - active_pkmn.id as i16,
+        }));
+    active_pkmn.id = mega_forme;
+    active_pkmn.recalculate_stats(side_ref, incoming_instructions);
+
+    let mega_types = mega_forme.types();
+    if active_pkmn.types != mega_types {
+        incoming_instructions
+            .instruction_list
+            .push(Instruction::ChangeType(ChangeType {
+                side_ref: *side_ref,
+                new_types: mega_types,
+                old_types: active_pkmn.types,
+            }));
+        active_pkmn.types = mega_types;
+    }
+
+    // the mega forme's ability stays when it switches out
+    let mega_ability = mega_forme
+        .abilities()
+        .first()
+        .copied()
+        .unwrap_or(Abilities::NONE);
+    if active_pkmn.ability != mega_ability {
+        incoming_instructions
+            .instruction_list
+            .push(Instruction::ChangeAbility(ChangeAbilityInstruction {
+                side_ref: *side_ref,
+                ability_change: mega_ability as i16 - active_pkmn.ability as i16,
+            }));
+        active_pkmn.ability = mega_ability;
+    }
+    if active_pkmn.base_ability != mega_ability {
+        incoming_instructions
+            .instruction_list
+            .push(Instruction::ChangeBaseAbility(ChangeAbilityInstruction {
+                side_ref: *side_ref,
+                ability_change: mega_ability as i16 - active_pkmn.base_ability as i16,
+            }));
+        active_pkmn.base_ability = mega_ability;
+    }
+    ability_on_switch_in(state, side_ref, incoming_instructions);
+}
+
 pub fn generate_instructions_from_move_pair(
     state: &mut State,
     side_one_move: &MoveChoice,
     side_two_move: &MoveChoice,
-    branch_on_damage: bool,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+) -> Vec<StateInstructions> {
+    generate_instructions_from_move_pair_with_pruning_stats(
+        state,
+        side_one_move,
+        side_two_move,
+        damage_branching,
+        prune_threshold,
+        &mut PruningStats::default(),
+    )
+}
+
+/// Like `generate_instructions_from_move_pair`, and records the percentage pruned by
+/// `prune_threshold` in `pruning_stats`
+pub fn generate_instructions_from_move_pair_with_pruning_stats(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+    damage_branching: DamageBranching,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
 ) -> Vec<StateInstructions> {
+    // instruction generation modifies the state directly in many places and relies on
+    // reversing the generated instructions to undo those modifications, which the
+    // incrementally maintained zobrist hash cannot follow.
+    // `state.zobrist_hash` is therefore only valid between calls, and is restored before returning
+    let zobrist_hash = state.zobrist_hash;
+
     let mut side_one_choice;
     let mut s1_tera = false;
+    let mut s1_mega = false;
+    let mut s1_z_move = false;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
             side_one_choice = Choice::default();
@@ -3689,6 +4187,18 @@ pub fn generate_instructions_from_move_pair(
             side_one_choice.move_index = *move_index;
             s1_tera = true;
         }
+        MoveChoice::MoveMega(move_index) => {
+            side_one_choice = state.side_one.get_active().moves[move_index].choice.clone();
+            side_one_choice.move_index = *move_index;
+            s1_mega = true;
+        }
+        MoveChoice::MoveZ(move_index) => {
+            let active = state.side_one.get_active_immutable();
+            let mut choice = active.moves[move_index].choice.clone();
+            choice.move_index = *move_index;
+            side_one_choice = active.z_move(&choice).unwrap_or_else(|| choice.to_z_move());
+            s1_z_move = true;
+        }
         MoveChoice::None => {
             side_one_choice = Choice::default();
         }
@@ -3696,6 +4206,8 @@ pub fn generate_instructions_from_move_pair(
 
     let mut side_two_choice;
     let mut s2_tera = false;
+    let mut s2_mega = false;
+    let mut s2_z_move = false;
     match side_two_move {
         MoveChoice::Switch(switch_id) => {
             side_two_choice = Choice::default();
@@ -3711,6 +4223,18 @@ pub fn generate_instructions_from_move_pair(
             side_two_choice.move_index = *move_index;
             s2_tera = true;
         }
+        MoveChoice::MoveMega(move_index) => {
+            side_two_choice = state.side_two.get_active().moves[move_index].choice.clone();
+            side_two_choice.move_index = *move_index;
+            s2_mega = true;
+        }
+        MoveChoice::MoveZ(move_index) => {
+            let active = state.side_two.get_active_immutable();
+            let mut choice = active.moves[move_index].choice.clone();
+            choice.move_index = *move_index;
+            side_two_choice = active.z_move(&choice).unwrap_or_else(|| choice.to_z_move());
+            s2_z_move = true;
+        }
         MoveChoice::None => {
             side_two_choice = Choice::default();
         }
@@ -3740,15 +4264,49 @@ pub fn generate_instructions_from_move_pair(
                 },
             ));
     }
+    if s1_z_move {
+        incoming_instructions
+            .instruction_list
+            .push(Instruction::ToggleZMoveUsed(ToggleZMoveUsedInstruction {
+                side_ref: SideReference::SideOne,
+            }));
+    }
+    if s2_z_move {
+        incoming_instructions
+            .instruction_list
+            .push(Instruction::ToggleZMoveUsed(ToggleZMoveUsedInstruction {
+                side_ref: SideReference::SideTwo,
+            }));
+    }
 
-    modify_choice_priority(&state, &SideReference::SideOne, &mut side_one_choice);
-    modify_choice_priority(&state, &SideReference::SideTwo, &mut side_two_choice);
-    match moves_first(
-        &state,
+    // Mega evolution happens before any move. Unlike terastallization the instructions are
+    // applied as they are generated and undone before the moves are generated
+    let mega_start = incoming_instructions.instruction_list.len();
+    if s1_mega {
+        mega_evolve(state, &SideReference::SideOne, &mut incoming_instructions);
+    }
+    if s2_mega {
+        mega_evolve(state, &SideReference::SideTwo, &mut incoming_instructions);
+    }
+    let mega_instructions = incoming_instructions.instruction_list[mega_start..].to_vec();
+
+    // in gen6 the turn order uses the speed from before mega evolving
+    #[cfg(feature = "gen6")]
+    state.reverse_instructions(&mega_instructions);
+
+    modify_choice_priority(state, &SideReference::SideOne, &mut side_one_choice);
+    modify_choice_priority(state, &SideReference::SideTwo, &mut side_two_choice);
+    let side_moves_first = moves_first(
+        state,
         &side_one_choice,
         &side_two_choice,
         &mut incoming_instructions,
-    ) {
+    );
+
+    #[cfg(not(feature = "gen6"))]
+    state.reverse_instructions(&mega_instructions);
+
+    match side_moves_first {
         SideMovesFirst::SideOne => {
             handle_both_moves(
                 state,
@@ -3757,12 +4315,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideOne);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideOne,
+                );
             }
         }
         SideMovesFirst::SideTwo => {
@@ -3773,12 +4336,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideTwo);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideTwo,
+                );
             }
         }
         SideMovesFirst::SpeedTie => {
@@ -3794,12 +4362,17 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideOne,
                 side_one_moves_first_instruction,
                 &mut state_instructions_vec,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in state_instructions_vec.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideOne);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut state_instructions_vec,
+                    &SideReference::SideOne,
+                );
             }
 
             // side_two moves first
@@ -3811,18 +4384,28 @@ pub fn generate_instructions_from_move_pair(
                 SideReference::SideTwo,
                 incoming_instructions,
                 &mut side_two_moves_first_si,
-                branch_on_damage,
+                damage_branching,
             );
             if end_of_turn_triggered(side_one_move, side_two_move) {
                 for state_instruction in side_two_moves_first_si.iter_mut() {
                     add_end_of_turn_instructions(state, state_instruction, &SideReference::SideTwo);
                 }
+                add_random_duration_branches(
+                    state,
+                    &mut side_two_moves_first_si,
+                    &SideReference::SideTwo,
+                );
             }
 
             // combine both vectors into the final vector
             state_instructions_vec.extend(side_two_moves_first_si);
         }
     }
+    state.zobrist_hash = zobrist_hash;
+    pruning_stats.record(prune_state_instructions(
+        &mut state_instructions_vec,
+        prune_threshold,
+    ));
     state_instructions_vec
 }
 
@@ -3892,6 +4475,9 @@ pub fn calculate_damage_rolls(
         Choices::SUPERFANG | Choices::NATURESMADNESS | Choices::RUINATION => {
             return Some(vec![defender_active.hp / 2]);
         }
+        Choices::GUARDIANOFALOLA => {
+            return Some(vec![defender_active.hp * 3 / 4]);
+        }
         Choices::SUCKERPUNCH | Choices::THUNDERCLAP => {
             defending_choice = MOVES.get(&Choices::TACKLE).unwrap();
         }
@@ -3983,7 +4569,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
         assert_eq!(instructions, vec![StateInstructions::default()])
     }
@@ -4003,7 +4589,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
         assert_eq!(instructions, vec![StateInstructions::default()])
     }
@@ -4023,7 +4609,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
@@ -4056,7 +4642,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4087,7 +4673,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4112,7 +4698,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4142,7 +4728,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4167,7 +4753,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4192,7 +4778,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -4216,7 +4802,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4256,7 +4842,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4296,7 +4882,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4329,7 +4915,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4373,7 +4959,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4422,7 +5008,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4451,7 +5037,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4484,7 +5070,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4509,7 +5095,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4534,7 +5120,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4558,7 +5144,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4622,7 +5208,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4670,7 +5256,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4741,7 +5327,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4779,7 +5365,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4813,7 +5399,7 @@ mod tests {
             SideReference::SideOne,
             previous_instruction,
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4878,7 +5464,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4908,7 +5494,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -4944,7 +5530,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -4969,7 +5555,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -5015,7 +5601,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5043,7 +5629,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5072,7 +5658,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5099,7 +5685,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -5180,7 +5766,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -5225,7 +5811,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -5309,7 +5895,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5351,7 +5937,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5378,7 +5964,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5402,7 +5988,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5436,7 +6022,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5470,7 +6056,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5497,7 +6083,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5526,7 +6112,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5555,7 +6141,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5579,7 +6165,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -5613,7 +6199,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5642,7 +6228,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5671,7 +6257,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5696,7 +6282,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5721,7 +6307,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5772,7 +6358,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5799,7 +6385,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5833,7 +6419,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5888,7 +6474,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5951,7 +6537,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -5993,7 +6579,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6018,7 +6604,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6047,7 +6633,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6107,7 +6693,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6143,7 +6729,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6183,7 +6769,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![StateInstructions {
@@ -6249,7 +6835,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions = vec![
@@ -6286,7 +6872,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
         assert_eq!(instructions, vec![StateInstructions::default()])
     }
@@ -6306,7 +6892,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
         assert_eq!(instructions, vec![StateInstructions::default()])
     }
@@ -6327,7 +6913,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6357,7 +6943,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6387,7 +6973,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6417,7 +7003,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6453,7 +7039,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6490,7 +7076,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6520,7 +7106,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6549,7 +7135,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6584,7 +7170,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6619,7 +7205,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6654,7 +7240,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6690,7 +7276,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6727,7 +7313,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: Vec<StateInstructions> = vec![
@@ -6764,7 +7350,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: Vec<StateInstructions> = vec![StateInstructions {
@@ -6792,7 +7378,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: Vec<StateInstructions> = vec![
@@ -6830,7 +7416,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: StateInstructions = StateInstructions {
@@ -6865,7 +7451,7 @@ mod tests {
             SideReference::SideOne,
             StateInstructions::default(),
             &mut instructions,
-            false,
+            DamageBranching::None,
         );
 
         let expected_instructions: Vec<StateInstructions> = vec![
@@ -179,10 +179,243 @@ define_enum_with_from_str! {
         LOADEDDICE,
         RUSTEDSWORD,
         RUSTEDSHIELD,
+        VENUSAURITE,
+        CHARIZARDITEX,
+        CHARIZARDITEY,
+        BLASTOISINITE,
+        BEEDRILLITE,
+        PIDGEOTITE,
+        ALAKAZITE,
+        SLOWBRONITE,
+        GENGARITE,
+        KANGASKHANITE,
+        PINSIRITE,
+        GYARADOSITE,
+        AERODACTYLITE,
+        MEWTWONITEX,
+        MEWTWONITEY,
+        AMPHAROSITE,
+        STEELIXITE,
+        SCIZORITE,
+        HERACRONITE,
+        HOUNDOOMINITE,
+        TYRANITARITE,
+        SCEPTILITE,
+        BLAZIKENITE,
+        SWAMPERTITE,
+        GARDEVOIRITE,
+        SABLENITE,
+        MAWILITE,
+        AGGRONITE,
+        MEDICHAMITE,
+        MANECTITE,
+        SHARPEDONITE,
+        CAMERUPTITE,
+        ALTARIANITE,
+        BANETTITE,
+        ABSOLITE,
+        GLALITITE,
+        SALAMENCITE,
+        METAGROSSITE,
+        LATIASITE,
+        LATIOSITE,
+        LOPUNNITE,
+        GARCHOMPITE,
+        LUCARIONITE,
+        ABOMASITE,
+        GALLADITE,
+        AUDINITE,
+        DIANCITE,
+        NORMALIUMZ,
+        FIRIUMZ,
+        WATERIUMZ,
+        ELECTRIUMZ,
+        GRASSIUMZ,
+        ICIUMZ,
+        FIGHTINIUMZ,
+        POISONIUMZ,
+        GROUNDIUMZ,
+        FLYINIUMZ,
+        PSYCHIUMZ,
+        BUGINIUMZ,
+        ROCKIUMZ,
+        GHOSTIUMZ,
+        DRAGONIUMZ,
+        DARKINIUMZ,
+        STEELIUMZ,
+        FAIRIUMZ,
+        PIKANIUMZ,
+        PIKASHUNIUMZ,
+        ALORAICHIUMZ,
+        DECIDIUMZ,
+        INCINIUMZ,
+        PRIMARIUMZ,
+        EEVIUMZ,
+        SNORLIUMZ,
+        MEWNIUMZ,
+        TAPUNIUMZ,
+        MARSHADIUMZ,
+        KOMMONIUMZ,
+        LYCANIUMZ,
+        MIMIKIUMZ,
+        LUNALIUMZ,
+        SOLGANIUMZ,
+        ULTRANECROZIUMZ,
     },
     default = UNKNOWNITEM
 }
 
+impl Items {
+    // the pokemon a mega stone belongs to and the forme it mega evolves into
+    pub fn mega_evolution(&self) -> Option<(PokemonName, PokemonName)> {
+        match self {
+            Items::VENUSAURITE => Some((PokemonName::VENUSAUR, PokemonName::VENUSAURMEGA)),
+            Items::CHARIZARDITEX => Some((PokemonName::CHARIZARD, PokemonName::CHARIZARDMEGAX)),
+            Items::CHARIZARDITEY => Some((PokemonName::CHARIZARD, PokemonName::CHARIZARDMEGAY)),
+            Items::BLASTOISINITE => Some((PokemonName::BLASTOISE, PokemonName::BLASTOISEMEGA)),
+            Items::BEEDRILLITE => Some((PokemonName::BEEDRILL, PokemonName::BEEDRILLMEGA)),
+            Items::PIDGEOTITE => Some((PokemonName::PIDGEOT, PokemonName::PIDGEOTMEGA)),
+            Items::ALAKAZITE => Some((PokemonName::ALAKAZAM, PokemonName::ALAKAZAMMEGA)),
+            Items::SLOWBRONITE => Some((PokemonName::SLOWBRO, PokemonName::SLOWBROMEGA)),
+            Items::GENGARITE => Some((PokemonName::GENGAR, PokemonName::GENGARMEGA)),
+            Items::KANGASKHANITE => Some((PokemonName::KANGASKHAN, PokemonName::KANGASKHANMEGA)),
+            Items::PINSIRITE => Some((PokemonName::PINSIR, PokemonName::PINSIRMEGA)),
+            Items::GYARADOSITE => Some((PokemonName::GYARADOS, PokemonName::GYARADOSMEGA)),
+            Items::AERODACTYLITE => Some((PokemonName::AERODACTYL, PokemonName::AERODACTYLMEGA)),
+            Items::MEWTWONITEX => Some((PokemonName::MEWTWO, PokemonName::MEWTWOMEGAX)),
+            Items::MEWTWONITEY => Some((PokemonName::MEWTWO, PokemonName::MEWTWOMEGAY)),
+            Items::AMPHAROSITE => Some((PokemonName::AMPHAROS, PokemonName::AMPHAROSMEGA)),
+            Items::STEELIXITE => Some((PokemonName::STEELIX, PokemonName::STEELIXMEGA)),
+            Items::SCIZORITE => Some((PokemonName::SCIZOR, PokemonName::SCIZORMEGA)),
+            Items::HERACRONITE => Some((PokemonName::HERACROSS, PokemonName::HERACROSSMEGA)),
+            Items::HOUNDOOMINITE => Some((PokemonName::HOUNDOOM, PokemonName::HOUNDOOMMEGA)),
+            Items::TYRANITARITE => Some((PokemonName::TYRANITAR, PokemonName::TYRANITARMEGA)),
+            Items::SCEPTILITE => Some((PokemonName::SCEPTILE, PokemonName::SCEPTILEMEGA)),
+            Items::BLAZIKENITE => Some((PokemonName::BLAZIKEN, PokemonName::BLAZIKENMEGA)),
+            Items::SWAMPERTITE => Some((PokemonName::SWAMPERT, PokemonName::SWAMPERTMEGA)),
+            Items::GARDEVOIRITE => Some((PokemonName::GARDEVOIR, PokemonName::GARDEVOIRMEGA)),
+            Items::SABLENITE => Some((PokemonName::SABLEYE, PokemonName::SABLEYEMEGA)),
+            Items::MAWILITE => Some((PokemonName::MAWILE, PokemonName::MAWILEMEGA)),
+            Items::AGGRONITE => Some((PokemonName::AGGRON, PokemonName::AGGRONMEGA)),
+            Items::MEDICHAMITE => Some((PokemonName::MEDICHAM, PokemonName::MEDICHAMMEGA)),
+            Items::MANECTITE => Some((PokemonName::MANECTRIC, PokemonName::MANECTRICMEGA)),
+            Items::SHARPEDONITE => Some((PokemonName::SHARPEDO, PokemonName::SHARPEDOMEGA)),
+            Items::CAMERUPTITE => Some((PokemonName::CAMERUPT, PokemonName::CAMERUPTMEGA)),
+            Items::ALTARIANITE => Some((PokemonName::ALTARIA, PokemonName::ALTARIAMEGA)),
+            Items::BANETTITE => Some((PokemonName::BANETTE, PokemonName::BANETTEMEGA)),
+            Items::ABSOLITE => Some((PokemonName::ABSOL, PokemonName::ABSOLMEGA)),
+            Items::GLALITITE => Some((PokemonName::GLALIE, PokemonName::GLALIEMEGA)),
+            Items::SALAMENCITE => Some((PokemonName::SALAMENCE, PokemonName::SALAMENCEMEGA)),
+            Items::METAGROSSITE => Some((PokemonName::METAGROSS, PokemonName::METAGROSSMEGA)),
+            Items::LATIASITE => Some((PokemonName::LATIAS, PokemonName::LATIASMEGA)),
+            Items::LATIOSITE => Some((PokemonName::LATIOS, PokemonName::LATIOSMEGA)),
+            Items::LOPUNNITE => Some((PokemonName::LOPUNNY, PokemonName::LOPUNNYMEGA)),
+            Items::GARCHOMPITE => Some((PokemonName::GARCHOMP, PokemonName::GARCHOMPMEGA)),
+            Items::LUCARIONITE => Some((PokemonName::LUCARIO, PokemonName::LUCARIOMEGA)),
+            Items::ABOMASITE => Some((PokemonName::ABOMASNOW, PokemonName::ABOMASNOWMEGA)),
+            Items::GALLADITE => Some((PokemonName::GALLADE, PokemonName::GALLADEMEGA)),
+            Items::AUDINITE => Some((PokemonName::AUDINO, PokemonName::AUDINOMEGA)),
+            Items::DIANCITE => Some((PokemonName::DIANCIE, PokemonName::DIANCIEMEGA)),
+            _ => None,
+        }
+    }
+
+    // the type of move a z-crystal turns into a z-move
+    pub fn z_crystal_type(&self) -> Option<PokemonType> {
+        match self {
+            Items::NORMALIUMZ => Some(PokemonType::NORMAL),
+            Items::FIRIUMZ => Some(PokemonType::FIRE),
+            Items::WATERIUMZ => Some(PokemonType::WATER),
+            Items::ELECTRIUMZ => Some(PokemonType::ELECTRIC),
+            Items::GRASSIUMZ => Some(PokemonType::GRASS),
+            Items::ICIUMZ => Some(PokemonType::ICE),
+            Items::FIGHTINIUMZ => Some(PokemonType::FIGHTING),
+            Items::POISONIUMZ => Some(PokemonType::POISON),
+            Items::GROUNDIUMZ => Some(PokemonType::GROUND),
+            Items::FLYINIUMZ => Some(PokemonType::FLYING),
+            Items::PSYCHIUMZ => Some(PokemonType::PSYCHIC),
+            Items::BUGINIUMZ => Some(PokemonType::BUG),
+            Items::ROCKIUMZ => Some(PokemonType::ROCK),
+            Items::GHOSTIUMZ => Some(PokemonType::GHOST),
+            Items::DRAGONIUMZ => Some(PokemonType::DRAGON),
+            Items::DARKINIUMZ => Some(PokemonType::DARK),
+            Items::STEELIUMZ => Some(PokemonType::STEEL),
+            Items::FAIRIUMZ => Some(PokemonType::FAIRY),
+            _ => None,
+        }
+    }
+
+    // the pokemon that can use a species-exclusive z-crystal and the z-move it gives them.
+    // The move it is made from is `Choices::exclusive_z_move_base`.
+    // Ultra Burst is not modelled, so Ultranecrozium Z only works for Necrozma that is already
+    // in its Ultra forme
+    pub fn exclusive_z_move(&self) -> Option<(&'static [PokemonName], Choices)> {
+        match self {
+            Items::PIKANIUMZ => Some((&[PokemonName::PIKACHU], Choices::CATASTROPIKA)),
+            Items::PIKASHUNIUMZ => Some((
+                &[
+                    PokemonName::PIKACHUORIGINAL,
+                    PokemonName::PIKACHUHOENN,
+                    PokemonName::PIKACHUSINNOH,
+                    PokemonName::PIKACHUUNOVA,
+                    PokemonName::PIKACHUKALOS,
+                    PokemonName::PIKACHUALOLA,
+                    PokemonName::PIKACHUPARTNER,
+                ],
+                Choices::TENMILLIONVOLTTHUNDERBOLT,
+            )),
+            Items::ALORAICHIUMZ => Some((&[PokemonName::RAICHUALOLA], Choices::STOKEDSPARKSURFER)),
+            Items::DECIDIUMZ => Some((&[PokemonName::DECIDUEYE], Choices::SINISTERARROWRAID)),
+            Items::INCINIUMZ => Some((&[PokemonName::INCINEROAR], Choices::MALICIOUSMOONSAULT)),
+            Items::PRIMARIUMZ => Some((&[PokemonName::PRIMARINA], Choices::OCEANICOPERETTA)),
+            Items::EEVIUMZ => Some((&[PokemonName::EEVEE], Choices::EXTREMEEVOBOOST)),
+            Items::SNORLIUMZ => Some((&[PokemonName::SNORLAX], Choices::PULVERIZINGPANCAKE)),
+            Items::MEWNIUMZ => Some((&[PokemonName::MEW], Choices::GENESISSUPERNOVA)),
+            Items::TAPUNIUMZ => Some((
+                &[
+                    PokemonName::TAPUKOKO,
+                    PokemonName::TAPULELE,
+                    PokemonName::TAPUBULU,
+                    PokemonName::TAPUFINI,
+                ],
+                Choices::GUARDIANOFALOLA,
+            )),
+            Items::MARSHADIUMZ => {
+                Some((&[PokemonName::MARSHADOW], Choices::SOULSTEALING7STARSTRIKE))
+            }
+            Items::KOMMONIUMZ => Some((&[PokemonName::KOMMOO], Choices::CLANGOROUSSOULBLAZE)),
+            Items::LYCANIUMZ => Some((
+                &[
+                    PokemonName::LYCANROC,
+                    PokemonName::LYCANROCMIDNIGHT,
+                    PokemonName::LYCANROCDUSK,
+                ],
+                Choices::SPLINTEREDSTORMSHARDS,
+            )),
+            Items::MIMIKIUMZ => Some((
+                &[PokemonName::MIMIKYU, PokemonName::MIMIKYUBUSTED],
+                Choices::LETSSNUGGLEFOREVER,
+            )),
+            Items::LUNALIUMZ => Some((
+                &[PokemonName::LUNALA, PokemonName::NECROZMADAWNWINGS],
+                Choices::MENACINGMOONRAZEMAELSTROM,
+            )),
+            Items::SOLGANIUMZ => Some((
+                &[PokemonName::SOLGALEO, PokemonName::NECROZMADUSKMANE],
+                Choices::SEARINGSUNRAZESMASH,
+            )),
+            Items::ULTRANECROZIUMZ => {
+                Some((&[PokemonName::NECROZMAULTRA], Choices::LIGHTTHATBURNSTHESKY))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_z_crystal(&self) -> bool {
+        self.z_crystal_type().is_some() || self.exclusive_z_move().is_some()
+    }
+}
+
 pub fn get_choice_move_disable_instructions(
     pkmn: &Pokemon,
     side_ref: &SideReference,
@@ -1497,6 +1730,9 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.2;
             }
         }
+        Items::WIDELENS => {
+            attacking_choice.accuracy *= 1.1;
+        }
         Items::WISEGLASSES => {
             if attacking_choice.category == MoveCategory::Special {
                 attacking_choice.base_power *= 1.1;
@@ -0,0 +1,5396 @@
+use super::abilities::Abilities;
+use crate::pokemon::PokemonName;
+use crate::state::PokemonType;
+
+impl PokemonName {
+    // Types of the species in the current generation
+    // The second type is TYPELESS for single-typed pokemon
+    pub fn types(&self) -> (PokemonType, PokemonType) {
+        match self {
+            PokemonName::NONE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::BULBASAUR => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::IVYSAUR => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::VENUSAUR => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::VENUSAURMEGA => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::VENUSAURGMAX => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::CHARMANDER => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CHARMELEON => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CHARIZARD => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::CHARIZARDMEGAX => (PokemonType::FIRE, PokemonType::DRAGON),
+            PokemonName::CHARIZARDMEGAY => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::CHARIZARDGMAX => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::SQUIRTLE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::WARTORTLE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BLASTOISE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BLASTOISEMEGA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BLASTOISEGMAX => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CATERPIE => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::METAPOD => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::BUTTERFREE => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::BUTTERFREEGMAX => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::WEEDLE => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::KAKUNA => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::BEEDRILL => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::BEEDRILLMEGA => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::PIDGEY => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::PIDGEOTTO => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::PIDGEOT => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::PIDGEOTMEGA => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::RATTATA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RATTATAALOLA => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::RATICATE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RATICATEALOLA => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::RATICATEALOLATOTEM => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::SPEAROW => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::FEAROW => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::EKANS => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::ARBOK => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::PIKACHU => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUCOSPLAY => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUROCKSTAR => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUBELLE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUPOPSTAR => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUPHD => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHULIBRE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUORIGINAL => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUHOENN => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUSINNOH => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUUNOVA => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUKALOS => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUALOLA => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUPARTNER => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUSTARTER => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUGMAX => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PIKACHUWORLD => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::RAICHU => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::RAICHUALOLA => (PokemonType::ELECTRIC, PokemonType::PSYCHIC),
+            PokemonName::SANDSHREW => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SANDSHREWALOLA => (PokemonType::ICE, PokemonType::STEEL),
+            PokemonName::SANDSLASH => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SANDSLASHALOLA => (PokemonType::ICE, PokemonType::STEEL),
+            PokemonName::NIDORANF => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::NIDORINA => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::NIDOQUEEN => (PokemonType::POISON, PokemonType::GROUND),
+            PokemonName::NIDORANM => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::NIDORINO => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::NIDOKING => (PokemonType::POISON, PokemonType::GROUND),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::CLEFAIRY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::CLEFAIRY => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::CLEFABLE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::CLEFABLE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::VULPIX => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::VULPIXALOLA => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::NINETALES => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::NINETALESALOLA => (PokemonType::ICE, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::JIGGLYPUFF => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::JIGGLYPUFF => (PokemonType::NORMAL, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::WIGGLYTUFF => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::WIGGLYTUFF => (PokemonType::NORMAL, PokemonType::FAIRY),
+            PokemonName::ZUBAT => (PokemonType::POISON, PokemonType::FLYING),
+            PokemonName::GOLBAT => (PokemonType::POISON, PokemonType::FLYING),
+            PokemonName::ODDISH => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::GLOOM => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::VILEPLUME => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::PARAS => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::PARASECT => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::VENONAT => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::VENOMOTH => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::DIGLETT => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::DIGLETTALOLA => (PokemonType::GROUND, PokemonType::STEEL),
+            PokemonName::DUGTRIO => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::DUGTRIOALOLA => (PokemonType::GROUND, PokemonType::STEEL),
+            PokemonName::MEOWTH => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::MEOWTHALOLA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::MEOWTHGALAR => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::MEOWTHGMAX => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::PERSIAN => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::PERSIANALOLA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::PSYDUCK => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GOLDUCK => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MANKEY => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::PRIMEAPE => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::GROWLITHE => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::GROWLITHEHISUI => (PokemonType::FIRE, PokemonType::ROCK),
+            PokemonName::ARCANINE => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::ARCANINEHISUI => (PokemonType::FIRE, PokemonType::ROCK),
+            PokemonName::POLIWAG => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::POLIWHIRL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::POLIWRATH => (PokemonType::WATER, PokemonType::FIGHTING),
+            PokemonName::ABRA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::KADABRA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::ALAKAZAM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::ALAKAZAMMEGA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MACHOP => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::MACHOKE => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::MACHAMP => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::MACHAMPGMAX => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::BELLSPROUT => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::WEEPINBELL => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::VICTREEBEL => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::TENTACOOL => (PokemonType::WATER, PokemonType::POISON),
+            PokemonName::TENTACRUEL => (PokemonType::WATER, PokemonType::POISON),
+            PokemonName::GEODUDE => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::GEODUDEALOLA => (PokemonType::ROCK, PokemonType::ELECTRIC),
+            PokemonName::GRAVELER => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::GRAVELERALOLA => (PokemonType::ROCK, PokemonType::ELECTRIC),
+            PokemonName::GOLEM => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::GOLEMALOLA => (PokemonType::ROCK, PokemonType::ELECTRIC),
+            PokemonName::PONYTA => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::PONYTAGALAR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::RAPIDASH => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::RAPIDASHGALAR => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::SLOWPOKE => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::SLOWPOKEGALAR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SLOWBRO => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::SLOWBROMEGA => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::SLOWBROGALAR => (PokemonType::POISON, PokemonType::PSYCHIC),
+            PokemonName::MAGNEMITE => (PokemonType::ELECTRIC, PokemonType::STEEL),
+            PokemonName::MAGNETON => (PokemonType::ELECTRIC, PokemonType::STEEL),
+            PokemonName::FARFETCHD => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::FARFETCHDGALAR => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::DODUO => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::DODRIO => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SEEL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DEWGONG => (PokemonType::WATER, PokemonType::ICE),
+            PokemonName::GRIMER => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::GRIMERALOLA => (PokemonType::POISON, PokemonType::DARK),
+            PokemonName::MUK => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::MUKALOLA => (PokemonType::POISON, PokemonType::DARK),
+            PokemonName::SHELLDER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CLOYSTER => (PokemonType::WATER, PokemonType::ICE),
+            PokemonName::GASTLY => (PokemonType::GHOST, PokemonType::POISON),
+            PokemonName::HAUNTER => (PokemonType::GHOST, PokemonType::POISON),
+            PokemonName::GENGAR => (PokemonType::GHOST, PokemonType::POISON),
+            PokemonName::GENGARMEGA => (PokemonType::GHOST, PokemonType::POISON),
+            PokemonName::GENGARGMAX => (PokemonType::GHOST, PokemonType::POISON),
+            PokemonName::ONIX => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::DROWZEE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::HYPNO => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::KRABBY => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::KINGLER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::KINGLERGMAX => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::VOLTORB => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::VOLTORBHISUI => (PokemonType::ELECTRIC, PokemonType::GRASS),
+            PokemonName::ELECTRODE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ELECTRODEHISUI => (PokemonType::ELECTRIC, PokemonType::GRASS),
+            PokemonName::EXEGGCUTE => (PokemonType::GRASS, PokemonType::PSYCHIC),
+            PokemonName::EXEGGUTOR => (PokemonType::GRASS, PokemonType::PSYCHIC),
+            PokemonName::EXEGGUTORALOLA => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::CUBONE => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::MAROWAK => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::MAROWAKALOLA => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::MAROWAKALOLATOTEM => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::HITMONLEE => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::HITMONCHAN => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::LICKITUNG => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::KOFFING => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::WEEZING => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::WEEZINGGALAR => (PokemonType::POISON, PokemonType::FAIRY),
+            PokemonName::RHYHORN => (PokemonType::GROUND, PokemonType::ROCK),
+            PokemonName::RHYDON => (PokemonType::GROUND, PokemonType::ROCK),
+            PokemonName::CHANSEY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TANGELA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::KANGASKHAN => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::KANGASKHANMEGA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::HORSEA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SEADRA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GOLDEEN => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SEAKING => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::STARYU => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::STARMIE => (PokemonType::WATER, PokemonType::PSYCHIC),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::MRMIME => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::MRMIME => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::MRMIMEGALAR => (PokemonType::ICE, PokemonType::PSYCHIC),
+            PokemonName::SCYTHER => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::JYNX => (PokemonType::ICE, PokemonType::PSYCHIC),
+            PokemonName::ELECTABUZZ => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MAGMAR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::PINSIR => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::PINSIRMEGA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::TAUROS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TAUROSPALDEACOMBAT => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::TAUROSPALDEABLAZE => (PokemonType::FIGHTING, PokemonType::FIRE),
+            PokemonName::TAUROSPALDEAAQUA => (PokemonType::FIGHTING, PokemonType::WATER),
+            PokemonName::MAGIKARP => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GYARADOS => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::GYARADOSMEGA => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::LAPRAS => (PokemonType::WATER, PokemonType::ICE),
+            PokemonName::LAPRASGMAX => (PokemonType::WATER, PokemonType::ICE),
+            PokemonName::DITTO => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::EEVEE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::EEVEESTARTER => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::EEVEEGMAX => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::VAPOREON => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::JOLTEON => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::FLAREON => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::PORYGON => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::OMANYTE => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::OMASTAR => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::KABUTO => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::KABUTOPS => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::AERODACTYL => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::AERODACTYLMEGA => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::SNORLAX => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SNORLAXGMAX => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::ARTICUNO => (PokemonType::ICE, PokemonType::FLYING),
+            PokemonName::ARTICUNOGALAR => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::ZAPDOS => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::ZAPDOSGALAR => (PokemonType::FIGHTING, PokemonType::FLYING),
+            PokemonName::MOLTRES => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::MOLTRESGALAR => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::DRATINI => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::DRAGONAIR => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::DRAGONITE => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::MEWTWO => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MEWTWOMEGAX => (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+            PokemonName::MEWTWOMEGAY => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MEW => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::CHIKORITA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::BAYLEEF => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::MEGANIUM => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::CYNDAQUIL => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::QUILAVA => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::TYPHLOSION => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::TYPHLOSIONHISUI => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::TOTODILE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CROCONAW => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::FERALIGATR => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SENTRET => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURRET => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::HOOTHOOT => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::NOCTOWL => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::LEDYBA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::LEDIAN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::SPINARAK => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::ARIADOS => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::CROBAT => (PokemonType::POISON, PokemonType::FLYING),
+            PokemonName::CHINCHOU => (PokemonType::WATER, PokemonType::ELECTRIC),
+            PokemonName::LANTURN => (PokemonType::WATER, PokemonType::ELECTRIC),
+            PokemonName::PICHU => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PICHUSPIKYEARED => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::CLEFFA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::CLEFFA => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::IGGLYBUFF => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::IGGLYBUFF => (PokemonType::NORMAL, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::TOGEPI => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::TOGEPI => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::TOGETIC => (PokemonType::NORMAL, PokemonType::FLYING),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::TOGETIC => (PokemonType::FAIRY, PokemonType::FLYING),
+            PokemonName::NATU => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::XATU => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::MAREEP => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::FLAAFFY => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::AMPHAROS => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::AMPHAROSMEGA => (PokemonType::ELECTRIC, PokemonType::DRAGON),
+            PokemonName::BELLOSSOM => (PokemonType::GRASS, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::MARILL => (PokemonType::WATER, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::MARILL => (PokemonType::WATER, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::AZUMARILL => (PokemonType::WATER, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::AZUMARILL => (PokemonType::WATER, PokemonType::FAIRY),
+            PokemonName::SUDOWOODO => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::POLITOED => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::HOPPIP => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::SKIPLOOM => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::JUMPLUFF => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::AIPOM => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SUNKERN => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SUNFLORA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::YANMA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::WOOPER => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::WOOPERPALDEA => (PokemonType::POISON, PokemonType::GROUND),
+            PokemonName::QUAGSIRE => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::ESPEON => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UMBREON => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::MURKROW => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::SLOWKING => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::SLOWKINGGALAR => (PokemonType::POISON, PokemonType::PSYCHIC),
+            PokemonName::MISDREAVUS => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::UNOWN => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNB => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNC => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWND => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNF => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNG => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNH => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNI => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNJ => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNK => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNL => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNN => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNO => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNP => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNQ => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNS => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNT => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNU => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNV => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNW => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNX => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNY => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNZ => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNEXCLAMATION => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::UNOWNQUESTION => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::WOBBUFFET => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::GIRAFARIG => (PokemonType::NORMAL, PokemonType::PSYCHIC),
+            PokemonName::PINECO => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::FORRETRESS => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::DUNSPARCE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GLIGAR => (PokemonType::GROUND, PokemonType::FLYING),
+            PokemonName::STEELIX => (PokemonType::STEEL, PokemonType::GROUND),
+            PokemonName::STEELIXMEGA => (PokemonType::STEEL, PokemonType::GROUND),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::SNUBBULL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::SNUBBULL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::GRANBULL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::GRANBULL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::QWILFISH => (PokemonType::WATER, PokemonType::POISON),
+            PokemonName::QWILFISHHISUI => (PokemonType::DARK, PokemonType::POISON),
+            PokemonName::SCIZOR => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::SCIZORMEGA => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::SHUCKLE => (PokemonType::BUG, PokemonType::ROCK),
+            PokemonName::HERACROSS => (PokemonType::BUG, PokemonType::FIGHTING),
+            PokemonName::HERACROSSMEGA => (PokemonType::BUG, PokemonType::FIGHTING),
+            PokemonName::SNEASEL => (PokemonType::DARK, PokemonType::ICE),
+            PokemonName::SNEASELHISUI => (PokemonType::FIGHTING, PokemonType::POISON),
+            PokemonName::TEDDIURSA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::URSARING => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SLUGMA => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::MAGCARGO => (PokemonType::FIRE, PokemonType::ROCK),
+            PokemonName::SWINUB => (PokemonType::ICE, PokemonType::GROUND),
+            PokemonName::PILOSWINE => (PokemonType::ICE, PokemonType::GROUND),
+            PokemonName::CORSOLA => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::CORSOLAGALAR => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::REMORAID => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::OCTILLERY => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DELIBIRD => (PokemonType::ICE, PokemonType::FLYING),
+            PokemonName::MANTINE => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::SKARMORY => (PokemonType::STEEL, PokemonType::FLYING),
+            PokemonName::HOUNDOUR => (PokemonType::DARK, PokemonType::FIRE),
+            PokemonName::HOUNDOOM => (PokemonType::DARK, PokemonType::FIRE),
+            PokemonName::HOUNDOOMMEGA => (PokemonType::DARK, PokemonType::FIRE),
+            PokemonName::KINGDRA => (PokemonType::WATER, PokemonType::DRAGON),
+            PokemonName::PHANPY => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::DONPHAN => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::PORYGON2 => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::STANTLER => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SMEARGLE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TYROGUE => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::HITMONTOP => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::SMOOCHUM => (PokemonType::ICE, PokemonType::PSYCHIC),
+            PokemonName::ELEKID => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MAGBY => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::MILTANK => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::BLISSEY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RAIKOU => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ENTEI => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SUICUNE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::LARVITAR => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::PUPITAR => (PokemonType::ROCK, PokemonType::GROUND),
+            PokemonName::TYRANITAR => (PokemonType::ROCK, PokemonType::DARK),
+            PokemonName::TYRANITARMEGA => (PokemonType::ROCK, PokemonType::DARK),
+            PokemonName::LUGIA => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::HOOH => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::CELEBI => (PokemonType::PSYCHIC, PokemonType::GRASS),
+            PokemonName::TREECKO => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::GROVYLE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SCEPTILE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SCEPTILEMEGA => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::TORCHIC => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::COMBUSKEN => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::BLAZIKEN => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::BLAZIKENMEGA => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::MUDKIP => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MARSHTOMP => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::SWAMPERT => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::SWAMPERTMEGA => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::POOCHYENA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::MIGHTYENA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::ZIGZAGOON => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::ZIGZAGOONGALAR => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::LINOONE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::LINOONEGALAR => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::WURMPLE => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::SILCOON => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::BEAUTIFLY => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::CASCOON => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::DUSTOX => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::LOTAD => (PokemonType::WATER, PokemonType::GRASS),
+            PokemonName::LOMBRE => (PokemonType::WATER, PokemonType::GRASS),
+            PokemonName::LUDICOLO => (PokemonType::WATER, PokemonType::GRASS),
+            PokemonName::SEEDOT => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::NUZLEAF => (PokemonType::GRASS, PokemonType::DARK),
+            PokemonName::SHIFTRY => (PokemonType::GRASS, PokemonType::DARK),
+            PokemonName::TAILLOW => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SWELLOW => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::WINGULL => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::PELIPPER => (PokemonType::WATER, PokemonType::FLYING),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::RALTS => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::RALTS => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::KIRLIA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::KIRLIA => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::GARDEVOIR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::GARDEVOIR => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::GARDEVOIRMEGA => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::SURSKIT => (PokemonType::BUG, PokemonType::WATER),
+            PokemonName::MASQUERAIN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::SHROOMISH => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::BRELOOM => (PokemonType::GRASS, PokemonType::FIGHTING),
+            PokemonName::SLAKOTH => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::VIGOROTH => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SLAKING => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::NINCADA => (PokemonType::BUG, PokemonType::GROUND),
+            PokemonName::NINJASK => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::SHEDINJA => (PokemonType::BUG, PokemonType::GHOST),
+            PokemonName::WHISMUR => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::LOUDRED => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::EXPLOUD => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::MAKUHITA => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::HARIYAMA => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::AZURILL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::AZURILL => (PokemonType::NORMAL, PokemonType::FAIRY),
+            PokemonName::NOSEPASS => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::SKITTY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::DELCATTY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SABLEYE => (PokemonType::DARK, PokemonType::GHOST),
+            PokemonName::SABLEYEMEGA => (PokemonType::DARK, PokemonType::GHOST),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::MAWILE => (PokemonType::STEEL, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::MAWILE => (PokemonType::STEEL, PokemonType::FAIRY),
+            PokemonName::MAWILEMEGA => (PokemonType::STEEL, PokemonType::FAIRY),
+            PokemonName::ARON => (PokemonType::STEEL, PokemonType::ROCK),
+            PokemonName::LAIRON => (PokemonType::STEEL, PokemonType::ROCK),
+            PokemonName::AGGRON => (PokemonType::STEEL, PokemonType::ROCK),
+            PokemonName::AGGRONMEGA => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::MEDITITE => (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+            PokemonName::MEDICHAM => (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+            PokemonName::MEDICHAMMEGA => (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+            PokemonName::ELECTRIKE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MANECTRIC => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MANECTRICMEGA => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PLUSLE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MINUN => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::VOLBEAT => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::ILLUMISE => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::ROSELIA => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::GULPIN => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::SWALOT => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::CARVANHA => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::SHARPEDO => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::SHARPEDOMEGA => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::WAILMER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::WAILORD => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::NUMEL => (PokemonType::FIRE, PokemonType::GROUND),
+            PokemonName::CAMERUPT => (PokemonType::FIRE, PokemonType::GROUND),
+            PokemonName::CAMERUPTMEGA => (PokemonType::FIRE, PokemonType::GROUND),
+            PokemonName::TORKOAL => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SPOINK => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::GRUMPIG => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SPINDA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TRAPINCH => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::VIBRAVA => (PokemonType::GROUND, PokemonType::DRAGON),
+            PokemonName::FLYGON => (PokemonType::GROUND, PokemonType::DRAGON),
+            PokemonName::CACNEA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::CACTURNE => (PokemonType::GRASS, PokemonType::DARK),
+            PokemonName::SWABLU => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::ALTARIA => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::ALTARIAMEGA => (PokemonType::DRAGON, PokemonType::FAIRY),
+            PokemonName::ZANGOOSE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SEVIPER => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::LUNATONE => (PokemonType::ROCK, PokemonType::PSYCHIC),
+            PokemonName::SOLROCK => (PokemonType::ROCK, PokemonType::PSYCHIC),
+            PokemonName::BARBOACH => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::WHISCASH => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::CORPHISH => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CRAWDAUNT => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::BALTOY => (PokemonType::GROUND, PokemonType::PSYCHIC),
+            PokemonName::CLAYDOL => (PokemonType::GROUND, PokemonType::PSYCHIC),
+            PokemonName::LILEEP => (PokemonType::ROCK, PokemonType::GRASS),
+            PokemonName::CRADILY => (PokemonType::ROCK, PokemonType::GRASS),
+            PokemonName::ANORITH => (PokemonType::ROCK, PokemonType::BUG),
+            PokemonName::ARMALDO => (PokemonType::ROCK, PokemonType::BUG),
+            PokemonName::FEEBAS => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MILOTIC => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CASTFORM => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::CASTFORMSUNNY => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CASTFORMRAINY => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CASTFORMSNOWY => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::KECLEON => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SHUPPET => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::BANETTE => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::BANETTEMEGA => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::DUSKULL => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::DUSCLOPS => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::TROPIUS => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::CHIMECHO => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::ABSOL => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::ABSOLMEGA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::WYNAUT => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SNORUNT => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::GLALIE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::GLALIEMEGA => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::SPHEAL => (PokemonType::ICE, PokemonType::WATER),
+            PokemonName::SEALEO => (PokemonType::ICE, PokemonType::WATER),
+            PokemonName::WALREIN => (PokemonType::ICE, PokemonType::WATER),
+            PokemonName::CLAMPERL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::HUNTAIL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GOREBYSS => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::RELICANTH => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::LUVDISC => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BAGON => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::SHELGON => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::SALAMENCE => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::SALAMENCEMEGA => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::BELDUM => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::METANG => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::METAGROSS => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::METAGROSSMEGA => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::REGIROCK => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::REGICE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::REGISTEEL => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::LATIAS => (PokemonType::DRAGON, PokemonType::PSYCHIC),
+            PokemonName::LATIASMEGA => (PokemonType::DRAGON, PokemonType::PSYCHIC),
+            PokemonName::LATIOS => (PokemonType::DRAGON, PokemonType::PSYCHIC),
+            PokemonName::LATIOSMEGA => (PokemonType::DRAGON, PokemonType::PSYCHIC),
+            PokemonName::KYOGRE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::KYOGREPRIMAL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GROUDON => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::GROUDONPRIMAL => (PokemonType::GROUND, PokemonType::FIRE),
+            PokemonName::RAYQUAZA => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::RAYQUAZAMEGA => (PokemonType::DRAGON, PokemonType::FLYING),
+            PokemonName::JIRACHI => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::DEOXYS => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DEOXYSATTACK => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DEOXYSDEFENSE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DEOXYSSPEED => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::TURTWIG => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::GROTLE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::TORTERRA => (PokemonType::GRASS, PokemonType::GROUND),
+            PokemonName::CHIMCHAR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::MONFERNO => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::INFERNAPE => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::PIPLUP => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::PRINPLUP => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::EMPOLEON => (PokemonType::WATER, PokemonType::STEEL),
+            PokemonName::STARLY => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::STARAVIA => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::STARAPTOR => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::BIDOOF => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::BIBAREL => (PokemonType::NORMAL, PokemonType::WATER),
+            PokemonName::KRICKETOT => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::KRICKETUNE => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::SHINX => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::LUXIO => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::LUXRAY => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::BUDEW => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::ROSERADE => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::CRANIDOS => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::RAMPARDOS => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::SHIELDON => (PokemonType::ROCK, PokemonType::STEEL),
+            PokemonName::BASTIODON => (PokemonType::ROCK, PokemonType::STEEL),
+            PokemonName::BURMY => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::BURMYSANDY => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::BURMYTRASH => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::WORMADAM => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::WORMADAMSANDY => (PokemonType::BUG, PokemonType::GROUND),
+            PokemonName::WORMADAMTRASH => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::MOTHIM => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::COMBEE => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VESPIQUEN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::PACHIRISU => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::BUIZEL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::FLOATZEL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CHERUBI => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::CHERRIM => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::CHERRIMSUNSHINE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SHELLOS => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SHELLOSEAST => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GASTRODON => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::GASTRODONEAST => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::AMBIPOM => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::DRIFLOON => (PokemonType::GHOST, PokemonType::FLYING),
+            PokemonName::DRIFBLIM => (PokemonType::GHOST, PokemonType::FLYING),
+            PokemonName::BUNEARY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::LOPUNNY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::LOPUNNYMEGA => (PokemonType::NORMAL, PokemonType::FIGHTING),
+            PokemonName::MISMAGIUS => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::HONCHKROW => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::GLAMEOW => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::PURUGLY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::CHINGLING => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::STUNKY => (PokemonType::POISON, PokemonType::DARK),
+            PokemonName::SKUNTANK => (PokemonType::POISON, PokemonType::DARK),
+            PokemonName::BRONZOR => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::BRONZONG => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::BONSLY => (PokemonType::ROCK, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::MIMEJR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::MIMEJR => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::HAPPINY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::CHATOT => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SPIRITOMB => (PokemonType::GHOST, PokemonType::DARK),
+            PokemonName::GIBLE => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::GABITE => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::GARCHOMP => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::GARCHOMPMEGA => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::MUNCHLAX => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RIOLU => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::LUCARIO => (PokemonType::FIGHTING, PokemonType::STEEL),
+            PokemonName::LUCARIOMEGA => (PokemonType::FIGHTING, PokemonType::STEEL),
+            PokemonName::HIPPOPOTAS => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::HIPPOWDON => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SKORUPI => (PokemonType::POISON, PokemonType::BUG),
+            PokemonName::DRAPION => (PokemonType::POISON, PokemonType::DARK),
+            PokemonName::CROAGUNK => (PokemonType::POISON, PokemonType::FIGHTING),
+            PokemonName::TOXICROAK => (PokemonType::POISON, PokemonType::FIGHTING),
+            PokemonName::CARNIVINE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::FINNEON => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::LUMINEON => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MANTYKE => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::SNOVER => (PokemonType::GRASS, PokemonType::ICE),
+            PokemonName::ABOMASNOW => (PokemonType::GRASS, PokemonType::ICE),
+            PokemonName::ABOMASNOWMEGA => (PokemonType::GRASS, PokemonType::ICE),
+            PokemonName::WEAVILE => (PokemonType::DARK, PokemonType::ICE),
+            PokemonName::MAGNEZONE => (PokemonType::ELECTRIC, PokemonType::STEEL),
+            PokemonName::LICKILICKY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RHYPERIOR => (PokemonType::GROUND, PokemonType::ROCK),
+            PokemonName::TANGROWTH => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::ELECTIVIRE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MAGMORTAR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::TOGEKISS => (PokemonType::NORMAL, PokemonType::FLYING),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::TOGEKISS => (PokemonType::FAIRY, PokemonType::FLYING),
+            PokemonName::YANMEGA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::LEAFEON => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::GLACEON => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::GLISCOR => (PokemonType::GROUND, PokemonType::FLYING),
+            PokemonName::MAMOSWINE => (PokemonType::ICE, PokemonType::GROUND),
+            PokemonName::PORYGONZ => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GALLADE => (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+            PokemonName::GALLADEMEGA => (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+            PokemonName::PROBOPASS => (PokemonType::ROCK, PokemonType::STEEL),
+            PokemonName::DUSKNOIR => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::FROSLASS => (PokemonType::ICE, PokemonType::GHOST),
+            PokemonName::ROTOM => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(feature = "gen4")]
+            PokemonName::ROTOMHEAT => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(not(feature = "gen4"))]
+            PokemonName::ROTOMHEAT => (PokemonType::ELECTRIC, PokemonType::FIRE),
+            #[cfg(feature = "gen4")]
+            PokemonName::ROTOMWASH => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(not(feature = "gen4"))]
+            PokemonName::ROTOMWASH => (PokemonType::ELECTRIC, PokemonType::WATER),
+            #[cfg(feature = "gen4")]
+            PokemonName::ROTOMFROST => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(not(feature = "gen4"))]
+            PokemonName::ROTOMFROST => (PokemonType::ELECTRIC, PokemonType::ICE),
+            #[cfg(feature = "gen4")]
+            PokemonName::ROTOMFAN => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(not(feature = "gen4"))]
+            PokemonName::ROTOMFAN => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            #[cfg(feature = "gen4")]
+            PokemonName::ROTOMMOW => (PokemonType::ELECTRIC, PokemonType::GHOST),
+            #[cfg(not(feature = "gen4"))]
+            PokemonName::ROTOMMOW => (PokemonType::ELECTRIC, PokemonType::GRASS),
+            PokemonName::UXIE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MESPRIT => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::AZELF => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DIALGA => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::DIALGAORIGIN => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::PALKIA => (PokemonType::WATER, PokemonType::DRAGON),
+            PokemonName::PALKIAORIGIN => (PokemonType::WATER, PokemonType::DRAGON),
+            PokemonName::HEATRAN => (PokemonType::FIRE, PokemonType::STEEL),
+            PokemonName::REGIGIGAS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GIRATINA => (PokemonType::GHOST, PokemonType::DRAGON),
+            PokemonName::GIRATINAORIGIN => (PokemonType::GHOST, PokemonType::DRAGON),
+            PokemonName::CRESSELIA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::PHIONE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MANAPHY => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DARKRAI => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::SHAYMIN => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SHAYMINSKY => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::ARCEUS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::ARCEUSBUG => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::ARCEUSDARK => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::ARCEUSDRAGON => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::ARCEUSELECTRIC => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ARCEUSFAIRY => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ARCEUSFIGHTING => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::ARCEUSFIRE => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::ARCEUSFLYING => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::ARCEUSGHOST => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::ARCEUSGRASS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::ARCEUSGROUND => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::ARCEUSICE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::ARCEUSPOISON => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::ARCEUSPSYCHIC => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::ARCEUSROCK => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::ARCEUSSTEEL => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::ARCEUSWATER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::VICTINI => (PokemonType::PSYCHIC, PokemonType::FIRE),
+            PokemonName::SNIVY => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SERVINE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SERPERIOR => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::TEPIG => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::PIGNITE => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::EMBOAR => (PokemonType::FIRE, PokemonType::FIGHTING),
+            PokemonName::OSHAWOTT => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DEWOTT => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SAMUROTT => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SAMUROTTHISUI => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::PATRAT => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::WATCHOG => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::LILLIPUP => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::HERDIER => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::STOUTLAND => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::PURRLOIN => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::LIEPARD => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::PANSAGE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SIMISAGE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::PANSEAR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SIMISEAR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::PANPOUR => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SIMIPOUR => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MUNNA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MUSHARNA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::PIDOVE => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::TRANQUILL => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::UNFEZANT => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::BLITZLE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ZEBSTRIKA => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ROGGENROLA => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::BOLDORE => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::GIGALITH => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::WOOBAT => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::SWOOBAT => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::DRILBUR => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::EXCADRILL => (PokemonType::GROUND, PokemonType::STEEL),
+            PokemonName::AUDINO => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::AUDINOMEGA => (PokemonType::NORMAL, PokemonType::FAIRY),
+            PokemonName::TIMBURR => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::GURDURR => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::CONKELDURR => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::TYMPOLE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::PALPITOAD => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::SEISMITOAD => (PokemonType::WATER, PokemonType::GROUND),
+            PokemonName::THROH => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::SAWK => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::SEWADDLE => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::SWADLOON => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::LEAVANNY => (PokemonType::BUG, PokemonType::GRASS),
+            PokemonName::VENIPEDE => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::WHIRLIPEDE => (PokemonType::BUG, PokemonType::POISON),
+            PokemonName::SCOLIPEDE => (PokemonType::BUG, PokemonType::POISON),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::COTTONEE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::COTTONEE => (PokemonType::GRASS, PokemonType::FAIRY),
+            #[cfg(any(feature = "gen4", feature = "gen5"))]
+            PokemonName::WHIMSICOTT => (PokemonType::GRASS, PokemonType::TYPELESS),
+            #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+            PokemonName::WHIMSICOTT => (PokemonType::GRASS, PokemonType::FAIRY),
+            PokemonName::PETILIL => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::LILLIGANT => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::LILLIGANTHISUI => (PokemonType::GRASS, PokemonType::FIGHTING),
+            PokemonName::BASCULIN => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BASCULINBLUESTRIPED => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BASCULINWHITESTRIPED => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SANDILE => (PokemonType::GROUND, PokemonType::DARK),
+            PokemonName::KROKOROK => (PokemonType::GROUND, PokemonType::DARK),
+            PokemonName::KROOKODILE => (PokemonType::GROUND, PokemonType::DARK),
+            PokemonName::DARUMAKA => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::DARUMAKAGALAR => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::DARMANITAN => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::DARMANITANZEN => (PokemonType::FIRE, PokemonType::PSYCHIC),
+            PokemonName::DARMANITANGALAR => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::DARMANITANGALARZEN => (PokemonType::ICE, PokemonType::FIRE),
+            PokemonName::MARACTUS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::DWEBBLE => (PokemonType::BUG, PokemonType::ROCK),
+            PokemonName::CRUSTLE => (PokemonType::BUG, PokemonType::ROCK),
+            PokemonName::SCRAGGY => (PokemonType::DARK, PokemonType::FIGHTING),
+            PokemonName::SCRAFTY => (PokemonType::DARK, PokemonType::FIGHTING),
+            PokemonName::SIGILYPH => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::YAMASK => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::YAMASKGALAR => (PokemonType::GROUND, PokemonType::GHOST),
+            PokemonName::COFAGRIGUS => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::TIRTOUGA => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::CARRACOSTA => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::ARCHEN => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::ARCHEOPS => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::TRUBBISH => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::GARBODOR => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::GARBODORGMAX => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::ZORUA => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::ZORUAHISUI => (PokemonType::NORMAL, PokemonType::GHOST),
+            PokemonName::ZOROARK => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::ZOROARKHISUI => (PokemonType::NORMAL, PokemonType::GHOST),
+            PokemonName::MINCCINO => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::CINCCINO => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GOTHITA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::GOTHORITA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::GOTHITELLE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SOLOSIS => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DUOSION => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::REUNICLUS => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::DUCKLETT => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::SWANNA => (PokemonType::WATER, PokemonType::FLYING),
+            PokemonName::VANILLITE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::VANILLISH => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::VANILLUXE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::DEERLING => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::DEERLINGSUMMER => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::DEERLINGAUTUMN => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::DEERLINGWINTER => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::SAWSBUCK => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::SAWSBUCKSUMMER => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::SAWSBUCKAUTUMN => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::SAWSBUCKWINTER => (PokemonType::NORMAL, PokemonType::GRASS),
+            PokemonName::EMOLGA => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::KARRABLAST => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::ESCAVALIER => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::FOONGUS => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::AMOONGUSS => (PokemonType::GRASS, PokemonType::POISON),
+            PokemonName::FRILLISH => (PokemonType::WATER, PokemonType::GHOST),
+            PokemonName::JELLICENT => (PokemonType::WATER, PokemonType::GHOST),
+            PokemonName::ALOMOMOLA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::JOLTIK => (PokemonType::BUG, PokemonType::ELECTRIC),
+            PokemonName::GALVANTULA => (PokemonType::BUG, PokemonType::ELECTRIC),
+            PokemonName::FERROSEED => (PokemonType::GRASS, PokemonType::STEEL),
+            PokemonName::FERROTHORN => (PokemonType::GRASS, PokemonType::STEEL),
+            PokemonName::KLINK => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::KLANG => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::KLINKLANG => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::TYNAMO => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::EELEKTRIK => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::EELEKTROSS => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ELGYEM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::BEHEEYEM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::LITWICK => (PokemonType::GHOST, PokemonType::FIRE),
+            PokemonName::LAMPENT => (PokemonType::GHOST, PokemonType::FIRE),
+            PokemonName::CHANDELURE => (PokemonType::GHOST, PokemonType::FIRE),
+            PokemonName::AXEW => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::FRAXURE => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::HAXORUS => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::CUBCHOO => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::BEARTIC => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::CRYOGONAL => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::SHELMET => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::ACCELGOR => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::STUNFISK => (PokemonType::GROUND, PokemonType::ELECTRIC),
+            PokemonName::STUNFISKGALAR => (PokemonType::GROUND, PokemonType::STEEL),
+            PokemonName::MIENFOO => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::MIENSHAO => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::DRUDDIGON => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::GOLETT => (PokemonType::GROUND, PokemonType::GHOST),
+            PokemonName::GOLURK => (PokemonType::GROUND, PokemonType::GHOST),
+            PokemonName::PAWNIARD => (PokemonType::DARK, PokemonType::STEEL),
+            PokemonName::BISHARP => (PokemonType::DARK, PokemonType::STEEL),
+            PokemonName::BOUFFALANT => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::RUFFLET => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::BRAVIARY => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::BRAVIARYHISUI => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::VULLABY => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::MANDIBUZZ => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::HEATMOR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::DURANT => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::DEINO => (PokemonType::DARK, PokemonType::DRAGON),
+            PokemonName::ZWEILOUS => (PokemonType::DARK, PokemonType::DRAGON),
+            PokemonName::HYDREIGON => (PokemonType::DARK, PokemonType::DRAGON),
+            PokemonName::LARVESTA => (PokemonType::BUG, PokemonType::FIRE),
+            PokemonName::VOLCARONA => (PokemonType::BUG, PokemonType::FIRE),
+            PokemonName::COBALION => (PokemonType::STEEL, PokemonType::FIGHTING),
+            PokemonName::TERRAKION => (PokemonType::ROCK, PokemonType::FIGHTING),
+            PokemonName::VIRIZION => (PokemonType::GRASS, PokemonType::FIGHTING),
+            PokemonName::TORNADUS => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::TORNADUSTHERIAN => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::THUNDURUS => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::THUNDURUSTHERIAN => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::RESHIRAM => (PokemonType::DRAGON, PokemonType::FIRE),
+            PokemonName::ZEKROM => (PokemonType::DRAGON, PokemonType::ELECTRIC),
+            PokemonName::LANDORUS => (PokemonType::GROUND, PokemonType::FLYING),
+            PokemonName::LANDORUSTHERIAN => (PokemonType::GROUND, PokemonType::FLYING),
+            PokemonName::KYUREM => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::KYUREMBLACK => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::KYUREMWHITE => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::KELDEO => (PokemonType::WATER, PokemonType::FIGHTING),
+            PokemonName::KELDEORESOLUTE => (PokemonType::WATER, PokemonType::FIGHTING),
+            PokemonName::MELOETTA => (PokemonType::NORMAL, PokemonType::PSYCHIC),
+            PokemonName::MELOETTAPIROUETTE => (PokemonType::NORMAL, PokemonType::FIGHTING),
+            PokemonName::GENESECT => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::GENESECTDOUSE => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::GENESECTSHOCK => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::GENESECTBURN => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::GENESECTCHILL => (PokemonType::BUG, PokemonType::STEEL),
+            PokemonName::CHESPIN => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::QUILLADIN => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::CHESNAUGHT => (PokemonType::GRASS, PokemonType::FIGHTING),
+            PokemonName::FENNEKIN => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::BRAIXEN => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::DELPHOX => (PokemonType::FIRE, PokemonType::PSYCHIC),
+            PokemonName::FROAKIE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::FROGADIER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::GRENINJA => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::GRENINJABOND => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::GRENINJAASH => (PokemonType::WATER, PokemonType::DARK),
+            PokemonName::BUNNELBY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::DIGGERSBY => (PokemonType::NORMAL, PokemonType::GROUND),
+            PokemonName::FLETCHLING => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::FLETCHINDER => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::TALONFLAME => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::SCATTERBUG => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::SPEWPA => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::VIVILLON => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONARCHIPELAGO => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONCONTINENTAL => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONELEGANT => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONGARDEN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONHIGHPLAINS => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONICYSNOW => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONJUNGLE => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONMARINE => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONMODERN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONMONSOON => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONOCEAN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONPOLAR => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONRIVER => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONSANDSTORM => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONSAVANNA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONSUN => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONTUNDRA => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONFANCY => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::VIVILLONPOKEBALL => (PokemonType::BUG, PokemonType::FLYING),
+            PokemonName::LITLEO => (PokemonType::FIRE, PokemonType::NORMAL),
+            PokemonName::PYROAR => (PokemonType::FIRE, PokemonType::NORMAL),
+            PokemonName::FLABEBE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLABEBEBLUE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLABEBEORANGE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLABEBEWHITE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLABEBEYELLOW => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTEBLUE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTEORANGE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTEWHITE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTEYELLOW => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLOETTEETERNAL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLORGES => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLORGESBLUE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLORGESORANGE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLORGESWHITE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FLORGESYELLOW => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::SKIDDO => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::GOGOAT => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::PANCHAM => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::PANGORO => (PokemonType::FIGHTING, PokemonType::DARK),
+            PokemonName::FURFROU => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUDANDY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUDEBUTANTE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUDIAMOND => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUHEART => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUKABUKI => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROULAREINE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUMATRON => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUPHARAOH => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FURFROUSTAR => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::ESPURR => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MEOWSTIC => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::MEOWSTICF => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::HONEDGE => (PokemonType::STEEL, PokemonType::GHOST),
+            PokemonName::DOUBLADE => (PokemonType::STEEL, PokemonType::GHOST),
+            PokemonName::AEGISLASH => (PokemonType::STEEL, PokemonType::GHOST),
+            PokemonName::AEGISLASHBLADE => (PokemonType::STEEL, PokemonType::GHOST),
+            PokemonName::SPRITZEE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::AROMATISSE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::SWIRLIX => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::SLURPUFF => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::INKAY => (PokemonType::DARK, PokemonType::PSYCHIC),
+            PokemonName::MALAMAR => (PokemonType::DARK, PokemonType::PSYCHIC),
+            PokemonName::BINACLE => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::BARBARACLE => (PokemonType::ROCK, PokemonType::WATER),
+            PokemonName::SKRELP => (PokemonType::POISON, PokemonType::WATER),
+            PokemonName::DRAGALGE => (PokemonType::POISON, PokemonType::DRAGON),
+            PokemonName::CLAUNCHER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::CLAWITZER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::HELIOPTILE => (PokemonType::ELECTRIC, PokemonType::NORMAL),
+            PokemonName::HELIOLISK => (PokemonType::ELECTRIC, PokemonType::NORMAL),
+            PokemonName::TYRUNT => (PokemonType::ROCK, PokemonType::DRAGON),
+            PokemonName::TYRANTRUM => (PokemonType::ROCK, PokemonType::DRAGON),
+            PokemonName::AMAURA => (PokemonType::ROCK, PokemonType::ICE),
+            PokemonName::AURORUS => (PokemonType::ROCK, PokemonType::ICE),
+            PokemonName::SYLVEON => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::HAWLUCHA => (PokemonType::FIGHTING, PokemonType::FLYING),
+            PokemonName::DEDENNE => (PokemonType::ELECTRIC, PokemonType::FAIRY),
+            PokemonName::CARBINK => (PokemonType::ROCK, PokemonType::FAIRY),
+            PokemonName::GOOMY => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::SLIGGOO => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::SLIGGOOHISUI => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::GOODRA => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::GOODRAHISUI => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::KLEFKI => (PokemonType::STEEL, PokemonType::FAIRY),
+            PokemonName::PHANTUMP => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::TREVENANT => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::PUMPKABOO => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::PUMPKABOOSMALL => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::PUMPKABOOLARGE => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::PUMPKABOOSUPER => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::GOURGEIST => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::GOURGEISTSMALL => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::GOURGEISTLARGE => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::GOURGEISTSUPER => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::BERGMITE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::AVALUGG => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::AVALUGGHISUI => (PokemonType::ICE, PokemonType::ROCK),
+            PokemonName::NOIBAT => (PokemonType::FLYING, PokemonType::DRAGON),
+            PokemonName::NOIVERN => (PokemonType::FLYING, PokemonType::DRAGON),
+            PokemonName::XERNEAS => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::XERNEASNEUTRAL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::YVELTAL => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::ZYGARDE => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::ZYGARDE10 => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::ZYGARDECOMPLETE => (PokemonType::DRAGON, PokemonType::GROUND),
+            PokemonName::DIANCIE => (PokemonType::ROCK, PokemonType::FAIRY),
+            PokemonName::DIANCIEMEGA => (PokemonType::ROCK, PokemonType::FAIRY),
+            PokemonName::HOOPA => (PokemonType::PSYCHIC, PokemonType::GHOST),
+            PokemonName::HOOPAUNBOUND => (PokemonType::PSYCHIC, PokemonType::DARK),
+            PokemonName::VOLCANION => (PokemonType::FIRE, PokemonType::WATER),
+            PokemonName::ROWLET => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::DARTRIX => (PokemonType::GRASS, PokemonType::FLYING),
+            PokemonName::DECIDUEYE => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::DECIDUEYEHISUI => (PokemonType::GRASS, PokemonType::FIGHTING),
+            PokemonName::LITTEN => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::TORRACAT => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::INCINEROAR => (PokemonType::FIRE, PokemonType::DARK),
+            PokemonName::POPPLIO => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BRIONNE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::PRIMARINA => (PokemonType::WATER, PokemonType::FAIRY),
+            PokemonName::PIKIPEK => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::TRUMBEAK => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::TOUCANNON => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::YUNGOOS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GUMSHOOS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GUMSHOOSTOTEM => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GRUBBIN => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::CHARJABUG => (PokemonType::BUG, PokemonType::ELECTRIC),
+            PokemonName::VIKAVOLT => (PokemonType::BUG, PokemonType::ELECTRIC),
+            PokemonName::VIKAVOLTTOTEM => (PokemonType::BUG, PokemonType::ELECTRIC),
+            PokemonName::CRABRAWLER => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::CRABOMINABLE => (PokemonType::FIGHTING, PokemonType::ICE),
+            PokemonName::ORICORIO => (PokemonType::FIRE, PokemonType::FLYING),
+            PokemonName::ORICORIOPOMPOM => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::ORICORIOPAU => (PokemonType::PSYCHIC, PokemonType::FLYING),
+            PokemonName::ORICORIOSENSU => (PokemonType::GHOST, PokemonType::FLYING),
+            PokemonName::CUTIEFLY => (PokemonType::BUG, PokemonType::FAIRY),
+            PokemonName::RIBOMBEE => (PokemonType::BUG, PokemonType::FAIRY),
+            PokemonName::RIBOMBEETOTEM => (PokemonType::BUG, PokemonType::FAIRY),
+            PokemonName::ROCKRUFF => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::LYCANROC => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::LYCANROCMIDNIGHT => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::LYCANROCDUSK => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::WISHIWASHI => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::WISHIWASHISCHOOL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MAREANIE => (PokemonType::POISON, PokemonType::WATER),
+            PokemonName::TOXAPEX => (PokemonType::POISON, PokemonType::WATER),
+            PokemonName::MUDBRAY => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::MUDSDALE => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::DEWPIDER => (PokemonType::WATER, PokemonType::BUG),
+            PokemonName::ARAQUANID => (PokemonType::WATER, PokemonType::BUG),
+            PokemonName::ARAQUANIDTOTEM => (PokemonType::WATER, PokemonType::BUG),
+            PokemonName::FOMANTIS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::LURANTIS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::LURANTISTOTEM => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::MORELULL => (PokemonType::GRASS, PokemonType::FAIRY),
+            PokemonName::SHIINOTIC => (PokemonType::GRASS, PokemonType::FAIRY),
+            PokemonName::SALANDIT => (PokemonType::POISON, PokemonType::FIRE),
+            PokemonName::SALAZZLE => (PokemonType::POISON, PokemonType::FIRE),
+            PokemonName::SALAZZLETOTEM => (PokemonType::POISON, PokemonType::FIRE),
+            PokemonName::STUFFUL => (PokemonType::NORMAL, PokemonType::FIGHTING),
+            PokemonName::BEWEAR => (PokemonType::NORMAL, PokemonType::FIGHTING),
+            PokemonName::BOUNSWEET => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::STEENEE => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::TSAREENA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::COMFEY => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ORANGURU => (PokemonType::NORMAL, PokemonType::PSYCHIC),
+            PokemonName::PASSIMIAN => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::WIMPOD => (PokemonType::BUG, PokemonType::WATER),
+            PokemonName::GOLISOPOD => (PokemonType::BUG, PokemonType::WATER),
+            PokemonName::SANDYGAST => (PokemonType::GHOST, PokemonType::GROUND),
+            PokemonName::PALOSSAND => (PokemonType::GHOST, PokemonType::GROUND),
+            PokemonName::PYUKUMUKU => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::TYPENULL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SILVALLY => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::SILVALLYBUG => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::SILVALLYDARK => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::SILVALLYDRAGON => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::SILVALLYELECTRIC => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::SILVALLYFAIRY => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::SILVALLYFIGHTING => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::SILVALLYFIRE => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SILVALLYFLYING => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::SILVALLYGHOST => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::SILVALLYGRASS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SILVALLYGROUND => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SILVALLYICE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::SILVALLYPOISON => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::SILVALLYPSYCHIC => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SILVALLYROCK => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::SILVALLYSTEEL => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::SILVALLYWATER => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::MINIOR => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORORANGE => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORYELLOW => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORGREEN => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORBLUE => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORINDIGO => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORVIOLET => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::MINIORMETEOR => (PokemonType::ROCK, PokemonType::FLYING),
+            PokemonName::KOMALA => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TURTONATOR => (PokemonType::FIRE, PokemonType::DRAGON),
+            PokemonName::TOGEDEMARU => (PokemonType::ELECTRIC, PokemonType::STEEL),
+            PokemonName::TOGEDEMARUTOTEM => (PokemonType::ELECTRIC, PokemonType::STEEL),
+            PokemonName::MIMIKYU => (PokemonType::GHOST, PokemonType::FAIRY),
+            PokemonName::MIMIKYUBUSTED => (PokemonType::GHOST, PokemonType::FAIRY),
+            PokemonName::MIMIKYUTOTEM => (PokemonType::GHOST, PokemonType::FAIRY),
+            PokemonName::MIMIKYUBUSTEDTOTEM => (PokemonType::GHOST, PokemonType::FAIRY),
+            PokemonName::BRUXISH => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::DRAMPA => (PokemonType::NORMAL, PokemonType::DRAGON),
+            PokemonName::DHELMISE => (PokemonType::GHOST, PokemonType::GRASS),
+            PokemonName::JANGMOO => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::HAKAMOO => (PokemonType::DRAGON, PokemonType::FIGHTING),
+            PokemonName::KOMMOO => (PokemonType::DRAGON, PokemonType::FIGHTING),
+            PokemonName::KOMMOOTOTEM => (PokemonType::DRAGON, PokemonType::FIGHTING),
+            PokemonName::TAPUKOKO => (PokemonType::ELECTRIC, PokemonType::FAIRY),
+            PokemonName::TAPULELE => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::TAPUBULU => (PokemonType::GRASS, PokemonType::FAIRY),
+            PokemonName::TAPUFINI => (PokemonType::WATER, PokemonType::FAIRY),
+            PokemonName::COSMOG => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::COSMOEM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::SOLGALEO => (PokemonType::PSYCHIC, PokemonType::STEEL),
+            PokemonName::LUNALA => (PokemonType::PSYCHIC, PokemonType::GHOST),
+            PokemonName::NIHILEGO => (PokemonType::ROCK, PokemonType::POISON),
+            PokemonName::BUZZWOLE => (PokemonType::BUG, PokemonType::FIGHTING),
+            PokemonName::PHEROMOSA => (PokemonType::BUG, PokemonType::FIGHTING),
+            PokemonName::XURKITREE => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::CELESTEELA => (PokemonType::STEEL, PokemonType::FLYING),
+            PokemonName::KARTANA => (PokemonType::GRASS, PokemonType::STEEL),
+            PokemonName::GUZZLORD => (PokemonType::DARK, PokemonType::DRAGON),
+            PokemonName::NECROZMA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::NECROZMADUSKMANE => (PokemonType::PSYCHIC, PokemonType::STEEL),
+            PokemonName::NECROZMADAWNWINGS => (PokemonType::PSYCHIC, PokemonType::GHOST),
+            PokemonName::NECROZMAULTRA => (PokemonType::PSYCHIC, PokemonType::DRAGON),
+            PokemonName::MAGEARNA => (PokemonType::STEEL, PokemonType::FAIRY),
+            PokemonName::MAGEARNAORIGINAL => (PokemonType::STEEL, PokemonType::FAIRY),
+            PokemonName::MARSHADOW => (PokemonType::FIGHTING, PokemonType::GHOST),
+            PokemonName::POIPOLE => (PokemonType::POISON, PokemonType::TYPELESS),
+            PokemonName::NAGANADEL => (PokemonType::POISON, PokemonType::DRAGON),
+            PokemonName::STAKATAKA => (PokemonType::ROCK, PokemonType::STEEL),
+            PokemonName::BLACEPHALON => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::ZERAORA => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::MELTAN => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::MELMETAL => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::MELMETALGMAX => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::GROOKEY => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::THWACKEY => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::RILLABOOM => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::RILLABOOMGMAX => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SCORBUNNY => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::RABOOT => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CINDERACE => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CINDERACEGMAX => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SOBBLE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DRIZZILE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::INTELEON => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::INTELEONGMAX => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::SKWOVET => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::GREEDENT => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::ROOKIDEE => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::CORVISQUIRE => (PokemonType::FLYING, PokemonType::TYPELESS),
+            PokemonName::CORVIKNIGHT => (PokemonType::FLYING, PokemonType::STEEL),
+            PokemonName::CORVIKNIGHTGMAX => (PokemonType::FLYING, PokemonType::STEEL),
+            PokemonName::BLIPBUG => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::DOTTLER => (PokemonType::BUG, PokemonType::PSYCHIC),
+            PokemonName::ORBEETLE => (PokemonType::BUG, PokemonType::PSYCHIC),
+            PokemonName::ORBEETLEGMAX => (PokemonType::BUG, PokemonType::PSYCHIC),
+            PokemonName::NICKIT => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::THIEVUL => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::GOSSIFLEUR => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::ELDEGOSS => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::WOOLOO => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::DUBWOOL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::CHEWTLE => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::DREDNAW => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::DREDNAWGMAX => (PokemonType::WATER, PokemonType::ROCK),
+            PokemonName::YAMPER => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::BOLTUND => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::ROLYCOLY => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::CARKOL => (PokemonType::ROCK, PokemonType::FIRE),
+            PokemonName::COALOSSAL => (PokemonType::ROCK, PokemonType::FIRE),
+            PokemonName::COALOSSALGMAX => (PokemonType::ROCK, PokemonType::FIRE),
+            PokemonName::APPLIN => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::FLAPPLE => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::FLAPPLEGMAX => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::APPLETUN => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::APPLETUNGMAX => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::SILICOBRA => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SANDACONDA => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::SANDACONDAGMAX => (PokemonType::GROUND, PokemonType::TYPELESS),
+            PokemonName::CRAMORANT => (PokemonType::FLYING, PokemonType::WATER),
+            PokemonName::CRAMORANTGULPING => (PokemonType::FLYING, PokemonType::WATER),
+            PokemonName::CRAMORANTGORGING => (PokemonType::FLYING, PokemonType::WATER),
+            PokemonName::ARROKUDA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BARRASKEWDA => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::TOXEL => (PokemonType::ELECTRIC, PokemonType::POISON),
+            PokemonName::TOXTRICITY => (PokemonType::ELECTRIC, PokemonType::POISON),
+            PokemonName::TOXTRICITYLOWKEY => (PokemonType::ELECTRIC, PokemonType::POISON),
+            PokemonName::TOXTRICITYGMAX => (PokemonType::ELECTRIC, PokemonType::POISON),
+            PokemonName::TOXTRICITYLOWKEYGMAX => (PokemonType::ELECTRIC, PokemonType::POISON),
+            PokemonName::SIZZLIPEDE => (PokemonType::FIRE, PokemonType::BUG),
+            PokemonName::CENTISKORCH => (PokemonType::FIRE, PokemonType::BUG),
+            PokemonName::CENTISKORCHGMAX => (PokemonType::FIRE, PokemonType::BUG),
+            PokemonName::CLOBBOPUS => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::GRAPPLOCT => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::SINISTEA => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::SINISTEAANTIQUE => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::POLTEAGEIST => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::POLTEAGEISTANTIQUE => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::HATENNA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::HATTREM => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::HATTERENE => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::HATTERENEGMAX => (PokemonType::PSYCHIC, PokemonType::FAIRY),
+            PokemonName::IMPIDIMP => (PokemonType::DARK, PokemonType::FAIRY),
+            PokemonName::MORGREM => (PokemonType::DARK, PokemonType::FAIRY),
+            PokemonName::GRIMMSNARL => (PokemonType::DARK, PokemonType::FAIRY),
+            PokemonName::GRIMMSNARLGMAX => (PokemonType::DARK, PokemonType::FAIRY),
+            PokemonName::OBSTAGOON => (PokemonType::DARK, PokemonType::NORMAL),
+            PokemonName::PERRSERKER => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::CURSOLA => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::SIRFETCHD => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::MRRIME => (PokemonType::ICE, PokemonType::PSYCHIC),
+            PokemonName::RUNERIGUS => (PokemonType::GROUND, PokemonType::GHOST),
+            PokemonName::MILCERY => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIE => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIERUBYCREAM => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIEMATCHACREAM => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIEMINTCREAM => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIELEMONCREAM => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIESALTEDCREAM => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIERUBYSWIRL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIECARAMELSWIRL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIERAINBOWSWIRL => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ALCREMIEGMAX => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::FALINKS => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::PINCURCHIN => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::SNOM => (PokemonType::ICE, PokemonType::BUG),
+            PokemonName::FROSMOTH => (PokemonType::ICE, PokemonType::BUG),
+            PokemonName::STONJOURNER => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::EISCUE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::EISCUENOICE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::INDEEDEE => (PokemonType::PSYCHIC, PokemonType::NORMAL),
+            PokemonName::INDEEDEEF => (PokemonType::PSYCHIC, PokemonType::NORMAL),
+            PokemonName::MORPEKO => (PokemonType::ELECTRIC, PokemonType::DARK),
+            PokemonName::MORPEKOHANGRY => (PokemonType::ELECTRIC, PokemonType::DARK),
+            PokemonName::CUFANT => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::COPPERAJAH => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::COPPERAJAHGMAX => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::DRACOZOLT => (PokemonType::ELECTRIC, PokemonType::DRAGON),
+            PokemonName::ARCTOZOLT => (PokemonType::ELECTRIC, PokemonType::ICE),
+            PokemonName::DRACOVISH => (PokemonType::WATER, PokemonType::DRAGON),
+            PokemonName::ARCTOVISH => (PokemonType::WATER, PokemonType::ICE),
+            PokemonName::DURALUDON => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::DURALUDONGMAX => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::DREEPY => (PokemonType::DRAGON, PokemonType::GHOST),
+            PokemonName::DRAKLOAK => (PokemonType::DRAGON, PokemonType::GHOST),
+            PokemonName::DRAGAPULT => (PokemonType::DRAGON, PokemonType::GHOST),
+            PokemonName::ZACIAN => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::ZACIANCROWNED => (PokemonType::FAIRY, PokemonType::STEEL),
+            PokemonName::ZAMAZENTA => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::ZAMAZENTACROWNED => (PokemonType::FIGHTING, PokemonType::STEEL),
+            PokemonName::ETERNATUS => (PokemonType::POISON, PokemonType::DRAGON),
+            PokemonName::ETERNATUSETERNAMAX => (PokemonType::POISON, PokemonType::DRAGON),
+            PokemonName::KUBFU => (PokemonType::FIGHTING, PokemonType::TYPELESS),
+            PokemonName::URSHIFU => (PokemonType::FIGHTING, PokemonType::DARK),
+            PokemonName::URSHIFURAPIDSTRIKE => (PokemonType::FIGHTING, PokemonType::WATER),
+            PokemonName::URSHIFUGMAX => (PokemonType::FIGHTING, PokemonType::DARK),
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => (PokemonType::FIGHTING, PokemonType::WATER),
+            PokemonName::ZARUDE => (PokemonType::DARK, PokemonType::GRASS),
+            PokemonName::ZARUDEDADA => (PokemonType::DARK, PokemonType::GRASS),
+            PokemonName::REGIELEKI => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::REGIDRAGO => (PokemonType::DRAGON, PokemonType::TYPELESS),
+            PokemonName::GLASTRIER => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::SPECTRIER => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::CALYREX => (PokemonType::PSYCHIC, PokemonType::GRASS),
+            PokemonName::CALYREXICE => (PokemonType::PSYCHIC, PokemonType::ICE),
+            PokemonName::CALYREXSHADOW => (PokemonType::PSYCHIC, PokemonType::GHOST),
+            PokemonName::WYRDEER => (PokemonType::NORMAL, PokemonType::PSYCHIC),
+            PokemonName::KLEAVOR => (PokemonType::BUG, PokemonType::ROCK),
+            PokemonName::URSALUNA => (PokemonType::GROUND, PokemonType::NORMAL),
+            PokemonName::URSALUNABLOODMOON => (PokemonType::GROUND, PokemonType::NORMAL),
+            PokemonName::BASCULEGION => (PokemonType::WATER, PokemonType::GHOST),
+            PokemonName::BASCULEGIONF => (PokemonType::WATER, PokemonType::GHOST),
+            PokemonName::SNEASLER => (PokemonType::FIGHTING, PokemonType::POISON),
+            PokemonName::OVERQWIL => (PokemonType::DARK, PokemonType::POISON),
+            PokemonName::ENAMORUS => (PokemonType::FAIRY, PokemonType::FLYING),
+            PokemonName::ENAMORUSTHERIAN => (PokemonType::FAIRY, PokemonType::FLYING),
+            PokemonName::SPRIGATITO => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::FLORAGATO => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::MEOWSCARADA => (PokemonType::GRASS, PokemonType::DARK),
+            PokemonName::FUECOCO => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::CROCALOR => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::SKELEDIRGE => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::QUAXLY => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::QUAXWELL => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::QUAQUAVAL => (PokemonType::WATER, PokemonType::FIGHTING),
+            PokemonName::LECHONK => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::OINKOLOGNE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::OINKOLOGNEF => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TAROUNTULA => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::SPIDOPS => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::NYMBLE => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::LOKIX => (PokemonType::BUG, PokemonType::DARK),
+            PokemonName::PAWMI => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::PAWMO => (PokemonType::ELECTRIC, PokemonType::FIGHTING),
+            PokemonName::PAWMOT => (PokemonType::ELECTRIC, PokemonType::FIGHTING),
+            PokemonName::TANDEMAUS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::MAUSHOLD => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::MAUSHOLDFOUR => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::FIDOUGH => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::DACHSBUN => (PokemonType::FAIRY, PokemonType::TYPELESS),
+            PokemonName::SMOLIV => (PokemonType::GRASS, PokemonType::NORMAL),
+            PokemonName::DOLLIV => (PokemonType::GRASS, PokemonType::NORMAL),
+            PokemonName::ARBOLIVA => (PokemonType::GRASS, PokemonType::NORMAL),
+            PokemonName::SQUAWKABILLY => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SQUAWKABILLYBLUE => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SQUAWKABILLYYELLOW => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::SQUAWKABILLYWHITE => (PokemonType::NORMAL, PokemonType::FLYING),
+            PokemonName::NACLI => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::NACLSTACK => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::GARGANACL => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::CHARCADET => (PokemonType::FIRE, PokemonType::TYPELESS),
+            PokemonName::ARMAROUGE => (PokemonType::FIRE, PokemonType::PSYCHIC),
+            PokemonName::CERULEDGE => (PokemonType::FIRE, PokemonType::GHOST),
+            PokemonName::TADBULB => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::BELLIBOLT => (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            PokemonName::WATTREL => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::KILOWATTREL => (PokemonType::ELECTRIC, PokemonType::FLYING),
+            PokemonName::MASCHIFF => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::MABOSSTIFF => (PokemonType::DARK, PokemonType::TYPELESS),
+            PokemonName::SHROODLE => (PokemonType::POISON, PokemonType::NORMAL),
+            PokemonName::GRAFAIAI => (PokemonType::POISON, PokemonType::NORMAL),
+            PokemonName::BRAMBLIN => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::BRAMBLEGHAST => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::TOEDSCOOL => (PokemonType::GROUND, PokemonType::GRASS),
+            PokemonName::TOEDSCRUEL => (PokemonType::GROUND, PokemonType::GRASS),
+            PokemonName::KLAWF => (PokemonType::ROCK, PokemonType::TYPELESS),
+            PokemonName::CAPSAKID => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::SCOVILLAIN => (PokemonType::GRASS, PokemonType::FIRE),
+            PokemonName::RELLOR => (PokemonType::BUG, PokemonType::TYPELESS),
+            PokemonName::RABSCA => (PokemonType::BUG, PokemonType::PSYCHIC),
+            PokemonName::FLITTLE => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::ESPATHRA => (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+            PokemonName::TINKATINK => (PokemonType::FAIRY, PokemonType::STEEL),
+            PokemonName::TINKATUFF => (PokemonType::FAIRY, PokemonType::STEEL),
+            PokemonName::TINKATON => (PokemonType::FAIRY, PokemonType::STEEL),
+            PokemonName::WIGLETT => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::WUGTRIO => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::BOMBIRDIER => (PokemonType::FLYING, PokemonType::DARK),
+            PokemonName::FINIZEN => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::PALAFIN => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::PALAFINHERO => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::VAROOM => (PokemonType::STEEL, PokemonType::POISON),
+            PokemonName::REVAVROOM => (PokemonType::STEEL, PokemonType::POISON),
+            PokemonName::CYCLIZAR => (PokemonType::DRAGON, PokemonType::NORMAL),
+            PokemonName::ORTHWORM => (PokemonType::STEEL, PokemonType::TYPELESS),
+            PokemonName::GLIMMET => (PokemonType::ROCK, PokemonType::POISON),
+            PokemonName::GLIMMORA => (PokemonType::ROCK, PokemonType::POISON),
+            PokemonName::GREAVARD => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::HOUNDSTONE => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::FLAMIGO => (PokemonType::FLYING, PokemonType::FIGHTING),
+            PokemonName::CETODDLE => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::CETITAN => (PokemonType::ICE, PokemonType::TYPELESS),
+            PokemonName::VELUZA => (PokemonType::WATER, PokemonType::PSYCHIC),
+            PokemonName::DONDOZO => (PokemonType::WATER, PokemonType::TYPELESS),
+            PokemonName::TATSUGIRI => (PokemonType::DRAGON, PokemonType::WATER),
+            PokemonName::TATSUGIRIDROOPY => (PokemonType::DRAGON, PokemonType::WATER),
+            PokemonName::TATSUGIRISTRETCHY => (PokemonType::DRAGON, PokemonType::WATER),
+            PokemonName::ANNIHILAPE => (PokemonType::FIGHTING, PokemonType::GHOST),
+            PokemonName::CLODSIRE => (PokemonType::POISON, PokemonType::GROUND),
+            PokemonName::FARIGIRAF => (PokemonType::NORMAL, PokemonType::PSYCHIC),
+            PokemonName::DUDUNSPARCE => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::DUDUNSPARCETHREESEGMENT => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::KINGAMBIT => (PokemonType::DARK, PokemonType::STEEL),
+            PokemonName::GREATTUSK => (PokemonType::GROUND, PokemonType::FIGHTING),
+            PokemonName::SCREAMTAIL => (PokemonType::FAIRY, PokemonType::PSYCHIC),
+            PokemonName::BRUTEBONNET => (PokemonType::GRASS, PokemonType::DARK),
+            PokemonName::FLUTTERMANE => (PokemonType::GHOST, PokemonType::FAIRY),
+            PokemonName::SLITHERWING => (PokemonType::BUG, PokemonType::FIGHTING),
+            PokemonName::SANDYSHOCKS => (PokemonType::ELECTRIC, PokemonType::GROUND),
+            PokemonName::IRONTREADS => (PokemonType::GROUND, PokemonType::STEEL),
+            PokemonName::IRONBUNDLE => (PokemonType::ICE, PokemonType::WATER),
+            PokemonName::IRONHANDS => (PokemonType::FIGHTING, PokemonType::ELECTRIC),
+            PokemonName::IRONJUGULIS => (PokemonType::DARK, PokemonType::FLYING),
+            PokemonName::IRONMOTH => (PokemonType::FIRE, PokemonType::POISON),
+            PokemonName::IRONTHORNS => (PokemonType::ROCK, PokemonType::ELECTRIC),
+            PokemonName::FRIGIBAX => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::ARCTIBAX => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::BAXCALIBUR => (PokemonType::DRAGON, PokemonType::ICE),
+            PokemonName::GIMMIGHOUL => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::GIMMIGHOULROAMING => (PokemonType::GHOST, PokemonType::TYPELESS),
+            PokemonName::GHOLDENGO => (PokemonType::STEEL, PokemonType::GHOST),
+            PokemonName::WOCHIEN => (PokemonType::DARK, PokemonType::GRASS),
+            PokemonName::CHIENPAO => (PokemonType::DARK, PokemonType::ICE),
+            PokemonName::TINGLU => (PokemonType::DARK, PokemonType::GROUND),
+            PokemonName::CHIYU => (PokemonType::DARK, PokemonType::FIRE),
+            PokemonName::ROARINGMOON => (PokemonType::DRAGON, PokemonType::DARK),
+            PokemonName::IRONVALIANT => (PokemonType::FAIRY, PokemonType::FIGHTING),
+            PokemonName::KORAIDON => (PokemonType::FIGHTING, PokemonType::DRAGON),
+            PokemonName::MIRAIDON => (PokemonType::ELECTRIC, PokemonType::DRAGON),
+            PokemonName::WALKINGWAKE => (PokemonType::WATER, PokemonType::DRAGON),
+            PokemonName::IRONLEAVES => (PokemonType::GRASS, PokemonType::PSYCHIC),
+            PokemonName::DIPPLIN => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::POLTCHAGEIST => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::POLTCHAGEISTARTISAN => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::SINISTCHA => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::SINISTCHAMASTERPIECE => (PokemonType::GRASS, PokemonType::GHOST),
+            PokemonName::OKIDOGI => (PokemonType::POISON, PokemonType::FIGHTING),
+            PokemonName::MUNKIDORI => (PokemonType::POISON, PokemonType::PSYCHIC),
+            PokemonName::FEZANDIPITI => (PokemonType::POISON, PokemonType::FAIRY),
+            PokemonName::OGERPON => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::OGERPONWELLSPRING => (PokemonType::GRASS, PokemonType::WATER),
+            PokemonName::OGERPONHEARTHFLAME => (PokemonType::GRASS, PokemonType::FIRE),
+            PokemonName::OGERPONCORNERSTONE => (PokemonType::GRASS, PokemonType::ROCK),
+            PokemonName::OGERPONTEALTERA => (PokemonType::GRASS, PokemonType::TYPELESS),
+            PokemonName::OGERPONWELLSPRINGTERA => (PokemonType::GRASS, PokemonType::WATER),
+            PokemonName::OGERPONHEARTHFLAMETERA => (PokemonType::GRASS, PokemonType::FIRE),
+            PokemonName::OGERPONCORNERSTONETERA => (PokemonType::GRASS, PokemonType::ROCK),
+            PokemonName::ARCHALUDON => (PokemonType::STEEL, PokemonType::DRAGON),
+            PokemonName::HYDRAPPLE => (PokemonType::GRASS, PokemonType::DRAGON),
+            PokemonName::GOUGINGFIRE => (PokemonType::FIRE, PokemonType::DRAGON),
+            PokemonName::RAGINGBOLT => (PokemonType::ELECTRIC, PokemonType::DRAGON),
+            PokemonName::IRONBOULDER => (PokemonType::ROCK, PokemonType::PSYCHIC),
+            PokemonName::IRONCROWN => (PokemonType::STEEL, PokemonType::PSYCHIC),
+            PokemonName::TERAPAGOS => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TERAPAGOSTERASTAL => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::TERAPAGOSSTELLAR => (PokemonType::NORMAL, PokemonType::TYPELESS),
+            PokemonName::PECHARUNT => (PokemonType::POISON, PokemonType::GHOST),
+        }
+    }
+
+    pub fn weight_kg(&self) -> f32 {
+        match self {
+            PokemonName::NONE => 0.0,
+            PokemonName::BULBASAUR => 6.9,
+            PokemonName::IVYSAUR => 13.0,
+            PokemonName::VENUSAUR => 100.0,
+            PokemonName::VENUSAURMEGA => 155.5,
+            PokemonName::VENUSAURGMAX => 100.0,
+            PokemonName::CHARMANDER => 8.5,
+            PokemonName::CHARMELEON => 19.0,
+            PokemonName::CHARIZARD => 90.5,
+            PokemonName::CHARIZARDMEGAX => 110.5,
+            PokemonName::CHARIZARDMEGAY => 100.5,
+            PokemonName::CHARIZARDGMAX => 90.5,
+            PokemonName::SQUIRTLE => 9.0,
+            PokemonName::WARTORTLE => 22.5,
+            PokemonName::BLASTOISE => 85.5,
+            PokemonName::BLASTOISEMEGA => 101.1,
+            PokemonName::BLASTOISEGMAX => 85.5,
+            PokemonName::CATERPIE => 2.9,
+            PokemonName::METAPOD => 9.9,
+            PokemonName::BUTTERFREE => 32.0,
+            PokemonName::BUTTERFREEGMAX => 32.0,
+            PokemonName::WEEDLE => 3.2,
+            PokemonName::KAKUNA => 10.0,
+            PokemonName::BEEDRILL => 29.5,
+            PokemonName::BEEDRILLMEGA => 40.5,
+            PokemonName::PIDGEY => 1.8,
+            PokemonName::PIDGEOTTO => 30.0,
+            PokemonName::PIDGEOT => 39.5,
+            PokemonName::PIDGEOTMEGA => 50.5,
+            PokemonName::RATTATA => 3.5,
+            PokemonName::RATTATAALOLA => 3.8,
+            PokemonName::RATICATE => 18.5,
+            PokemonName::RATICATEALOLA => 25.5,
+            PokemonName::RATICATEALOLATOTEM => 105.0,
+            PokemonName::SPEAROW => 2.0,
+            PokemonName::FEAROW => 38.0,
+            PokemonName::EKANS => 6.9,
+            PokemonName::ARBOK => 65.0,
+            PokemonName::PIKACHU => 6.0,
+            PokemonName::PIKACHUCOSPLAY => 6.0,
+            PokemonName::PIKACHUROCKSTAR => 6.0,
+            PokemonName::PIKACHUBELLE => 6.0,
+            PokemonName::PIKACHUPOPSTAR => 6.0,
+            PokemonName::PIKACHUPHD => 6.0,
+            PokemonName::PIKACHULIBRE => 6.0,
+            PokemonName::PIKACHUORIGINAL => 6.0,
+            PokemonName::PIKACHUHOENN => 6.0,
+            PokemonName::PIKACHUSINNOH => 6.0,
+            PokemonName::PIKACHUUNOVA => 6.0,
+            PokemonName::PIKACHUKALOS => 6.0,
+            PokemonName::PIKACHUALOLA => 6.0,
+            PokemonName::PIKACHUPARTNER => 6.0,
+            PokemonName::PIKACHUSTARTER => 6.0,
+            PokemonName::PIKACHUGMAX => 6.0,
+            PokemonName::PIKACHUWORLD => 6.0,
+            PokemonName::RAICHU => 30.0,
+            PokemonName::RAICHUALOLA => 21.0,
+            PokemonName::SANDSHREW => 12.0,
+            PokemonName::SANDSHREWALOLA => 40.0,
+            PokemonName::SANDSLASH => 29.5,
+            PokemonName::SANDSLASHALOLA => 55.0,
+            PokemonName::NIDORANF => 7.0,
+            PokemonName::NIDORINA => 20.0,
+            PokemonName::NIDOQUEEN => 60.0,
+            PokemonName::NIDORANM => 9.0,
+            PokemonName::NIDORINO => 19.5,
+            PokemonName::NIDOKING => 62.0,
+            PokemonName::CLEFAIRY => 7.5,
+            PokemonName::CLEFABLE => 40.0,
+            PokemonName::VULPIX => 9.9,
+            PokemonName::VULPIXALOLA => 9.9,
+            PokemonName::NINETALES => 19.9,
+            PokemonName::NINETALESALOLA => 19.9,
+            PokemonName::JIGGLYPUFF => 5.5,
+            PokemonName::WIGGLYTUFF => 12.0,
+            PokemonName::ZUBAT => 7.5,
+            PokemonName::GOLBAT => 55.0,
+            PokemonName::ODDISH => 5.4,
+            PokemonName::GLOOM => 8.6,
+            PokemonName::VILEPLUME => 18.6,
+            PokemonName::PARAS => 5.4,
+            PokemonName::PARASECT => 29.5,
+            PokemonName::VENONAT => 30.0,
+            PokemonName::VENOMOTH => 12.5,
+            PokemonName::DIGLETT => 0.8,
+            PokemonName::DIGLETTALOLA => 1.0,
+            PokemonName::DUGTRIO => 33.3,
+            PokemonName::DUGTRIOALOLA => 66.6,
+            PokemonName::MEOWTH => 4.2,
+            PokemonName::MEOWTHALOLA => 4.2,
+            PokemonName::MEOWTHGALAR => 7.5,
+            PokemonName::MEOWTHGMAX => 4.2,
+            PokemonName::PERSIAN => 32.0,
+            PokemonName::PERSIANALOLA => 33.0,
+            PokemonName::PSYDUCK => 19.6,
+            PokemonName::GOLDUCK => 76.6,
+            PokemonName::MANKEY => 28.0,
+            PokemonName::PRIMEAPE => 32.0,
+            PokemonName::GROWLITHE => 19.0,
+            PokemonName::GROWLITHEHISUI => 22.7,
+            PokemonName::ARCANINE => 155.0,
+            PokemonName::ARCANINEHISUI => 168.0,
+            PokemonName::POLIWAG => 12.4,
+            PokemonName::POLIWHIRL => 20.0,
+            PokemonName::POLIWRATH => 54.0,
+            PokemonName::ABRA => 19.5,
+            PokemonName::KADABRA => 56.5,
+            PokemonName::ALAKAZAM => 48.0,
+            PokemonName::ALAKAZAMMEGA => 48.0,
+            PokemonName::MACHOP => 19.5,
+            PokemonName::MACHOKE => 70.5,
+            PokemonName::MACHAMP => 130.0,
+            PokemonName::MACHAMPGMAX => 130.0,
+            PokemonName::BELLSPROUT => 4.0,
+            PokemonName::WEEPINBELL => 6.4,
+            PokemonName::VICTREEBEL => 15.5,
+            PokemonName::TENTACOOL => 45.5,
+            PokemonName::TENTACRUEL => 55.0,
+            PokemonName::GEODUDE => 20.0,
+            PokemonName::GEODUDEALOLA => 20.3,
+            PokemonName::GRAVELER => 105.0,
+            PokemonName::GRAVELERALOLA => 110.0,
+            PokemonName::GOLEM => 300.0,
+            PokemonName::GOLEMALOLA => 316.0,
+            PokemonName::PONYTA => 30.0,
+            PokemonName::PONYTAGALAR => 24.0,
+            PokemonName::RAPIDASH => 95.0,
+            PokemonName::RAPIDASHGALAR => 80.0,
+            PokemonName::SLOWPOKE => 36.0,
+            PokemonName::SLOWPOKEGALAR => 36.0,
+            PokemonName::SLOWBRO => 78.5,
+            PokemonName::SLOWBROMEGA => 120.0,
+            PokemonName::SLOWBROGALAR => 70.5,
+            PokemonName::MAGNEMITE => 6.0,
+            PokemonName::MAGNETON => 60.0,
+            PokemonName::FARFETCHD => 15.0,
+            PokemonName::FARFETCHDGALAR => 42.0,
+            PokemonName::DODUO => 39.2,
+            PokemonName::DODRIO => 85.2,
+            PokemonName::SEEL => 90.0,
+            PokemonName::DEWGONG => 120.0,
+            PokemonName::GRIMER => 30.0,
+            PokemonName::GRIMERALOLA => 42.0,
+            PokemonName::MUK => 30.0,
+            PokemonName::MUKALOLA => 52.0,
+            PokemonName::SHELLDER => 4.0,
+            PokemonName::CLOYSTER => 132.5,
+            PokemonName::GASTLY => 0.1,
+            PokemonName::HAUNTER => 0.1,
+            PokemonName::GENGAR => 40.5,
+            PokemonName::GENGARMEGA => 40.5,
+            PokemonName::GENGARGMAX => 40.5,
+            PokemonName::ONIX => 210.0,
+            PokemonName::DROWZEE => 32.4,
+            PokemonName::HYPNO => 75.6,
+            PokemonName::KRABBY => 6.5,
+            PokemonName::KINGLER => 60.0,
+            PokemonName::KINGLERGMAX => 60.0,
+            PokemonName::VOLTORB => 10.4,
+            PokemonName::VOLTORBHISUI => 13.0,
+            PokemonName::ELECTRODE => 66.6,
+            PokemonName::ELECTRODEHISUI => 71.0,
+            PokemonName::EXEGGCUTE => 2.5,
+            PokemonName::EXEGGUTOR => 120.0,
+            PokemonName::EXEGGUTORALOLA => 415.6,
+            PokemonName::CUBONE => 6.5,
+            PokemonName::MAROWAK => 45.0,
+            PokemonName::MAROWAKALOLA => 34.0,
+            PokemonName::MAROWAKALOLATOTEM => 98.0,
+            PokemonName::HITMONLEE => 49.8,
+            PokemonName::HITMONCHAN => 50.2,
+            PokemonName::LICKITUNG => 65.5,
+            PokemonName::KOFFING => 1.0,
+            PokemonName::WEEZING => 9.5,
+            PokemonName::WEEZINGGALAR => 16.0,
+            PokemonName::RHYHORN => 115.0,
+            PokemonName::RHYDON => 120.0,
+            PokemonName::CHANSEY => 34.6,
+            PokemonName::TANGELA => 35.0,
+            PokemonName::KANGASKHAN => 80.0,
+            PokemonName::KANGASKHANMEGA => 100.0,
+            PokemonName::HORSEA => 8.0,
+            PokemonName::SEADRA => 25.0,
+            PokemonName::GOLDEEN => 15.0,
+            PokemonName::SEAKING => 39.0,
+            PokemonName::STARYU => 34.5,
+            PokemonName::STARMIE => 80.0,
+            PokemonName::MRMIME => 54.5,
+            PokemonName::MRMIMEGALAR => 56.8,
+            PokemonName::SCYTHER => 56.0,
+            PokemonName::JYNX => 40.6,
+            PokemonName::ELECTABUZZ => 30.0,
+            PokemonName::MAGMAR => 44.5,
+            PokemonName::PINSIR => 55.0,
+            PokemonName::PINSIRMEGA => 59.0,
+            PokemonName::TAUROS => 88.4,
+            PokemonName::TAUROSPALDEACOMBAT => 115.0,
+            PokemonName::TAUROSPALDEABLAZE => 85.0,
+            PokemonName::TAUROSPALDEAAQUA => 110.0,
+            PokemonName::MAGIKARP => 10.0,
+            PokemonName::GYARADOS => 235.0,
+            PokemonName::GYARADOSMEGA => 305.0,
+            PokemonName::LAPRAS => 220.0,
+            PokemonName::LAPRASGMAX => 220.0,
+            PokemonName::DITTO => 4.0,
+            PokemonName::EEVEE => 6.5,
+            PokemonName::EEVEESTARTER => 6.5,
+            PokemonName::EEVEEGMAX => 6.5,
+            PokemonName::VAPOREON => 29.0,
+            PokemonName::JOLTEON => 24.5,
+            PokemonName::FLAREON => 25.0,
+            PokemonName::PORYGON => 36.5,
+            PokemonName::OMANYTE => 7.5,
+            PokemonName::OMASTAR => 35.0,
+            PokemonName::KABUTO => 11.5,
+            PokemonName::KABUTOPS => 40.5,
+            PokemonName::AERODACTYL => 59.0,
+            PokemonName::AERODACTYLMEGA => 79.0,
+            PokemonName::SNORLAX => 460.0,
+            PokemonName::SNORLAXGMAX => 460.0,
+            PokemonName::ARTICUNO => 55.4,
+            PokemonName::ARTICUNOGALAR => 50.9,
+            PokemonName::ZAPDOS => 52.6,
+            PokemonName::ZAPDOSGALAR => 58.2,
+            PokemonName::MOLTRES => 60.0,
+            PokemonName::MOLTRESGALAR => 66.0,
+            PokemonName::DRATINI => 3.3,
+            PokemonName::DRAGONAIR => 16.5,
+            PokemonName::DRAGONITE => 210.0,
+            PokemonName::MEWTWO => 122.0,
+            PokemonName::MEWTWOMEGAX => 127.0,
+            PokemonName::MEWTWOMEGAY => 33.0,
+            PokemonName::MEW => 4.0,
+            PokemonName::CHIKORITA => 6.4,
+            PokemonName::BAYLEEF => 15.8,
+            PokemonName::MEGANIUM => 100.5,
+            PokemonName::CYNDAQUIL => 7.9,
+            PokemonName::QUILAVA => 19.0,
+            PokemonName::TYPHLOSION => 79.5,
+            PokemonName::TYPHLOSIONHISUI => 69.8,
+            PokemonName::TOTODILE => 9.5,
+            PokemonName::CROCONAW => 25.0,
+            PokemonName::FERALIGATR => 88.8,
+            PokemonName::SENTRET => 6.0,
+            PokemonName::FURRET => 32.5,
+            PokemonName::HOOTHOOT => 21.2,
+            PokemonName::NOCTOWL => 40.8,
+            PokemonName::LEDYBA => 10.8,
+            PokemonName::LEDIAN => 35.6,
+            PokemonName::SPINARAK => 8.5,
+            PokemonName::ARIADOS => 33.5,
+            PokemonName::CROBAT => 75.0,
+            PokemonName::CHINCHOU => 12.0,
+            PokemonName::LANTURN => 22.5,
+            PokemonName::PICHU => 2.0,
+            PokemonName::PICHUSPIKYEARED => 2.0,
+            PokemonName::CLEFFA => 3.0,
+            PokemonName::IGGLYBUFF => 1.0,
+            PokemonName::TOGEPI => 1.5,
+            PokemonName::TOGETIC => 3.2,
+            PokemonName::NATU => 2.0,
+            PokemonName::XATU => 15.0,
+            PokemonName::MAREEP => 7.8,
+            PokemonName::FLAAFFY => 13.3,
+            PokemonName::AMPHAROS => 61.5,
+            PokemonName::AMPHAROSMEGA => 61.5,
+            PokemonName::BELLOSSOM => 5.8,
+            PokemonName::MARILL => 8.5,
+            PokemonName::AZUMARILL => 28.5,
+            PokemonName::SUDOWOODO => 38.0,
+            PokemonName::POLITOED => 33.9,
+            PokemonName::HOPPIP => 0.5,
+            PokemonName::SKIPLOOM => 1.0,
+            PokemonName::JUMPLUFF => 3.0,
+            PokemonName::AIPOM => 11.5,
+            PokemonName::SUNKERN => 1.8,
+            PokemonName::SUNFLORA => 8.5,
+            PokemonName::YANMA => 38.0,
+            PokemonName::WOOPER => 8.5,
+            PokemonName::WOOPERPALDEA => 11.0,
+            PokemonName::QUAGSIRE => 75.0,
+            PokemonName::ESPEON => 26.5,
+            PokemonName::UMBREON => 27.0,
+            PokemonName::MURKROW => 2.1,
+            PokemonName::SLOWKING => 79.5,
+            PokemonName::SLOWKINGGALAR => 79.5,
+            PokemonName::MISDREAVUS => 1.0,
+            PokemonName::UNOWN => 5.0,
+            PokemonName::UNOWNB => 5.0,
+            PokemonName::UNOWNC => 5.0,
+            PokemonName::UNOWND => 5.0,
+            PokemonName::UNOWNE => 5.0,
+            PokemonName::UNOWNF => 5.0,
+            PokemonName::UNOWNG => 5.0,
+            PokemonName::UNOWNH => 5.0,
+            PokemonName::UNOWNI => 5.0,
+            PokemonName::UNOWNJ => 5.0,
+            PokemonName::UNOWNK => 5.0,
+            PokemonName::UNOWNL => 5.0,
+            PokemonName::UNOWNM => 5.0,
+            PokemonName::UNOWNN => 5.0,
+            PokemonName::UNOWNO => 5.0,
+            PokemonName::UNOWNP => 5.0,
+            PokemonName::UNOWNQ => 5.0,
+            PokemonName::UNOWNR => 5.0,
+            PokemonName::UNOWNS => 5.0,
+            PokemonName::UNOWNT => 5.0,
+            PokemonName::UNOWNU => 5.0,
+            PokemonName::UNOWNV => 5.0,
+            PokemonName::UNOWNW => 5.0,
+            PokemonName::UNOWNX => 5.0,
+            PokemonName::UNOWNY => 5.0,
+            PokemonName::UNOWNZ => 5.0,
+            PokemonName::UNOWNEXCLAMATION => 5.0,
+            PokemonName::UNOWNQUESTION => 5.0,
+            PokemonName::WOBBUFFET => 28.5,
+            PokemonName::GIRAFARIG => 41.5,
+            PokemonName::PINECO => 7.2,
+            PokemonName::FORRETRESS => 125.8,
+            PokemonName::DUNSPARCE => 14.0,
+            PokemonName::GLIGAR => 64.8,
+            PokemonName::STEELIX => 400.0,
+            PokemonName::STEELIXMEGA => 740.0,
+            PokemonName::SNUBBULL => 7.8,
+            PokemonName::GRANBULL => 48.7,
+            PokemonName::QWILFISH => 3.9,
+            PokemonName::QWILFISHHISUI => 3.9,
+            PokemonName::SCIZOR => 118.0,
+            PokemonName::SCIZORMEGA => 125.0,
+            PokemonName::SHUCKLE => 20.5,
+            PokemonName::HERACROSS => 54.0,
+            PokemonName::HERACROSSMEGA => 62.5,
+            PokemonName::SNEASEL => 28.0,
+            PokemonName::SNEASELHISUI => 27.0,
+            PokemonName::TEDDIURSA => 8.8,
+            PokemonName::URSARING => 125.8,
+            PokemonName::SLUGMA => 35.0,
+            PokemonName::MAGCARGO => 55.0,
+            PokemonName::SWINUB => 6.5,
+            PokemonName::PILOSWINE => 55.8,
+            PokemonName::CORSOLA => 5.0,
+            PokemonName::CORSOLAGALAR => 0.5,
+            PokemonName::REMORAID => 12.0,
+            PokemonName::OCTILLERY => 28.5,
+            PokemonName::DELIBIRD => 16.0,
+            PokemonName::MANTINE => 220.0,
+            PokemonName::SKARMORY => 50.5,
+            PokemonName::HOUNDOUR => 10.8,
+            PokemonName::HOUNDOOM => 35.0,
+            PokemonName::HOUNDOOMMEGA => 49.5,
+            PokemonName::KINGDRA => 152.0,
+            PokemonName::PHANPY => 33.5,
+            PokemonName::DONPHAN => 120.0,
+            PokemonName::PORYGON2 => 32.5,
+            PokemonName::STANTLER => 71.2,
+            PokemonName::SMEARGLE => 58.0,
+            PokemonName::TYROGUE => 21.0,
+            PokemonName::HITMONTOP => 48.0,
+            PokemonName::SMOOCHUM => 6.0,
+            PokemonName::ELEKID => 23.5,
+            PokemonName::MAGBY => 21.4,
+            PokemonName::MILTANK => 75.5,
+            PokemonName::BLISSEY => 46.8,
+            PokemonName::RAIKOU => 178.0,
+            PokemonName::ENTEI => 198.0,
+            PokemonName::SUICUNE => 187.0,
+            PokemonName::LARVITAR => 72.0,
+            PokemonName::PUPITAR => 152.0,
+            PokemonName::TYRANITAR => 202.0,
+            PokemonName::TYRANITARMEGA => 255.0,
+            PokemonName::LUGIA => 216.0,
+            PokemonName::HOOH => 199.0,
+            PokemonName::CELEBI => 5.0,
+            PokemonName::TREECKO => 5.0,
+            PokemonName::GROVYLE => 21.6,
+            PokemonName::SCEPTILE => 52.2,
+            PokemonName::SCEPTILEMEGA => 55.2,
+            PokemonName::TORCHIC => 2.5,
+            PokemonName::COMBUSKEN => 19.5,
+            PokemonName::BLAZIKEN => 52.0,
+            PokemonName::BLAZIKENMEGA => 52.0,
+            PokemonName::MUDKIP => 7.6,
+            PokemonName::MARSHTOMP => 28.0,
+            PokemonName::SWAMPERT => 81.9,
+            PokemonName::SWAMPERTMEGA => 102.0,
+            PokemonName::POOCHYENA => 13.6,
+            PokemonName::MIGHTYENA => 37.0,
+            PokemonName::ZIGZAGOON => 17.5,
+            PokemonName::ZIGZAGOONGALAR => 17.5,
+            PokemonName::LINOONE => 32.5,
+            PokemonName::LINOONEGALAR => 32.5,
+            PokemonName::WURMPLE => 3.6,
+            PokemonName::SILCOON => 10.0,
+            PokemonName::BEAUTIFLY => 28.4,
+            PokemonName::CASCOON => 11.5,
+            PokemonName::DUSTOX => 31.6,
+            PokemonName::LOTAD => 2.6,
+            PokemonName::LOMBRE => 32.5,
+            PokemonName::LUDICOLO => 55.0,
+            PokemonName::SEEDOT => 4.0,
+            PokemonName::NUZLEAF => 28.0,
+            PokemonName::SHIFTRY => 59.6,
+            PokemonName::TAILLOW => 2.3,
+            PokemonName::SWELLOW => 19.8,
+            PokemonName::WINGULL => 9.5,
+            PokemonName::PELIPPER => 28.0,
+            PokemonName::RALTS => 6.6,
+            PokemonName::KIRLIA => 20.2,
+            PokemonName::GARDEVOIR => 48.4,
+            PokemonName::GARDEVOIRMEGA => 48.4,
+            PokemonName::SURSKIT => 1.7,
+            PokemonName::MASQUERAIN => 3.6,
+            PokemonName::SHROOMISH => 4.5,
+            PokemonName::BRELOOM => 39.2,
+            PokemonName::SLAKOTH => 24.0,
+            PokemonName::VIGOROTH => 46.5,
+            PokemonName::SLAKING => 130.5,
+            PokemonName::NINCADA => 5.5,
+            PokemonName::NINJASK => 12.0,
+            PokemonName::SHEDINJA => 1.2,
+            PokemonName::WHISMUR => 16.3,
+            PokemonName::LOUDRED => 40.5,
+            PokemonName::EXPLOUD => 84.0,
+            PokemonName::MAKUHITA => 86.4,
+            PokemonName::HARIYAMA => 253.8,
+            PokemonName::AZURILL => 2.0,
+            PokemonName::NOSEPASS => 97.0,
+            PokemonName::SKITTY => 11.0,
+            PokemonName::DELCATTY => 32.6,
+            PokemonName::SABLEYE => 11.0,
+            PokemonName::SABLEYEMEGA => 161.0,
+            PokemonName::MAWILE => 11.5,
+            PokemonName::MAWILEMEGA => 23.5,
+            PokemonName::ARON => 60.0,
+            PokemonName::LAIRON => 120.0,
+            PokemonName::AGGRON => 360.0,
+            PokemonName::AGGRONMEGA => 395.0,
+            PokemonName::MEDITITE => 11.2,
+            PokemonName::MEDICHAM => 31.5,
+            PokemonName::MEDICHAMMEGA => 31.5,
+            PokemonName::ELECTRIKE => 15.2,
+            PokemonName::MANECTRIC => 40.2,
+            PokemonName::MANECTRICMEGA => 44.0,
+            PokemonName::PLUSLE => 4.2,
+            PokemonName::MINUN => 4.2,
+            PokemonName::VOLBEAT => 17.7,
+            PokemonName::ILLUMISE => 17.7,
+            PokemonName::ROSELIA => 2.0,
+            PokemonName::GULPIN => 10.3,
+            PokemonName::SWALOT => 80.0,
+            PokemonName::CARVANHA => 20.8,
+            PokemonName::SHARPEDO => 88.8,
+            PokemonName::SHARPEDOMEGA => 130.3,
+            PokemonName::WAILMER => 130.0,
+            PokemonName::WAILORD => 398.0,
+            PokemonName::NUMEL => 24.0,
+            PokemonName::CAMERUPT => 220.0,
+            PokemonName::CAMERUPTMEGA => 320.5,
+            PokemonName::TORKOAL => 80.4,
+            PokemonName::SPOINK => 30.6,
+            PokemonName::GRUMPIG => 71.5,
+            PokemonName::SPINDA => 5.0,
+            PokemonName::TRAPINCH => 15.0,
+            PokemonName::VIBRAVA => 15.3,
+            PokemonName::FLYGON => 82.0,
+            PokemonName::CACNEA => 51.3,
+            PokemonName::CACTURNE => 77.4,
+            PokemonName::SWABLU => 1.2,
+            PokemonName::ALTARIA => 20.6,
+            PokemonName::ALTARIAMEGA => 20.6,
+            PokemonName::ZANGOOSE => 40.3,
+            PokemonName::SEVIPER => 52.5,
+            PokemonName::LUNATONE => 168.0,
+            PokemonName::SOLROCK => 154.0,
+            PokemonName::BARBOACH => 1.9,
+            PokemonName::WHISCASH => 23.6,
+            PokemonName::CORPHISH => 11.5,
+            PokemonName::CRAWDAUNT => 32.8,
+            PokemonName::BALTOY => 21.5,
+            PokemonName::CLAYDOL => 108.0,
+            PokemonName::LILEEP => 23.8,
+            PokemonName::CRADILY => 60.4,
+            PokemonName::ANORITH => 12.5,
+            PokemonName::ARMALDO => 68.2,
+            PokemonName::FEEBAS => 7.4,
+            PokemonName::MILOTIC => 162.0,
+            PokemonName::CASTFORM => 0.8,
+            PokemonName::CASTFORMSUNNY => 0.8,
+            PokemonName::CASTFORMRAINY => 0.8,
+            PokemonName::CASTFORMSNOWY => 0.8,
+            PokemonName::KECLEON => 22.0,
+            PokemonName::SHUPPET => 2.3,
+            PokemonName::BANETTE => 12.5,
+            PokemonName::BANETTEMEGA => 13.0,
+            PokemonName::DUSKULL => 15.0,
+            PokemonName::DUSCLOPS => 30.6,
+            PokemonName::TROPIUS => 100.0,
+            PokemonName::CHIMECHO => 1.0,
+            PokemonName::ABSOL => 47.0,
+            PokemonName::ABSOLMEGA => 49.0,
+            PokemonName::WYNAUT => 14.0,
+            PokemonName::SNORUNT => 16.8,
+            PokemonName::GLALIE => 256.5,
+            PokemonName::GLALIEMEGA => 350.2,
+            PokemonName::SPHEAL => 39.5,
+            PokemonName::SEALEO => 87.6,
+            PokemonName::WALREIN => 150.6,
+            PokemonName::CLAMPERL => 52.5,
+            PokemonName::HUNTAIL => 27.0,
+            PokemonName::GOREBYSS => 22.6,
+            PokemonName::RELICANTH => 23.4,
+            PokemonName::LUVDISC => 8.7,
+            PokemonName::BAGON => 42.1,
+            PokemonName::SHELGON => 110.5,
+            PokemonName::SALAMENCE => 102.6,
+            PokemonName::SALAMENCEMEGA => 112.6,
+            PokemonName::BELDUM => 95.2,
+            PokemonName::METANG => 202.5,
+            PokemonName::METAGROSS => 550.0,
+            PokemonName::METAGROSSMEGA => 942.9,
+            PokemonName::REGIROCK => 230.0,
+            PokemonName::REGICE => 175.0,
+            PokemonName::REGISTEEL => 205.0,
+            PokemonName::LATIAS => 40.0,
+            PokemonName::LATIASMEGA => 52.0,
+            PokemonName::LATIOS => 60.0,
+            PokemonName::LATIOSMEGA => 70.0,
+            PokemonName::KYOGRE => 352.0,
+            PokemonName::KYOGREPRIMAL => 430.0,
+            PokemonName::GROUDON => 950.0,
+            PokemonName::GROUDONPRIMAL => 999.7,
+            PokemonName::RAYQUAZA => 206.5,
+            PokemonName::RAYQUAZAMEGA => 392.0,
+            PokemonName::JIRACHI => 1.1,
+            PokemonName::DEOXYS => 60.8,
+            PokemonName::DEOXYSATTACK => 60.8,
+            PokemonName::DEOXYSDEFENSE => 60.8,
+            PokemonName::DEOXYSSPEED => 60.8,
+            PokemonName::TURTWIG => 10.2,
+            PokemonName::GROTLE => 97.0,
+            PokemonName::TORTERRA => 310.0,
+            PokemonName::CHIMCHAR => 6.2,
+            PokemonName::MONFERNO => 22.0,
+            PokemonName::INFERNAPE => 55.0,
+            PokemonName::PIPLUP => 5.2,
+            PokemonName::PRINPLUP => 23.0,
+            PokemonName::EMPOLEON => 84.5,
+            PokemonName::STARLY => 2.0,
+            PokemonName::STARAVIA => 15.5,
+            PokemonName::STARAPTOR => 24.9,
+            PokemonName::BIDOOF => 20.0,
+            PokemonName::BIBAREL => 31.5,
+            PokemonName::KRICKETOT => 2.2,
+            PokemonName::KRICKETUNE => 25.5,
+            PokemonName::SHINX => 9.5,
+            PokemonName::LUXIO => 30.5,
+            PokemonName::LUXRAY => 42.0,
+            PokemonName::BUDEW => 1.2,
+            PokemonName::ROSERADE => 14.5,
+            PokemonName::CRANIDOS => 31.5,
+            PokemonName::RAMPARDOS => 102.5,
+            PokemonName::SHIELDON => 57.0,
+            PokemonName::BASTIODON => 149.5,
+            PokemonName::BURMY => 3.4,
+            PokemonName::BURMYSANDY => 3.4,
+            PokemonName::BURMYTRASH => 3.4,
+            PokemonName::WORMADAM => 6.5,
+            PokemonName::WORMADAMSANDY => 6.5,
+            PokemonName::WORMADAMTRASH => 6.5,
+            PokemonName::MOTHIM => 23.3,
+            PokemonName::COMBEE => 5.5,
+            PokemonName::VESPIQUEN => 38.5,
+            PokemonName::PACHIRISU => 3.9,
+            PokemonName::BUIZEL => 29.5,
+            PokemonName::FLOATZEL => 33.5,
+            PokemonName::CHERUBI => 3.3,
+            PokemonName::CHERRIM => 9.3,
+            PokemonName::CHERRIMSUNSHINE => 9.3,
+            PokemonName::SHELLOS => 6.3,
+            PokemonName::SHELLOSEAST => 6.3,
+            PokemonName::GASTRODON => 29.9,
+            PokemonName::GASTRODONEAST => 29.9,
+            PokemonName::AMBIPOM => 20.3,
+            PokemonName::DRIFLOON => 1.2,
+            PokemonName::DRIFBLIM => 15.0,
+            PokemonName::BUNEARY => 5.5,
+            PokemonName::LOPUNNY => 33.3,
+            PokemonName::LOPUNNYMEGA => 28.3,
+            PokemonName::MISMAGIUS => 4.4,
+            PokemonName::HONCHKROW => 27.3,
+            PokemonName::GLAMEOW => 3.9,
+            PokemonName::PURUGLY => 43.8,
+            PokemonName::CHINGLING => 0.6,
+            PokemonName::STUNKY => 19.2,
+            PokemonName::SKUNTANK => 38.0,
+            PokemonName::BRONZOR => 60.5,
+            PokemonName::BRONZONG => 187.0,
+            PokemonName::BONSLY => 15.0,
+            PokemonName::MIMEJR => 13.0,
+            PokemonName::HAPPINY => 24.4,
+            PokemonName::CHATOT => 1.9,
+            PokemonName::SPIRITOMB => 108.0,
+            PokemonName::GIBLE => 20.5,
+            PokemonName::GABITE => 56.0,
+            PokemonName::GARCHOMP => 95.0,
+            PokemonName::GARCHOMPMEGA => 95.0,
+            PokemonName::MUNCHLAX => 105.0,
+            PokemonName::RIOLU => 20.2,
+            PokemonName::LUCARIO => 54.0,
+            PokemonName::LUCARIOMEGA => 57.5,
+            PokemonName::HIPPOPOTAS => 49.5,
+            PokemonName::HIPPOWDON => 300.0,
+            PokemonName::SKORUPI => 12.0,
+            PokemonName::DRAPION => 61.5,
+            PokemonName::CROAGUNK => 23.0,
+            PokemonName::TOXICROAK => 44.4,
+            PokemonName::CARNIVINE => 27.0,
+            PokemonName::FINNEON => 7.0,
+            PokemonName::LUMINEON => 24.0,
+            PokemonName::MANTYKE => 65.0,
+            PokemonName::SNOVER => 50.5,
+            PokemonName::ABOMASNOW => 135.5,
+            PokemonName::ABOMASNOWMEGA => 185.0,
+            PokemonName::WEAVILE => 34.0,
+            PokemonName::MAGNEZONE => 180.0,
+            PokemonName::LICKILICKY => 140.0,
+            PokemonName::RHYPERIOR => 282.8,
+            PokemonName::TANGROWTH => 128.6,
+            PokemonName::ELECTIVIRE => 138.6,
+            PokemonName::MAGMORTAR => 68.0,
+            PokemonName::TOGEKISS => 38.0,
+            PokemonName::YANMEGA => 51.5,
+            PokemonName::LEAFEON => 25.5,
+            PokemonName::GLACEON => 25.9,
+            PokemonName::GLISCOR => 42.5,
+            PokemonName::MAMOSWINE => 291.0,
+            PokemonName::PORYGONZ => 34.0,
+            PokemonName::GALLADE => 52.0,
+            PokemonName::GALLADEMEGA => 56.4,
+            PokemonName::PROBOPASS => 340.0,
+            PokemonName::DUSKNOIR => 106.6,
+            PokemonName::FROSLASS => 26.6,
+            PokemonName::ROTOM => 0.3,
+            PokemonName::ROTOMHEAT => 0.3,
+            PokemonName::ROTOMWASH => 0.3,
+            PokemonName::ROTOMFROST => 0.3,
+            PokemonName::ROTOMFAN => 0.3,
+            PokemonName::ROTOMMOW => 0.3,
+            PokemonName::UXIE => 0.3,
+            PokemonName::MESPRIT => 0.3,
+            PokemonName::AZELF => 0.3,
+            PokemonName::DIALGA => 683.0,
+            PokemonName::DIALGAORIGIN => 850.0,
+            PokemonName::PALKIA => 336.0,
+            PokemonName::PALKIAORIGIN => 660.0,
+            PokemonName::HEATRAN => 430.0,
+            PokemonName::REGIGIGAS => 420.0,
+            PokemonName::GIRATINA => 750.0,
+            PokemonName::GIRATINAORIGIN => 650.0,
+            PokemonName::CRESSELIA => 85.6,
+            PokemonName::PHIONE => 3.1,
+            PokemonName::MANAPHY => 1.4,
+            PokemonName::DARKRAI => 50.5,
+            PokemonName::SHAYMIN => 2.1,
+            PokemonName::SHAYMINSKY => 5.2,
+            PokemonName::ARCEUS => 320.0,
+            PokemonName::ARCEUSBUG => 320.0,
+            PokemonName::ARCEUSDARK => 320.0,
+            PokemonName::ARCEUSDRAGON => 320.0,
+            PokemonName::ARCEUSELECTRIC => 320.0,
+            PokemonName::ARCEUSFAIRY => 320.0,
+            PokemonName::ARCEUSFIGHTING => 320.0,
+            PokemonName::ARCEUSFIRE => 320.0,
+            PokemonName::ARCEUSFLYING => 320.0,
+            PokemonName::ARCEUSGHOST => 320.0,
+            PokemonName::ARCEUSGRASS => 320.0,
+            PokemonName::ARCEUSGROUND => 320.0,
+            PokemonName::ARCEUSICE => 320.0,
+            PokemonName::ARCEUSPOISON => 320.0,
+            PokemonName::ARCEUSPSYCHIC => 320.0,
+            PokemonName::ARCEUSROCK => 320.0,
+            PokemonName::ARCEUSSTEEL => 320.0,
+            PokemonName::ARCEUSWATER => 320.0,
+            PokemonName::VICTINI => 4.0,
+            PokemonName::SNIVY => 8.1,
+            PokemonName::SERVINE => 16.0,
+            PokemonName::SERPERIOR => 63.0,
+            PokemonName::TEPIG => 9.9,
+            PokemonName::PIGNITE => 55.5,
+            PokemonName::EMBOAR => 150.0,
+            PokemonName::OSHAWOTT => 5.9,
+            PokemonName::DEWOTT => 24.5,
+            PokemonName::SAMUROTT => 94.6,
+            PokemonName::SAMUROTTHISUI => 58.2,
+            PokemonName::PATRAT => 11.6,
+            PokemonName::WATCHOG => 27.0,
+            PokemonName::LILLIPUP => 4.1,
+            PokemonName::HERDIER => 14.7,
+            PokemonName::STOUTLAND => 61.0,
+            PokemonName::PURRLOIN => 10.1,
+            PokemonName::LIEPARD => 37.5,
+            PokemonName::PANSAGE => 10.5,
+            PokemonName::SIMISAGE => 30.5,
+            PokemonName::PANSEAR => 11.0,
+            PokemonName::SIMISEAR => 28.0,
+            PokemonName::PANPOUR => 13.5,
+            PokemonName::SIMIPOUR => 29.0,
+            PokemonName::MUNNA => 23.3,
+            PokemonName::MUSHARNA => 60.5,
+            PokemonName::PIDOVE => 2.1,
+            PokemonName::TRANQUILL => 15.0,
+            PokemonName::UNFEZANT => 29.0,
+            PokemonName::BLITZLE => 29.8,
+            PokemonName::ZEBSTRIKA => 79.5,
+            PokemonName::ROGGENROLA => 18.0,
+            PokemonName::BOLDORE => 102.0,
+            PokemonName::GIGALITH => 260.0,
+            PokemonName::WOOBAT => 2.1,
+            PokemonName::SWOOBAT => 10.5,
+            PokemonName::DRILBUR => 8.5,
+            PokemonName::EXCADRILL => 40.4,
+            PokemonName::AUDINO => 31.0,
+            PokemonName::AUDINOMEGA => 32.0,
+            PokemonName::TIMBURR => 12.5,
+            PokemonName::GURDURR => 40.0,
+            PokemonName::CONKELDURR => 87.0,
+            PokemonName::TYMPOLE => 4.5,
+            PokemonName::PALPITOAD => 17.0,
+            PokemonName::SEISMITOAD => 62.0,
+            PokemonName::THROH => 55.5,
+            PokemonName::SAWK => 51.0,
+            PokemonName::SEWADDLE => 2.5,
+            PokemonName::SWADLOON => 7.3,
+            PokemonName::LEAVANNY => 20.5,
+            PokemonName::VENIPEDE => 5.3,
+            PokemonName::WHIRLIPEDE => 58.5,
+            PokemonName::SCOLIPEDE => 200.5,
+            PokemonName::COTTONEE => 0.6,
+            PokemonName::WHIMSICOTT => 6.6,
+            PokemonName::PETILIL => 6.6,
+            PokemonName::LILLIGANT => 16.3,
+            PokemonName::LILLIGANTHISUI => 19.2,
+            PokemonName::BASCULIN => 18.0,
+            PokemonName::BASCULINBLUESTRIPED => 18.0,
+            PokemonName::BASCULINWHITESTRIPED => 18.0,
+            PokemonName::SANDILE => 15.2,
+            PokemonName::KROKOROK => 33.4,
+            PokemonName::KROOKODILE => 96.3,
+            PokemonName::DARUMAKA => 37.5,
+            PokemonName::DARUMAKAGALAR => 40.0,
+            PokemonName::DARMANITAN => 92.9,
+            PokemonName::DARMANITANZEN => 92.9,
+            PokemonName::DARMANITANGALAR => 120.0,
+            PokemonName::DARMANITANGALARZEN => 120.0,
+            PokemonName::MARACTUS => 28.0,
+            PokemonName::DWEBBLE => 14.5,
+            PokemonName::CRUSTLE => 200.0,
+            PokemonName::SCRAGGY => 11.8,
+            PokemonName::SCRAFTY => 30.0,
+            PokemonName::SIGILYPH => 14.0,
+            PokemonName::YAMASK => 1.5,
+            PokemonName::YAMASKGALAR => 1.5,
+            PokemonName::COFAGRIGUS => 76.5,
+            PokemonName::TIRTOUGA => 16.5,
+            PokemonName::CARRACOSTA => 81.0,
+            PokemonName::ARCHEN => 9.5,
+            PokemonName::ARCHEOPS => 32.0,
+            PokemonName::TRUBBISH => 31.0,
+            PokemonName::GARBODOR => 107.3,
+            PokemonName::GARBODORGMAX => 107.3,
+            PokemonName::ZORUA => 12.5,
+            PokemonName::ZORUAHISUI => 12.5,
+            PokemonName::ZOROARK => 81.1,
+            PokemonName::ZOROARKHISUI => 73.0,
+            PokemonName::MINCCINO => 5.8,
+            PokemonName::CINCCINO => 7.5,
+            PokemonName::GOTHITA => 5.8,
+            PokemonName::GOTHORITA => 18.0,
+            PokemonName::GOTHITELLE => 44.0,
+            PokemonName::SOLOSIS => 1.0,
+            PokemonName::DUOSION => 8.0,
+            PokemonName::REUNICLUS => 20.1,
+            PokemonName::DUCKLETT => 5.5,
+            PokemonName::SWANNA => 24.2,
+            PokemonName::VANILLITE => 5.7,
+            PokemonName::VANILLISH => 41.0,
+            PokemonName::VANILLUXE => 57.5,
+            PokemonName::DEERLING => 19.5,
+            PokemonName::DEERLINGSUMMER => 19.5,
+            PokemonName::DEERLINGAUTUMN => 19.5,
+            PokemonName::DEERLINGWINTER => 19.5,
+            PokemonName::SAWSBUCK => 92.5,
+            PokemonName::SAWSBUCKSUMMER => 92.5,
+            PokemonName::SAWSBUCKAUTUMN => 92.5,
+            PokemonName::SAWSBUCKWINTER => 92.5,
+            PokemonName::EMOLGA => 5.0,
+            PokemonName::KARRABLAST => 5.9,
+            PokemonName::ESCAVALIER => 33.0,
+            PokemonName::FOONGUS => 1.0,
+            PokemonName::AMOONGUSS => 10.5,
+            PokemonName::FRILLISH => 33.0,
+            PokemonName::JELLICENT => 135.0,
+            PokemonName::ALOMOMOLA => 31.6,
+            PokemonName::JOLTIK => 0.6,
+            PokemonName::GALVANTULA => 14.3,
+            PokemonName::FERROSEED => 18.8,
+            PokemonName::FERROTHORN => 110.0,
+            PokemonName::KLINK => 21.0,
+            PokemonName::KLANG => 51.0,
+            PokemonName::KLINKLANG => 81.0,
+            PokemonName::TYNAMO => 0.3,
+            PokemonName::EELEKTRIK => 22.0,
+            PokemonName::EELEKTROSS => 80.5,
+            PokemonName::ELGYEM => 9.0,
+            PokemonName::BEHEEYEM => 34.5,
+            PokemonName::LITWICK => 3.1,
+            PokemonName::LAMPENT => 13.0,
+            PokemonName::CHANDELURE => 34.3,
+            PokemonName::AXEW => 18.0,
+            PokemonName::FRAXURE => 36.0,
+            PokemonName::HAXORUS => 105.5,
+            PokemonName::CUBCHOO => 8.5,
+            PokemonName::BEARTIC => 260.0,
+            PokemonName::CRYOGONAL => 148.0,
+            PokemonName::SHELMET => 7.7,
+            PokemonName::ACCELGOR => 25.3,
+            PokemonName::STUNFISK => 11.0,
+            PokemonName::STUNFISKGALAR => 20.5,
+            PokemonName::MIENFOO => 20.0,
+            PokemonName::MIENSHAO => 35.5,
+            PokemonName::DRUDDIGON => 139.0,
+            PokemonName::GOLETT => 92.0,
+            PokemonName::GOLURK => 330.0,
+            PokemonName::PAWNIARD => 10.2,
+            PokemonName::BISHARP => 70.0,
+            PokemonName::BOUFFALANT => 94.6,
+            PokemonName::RUFFLET => 10.5,
+            PokemonName::BRAVIARY => 41.0,
+            PokemonName::BRAVIARYHISUI => 43.4,
+            PokemonName::VULLABY => 9.0,
+            PokemonName::MANDIBUZZ => 39.5,
+            PokemonName::HEATMOR => 58.0,
+            PokemonName::DURANT => 33.0,
+            PokemonName::DEINO => 17.3,
+            PokemonName::ZWEILOUS => 50.0,
+            PokemonName::HYDREIGON => 160.0,
+            PokemonName::LARVESTA => 28.8,
+            PokemonName::VOLCARONA => 46.0,
+            PokemonName::COBALION => 250.0,
+            PokemonName::TERRAKION => 260.0,
+            PokemonName::VIRIZION => 200.0,
+            PokemonName::TORNADUS => 63.0,
+            PokemonName::TORNADUSTHERIAN => 63.0,
+            PokemonName::THUNDURUS => 61.0,
+            PokemonName::THUNDURUSTHERIAN => 61.0,
+            PokemonName::RESHIRAM => 330.0,
+            PokemonName::ZEKROM => 345.0,
+            PokemonName::LANDORUS => 68.0,
+            PokemonName::LANDORUSTHERIAN => 68.0,
+            PokemonName::KYUREM => 325.0,
+            PokemonName::KYUREMBLACK => 325.0,
+            PokemonName::KYUREMWHITE => 325.0,
+            PokemonName::KELDEO => 48.5,
+            PokemonName::KELDEORESOLUTE => 48.5,
+            PokemonName::MELOETTA => 6.5,
+            PokemonName::MELOETTAPIROUETTE => 6.5,
+            PokemonName::GENESECT => 82.5,
+            PokemonName::GENESECTDOUSE => 82.5,
+            PokemonName::GENESECTSHOCK => 82.5,
+            PokemonName::GENESECTBURN => 82.5,
+            PokemonName::GENESECTCHILL => 82.5,
+            PokemonName::CHESPIN => 9.0,
+            PokemonName::QUILLADIN => 29.0,
+            PokemonName::CHESNAUGHT => 90.0,
+            PokemonName::FENNEKIN => 9.4,
+            PokemonName::BRAIXEN => 14.5,
+            PokemonName::DELPHOX => 39.0,
+            PokemonName::FROAKIE => 7.0,
+            PokemonName::FROGADIER => 10.9,
+            PokemonName::GRENINJA => 40.0,
+            PokemonName::GRENINJABOND => 40.0,
+            PokemonName::GRENINJAASH => 40.0,
+            PokemonName::BUNNELBY => 5.0,
+            PokemonName::DIGGERSBY => 42.4,
+            PokemonName::FLETCHLING => 1.7,
+            PokemonName::FLETCHINDER => 16.0,
+            PokemonName::TALONFLAME => 24.5,
+            PokemonName::SCATTERBUG => 2.5,
+            PokemonName::SPEWPA => 8.4,
+            PokemonName::VIVILLON => 17.0,
+            PokemonName::VIVILLONARCHIPELAGO => 17.0,
+            PokemonName::VIVILLONCONTINENTAL => 17.0,
+            PokemonName::VIVILLONELEGANT => 17.0,
+            PokemonName::VIVILLONGARDEN => 17.0,
+            PokemonName::VIVILLONHIGHPLAINS => 17.0,
+            PokemonName::VIVILLONICYSNOW => 17.0,
+            PokemonName::VIVILLONJUNGLE => 17.0,
+            PokemonName::VIVILLONMARINE => 17.0,
+            PokemonName::VIVILLONMODERN => 17.0,
+            PokemonName::VIVILLONMONSOON => 17.0,
+            PokemonName::VIVILLONOCEAN => 17.0,
+            PokemonName::VIVILLONPOLAR => 17.0,
+            PokemonName::VIVILLONRIVER => 17.0,
+            PokemonName::VIVILLONSANDSTORM => 17.0,
+            PokemonName::VIVILLONSAVANNA => 17.0,
+            PokemonName::VIVILLONSUN => 17.0,
+            PokemonName::VIVILLONTUNDRA => 17.0,
+            PokemonName::VIVILLONFANCY => 17.0,
+            PokemonName::VIVILLONPOKEBALL => 17.0,
+            PokemonName::LITLEO => 13.5,
+            PokemonName::PYROAR => 81.5,
+            PokemonName::FLABEBE => 0.1,
+            PokemonName::FLABEBEBLUE => 0.1,
+            PokemonName::FLABEBEORANGE => 0.1,
+            PokemonName::FLABEBEWHITE => 0.1,
+            PokemonName::FLABEBEYELLOW => 0.1,
+            PokemonName::FLOETTE => 0.9,
+            PokemonName::FLOETTEBLUE => 0.9,
+            PokemonName::FLOETTEORANGE => 0.9,
+            PokemonName::FLOETTEWHITE => 0.9,
+            PokemonName::FLOETTEYELLOW => 0.9,
+            PokemonName::FLOETTEETERNAL => 0.9,
+            PokemonName::FLORGES => 10.0,
+            PokemonName::FLORGESBLUE => 10.0,
+            PokemonName::FLORGESORANGE => 10.0,
+            PokemonName::FLORGESWHITE => 10.0,
+            PokemonName::FLORGESYELLOW => 10.0,
+            PokemonName::SKIDDO => 31.0,
+            PokemonName::GOGOAT => 91.0,
+            PokemonName::PANCHAM => 8.0,
+            PokemonName::PANGORO => 136.0,
+            PokemonName::FURFROU => 28.0,
+            PokemonName::FURFROUDANDY => 28.0,
+            PokemonName::FURFROUDEBUTANTE => 28.0,
+            PokemonName::FURFROUDIAMOND => 28.0,
+            PokemonName::FURFROUHEART => 28.0,
+            PokemonName::FURFROUKABUKI => 28.0,
+            PokemonName::FURFROULAREINE => 28.0,
+            PokemonName::FURFROUMATRON => 28.0,
+            PokemonName::FURFROUPHARAOH => 28.0,
+            PokemonName::FURFROUSTAR => 28.0,
+            PokemonName::ESPURR => 3.5,
+            PokemonName::MEOWSTIC => 8.5,
+            PokemonName::MEOWSTICF => 8.5,
+            PokemonName::HONEDGE => 2.0,
+            PokemonName::DOUBLADE => 4.5,
+            PokemonName::AEGISLASH => 53.0,
+            PokemonName::AEGISLASHBLADE => 53.0,
+            PokemonName::SPRITZEE => 0.5,
+            PokemonName::AROMATISSE => 15.5,
+            PokemonName::SWIRLIX => 3.5,
+            PokemonName::SLURPUFF => 5.0,
+            PokemonName::INKAY => 3.5,
+            PokemonName::MALAMAR => 47.0,
+            PokemonName::BINACLE => 31.0,
+            PokemonName::BARBARACLE => 96.0,
+            PokemonName::SKRELP => 7.3,
+            PokemonName::DRAGALGE => 81.5,
+            PokemonName::CLAUNCHER => 8.3,
+            PokemonName::CLAWITZER => 35.3,
+            PokemonName::HELIOPTILE => 6.0,
+            PokemonName::HELIOLISK => 21.0,
+            PokemonName::TYRUNT => 26.0,
+            PokemonName::TYRANTRUM => 270.0,
+            PokemonName::AMAURA => 25.2,
+            PokemonName::AURORUS => 225.0,
+            PokemonName::SYLVEON => 23.5,
+            PokemonName::HAWLUCHA => 21.5,
+            PokemonName::DEDENNE => 2.2,
+            PokemonName::CARBINK => 5.7,
+            PokemonName::GOOMY => 2.8,
+            PokemonName::SLIGGOO => 17.5,
+            PokemonName::SLIGGOOHISUI => 68.5,
+            PokemonName::GOODRA => 150.5,
+            PokemonName::GOODRAHISUI => 334.1,
+            PokemonName::KLEFKI => 3.0,
+            PokemonName::PHANTUMP => 7.0,
+            PokemonName::TREVENANT => 71.0,
+            PokemonName::PUMPKABOO => 5.0,
+            PokemonName::PUMPKABOOSMALL => 3.5,
+            PokemonName::PUMPKABOOLARGE => 7.5,
+            PokemonName::PUMPKABOOSUPER => 15.0,
+            PokemonName::GOURGEIST => 12.5,
+            PokemonName::GOURGEISTSMALL => 9.5,
+            PokemonName::GOURGEISTLARGE => 14.0,
+            PokemonName::GOURGEISTSUPER => 39.0,
+            PokemonName::BERGMITE => 99.5,
+            PokemonName::AVALUGG => 505.0,
+            PokemonName::AVALUGGHISUI => 262.4,
+            PokemonName::NOIBAT => 8.0,
+            PokemonName::NOIVERN => 85.0,
+            PokemonName::XERNEAS => 215.0,
+            PokemonName::XERNEASNEUTRAL => 215.0,
+            PokemonName::YVELTAL => 203.0,
+            PokemonName::ZYGARDE => 305.0,
+            PokemonName::ZYGARDE10 => 33.5,
+            PokemonName::ZYGARDECOMPLETE => 610.0,
+            PokemonName::DIANCIE => 8.8,
+            PokemonName::DIANCIEMEGA => 27.8,
+            PokemonName::HOOPA => 9.0,
+            PokemonName::HOOPAUNBOUND => 490.0,
+            PokemonName::VOLCANION => 195.0,
+            PokemonName::ROWLET => 1.5,
+            PokemonName::DARTRIX => 16.0,
+            PokemonName::DECIDUEYE => 36.6,
+            PokemonName::DECIDUEYEHISUI => 37.0,
+            PokemonName::LITTEN => 4.3,
+            PokemonName::TORRACAT => 25.0,
+            PokemonName::INCINEROAR => 83.0,
+            PokemonName::POPPLIO => 7.5,
+            PokemonName::BRIONNE => 17.5,
+            PokemonName::PRIMARINA => 44.0,
+            PokemonName::PIKIPEK => 1.2,
+            PokemonName::TRUMBEAK => 14.8,
+            PokemonName::TOUCANNON => 26.0,
+            PokemonName::YUNGOOS => 6.0,
+            PokemonName::GUMSHOOS => 14.2,
+            PokemonName::GUMSHOOSTOTEM => 60.0,
+            PokemonName::GRUBBIN => 4.4,
+            PokemonName::CHARJABUG => 10.5,
+            PokemonName::VIKAVOLT => 45.0,
+            PokemonName::VIKAVOLTTOTEM => 147.5,
+            PokemonName::CRABRAWLER => 7.0,
+            PokemonName::CRABOMINABLE => 180.0,
+            PokemonName::ORICORIO => 3.4,
+            PokemonName::ORICORIOPOMPOM => 3.4,
+            PokemonName::ORICORIOPAU => 3.4,
+            PokemonName::ORICORIOSENSU => 3.4,
+            PokemonName::CUTIEFLY => 0.2,
+            PokemonName::RIBOMBEE => 0.5,
+            PokemonName::RIBOMBEETOTEM => 2.0,
+            PokemonName::ROCKRUFF => 9.2,
+            PokemonName::LYCANROC => 25.0,
+            PokemonName::LYCANROCMIDNIGHT => 25.0,
+            PokemonName::LYCANROCDUSK => 25.0,
+            PokemonName::WISHIWASHI => 0.3,
+            PokemonName::WISHIWASHISCHOOL => 78.6,
+            PokemonName::MAREANIE => 8.0,
+            PokemonName::TOXAPEX => 14.5,
+            PokemonName::MUDBRAY => 110.0,
+            PokemonName::MUDSDALE => 920.0,
+            PokemonName::DEWPIDER => 4.0,
+            PokemonName::ARAQUANID => 82.0,
+            PokemonName::ARAQUANIDTOTEM => 217.5,
+            PokemonName::FOMANTIS => 1.5,
+            PokemonName::LURANTIS => 18.5,
+            PokemonName::LURANTISTOTEM => 58.0,
+            PokemonName::MORELULL => 1.5,
+            PokemonName::SHIINOTIC => 11.5,
+            PokemonName::SALANDIT => 4.8,
+            PokemonName::SALAZZLE => 22.2,
+            PokemonName::SALAZZLETOTEM => 81.0,
+            PokemonName::STUFFUL => 6.8,
+            PokemonName::BEWEAR => 135.0,
+            PokemonName::BOUNSWEET => 3.2,
+            PokemonName::STEENEE => 8.2,
+            PokemonName::TSAREENA => 21.4,
+            PokemonName::COMFEY => 0.3,
+            PokemonName::ORANGURU => 76.0,
+            PokemonName::PASSIMIAN => 82.8,
+            PokemonName::WIMPOD => 12.0,
+            PokemonName::GOLISOPOD => 108.0,
+            PokemonName::SANDYGAST => 70.0,
+            PokemonName::PALOSSAND => 250.0,
+            PokemonName::PYUKUMUKU => 1.2,
+            PokemonName::TYPENULL => 120.5,
+            PokemonName::SILVALLY => 100.5,
+            PokemonName::SILVALLYBUG => 100.5,
+            PokemonName::SILVALLYDARK => 100.5,
+            PokemonName::SILVALLYDRAGON => 100.5,
+            PokemonName::SILVALLYELECTRIC => 100.5,
+            PokemonName::SILVALLYFAIRY => 100.5,
+            PokemonName::SILVALLYFIGHTING => 100.5,
+            PokemonName::SILVALLYFIRE => 100.5,
+            PokemonName::SILVALLYFLYING => 100.5,
+            PokemonName::SILVALLYGHOST => 100.5,
+            PokemonName::SILVALLYGRASS => 100.5,
+            PokemonName::SILVALLYGROUND => 100.5,
+            PokemonName::SILVALLYICE => 100.5,
+            PokemonName::SILVALLYPOISON => 100.5,
+            PokemonName::SILVALLYPSYCHIC => 100.5,
+            PokemonName::SILVALLYROCK => 100.5,
+            PokemonName::SILVALLYSTEEL => 100.5,
+            PokemonName::SILVALLYWATER => 100.5,
+            PokemonName::MINIOR => 0.3,
+            PokemonName::MINIORORANGE => 0.3,
+            PokemonName::MINIORYELLOW => 0.3,
+            PokemonName::MINIORGREEN => 0.3,
+            PokemonName::MINIORBLUE => 0.3,
+            PokemonName::MINIORINDIGO => 0.3,
+            PokemonName::MINIORVIOLET => 0.3,
+            PokemonName::MINIORMETEOR => 40.0,
+            PokemonName::KOMALA => 19.9,
+            PokemonName::TURTONATOR => 212.0,
+            PokemonName::TOGEDEMARU => 3.3,
+            PokemonName::TOGEDEMARUTOTEM => 13.0,
+            PokemonName::MIMIKYU => 0.7,
+            PokemonName::MIMIKYUBUSTED => 0.7,
+            PokemonName::MIMIKYUTOTEM => 2.8,
+            PokemonName::MIMIKYUBUSTEDTOTEM => 2.8,
+            PokemonName::BRUXISH => 19.0,
+            PokemonName::DRAMPA => 185.0,
+            PokemonName::DHELMISE => 210.0,
+            PokemonName::JANGMOO => 29.7,
+            PokemonName::HAKAMOO => 47.0,
+            PokemonName::KOMMOO => 78.2,
+            PokemonName::KOMMOOTOTEM => 207.5,
+            PokemonName::TAPUKOKO => 20.5,
+            PokemonName::TAPULELE => 18.6,
+            PokemonName::TAPUBULU => 45.5,
+            PokemonName::TAPUFINI => 21.2,
+            PokemonName::COSMOG => 0.1,
+            PokemonName::COSMOEM => 999.9,
+            PokemonName::SOLGALEO => 230.0,
+            PokemonName::LUNALA => 120.0,
+            PokemonName::NIHILEGO => 55.5,
+            PokemonName::BUZZWOLE => 333.6,
+            PokemonName::PHEROMOSA => 25.0,
+            PokemonName::XURKITREE => 100.0,
+            PokemonName::CELESTEELA => 999.9,
+            PokemonName::KARTANA => 0.1,
+            PokemonName::GUZZLORD => 888.0,
+            PokemonName::NECROZMA => 230.0,
+            PokemonName::NECROZMADUSKMANE => 460.0,
+            PokemonName::NECROZMADAWNWINGS => 350.0,
+            PokemonName::NECROZMAULTRA => 230.0,
+            PokemonName::MAGEARNA => 80.5,
+            PokemonName::MAGEARNAORIGINAL => 80.5,
+            PokemonName::MARSHADOW => 22.2,
+            PokemonName::POIPOLE => 1.8,
+            PokemonName::NAGANADEL => 150.0,
+            PokemonName::STAKATAKA => 820.0,
+            PokemonName::BLACEPHALON => 13.0,
+            PokemonName::ZERAORA => 44.5,
+            PokemonName::MELTAN => 8.0,
+            PokemonName::MELMETAL => 800.0,
+            PokemonName::MELMETALGMAX => 800.0,
+            PokemonName::GROOKEY => 5.0,
+            PokemonName::THWACKEY => 14.0,
+            PokemonName::RILLABOOM => 90.0,
+            PokemonName::RILLABOOMGMAX => 90.0,
+            PokemonName::SCORBUNNY => 4.5,
+            PokemonName::RABOOT => 9.0,
+            PokemonName::CINDERACE => 33.0,
+            PokemonName::CINDERACEGMAX => 33.0,
+            PokemonName::SOBBLE => 4.0,
+            PokemonName::DRIZZILE => 11.5,
+            PokemonName::INTELEON => 45.2,
+            PokemonName::INTELEONGMAX => 45.2,
+            PokemonName::SKWOVET => 2.5,
+            PokemonName::GREEDENT => 6.0,
+            PokemonName::ROOKIDEE => 1.8,
+            PokemonName::CORVISQUIRE => 16.0,
+            PokemonName::CORVIKNIGHT => 75.0,
+            PokemonName::CORVIKNIGHTGMAX => 75.0,
+            PokemonName::BLIPBUG => 8.0,
+            PokemonName::DOTTLER => 19.5,
+            PokemonName::ORBEETLE => 40.8,
+            PokemonName::ORBEETLEGMAX => 40.8,
+            PokemonName::NICKIT => 8.9,
+            PokemonName::THIEVUL => 19.9,
+            PokemonName::GOSSIFLEUR => 2.2,
+            PokemonName::ELDEGOSS => 2.5,
+            PokemonName::WOOLOO => 6.0,
+            PokemonName::DUBWOOL => 43.0,
+            PokemonName::CHEWTLE => 8.5,
+            PokemonName::DREDNAW => 115.5,
+            PokemonName::DREDNAWGMAX => 115.5,
+            PokemonName::YAMPER => 13.5,
+            PokemonName::BOLTUND => 34.0,
+            PokemonName::ROLYCOLY => 12.0,
+            PokemonName::CARKOL => 78.0,
+            PokemonName::COALOSSAL => 310.5,
+            PokemonName::COALOSSALGMAX => 310.5,
+            PokemonName::APPLIN => 0.5,
+            PokemonName::FLAPPLE => 1.0,
+            PokemonName::FLAPPLEGMAX => 1.0,
+            PokemonName::APPLETUN => 13.0,
+            PokemonName::APPLETUNGMAX => 13.0,
+            PokemonName::SILICOBRA => 7.6,
+            PokemonName::SANDACONDA => 65.5,
+            PokemonName::SANDACONDAGMAX => 65.5,
+            PokemonName::CRAMORANT => 18.0,
+            PokemonName::CRAMORANTGULPING => 18.0,
+            PokemonName::CRAMORANTGORGING => 18.0,
+            PokemonName::ARROKUDA => 1.0,
+            PokemonName::BARRASKEWDA => 30.0,
+            PokemonName::TOXEL => 11.0,
+            PokemonName::TOXTRICITY => 40.0,
+            PokemonName::TOXTRICITYLOWKEY => 40.0,
+            PokemonName::TOXTRICITYGMAX => 40.0,
+            PokemonName::TOXTRICITYLOWKEYGMAX => 40.0,
+            PokemonName::SIZZLIPEDE => 1.0,
+            PokemonName::CENTISKORCH => 120.0,
+            PokemonName::CENTISKORCHGMAX => 120.0,
+            PokemonName::CLOBBOPUS => 4.0,
+            PokemonName::GRAPPLOCT => 39.0,
+            PokemonName::SINISTEA => 0.2,
+            PokemonName::SINISTEAANTIQUE => 0.2,
+            PokemonName::POLTEAGEIST => 0.4,
+            PokemonName::POLTEAGEISTANTIQUE => 0.4,
+            PokemonName::HATENNA => 3.4,
+            PokemonName::HATTREM => 4.8,
+            PokemonName::HATTERENE => 5.1,
+            PokemonName::HATTERENEGMAX => 5.1,
+            PokemonName::IMPIDIMP => 5.5,
+            PokemonName::MORGREM => 12.5,
+            PokemonName::GRIMMSNARL => 61.0,
+            PokemonName::GRIMMSNARLGMAX => 61.0,
+            PokemonName::OBSTAGOON => 46.0,
+            PokemonName::PERRSERKER => 28.0,
+            PokemonName::CURSOLA => 0.4,
+            PokemonName::SIRFETCHD => 117.0,
+            PokemonName::MRRIME => 58.2,
+            PokemonName::RUNERIGUS => 66.6,
+            PokemonName::MILCERY => 0.3,
+            PokemonName::ALCREMIE => 0.5,
+            PokemonName::ALCREMIERUBYCREAM => 0.5,
+            PokemonName::ALCREMIEMATCHACREAM => 0.5,
+            PokemonName::ALCREMIEMINTCREAM => 0.5,
+            PokemonName::ALCREMIELEMONCREAM => 0.5,
+            PokemonName::ALCREMIESALTEDCREAM => 0.5,
+            PokemonName::ALCREMIERUBYSWIRL => 0.5,
+            PokemonName::ALCREMIECARAMELSWIRL => 0.5,
+            PokemonName::ALCREMIERAINBOWSWIRL => 0.5,
+            PokemonName::ALCREMIEGMAX => 0.5,
+            PokemonName::FALINKS => 62.0,
+            PokemonName::PINCURCHIN => 1.0,
+            PokemonName::SNOM => 3.8,
+            PokemonName::FROSMOTH => 42.0,
+            PokemonName::STONJOURNER => 520.0,
+            PokemonName::EISCUE => 89.0,
+            PokemonName::EISCUENOICE => 89.0,
+            PokemonName::INDEEDEE => 28.0,
+            PokemonName::INDEEDEEF => 28.0,
+            PokemonName::MORPEKO => 3.0,
+            PokemonName::MORPEKOHANGRY => 3.0,
+            PokemonName::CUFANT => 100.0,
+            PokemonName::COPPERAJAH => 650.0,
+            PokemonName::COPPERAJAHGMAX => 650.0,
+            PokemonName::DRACOZOLT => 190.0,
+            PokemonName::ARCTOZOLT => 150.0,
+            PokemonName::DRACOVISH => 215.0,
+            PokemonName::ARCTOVISH => 175.0,
+            PokemonName::DURALUDON => 40.0,
+            PokemonName::DURALUDONGMAX => 40.0,
+            PokemonName::DREEPY => 2.0,
+            PokemonName::DRAKLOAK => 11.0,
+            PokemonName::DRAGAPULT => 50.0,
+            PokemonName::ZACIAN => 110.0,
+            PokemonName::ZACIANCROWNED => 355.0,
+            PokemonName::ZAMAZENTA => 210.0,
+            PokemonName::ZAMAZENTACROWNED => 785.0,
+            PokemonName::ETERNATUS => 950.0,
+            PokemonName::ETERNATUSETERNAMAX => 0.0,
+            PokemonName::KUBFU => 12.0,
+            PokemonName::URSHIFU => 105.0,
+            PokemonName::URSHIFURAPIDSTRIKE => 105.0,
+            PokemonName::URSHIFUGMAX => 105.0,
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => 105.0,
+            PokemonName::ZARUDE => 70.0,
+            PokemonName::ZARUDEDADA => 70.0,
+            PokemonName::REGIELEKI => 145.0,
+            PokemonName::REGIDRAGO => 200.0,
+            PokemonName::GLASTRIER => 800.0,
+            PokemonName::SPECTRIER => 44.5,
+            PokemonName::CALYREX => 7.7,
+            PokemonName::CALYREXICE => 809.1,
+            PokemonName::CALYREXSHADOW => 53.6,
+            PokemonName::WYRDEER => 95.1,
+            PokemonName::KLEAVOR => 89.0,
+            PokemonName::URSALUNA => 290.0,
+            PokemonName::URSALUNABLOODMOON => 333.0,
+            PokemonName::BASCULEGION => 110.0,
+            PokemonName::BASCULEGIONF => 110.0,
+            PokemonName::SNEASLER => 43.0,
+            PokemonName::OVERQWIL => 60.5,
+            PokemonName::ENAMORUS => 48.0,
+            PokemonName::ENAMORUSTHERIAN => 48.0,
+            PokemonName::SPRIGATITO => 4.1,
+            PokemonName::FLORAGATO => 12.2,
+            PokemonName::MEOWSCARADA => 31.2,
+            PokemonName::FUECOCO => 9.8,
+            PokemonName::CROCALOR => 30.7,
+            PokemonName::SKELEDIRGE => 326.5,
+            PokemonName::QUAXLY => 6.1,
+            PokemonName::QUAXWELL => 21.5,
+            PokemonName::QUAQUAVAL => 61.9,
+            PokemonName::LECHONK => 10.2,
+            PokemonName::OINKOLOGNE => 120.0,
+            PokemonName::OINKOLOGNEF => 120.0,
+            PokemonName::TAROUNTULA => 4.0,
+            PokemonName::SPIDOPS => 16.5,
+            PokemonName::NYMBLE => 1.0,
+            PokemonName::LOKIX => 17.5,
+            PokemonName::PAWMI => 2.5,
+            PokemonName::PAWMO => 6.5,
+            PokemonName::PAWMOT => 41.0,
+            PokemonName::TANDEMAUS => 1.8,
+            PokemonName::MAUSHOLD => 2.3,
+            PokemonName::MAUSHOLDFOUR => 2.8,
+            PokemonName::FIDOUGH => 10.9,
+            PokemonName::DACHSBUN => 14.9,
+            PokemonName::SMOLIV => 6.5,
+            PokemonName::DOLLIV => 11.9,
+            PokemonName::ARBOLIVA => 48.2,
+            PokemonName::SQUAWKABILLY => 2.4,
+            PokemonName::SQUAWKABILLYBLUE => 2.4,
+            PokemonName::SQUAWKABILLYYELLOW => 2.4,
+            PokemonName::SQUAWKABILLYWHITE => 2.4,
+            PokemonName::NACLI => 16.0,
+            PokemonName::NACLSTACK => 105.0,
+            PokemonName::GARGANACL => 240.0,
+            PokemonName::CHARCADET => 10.5,
+            PokemonName::ARMAROUGE => 85.0,
+            PokemonName::CERULEDGE => 62.0,
+            PokemonName::TADBULB => 0.4,
+            PokemonName::BELLIBOLT => 113.0,
+            PokemonName::WATTREL => 3.6,
+            PokemonName::KILOWATTREL => 38.6,
+            PokemonName::MASCHIFF => 16.0,
+            PokemonName::MABOSSTIFF => 61.0,
+            PokemonName::SHROODLE => 0.7,
+            PokemonName::GRAFAIAI => 27.2,
+            PokemonName::BRAMBLIN => 0.6,
+            PokemonName::BRAMBLEGHAST => 6.0,
+            PokemonName::TOEDSCOOL => 33.0,
+            PokemonName::TOEDSCRUEL => 58.0,
+            PokemonName::KLAWF => 79.0,
+            PokemonName::CAPSAKID => 3.0,
+            PokemonName::SCOVILLAIN => 15.0,
+            PokemonName::RELLOR => 1.0,
+            PokemonName::RABSCA => 3.5,
+            PokemonName::FLITTLE => 1.5,
+            PokemonName::ESPATHRA => 90.0,
+            PokemonName::TINKATINK => 8.9,
+            PokemonName::TINKATUFF => 59.1,
+            PokemonName::TINKATON => 112.8,
+            PokemonName::WIGLETT => 1.8,
+            PokemonName::WUGTRIO => 5.4,
+            PokemonName::BOMBIRDIER => 42.9,
+            PokemonName::FINIZEN => 60.2,
+            PokemonName::PALAFIN => 60.2,
+            PokemonName::PALAFINHERO => 97.4,
+            PokemonName::VAROOM => 35.0,
+            PokemonName::REVAVROOM => 120.0,
+            PokemonName::CYCLIZAR => 63.0,
+            PokemonName::ORTHWORM => 310.0,
+            PokemonName::GLIMMET => 8.0,
+            PokemonName::GLIMMORA => 45.0,
+            PokemonName::GREAVARD => 35.0,
+            PokemonName::HOUNDSTONE => 15.0,
+            PokemonName::FLAMIGO => 37.0,
+            PokemonName::CETODDLE => 45.0,
+            PokemonName::CETITAN => 700.0,
+            PokemonName::VELUZA => 90.0,
+            PokemonName::DONDOZO => 220.0,
+            PokemonName::TATSUGIRI => 8.0,
+            PokemonName::TATSUGIRIDROOPY => 8.0,
+            PokemonName::TATSUGIRISTRETCHY => 8.0,
+            PokemonName::ANNIHILAPE => 56.0,
+            PokemonName::CLODSIRE => 223.0,
+            PokemonName::FARIGIRAF => 160.0,
+            PokemonName::DUDUNSPARCE => 39.2,
+            PokemonName::DUDUNSPARCETHREESEGMENT => 47.4,
+            PokemonName::KINGAMBIT => 120.0,
+            PokemonName::GREATTUSK => 320.0,
+            PokemonName::SCREAMTAIL => 8.0,
+            PokemonName::BRUTEBONNET => 21.0,
+            PokemonName::FLUTTERMANE => 4.0,
+            PokemonName::SLITHERWING => 92.0,
+            PokemonName::SANDYSHOCKS => 60.0,
+            PokemonName::IRONTREADS => 240.0,
+            PokemonName::IRONBUNDLE => 11.0,
+            PokemonName::IRONHANDS => 380.7,
+            PokemonName::IRONJUGULIS => 111.0,
+            PokemonName::IRONMOTH => 36.0,
+            PokemonName::IRONTHORNS => 303.0,
+            PokemonName::FRIGIBAX => 17.0,
+            PokemonName::ARCTIBAX => 30.0,
+            PokemonName::BAXCALIBUR => 210.0,
+            PokemonName::GIMMIGHOUL => 5.0,
+            PokemonName::GIMMIGHOULROAMING => 0.1,
+            PokemonName::GHOLDENGO => 30.0,
+            PokemonName::WOCHIEN => 74.2,
+            PokemonName::CHIENPAO => 152.2,
+            PokemonName::TINGLU => 699.7,
+            PokemonName::CHIYU => 4.9,
+            PokemonName::ROARINGMOON => 380.0,
+            PokemonName::IRONVALIANT => 35.0,
+            PokemonName::KORAIDON => 303.0,
+            PokemonName::MIRAIDON => 240.0,
+            PokemonName::WALKINGWAKE => 280.0,
+            PokemonName::IRONLEAVES => 125.0,
+            PokemonName::DIPPLIN => 4.4,
+            PokemonName::POLTCHAGEIST => 1.1,
+            PokemonName::POLTCHAGEISTARTISAN => 1.1,
+            PokemonName::SINISTCHA => 2.2,
+            PokemonName::SINISTCHAMASTERPIECE => 2.2,
+            PokemonName::OKIDOGI => 92.2,
+            PokemonName::MUNKIDORI => 12.2,
+            PokemonName::FEZANDIPITI => 30.1,
+            PokemonName::OGERPON => 39.8,
+            PokemonName::OGERPONWELLSPRING => 39.8,
+            PokemonName::OGERPONHEARTHFLAME => 39.8,
+            PokemonName::OGERPONCORNERSTONE => 39.8,
+            PokemonName::OGERPONTEALTERA => 39.8,
+            PokemonName::OGERPONWELLSPRINGTERA => 39.8,
+            PokemonName::OGERPONHEARTHFLAMETERA => 39.8,
+            PokemonName::OGERPONCORNERSTONETERA => 39.8,
+            PokemonName::ARCHALUDON => 60.0,
+            PokemonName::HYDRAPPLE => 93.0,
+            PokemonName::GOUGINGFIRE => 590.0,
+            PokemonName::RAGINGBOLT => 480.0,
+            PokemonName::IRONBOULDER => 162.5,
+            PokemonName::IRONCROWN => 156.0,
+            PokemonName::TERAPAGOS => 6.5,
+            PokemonName::TERAPAGOSTERASTAL => 16.0,
+            PokemonName::TERAPAGOSSTELLAR => 77.0,
+            PokemonName::PECHARUNT => 0.3,
+        }
+    }
+
+    pub fn standard_abilities(&self) -> &'static [Abilities] {
+        match self {
+            PokemonName::NONE => &[],
+            PokemonName::BULBASAUR => &[Abilities::OVERGROW],
+            PokemonName::IVYSAUR => &[Abilities::OVERGROW],
+            PokemonName::VENUSAUR => &[Abilities::OVERGROW],
+            PokemonName::VENUSAURMEGA => &[Abilities::THICKFAT],
+            PokemonName::VENUSAURGMAX => &[Abilities::OVERGROW],
+            PokemonName::CHARMANDER => &[Abilities::BLAZE],
+            PokemonName::CHARMELEON => &[Abilities::BLAZE],
+            PokemonName::CHARIZARD => &[Abilities::BLAZE],
+            PokemonName::CHARIZARDMEGAX => &[Abilities::TOUGHCLAWS],
+            PokemonName::CHARIZARDMEGAY => &[Abilities::DROUGHT],
+            PokemonName::CHARIZARDGMAX => &[Abilities::BLAZE],
+            PokemonName::SQUIRTLE => &[Abilities::TORRENT],
+            PokemonName::WARTORTLE => &[Abilities::TORRENT],
+            PokemonName::BLASTOISE => &[Abilities::TORRENT],
+            PokemonName::BLASTOISEMEGA => &[Abilities::MEGALAUNCHER],
+            PokemonName::BLASTOISEGMAX => &[Abilities::TORRENT],
+            PokemonName::CATERPIE => &[Abilities::SHIELDDUST],
+            PokemonName::METAPOD => &[Abilities::SHEDSKIN],
+            PokemonName::BUTTERFREE => &[Abilities::COMPOUNDEYES],
+            PokemonName::BUTTERFREEGMAX => &[Abilities::COMPOUNDEYES],
+            PokemonName::WEEDLE => &[Abilities::SHIELDDUST],
+            PokemonName::KAKUNA => &[Abilities::SHEDSKIN],
+            PokemonName::BEEDRILL => &[Abilities::SWARM],
+            PokemonName::BEEDRILLMEGA => &[Abilities::ADAPTABILITY],
+            PokemonName::PIDGEY => &[Abilities::KEENEYE, Abilities::TANGLEDFEET],
+            PokemonName::PIDGEOTTO => &[Abilities::KEENEYE, Abilities::TANGLEDFEET],
+            PokemonName::PIDGEOT => &[Abilities::KEENEYE, Abilities::TANGLEDFEET],
+            PokemonName::PIDGEOTMEGA => &[Abilities::NOGUARD],
+            PokemonName::RATTATA => &[Abilities::RUNAWAY, Abilities::GUTS],
+            PokemonName::RATTATAALOLA => &[Abilities::GLUTTONY, Abilities::HUSTLE],
+            PokemonName::RATICATE => &[Abilities::RUNAWAY, Abilities::GUTS],
+            PokemonName::RATICATEALOLA => &[Abilities::GLUTTONY, Abilities::HUSTLE],
+            PokemonName::RATICATEALOLATOTEM => &[Abilities::THICKFAT],
+            PokemonName::SPEAROW => &[Abilities::KEENEYE],
+            PokemonName::FEAROW => &[Abilities::KEENEYE],
+            PokemonName::EKANS => &[Abilities::INTIMIDATE, Abilities::SHEDSKIN],
+            PokemonName::ARBOK => &[Abilities::INTIMIDATE, Abilities::SHEDSKIN],
+            PokemonName::PIKACHU => &[Abilities::STATIC],
+            PokemonName::PIKACHUCOSPLAY => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUROCKSTAR => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUBELLE => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUPOPSTAR => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUPHD => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHULIBRE => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUORIGINAL => &[Abilities::STATIC],
+            PokemonName::PIKACHUHOENN => &[Abilities::STATIC],
+            PokemonName::PIKACHUSINNOH => &[Abilities::STATIC],
+            PokemonName::PIKACHUUNOVA => &[Abilities::STATIC],
+            PokemonName::PIKACHUKALOS => &[Abilities::STATIC],
+            PokemonName::PIKACHUALOLA => &[Abilities::STATIC],
+            PokemonName::PIKACHUPARTNER => &[Abilities::STATIC],
+            PokemonName::PIKACHUSTARTER => &[Abilities::STATIC],
+            PokemonName::PIKACHUGMAX => &[Abilities::STATIC],
+            PokemonName::PIKACHUWORLD => &[Abilities::STATIC],
+            PokemonName::RAICHU => &[Abilities::STATIC],
+            PokemonName::RAICHUALOLA => &[Abilities::SURGESURFER],
+            PokemonName::SANDSHREW => &[Abilities::SANDVEIL],
+            PokemonName::SANDSHREWALOLA => &[Abilities::SNOWCLOAK],
+            PokemonName::SANDSLASH => &[Abilities::SANDVEIL],
+            PokemonName::SANDSLASHALOLA => &[Abilities::SNOWCLOAK],
+            PokemonName::NIDORANF => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::NIDORINA => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::NIDOQUEEN => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::NIDORANM => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::NIDORINO => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::NIDOKING => &[Abilities::POISONPOINT, Abilities::RIVALRY],
+            PokemonName::CLEFAIRY => &[Abilities::CUTECHARM, Abilities::MAGICGUARD],
+            PokemonName::CLEFABLE => &[Abilities::CUTECHARM, Abilities::MAGICGUARD],
+            PokemonName::VULPIX => &[Abilities::FLASHFIRE],
+            PokemonName::VULPIXALOLA => &[Abilities::SNOWCLOAK],
+            PokemonName::NINETALES => &[Abilities::FLASHFIRE],
+            PokemonName::NINETALESALOLA => &[Abilities::SNOWCLOAK],
+            PokemonName::JIGGLYPUFF => &[Abilities::CUTECHARM, Abilities::COMPETITIVE],
+            PokemonName::WIGGLYTUFF => &[Abilities::CUTECHARM, Abilities::COMPETITIVE],
+            PokemonName::ZUBAT => &[Abilities::INNERFOCUS],
+            PokemonName::GOLBAT => &[Abilities::INNERFOCUS],
+            PokemonName::ODDISH => &[Abilities::CHLOROPHYLL],
+            PokemonName::GLOOM => &[Abilities::CHLOROPHYLL],
+            PokemonName::VILEPLUME => &[Abilities::CHLOROPHYLL],
+            PokemonName::PARAS => &[Abilities::EFFECTSPORE, Abilities::DRYSKIN],
+            PokemonName::PARASECT => &[Abilities::EFFECTSPORE, Abilities::DRYSKIN],
+            PokemonName::VENONAT => &[Abilities::COMPOUNDEYES, Abilities::TINTEDLENS],
+            PokemonName::VENOMOTH => &[Abilities::SHIELDDUST, Abilities::TINTEDLENS],
+            PokemonName::DIGLETT => &[Abilities::SANDVEIL, Abilities::ARENATRAP],
+            PokemonName::DIGLETTALOLA => &[Abilities::SANDVEIL, Abilities::TANGLINGHAIR],
+            PokemonName::DUGTRIO => &[Abilities::SANDVEIL, Abilities::ARENATRAP],
+            PokemonName::DUGTRIOALOLA => &[Abilities::SANDVEIL, Abilities::TANGLINGHAIR],
+            PokemonName::MEOWTH => &[Abilities::PICKUP, Abilities::TECHNICIAN],
+            PokemonName::MEOWTHALOLA => &[Abilities::PICKUP, Abilities::TECHNICIAN],
+            PokemonName::MEOWTHGALAR => &[Abilities::PICKUP, Abilities::TOUGHCLAWS],
+            PokemonName::MEOWTHGMAX => &[Abilities::PICKUP, Abilities::TECHNICIAN],
+            PokemonName::PERSIAN => &[Abilities::LIMBER, Abilities::TECHNICIAN],
+            PokemonName::PERSIANALOLA => &[Abilities::FURCOAT, Abilities::TECHNICIAN],
+            PokemonName::PSYDUCK => &[Abilities::DAMP, Abilities::CLOUDNINE],
+            PokemonName::GOLDUCK => &[Abilities::DAMP, Abilities::CLOUDNINE],
+            PokemonName::MANKEY => &[Abilities::VITALSPIRIT, Abilities::ANGERPOINT],
+            PokemonName::PRIMEAPE => &[Abilities::VITALSPIRIT, Abilities::ANGERPOINT],
+            PokemonName::GROWLITHE => &[Abilities::INTIMIDATE, Abilities::FLASHFIRE],
+            PokemonName::GROWLITHEHISUI => &[Abilities::INTIMIDATE, Abilities::FLASHFIRE],
+            PokemonName::ARCANINE => &[Abilities::INTIMIDATE, Abilities::FLASHFIRE],
+            PokemonName::ARCANINEHISUI => &[Abilities::INTIMIDATE, Abilities::FLASHFIRE],
+            PokemonName::POLIWAG => &[Abilities::WATERABSORB, Abilities::DAMP],
+            PokemonName::POLIWHIRL => &[Abilities::WATERABSORB, Abilities::DAMP],
+            PokemonName::POLIWRATH => &[Abilities::WATERABSORB, Abilities::DAMP],
+            PokemonName::ABRA => &[Abilities::SYNCHRONIZE, Abilities::INNERFOCUS],
+            PokemonName::KADABRA => &[Abilities::SYNCHRONIZE, Abilities::INNERFOCUS],
+            PokemonName::ALAKAZAM => &[Abilities::SYNCHRONIZE, Abilities::INNERFOCUS],
+            PokemonName::ALAKAZAMMEGA => &[Abilities::TRACE],
+            PokemonName::MACHOP => &[Abilities::GUTS, Abilities::NOGUARD],
+            PokemonName::MACHOKE => &[Abilities::GUTS, Abilities::NOGUARD],
+            PokemonName::MACHAMP => &[Abilities::GUTS, Abilities::NOGUARD],
+            PokemonName::MACHAMPGMAX => &[Abilities::GUTS, Abilities::NOGUARD],
+            PokemonName::BELLSPROUT => &[Abilities::CHLOROPHYLL],
+            PokemonName::WEEPINBELL => &[Abilities::CHLOROPHYLL],
+            PokemonName::VICTREEBEL => &[Abilities::CHLOROPHYLL],
+            PokemonName::TENTACOOL => &[Abilities::CLEARBODY, Abilities::LIQUIDOOZE],
+            PokemonName::TENTACRUEL => &[Abilities::CLEARBODY, Abilities::LIQUIDOOZE],
+            PokemonName::GEODUDE => &[Abilities::ROCKHEAD, Abilities::STURDY],
+            PokemonName::GEODUDEALOLA => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::GRAVELER => &[Abilities::ROCKHEAD, Abilities::STURDY],
+            PokemonName::GRAVELERALOLA => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::GOLEM => &[Abilities::ROCKHEAD, Abilities::STURDY],
+            PokemonName::GOLEMALOLA => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::PONYTA => &[Abilities::RUNAWAY, Abilities::FLASHFIRE],
+            PokemonName::PONYTAGALAR => &[Abilities::RUNAWAY, Abilities::PASTELVEIL],
+            PokemonName::RAPIDASH => &[Abilities::RUNAWAY, Abilities::FLASHFIRE],
+            PokemonName::RAPIDASHGALAR => &[Abilities::RUNAWAY, Abilities::PASTELVEIL],
+            PokemonName::SLOWPOKE => &[Abilities::OBLIVIOUS, Abilities::OWNTEMPO],
+            PokemonName::SLOWPOKEGALAR => &[Abilities::GLUTTONY, Abilities::OWNTEMPO],
+            PokemonName::SLOWBRO => &[Abilities::OBLIVIOUS, Abilities::OWNTEMPO],
+            PokemonName::SLOWBROMEGA => &[Abilities::SHELLARMOR],
+            PokemonName::SLOWBROGALAR => &[Abilities::QUICKDRAW, Abilities::OWNTEMPO],
+            PokemonName::MAGNEMITE => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::MAGNETON => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::FARFETCHD => &[Abilities::KEENEYE, Abilities::INNERFOCUS],
+            PokemonName::FARFETCHDGALAR => &[Abilities::STEADFAST],
+            PokemonName::DODUO => &[Abilities::RUNAWAY, Abilities::EARLYBIRD],
+            PokemonName::DODRIO => &[Abilities::RUNAWAY, Abilities::EARLYBIRD],
+            PokemonName::SEEL => &[Abilities::THICKFAT, Abilities::HYDRATION],
+            PokemonName::DEWGONG => &[Abilities::THICKFAT, Abilities::HYDRATION],
+            PokemonName::GRIMER => &[Abilities::STENCH, Abilities::STICKYHOLD],
+            PokemonName::GRIMERALOLA => &[Abilities::POISONTOUCH, Abilities::GLUTTONY],
+            PokemonName::MUK => &[Abilities::STENCH, Abilities::STICKYHOLD],
+            PokemonName::MUKALOLA => &[Abilities::POISONTOUCH, Abilities::GLUTTONY],
+            PokemonName::SHELLDER => &[Abilities::SHELLARMOR, Abilities::SKILLLINK],
+            PokemonName::CLOYSTER => &[Abilities::SHELLARMOR, Abilities::SKILLLINK],
+            PokemonName::GASTLY => &[Abilities::LEVITATE],
+            PokemonName::HAUNTER => &[Abilities::LEVITATE],
+            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+            PokemonName::GENGAR => &[Abilities::LEVITATE],
+            #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+            PokemonName::GENGAR => &[Abilities::CURSEDBODY],
+            PokemonName::GENGARMEGA => &[Abilities::SHADOWTAG],
+            PokemonName::GENGARGMAX => &[Abilities::CURSEDBODY],
+            PokemonName::ONIX => &[Abilities::ROCKHEAD, Abilities::STURDY],
+            PokemonName::DROWZEE => &[Abilities::INSOMNIA, Abilities::FOREWARN],
+            PokemonName::HYPNO => &[Abilities::INSOMNIA, Abilities::FOREWARN],
+            PokemonName::KRABBY => &[Abilities::HYPERCUTTER, Abilities::SHELLARMOR],
+            PokemonName::KINGLER => &[Abilities::HYPERCUTTER, Abilities::SHELLARMOR],
+            PokemonName::KINGLERGMAX => &[Abilities::HYPERCUTTER, Abilities::SHELLARMOR],
+            PokemonName::VOLTORB => &[Abilities::SOUNDPROOF, Abilities::STATIC],
+            PokemonName::VOLTORBHISUI => &[Abilities::SOUNDPROOF, Abilities::STATIC],
+            PokemonName::ELECTRODE => &[Abilities::SOUNDPROOF, Abilities::STATIC],
+            PokemonName::ELECTRODEHISUI => &[Abilities::SOUNDPROOF, Abilities::STATIC],
+            PokemonName::EXEGGCUTE => &[Abilities::CHLOROPHYLL],
+            PokemonName::EXEGGUTOR => &[Abilities::CHLOROPHYLL],
+            PokemonName::EXEGGUTORALOLA => &[Abilities::FRISK],
+            PokemonName::CUBONE => &[Abilities::ROCKHEAD, Abilities::LIGHTNINGROD],
+            PokemonName::MAROWAK => &[Abilities::ROCKHEAD, Abilities::LIGHTNINGROD],
+            PokemonName::MAROWAKALOLA => &[Abilities::CURSEDBODY, Abilities::LIGHTNINGROD],
+            PokemonName::MAROWAKALOLATOTEM => &[Abilities::ROCKHEAD],
+            PokemonName::HITMONLEE => &[Abilities::LIMBER, Abilities::RECKLESS],
+            PokemonName::HITMONCHAN => &[Abilities::KEENEYE, Abilities::IRONFIST],
+            PokemonName::LICKITUNG => &[Abilities::OWNTEMPO, Abilities::OBLIVIOUS],
+            PokemonName::KOFFING => &[Abilities::LEVITATE, Abilities::NEUTRALIZINGGAS],
+            PokemonName::WEEZING => &[Abilities::LEVITATE, Abilities::NEUTRALIZINGGAS],
+            PokemonName::WEEZINGGALAR => &[Abilities::LEVITATE, Abilities::NEUTRALIZINGGAS],
+            PokemonName::RHYHORN => &[Abilities::LIGHTNINGROD, Abilities::ROCKHEAD],
+            PokemonName::RHYDON => &[Abilities::LIGHTNINGROD, Abilities::ROCKHEAD],
+            PokemonName::CHANSEY => &[Abilities::NATURALCURE, Abilities::SERENEGRACE],
+            PokemonName::TANGELA => &[Abilities::CHLOROPHYLL, Abilities::LEAFGUARD],
+            PokemonName::KANGASKHAN => &[Abilities::EARLYBIRD, Abilities::SCRAPPY],
+            PokemonName::KANGASKHANMEGA => &[Abilities::PARENTALBOND],
+            PokemonName::HORSEA => &[Abilities::SWIFTSWIM, Abilities::SNIPER],
+            PokemonName::SEADRA => &[Abilities::POISONPOINT, Abilities::SNIPER],
+            PokemonName::GOLDEEN => &[Abilities::SWIFTSWIM, Abilities::WATERVEIL],
+            PokemonName::SEAKING => &[Abilities::SWIFTSWIM, Abilities::WATERVEIL],
+            PokemonName::STARYU => &[Abilities::ILLUMINATE, Abilities::NATURALCURE],
+            PokemonName::STARMIE => &[Abilities::ILLUMINATE, Abilities::NATURALCURE],
+            PokemonName::MRMIME => &[Abilities::SOUNDPROOF, Abilities::FILTER],
+            PokemonName::MRMIMEGALAR => &[Abilities::VITALSPIRIT, Abilities::SCREENCLEANER],
+            PokemonName::SCYTHER => &[Abilities::SWARM, Abilities::TECHNICIAN],
+            PokemonName::JYNX => &[Abilities::OBLIVIOUS, Abilities::FOREWARN],
+            PokemonName::ELECTABUZZ => &[Abilities::STATIC],
+            PokemonName::MAGMAR => &[Abilities::FLAMEBODY],
+            PokemonName::PINSIR => &[Abilities::HYPERCUTTER, Abilities::MOLDBREAKER],
+            PokemonName::PINSIRMEGA => &[Abilities::AERILATE],
+            PokemonName::TAUROS => &[Abilities::INTIMIDATE, Abilities::ANGERPOINT],
+            PokemonName::TAUROSPALDEACOMBAT => &[Abilities::INTIMIDATE, Abilities::ANGERPOINT],
+            PokemonName::TAUROSPALDEABLAZE => &[Abilities::INTIMIDATE, Abilities::ANGERPOINT],
+            PokemonName::TAUROSPALDEAAQUA => &[Abilities::INTIMIDATE, Abilities::ANGERPOINT],
+            PokemonName::MAGIKARP => &[Abilities::SWIFTSWIM],
+            PokemonName::GYARADOS => &[Abilities::INTIMIDATE],
+            PokemonName::GYARADOSMEGA => &[Abilities::MOLDBREAKER],
+            PokemonName::LAPRAS => &[Abilities::WATERABSORB, Abilities::SHELLARMOR],
+            PokemonName::LAPRASGMAX => &[Abilities::WATERABSORB, Abilities::SHELLARMOR],
+            PokemonName::DITTO => &[Abilities::LIMBER],
+            PokemonName::EEVEE => &[Abilities::RUNAWAY, Abilities::ADAPTABILITY],
+            PokemonName::EEVEESTARTER => &[Abilities::RUNAWAY, Abilities::ADAPTABILITY],
+            PokemonName::EEVEEGMAX => &[Abilities::RUNAWAY, Abilities::ADAPTABILITY],
+            PokemonName::VAPOREON => &[Abilities::WATERABSORB],
+            PokemonName::JOLTEON => &[Abilities::VOLTABSORB],
+            PokemonName::FLAREON => &[Abilities::FLASHFIRE],
+            PokemonName::PORYGON => &[Abilities::TRACE, Abilities::DOWNLOAD],
+            PokemonName::OMANYTE => &[Abilities::SWIFTSWIM, Abilities::SHELLARMOR],
+            PokemonName::OMASTAR => &[Abilities::SWIFTSWIM, Abilities::SHELLARMOR],
+            PokemonName::KABUTO => &[Abilities::SWIFTSWIM, Abilities::BATTLEARMOR],
+            PokemonName::KABUTOPS => &[Abilities::SWIFTSWIM, Abilities::BATTLEARMOR],
+            PokemonName::AERODACTYL => &[Abilities::ROCKHEAD, Abilities::PRESSURE],
+            PokemonName::AERODACTYLMEGA => &[Abilities::TOUGHCLAWS],
+            PokemonName::SNORLAX => &[Abilities::IMMUNITY, Abilities::THICKFAT],
+            PokemonName::SNORLAXGMAX => &[Abilities::IMMUNITY, Abilities::THICKFAT],
+            PokemonName::ARTICUNO => &[Abilities::PRESSURE],
+            PokemonName::ARTICUNOGALAR => &[Abilities::COMPETITIVE],
+            PokemonName::ZAPDOS => &[Abilities::PRESSURE],
+            PokemonName::ZAPDOSGALAR => &[Abilities::DEFIANT],
+            PokemonName::MOLTRES => &[Abilities::PRESSURE],
+            PokemonName::MOLTRESGALAR => &[Abilities::BERSERK],
+            PokemonName::DRATINI => &[Abilities::SHEDSKIN],
+            PokemonName::DRAGONAIR => &[Abilities::SHEDSKIN],
+            PokemonName::DRAGONITE => &[Abilities::INNERFOCUS],
+            PokemonName::MEWTWO => &[Abilities::PRESSURE],
+            PokemonName::MEWTWOMEGAX => &[Abilities::STEADFAST],
+            PokemonName::MEWTWOMEGAY => &[Abilities::INSOMNIA],
+            PokemonName::MEW => &[Abilities::SYNCHRONIZE],
+            PokemonName::CHIKORITA => &[Abilities::OVERGROW],
+            PokemonName::BAYLEEF => &[Abilities::OVERGROW],
+            PokemonName::MEGANIUM => &[Abilities::OVERGROW],
+            PokemonName::CYNDAQUIL => &[Abilities::BLAZE],
+            PokemonName::QUILAVA => &[Abilities::BLAZE],
+            PokemonName::TYPHLOSION => &[Abilities::BLAZE],
+            PokemonName::TYPHLOSIONHISUI => &[Abilities::BLAZE],
+            PokemonName::TOTODILE => &[Abilities::TORRENT],
+            PokemonName::CROCONAW => &[Abilities::TORRENT],
+            PokemonName::FERALIGATR => &[Abilities::TORRENT],
+            PokemonName::SENTRET => &[Abilities::RUNAWAY, Abilities::KEENEYE],
+            PokemonName::FURRET => &[Abilities::RUNAWAY, Abilities::KEENEYE],
+            PokemonName::HOOTHOOT => &[Abilities::INSOMNIA, Abilities::KEENEYE],
+            PokemonName::NOCTOWL => &[Abilities::INSOMNIA, Abilities::KEENEYE],
+            PokemonName::LEDYBA => &[Abilities::SWARM, Abilities::EARLYBIRD],
+            PokemonName::LEDIAN => &[Abilities::SWARM, Abilities::EARLYBIRD],
+            PokemonName::SPINARAK => &[Abilities::SWARM, Abilities::INSOMNIA],
+            PokemonName::ARIADOS => &[Abilities::SWARM, Abilities::INSOMNIA],
+            PokemonName::CROBAT => &[Abilities::INNERFOCUS],
+            PokemonName::CHINCHOU => &[Abilities::VOLTABSORB, Abilities::ILLUMINATE],
+            PokemonName::LANTURN => &[Abilities::VOLTABSORB, Abilities::ILLUMINATE],
+            PokemonName::PICHU => &[Abilities::STATIC],
+            PokemonName::PICHUSPIKYEARED => &[Abilities::STATIC],
+            PokemonName::CLEFFA => &[Abilities::CUTECHARM, Abilities::MAGICGUARD],
+            PokemonName::IGGLYBUFF => &[Abilities::CUTECHARM, Abilities::COMPETITIVE],
+            PokemonName::TOGEPI => &[Abilities::HUSTLE, Abilities::SERENEGRACE],
+            PokemonName::TOGETIC => &[Abilities::HUSTLE, Abilities::SERENEGRACE],
+            PokemonName::NATU => &[Abilities::SYNCHRONIZE, Abilities::EARLYBIRD],
+            PokemonName::XATU => &[Abilities::SYNCHRONIZE, Abilities::EARLYBIRD],
+            PokemonName::MAREEP => &[Abilities::STATIC],
+            PokemonName::FLAAFFY => &[Abilities::STATIC],
+            PokemonName::AMPHAROS => &[Abilities::STATIC],
+            PokemonName::AMPHAROSMEGA => &[Abilities::MOLDBREAKER],
+            PokemonName::BELLOSSOM => &[Abilities::CHLOROPHYLL],
+            PokemonName::MARILL => &[Abilities::THICKFAT, Abilities::HUGEPOWER],
+            PokemonName::AZUMARILL => &[Abilities::THICKFAT, Abilities::HUGEPOWER],
+            PokemonName::SUDOWOODO => &[Abilities::STURDY, Abilities::ROCKHEAD],
+            PokemonName::POLITOED => &[Abilities::WATERABSORB, Abilities::DAMP],
+            PokemonName::HOPPIP => &[Abilities::CHLOROPHYLL, Abilities::LEAFGUARD],
+            PokemonName::SKIPLOOM => &[Abilities::CHLOROPHYLL, Abilities::LEAFGUARD],
+            PokemonName::JUMPLUFF => &[Abilities::CHLOROPHYLL, Abilities::LEAFGUARD],
+            PokemonName::AIPOM => &[Abilities::RUNAWAY, Abilities::PICKUP],
+            PokemonName::SUNKERN => &[Abilities::CHLOROPHYLL, Abilities::SOLARPOWER],
+            PokemonName::SUNFLORA => &[Abilities::CHLOROPHYLL, Abilities::SOLARPOWER],
+            PokemonName::YANMA => &[Abilities::SPEEDBOOST, Abilities::COMPOUNDEYES],
+            PokemonName::WOOPER => &[Abilities::DAMP, Abilities::WATERABSORB],
+            PokemonName::WOOPERPALDEA => &[Abilities::POISONPOINT, Abilities::WATERABSORB],
+            PokemonName::QUAGSIRE => &[Abilities::DAMP, Abilities::WATERABSORB],
+            PokemonName::ESPEON => &[Abilities::SYNCHRONIZE],
+            PokemonName::UMBREON => &[Abilities::SYNCHRONIZE],
+            PokemonName::MURKROW => &[Abilities::INSOMNIA, Abilities::SUPERLUCK],
+            PokemonName::SLOWKING => &[Abilities::OBLIVIOUS, Abilities::OWNTEMPO],
+            PokemonName::SLOWKINGGALAR => &[Abilities::CURIOUSMEDICINE, Abilities::OWNTEMPO],
+            PokemonName::MISDREAVUS => &[Abilities::LEVITATE],
+            PokemonName::UNOWN => &[Abilities::LEVITATE],
+            PokemonName::UNOWNB => &[Abilities::LEVITATE],
+            PokemonName::UNOWNC => &[Abilities::LEVITATE],
+            PokemonName::UNOWND => &[Abilities::LEVITATE],
+            PokemonName::UNOWNE => &[Abilities::LEVITATE],
+            PokemonName::UNOWNF => &[Abilities::LEVITATE],
+            PokemonName::UNOWNG => &[Abilities::LEVITATE],
+            PokemonName::UNOWNH => &[Abilities::LEVITATE],
+            PokemonName::UNOWNI => &[Abilities::LEVITATE],
+            PokemonName::UNOWNJ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNK => &[Abilities::LEVITATE],
+            PokemonName::UNOWNL => &[Abilities::LEVITATE],
+            PokemonName::UNOWNM => &[Abilities::LEVITATE],
+            PokemonName::UNOWNN => &[Abilities::LEVITATE],
+            PokemonName::UNOWNO => &[Abilities::LEVITATE],
+            PokemonName::UNOWNP => &[Abilities::LEVITATE],
+            PokemonName::UNOWNQ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNR => &[Abilities::LEVITATE],
+            PokemonName::UNOWNS => &[Abilities::LEVITATE],
+            PokemonName::UNOWNT => &[Abilities::LEVITATE],
+            PokemonName::UNOWNU => &[Abilities::LEVITATE],
+            PokemonName::UNOWNV => &[Abilities::LEVITATE],
+            PokemonName::UNOWNW => &[Abilities::LEVITATE],
+            PokemonName::UNOWNX => &[Abilities::LEVITATE],
+            PokemonName::UNOWNY => &[Abilities::LEVITATE],
+            PokemonName::UNOWNZ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNEXCLAMATION => &[Abilities::LEVITATE],
+            PokemonName::UNOWNQUESTION => &[Abilities::LEVITATE],
+            PokemonName::WOBBUFFET => &[Abilities::SHADOWTAG],
+            PokemonName::GIRAFARIG => &[Abilities::INNERFOCUS, Abilities::EARLYBIRD],
+            PokemonName::PINECO => &[Abilities::STURDY],
+            PokemonName::FORRETRESS => &[Abilities::STURDY],
+            PokemonName::DUNSPARCE => &[Abilities::SERENEGRACE, Abilities::RUNAWAY],
+            PokemonName::GLIGAR => &[Abilities::HYPERCUTTER, Abilities::SANDVEIL],
+            PokemonName::STEELIX => &[Abilities::ROCKHEAD, Abilities::STURDY],
+            PokemonName::STEELIXMEGA => &[Abilities::SANDFORCE],
+            PokemonName::SNUBBULL => &[Abilities::INTIMIDATE, Abilities::RUNAWAY],
+            PokemonName::GRANBULL => &[Abilities::INTIMIDATE, Abilities::QUICKFEET],
+            PokemonName::QWILFISH => &[Abilities::POISONPOINT, Abilities::SWIFTSWIM],
+            PokemonName::QWILFISHHISUI => &[Abilities::POISONPOINT, Abilities::SWIFTSWIM],
+            PokemonName::SCIZOR => &[Abilities::SWARM, Abilities::TECHNICIAN],
+            PokemonName::SCIZORMEGA => &[Abilities::TECHNICIAN],
+            PokemonName::SHUCKLE => &[Abilities::STURDY, Abilities::GLUTTONY],
+            PokemonName::HERACROSS => &[Abilities::SWARM, Abilities::GUTS],
+            PokemonName::HERACROSSMEGA => &[Abilities::SKILLLINK],
+            PokemonName::SNEASEL => &[Abilities::INNERFOCUS, Abilities::KEENEYE],
+            PokemonName::SNEASELHISUI => &[Abilities::INNERFOCUS, Abilities::KEENEYE],
+            PokemonName::TEDDIURSA => &[Abilities::PICKUP, Abilities::QUICKFEET],
+            PokemonName::URSARING => &[Abilities::GUTS, Abilities::QUICKFEET],
+            PokemonName::SLUGMA => &[Abilities::MAGMAARMOR, Abilities::FLAMEBODY],
+            PokemonName::MAGCARGO => &[Abilities::MAGMAARMOR, Abilities::FLAMEBODY],
+            PokemonName::SWINUB => &[Abilities::OBLIVIOUS, Abilities::SNOWCLOAK],
+            PokemonName::PILOSWINE => &[Abilities::OBLIVIOUS, Abilities::SNOWCLOAK],
+            PokemonName::CORSOLA => &[Abilities::HUSTLE, Abilities::NATURALCURE],
+            PokemonName::CORSOLAGALAR => &[Abilities::WEAKARMOR],
+            PokemonName::REMORAID => &[Abilities::HUSTLE, Abilities::SNIPER],
+            PokemonName::OCTILLERY => &[Abilities::SUCTIONCUPS, Abilities::SNIPER],
+            PokemonName::DELIBIRD => &[Abilities::VITALSPIRIT, Abilities::HUSTLE],
+            PokemonName::MANTINE => &[Abilities::SWIFTSWIM, Abilities::WATERABSORB],
+            PokemonName::SKARMORY => &[Abilities::KEENEYE, Abilities::STURDY],
+            PokemonName::HOUNDOUR => &[Abilities::EARLYBIRD, Abilities::FLASHFIRE],
+            PokemonName::HOUNDOOM => &[Abilities::EARLYBIRD, Abilities::FLASHFIRE],
+            PokemonName::HOUNDOOMMEGA => &[Abilities::SOLARPOWER],
+            PokemonName::KINGDRA => &[Abilities::SWIFTSWIM, Abilities::SNIPER],
+            PokemonName::PHANPY => &[Abilities::PICKUP],
+            PokemonName::DONPHAN => &[Abilities::STURDY],
+            PokemonName::PORYGON2 => &[Abilities::TRACE, Abilities::DOWNLOAD],
+            PokemonName::STANTLER => &[Abilities::INTIMIDATE, Abilities::FRISK],
+            PokemonName::SMEARGLE => &[Abilities::OWNTEMPO, Abilities::TECHNICIAN],
+            PokemonName::TYROGUE => &[Abilities::GUTS, Abilities::STEADFAST],
+            PokemonName::HITMONTOP => &[Abilities::INTIMIDATE, Abilities::TECHNICIAN],
+            PokemonName::SMOOCHUM => &[Abilities::OBLIVIOUS, Abilities::FOREWARN],
+            PokemonName::ELEKID => &[Abilities::STATIC],
+            PokemonName::MAGBY => &[Abilities::FLAMEBODY],
+            PokemonName::MILTANK => &[Abilities::THICKFAT, Abilities::SCRAPPY],
+            PokemonName::BLISSEY => &[Abilities::NATURALCURE, Abilities::SERENEGRACE],
+            PokemonName::RAIKOU => &[Abilities::PRESSURE],
+            PokemonName::ENTEI => &[Abilities::PRESSURE],
+            PokemonName::SUICUNE => &[Abilities::PRESSURE],
+            PokemonName::LARVITAR => &[Abilities::GUTS],
+            PokemonName::PUPITAR => &[Abilities::SHEDSKIN],
+            PokemonName::TYRANITAR => &[Abilities::SANDSTREAM],
+            PokemonName::TYRANITARMEGA => &[Abilities::SANDSTREAM],
+            PokemonName::LUGIA => &[Abilities::PRESSURE],
+            PokemonName::HOOH => &[Abilities::PRESSURE],
+            PokemonName::CELEBI => &[Abilities::NATURALCURE],
+            PokemonName::TREECKO => &[Abilities::OVERGROW],
+            PokemonName::GROVYLE => &[Abilities::OVERGROW],
+            PokemonName::SCEPTILE => &[Abilities::OVERGROW],
+            PokemonName::SCEPTILEMEGA => &[Abilities::LIGHTNINGROD],
+            PokemonName::TORCHIC => &[Abilities::BLAZE],
+            PokemonName::COMBUSKEN => &[Abilities::BLAZE],
+            PokemonName::BLAZIKEN => &[Abilities::BLAZE],
+            PokemonName::BLAZIKENMEGA => &[Abilities::SPEEDBOOST],
+            PokemonName::MUDKIP => &[Abilities::TORRENT],
+            PokemonName::MARSHTOMP => &[Abilities::TORRENT],
+            PokemonName::SWAMPERT => &[Abilities::TORRENT],
+            PokemonName::SWAMPERTMEGA => &[Abilities::SWIFTSWIM],
+            PokemonName::POOCHYENA => &[Abilities::RUNAWAY, Abilities::QUICKFEET],
+            PokemonName::MIGHTYENA => &[Abilities::INTIMIDATE, Abilities::QUICKFEET],
+            PokemonName::ZIGZAGOON => &[Abilities::PICKUP, Abilities::GLUTTONY],
+            PokemonName::ZIGZAGOONGALAR => &[Abilities::PICKUP, Abilities::GLUTTONY],
+            PokemonName::LINOONE => &[Abilities::PICKUP, Abilities::GLUTTONY],
+            PokemonName::LINOONEGALAR => &[Abilities::PICKUP, Abilities::GLUTTONY],
+            PokemonName::WURMPLE => &[Abilities::SHIELDDUST],
+            PokemonName::SILCOON => &[Abilities::SHEDSKIN],
+            PokemonName::BEAUTIFLY => &[Abilities::SWARM],
+            PokemonName::CASCOON => &[Abilities::SHEDSKIN],
+            PokemonName::DUSTOX => &[Abilities::SHIELDDUST],
+            PokemonName::LOTAD => &[Abilities::SWIFTSWIM, Abilities::RAINDISH],
+            PokemonName::LOMBRE => &[Abilities::SWIFTSWIM, Abilities::RAINDISH],
+            PokemonName::LUDICOLO => &[Abilities::SWIFTSWIM, Abilities::RAINDISH],
+            PokemonName::SEEDOT => &[Abilities::CHLOROPHYLL, Abilities::EARLYBIRD],
+            PokemonName::NUZLEAF => &[Abilities::CHLOROPHYLL, Abilities::EARLYBIRD],
+            PokemonName::SHIFTRY => &[Abilities::CHLOROPHYLL, Abilities::WINDRIDER],
+            PokemonName::TAILLOW => &[Abilities::GUTS],
+            PokemonName::SWELLOW => &[Abilities::GUTS],
+            PokemonName::WINGULL => &[Abilities::KEENEYE, Abilities::HYDRATION],
+            PokemonName::PELIPPER => &[Abilities::KEENEYE, Abilities::DRIZZLE],
+            PokemonName::RALTS => &[Abilities::SYNCHRONIZE, Abilities::TRACE],
+            PokemonName::KIRLIA => &[Abilities::SYNCHRONIZE, Abilities::TRACE],
+            PokemonName::GARDEVOIR => &[Abilities::SYNCHRONIZE, Abilities::TRACE],
+            PokemonName::GARDEVOIRMEGA => &[Abilities::PIXILATE],
+            PokemonName::SURSKIT => &[Abilities::SWIFTSWIM],
+            PokemonName::MASQUERAIN => &[Abilities::INTIMIDATE],
+            PokemonName::SHROOMISH => &[Abilities::EFFECTSPORE, Abilities::POISONHEAL],
+            PokemonName::BRELOOM => &[Abilities::EFFECTSPORE, Abilities::POISONHEAL],
+            PokemonName::SLAKOTH => &[Abilities::TRUANT],
+            PokemonName::VIGOROTH => &[Abilities::VITALSPIRIT],
+            PokemonName::SLAKING => &[Abilities::TRUANT],
+            PokemonName::NINCADA => &[Abilities::COMPOUNDEYES],
+            PokemonName::NINJASK => &[Abilities::SPEEDBOOST],
+            PokemonName::SHEDINJA => &[Abilities::WONDERGUARD],
+            PokemonName::WHISMUR => &[Abilities::SOUNDPROOF],
+            PokemonName::LOUDRED => &[Abilities::SOUNDPROOF],
+            PokemonName::EXPLOUD => &[Abilities::SOUNDPROOF],
+            PokemonName::MAKUHITA => &[Abilities::THICKFAT, Abilities::GUTS],
+            PokemonName::HARIYAMA => &[Abilities::THICKFAT, Abilities::GUTS],
+            PokemonName::AZURILL => &[Abilities::THICKFAT, Abilities::HUGEPOWER],
+            PokemonName::NOSEPASS => &[Abilities::STURDY, Abilities::MAGNETPULL],
+            PokemonName::SKITTY => &[Abilities::CUTECHARM, Abilities::NORMALIZE],
+            PokemonName::DELCATTY => &[Abilities::CUTECHARM, Abilities::NORMALIZE],
+            PokemonName::SABLEYE => &[Abilities::KEENEYE, Abilities::STALL],
+            PokemonName::SABLEYEMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::MAWILE => &[Abilities::HYPERCUTTER, Abilities::INTIMIDATE],
+            PokemonName::MAWILEMEGA => &[Abilities::HUGEPOWER],
+            PokemonName::ARON => &[Abilities::STURDY, Abilities::ROCKHEAD],
+            PokemonName::LAIRON => &[Abilities::STURDY, Abilities::ROCKHEAD],
+            PokemonName::AGGRON => &[Abilities::STURDY, Abilities::ROCKHEAD],
+            PokemonName::AGGRONMEGA => &[Abilities::FILTER],
+            PokemonName::MEDITITE => &[Abilities::PUREPOWER],
+            PokemonName::MEDICHAM => &[Abilities::PUREPOWER],
+            PokemonName::MEDICHAMMEGA => &[Abilities::PUREPOWER],
+            PokemonName::ELECTRIKE => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::MANECTRIC => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::MANECTRICMEGA => &[Abilities::INTIMIDATE],
+            PokemonName::PLUSLE => &[Abilities::PLUS],
+            PokemonName::MINUN => &[Abilities::MINUS],
+            PokemonName::VOLBEAT => &[Abilities::ILLUMINATE, Abilities::SWARM],
+            PokemonName::ILLUMISE => &[Abilities::OBLIVIOUS, Abilities::TINTEDLENS],
+            PokemonName::ROSELIA => &[Abilities::NATURALCURE, Abilities::POISONPOINT],
+            PokemonName::GULPIN => &[Abilities::LIQUIDOOZE, Abilities::STICKYHOLD],
+            PokemonName::SWALOT => &[Abilities::LIQUIDOOZE, Abilities::STICKYHOLD],
+            PokemonName::CARVANHA => &[Abilities::ROUGHSKIN],
+            PokemonName::SHARPEDO => &[Abilities::ROUGHSKIN],
+            PokemonName::SHARPEDOMEGA => &[Abilities::STRONGJAW],
+            PokemonName::WAILMER => &[Abilities::WATERVEIL, Abilities::OBLIVIOUS],
+            PokemonName::WAILORD => &[Abilities::WATERVEIL, Abilities::OBLIVIOUS],
+            PokemonName::NUMEL => &[Abilities::OBLIVIOUS, Abilities::SIMPLE],
+            PokemonName::CAMERUPT => &[Abilities::MAGMAARMOR, Abilities::SOLIDROCK],
+            PokemonName::CAMERUPTMEGA => &[Abilities::SHEERFORCE],
+            PokemonName::TORKOAL => &[Abilities::WHITESMOKE, Abilities::DROUGHT],
+            PokemonName::SPOINK => &[Abilities::THICKFAT, Abilities::OWNTEMPO],
+            PokemonName::GRUMPIG => &[Abilities::THICKFAT, Abilities::OWNTEMPO],
+            PokemonName::SPINDA => &[Abilities::OWNTEMPO, Abilities::TANGLEDFEET],
+            PokemonName::TRAPINCH => &[Abilities::HYPERCUTTER, Abilities::ARENATRAP],
+            PokemonName::VIBRAVA => &[Abilities::LEVITATE],
+            PokemonName::FLYGON => &[Abilities::LEVITATE],
+            PokemonName::CACNEA => &[Abilities::SANDVEIL],
+            PokemonName::CACTURNE => &[Abilities::SANDVEIL],
+            PokemonName::SWABLU => &[Abilities::NATURALCURE],
+            PokemonName::ALTARIA => &[Abilities::NATURALCURE],
+            PokemonName::ALTARIAMEGA => &[Abilities::PIXILATE],
+            PokemonName::ZANGOOSE => &[Abilities::IMMUNITY],
+            PokemonName::SEVIPER => &[Abilities::SHEDSKIN],
+            PokemonName::LUNATONE => &[Abilities::LEVITATE],
+            PokemonName::SOLROCK => &[Abilities::LEVITATE],
+            PokemonName::BARBOACH => &[Abilities::OBLIVIOUS, Abilities::ANTICIPATION],
+            PokemonName::WHISCASH => &[Abilities::OBLIVIOUS, Abilities::ANTICIPATION],
+            PokemonName::CORPHISH => &[Abilities::HYPERCUTTER, Abilities::SHELLARMOR],
+            PokemonName::CRAWDAUNT => &[Abilities::HYPERCUTTER, Abilities::SHELLARMOR],
+            PokemonName::BALTOY => &[Abilities::LEVITATE],
+            PokemonName::CLAYDOL => &[Abilities::LEVITATE],
+            PokemonName::LILEEP => &[Abilities::SUCTIONCUPS],
+            PokemonName::CRADILY => &[Abilities::SUCTIONCUPS],
+            PokemonName::ANORITH => &[Abilities::BATTLEARMOR],
+            PokemonName::ARMALDO => &[Abilities::BATTLEARMOR],
+            PokemonName::FEEBAS => &[Abilities::SWIFTSWIM, Abilities::OBLIVIOUS],
+            PokemonName::MILOTIC => &[Abilities::MARVELSCALE, Abilities::COMPETITIVE],
+            PokemonName::CASTFORM => &[Abilities::FORECAST],
+            PokemonName::CASTFORMSUNNY => &[Abilities::FORECAST],
+            PokemonName::CASTFORMRAINY => &[Abilities::FORECAST],
+            PokemonName::CASTFORMSNOWY => &[Abilities::FORECAST],
+            PokemonName::KECLEON => &[Abilities::COLORCHANGE],
+            PokemonName::SHUPPET => &[Abilities::INSOMNIA, Abilities::FRISK],
+            PokemonName::BANETTE => &[Abilities::INSOMNIA, Abilities::FRISK],
+            PokemonName::BANETTEMEGA => &[Abilities::PRANKSTER],
+            PokemonName::DUSKULL => &[Abilities::LEVITATE],
+            PokemonName::DUSCLOPS => &[Abilities::PRESSURE],
+            PokemonName::TROPIUS => &[Abilities::CHLOROPHYLL, Abilities::SOLARPOWER],
+            PokemonName::CHIMECHO => &[Abilities::LEVITATE],
+            PokemonName::ABSOL => &[Abilities::PRESSURE, Abilities::SUPERLUCK],
+            PokemonName::ABSOLMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::WYNAUT => &[Abilities::SHADOWTAG],
+            PokemonName::SNORUNT => &[Abilities::INNERFOCUS, Abilities::ICEBODY],
+            PokemonName::GLALIE => &[Abilities::INNERFOCUS, Abilities::ICEBODY],
+            PokemonName::GLALIEMEGA => &[Abilities::REFRIGERATE],
+            PokemonName::SPHEAL => &[Abilities::THICKFAT, Abilities::ICEBODY],
+            PokemonName::SEALEO => &[Abilities::THICKFAT, Abilities::ICEBODY],
+            PokemonName::WALREIN => &[Abilities::THICKFAT, Abilities::ICEBODY],
+            PokemonName::CLAMPERL => &[Abilities::SHELLARMOR],
+            PokemonName::HUNTAIL => &[Abilities::SWIFTSWIM],
+            PokemonName::GOREBYSS => &[Abilities::SWIFTSWIM],
+            PokemonName::RELICANTH => &[Abilities::SWIFTSWIM, Abilities::ROCKHEAD],
+            PokemonName::LUVDISC => &[Abilities::SWIFTSWIM],
+            PokemonName::BAGON => &[Abilities::ROCKHEAD],
+            PokemonName::SHELGON => &[Abilities::ROCKHEAD],
+            PokemonName::SALAMENCE => &[Abilities::INTIMIDATE],
+            PokemonName::SALAMENCEMEGA => &[Abilities::AERILATE],
+            PokemonName::BELDUM => &[Abilities::CLEARBODY],
+            PokemonName::METANG => &[Abilities::CLEARBODY],
+            PokemonName::METAGROSS => &[Abilities::CLEARBODY],
+            PokemonName::METAGROSSMEGA => &[Abilities::TOUGHCLAWS],
+            PokemonName::REGIROCK => &[Abilities::CLEARBODY],
+            PokemonName::REGICE => &[Abilities::CLEARBODY],
+            PokemonName::REGISTEEL => &[Abilities::CLEARBODY],
+            PokemonName::LATIAS => &[Abilities::LEVITATE],
+            PokemonName::LATIASMEGA => &[Abilities::LEVITATE],
+            PokemonName::LATIOS => &[Abilities::LEVITATE],
+            PokemonName::LATIOSMEGA => &[Abilities::LEVITATE],
+            PokemonName::KYOGRE => &[Abilities::DRIZZLE],
+            PokemonName::KYOGREPRIMAL => &[Abilities::PRIMORDIALSEA],
+            PokemonName::GROUDON => &[Abilities::DROUGHT],
+            PokemonName::GROUDONPRIMAL => &[Abilities::DESOLATELAND],
+            PokemonName::RAYQUAZA => &[Abilities::AIRLOCK],
+            PokemonName::RAYQUAZAMEGA => &[Abilities::DELTASTREAM],
+            PokemonName::JIRACHI => &[Abilities::SERENEGRACE],
+            PokemonName::DEOXYS => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSATTACK => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSDEFENSE => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSSPEED => &[Abilities::PRESSURE],
+            PokemonName::TURTWIG => &[Abilities::OVERGROW],
+            PokemonName::GROTLE => &[Abilities::OVERGROW],
+            PokemonName::TORTERRA => &[Abilities::OVERGROW],
+            PokemonName::CHIMCHAR => &[Abilities::BLAZE],
+            PokemonName::MONFERNO => &[Abilities::BLAZE],
+            PokemonName::INFERNAPE => &[Abilities::BLAZE],
+            PokemonName::PIPLUP => &[Abilities::TORRENT],
+            PokemonName::PRINPLUP => &[Abilities::TORRENT],
+            PokemonName::EMPOLEON => &[Abilities::TORRENT],
+            PokemonName::STARLY => &[Abilities::KEENEYE],
+            PokemonName::STARAVIA => &[Abilities::INTIMIDATE],
+            PokemonName::STARAPTOR => &[Abilities::INTIMIDATE],
+            PokemonName::BIDOOF => &[Abilities::SIMPLE, Abilities::UNAWARE],
+            PokemonName::BIBAREL => &[Abilities::SIMPLE, Abilities::UNAWARE],
+            PokemonName::KRICKETOT => &[Abilities::SHEDSKIN],
+            PokemonName::KRICKETUNE => &[Abilities::SWARM],
+            PokemonName::SHINX => &[Abilities::RIVALRY, Abilities::INTIMIDATE],
+            PokemonName::LUXIO => &[Abilities::RIVALRY, Abilities::INTIMIDATE],
+            PokemonName::LUXRAY => &[Abilities::RIVALRY, Abilities::INTIMIDATE],
+            PokemonName::BUDEW => &[Abilities::NATURALCURE, Abilities::POISONPOINT],
+            PokemonName::ROSERADE => &[Abilities::NATURALCURE, Abilities::POISONPOINT],
+            PokemonName::CRANIDOS => &[Abilities::MOLDBREAKER],
+            PokemonName::RAMPARDOS => &[Abilities::MOLDBREAKER],
+            PokemonName::SHIELDON => &[Abilities::STURDY],
+            PokemonName::BASTIODON => &[Abilities::STURDY],
+            PokemonName::BURMY => &[Abilities::SHEDSKIN],
+            PokemonName::BURMYSANDY => &[Abilities::SHEDSKIN],
+            PokemonName::BURMYTRASH => &[Abilities::SHEDSKIN],
+            PokemonName::WORMADAM => &[Abilities::ANTICIPATION],
+            PokemonName::WORMADAMSANDY => &[Abilities::ANTICIPATION],
+            PokemonName::WORMADAMTRASH => &[Abilities::ANTICIPATION],
+            PokemonName::MOTHIM => &[Abilities::SWARM],
+            PokemonName::COMBEE => &[Abilities::HONEYGATHER],
+            PokemonName::VESPIQUEN => &[Abilities::PRESSURE],
+            PokemonName::PACHIRISU => &[Abilities::RUNAWAY, Abilities::PICKUP],
+            PokemonName::BUIZEL => &[Abilities::SWIFTSWIM],
+            PokemonName::FLOATZEL => &[Abilities::SWIFTSWIM],
+            PokemonName::CHERUBI => &[Abilities::CHLOROPHYLL],
+            PokemonName::CHERRIM => &[Abilities::FLOWERGIFT],
+            PokemonName::CHERRIMSUNSHINE => &[Abilities::FLOWERGIFT],
+            PokemonName::SHELLOS => &[Abilities::STICKYHOLD, Abilities::STORMDRAIN],
+            PokemonName::SHELLOSEAST => &[Abilities::STICKYHOLD, Abilities::STORMDRAIN],
+            PokemonName::GASTRODON => &[Abilities::STICKYHOLD, Abilities::STORMDRAIN],
+            PokemonName::GASTRODONEAST => &[Abilities::STICKYHOLD, Abilities::STORMDRAIN],
+            PokemonName::AMBIPOM => &[Abilities::TECHNICIAN, Abilities::PICKUP],
+            PokemonName::DRIFLOON => &[Abilities::AFTERMATH, Abilities::UNBURDEN],
+            PokemonName::DRIFBLIM => &[Abilities::AFTERMATH, Abilities::UNBURDEN],
+            PokemonName::BUNEARY => &[Abilities::RUNAWAY, Abilities::KLUTZ],
+            PokemonName::LOPUNNY => &[Abilities::CUTECHARM, Abilities::KLUTZ],
+            PokemonName::LOPUNNYMEGA => &[Abilities::SCRAPPY],
+            PokemonName::MISMAGIUS => &[Abilities::LEVITATE],
+            PokemonName::HONCHKROW => &[Abilities::INSOMNIA, Abilities::SUPERLUCK],
+            PokemonName::GLAMEOW => &[Abilities::LIMBER, Abilities::OWNTEMPO],
+            PokemonName::PURUGLY => &[Abilities::THICKFAT, Abilities::OWNTEMPO],
+            PokemonName::CHINGLING => &[Abilities::LEVITATE],
+            PokemonName::STUNKY => &[Abilities::STENCH, Abilities::AFTERMATH],
+            PokemonName::SKUNTANK => &[Abilities::STENCH, Abilities::AFTERMATH],
+            PokemonName::BRONZOR => &[Abilities::LEVITATE, Abilities::HEATPROOF],
+            PokemonName::BRONZONG => &[Abilities::LEVITATE, Abilities::HEATPROOF],
+            PokemonName::BONSLY => &[Abilities::STURDY, Abilities::ROCKHEAD],
+            PokemonName::MIMEJR => &[Abilities::SOUNDPROOF, Abilities::FILTER],
+            PokemonName::HAPPINY => &[Abilities::NATURALCURE, Abilities::SERENEGRACE],
+            PokemonName::CHATOT => &[Abilities::KEENEYE, Abilities::TANGLEDFEET],
+            PokemonName::SPIRITOMB => &[Abilities::PRESSURE],
+            PokemonName::GIBLE => &[Abilities::SANDVEIL],
+            PokemonName::GABITE => &[Abilities::SANDVEIL],
+            PokemonName::GARCHOMP => &[Abilities::SANDVEIL],
+            PokemonName::GARCHOMPMEGA => &[Abilities::SANDFORCE],
+            PokemonName::MUNCHLAX => &[Abilities::PICKUP, Abilities::THICKFAT],
+            PokemonName::RIOLU => &[Abilities::STEADFAST, Abilities::INNERFOCUS],
+            PokemonName::LUCARIO => &[Abilities::STEADFAST, Abilities::INNERFOCUS],
+            PokemonName::LUCARIOMEGA => &[Abilities::ADAPTABILITY],
+            PokemonName::HIPPOPOTAS => &[Abilities::SANDSTREAM],
+            PokemonName::HIPPOWDON => &[Abilities::SANDSTREAM],
+            PokemonName::SKORUPI => &[Abilities::BATTLEARMOR, Abilities::SNIPER],
+            PokemonName::DRAPION => &[Abilities::BATTLEARMOR, Abilities::SNIPER],
+            PokemonName::CROAGUNK => &[Abilities::ANTICIPATION, Abilities::DRYSKIN],
+            PokemonName::TOXICROAK => &[Abilities::ANTICIPATION, Abilities::DRYSKIN],
+            PokemonName::CARNIVINE => &[Abilities::LEVITATE],
+            PokemonName::FINNEON => &[Abilities::SWIFTSWIM, Abilities::STORMDRAIN],
+            PokemonName::LUMINEON => &[Abilities::SWIFTSWIM, Abilities::STORMDRAIN],
+            PokemonName::MANTYKE => &[Abilities::SWIFTSWIM, Abilities::WATERABSORB],
+            PokemonName::SNOVER => &[Abilities::SNOWWARNING],
+            PokemonName::ABOMASNOW => &[Abilities::SNOWWARNING],
+            PokemonName::ABOMASNOWMEGA => &[Abilities::SNOWWARNING],
+            PokemonName::WEAVILE => &[Abilities::PRESSURE],
+            PokemonName::MAGNEZONE => &[Abilities::MAGNETPULL, Abilities::STURDY],
+            PokemonName::LICKILICKY => &[Abilities::OWNTEMPO, Abilities::OBLIVIOUS],
+            PokemonName::RHYPERIOR => &[Abilities::LIGHTNINGROD, Abilities::SOLIDROCK],
+            PokemonName::TANGROWTH => &[Abilities::CHLOROPHYLL, Abilities::LEAFGUARD],
+            PokemonName::ELECTIVIRE => &[Abilities::MOTORDRIVE],
+            PokemonName::MAGMORTAR => &[Abilities::FLAMEBODY],
+            PokemonName::TOGEKISS => &[Abilities::HUSTLE, Abilities::SERENEGRACE],
+            PokemonName::YANMEGA => &[Abilities::SPEEDBOOST, Abilities::TINTEDLENS],
+            PokemonName::LEAFEON => &[Abilities::LEAFGUARD],
+            PokemonName::GLACEON => &[Abilities::SNOWCLOAK],
+            PokemonName::GLISCOR => &[Abilities::HYPERCUTTER, Abilities::SANDVEIL],
+            PokemonName::MAMOSWINE => &[Abilities::OBLIVIOUS, Abilities::SNOWCLOAK],
+            PokemonName::PORYGONZ => &[Abilities::ADAPTABILITY, Abilities::DOWNLOAD],
+            PokemonName::GALLADE => &[Abilities::STEADFAST, Abilities::SHARPNESS],
+            PokemonName::GALLADEMEGA => &[Abilities::INNERFOCUS],
+            PokemonName::PROBOPASS => &[Abilities::STURDY, Abilities::MAGNETPULL],
+            PokemonName::DUSKNOIR => &[Abilities::PRESSURE],
+            PokemonName::FROSLASS => &[Abilities::SNOWCLOAK],
+            PokemonName::ROTOM => &[Abilities::LEVITATE],
+            PokemonName::ROTOMHEAT => &[Abilities::LEVITATE],
+            PokemonName::ROTOMWASH => &[Abilities::LEVITATE],
+            PokemonName::ROTOMFROST => &[Abilities::LEVITATE],
+            PokemonName::ROTOMFAN => &[Abilities::LEVITATE],
+            PokemonName::ROTOMMOW => &[Abilities::LEVITATE],
+            PokemonName::UXIE => &[Abilities::LEVITATE],
+            PokemonName::MESPRIT => &[Abilities::LEVITATE],
+            PokemonName::AZELF => &[Abilities::LEVITATE],
+            PokemonName::DIALGA => &[Abilities::PRESSURE],
+            PokemonName::DIALGAORIGIN => &[Abilities::PRESSURE],
+            PokemonName::PALKIA => &[Abilities::PRESSURE],
+            PokemonName::PALKIAORIGIN => &[Abilities::PRESSURE],
+            PokemonName::HEATRAN => &[Abilities::FLASHFIRE],
+            PokemonName::REGIGIGAS => &[Abilities::SLOWSTART],
+            PokemonName::GIRATINA => &[Abilities::PRESSURE],
+            PokemonName::GIRATINAORIGIN => &[Abilities::LEVITATE],
+            PokemonName::CRESSELIA => &[Abilities::LEVITATE],
+            PokemonName::PHIONE => &[Abilities::HYDRATION],
+            PokemonName::MANAPHY => &[Abilities::HYDRATION],
+            PokemonName::DARKRAI => &[Abilities::BADDREAMS],
+            PokemonName::SHAYMIN => &[Abilities::NATURALCURE],
+            PokemonName::SHAYMINSKY => &[Abilities::SERENEGRACE],
+            PokemonName::ARCEUS => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSBUG => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSDARK => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSDRAGON => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSELECTRIC => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFAIRY => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFIGHTING => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFIRE => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFLYING => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGHOST => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGRASS => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGROUND => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSICE => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSPOISON => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSPSYCHIC => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSROCK => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSSTEEL => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSWATER => &[Abilities::MULTITYPE],
+            PokemonName::VICTINI => &[Abilities::VICTORYSTAR],
+            PokemonName::SNIVY => &[Abilities::OVERGROW],
+            PokemonName::SERVINE => &[Abilities::OVERGROW],
+            PokemonName::SERPERIOR => &[Abilities::OVERGROW],
+            PokemonName::TEPIG => &[Abilities::BLAZE],
+            PokemonName::PIGNITE => &[Abilities::BLAZE],
+            PokemonName::EMBOAR => &[Abilities::BLAZE],
+            PokemonName::OSHAWOTT => &[Abilities::TORRENT],
+            PokemonName::DEWOTT => &[Abilities::TORRENT],
+            PokemonName::SAMUROTT => &[Abilities::TORRENT],
+            PokemonName::SAMUROTTHISUI => &[Abilities::TORRENT],
+            PokemonName::PATRAT => &[Abilities::RUNAWAY, Abilities::KEENEYE],
+            PokemonName::WATCHOG => &[Abilities::ILLUMINATE, Abilities::KEENEYE],
+            PokemonName::LILLIPUP => &[Abilities::VITALSPIRIT, Abilities::PICKUP],
+            PokemonName::HERDIER => &[Abilities::INTIMIDATE, Abilities::SANDRUSH],
+            PokemonName::STOUTLAND => &[Abilities::INTIMIDATE, Abilities::SANDRUSH],
+            PokemonName::PURRLOIN => &[Abilities::LIMBER, Abilities::UNBURDEN],
+            PokemonName::LIEPARD => &[Abilities::LIMBER, Abilities::UNBURDEN],
+            PokemonName::PANSAGE => &[Abilities::GLUTTONY],
+            PokemonName::SIMISAGE => &[Abilities::GLUTTONY],
+            PokemonName::PANSEAR => &[Abilities::GLUTTONY],
+            PokemonName::SIMISEAR => &[Abilities::GLUTTONY],
+            PokemonName::PANPOUR => &[Abilities::GLUTTONY],
+            PokemonName::SIMIPOUR => &[Abilities::GLUTTONY],
+            PokemonName::MUNNA => &[Abilities::FOREWARN, Abilities::SYNCHRONIZE],
+            PokemonName::MUSHARNA => &[Abilities::FOREWARN, Abilities::SYNCHRONIZE],
+            PokemonName::PIDOVE => &[Abilities::BIGPECKS, Abilities::SUPERLUCK],
+            PokemonName::TRANQUILL => &[Abilities::BIGPECKS, Abilities::SUPERLUCK],
+            PokemonName::UNFEZANT => &[Abilities::BIGPECKS, Abilities::SUPERLUCK],
+            PokemonName::BLITZLE => &[Abilities::LIGHTNINGROD, Abilities::MOTORDRIVE],
+            PokemonName::ZEBSTRIKA => &[Abilities::LIGHTNINGROD, Abilities::MOTORDRIVE],
+            PokemonName::ROGGENROLA => &[Abilities::STURDY, Abilities::WEAKARMOR],
+            PokemonName::BOLDORE => &[Abilities::STURDY, Abilities::WEAKARMOR],
+            PokemonName::GIGALITH => &[Abilities::STURDY, Abilities::SANDSTREAM],
+            PokemonName::WOOBAT => &[Abilities::UNAWARE, Abilities::KLUTZ],
+            PokemonName::SWOOBAT => &[Abilities::UNAWARE, Abilities::KLUTZ],
+            PokemonName::DRILBUR => &[Abilities::SANDRUSH, Abilities::SANDFORCE],
+            PokemonName::EXCADRILL => &[Abilities::SANDRUSH, Abilities::SANDFORCE],
+            PokemonName::AUDINO => &[Abilities::HEALER, Abilities::REGENERATOR],
+            PokemonName::AUDINOMEGA => &[Abilities::HEALER],
+            PokemonName::TIMBURR => &[Abilities::GUTS, Abilities::SHEERFORCE],
+            PokemonName::GURDURR => &[Abilities::GUTS, Abilities::SHEERFORCE],
+            PokemonName::CONKELDURR => &[Abilities::GUTS, Abilities::SHEERFORCE],
+            PokemonName::TYMPOLE => &[Abilities::SWIFTSWIM, Abilities::HYDRATION],
+            PokemonName::PALPITOAD => &[Abilities::SWIFTSWIM, Abilities::HYDRATION],
+            PokemonName::SEISMITOAD => &[Abilities::SWIFTSWIM, Abilities::POISONTOUCH],
+            PokemonName::THROH => &[Abilities::GUTS, Abilities::INNERFOCUS],
+            PokemonName::SAWK => &[Abilities::STURDY, Abilities::INNERFOCUS],
+            PokemonName::SEWADDLE => &[Abilities::SWARM, Abilities::CHLOROPHYLL],
+            PokemonName::SWADLOON => &[Abilities::LEAFGUARD, Abilities::CHLOROPHYLL],
+            PokemonName::LEAVANNY => &[Abilities::SWARM, Abilities::CHLOROPHYLL],
+            PokemonName::VENIPEDE => &[Abilities::POISONPOINT, Abilities::SWARM],
+            PokemonName::WHIRLIPEDE => &[Abilities::POISONPOINT, Abilities::SWARM],
+            PokemonName::SCOLIPEDE => &[Abilities::POISONPOINT, Abilities::SWARM],
+            PokemonName::COTTONEE => &[Abilities::PRANKSTER, Abilities::INFILTRATOR],
+            PokemonName::WHIMSICOTT => &[Abilities::PRANKSTER, Abilities::INFILTRATOR],
+            PokemonName::PETILIL => &[Abilities::CHLOROPHYLL, Abilities::OWNTEMPO],
+            PokemonName::LILLIGANT => &[Abilities::CHLOROPHYLL, Abilities::OWNTEMPO],
+            PokemonName::LILLIGANTHISUI => &[Abilities::CHLOROPHYLL, Abilities::HUSTLE],
+            PokemonName::BASCULIN => &[Abilities::RECKLESS, Abilities::ADAPTABILITY],
+            PokemonName::BASCULINBLUESTRIPED => &[Abilities::ROCKHEAD, Abilities::ADAPTABILITY],
+            PokemonName::BASCULINWHITESTRIPED => &[Abilities::RATTLED, Abilities::ADAPTABILITY],
+            PokemonName::SANDILE => &[Abilities::INTIMIDATE, Abilities::MOXIE],
+            PokemonName::KROKOROK => &[Abilities::INTIMIDATE, Abilities::MOXIE],
+            PokemonName::KROOKODILE => &[Abilities::INTIMIDATE, Abilities::MOXIE],
+            PokemonName::DARUMAKA => &[Abilities::HUSTLE],
+            PokemonName::DARUMAKAGALAR => &[Abilities::HUSTLE],
+            PokemonName::DARMANITAN => &[Abilities::SHEERFORCE],
+            PokemonName::DARMANITANZEN => &[Abilities::ZENMODE],
+            PokemonName::DARMANITANGALAR => &[Abilities::GORILLATACTICS],
+            PokemonName::DARMANITANGALARZEN => &[Abilities::ZENMODE],
+            PokemonName::MARACTUS => &[Abilities::WATERABSORB, Abilities::CHLOROPHYLL],
+            PokemonName::DWEBBLE => &[Abilities::STURDY, Abilities::SHELLARMOR],
+            PokemonName::CRUSTLE => &[Abilities::STURDY, Abilities::SHELLARMOR],
+            PokemonName::SCRAGGY => &[Abilities::SHEDSKIN, Abilities::MOXIE],
+            PokemonName::SCRAFTY => &[Abilities::SHEDSKIN, Abilities::MOXIE],
+            PokemonName::SIGILYPH => &[Abilities::WONDERSKIN, Abilities::MAGICGUARD],
+            PokemonName::YAMASK => &[Abilities::MUMMY],
+            PokemonName::YAMASKGALAR => &[Abilities::WANDERINGSPIRIT],
+            PokemonName::COFAGRIGUS => &[Abilities::MUMMY],
+            PokemonName::TIRTOUGA => &[Abilities::SOLIDROCK, Abilities::STURDY],
+            PokemonName::CARRACOSTA => &[Abilities::SOLIDROCK, Abilities::STURDY],
+            PokemonName::ARCHEN => &[Abilities::DEFEATIST],
+            PokemonName::ARCHEOPS => &[Abilities::DEFEATIST],
+            PokemonName::TRUBBISH => &[Abilities::STENCH, Abilities::STICKYHOLD],
+            PokemonName::GARBODOR => &[Abilities::STENCH, Abilities::WEAKARMOR],
+            PokemonName::GARBODORGMAX => &[Abilities::STENCH, Abilities::WEAKARMOR],
+            PokemonName::ZORUA => &[Abilities::ILLUSION],
+            PokemonName::ZORUAHISUI => &[Abilities::ILLUSION],
+            PokemonName::ZOROARK => &[Abilities::ILLUSION],
+            PokemonName::ZOROARKHISUI => &[Abilities::ILLUSION],
+            PokemonName::MINCCINO => &[Abilities::CUTECHARM, Abilities::TECHNICIAN],
+            PokemonName::CINCCINO => &[Abilities::CUTECHARM, Abilities::TECHNICIAN],
+            PokemonName::GOTHITA => &[Abilities::FRISK, Abilities::COMPETITIVE],
+            PokemonName::GOTHORITA => &[Abilities::FRISK, Abilities::COMPETITIVE],
+            PokemonName::GOTHITELLE => &[Abilities::FRISK, Abilities::COMPETITIVE],
+            PokemonName::SOLOSIS => &[Abilities::OVERCOAT, Abilities::MAGICGUARD],
+            PokemonName::DUOSION => &[Abilities::OVERCOAT, Abilities::MAGICGUARD],
+            PokemonName::REUNICLUS => &[Abilities::OVERCOAT, Abilities::MAGICGUARD],
+            PokemonName::DUCKLETT => &[Abilities::KEENEYE, Abilities::BIGPECKS],
+            PokemonName::SWANNA => &[Abilities::KEENEYE, Abilities::BIGPECKS],
+            PokemonName::VANILLITE => &[Abilities::ICEBODY, Abilities::SNOWCLOAK],
+            PokemonName::VANILLISH => &[Abilities::ICEBODY, Abilities::SNOWCLOAK],
+            PokemonName::VANILLUXE => &[Abilities::ICEBODY, Abilities::SNOWWARNING],
+            PokemonName::DEERLING => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::DEERLINGSUMMER => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::DEERLINGAUTUMN => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::DEERLINGWINTER => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::SAWSBUCK => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::SAWSBUCKSUMMER => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::SAWSBUCKAUTUMN => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::SAWSBUCKWINTER => &[Abilities::CHLOROPHYLL, Abilities::SAPSIPPER],
+            PokemonName::EMOLGA => &[Abilities::STATIC],
+            PokemonName::KARRABLAST => &[Abilities::SWARM, Abilities::SHEDSKIN],
+            PokemonName::ESCAVALIER => &[Abilities::SWARM, Abilities::SHELLARMOR],
+            PokemonName::FOONGUS => &[Abilities::EFFECTSPORE],
+            PokemonName::AMOONGUSS => &[Abilities::EFFECTSPORE],
+            PokemonName::FRILLISH => &[Abilities::WATERABSORB, Abilities::CURSEDBODY],
+            PokemonName::JELLICENT => &[Abilities::WATERABSORB, Abilities::CURSEDBODY],
+            PokemonName::ALOMOMOLA => &[Abilities::HEALER, Abilities::HYDRATION],
+            PokemonName::JOLTIK => &[Abilities::COMPOUNDEYES, Abilities::UNNERVE],
+            PokemonName::GALVANTULA => &[Abilities::COMPOUNDEYES, Abilities::UNNERVE],
+            PokemonName::FERROSEED => &[Abilities::IRONBARBS],
+            PokemonName::FERROTHORN => &[Abilities::IRONBARBS],
+            PokemonName::KLINK => &[Abilities::PLUS, Abilities::MINUS],
+            PokemonName::KLANG => &[Abilities::PLUS, Abilities::MINUS],
+            PokemonName::KLINKLANG => &[Abilities::PLUS, Abilities::MINUS],
+            PokemonName::TYNAMO => &[Abilities::LEVITATE],
+            PokemonName::EELEKTRIK => &[Abilities::LEVITATE],
+            PokemonName::EELEKTROSS => &[Abilities::LEVITATE],
+            PokemonName::ELGYEM => &[Abilities::TELEPATHY, Abilities::SYNCHRONIZE],
+            PokemonName::BEHEEYEM => &[Abilities::TELEPATHY, Abilities::SYNCHRONIZE],
+            PokemonName::LITWICK => &[Abilities::FLASHFIRE, Abilities::FLAMEBODY],
+            PokemonName::LAMPENT => &[Abilities::FLASHFIRE, Abilities::FLAMEBODY],
+            PokemonName::CHANDELURE => &[Abilities::FLASHFIRE, Abilities::FLAMEBODY],
+            PokemonName::AXEW => &[Abilities::RIVALRY, Abilities::MOLDBREAKER],
+            PokemonName::FRAXURE => &[Abilities::RIVALRY, Abilities::MOLDBREAKER],
+            PokemonName::HAXORUS => &[Abilities::RIVALRY, Abilities::MOLDBREAKER],
+            PokemonName::CUBCHOO => &[Abilities::SNOWCLOAK, Abilities::SLUSHRUSH],
+            PokemonName::BEARTIC => &[Abilities::SNOWCLOAK, Abilities::SLUSHRUSH],
+            PokemonName::CRYOGONAL => &[Abilities::LEVITATE],
+            PokemonName::SHELMET => &[Abilities::HYDRATION, Abilities::SHELLARMOR],
+            PokemonName::ACCELGOR => &[Abilities::HYDRATION, Abilities::STICKYHOLD],
+            PokemonName::STUNFISK => &[Abilities::STATIC, Abilities::LIMBER],
+            PokemonName::STUNFISKGALAR => &[Abilities::MIMICRY],
+            PokemonName::MIENFOO => &[Abilities::INNERFOCUS, Abilities::REGENERATOR],
+            PokemonName::MIENSHAO => &[Abilities::INNERFOCUS, Abilities::REGENERATOR],
+            PokemonName::DRUDDIGON => &[Abilities::ROUGHSKIN, Abilities::SHEERFORCE],
+            PokemonName::GOLETT => &[Abilities::IRONFIST, Abilities::KLUTZ],
+            PokemonName::GOLURK => &[Abilities::IRONFIST, Abilities::KLUTZ],
+            PokemonName::PAWNIARD => &[Abilities::DEFIANT, Abilities::INNERFOCUS],
+            PokemonName::BISHARP => &[Abilities::DEFIANT, Abilities::INNERFOCUS],
+            PokemonName::BOUFFALANT => &[Abilities::RECKLESS, Abilities::SAPSIPPER],
+            PokemonName::RUFFLET => &[Abilities::KEENEYE, Abilities::SHEERFORCE],
+            PokemonName::BRAVIARY => &[Abilities::KEENEYE, Abilities::SHEERFORCE],
+            PokemonName::BRAVIARYHISUI => &[Abilities::KEENEYE, Abilities::SHEERFORCE],
+            PokemonName::VULLABY => &[Abilities::BIGPECKS, Abilities::OVERCOAT],
+            PokemonName::MANDIBUZZ => &[Abilities::BIGPECKS, Abilities::OVERCOAT],
+            PokemonName::HEATMOR => &[Abilities::GLUTTONY, Abilities::FLASHFIRE],
+            PokemonName::DURANT => &[Abilities::SWARM, Abilities::HUSTLE],
+            PokemonName::DEINO => &[Abilities::HUSTLE],
+            PokemonName::ZWEILOUS => &[Abilities::HUSTLE],
+            PokemonName::HYDREIGON => &[Abilities::LEVITATE],
+            PokemonName::LARVESTA => &[Abilities::FLAMEBODY],
+            PokemonName::VOLCARONA => &[Abilities::FLAMEBODY],
+            PokemonName::COBALION => &[Abilities::JUSTIFIED],
+            PokemonName::TERRAKION => &[Abilities::JUSTIFIED],
+            PokemonName::VIRIZION => &[Abilities::JUSTIFIED],
+            PokemonName::TORNADUS => &[Abilities::PRANKSTER],
+            PokemonName::TORNADUSTHERIAN => &[Abilities::REGENERATOR],
+            PokemonName::THUNDURUS => &[Abilities::PRANKSTER],
+            PokemonName::THUNDURUSTHERIAN => &[Abilities::VOLTABSORB],
+            PokemonName::RESHIRAM => &[Abilities::TURBOBLAZE],
+            PokemonName::ZEKROM => &[Abilities::TERAVOLT],
+            PokemonName::LANDORUS => &[Abilities::SANDFORCE],
+            PokemonName::LANDORUSTHERIAN => &[Abilities::INTIMIDATE],
+            PokemonName::KYUREM => &[Abilities::PRESSURE],
+            PokemonName::KYUREMBLACK => &[Abilities::TERAVOLT],
+            PokemonName::KYUREMWHITE => &[Abilities::TURBOBLAZE],
+            PokemonName::KELDEO => &[Abilities::JUSTIFIED],
+            PokemonName::KELDEORESOLUTE => &[Abilities::JUSTIFIED],
+            PokemonName::MELOETTA => &[Abilities::SERENEGRACE],
+            PokemonName::MELOETTAPIROUETTE => &[Abilities::SERENEGRACE],
+            PokemonName::GENESECT => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTDOUSE => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTSHOCK => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTBURN => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTCHILL => &[Abilities::DOWNLOAD],
+            PokemonName::CHESPIN => &[Abilities::OVERGROW],
+            PokemonName::QUILLADIN => &[Abilities::OVERGROW],
+            PokemonName::CHESNAUGHT => &[Abilities::OVERGROW],
+            PokemonName::FENNEKIN => &[Abilities::BLAZE],
+            PokemonName::BRAIXEN => &[Abilities::BLAZE],
+            PokemonName::DELPHOX => &[Abilities::BLAZE],
+            PokemonName::FROAKIE => &[Abilities::TORRENT],
+            PokemonName::FROGADIER => &[Abilities::TORRENT],
+            PokemonName::GRENINJA => &[Abilities::TORRENT],
+            PokemonName::GRENINJABOND => &[Abilities::BATTLEBOND],
+            PokemonName::GRENINJAASH => &[Abilities::BATTLEBOND],
+            PokemonName::BUNNELBY => &[Abilities::PICKUP, Abilities::CHEEKPOUCH],
+            PokemonName::DIGGERSBY => &[Abilities::PICKUP, Abilities::CHEEKPOUCH],
+            PokemonName::FLETCHLING => &[Abilities::BIGPECKS],
+            PokemonName::FLETCHINDER => &[Abilities::FLAMEBODY],
+            PokemonName::TALONFLAME => &[Abilities::FLAMEBODY],
+            PokemonName::SCATTERBUG => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::SPEWPA => &[Abilities::SHEDSKIN],
+            PokemonName::VIVILLON => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONARCHIPELAGO => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONCONTINENTAL => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONELEGANT => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONGARDEN => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONHIGHPLAINS => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONICYSNOW => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONJUNGLE => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONMARINE => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONMODERN => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONMONSOON => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONOCEAN => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONPOLAR => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONRIVER => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONSANDSTORM => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONSAVANNA => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONSUN => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONTUNDRA => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONFANCY => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::VIVILLONPOKEBALL => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::LITLEO => &[Abilities::RIVALRY, Abilities::UNNERVE],
+            PokemonName::PYROAR => &[Abilities::RIVALRY, Abilities::UNNERVE],
+            PokemonName::FLABEBE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLABEBEBLUE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLABEBEORANGE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLABEBEWHITE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLABEBEYELLOW => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTEBLUE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTEORANGE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTEWHITE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTEYELLOW => &[Abilities::FLOWERVEIL],
+            PokemonName::FLOETTEETERNAL => &[Abilities::FLOWERVEIL],
+            PokemonName::FLORGES => &[Abilities::FLOWERVEIL],
+            PokemonName::FLORGESBLUE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLORGESORANGE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLORGESWHITE => &[Abilities::FLOWERVEIL],
+            PokemonName::FLORGESYELLOW => &[Abilities::FLOWERVEIL],
+            PokemonName::SKIDDO => &[Abilities::SAPSIPPER],
+            PokemonName::GOGOAT => &[Abilities::SAPSIPPER],
+            PokemonName::PANCHAM => &[Abilities::IRONFIST, Abilities::MOLDBREAKER],
+            PokemonName::PANGORO => &[Abilities::IRONFIST, Abilities::MOLDBREAKER],
+            PokemonName::FURFROU => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDANDY => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDEBUTANTE => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDIAMOND => &[Abilities::FURCOAT],
+            PokemonName::FURFROUHEART => &[Abilities::FURCOAT],
+            PokemonName::FURFROUKABUKI => &[Abilities::FURCOAT],
+            PokemonName::FURFROULAREINE => &[Abilities::FURCOAT],
+            PokemonName::FURFROUMATRON => &[Abilities::FURCOAT],
+            PokemonName::FURFROUPHARAOH => &[Abilities::FURCOAT],
+            PokemonName::FURFROUSTAR => &[Abilities::FURCOAT],
+            PokemonName::ESPURR => &[Abilities::KEENEYE, Abilities::INFILTRATOR],
+            PokemonName::MEOWSTIC => &[Abilities::KEENEYE, Abilities::INFILTRATOR],
+            PokemonName::MEOWSTICF => &[Abilities::KEENEYE, Abilities::INFILTRATOR],
+            PokemonName::HONEDGE => &[Abilities::NOGUARD],
+            PokemonName::DOUBLADE => &[Abilities::NOGUARD],
+            PokemonName::AEGISLASH => &[Abilities::STANCECHANGE],
+            PokemonName::AEGISLASHBLADE => &[Abilities::STANCECHANGE],
+            PokemonName::SPRITZEE => &[Abilities::HEALER],
+            PokemonName::AROMATISSE => &[Abilities::HEALER],
+            PokemonName::SWIRLIX => &[Abilities::SWEETVEIL],
+            PokemonName::SLURPUFF => &[Abilities::SWEETVEIL],
+            PokemonName::INKAY => &[Abilities::CONTRARY, Abilities::SUCTIONCUPS],
+            PokemonName::MALAMAR => &[Abilities::CONTRARY, Abilities::SUCTIONCUPS],
+            PokemonName::BINACLE => &[Abilities::TOUGHCLAWS, Abilities::SNIPER],
+            PokemonName::BARBARACLE => &[Abilities::TOUGHCLAWS, Abilities::SNIPER],
+            PokemonName::SKRELP => &[Abilities::POISONPOINT, Abilities::POISONTOUCH],
+            PokemonName::DRAGALGE => &[Abilities::POISONPOINT, Abilities::POISONTOUCH],
+            PokemonName::CLAUNCHER => &[Abilities::MEGALAUNCHER],
+            PokemonName::CLAWITZER => &[Abilities::MEGALAUNCHER],
+            PokemonName::HELIOPTILE => &[Abilities::DRYSKIN, Abilities::SANDVEIL],
+            PokemonName::HELIOLISK => &[Abilities::DRYSKIN, Abilities::SANDVEIL],
+            PokemonName::TYRUNT => &[Abilities::STRONGJAW],
+            PokemonName::TYRANTRUM => &[Abilities::STRONGJAW],
+            PokemonName::AMAURA => &[Abilities::REFRIGERATE],
+            PokemonName::AURORUS => &[Abilities::REFRIGERATE],
+            PokemonName::SYLVEON => &[Abilities::CUTECHARM],
+            PokemonName::HAWLUCHA => &[Abilities::LIMBER, Abilities::UNBURDEN],
+            PokemonName::DEDENNE => &[Abilities::CHEEKPOUCH, Abilities::PICKUP],
+            PokemonName::CARBINK => &[Abilities::CLEARBODY],
+            PokemonName::GOOMY => &[Abilities::SAPSIPPER, Abilities::HYDRATION],
+            PokemonName::SLIGGOO => &[Abilities::SAPSIPPER, Abilities::HYDRATION],
+            PokemonName::SLIGGOOHISUI => &[Abilities::SAPSIPPER, Abilities::SHELLARMOR],
+            PokemonName::GOODRA => &[Abilities::SAPSIPPER, Abilities::HYDRATION],
+            PokemonName::GOODRAHISUI => &[Abilities::SAPSIPPER, Abilities::SHELLARMOR],
+            PokemonName::KLEFKI => &[Abilities::PRANKSTER],
+            PokemonName::PHANTUMP => &[Abilities::NATURALCURE, Abilities::FRISK],
+            PokemonName::TREVENANT => &[Abilities::NATURALCURE, Abilities::FRISK],
+            PokemonName::PUMPKABOO => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::PUMPKABOOSMALL => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::PUMPKABOOLARGE => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::PUMPKABOOSUPER => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::GOURGEIST => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::GOURGEISTSMALL => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::GOURGEISTLARGE => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::GOURGEISTSUPER => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::BERGMITE => &[Abilities::OWNTEMPO, Abilities::ICEBODY],
+            PokemonName::AVALUGG => &[Abilities::OWNTEMPO, Abilities::ICEBODY],
+            PokemonName::AVALUGGHISUI => &[Abilities::STRONGJAW, Abilities::ICEBODY],
+            PokemonName::NOIBAT => &[Abilities::FRISK, Abilities::INFILTRATOR],
+            PokemonName::NOIVERN => &[Abilities::FRISK, Abilities::INFILTRATOR],
+            PokemonName::XERNEAS => &[Abilities::FAIRYAURA],
+            PokemonName::XERNEASNEUTRAL => &[Abilities::FAIRYAURA],
+            PokemonName::YVELTAL => &[Abilities::DARKAURA],
+            PokemonName::ZYGARDE => &[Abilities::AURABREAK, Abilities::POWERCONSTRUCT],
+            PokemonName::ZYGARDE10 => &[Abilities::AURABREAK, Abilities::POWERCONSTRUCT],
+            PokemonName::ZYGARDECOMPLETE => &[Abilities::POWERCONSTRUCT],
+            PokemonName::DIANCIE => &[Abilities::CLEARBODY],
+            PokemonName::DIANCIEMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::HOOPA => &[Abilities::MAGICIAN],
+            PokemonName::HOOPAUNBOUND => &[Abilities::MAGICIAN],
+            PokemonName::VOLCANION => &[Abilities::WATERABSORB],
+            PokemonName::ROWLET => &[Abilities::OVERGROW],
+            PokemonName::DARTRIX => &[Abilities::OVERGROW],
+            PokemonName::DECIDUEYE => &[Abilities::OVERGROW],
+            PokemonName::DECIDUEYEHISUI => &[Abilities::OVERGROW],
+            PokemonName::LITTEN => &[Abilities::BLAZE],
+            PokemonName::TORRACAT => &[Abilities::BLAZE],
+            PokemonName::INCINEROAR => &[Abilities::BLAZE],
+            PokemonName::POPPLIO => &[Abilities::TORRENT],
+            PokemonName::BRIONNE => &[Abilities::TORRENT],
+            PokemonName::PRIMARINA => &[Abilities::TORRENT],
+            PokemonName::PIKIPEK => &[Abilities::KEENEYE, Abilities::SKILLLINK],
+            PokemonName::TRUMBEAK => &[Abilities::KEENEYE, Abilities::SKILLLINK],
+            PokemonName::TOUCANNON => &[Abilities::KEENEYE, Abilities::SKILLLINK],
+            PokemonName::YUNGOOS => &[Abilities::STAKEOUT, Abilities::STRONGJAW],
+            PokemonName::GUMSHOOS => &[Abilities::STAKEOUT, Abilities::STRONGJAW],
+            PokemonName::GUMSHOOSTOTEM => &[Abilities::ADAPTABILITY],
+            PokemonName::GRUBBIN => &[Abilities::SWARM],
+            PokemonName::CHARJABUG => &[Abilities::BATTERY],
+            PokemonName::VIKAVOLT => &[Abilities::LEVITATE],
+            PokemonName::VIKAVOLTTOTEM => &[Abilities::LEVITATE],
+            PokemonName::CRABRAWLER => &[Abilities::HYPERCUTTER, Abilities::IRONFIST],
+            PokemonName::CRABOMINABLE => &[Abilities::HYPERCUTTER, Abilities::IRONFIST],
+            PokemonName::ORICORIO => &[Abilities::DANCER],
+            PokemonName::ORICORIOPOMPOM => &[Abilities::DANCER],
+            PokemonName::ORICORIOPAU => &[Abilities::DANCER],
+            PokemonName::ORICORIOSENSU => &[Abilities::DANCER],
+            PokemonName::CUTIEFLY => &[Abilities::HONEYGATHER, Abilities::SHIELDDUST],
+            PokemonName::RIBOMBEE => &[Abilities::HONEYGATHER, Abilities::SHIELDDUST],
+            PokemonName::RIBOMBEETOTEM => &[Abilities::SWEETVEIL],
+            PokemonName::ROCKRUFF => &[Abilities::KEENEYE, Abilities::VITALSPIRIT],
+            PokemonName::LYCANROC => &[Abilities::KEENEYE, Abilities::SANDRUSH],
+            PokemonName::LYCANROCMIDNIGHT => &[Abilities::KEENEYE, Abilities::VITALSPIRIT],
+            PokemonName::LYCANROCDUSK => &[Abilities::TOUGHCLAWS],
+            PokemonName::WISHIWASHI => &[Abilities::SCHOOLING],
+            PokemonName::WISHIWASHISCHOOL => &[Abilities::SCHOOLING],
+            PokemonName::MAREANIE => &[Abilities::MERCILESS, Abilities::LIMBER],
+            PokemonName::TOXAPEX => &[Abilities::MERCILESS, Abilities::LIMBER],
+            PokemonName::MUDBRAY => &[Abilities::OWNTEMPO, Abilities::STAMINA],
+            PokemonName::MUDSDALE => &[Abilities::OWNTEMPO, Abilities::STAMINA],
+            PokemonName::DEWPIDER => &[Abilities::WATERBUBBLE],
+            PokemonName::ARAQUANID => &[Abilities::WATERBUBBLE],
+            PokemonName::ARAQUANIDTOTEM => &[Abilities::WATERBUBBLE],
+            PokemonName::FOMANTIS => &[Abilities::LEAFGUARD],
+            PokemonName::LURANTIS => &[Abilities::LEAFGUARD],
+            PokemonName::LURANTISTOTEM => &[Abilities::LEAFGUARD],
+            PokemonName::MORELULL => &[Abilities::ILLUMINATE, Abilities::EFFECTSPORE],
+            PokemonName::SHIINOTIC => &[Abilities::ILLUMINATE, Abilities::EFFECTSPORE],
+            PokemonName::SALANDIT => &[Abilities::CORROSION],
+            PokemonName::SALAZZLE => &[Abilities::CORROSION],
+            PokemonName::SALAZZLETOTEM => &[Abilities::CORROSION],
+            PokemonName::STUFFUL => &[Abilities::FLUFFY, Abilities::KLUTZ],
+            PokemonName::BEWEAR => &[Abilities::FLUFFY, Abilities::KLUTZ],
+            PokemonName::BOUNSWEET => &[Abilities::LEAFGUARD, Abilities::OBLIVIOUS],
+            PokemonName::STEENEE => &[Abilities::LEAFGUARD, Abilities::OBLIVIOUS],
+            PokemonName::TSAREENA => &[Abilities::LEAFGUARD, Abilities::QUEENLYMAJESTY],
+            PokemonName::COMFEY => &[Abilities::FLOWERVEIL, Abilities::TRIAGE],
+            PokemonName::ORANGURU => &[Abilities::INNERFOCUS, Abilities::TELEPATHY],
+            PokemonName::PASSIMIAN => &[Abilities::RECEIVER],
+            PokemonName::WIMPOD => &[Abilities::WIMPOUT],
+            PokemonName::GOLISOPOD => &[Abilities::EMERGENCYEXIT],
+            PokemonName::SANDYGAST => &[Abilities::WATERCOMPACTION],
+            PokemonName::PALOSSAND => &[Abilities::WATERCOMPACTION],
+            PokemonName::PYUKUMUKU => &[Abilities::INNARDSOUT],
+            PokemonName::TYPENULL => &[Abilities::BATTLEARMOR],
+            PokemonName::SILVALLY => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYBUG => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYDARK => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYDRAGON => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYELECTRIC => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFAIRY => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFIGHTING => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFIRE => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFLYING => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGHOST => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGRASS => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGROUND => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYICE => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYPOISON => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYPSYCHIC => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYROCK => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYSTEEL => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYWATER => &[Abilities::RKSSYSTEM],
+            PokemonName::MINIOR => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORORANGE => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORYELLOW => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORGREEN => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORBLUE => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORINDIGO => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORVIOLET => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORMETEOR => &[Abilities::SHIELDSDOWN],
+            PokemonName::KOMALA => &[Abilities::COMATOSE],
+            PokemonName::TURTONATOR => &[Abilities::SHELLARMOR],
+            PokemonName::TOGEDEMARU => &[Abilities::IRONBARBS, Abilities::LIGHTNINGROD],
+            PokemonName::TOGEDEMARUTOTEM => &[Abilities::STURDY],
+            PokemonName::MIMIKYU => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUBUSTED => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUTOTEM => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUBUSTEDTOTEM => &[Abilities::DISGUISE],
+            PokemonName::BRUXISH => &[Abilities::DAZZLING, Abilities::STRONGJAW],
+            PokemonName::DRAMPA => &[Abilities::BERSERK, Abilities::SAPSIPPER],
+            PokemonName::DHELMISE => &[Abilities::STEELWORKER],
+            PokemonName::JANGMOO => &[Abilities::BULLETPROOF, Abilities::SOUNDPROOF],
+            PokemonName::HAKAMOO => &[Abilities::BULLETPROOF, Abilities::SOUNDPROOF],
+            PokemonName::KOMMOO => &[Abilities::BULLETPROOF, Abilities::SOUNDPROOF],
+            PokemonName::KOMMOOTOTEM => &[Abilities::OVERCOAT],
+            PokemonName::TAPUKOKO => &[Abilities::ELECTRICSURGE],
+            PokemonName::TAPULELE => &[Abilities::PSYCHICSURGE],
+            PokemonName::TAPUBULU => &[Abilities::GRASSYSURGE],
+            PokemonName::TAPUFINI => &[Abilities::MISTYSURGE],
+            PokemonName::COSMOG => &[Abilities::UNAWARE],
+            PokemonName::COSMOEM => &[Abilities::STURDY],
+            PokemonName::SOLGALEO => &[Abilities::FULLMETALBODY],
+            PokemonName::LUNALA => &[Abilities::SHADOWSHIELD],
+            PokemonName::NIHILEGO => &[Abilities::BEASTBOOST],
+            PokemonName::BUZZWOLE => &[Abilities::BEASTBOOST],
+            PokemonName::PHEROMOSA => &[Abilities::BEASTBOOST],
+            PokemonName::XURKITREE => &[Abilities::BEASTBOOST],
+            PokemonName::CELESTEELA => &[Abilities::BEASTBOOST],
+            PokemonName::KARTANA => &[Abilities::BEASTBOOST],
+            PokemonName::GUZZLORD => &[Abilities::BEASTBOOST],
+            PokemonName::NECROZMA => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMADUSKMANE => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMADAWNWINGS => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMAULTRA => &[Abilities::NEUROFORCE],
+            PokemonName::MAGEARNA => &[Abilities::SOULHEART],
+            PokemonName::MAGEARNAORIGINAL => &[Abilities::SOULHEART],
+            PokemonName::MARSHADOW => &[Abilities::TECHNICIAN],
+            PokemonName::POIPOLE => &[Abilities::BEASTBOOST],
+            PokemonName::NAGANADEL => &[Abilities::BEASTBOOST],
+            PokemonName::STAKATAKA => &[Abilities::BEASTBOOST],
+            PokemonName::BLACEPHALON => &[Abilities::BEASTBOOST],
+            PokemonName::ZERAORA => &[Abilities::VOLTABSORB],
+            PokemonName::MELTAN => &[Abilities::MAGNETPULL],
+            PokemonName::MELMETAL => &[Abilities::IRONFIST],
+            PokemonName::MELMETALGMAX => &[Abilities::IRONFIST],
+            PokemonName::GROOKEY => &[Abilities::OVERGROW],
+            PokemonName::THWACKEY => &[Abilities::OVERGROW],
+            PokemonName::RILLABOOM => &[Abilities::OVERGROW],
+            PokemonName::RILLABOOMGMAX => &[Abilities::OVERGROW],
+            PokemonName::SCORBUNNY => &[Abilities::BLAZE],
+            PokemonName::RABOOT => &[Abilities::BLAZE],
+            PokemonName::CINDERACE => &[Abilities::BLAZE],
+            PokemonName::CINDERACEGMAX => &[Abilities::BLAZE],
+            PokemonName::SOBBLE => &[Abilities::TORRENT],
+            PokemonName::DRIZZILE => &[Abilities::TORRENT],
+            PokemonName::INTELEON => &[Abilities::TORRENT],
+            PokemonName::INTELEONGMAX => &[Abilities::TORRENT],
+            PokemonName::SKWOVET => &[Abilities::CHEEKPOUCH],
+            PokemonName::GREEDENT => &[Abilities::CHEEKPOUCH],
+            PokemonName::ROOKIDEE => &[Abilities::KEENEYE, Abilities::UNNERVE],
+            PokemonName::CORVISQUIRE => &[Abilities::KEENEYE, Abilities::UNNERVE],
+            PokemonName::CORVIKNIGHT => &[Abilities::PRESSURE, Abilities::UNNERVE],
+            PokemonName::CORVIKNIGHTGMAX => &[Abilities::PRESSURE, Abilities::UNNERVE],
+            PokemonName::BLIPBUG => &[Abilities::SWARM, Abilities::COMPOUNDEYES],
+            PokemonName::DOTTLER => &[Abilities::SWARM, Abilities::COMPOUNDEYES],
+            PokemonName::ORBEETLE => &[Abilities::SWARM, Abilities::FRISK],
+            PokemonName::ORBEETLEGMAX => &[Abilities::SWARM, Abilities::FRISK],
+            PokemonName::NICKIT => &[Abilities::RUNAWAY, Abilities::UNBURDEN],
+            PokemonName::THIEVUL => &[Abilities::RUNAWAY, Abilities::UNBURDEN],
+            PokemonName::GOSSIFLEUR => &[Abilities::COTTONDOWN, Abilities::REGENERATOR],
+            PokemonName::ELDEGOSS => &[Abilities::COTTONDOWN, Abilities::REGENERATOR],
+            PokemonName::WOOLOO => &[Abilities::FLUFFY, Abilities::RUNAWAY],
+            PokemonName::DUBWOOL => &[Abilities::FLUFFY, Abilities::STEADFAST],
+            PokemonName::CHEWTLE => &[Abilities::STRONGJAW, Abilities::SHELLARMOR],
+            PokemonName::DREDNAW => &[Abilities::STRONGJAW, Abilities::SHELLARMOR],
+            PokemonName::DREDNAWGMAX => &[Abilities::STRONGJAW, Abilities::SHELLARMOR],
+            PokemonName::YAMPER => &[Abilities::BALLFETCH],
+            PokemonName::BOLTUND => &[Abilities::STRONGJAW],
+            PokemonName::ROLYCOLY => &[Abilities::STEAMENGINE, Abilities::HEATPROOF],
+            PokemonName::CARKOL => &[Abilities::STEAMENGINE, Abilities::FLAMEBODY],
+            PokemonName::COALOSSAL => &[Abilities::STEAMENGINE, Abilities::FLAMEBODY],
+            PokemonName::COALOSSALGMAX => &[Abilities::STEAMENGINE, Abilities::FLAMEBODY],
+            PokemonName::APPLIN => &[Abilities::RIPEN, Abilities::GLUTTONY],
+            PokemonName::FLAPPLE => &[Abilities::RIPEN, Abilities::GLUTTONY],
+            PokemonName::FLAPPLEGMAX => &[Abilities::RIPEN, Abilities::GLUTTONY],
+            PokemonName::APPLETUN => &[Abilities::RIPEN, Abilities::GLUTTONY],
+            PokemonName::APPLETUNGMAX => &[Abilities::RIPEN, Abilities::GLUTTONY],
+            PokemonName::SILICOBRA => &[Abilities::SANDSPIT, Abilities::SHEDSKIN],
+            PokemonName::SANDACONDA => &[Abilities::SANDSPIT, Abilities::SHEDSKIN],
+            PokemonName::SANDACONDAGMAX => &[Abilities::SANDSPIT, Abilities::SHEDSKIN],
+            PokemonName::CRAMORANT => &[Abilities::GULPMISSILE],
+            PokemonName::CRAMORANTGULPING => &[Abilities::GULPMISSILE],
+            PokemonName::CRAMORANTGORGING => &[Abilities::GULPMISSILE],
+            PokemonName::ARROKUDA => &[Abilities::SWIFTSWIM],
+            PokemonName::BARRASKEWDA => &[Abilities::SWIFTSWIM],
+            PokemonName::TOXEL => &[Abilities::RATTLED, Abilities::STATIC],
+            PokemonName::TOXTRICITY => &[Abilities::PUNKROCK, Abilities::PLUS],
+            PokemonName::TOXTRICITYLOWKEY => &[Abilities::PUNKROCK, Abilities::MINUS],
+            PokemonName::TOXTRICITYGMAX => &[Abilities::PUNKROCK, Abilities::PLUS],
+            PokemonName::TOXTRICITYLOWKEYGMAX => &[Abilities::PUNKROCK, Abilities::MINUS],
+            PokemonName::SIZZLIPEDE => &[Abilities::FLASHFIRE, Abilities::WHITESMOKE],
+            PokemonName::CENTISKORCH => &[Abilities::FLASHFIRE, Abilities::WHITESMOKE],
+            PokemonName::CENTISKORCHGMAX => &[Abilities::FLASHFIRE, Abilities::WHITESMOKE],
+            PokemonName::CLOBBOPUS => &[Abilities::LIMBER],
+            PokemonName::GRAPPLOCT => &[Abilities::LIMBER],
+            PokemonName::SINISTEA => &[Abilities::WEAKARMOR],
+            PokemonName::SINISTEAANTIQUE => &[Abilities::WEAKARMOR],
+            PokemonName::POLTEAGEIST => &[Abilities::WEAKARMOR],
+            PokemonName::POLTEAGEISTANTIQUE => &[Abilities::WEAKARMOR],
+            PokemonName::HATENNA => &[Abilities::HEALER, Abilities::ANTICIPATION],
+            PokemonName::HATTREM => &[Abilities::HEALER, Abilities::ANTICIPATION],
+            PokemonName::HATTERENE => &[Abilities::HEALER, Abilities::ANTICIPATION],
+            PokemonName::HATTERENEGMAX => &[Abilities::HEALER, Abilities::ANTICIPATION],
+            PokemonName::IMPIDIMP => &[Abilities::PRANKSTER, Abilities::FRISK],
+            PokemonName::MORGREM => &[Abilities::PRANKSTER, Abilities::FRISK],
+            PokemonName::GRIMMSNARL => &[Abilities::PRANKSTER, Abilities::FRISK],
+            PokemonName::GRIMMSNARLGMAX => &[Abilities::PRANKSTER, Abilities::FRISK],
+            PokemonName::OBSTAGOON => &[Abilities::RECKLESS, Abilities::GUTS],
+            PokemonName::PERRSERKER => &[Abilities::BATTLEARMOR, Abilities::TOUGHCLAWS],
+            PokemonName::CURSOLA => &[Abilities::WEAKARMOR],
+            PokemonName::SIRFETCHD => &[Abilities::STEADFAST],
+            PokemonName::MRRIME => &[Abilities::TANGLEDFEET, Abilities::SCREENCLEANER],
+            PokemonName::RUNERIGUS => &[Abilities::WANDERINGSPIRIT],
+            PokemonName::MILCERY => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIE => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIERUBYCREAM => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIEMATCHACREAM => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIEMINTCREAM => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIELEMONCREAM => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIESALTEDCREAM => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIERUBYSWIRL => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIECARAMELSWIRL => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIERAINBOWSWIRL => &[Abilities::SWEETVEIL],
+            PokemonName::ALCREMIEGMAX => &[Abilities::SWEETVEIL],
+            PokemonName::FALINKS => &[Abilities::BATTLEARMOR],
+            PokemonName::PINCURCHIN => &[Abilities::LIGHTNINGROD],
+            PokemonName::SNOM => &[Abilities::SHIELDDUST],
+            PokemonName::FROSMOTH => &[Abilities::SHIELDDUST],
+            PokemonName::STONJOURNER => &[Abilities::POWERSPOT],
+            PokemonName::EISCUE => &[Abilities::ICEFACE],
+            PokemonName::EISCUENOICE => &[Abilities::ICEFACE],
+            PokemonName::INDEEDEE => &[Abilities::INNERFOCUS, Abilities::SYNCHRONIZE],
+            PokemonName::INDEEDEEF => &[Abilities::OWNTEMPO, Abilities::SYNCHRONIZE],
+            PokemonName::MORPEKO => &[Abilities::HUNGERSWITCH],
+            PokemonName::MORPEKOHANGRY => &[Abilities::HUNGERSWITCH],
+            PokemonName::CUFANT => &[Abilities::SHEERFORCE],
+            PokemonName::COPPERAJAH => &[Abilities::SHEERFORCE],
+            PokemonName::COPPERAJAHGMAX => &[Abilities::SHEERFORCE],
+            PokemonName::DRACOZOLT => &[Abilities::VOLTABSORB, Abilities::HUSTLE],
+            PokemonName::ARCTOZOLT => &[Abilities::VOLTABSORB, Abilities::STATIC],
+            PokemonName::DRACOVISH => &[Abilities::WATERABSORB, Abilities::STRONGJAW],
+            PokemonName::ARCTOVISH => &[Abilities::WATERABSORB, Abilities::ICEBODY],
+            PokemonName::DURALUDON => &[Abilities::LIGHTMETAL, Abilities::HEAVYMETAL],
+            PokemonName::DURALUDONGMAX => &[Abilities::LIGHTMETAL, Abilities::HEAVYMETAL],
+            PokemonName::DREEPY => &[Abilities::CLEARBODY, Abilities::INFILTRATOR],
+            PokemonName::DRAKLOAK => &[Abilities::CLEARBODY, Abilities::INFILTRATOR],
+            PokemonName::DRAGAPULT => &[Abilities::CLEARBODY, Abilities::INFILTRATOR],
+            PokemonName::ZACIAN => &[Abilities::INTREPIDSWORD],
+            PokemonName::ZACIANCROWNED => &[Abilities::INTREPIDSWORD],
+            PokemonName::ZAMAZENTA => &[Abilities::DAUNTLESSSHIELD],
+            PokemonName::ZAMAZENTACROWNED => &[Abilities::DAUNTLESSSHIELD],
+            PokemonName::ETERNATUS => &[Abilities::PRESSURE],
+            PokemonName::ETERNATUSETERNAMAX => &[Abilities::PRESSURE],
+            PokemonName::KUBFU => &[Abilities::INNERFOCUS],
+            PokemonName::URSHIFU => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFURAPIDSTRIKE => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFUGMAX => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => &[Abilities::UNSEENFIST],
+            PokemonName::ZARUDE => &[Abilities::LEAFGUARD],
+            PokemonName::ZARUDEDADA => &[Abilities::LEAFGUARD],
+            PokemonName::REGIELEKI => &[Abilities::TRANSISTOR],
+            PokemonName::REGIDRAGO => &[Abilities::DRAGONSMAW],
+            PokemonName::GLASTRIER => &[Abilities::CHILLINGNEIGH],
+            PokemonName::SPECTRIER => &[Abilities::GRIMNEIGH],
+            PokemonName::CALYREX => &[Abilities::UNNERVE],
+            PokemonName::CALYREXICE => &[Abilities::ASONEGLASTRIER],
+            PokemonName::CALYREXSHADOW => &[Abilities::ASONESPECTRIER],
+            PokemonName::WYRDEER => &[Abilities::INTIMIDATE, Abilities::FRISK],
+            PokemonName::KLEAVOR => &[Abilities::SWARM, Abilities::SHEERFORCE],
+            PokemonName::URSALUNA => &[Abilities::GUTS, Abilities::BULLETPROOF],
+            PokemonName::URSALUNABLOODMOON => &[Abilities::MINDSEYE],
+            PokemonName::BASCULEGION => &[Abilities::SWIFTSWIM, Abilities::ADAPTABILITY],
+            PokemonName::BASCULEGIONF => &[Abilities::SWIFTSWIM, Abilities::ADAPTABILITY],
+            PokemonName::SNEASLER => &[Abilities::PRESSURE, Abilities::UNBURDEN],
+            PokemonName::OVERQWIL => &[Abilities::POISONPOINT, Abilities::SWIFTSWIM],
+            PokemonName::ENAMORUS => &[Abilities::CUTECHARM],
+            PokemonName::ENAMORUSTHERIAN => &[Abilities::OVERCOAT],
+            PokemonName::SPRIGATITO => &[Abilities::OVERGROW],
+            PokemonName::FLORAGATO => &[Abilities::OVERGROW],
+            PokemonName::MEOWSCARADA => &[Abilities::OVERGROW],
+            PokemonName::FUECOCO => &[Abilities::BLAZE],
+            PokemonName::CROCALOR => &[Abilities::BLAZE],
+            PokemonName::SKELEDIRGE => &[Abilities::BLAZE],
+            PokemonName::QUAXLY => &[Abilities::TORRENT],
+            PokemonName::QUAXWELL => &[Abilities::TORRENT],
+            PokemonName::QUAQUAVAL => &[Abilities::TORRENT],
+            PokemonName::LECHONK => &[Abilities::AROMAVEIL, Abilities::GLUTTONY],
+            PokemonName::OINKOLOGNE => &[Abilities::LINGERINGAROMA, Abilities::GLUTTONY],
+            PokemonName::OINKOLOGNEF => &[Abilities::AROMAVEIL, Abilities::GLUTTONY],
+            PokemonName::TAROUNTULA => &[Abilities::INSOMNIA],
+            PokemonName::SPIDOPS => &[Abilities::INSOMNIA],
+            PokemonName::NYMBLE => &[Abilities::SWARM],
+            PokemonName::LOKIX => &[Abilities::SWARM],
+            PokemonName::PAWMI => &[Abilities::STATIC, Abilities::NATURALCURE],
+            PokemonName::PAWMO => &[Abilities::VOLTABSORB, Abilities::NATURALCURE],
+            PokemonName::PAWMOT => &[Abilities::VOLTABSORB, Abilities::NATURALCURE],
+            PokemonName::TANDEMAUS => &[Abilities::RUNAWAY, Abilities::PICKUP],
+            PokemonName::MAUSHOLD => &[Abilities::FRIENDGUARD, Abilities::CHEEKPOUCH],
+            PokemonName::MAUSHOLDFOUR => &[Abilities::FRIENDGUARD, Abilities::CHEEKPOUCH],
+            PokemonName::FIDOUGH => &[Abilities::OWNTEMPO],
+            PokemonName::DACHSBUN => &[Abilities::WELLBAKEDBODY],
+            PokemonName::SMOLIV => &[Abilities::EARLYBIRD],
+            PokemonName::DOLLIV => &[Abilities::EARLYBIRD],
+            PokemonName::ARBOLIVA => &[Abilities::SEEDSOWER],
+            PokemonName::SQUAWKABILLY => &[Abilities::INTIMIDATE, Abilities::HUSTLE],
+            PokemonName::SQUAWKABILLYBLUE => &[Abilities::INTIMIDATE, Abilities::HUSTLE],
+            PokemonName::SQUAWKABILLYYELLOW => &[Abilities::INTIMIDATE, Abilities::HUSTLE],
+            PokemonName::SQUAWKABILLYWHITE => &[Abilities::INTIMIDATE, Abilities::HUSTLE],
+            PokemonName::NACLI => &[Abilities::PURIFYINGSALT, Abilities::STURDY],
+            PokemonName::NACLSTACK => &[Abilities::PURIFYINGSALT, Abilities::STURDY],
+            PokemonName::GARGANACL => &[Abilities::PURIFYINGSALT, Abilities::STURDY],
+            PokemonName::CHARCADET => &[Abilities::FLASHFIRE],
+            PokemonName::ARMAROUGE => &[Abilities::FLASHFIRE],
+            PokemonName::CERULEDGE => &[Abilities::FLASHFIRE],
+            PokemonName::TADBULB => &[Abilities::OWNTEMPO, Abilities::STATIC],
+            PokemonName::BELLIBOLT => &[Abilities::ELECTROMORPHOSIS, Abilities::STATIC],
+            PokemonName::WATTREL => &[Abilities::WINDPOWER, Abilities::VOLTABSORB],
+            PokemonName::KILOWATTREL => &[Abilities::WINDPOWER, Abilities::VOLTABSORB],
+            PokemonName::MASCHIFF => &[Abilities::INTIMIDATE, Abilities::RUNAWAY],
+            PokemonName::MABOSSTIFF => &[Abilities::INTIMIDATE, Abilities::GUARDDOG],
+            PokemonName::SHROODLE => &[Abilities::UNBURDEN, Abilities::PICKPOCKET],
+            PokemonName::GRAFAIAI => &[Abilities::UNBURDEN, Abilities::POISONTOUCH],
+            PokemonName::BRAMBLIN => &[Abilities::WINDRIDER],
+            PokemonName::BRAMBLEGHAST => &[Abilities::WINDRIDER],
+            PokemonName::TOEDSCOOL => &[Abilities::MYCELIUMMIGHT],
+            PokemonName::TOEDSCRUEL => &[Abilities::MYCELIUMMIGHT],
+            PokemonName::KLAWF => &[Abilities::ANGERSHELL, Abilities::SHELLARMOR],
+            PokemonName::CAPSAKID => &[Abilities::CHLOROPHYLL, Abilities::INSOMNIA],
+            PokemonName::SCOVILLAIN => &[Abilities::CHLOROPHYLL, Abilities::INSOMNIA],
+            PokemonName::RELLOR => &[Abilities::COMPOUNDEYES],
+            PokemonName::RABSCA => &[Abilities::SYNCHRONIZE],
+            PokemonName::FLITTLE => &[Abilities::ANTICIPATION, Abilities::FRISK],
+            PokemonName::ESPATHRA => &[Abilities::OPPORTUNIST, Abilities::FRISK],
+            PokemonName::TINKATINK => &[Abilities::MOLDBREAKER, Abilities::OWNTEMPO],
+            PokemonName::TINKATUFF => &[Abilities::MOLDBREAKER, Abilities::OWNTEMPO],
+            PokemonName::TINKATON => &[Abilities::MOLDBREAKER, Abilities::OWNTEMPO],
+            PokemonName::WIGLETT => &[Abilities::GOOEY, Abilities::RATTLED],
+            PokemonName::WUGTRIO => &[Abilities::GOOEY, Abilities::RATTLED],
+            PokemonName::BOMBIRDIER => &[Abilities::BIGPECKS, Abilities::KEENEYE],
+            PokemonName::FINIZEN => &[Abilities::WATERVEIL],
+            PokemonName::PALAFIN => &[Abilities::ZEROTOHERO],
+            PokemonName::PALAFINHERO => &[Abilities::ZEROTOHERO],
+            PokemonName::VAROOM => &[Abilities::OVERCOAT],
+            PokemonName::REVAVROOM => &[Abilities::OVERCOAT],
+            PokemonName::CYCLIZAR => &[Abilities::SHEDSKIN],
+            PokemonName::ORTHWORM => &[Abilities::EARTHEATER],
+            PokemonName::GLIMMET => &[Abilities::TOXICDEBRIS],
+            PokemonName::GLIMMORA => &[Abilities::TOXICDEBRIS],
+            PokemonName::GREAVARD => &[Abilities::PICKUP],
+            PokemonName::HOUNDSTONE => &[Abilities::SANDRUSH],
+            PokemonName::FLAMIGO => &[Abilities::SCRAPPY, Abilities::TANGLEDFEET],
+            PokemonName::CETODDLE => &[Abilities::THICKFAT, Abilities::SNOWCLOAK],
+            PokemonName::CETITAN => &[Abilities::THICKFAT, Abilities::SLUSHRUSH],
+            PokemonName::VELUZA => &[Abilities::MOLDBREAKER],
+            PokemonName::DONDOZO => &[Abilities::UNAWARE, Abilities::OBLIVIOUS],
+            PokemonName::TATSUGIRI => &[Abilities::COMMANDER],
+            PokemonName::TATSUGIRIDROOPY => &[Abilities::COMMANDER],
+            PokemonName::TATSUGIRISTRETCHY => &[Abilities::COMMANDER],
+            PokemonName::ANNIHILAPE => &[Abilities::VITALSPIRIT, Abilities::INNERFOCUS],
+            PokemonName::CLODSIRE => &[Abilities::POISONPOINT, Abilities::WATERABSORB],
+            PokemonName::FARIGIRAF => &[Abilities::CUDCHEW, Abilities::ARMORTAIL],
+            PokemonName::DUDUNSPARCE => &[Abilities::SERENEGRACE, Abilities::RUNAWAY],
+            PokemonName::DUDUNSPARCETHREESEGMENT => &[Abilities::SERENEGRACE, Abilities::RUNAWAY],
+            PokemonName::KINGAMBIT => &[Abilities::DEFIANT, Abilities::SUPREMEOVERLORD],
+            PokemonName::GREATTUSK => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SCREAMTAIL => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::BRUTEBONNET => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::FLUTTERMANE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SLITHERWING => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SANDYSHOCKS => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONTREADS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONBUNDLE => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONHANDS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONJUGULIS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONMOTH => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONTHORNS => &[Abilities::QUARKDRIVE],
+            PokemonName::FRIGIBAX => &[Abilities::THERMALEXCHANGE],
+            PokemonName::ARCTIBAX => &[Abilities::THERMALEXCHANGE],
+            PokemonName::BAXCALIBUR => &[Abilities::THERMALEXCHANGE],
+            PokemonName::GIMMIGHOUL => &[Abilities::RATTLED],
+            PokemonName::GIMMIGHOULROAMING => &[Abilities::RUNAWAY],
+            PokemonName::GHOLDENGO => &[Abilities::GOODASGOLD],
+            PokemonName::WOCHIEN => &[Abilities::TABLETSOFRUIN],
+            PokemonName::CHIENPAO => &[Abilities::SWORDOFRUIN],
+            PokemonName::TINGLU => &[Abilities::VESSELOFRUIN],
+            PokemonName::CHIYU => &[Abilities::BEADSOFRUIN],
+            PokemonName::ROARINGMOON => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONVALIANT => &[Abilities::QUARKDRIVE],
+            PokemonName::KORAIDON => &[Abilities::ORICHALCUMPULSE],
+            PokemonName::MIRAIDON => &[Abilities::HADRONENGINE],
+            PokemonName::WALKINGWAKE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONLEAVES => &[Abilities::QUARKDRIVE],
+            PokemonName::DIPPLIN => &[Abilities::SUPERSWEETSYRUP, Abilities::GLUTTONY],
+            PokemonName::POLTCHAGEIST => &[Abilities::HOSPITALITY],
+            PokemonName::POLTCHAGEISTARTISAN => &[Abilities::HOSPITALITY],
+            PokemonName::SINISTCHA => &[Abilities::HOSPITALITY],
+            PokemonName::SINISTCHAMASTERPIECE => &[Abilities::HOSPITALITY],
+            PokemonName::OKIDOGI => &[Abilities::TOXICCHAIN],
+            PokemonName::MUNKIDORI => &[Abilities::TOXICCHAIN],
+            PokemonName::FEZANDIPITI => &[Abilities::TOXICCHAIN],
+            PokemonName::OGERPON => &[Abilities::DEFIANT],
+            PokemonName::OGERPONWELLSPRING => &[Abilities::WATERABSORB],
+            PokemonName::OGERPONHEARTHFLAME => &[Abilities::MOLDBREAKER],
+            PokemonName::OGERPONCORNERSTONE => &[Abilities::STURDY],
+            PokemonName::OGERPONTEALTERA => &[Abilities::EMBODYASPECTTEAL],
+            PokemonName::OGERPONWELLSPRINGTERA => &[Abilities::EMBODYASPECTWELLSPRING],
+            PokemonName::OGERPONHEARTHFLAMETERA => &[Abilities::EMBODYASPECTHEARTHFLAME],
+            PokemonName::OGERPONCORNERSTONETERA => &[Abilities::EMBODYASPECTCORNERSTONE],
+            PokemonName::ARCHALUDON => &[Abilities::STAMINA, Abilities::STURDY],
+            PokemonName::HYDRAPPLE => &[Abilities::SUPERSWEETSYRUP, Abilities::REGENERATOR],
+            PokemonName::GOUGINGFIRE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::RAGINGBOLT => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONBOULDER => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONCROWN => &[Abilities::QUARKDRIVE],
+            PokemonName::TERAPAGOS => &[Abilities::TERASHIFT],
+            PokemonName::TERAPAGOSTERASTAL => &[Abilities::TERASHELL],
+            PokemonName::TERAPAGOSSTELLAR => &[Abilities::TERAFORMZERO],
+            PokemonName::PECHARUNT => &[Abilities::POISONPUPPETEER],
+        }
+    }
+
+    pub fn hidden_ability(&self) -> Option<Abilities> {
+        match self {
+            PokemonName::BULBASAUR => Some(Abilities::CHLOROPHYLL),
+            PokemonName::IVYSAUR => Some(Abilities::CHLOROPHYLL),
+            PokemonName::VENUSAUR => Some(Abilities::CHLOROPHYLL),
+            PokemonName::VENUSAURGMAX => Some(Abilities::CHLOROPHYLL),
+            PokemonName::CHARMANDER => Some(Abilities::SOLARPOWER),
+            PokemonName::CHARMELEON => Some(Abilities::SOLARPOWER),
+            PokemonName::CHARIZARD => Some(Abilities::SOLARPOWER),
+            PokemonName::CHARIZARDGMAX => Some(Abilities::SOLARPOWER),
+            PokemonName::SQUIRTLE => Some(Abilities::RAINDISH),
+            PokemonName::WARTORTLE => Some(Abilities::RAINDISH),
+            PokemonName::BLASTOISE => Some(Abilities::RAINDISH),
+            PokemonName::BLASTOISEGMAX => Some(Abilities::RAINDISH),
+            PokemonName::CATERPIE => Some(Abilities::RUNAWAY),
+            PokemonName::BUTTERFREE => Some(Abilities::TINTEDLENS),
+            PokemonName::BUTTERFREEGMAX => Some(Abilities::TINTEDLENS),
+            PokemonName::WEEDLE => Some(Abilities::RUNAWAY),
+            PokemonName::BEEDRILL => Some(Abilities::SNIPER),
+            PokemonName::PIDGEY => Some(Abilities::BIGPECKS),
+            PokemonName::PIDGEOTTO => Some(Abilities::BIGPECKS),
+            PokemonName::PIDGEOT => Some(Abilities::BIGPECKS),
+            PokemonName::RATTATA => Some(Abilities::HUSTLE),
+            PokemonName::RATTATAALOLA => Some(Abilities::THICKFAT),
+            PokemonName::RATICATE => Some(Abilities::HUSTLE),
+            PokemonName::RATICATEALOLA => Some(Abilities::THICKFAT),
+            PokemonName::SPEAROW => Some(Abilities::SNIPER),
+            PokemonName::FEAROW => Some(Abilities::SNIPER),
+            PokemonName::EKANS => Some(Abilities::UNNERVE),
+            PokemonName::ARBOK => Some(Abilities::UNNERVE),
+            PokemonName::PIKACHU => Some(Abilities::LIGHTNINGROD),
+            PokemonName::PIKACHUGMAX => Some(Abilities::LIGHTNINGROD),
+            PokemonName::RAICHU => Some(Abilities::LIGHTNINGROD),
+            PokemonName::SANDSHREW => Some(Abilities::SANDRUSH),
+            PokemonName::SANDSHREWALOLA => Some(Abilities::SLUSHRUSH),
+            PokemonName::SANDSLASH => Some(Abilities::SANDRUSH),
+            PokemonName::SANDSLASHALOLA => Some(Abilities::SLUSHRUSH),
+            PokemonName::NIDORANF => Some(Abilities::HUSTLE),
+            PokemonName::NIDORINA => Some(Abilities::HUSTLE),
+            PokemonName::NIDOQUEEN => Some(Abilities::SHEERFORCE),
+            PokemonName::NIDORANM => Some(Abilities::HUSTLE),
+            PokemonName::NIDORINO => Some(Abilities::HUSTLE),
+            PokemonName::NIDOKING => Some(Abilities::SHEERFORCE),
+            PokemonName::CLEFAIRY => Some(Abilities::FRIENDGUARD),
+            PokemonName::CLEFABLE => Some(Abilities::UNAWARE),
+            PokemonName::VULPIX => Some(Abilities::DROUGHT),
+            PokemonName::VULPIXALOLA => Some(Abilities::SNOWWARNING),
+            PokemonName::NINETALES => Some(Abilities::DROUGHT),
+            PokemonName::NINETALESALOLA => Some(Abilities::SNOWWARNING),
+            PokemonName::JIGGLYPUFF => Some(Abilities::FRIENDGUARD),
+            PokemonName::WIGGLYTUFF => Some(Abilities::FRISK),
+            PokemonName::ZUBAT => Some(Abilities::INFILTRATOR),
+            PokemonName::GOLBAT => Some(Abilities::INFILTRATOR),
+            PokemonName::ODDISH => Some(Abilities::RUNAWAY),
+            PokemonName::GLOOM => Some(Abilities::STENCH),
+            PokemonName::VILEPLUME => Some(Abilities::EFFECTSPORE),
+            PokemonName::PARAS => Some(Abilities::DAMP),
+            PokemonName::PARASECT => Some(Abilities::DAMP),
+            PokemonName::VENONAT => Some(Abilities::RUNAWAY),
+            PokemonName::VENOMOTH => Some(Abilities::WONDERSKIN),
+            PokemonName::DIGLETT => Some(Abilities::SANDFORCE),
+            PokemonName::DIGLETTALOLA => Some(Abilities::SANDFORCE),
+            PokemonName::DUGTRIO => Some(Abilities::SANDFORCE),
+            PokemonName::DUGTRIOALOLA => Some(Abilities::SANDFORCE),
+            PokemonName::MEOWTH => Some(Abilities::UNNERVE),
+            PokemonName::MEOWTHALOLA => Some(Abilities::RATTLED),
+            PokemonName::MEOWTHGALAR => Some(Abilities::UNNERVE),
+            PokemonName::MEOWTHGMAX => Some(Abilities::UNNERVE),
+            PokemonName::PERSIAN => Some(Abilities::UNNERVE),
+            PokemonName::PERSIANALOLA => Some(Abilities::RATTLED),
+            PokemonName::PSYDUCK => Some(Abilities::SWIFTSWIM),
+            PokemonName::GOLDUCK => Some(Abilities::SWIFTSWIM),
+            PokemonName::MANKEY => Some(Abilities::DEFIANT),
+            PokemonName::PRIMEAPE => Some(Abilities::DEFIANT),
+            PokemonName::GROWLITHE => Some(Abilities::JUSTIFIED),
+            PokemonName::GROWLITHEHISUI => Some(Abilities::ROCKHEAD),
+            PokemonName::ARCANINE => Some(Abilities::JUSTIFIED),
+            PokemonName::ARCANINEHISUI => Some(Abilities::ROCKHEAD),
+            PokemonName::POLIWAG => Some(Abilities::SWIFTSWIM),
+            PokemonName::POLIWHIRL => Some(Abilities::SWIFTSWIM),
+            PokemonName::POLIWRATH => Some(Abilities::SWIFTSWIM),
+            PokemonName::ABRA => Some(Abilities::MAGICGUARD),
+            PokemonName::KADABRA => Some(Abilities::MAGICGUARD),
+            PokemonName::ALAKAZAM => Some(Abilities::MAGICGUARD),
+            PokemonName::MACHOP => Some(Abilities::STEADFAST),
+            PokemonName::MACHOKE => Some(Abilities::STEADFAST),
+            PokemonName::MACHAMP => Some(Abilities::STEADFAST),
+            PokemonName::MACHAMPGMAX => Some(Abilities::STEADFAST),
+            PokemonName::BELLSPROUT => Some(Abilities::GLUTTONY),
+            PokemonName::WEEPINBELL => Some(Abilities::GLUTTONY),
+            PokemonName::VICTREEBEL => Some(Abilities::GLUTTONY),
+            PokemonName::TENTACOOL => Some(Abilities::RAINDISH),
+            PokemonName::TENTACRUEL => Some(Abilities::RAINDISH),
+            PokemonName::GEODUDE => Some(Abilities::SANDVEIL),
+            PokemonName::GEODUDEALOLA => Some(Abilities::GALVANIZE),
+            PokemonName::GRAVELER => Some(Abilities::SANDVEIL),
+            PokemonName::GRAVELERALOLA => Some(Abilities::GALVANIZE),
+            PokemonName::GOLEM => Some(Abilities::SANDVEIL),
+            PokemonName::GOLEMALOLA => Some(Abilities::GALVANIZE),
+            PokemonName::PONYTA => Some(Abilities::FLAMEBODY),
+            PokemonName::PONYTAGALAR => Some(Abilities::ANTICIPATION),
+            PokemonName::RAPIDASH => Some(Abilities::FLAMEBODY),
+            PokemonName::RAPIDASHGALAR => Some(Abilities::ANTICIPATION),
+            PokemonName::SLOWPOKE => Some(Abilities::REGENERATOR),
+            PokemonName::SLOWPOKEGALAR => Some(Abilities::REGENERATOR),
+            PokemonName::SLOWBRO => Some(Abilities::REGENERATOR),
+            PokemonName::SLOWBROGALAR => Some(Abilities::REGENERATOR),
+            PokemonName::MAGNEMITE => Some(Abilities::ANALYTIC),
+            PokemonName::MAGNETON => Some(Abilities::ANALYTIC),
+            PokemonName::FARFETCHD => Some(Abilities::DEFIANT),
+            PokemonName::FARFETCHDGALAR => Some(Abilities::SCRAPPY),
+            PokemonName::DODUO => Some(Abilities::TANGLEDFEET),
+            PokemonName::DODRIO => Some(Abilities::TANGLEDFEET),
+            PokemonName::SEEL => Some(Abilities::ICEBODY),
+            PokemonName::DEWGONG => Some(Abilities::ICEBODY),
+            PokemonName::GRIMER => Some(Abilities::POISONTOUCH),
+            PokemonName::GRIMERALOLA => Some(Abilities::POWEROFALCHEMY),
+            PokemonName::MUK => Some(Abilities::POISONTOUCH),
+            PokemonName::MUKALOLA => Some(Abilities::POWEROFALCHEMY),
+            PokemonName::SHELLDER => Some(Abilities::OVERCOAT),
+            PokemonName::CLOYSTER => Some(Abilities::OVERCOAT),
+            PokemonName::ONIX => Some(Abilities::WEAKARMOR),
+            PokemonName::DROWZEE => Some(Abilities::INNERFOCUS),
+            PokemonName::HYPNO => Some(Abilities::INNERFOCUS),
+            PokemonName::KRABBY => Some(Abilities::SHEERFORCE),
+            PokemonName::KINGLER => Some(Abilities::SHEERFORCE),
+            PokemonName::KINGLERGMAX => Some(Abilities::SHEERFORCE),
+            PokemonName::VOLTORB => Some(Abilities::AFTERMATH),
+            PokemonName::VOLTORBHISUI => Some(Abilities::AFTERMATH),
+            PokemonName::ELECTRODE => Some(Abilities::AFTERMATH),
+            PokemonName::ELECTRODEHISUI => Some(Abilities::AFTERMATH),
+            PokemonName::EXEGGCUTE => Some(Abilities::HARVEST),
+            PokemonName::EXEGGUTOR => Some(Abilities::HARVEST),
+            PokemonName::EXEGGUTORALOLA => Some(Abilities::HARVEST),
+            PokemonName::CUBONE => Some(Abilities::BATTLEARMOR),
+            PokemonName::MAROWAK => Some(Abilities::BATTLEARMOR),
+            PokemonName::MAROWAKALOLA => Some(Abilities::ROCKHEAD),
+            PokemonName::HITMONLEE => Some(Abilities::UNBURDEN),
+            PokemonName::HITMONCHAN => Some(Abilities::INNERFOCUS),
+            PokemonName::LICKITUNG => Some(Abilities::CLOUDNINE),
+            PokemonName::KOFFING => Some(Abilities::STENCH),
+            PokemonName::WEEZING => Some(Abilities::STENCH),
+            PokemonName::WEEZINGGALAR => Some(Abilities::MISTYSURGE),
+            PokemonName::RHYHORN => Some(Abilities::RECKLESS),
+            PokemonName::RHYDON => Some(Abilities::RECKLESS),
+            PokemonName::CHANSEY => Some(Abilities::HEALER),
+            PokemonName::TANGELA => Some(Abilities::REGENERATOR),
+            PokemonName::KANGASKHAN => Some(Abilities::INNERFOCUS),
+            PokemonName::HORSEA => Some(Abilities::DAMP),
+            PokemonName::SEADRA => Some(Abilities::DAMP),
+            PokemonName::GOLDEEN => Some(Abilities::LIGHTNINGROD),
+            PokemonName::SEAKING => Some(Abilities::LIGHTNINGROD),
+            PokemonName::STARYU => Some(Abilities::ANALYTIC),
+            PokemonName::STARMIE => Some(Abilities::ANALYTIC),
+            PokemonName::MRMIME => Some(Abilities::TECHNICIAN),
+            PokemonName::MRMIMEGALAR => Some(Abilities::ICEBODY),
+            PokemonName::SCYTHER => Some(Abilities::STEADFAST),
+            PokemonName::JYNX => Some(Abilities::DRYSKIN),
+            PokemonName::ELECTABUZZ => Some(Abilities::VITALSPIRIT),
+            PokemonName::MAGMAR => Some(Abilities::VITALSPIRIT),
+            PokemonName::PINSIR => Some(Abilities::MOXIE),
+            PokemonName::TAUROS => Some(Abilities::SHEERFORCE),
+            PokemonName::TAUROSPALDEACOMBAT => Some(Abilities::CUDCHEW),
+            PokemonName::TAUROSPALDEABLAZE => Some(Abilities::CUDCHEW),
+            PokemonName::TAUROSPALDEAAQUA => Some(Abilities::CUDCHEW),
+            PokemonName::MAGIKARP => Some(Abilities::RATTLED),
+            PokemonName::GYARADOS => Some(Abilities::MOXIE),
+            PokemonName::LAPRAS => Some(Abilities::HYDRATION),
+            PokemonName::LAPRASGMAX => Some(Abilities::HYDRATION),
+            PokemonName::DITTO => Some(Abilities::IMPOSTER),
+            PokemonName::EEVEE => Some(Abilities::ANTICIPATION),
+            PokemonName::EEVEESTARTER => Some(Abilities::ANTICIPATION),
+            PokemonName::EEVEEGMAX => Some(Abilities::ANTICIPATION),
+            PokemonName::VAPOREON => Some(Abilities::HYDRATION),
+            PokemonName::JOLTEON => Some(Abilities::QUICKFEET),
+            PokemonName::FLAREON => Some(Abilities::GUTS),
+            PokemonName::PORYGON => Some(Abilities::ANALYTIC),
+            PokemonName::OMANYTE => Some(Abilities::WEAKARMOR),
+            PokemonName::OMASTAR => Some(Abilities::WEAKARMOR),
+            PokemonName::KABUTO => Some(Abilities::WEAKARMOR),
+            PokemonName::KABUTOPS => Some(Abilities::WEAKARMOR),
+            PokemonName::AERODACTYL => Some(Abilities::UNNERVE),
+            PokemonName::SNORLAX => Some(Abilities::GLUTTONY),
+            PokemonName::SNORLAXGMAX => Some(Abilities::GLUTTONY),
+            PokemonName::ARTICUNO => Some(Abilities::SNOWCLOAK),
+            PokemonName::ZAPDOS => Some(Abilities::STATIC),
+            PokemonName::MOLTRES => Some(Abilities::FLAMEBODY),
+            PokemonName::DRATINI => Some(Abilities::MARVELSCALE),
+            PokemonName::DRAGONAIR => Some(Abilities::MARVELSCALE),
+            PokemonName::DRAGONITE => Some(Abilities::MULTISCALE),
+            PokemonName::MEWTWO => Some(Abilities::UNNERVE),
+            PokemonName::CHIKORITA => Some(Abilities::LEAFGUARD),
+            PokemonName::BAYLEEF => Some(Abilities::LEAFGUARD),
+            PokemonName::MEGANIUM => Some(Abilities::LEAFGUARD),
+            PokemonName::CYNDAQUIL => Some(Abilities::FLASHFIRE),
+            PokemonName::QUILAVA => Some(Abilities::FLASHFIRE),
+            PokemonName::TYPHLOSION => Some(Abilities::FLASHFIRE),
+            PokemonName::TYPHLOSIONHISUI => Some(Abilities::FRISK),
+            PokemonName::TOTODILE => Some(Abilities::SHEERFORCE),
+            PokemonName::CROCONAW => Some(Abilities::SHEERFORCE),
+            PokemonName::FERALIGATR => Some(Abilities::SHEERFORCE),
+            PokemonName::SENTRET => Some(Abilities::FRISK),
+            PokemonName::FURRET => Some(Abilities::FRISK),
+            PokemonName::HOOTHOOT => Some(Abilities::TINTEDLENS),
+            PokemonName::NOCTOWL => Some(Abilities::TINTEDLENS),
+            PokemonName::LEDYBA => Some(Abilities::RATTLED),
+            PokemonName::LEDIAN => Some(Abilities::IRONFIST),
+            PokemonName::SPINARAK => Some(Abilities::SNIPER),
+            PokemonName::ARIADOS => Some(Abilities::SNIPER),
+            PokemonName::CROBAT => Some(Abilities::INFILTRATOR),
+            PokemonName::CHINCHOU => Some(Abilities::WATERABSORB),
+            PokemonName::LANTURN => Some(Abilities::WATERABSORB),
+            PokemonName::PICHU => Some(Abilities::LIGHTNINGROD),
+            PokemonName::CLEFFA => Some(Abilities::FRIENDGUARD),
+            PokemonName::IGGLYBUFF => Some(Abilities::FRIENDGUARD),
+            PokemonName::TOGEPI => Some(Abilities::SUPERLUCK),
+            PokemonName::TOGETIC => Some(Abilities::SUPERLUCK),
+            PokemonName::NATU => Some(Abilities::MAGICBOUNCE),
+            PokemonName::XATU => Some(Abilities::MAGICBOUNCE),
+            PokemonName::MAREEP => Some(Abilities::PLUS),
+            PokemonName::FLAAFFY => Some(Abilities::PLUS),
+            PokemonName::AMPHAROS => Some(Abilities::PLUS),
+            PokemonName::BELLOSSOM => Some(Abilities::HEALER),
+            PokemonName::MARILL => Some(Abilities::SAPSIPPER),
+            PokemonName::AZUMARILL => Some(Abilities::SAPSIPPER),
+            PokemonName::SUDOWOODO => Some(Abilities::RATTLED),
+            PokemonName::POLITOED => Some(Abilities::DRIZZLE),
+            PokemonName::HOPPIP => Some(Abilities::INFILTRATOR),
+            PokemonName::SKIPLOOM => Some(Abilities::INFILTRATOR),
+            PokemonName::JUMPLUFF => Some(Abilities::INFILTRATOR),
+            PokemonName::AIPOM => Some(Abilities::SKILLLINK),
+            PokemonName::SUNKERN => Some(Abilities::EARLYBIRD),
+            PokemonName::SUNFLORA => Some(Abilities::EARLYBIRD),
+            PokemonName::YANMA => Some(Abilities::FRISK),
+            PokemonName::WOOPER => Some(Abilities::UNAWARE),
+            PokemonName::WOOPERPALDEA => Some(Abilities::UNAWARE),
+            PokemonName::QUAGSIRE => Some(Abilities::UNAWARE),
+            PokemonName::ESPEON => Some(Abilities::MAGICBOUNCE),
+            PokemonName::UMBREON => Some(Abilities::INNERFOCUS),
+            PokemonName::MURKROW => Some(Abilities::PRANKSTER),
+            PokemonName::SLOWKING => Some(Abilities::REGENERATOR),
+            PokemonName::SLOWKINGGALAR => Some(Abilities::REGENERATOR),
+            PokemonName::WOBBUFFET => Some(Abilities::TELEPATHY),
+            PokemonName::GIRAFARIG => Some(Abilities::SAPSIPPER),
+            PokemonName::PINECO => Some(Abilities::OVERCOAT),
+            PokemonName::FORRETRESS => Some(Abilities::OVERCOAT),
+            PokemonName::DUNSPARCE => Some(Abilities::RATTLED),
+            PokemonName::GLIGAR => Some(Abilities::IMMUNITY),
+            PokemonName::STEELIX => Some(Abilities::SHEERFORCE),
+            PokemonName::SNUBBULL => Some(Abilities::RATTLED),
+            PokemonName::GRANBULL => Some(Abilities::RATTLED),
+            PokemonName::QWILFISH => Some(Abilities::INTIMIDATE),
+            PokemonName::QWILFISHHISUI => Some(Abilities::INTIMIDATE),
+            PokemonName::SCIZOR => Some(Abilities::LIGHTMETAL),
+            PokemonName::SHUCKLE => Some(Abilities::CONTRARY),
+            PokemonName::HERACROSS => Some(Abilities::MOXIE),
+            PokemonName::SNEASEL => Some(Abilities::PICKPOCKET),
+            PokemonName::SNEASELHISUI => Some(Abilities::PICKPOCKET),
+            PokemonName::TEDDIURSA => Some(Abilities::HONEYGATHER),
+            PokemonName::URSARING => Some(Abilities::UNNERVE),
+            PokemonName::SLUGMA => Some(Abilities::WEAKARMOR),
+            PokemonName::MAGCARGO => Some(Abilities::WEAKARMOR),
+            PokemonName::SWINUB => Some(Abilities::THICKFAT),
+            PokemonName::PILOSWINE => Some(Abilities::THICKFAT),
+            PokemonName::CORSOLA => Some(Abilities::REGENERATOR),
+            PokemonName::CORSOLAGALAR => Some(Abilities::CURSEDBODY),
+            PokemonName::REMORAID => Some(Abilities::MOODY),
+            PokemonName::OCTILLERY => Some(Abilities::MOODY),
+            PokemonName::DELIBIRD => Some(Abilities::INSOMNIA),
+            PokemonName::MANTINE => Some(Abilities::WATERVEIL),
+            PokemonName::SKARMORY => Some(Abilities::WEAKARMOR),
+            PokemonName::HOUNDOUR => Some(Abilities::UNNERVE),
+            PokemonName::HOUNDOOM => Some(Abilities::UNNERVE),
+            PokemonName::KINGDRA => Some(Abilities::DAMP),
+            PokemonName::PHANPY => Some(Abilities::SANDVEIL),
+            PokemonName::DONPHAN => Some(Abilities::SANDVEIL),
+            PokemonName::PORYGON2 => Some(Abilities::ANALYTIC),
+            PokemonName::STANTLER => Some(Abilities::SAPSIPPER),
+            PokemonName::SMEARGLE => Some(Abilities::MOODY),
+            PokemonName::TYROGUE => Some(Abilities::VITALSPIRIT),
+            PokemonName::HITMONTOP => Some(Abilities::STEADFAST),
+            PokemonName::SMOOCHUM => Some(Abilities::HYDRATION),
+            PokemonName::ELEKID => Some(Abilities::VITALSPIRIT),
+            PokemonName::MAGBY => Some(Abilities::VITALSPIRIT),
+            PokemonName::MILTANK => Some(Abilities::SAPSIPPER),
+            PokemonName::BLISSEY => Some(Abilities::HEALER),
+            PokemonName::RAIKOU => Some(Abilities::INNERFOCUS),
+            PokemonName::ENTEI => Some(Abilities::INNERFOCUS),
+            PokemonName::SUICUNE => Some(Abilities::INNERFOCUS),
+            PokemonName::LARVITAR => Some(Abilities::SANDVEIL),
+            PokemonName::TYRANITAR => Some(Abilities::UNNERVE),
+            PokemonName::LUGIA => Some(Abilities::MULTISCALE),
+            PokemonName::HOOH => Some(Abilities::REGENERATOR),
+            PokemonName::TREECKO => Some(Abilities::UNBURDEN),
+            PokemonName::GROVYLE => Some(Abilities::UNBURDEN),
+            PokemonName::SCEPTILE => Some(Abilities::UNBURDEN),
+            PokemonName::TORCHIC => Some(Abilities::SPEEDBOOST),
+            PokemonName::COMBUSKEN => Some(Abilities::SPEEDBOOST),
+            PokemonName::BLAZIKEN => Some(Abilities::SPEEDBOOST),
+            PokemonName::MUDKIP => Some(Abilities::DAMP),
+            PokemonName::MARSHTOMP => Some(Abilities::DAMP),
+            PokemonName::SWAMPERT => Some(Abilities::DAMP),
+            PokemonName::POOCHYENA => Some(Abilities::RATTLED),
+            PokemonName::MIGHTYENA => Some(Abilities::MOXIE),
+            PokemonName::ZIGZAGOON => Some(Abilities::QUICKFEET),
+            PokemonName::ZIGZAGOONGALAR => Some(Abilities::QUICKFEET),
+            PokemonName::LINOONE => Some(Abilities::QUICKFEET),
+            PokemonName::LINOONEGALAR => Some(Abilities::QUICKFEET),
+            PokemonName::WURMPLE => Some(Abilities::RUNAWAY),
+            PokemonName::BEAUTIFLY => Some(Abilities::RIVALRY),
+            PokemonName::DUSTOX => Some(Abilities::COMPOUNDEYES),
+            PokemonName::LOTAD => Some(Abilities::OWNTEMPO),
+            PokemonName::LOMBRE => Some(Abilities::OWNTEMPO),
+            PokemonName::LUDICOLO => Some(Abilities::OWNTEMPO),
+            PokemonName::SEEDOT => Some(Abilities::PICKPOCKET),
+            PokemonName::NUZLEAF => Some(Abilities::PICKPOCKET),
+            PokemonName::SHIFTRY => Some(Abilities::PICKPOCKET),
+            PokemonName::TAILLOW => Some(Abilities::SCRAPPY),
+            PokemonName::SWELLOW => Some(Abilities::SCRAPPY),
+            PokemonName::WINGULL => Some(Abilities::RAINDISH),
+            PokemonName::PELIPPER => Some(Abilities::RAINDISH),
+            PokemonName::RALTS => Some(Abilities::TELEPATHY),
+            PokemonName::KIRLIA => Some(Abilities::TELEPATHY),
+            PokemonName::GARDEVOIR => Some(Abilities::TELEPATHY),
+            PokemonName::SURSKIT => Some(Abilities::RAINDISH),
+            PokemonName::MASQUERAIN => Some(Abilities::UNNERVE),
+            PokemonName::SHROOMISH => Some(Abilities::QUICKFEET),
+            PokemonName::BRELOOM => Some(Abilities::TECHNICIAN),
+            PokemonName::NINCADA => Some(Abilities::RUNAWAY),
+            PokemonName::NINJASK => Some(Abilities::INFILTRATOR),
+            PokemonName::WHISMUR => Some(Abilities::RATTLED),
+            PokemonName::LOUDRED => Some(Abilities::SCRAPPY),
+            PokemonName::EXPLOUD => Some(Abilities::SCRAPPY),
+            PokemonName::MAKUHITA => Some(Abilities::SHEERFORCE),
+            PokemonName::HARIYAMA => Some(Abilities::SHEERFORCE),
+            PokemonName::AZURILL => Some(Abilities::SAPSIPPER),
+            PokemonName::NOSEPASS => Some(Abilities::SANDFORCE),
+            PokemonName::SKITTY => Some(Abilities::WONDERSKIN),
+            PokemonName::DELCATTY => Some(Abilities::WONDERSKIN),
+            PokemonName::SABLEYE => Some(Abilities::PRANKSTER),
+            PokemonName::MAWILE => Some(Abilities::SHEERFORCE),
+            PokemonName::ARON => Some(Abilities::HEAVYMETAL),
+            PokemonName::LAIRON => Some(Abilities::HEAVYMETAL),
+            PokemonName::AGGRON => Some(Abilities::HEAVYMETAL),
+            PokemonName::MEDITITE => Some(Abilities::TELEPATHY),
+            PokemonName::MEDICHAM => Some(Abilities::TELEPATHY),
+            PokemonName::ELECTRIKE => Some(Abilities::MINUS),
+            PokemonName::MANECTRIC => Some(Abilities::MINUS),
+            PokemonName::PLUSLE => Some(Abilities::LIGHTNINGROD),
+            PokemonName::MINUN => Some(Abilities::VOLTABSORB),
+            PokemonName::VOLBEAT => Some(Abilities::PRANKSTER),
+            PokemonName::ILLUMISE => Some(Abilities::PRANKSTER),
+            PokemonName::ROSELIA => Some(Abilities::LEAFGUARD),
+            PokemonName::GULPIN => Some(Abilities::GLUTTONY),
+            PokemonName::SWALOT => Some(Abilities::GLUTTONY),
+            PokemonName::CARVANHA => Some(Abilities::SPEEDBOOST),
+            PokemonName::SHARPEDO => Some(Abilities::SPEEDBOOST),
+            PokemonName::WAILMER => Some(Abilities::PRESSURE),
+            PokemonName::WAILORD => Some(Abilities::PRESSURE),
+            PokemonName::NUMEL => Some(Abilities::OWNTEMPO),
+            PokemonName::CAMERUPT => Some(Abilities::ANGERPOINT),
+            PokemonName::TORKOAL => Some(Abilities::SHELLARMOR),
+            PokemonName::SPOINK => Some(Abilities::GLUTTONY),
+            PokemonName::GRUMPIG => Some(Abilities::GLUTTONY),
+            PokemonName::SPINDA => Some(Abilities::CONTRARY),
+            PokemonName::TRAPINCH => Some(Abilities::SHEERFORCE),
+            PokemonName::CACNEA => Some(Abilities::WATERABSORB),
+            PokemonName::CACTURNE => Some(Abilities::WATERABSORB),
+            PokemonName::SWABLU => Some(Abilities::CLOUDNINE),
+            PokemonName::ALTARIA => Some(Abilities::CLOUDNINE),
+            PokemonName::ZANGOOSE => Some(Abilities::TOXICBOOST),
+            PokemonName::SEVIPER => Some(Abilities::INFILTRATOR),
+            PokemonName::BARBOACH => Some(Abilities::HYDRATION),
+            PokemonName::WHISCASH => Some(Abilities::HYDRATION),
+            PokemonName::CORPHISH => Some(Abilities::ADAPTABILITY),
+            PokemonName::CRAWDAUNT => Some(Abilities::ADAPTABILITY),
+            PokemonName::LILEEP => Some(Abilities::STORMDRAIN),
+            PokemonName::CRADILY => Some(Abilities::STORMDRAIN),
+            PokemonName::ANORITH => Some(Abilities::SWIFTSWIM),
+            PokemonName::ARMALDO => Some(Abilities::SWIFTSWIM),
+            PokemonName::FEEBAS => Some(Abilities::ADAPTABILITY),
+            PokemonName::MILOTIC => Some(Abilities::CUTECHARM),
+            PokemonName::KECLEON => Some(Abilities::PROTEAN),
+            PokemonName::SHUPPET => Some(Abilities::CURSEDBODY),
+            PokemonName::BANETTE => Some(Abilities::CURSEDBODY),
+            PokemonName::DUSKULL => Some(Abilities::FRISK),
+            PokemonName::DUSCLOPS => Some(Abilities::FRISK),
+            PokemonName::TROPIUS => Some(Abilities::HARVEST),
+            PokemonName::ABSOL => Some(Abilities::JUSTIFIED),
+            PokemonName::WYNAUT => Some(Abilities::TELEPATHY),
+            PokemonName::SNORUNT => Some(Abilities::MOODY),
+            PokemonName::GLALIE => Some(Abilities::MOODY),
+            PokemonName::SPHEAL => Some(Abilities::OBLIVIOUS),
+            PokemonName::SEALEO => Some(Abilities::OBLIVIOUS),
+            PokemonName::WALREIN => Some(Abilities::OBLIVIOUS),
+            PokemonName::CLAMPERL => Some(Abilities::RATTLED),
+            PokemonName::HUNTAIL => Some(Abilities::WATERVEIL),
+            PokemonName::GOREBYSS => Some(Abilities::HYDRATION),
+            PokemonName::RELICANTH => Some(Abilities::STURDY),
+            PokemonName::LUVDISC => Some(Abilities::HYDRATION),
+            PokemonName::BAGON => Some(Abilities::SHEERFORCE),
+            PokemonName::SHELGON => Some(Abilities::OVERCOAT),
+            PokemonName::SALAMENCE => Some(Abilities::MOXIE),
+            PokemonName::BELDUM => Some(Abilities::LIGHTMETAL),
+            PokemonName::METANG => Some(Abilities::LIGHTMETAL),
+            PokemonName::METAGROSS => Some(Abilities::LIGHTMETAL),
+            PokemonName::REGIROCK => Some(Abilities::STURDY),
+            PokemonName::REGICE => Some(Abilities::ICEBODY),
+            PokemonName::REGISTEEL => Some(Abilities::LIGHTMETAL),
+            PokemonName::TURTWIG => Some(Abilities::SHELLARMOR),
+            PokemonName::GROTLE => Some(Abilities::SHELLARMOR),
+            PokemonName::TORTERRA => Some(Abilities::SHELLARMOR),
+            PokemonName::CHIMCHAR => Some(Abilities::IRONFIST),
+            PokemonName::MONFERNO => Some(Abilities::IRONFIST),
+            PokemonName::INFERNAPE => Some(Abilities::IRONFIST),
+            PokemonName::PIPLUP => Some(Abilities::COMPETITIVE),
+            PokemonName::PRINPLUP => Some(Abilities::COMPETITIVE),
+            PokemonName::EMPOLEON => Some(Abilities::COMPETITIVE),
+            PokemonName::STARLY => Some(Abilities::RECKLESS),
+            PokemonName::STARAVIA => Some(Abilities::RECKLESS),
+            PokemonName::STARAPTOR => Some(Abilities::RECKLESS),
+            PokemonName::BIDOOF => Some(Abilities::MOODY),
+            PokemonName::BIBAREL => Some(Abilities::MOODY),
+            PokemonName::KRICKETOT => Some(Abilities::RUNAWAY),
+            PokemonName::KRICKETUNE => Some(Abilities::TECHNICIAN),
+            PokemonName::SHINX => Some(Abilities::GUTS),
+            PokemonName::LUXIO => Some(Abilities::GUTS),
+            PokemonName::LUXRAY => Some(Abilities::GUTS),
+            PokemonName::BUDEW => Some(Abilities::LEAFGUARD),
+            PokemonName::ROSERADE => Some(Abilities::TECHNICIAN),
+            PokemonName::CRANIDOS => Some(Abilities::SHEERFORCE),
+            PokemonName::RAMPARDOS => Some(Abilities::SHEERFORCE),
+            PokemonName::SHIELDON => Some(Abilities::SOUNDPROOF),
+            PokemonName::BASTIODON => Some(Abilities::SOUNDPROOF),
+            PokemonName::BURMY => Some(Abilities::OVERCOAT),
+            PokemonName::BURMYSANDY => Some(Abilities::OVERCOAT),
+            PokemonName::BURMYTRASH => Some(Abilities::OVERCOAT),
+            PokemonName::WORMADAM => Some(Abilities::OVERCOAT),
+            PokemonName::WORMADAMSANDY => Some(Abilities::OVERCOAT),
+            PokemonName::WORMADAMTRASH => Some(Abilities::OVERCOAT),
+            PokemonName::MOTHIM => Some(Abilities::TINTEDLENS),
+            PokemonName::COMBEE => Some(Abilities::HUSTLE),
+            PokemonName::VESPIQUEN => Some(Abilities::UNNERVE),
+            PokemonName::PACHIRISU => Some(Abilities::VOLTABSORB),
+            PokemonName::BUIZEL => Some(Abilities::WATERVEIL),
+            PokemonName::FLOATZEL => Some(Abilities::WATERVEIL),
+            PokemonName::SHELLOS => Some(Abilities::SANDFORCE),
+            PokemonName::SHELLOSEAST => Some(Abilities::SANDFORCE),
+            PokemonName::GASTRODON => Some(Abilities::SANDFORCE),
+            PokemonName::GASTRODONEAST => Some(Abilities::SANDFORCE),
+            PokemonName::AMBIPOM => Some(Abilities::SKILLLINK),
+            PokemonName::DRIFLOON => Some(Abilities::FLAREBOOST),
+            PokemonName::DRIFBLIM => Some(Abilities::FLAREBOOST),
+            PokemonName::BUNEARY => Some(Abilities::LIMBER),
+            PokemonName::LOPUNNY => Some(Abilities::LIMBER),
+            PokemonName::HONCHKROW => Some(Abilities::MOXIE),
+            PokemonName::GLAMEOW => Some(Abilities::KEENEYE),
+            PokemonName::PURUGLY => Some(Abilities::DEFIANT),
+            PokemonName::STUNKY => Some(Abilities::KEENEYE),
+            PokemonName::SKUNTANK => Some(Abilities::KEENEYE),
+            PokemonName::BRONZOR => Some(Abilities::HEAVYMETAL),
+            PokemonName::BRONZONG => Some(Abilities::HEAVYMETAL),
+            PokemonName::BONSLY => Some(Abilities::RATTLED),
+            PokemonName::MIMEJR => Some(Abilities::TECHNICIAN),
+            PokemonName::HAPPINY => Some(Abilities::FRIENDGUARD),
+            PokemonName::CHATOT => Some(Abilities::BIGPECKS),
+            PokemonName::SPIRITOMB => Some(Abilities::INFILTRATOR),
+            PokemonName::GIBLE => Some(Abilities::ROUGHSKIN),
+            PokemonName::GABITE => Some(Abilities::ROUGHSKIN),
+            PokemonName::GARCHOMP => Some(Abilities::ROUGHSKIN),
+            PokemonName::MUNCHLAX => Some(Abilities::GLUTTONY),
+            PokemonName::RIOLU => Some(Abilities::PRANKSTER),
+            PokemonName::LUCARIO => Some(Abilities::JUSTIFIED),
+            PokemonName::HIPPOPOTAS => Some(Abilities::SANDFORCE),
+            PokemonName::HIPPOWDON => Some(Abilities::SANDFORCE),
+            PokemonName::SKORUPI => Some(Abilities::KEENEYE),
+            PokemonName::DRAPION => Some(Abilities::KEENEYE),
+            PokemonName::CROAGUNK => Some(Abilities::POISONTOUCH),
+            PokemonName::TOXICROAK => Some(Abilities::POISONTOUCH),
+            PokemonName::FINNEON => Some(Abilities::WATERVEIL),
+            PokemonName::LUMINEON => Some(Abilities::WATERVEIL),
+            PokemonName::MANTYKE => Some(Abilities::WATERVEIL),
+            PokemonName::SNOVER => Some(Abilities::SOUNDPROOF),
+            PokemonName::ABOMASNOW => Some(Abilities::SOUNDPROOF),
+            PokemonName::WEAVILE => Some(Abilities::PICKPOCKET),
+            PokemonName::MAGNEZONE => Some(Abilities::ANALYTIC),
+            PokemonName::LICKILICKY => Some(Abilities::CLOUDNINE),
+            PokemonName::RHYPERIOR => Some(Abilities::RECKLESS),
+            PokemonName::TANGROWTH => Some(Abilities::REGENERATOR),
+            PokemonName::ELECTIVIRE => Some(Abilities::VITALSPIRIT),
+            PokemonName::MAGMORTAR => Some(Abilities::VITALSPIRIT),
+            PokemonName::TOGEKISS => Some(Abilities::SUPERLUCK),
+            PokemonName::YANMEGA => Some(Abilities::FRISK),
+            PokemonName::LEAFEON => Some(Abilities::CHLOROPHYLL),
+            PokemonName::GLACEON => Some(Abilities::ICEBODY),
+            PokemonName::GLISCOR => Some(Abilities::POISONHEAL),
+            PokemonName::MAMOSWINE => Some(Abilities::THICKFAT),
+            PokemonName::PORYGONZ => Some(Abilities::ANALYTIC),
+            PokemonName::GALLADE => Some(Abilities::JUSTIFIED),
+            PokemonName::PROBOPASS => Some(Abilities::SANDFORCE),
+            PokemonName::DUSKNOIR => Some(Abilities::FRISK),
+            PokemonName::FROSLASS => Some(Abilities::CURSEDBODY),
+            PokemonName::DIALGA => Some(Abilities::TELEPATHY),
+            PokemonName::DIALGAORIGIN => Some(Abilities::TELEPATHY),
+            PokemonName::PALKIA => Some(Abilities::TELEPATHY),
+            PokemonName::PALKIAORIGIN => Some(Abilities::TELEPATHY),
+            PokemonName::HEATRAN => Some(Abilities::FLAMEBODY),
+            PokemonName::GIRATINA => Some(Abilities::TELEPATHY),
+            PokemonName::SNIVY => Some(Abilities::CONTRARY),
+            PokemonName::SERVINE => Some(Abilities::CONTRARY),
+            PokemonName::SERPERIOR => Some(Abilities::CONTRARY),
+            PokemonName::TEPIG => Some(Abilities::THICKFAT),
+            PokemonName::PIGNITE => Some(Abilities::THICKFAT),
+            PokemonName::EMBOAR => Some(Abilities::RECKLESS),
+            PokemonName::OSHAWOTT => Some(Abilities::SHELLARMOR),
+            PokemonName::DEWOTT => Some(Abilities::SHELLARMOR),
+            PokemonName::SAMUROTT => Some(Abilities::SHELLARMOR),
+            PokemonName::SAMUROTTHISUI => Some(Abilities::SHARPNESS),
+            PokemonName::PATRAT => Some(Abilities::ANALYTIC),
+            PokemonName::WATCHOG => Some(Abilities::ANALYTIC),
+            PokemonName::LILLIPUP => Some(Abilities::RUNAWAY),
+            PokemonName::HERDIER => Some(Abilities::SCRAPPY),
+            PokemonName::STOUTLAND => Some(Abilities::SCRAPPY),
+            PokemonName::PURRLOIN => Some(Abilities::PRANKSTER),
+            PokemonName::LIEPARD => Some(Abilities::PRANKSTER),
+            PokemonName::PANSAGE => Some(Abilities::OVERGROW),
+            PokemonName::SIMISAGE => Some(Abilities::OVERGROW),
+            PokemonName::PANSEAR => Some(Abilities::BLAZE),
+            PokemonName::SIMISEAR => Some(Abilities::BLAZE),
+            PokemonName::PANPOUR => Some(Abilities::TORRENT),
+            PokemonName::SIMIPOUR => Some(Abilities::TORRENT),
+            PokemonName::MUNNA => Some(Abilities::TELEPATHY),
+            PokemonName::MUSHARNA => Some(Abilities::TELEPATHY),
+            PokemonName::PIDOVE => Some(Abilities::RIVALRY),
+            PokemonName::TRANQUILL => Some(Abilities::RIVALRY),
+            PokemonName::UNFEZANT => Some(Abilities::RIVALRY),
+            PokemonName::BLITZLE => Some(Abilities::SAPSIPPER),
+            PokemonName::ZEBSTRIKA => Some(Abilities::SAPSIPPER),
+            PokemonName::ROGGENROLA => Some(Abilities::SANDFORCE),
+            PokemonName::BOLDORE => Some(Abilities::SANDFORCE),
+            PokemonName::GIGALITH => Some(Abilities::SANDFORCE),
+            PokemonName::WOOBAT => Some(Abilities::SIMPLE),
+            PokemonName::SWOOBAT => Some(Abilities::SIMPLE),
+            PokemonName::DRILBUR => Some(Abilities::MOLDBREAKER),
+            PokemonName::EXCADRILL => Some(Abilities::MOLDBREAKER),
+            PokemonName::AUDINO => Some(Abilities::KLUTZ),
+            PokemonName::TIMBURR => Some(Abilities::IRONFIST),
+            PokemonName::GURDURR => Some(Abilities::IRONFIST),
+            PokemonName::CONKELDURR => Some(Abilities::IRONFIST),
+            PokemonName::TYMPOLE => Some(Abilities::WATERABSORB),
+            PokemonName::PALPITOAD => Some(Abilities::WATERABSORB),
+            PokemonName::SEISMITOAD => Some(Abilities::WATERABSORB),
+            PokemonName::THROH => Some(Abilities::MOLDBREAKER),
+            PokemonName::SAWK => Some(Abilities::MOLDBREAKER),
+            PokemonName::SEWADDLE => Some(Abilities::OVERCOAT),
+            PokemonName::SWADLOON => Some(Abilities::OVERCOAT),
+            PokemonName::LEAVANNY => Some(Abilities::OVERCOAT),
+            PokemonName::VENIPEDE => Some(Abilities::SPEEDBOOST),
+            PokemonName::WHIRLIPEDE => Some(Abilities::SPEEDBOOST),
+            PokemonName::SCOLIPEDE => Some(Abilities::SPEEDBOOST),
+            PokemonName::COTTONEE => Some(Abilities::CHLOROPHYLL),
+            PokemonName::WHIMSICOTT => Some(Abilities::CHLOROPHYLL),
+            PokemonName::PETILIL => Some(Abilities::LEAFGUARD),
+            PokemonName::LILLIGANT => Some(Abilities::LEAFGUARD),
+            PokemonName::LILLIGANTHISUI => Some(Abilities::LEAFGUARD),
+            PokemonName::BASCULIN => Some(Abilities::MOLDBREAKER),
+            PokemonName::BASCULINBLUESTRIPED => Some(Abilities::MOLDBREAKER),
+            PokemonName::BASCULINWHITESTRIPED => Some(Abilities::MOLDBREAKER),
+            PokemonName::SANDILE => Some(Abilities::ANGERPOINT),
+            PokemonName::KROKOROK => Some(Abilities::ANGERPOINT),
+            PokemonName::KROOKODILE => Some(Abilities::ANGERPOINT),
+            PokemonName::DARUMAKA => Some(Abilities::INNERFOCUS),
+            PokemonName::DARUMAKAGALAR => Some(Abilities::INNERFOCUS),
+            PokemonName::DARMANITAN => Some(Abilities::ZENMODE),
+            PokemonName::DARMANITANGALAR => Some(Abilities::ZENMODE),
+            PokemonName::MARACTUS => Some(Abilities::STORMDRAIN),
+            PokemonName::DWEBBLE => Some(Abilities::WEAKARMOR),
+            PokemonName::CRUSTLE => Some(Abilities::WEAKARMOR),
+            PokemonName::SCRAGGY => Some(Abilities::INTIMIDATE),
+            PokemonName::SCRAFTY => Some(Abilities::INTIMIDATE),
+            PokemonName::SIGILYPH => Some(Abilities::TINTEDLENS),
+            PokemonName::TIRTOUGA => Some(Abilities::SWIFTSWIM),
+            PokemonName::CARRACOSTA => Some(Abilities::SWIFTSWIM),
+            PokemonName::TRUBBISH => Some(Abilities::AFTERMATH),
+            PokemonName::GARBODOR => Some(Abilities::AFTERMATH),
+            PokemonName::GARBODORGMAX => Some(Abilities::AFTERMATH),
+            PokemonName::MINCCINO => Some(Abilities::SKILLLINK),
+            PokemonName::CINCCINO => Some(Abilities::SKILLLINK),
+            PokemonName::GOTHITA => Some(Abilities::SHADOWTAG),
+            PokemonName::GOTHORITA => Some(Abilities::SHADOWTAG),
+            PokemonName::GOTHITELLE => Some(Abilities::SHADOWTAG),
+            PokemonName::SOLOSIS => Some(Abilities::REGENERATOR),
+            PokemonName::DUOSION => Some(Abilities::REGENERATOR),
+            PokemonName::REUNICLUS => Some(Abilities::REGENERATOR),
+            PokemonName::DUCKLETT => Some(Abilities::HYDRATION),
+            PokemonName::SWANNA => Some(Abilities::HYDRATION),
+            PokemonName::VANILLITE => Some(Abilities::WEAKARMOR),
+            PokemonName::VANILLISH => Some(Abilities::WEAKARMOR),
+            PokemonName::VANILLUXE => Some(Abilities::WEAKARMOR),
+            PokemonName::DEERLING => Some(Abilities::SERENEGRACE),
+            PokemonName::DEERLINGSUMMER => Some(Abilities::SERENEGRACE),
+            PokemonName::DEERLINGAUTUMN => Some(Abilities::SERENEGRACE),
+            PokemonName::DEERLINGWINTER => Some(Abilities::SERENEGRACE),
+            PokemonName::SAWSBUCK => Some(Abilities::SERENEGRACE),
+            PokemonName::SAWSBUCKSUMMER => Some(Abilities::SERENEGRACE),
+            PokemonName::SAWSBUCKAUTUMN => Some(Abilities::SERENEGRACE),
+            PokemonName::SAWSBUCKWINTER => Some(Abilities::SERENEGRACE),
+            PokemonName::EMOLGA => Some(Abilities::MOTORDRIVE),
+            PokemonName::KARRABLAST => Some(Abilities::NOGUARD),
+            PokemonName::ESCAVALIER => Some(Abilities::OVERCOAT),
+            PokemonName::FOONGUS => Some(Abilities::REGENERATOR),
+            PokemonName::AMOONGUSS => Some(Abilities::REGENERATOR),
+            PokemonName::FRILLISH => Some(Abilities::DAMP),
+            PokemonName::JELLICENT => Some(Abilities::DAMP),
+            PokemonName::ALOMOMOLA => Some(Abilities::REGENERATOR),
+            PokemonName::JOLTIK => Some(Abilities::SWARM),
+            PokemonName::GALVANTULA => Some(Abilities::SWARM),
+            PokemonName::FERROTHORN => Some(Abilities::ANTICIPATION),
+            PokemonName::KLINK => Some(Abilities::CLEARBODY),
+            PokemonName::KLANG => Some(Abilities::CLEARBODY),
+            PokemonName::KLINKLANG => Some(Abilities::CLEARBODY),
+            PokemonName::ELGYEM => Some(Abilities::ANALYTIC),
+            PokemonName::BEHEEYEM => Some(Abilities::ANALYTIC),
+            PokemonName::LITWICK => Some(Abilities::INFILTRATOR),
+            PokemonName::LAMPENT => Some(Abilities::INFILTRATOR),
+            PokemonName::CHANDELURE => Some(Abilities::INFILTRATOR),
+            PokemonName::AXEW => Some(Abilities::UNNERVE),
+            PokemonName::FRAXURE => Some(Abilities::UNNERVE),
+            PokemonName::HAXORUS => Some(Abilities::UNNERVE),
+            PokemonName::CUBCHOO => Some(Abilities::RATTLED),
+            PokemonName::BEARTIC => Some(Abilities::SWIFTSWIM),
+            PokemonName::SHELMET => Some(Abilities::OVERCOAT),
+            PokemonName::ACCELGOR => Some(Abilities::UNBURDEN),
+            PokemonName::STUNFISK => Some(Abilities::SANDVEIL),
+            PokemonName::MIENFOO => Some(Abilities::RECKLESS),
+            PokemonName::MIENSHAO => Some(Abilities::RECKLESS),
+            PokemonName::DRUDDIGON => Some(Abilities::MOLDBREAKER),
+            PokemonName::GOLETT => Some(Abilities::NOGUARD),
+            PokemonName::GOLURK => Some(Abilities::NOGUARD),
+            PokemonName::PAWNIARD => Some(Abilities::PRESSURE),
+            PokemonName::BISHARP => Some(Abilities::PRESSURE),
+            PokemonName::BOUFFALANT => Some(Abilities::SOUNDPROOF),
+            PokemonName::RUFFLET => Some(Abilities::HUSTLE),
+            PokemonName::BRAVIARY => Some(Abilities::DEFIANT),
+            PokemonName::BRAVIARYHISUI => Some(Abilities::TINTEDLENS),
+            PokemonName::VULLABY => Some(Abilities::WEAKARMOR),
+            PokemonName::MANDIBUZZ => Some(Abilities::WEAKARMOR),
+            PokemonName::HEATMOR => Some(Abilities::WHITESMOKE),
+            PokemonName::DURANT => Some(Abilities::TRUANT),
+            PokemonName::LARVESTA => Some(Abilities::SWARM),
+            PokemonName::VOLCARONA => Some(Abilities::SWARM),
+            PokemonName::TORNADUS => Some(Abilities::DEFIANT),
+            PokemonName::THUNDURUS => Some(Abilities::DEFIANT),
+            PokemonName::LANDORUS => Some(Abilities::SHEERFORCE),
+            PokemonName::CHESPIN => Some(Abilities::BULLETPROOF),
+            PokemonName::QUILLADIN => Some(Abilities::BULLETPROOF),
+            PokemonName::CHESNAUGHT => Some(Abilities::BULLETPROOF),
+            PokemonName::FENNEKIN => Some(Abilities::MAGICIAN),
+            PokemonName::BRAIXEN => Some(Abilities::MAGICIAN),
+            PokemonName::DELPHOX => Some(Abilities::MAGICIAN),
+            PokemonName::FROAKIE => Some(Abilities::PROTEAN),
+            PokemonName::FROGADIER => Some(Abilities::PROTEAN),
+            PokemonName::GRENINJA => Some(Abilities::PROTEAN),
+            PokemonName::BUNNELBY => Some(Abilities::HUGEPOWER),
+            PokemonName::DIGGERSBY => Some(Abilities::HUGEPOWER),
+            PokemonName::FLETCHLING => Some(Abilities::GALEWINGS),
+            PokemonName::FLETCHINDER => Some(Abilities::GALEWINGS),
+            PokemonName::TALONFLAME => Some(Abilities::GALEWINGS),
+            PokemonName::SCATTERBUG => Some(Abilities::FRIENDGUARD),
+            PokemonName::SPEWPA => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLON => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONARCHIPELAGO => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONCONTINENTAL => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONELEGANT => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONGARDEN => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONHIGHPLAINS => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONICYSNOW => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONJUNGLE => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONMARINE => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONMODERN => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONMONSOON => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONOCEAN => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONPOLAR => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONRIVER => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONSANDSTORM => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONSAVANNA => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONSUN => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONTUNDRA => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONFANCY => Some(Abilities::FRIENDGUARD),
+            PokemonName::VIVILLONPOKEBALL => Some(Abilities::FRIENDGUARD),
+            PokemonName::LITLEO => Some(Abilities::MOXIE),
+            PokemonName::PYROAR => Some(Abilities::MOXIE),
+            PokemonName::FLABEBE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLABEBEBLUE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLABEBEORANGE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLABEBEWHITE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLABEBEYELLOW => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLOETTE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLOETTEBLUE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLOETTEORANGE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLOETTEWHITE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLOETTEYELLOW => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLORGES => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLORGESBLUE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLORGESORANGE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLORGESWHITE => Some(Abilities::SYMBIOSIS),
+            PokemonName::FLORGESYELLOW => Some(Abilities::SYMBIOSIS),
+            PokemonName::SKIDDO => Some(Abilities::GRASSPELT),
+            PokemonName::GOGOAT => Some(Abilities::GRASSPELT),
+            PokemonName::PANCHAM => Some(Abilities::SCRAPPY),
+            PokemonName::PANGORO => Some(Abilities::SCRAPPY),
+            PokemonName::ESPURR => Some(Abilities::OWNTEMPO),
+            PokemonName::MEOWSTIC => Some(Abilities::PRANKSTER),
+            PokemonName::MEOWSTICF => Some(Abilities::COMPETITIVE),
+            PokemonName::SPRITZEE => Some(Abilities::AROMAVEIL),
+            PokemonName::AROMATISSE => Some(Abilities::AROMAVEIL),
+            PokemonName::SWIRLIX => Some(Abilities::UNBURDEN),
+            PokemonName::SLURPUFF => Some(Abilities::UNBURDEN),
+            PokemonName::INKAY => Some(Abilities::INFILTRATOR),
+            PokemonName::MALAMAR => Some(Abilities::INFILTRATOR),
+            PokemonName::BINACLE => Some(Abilities::PICKPOCKET),
+            PokemonName::BARBARACLE => Some(Abilities::PICKPOCKET),
+            PokemonName::SKRELP => Some(Abilities::ADAPTABILITY),
+            PokemonName::DRAGALGE => Some(Abilities::ADAPTABILITY),
+            PokemonName::HELIOPTILE => Some(Abilities::SOLARPOWER),
+            PokemonName::HELIOLISK => Some(Abilities::SOLARPOWER),
+            PokemonName::TYRUNT => Some(Abilities::STURDY),
+            PokemonName::TYRANTRUM => Some(Abilities::ROCKHEAD),
+            PokemonName::AMAURA => Some(Abilities::SNOWWARNING),
+            PokemonName::AURORUS => Some(Abilities::SNOWWARNING),
+            PokemonName::SYLVEON => Some(Abilities::PIXILATE),
+            PokemonName::HAWLUCHA => Some(Abilities::MOLDBREAKER),
+            PokemonName::DEDENNE => Some(Abilities::PLUS),
+            PokemonName::CARBINK => Some(Abilities::STURDY),
+            PokemonName::GOOMY => Some(Abilities::GOOEY),
+            PokemonName::SLIGGOO => Some(Abilities::GOOEY),
+            PokemonName::SLIGGOOHISUI => Some(Abilities::GOOEY),
+            PokemonName::GOODRA => Some(Abilities::GOOEY),
+            PokemonName::GOODRAHISUI => Some(Abilities::GOOEY),
+            PokemonName::KLEFKI => Some(Abilities::MAGICIAN),
+            PokemonName::PHANTUMP => Some(Abilities::HARVEST),
+            PokemonName::TREVENANT => Some(Abilities::HARVEST),
+            PokemonName::PUMPKABOO => Some(Abilities::INSOMNIA),
+            PokemonName::PUMPKABOOSMALL => Some(Abilities::INSOMNIA),
+            PokemonName::PUMPKABOOLARGE => Some(Abilities::INSOMNIA),
+            PokemonName::PUMPKABOOSUPER => Some(Abilities::INSOMNIA),
+            PokemonName::GOURGEIST => Some(Abilities::INSOMNIA),
+            PokemonName::GOURGEISTSMALL => Some(Abilities::INSOMNIA),
+            PokemonName::GOURGEISTLARGE => Some(Abilities::INSOMNIA),
+            PokemonName::GOURGEISTSUPER => Some(Abilities::INSOMNIA),
+            PokemonName::BERGMITE => Some(Abilities::STURDY),
+            PokemonName::AVALUGG => Some(Abilities::STURDY),
+            PokemonName::AVALUGGHISUI => Some(Abilities::STURDY),
+            PokemonName::NOIBAT => Some(Abilities::TELEPATHY),
+            PokemonName::NOIVERN => Some(Abilities::TELEPATHY),
+            PokemonName::ROWLET => Some(Abilities::LONGREACH),
+            PokemonName::DARTRIX => Some(Abilities::LONGREACH),
+            PokemonName::DECIDUEYE => Some(Abilities::LONGREACH),
+            PokemonName::DECIDUEYEHISUI => Some(Abilities::SCRAPPY),
+            PokemonName::LITTEN => Some(Abilities::INTIMIDATE),
+            PokemonName::TORRACAT => Some(Abilities::INTIMIDATE),
+            PokemonName::INCINEROAR => Some(Abilities::INTIMIDATE),
+            PokemonName::POPPLIO => Some(Abilities::LIQUIDVOICE),
+            PokemonName::BRIONNE => Some(Abilities::LIQUIDVOICE),
+            PokemonName::PRIMARINA => Some(Abilities::LIQUIDVOICE),
+            PokemonName::PIKIPEK => Some(Abilities::PICKUP),
+            PokemonName::TRUMBEAK => Some(Abilities::PICKUP),
+            PokemonName::TOUCANNON => Some(Abilities::SHEERFORCE),
+            PokemonName::YUNGOOS => Some(Abilities::ADAPTABILITY),
+            PokemonName::GUMSHOOS => Some(Abilities::ADAPTABILITY),
+            PokemonName::CRABRAWLER => Some(Abilities::ANGERPOINT),
+            PokemonName::CRABOMINABLE => Some(Abilities::ANGERPOINT),
+            PokemonName::CUTIEFLY => Some(Abilities::SWEETVEIL),
+            PokemonName::RIBOMBEE => Some(Abilities::SWEETVEIL),
+            PokemonName::ROCKRUFF => Some(Abilities::STEADFAST),
+            PokemonName::LYCANROC => Some(Abilities::STEADFAST),
+            PokemonName::LYCANROCMIDNIGHT => Some(Abilities::NOGUARD),
+            PokemonName::MAREANIE => Some(Abilities::REGENERATOR),
+            PokemonName::TOXAPEX => Some(Abilities::REGENERATOR),
+            PokemonName::MUDBRAY => Some(Abilities::INNERFOCUS),
+            PokemonName::MUDSDALE => Some(Abilities::INNERFOCUS),
+            PokemonName::DEWPIDER => Some(Abilities::WATERABSORB),
+            PokemonName::ARAQUANID => Some(Abilities::WATERABSORB),
+            PokemonName::FOMANTIS => Some(Abilities::CONTRARY),
+            PokemonName::LURANTIS => Some(Abilities::CONTRARY),
+            PokemonName::MORELULL => Some(Abilities::RAINDISH),
+            PokemonName::SHIINOTIC => Some(Abilities::RAINDISH),
+            PokemonName::SALANDIT => Some(Abilities::OBLIVIOUS),
+            PokemonName::SALAZZLE => Some(Abilities::OBLIVIOUS),
+            PokemonName::STUFFUL => Some(Abilities::CUTECHARM),
+            PokemonName::BEWEAR => Some(Abilities::UNNERVE),
+            PokemonName::BOUNSWEET => Some(Abilities::SWEETVEIL),
+            PokemonName::STEENEE => Some(Abilities::SWEETVEIL),
+            PokemonName::TSAREENA => Some(Abilities::SWEETVEIL),
+            PokemonName::COMFEY => Some(Abilities::NATURALCURE),
+            PokemonName::ORANGURU => Some(Abilities::SYMBIOSIS),
+            PokemonName::PASSIMIAN => Some(Abilities::DEFIANT),
+            PokemonName::SANDYGAST => Some(Abilities::SANDVEIL),
+            PokemonName::PALOSSAND => Some(Abilities::SANDVEIL),
+            PokemonName::PYUKUMUKU => Some(Abilities::UNAWARE),
+            PokemonName::TOGEDEMARU => Some(Abilities::STURDY),
+            PokemonName::BRUXISH => Some(Abilities::WONDERSKIN),
+            PokemonName::DRAMPA => Some(Abilities::CLOUDNINE),
+            PokemonName::JANGMOO => Some(Abilities::OVERCOAT),
+            PokemonName::HAKAMOO => Some(Abilities::OVERCOAT),
+            PokemonName::KOMMOO => Some(Abilities::OVERCOAT),
+            PokemonName::TAPUKOKO => Some(Abilities::TELEPATHY),
+            PokemonName::TAPULELE => Some(Abilities::TELEPATHY),
+            PokemonName::TAPUBULU => Some(Abilities::TELEPATHY),
+            PokemonName::TAPUFINI => Some(Abilities::TELEPATHY),
+            PokemonName::GROOKEY => Some(Abilities::GRASSYSURGE),
+            PokemonName::THWACKEY => Some(Abilities::GRASSYSURGE),
+            PokemonName::RILLABOOM => Some(Abilities::GRASSYSURGE),
+            PokemonName::RILLABOOMGMAX => Some(Abilities::GRASSYSURGE),
+            PokemonName::SCORBUNNY => Some(Abilities::LIBERO),
+            PokemonName::RABOOT => Some(Abilities::LIBERO),
+            PokemonName::CINDERACE => Some(Abilities::LIBERO),
+            PokemonName::CINDERACEGMAX => Some(Abilities::LIBERO),
+            PokemonName::SOBBLE => Some(Abilities::SNIPER),
+            PokemonName::DRIZZILE => Some(Abilities::SNIPER),
+            PokemonName::INTELEON => Some(Abilities::SNIPER),
+            PokemonName::INTELEONGMAX => Some(Abilities::SNIPER),
+            PokemonName::SKWOVET => Some(Abilities::GLUTTONY),
+            PokemonName::GREEDENT => Some(Abilities::GLUTTONY),
+            PokemonName::ROOKIDEE => Some(Abilities::BIGPECKS),
+            PokemonName::CORVISQUIRE => Some(Abilities::BIGPECKS),
+            PokemonName::CORVIKNIGHT => Some(Abilities::MIRRORARMOR),
+            PokemonName::CORVIKNIGHTGMAX => Some(Abilities::MIRRORARMOR),
+            PokemonName::BLIPBUG => Some(Abilities::TELEPATHY),
+            PokemonName::DOTTLER => Some(Abilities::TELEPATHY),
+            PokemonName::ORBEETLE => Some(Abilities::TELEPATHY),
+            PokemonName::ORBEETLEGMAX => Some(Abilities::TELEPATHY),
+            PokemonName::NICKIT => Some(Abilities::STAKEOUT),
+            PokemonName::THIEVUL => Some(Abilities::STAKEOUT),
+            PokemonName::GOSSIFLEUR => Some(Abilities::EFFECTSPORE),
+            PokemonName::ELDEGOSS => Some(Abilities::EFFECTSPORE),
+            PokemonName::WOOLOO => Some(Abilities::BULLETPROOF),
+            PokemonName::DUBWOOL => Some(Abilities::BULLETPROOF),
+            PokemonName::CHEWTLE => Some(Abilities::SWIFTSWIM),
+            PokemonName::DREDNAW => Some(Abilities::SWIFTSWIM),
+            PokemonName::DREDNAWGMAX => Some(Abilities::SWIFTSWIM),
+            PokemonName::YAMPER => Some(Abilities::RATTLED),
+            PokemonName::BOLTUND => Some(Abilities::COMPETITIVE),
+            PokemonName::ROLYCOLY => Some(Abilities::FLASHFIRE),
+            PokemonName::CARKOL => Some(Abilities::FLASHFIRE),
+            PokemonName::COALOSSAL => Some(Abilities::FLASHFIRE),
+            PokemonName::COALOSSALGMAX => Some(Abilities::FLASHFIRE),
+            PokemonName::APPLIN => Some(Abilities::BULLETPROOF),
+            PokemonName::FLAPPLE => Some(Abilities::HUSTLE),
+            PokemonName::FLAPPLEGMAX => Some(Abilities::HUSTLE),
+            PokemonName::APPLETUN => Some(Abilities::THICKFAT),
+            PokemonName::APPLETUNGMAX => Some(Abilities::THICKFAT),
+            PokemonName::SILICOBRA => Some(Abilities::SANDVEIL),
+            PokemonName::SANDACONDA => Some(Abilities::SANDVEIL),
+            PokemonName::SANDACONDAGMAX => Some(Abilities::SANDVEIL),
+            PokemonName::ARROKUDA => Some(Abilities::PROPELLERTAIL),
+            PokemonName::BARRASKEWDA => Some(Abilities::PROPELLERTAIL),
+            PokemonName::TOXEL => Some(Abilities::KLUTZ),
+            PokemonName::TOXTRICITY => Some(Abilities::TECHNICIAN),
+            PokemonName::TOXTRICITYLOWKEY => Some(Abilities::TECHNICIAN),
+            PokemonName::TOXTRICITYGMAX => Some(Abilities::TECHNICIAN),
+            PokemonName::TOXTRICITYLOWKEYGMAX => Some(Abilities::TECHNICIAN),
+            PokemonName::SIZZLIPEDE => Some(Abilities::FLAMEBODY),
+            PokemonName::CENTISKORCH => Some(Abilities::FLAMEBODY),
+            PokemonName::CENTISKORCHGMAX => Some(Abilities::FLAMEBODY),
+            PokemonName::CLOBBOPUS => Some(Abilities::TECHNICIAN),
+            PokemonName::GRAPPLOCT => Some(Abilities::TECHNICIAN),
+            PokemonName::SINISTEA => Some(Abilities::CURSEDBODY),
+            PokemonName::SINISTEAANTIQUE => Some(Abilities::CURSEDBODY),
+            PokemonName::POLTEAGEIST => Some(Abilities::CURSEDBODY),
+            PokemonName::POLTEAGEISTANTIQUE => Some(Abilities::CURSEDBODY),
+            PokemonName::HATENNA => Some(Abilities::MAGICBOUNCE),
+            PokemonName::HATTREM => Some(Abilities::MAGICBOUNCE),
+            PokemonName::HATTERENE => Some(Abilities::MAGICBOUNCE),
+            PokemonName::HATTERENEGMAX => Some(Abilities::MAGICBOUNCE),
+            PokemonName::IMPIDIMP => Some(Abilities::PICKPOCKET),
+            PokemonName::MORGREM => Some(Abilities::PICKPOCKET),
+            PokemonName::GRIMMSNARL => Some(Abilities::PICKPOCKET),
+            PokemonName::GRIMMSNARLGMAX => Some(Abilities::PICKPOCKET),
+            PokemonName::OBSTAGOON => Some(Abilities::DEFIANT),
+            PokemonName::PERRSERKER => Some(Abilities::STEELYSPIRIT),
+            PokemonName::CURSOLA => Some(Abilities::PERISHBODY),
+            PokemonName::SIRFETCHD => Some(Abilities::SCRAPPY),
+            PokemonName::MRRIME => Some(Abilities::ICEBODY),
+            PokemonName::MILCERY => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIE => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIERUBYCREAM => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIEMATCHACREAM => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIEMINTCREAM => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIELEMONCREAM => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIESALTEDCREAM => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIERUBYSWIRL => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIECARAMELSWIRL => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIERAINBOWSWIRL => Some(Abilities::AROMAVEIL),
+            PokemonName::ALCREMIEGMAX => Some(Abilities::AROMAVEIL),
+            PokemonName::FALINKS => Some(Abilities::DEFIANT),
+            PokemonName::PINCURCHIN => Some(Abilities::ELECTRICSURGE),
+            PokemonName::SNOM => Some(Abilities::ICESCALES),
+            PokemonName::FROSMOTH => Some(Abilities::ICESCALES),
+            PokemonName::INDEEDEE => Some(Abilities::PSYCHICSURGE),
+            PokemonName::INDEEDEEF => Some(Abilities::PSYCHICSURGE),
+            PokemonName::CUFANT => Some(Abilities::HEAVYMETAL),
+            PokemonName::COPPERAJAH => Some(Abilities::HEAVYMETAL),
+            PokemonName::COPPERAJAHGMAX => Some(Abilities::HEAVYMETAL),
+            PokemonName::DRACOZOLT => Some(Abilities::SANDRUSH),
+            PokemonName::ARCTOZOLT => Some(Abilities::SLUSHRUSH),
+            PokemonName::DRACOVISH => Some(Abilities::SANDRUSH),
+            PokemonName::ARCTOVISH => Some(Abilities::SLUSHRUSH),
+            PokemonName::DURALUDON => Some(Abilities::STALWART),
+            PokemonName::DURALUDONGMAX => Some(Abilities::STALWART),
+            PokemonName::DREEPY => Some(Abilities::CURSEDBODY),
+            PokemonName::DRAKLOAK => Some(Abilities::CURSEDBODY),
+            PokemonName::DRAGAPULT => Some(Abilities::CURSEDBODY),
+            PokemonName::WYRDEER => Some(Abilities::SAPSIPPER),
+            PokemonName::KLEAVOR => Some(Abilities::SHARPNESS),
+            PokemonName::URSALUNA => Some(Abilities::UNNERVE),
+            PokemonName::BASCULEGION => Some(Abilities::MOLDBREAKER),
+            PokemonName::BASCULEGIONF => Some(Abilities::MOLDBREAKER),
+            PokemonName::SNEASLER => Some(Abilities::POISONTOUCH),
+            PokemonName::OVERQWIL => Some(Abilities::INTIMIDATE),
+            PokemonName::ENAMORUS => Some(Abilities::CONTRARY),
+            PokemonName::SPRIGATITO => Some(Abilities::PROTEAN),
+            PokemonName::FLORAGATO => Some(Abilities::PROTEAN),
+            PokemonName::MEOWSCARADA => Some(Abilities::PROTEAN),
+            PokemonName::FUECOCO => Some(Abilities::UNAWARE),
+            PokemonName::CROCALOR => Some(Abilities::UNAWARE),
+            PokemonName::SKELEDIRGE => Some(Abilities::UNAWARE),
+            PokemonName::QUAXLY => Some(Abilities::MOXIE),
+            PokemonName::QUAXWELL => Some(Abilities::MOXIE),
+            PokemonName::QUAQUAVAL => Some(Abilities::MOXIE),
+            PokemonName::LECHONK => Some(Abilities::THICKFAT),
+            PokemonName::OINKOLOGNE => Some(Abilities::THICKFAT),
+            PokemonName::OINKOLOGNEF => Some(Abilities::THICKFAT),
+            PokemonName::TAROUNTULA => Some(Abilities::STAKEOUT),
+            PokemonName::SPIDOPS => Some(Abilities::STAKEOUT),
+            PokemonName::NYMBLE => Some(Abilities::TINTEDLENS),
+            PokemonName::LOKIX => Some(Abilities::TINTEDLENS),
+            PokemonName::PAWMI => Some(Abilities::IRONFIST),
+            PokemonName::PAWMO => Some(Abilities::IRONFIST),
+            PokemonName::PAWMOT => Some(Abilities::IRONFIST),
+            PokemonName::TANDEMAUS => Some(Abilities::OWNTEMPO),
+            PokemonName::MAUSHOLD => Some(Abilities::TECHNICIAN),
+            PokemonName::MAUSHOLDFOUR => Some(Abilities::TECHNICIAN),
+            PokemonName::FIDOUGH => Some(Abilities::KLUTZ),
+            PokemonName::DACHSBUN => Some(Abilities::AROMAVEIL),
+            PokemonName::SMOLIV => Some(Abilities::HARVEST),
+            PokemonName::DOLLIV => Some(Abilities::HARVEST),
+            PokemonName::ARBOLIVA => Some(Abilities::HARVEST),
+            PokemonName::SQUAWKABILLY => Some(Abilities::GUTS),
+            PokemonName::SQUAWKABILLYBLUE => Some(Abilities::GUTS),
+            PokemonName::SQUAWKABILLYYELLOW => Some(Abilities::SHEERFORCE),
+            PokemonName::SQUAWKABILLYWHITE => Some(Abilities::SHEERFORCE),
+            PokemonName::NACLI => Some(Abilities::CLEARBODY),
+            PokemonName::NACLSTACK => Some(Abilities::CLEARBODY),
+            PokemonName::GARGANACL => Some(Abilities::CLEARBODY),
+            PokemonName::CHARCADET => Some(Abilities::FLAMEBODY),
+            PokemonName::ARMAROUGE => Some(Abilities::WEAKARMOR),
+            PokemonName::CERULEDGE => Some(Abilities::WEAKARMOR),
+            PokemonName::TADBULB => Some(Abilities::DAMP),
+            PokemonName::BELLIBOLT => Some(Abilities::DAMP),
+            PokemonName::WATTREL => Some(Abilities::COMPETITIVE),
+            PokemonName::KILOWATTREL => Some(Abilities::COMPETITIVE),
+            PokemonName::MASCHIFF => Some(Abilities::STAKEOUT),
+            PokemonName::MABOSSTIFF => Some(Abilities::STAKEOUT),
+            PokemonName::SHROODLE => Some(Abilities::PRANKSTER),
+            PokemonName::GRAFAIAI => Some(Abilities::PRANKSTER),
+            PokemonName::BRAMBLIN => Some(Abilities::INFILTRATOR),
+            PokemonName::BRAMBLEGHAST => Some(Abilities::INFILTRATOR),
+            PokemonName::KLAWF => Some(Abilities::REGENERATOR),
+            PokemonName::CAPSAKID => Some(Abilities::KLUTZ),
+            PokemonName::SCOVILLAIN => Some(Abilities::MOODY),
+            PokemonName::RELLOR => Some(Abilities::SHEDSKIN),
+            PokemonName::RABSCA => Some(Abilities::TELEPATHY),
+            PokemonName::FLITTLE => Some(Abilities::SPEEDBOOST),
+            PokemonName::ESPATHRA => Some(Abilities::SPEEDBOOST),
+            PokemonName::TINKATINK => Some(Abilities::PICKPOCKET),
+            PokemonName::TINKATUFF => Some(Abilities::PICKPOCKET),
+            PokemonName::TINKATON => Some(Abilities::PICKPOCKET),
+            PokemonName::WIGLETT => Some(Abilities::SANDVEIL),
+            PokemonName::WUGTRIO => Some(Abilities::SANDVEIL),
+            PokemonName::BOMBIRDIER => Some(Abilities::ROCKYPAYLOAD),
+            PokemonName::VAROOM => Some(Abilities::SLOWSTART),
+            PokemonName::REVAVROOM => Some(Abilities::FILTER),
+            PokemonName::CYCLIZAR => Some(Abilities::REGENERATOR),
+            PokemonName::ORTHWORM => Some(Abilities::SANDVEIL),
+            PokemonName::GLIMMET => Some(Abilities::CORROSION),
+            PokemonName::GLIMMORA => Some(Abilities::CORROSION),
+            PokemonName::GREAVARD => Some(Abilities::FLUFFY),
+            PokemonName::HOUNDSTONE => Some(Abilities::FLUFFY),
+            PokemonName::FLAMIGO => Some(Abilities::COSTAR),
+            PokemonName::CETODDLE => Some(Abilities::SHEERFORCE),
+            PokemonName::CETITAN => Some(Abilities::SHEERFORCE),
+            PokemonName::VELUZA => Some(Abilities::SHARPNESS),
+            PokemonName::DONDOZO => Some(Abilities::WATERVEIL),
+            PokemonName::TATSUGIRI => Some(Abilities::STORMDRAIN),
+            PokemonName::TATSUGIRIDROOPY => Some(Abilities::STORMDRAIN),
+            PokemonName::TATSUGIRISTRETCHY => Some(Abilities::STORMDRAIN),
+            PokemonName::ANNIHILAPE => Some(Abilities::DEFIANT),
+            PokemonName::CLODSIRE => Some(Abilities::UNAWARE),
+            PokemonName::FARIGIRAF => Some(Abilities::SAPSIPPER),
+            PokemonName::DUDUNSPARCE => Some(Abilities::RATTLED),
+            PokemonName::DUDUNSPARCETHREESEGMENT => Some(Abilities::RATTLED),
+            PokemonName::KINGAMBIT => Some(Abilities::PRESSURE),
+            PokemonName::FRIGIBAX => Some(Abilities::ICEBODY),
+            PokemonName::ARCTIBAX => Some(Abilities::ICEBODY),
+            PokemonName::BAXCALIBUR => Some(Abilities::ICEBODY),
+            PokemonName::DIPPLIN => Some(Abilities::STICKYHOLD),
+            PokemonName::POLTCHAGEIST => Some(Abilities::HEATPROOF),
+            PokemonName::POLTCHAGEISTARTISAN => Some(Abilities::HEATPROOF),
+            PokemonName::SINISTCHA => Some(Abilities::HEATPROOF),
+            PokemonName::SINISTCHAMASTERPIECE => Some(Abilities::HEATPROOF),
+            PokemonName::OKIDOGI => Some(Abilities::GUARDDOG),
+            PokemonName::MUNKIDORI => Some(Abilities::FRISK),
+            PokemonName::FEZANDIPITI => Some(Abilities::TECHNICIAN),
+            PokemonName::ARCHALUDON => Some(Abilities::STALWART),
+            PokemonName::HYDRAPPLE => Some(Abilities::STICKYHOLD),
+            _ => None,
+        }
+    }
+
+    // Abilities that a pokemon of this species can legally have in the current generation
+    // Hidden abilities did not exist before gen5
+    pub fn abilities(&self) -> Vec<Abilities> {
+        let standard_abilities = self.standard_abilities().iter().copied();
+        #[cfg(feature = "gen4")]
+        return standard_abilities.collect();
+        #[cfg(not(feature = "gen4"))]
+        return standard_abilities.chain(self.hidden_ability()).collect();
+    }
+}
@@ -1,7 +1,7 @@
 use super::abilities::Abilities;
 use super::choice_effects::charge_volatile_to_choice;
 use super::items::Items;
-use crate::choices::{Choices, MoveCategory};
+use crate::choices::{Choice, Choices, MoveCategory, MOVES};
 use crate::define_enum_with_from_str;
 use crate::instruction::{
     ChangeSideConditionInstruction, ChangeStatInstruction, ChangeType,
@@ -10,15 +10,15 @@ use crate::instruction::{
 };
 use crate::pokemon::PokemonName;
 use crate::state::{
-    LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
-    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference, State,
+    pokemon_index_iter, LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex,
+    PokemonMoveIndex, PokemonNature, PokemonSideCondition, PokemonStatus, PokemonType, Side,
+    SideReference, State,
 };
 use core::panic;
 use std::collections::HashSet;
 
-fn common_pkmn_stat_calc(stat: u16, ev: u16, level: u16) -> u16 {
-    // 31 IV always used
-    ((2 * stat + 31 + (ev / 4)) * level) / 100
+fn common_pkmn_stat_calc(stat: u16, iv: u16, ev: u16, level: u16) -> u16 {
+    ((2 * stat + iv + (ev / 4)) * level) / 100
 }
 
 fn multiply_boost(boost_num: i8, stat_value: i16) -> i16 {
@@ -43,6 +43,8 @@ fn multiply_boost(boost_num: i8, stat_value: i16) -> i16 {
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum MoveChoice {
     MoveTera(PokemonMoveIndex),
+    MoveMega(PokemonMoveIndex),
+    MoveZ(PokemonMoveIndex),
     Move(PokemonMoveIndex),
     Switch(PokemonIndex),
     None,
@@ -54,6 +56,12 @@ impl MoveChoice {
             MoveChoice::MoveTera(index) => {
                 format!("{}-tera", side.get_active_immutable().moves[&index].id).to_lowercase()
             }
+            MoveChoice::MoveMega(index) => {
+                format!("{}-mega", side.get_active_immutable().moves[&index].id).to_lowercase()
+            }
+            MoveChoice::MoveZ(index) => {
+                format!("{}-z", side.get_active_immutable().moves[&index].id).to_lowercase()
+            }
             MoveChoice::Move(index) => {
                 format!("{}", side.get_active_immutable().moves[&index].id).to_lowercase()
             }
@@ -61,6 +69,33 @@ impl MoveChoice {
             MoveChoice::None => "No Move".to_string(),
         }
     }
+
+    /// The choice as a Pokemon Showdown `/choose` command, e.g. "move 2 terastallize" or "switch 4"
+    ///
+    /// Moves and pokemon are numbered by their slot in `side`, which must be in the same
+    /// order as Showdown's request. During team preview a switch chooses the lead and
+    /// the rest of the team keeps its order, e.g. "team 312456"
+    pub fn to_showdown_choice(&self, side: &Side, team_preview: bool) -> String {
+        match self {
+            MoveChoice::MoveTera(index) => format!("move {} terastallize", *index as u8 + 1),
+            MoveChoice::MoveMega(index) => format!("move {} mega", *index as u8 + 1),
+            MoveChoice::MoveZ(index) => format!("move {} zmove", *index as u8 + 1),
+            MoveChoice::Move(index) => format!("move {}", *index as u8 + 1),
+            MoveChoice::Switch(index) if team_preview => {
+                let mut order = vec![*index];
+                order.extend(pokemon_index_iter().filter(|pkmn_index| {
+                    pkmn_index != index && side.pokemon[*pkmn_index].id != PokemonName::NONE
+                }));
+                let order: String = order
+                    .iter()
+                    .map(|pkmn_index| (*pkmn_index as u8 + 1).to_string())
+                    .collect();
+                format!("team {}", order)
+            }
+            MoveChoice::Switch(index) => format!("switch {}", *index as u8 + 1),
+            MoveChoice::None => "default".to_string(),
+        }
+    }
     pub fn from_string(s: &str, side: &Side) -> Option<MoveChoice> {
         let s = s.to_lowercase();
         if s == "none" {
@@ -76,8 +111,8 @@ impl MoveChoice {
             }
         }
 
-        // check if s endswith `-tera`
-        // if it does, find the move with the name and return MoveChoice::MoveTera
+        // check if s endswith `-tera`, `-mega` or `-z`
+        // if it does, find the move with the name and return MoveChoice::MoveTera, MoveMega or MoveZ
         // if it doesn't, find the move with the name and return MoveChoice::Move
         let mut move_iter = side.get_active_immutable().moves.into_iter();
         let mut move_name = s;
@@ -88,6 +123,20 @@ impl MoveChoice {
                     return Some(MoveChoice::MoveTera(move_iter.pokemon_move_index));
                 }
             }
+        } else if move_name.ends_with("-mega") {
+            move_name = move_name[..move_name.len() - 5].to_string();
+            while let Some(mv) = move_iter.next() {
+                if format!("{:?}", mv.id).to_lowercase() == move_name {
+                    return Some(MoveChoice::MoveMega(move_iter.pokemon_move_index));
+                }
+            }
+        } else if move_name.ends_with("-z") {
+            move_name = move_name[..move_name.len() - 2].to_string();
+            while let Some(mv) = move_iter.next() {
+                if format!("{:?}", mv.id).to_lowercase() == move_name {
+                    return Some(MoveChoice::MoveZ(move_iter.pokemon_move_index));
+                }
+            }
         } else {
             while let Some(mv) = move_iter.next() {
                 if format!("{:?}", mv.id).to_lowercase() == move_name {
@@ -290,22 +339,102 @@ impl Pokemon {
     }
     pub fn calculate_stats_from_base_stats(&self) -> (i16, i16, i16, i16, i16, i16) {
         let base_stats = self.id.base_stats();
+        let level = self.level as u16;
+        let ivs = self.ivs;
+        let stat = |base: i16, iv: u8, ev: u8, stat_type: PokemonBoostableStat| -> i16 {
+            self.nature.modify_stat(
+                common_pkmn_stat_calc(base as u16, iv as u16, ev as u16, level) + 5,
+                stat_type,
+            ) as i16
+        };
+        let hp = if self.id == PokemonName::SHEDINJA {
+            1
+        } else {
+            (common_pkmn_stat_calc(base_stats.0 as u16, ivs.0 as u16, self.evs.0 as u16, level)
+                + level
+                + 10) as i16
+        };
         (
-            (common_pkmn_stat_calc(base_stats.0 as u16, self.evs.0 as u16, self.level as u16)
-                + self.level as u16
-                + 10) as i16,
-            (common_pkmn_stat_calc(base_stats.1 as u16, self.evs.1 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.2 as u16, self.evs.2 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.3 as u16, self.evs.3 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.4 as u16, self.evs.4 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.5 as u16, self.evs.5 as u16, self.level as u16) + 5)
-                as i16,
+            hp,
+            stat(
+                base_stats.1,
+                ivs.1,
+                self.evs.1,
+                PokemonBoostableStat::Attack,
+            ),
+            stat(
+                base_stats.2,
+                ivs.2,
+                self.evs.2,
+                PokemonBoostableStat::Defense,
+            ),
+            stat(
+                base_stats.3,
+                ivs.3,
+                self.evs.3,
+                PokemonBoostableStat::SpecialAttack,
+            ),
+            stat(
+                base_stats.4,
+                ivs.4,
+                self.evs.4,
+                PokemonBoostableStat::SpecialDefense,
+            ),
+            stat(base_stats.5, ivs.5, self.evs.5, PokemonBoostableStat::Speed),
         )
     }
+
+    // Changes the nature and EVs and recalculates stats, keeping the same percentage of hp
+    pub fn set_nature_and_evs(&mut self, nature: PokemonNature, evs: (u8, u8, u8, u8, u8, u8)) {
+        self.nature = nature;
+        self.evs = evs;
+        let stats = self.calculate_stats_from_base_stats();
+        if self.hp > 0 && self.maxhp > 0 {
+            let hp = (self.hp as f32 * stats.0 as f32 / self.maxhp as f32).round() as i16;
+            self.hp = hp.max(1);
+        }
+        self.maxhp = stats.0;
+        self.attack = stats.1;
+        self.defense = stats.2;
+        self.special_attack = stats.3;
+        self.special_defense = stats.4;
+        self.speed = stats.5;
+    }
+
+    // Builds a pokemon of the given species with its stats, types, weight and first legal ability
+    // filled in. Moves and item are left empty
+    pub fn from_species(
+        id: PokemonName,
+        level: i8,
+        evs: (u8, u8, u8, u8, u8, u8),
+        nature: PokemonNature,
+    ) -> Pokemon {
+        let types = id.types();
+        let ability = id.abilities().first().copied().unwrap_or(Abilities::NONE);
+        let mut pokemon = Pokemon {
+            id,
+            level,
+            types,
+            base_types: types,
+            ability,
+            base_ability: ability,
+            nature,
+            evs,
+            weight_kg: id.weight_kg(),
+            tera_type: types.0,
+            ..Default::default()
+        };
+        let stats = pokemon.calculate_stats_from_base_stats();
+        pokemon.hp = stats.0;
+        pokemon.maxhp = stats.0;
+        pokemon.attack = stats.1;
+        pokemon.defense = stats.2;
+        pokemon.special_attack = stats.3;
+        pokemon.special_defense = stats.4;
+        pokemon.speed = stats.5;
+        pokemon
+    }
+    #[allow(clippy::too_many_arguments)]
     pub fn add_available_moves(
         &self,
         vec: &mut Vec<MoveChoice>,
@@ -313,6 +442,8 @@ impl Pokemon {
         encored: bool,
         taunted: bool,
         can_tera: bool,
+        can_mega: bool,
+        can_z_move: bool,
     ) {
         let mut iter = self.moves.into_iter();
         while let Some(p) = iter.next() {
@@ -343,6 +474,12 @@ impl Pokemon {
                 if can_tera {
                     vec.push(MoveChoice::MoveTera(iter.pokemon_move_index));
                 }
+                if can_mega {
+                    vec.push(MoveChoice::MoveMega(iter.pokemon_move_index));
+                }
+                if can_z_move && self.z_move(&p.choice).is_some() {
+                    vec.push(MoveChoice::MoveZ(iter.pokemon_move_index));
+                }
             }
         }
     }
@@ -428,7 +565,47 @@ impl Pokemon {
                 self.id == PokemonName::OGERPONWELLSPRING
                     || self.id == PokemonName::OGERPONWELLSPRINGTERA
             }
-            _ => false,
+            _ if self.item.is_z_crystal() => true,
+            _ => match self.item.mega_evolution() {
+                Some((base_forme, mega_forme)) => self.id == base_forme || self.id == mega_forme,
+                None => false,
+            },
+        }
+    }
+
+    // the z-move `choice` becomes with this pokemon's z-crystal, if it can become one
+    pub fn z_move(&self, choice: &Choice) -> Option<Choice> {
+        if let Some((species, z_move)) = self.item.exclusive_z_move() {
+            if !species.contains(&self.id) || z_move.exclusive_z_move_base() != Some(choice.move_id)
+            {
+                return None;
+            }
+            let mut z_move = MOVES.get(&z_move).unwrap().clone();
+            z_move.move_index = choice.move_index;
+            z_move.z_move = true;
+            return Some(z_move);
+        }
+        match self.item.z_crystal_type() {
+            Some(z_crystal_type) if z_crystal_type == choice.move_type => Some(choice.to_z_move()),
+            _ => None,
+        }
+    }
+
+    // the forme this pokemon would become if it mega evolved
+    pub fn mega_evolution(&self) -> Option<PokemonName> {
+        if self.id == PokemonName::RAYQUAZA {
+            return match self
+                .moves
+                .into_iter()
+                .any(|mv| mv.id == Choices::DRAGONASCENT)
+            {
+                true => Some(PokemonName::RAYQUAZAMEGA),
+                false => None,
+            };
+        }
+        match self.item.mega_evolution() {
+            Some((base_forme, mega_forme)) if self.id == base_forme => Some(mega_forme),
+            _ => None,
         }
     }
 
@@ -666,6 +843,43 @@ impl Side {
         true
     }
 
+    #[cfg(not(any(feature = "gen6", feature = "gen7")))]
+    pub fn can_mega_evolve(&self) -> bool {
+        false
+    }
+
+    #[cfg(any(feature = "gen6", feature = "gen7"))]
+    pub fn can_mega_evolve(&self) -> bool {
+        !self.mega_evolved && self.get_active_immutable().mega_evolution().is_some()
+    }
+
+    pub fn protected(&self) -> bool {
+        self.volatile_statuses
+            .contains(&PokemonVolatileStatus::PROTECT)
+            || self
+                .volatile_statuses
+                .contains(&PokemonVolatileStatus::SPIKYSHIELD)
+            || self
+                .volatile_statuses
+                .contains(&PokemonVolatileStatus::BANEFULBUNKER)
+            || self
+                .volatile_statuses
+                .contains(&PokemonVolatileStatus::BURNINGBULWARK)
+            || self
+                .volatile_statuses
+                .contains(&PokemonVolatileStatus::SILKTRAP)
+    }
+
+    #[cfg(not(feature = "gen7"))]
+    pub fn can_use_z_move(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "gen7")]
+    pub fn can_use_z_move(&self) -> bool {
+        !self.z_move_used && self.get_active_immutable().item.is_z_crystal()
+    }
+
     pub fn add_switches(&self, vec: &mut Vec<MoveChoice>) {
         let mut iter = self.pokemon.into_iter();
         while let Some(p) = iter.next() {
@@ -741,7 +955,10 @@ impl State {
 
         if self.side_one.force_trapped {
             s1_options.retain(|x| match x {
-                MoveChoice::Move(_) | MoveChoice::MoveTera(_) => true,
+                MoveChoice::Move(_)
+                | MoveChoice::MoveTera(_)
+                | MoveChoice::MoveMega(_)
+                | MoveChoice::MoveZ(_) => true,
                 MoveChoice::Switch(_) => false,
                 MoveChoice::None => true,
             });
@@ -762,12 +979,17 @@ impl State {
                 encored,
                 taunted,
                 self.side_one.can_use_tera(),
+                self.side_one.can_mega_evolve(),
+                self.side_one.can_use_z_move(),
             );
         }
 
         if self.side_two.force_trapped {
             s2_options.retain(|x| match x {
-                MoveChoice::Move(_) | MoveChoice::MoveTera(_) => true,
+                MoveChoice::Move(_)
+                | MoveChoice::MoveTera(_)
+                | MoveChoice::MoveMega(_)
+                | MoveChoice::MoveZ(_) => true,
                 MoveChoice::Switch(_) => false,
                 MoveChoice::None => true,
             });
@@ -788,6 +1010,8 @@ impl State {
                 encored,
                 taunted,
                 self.side_two.can_use_tera(),
+                self.side_two.can_mega_evolve(),
+                self.side_two.can_use_z_move(),
             );
         }
 
@@ -876,6 +1100,8 @@ impl State {
                 encored,
                 taunted,
                 self.side_one.can_use_tera(),
+                self.side_one.can_mega_evolve(),
+                self.side_one.can_use_z_move(),
             );
             if !self.side_one.trapped(side_two_active) {
                 self.side_one.add_switches(&mut side_one_options);
@@ -905,6 +1131,8 @@ impl State {
                 encored,
                 taunted,
                 self.side_two.can_use_tera(),
+                self.side_two.can_mega_evolve(),
+                self.side_two.can_use_z_move(),
             );
             if !self.side_two.trapped(side_one_active) {
                 self.side_two.add_switches(&mut side_two_options);
@@ -980,6 +1208,19 @@ impl State {
                     side.volatile_status_durations.lockedmove = 0;
                     false
                 }
+                PokemonVolatileStatus::CONFUSION => {
+                    if side.volatile_status_durations.confusion != 0 {
+                        instructions.push(Instruction::ChangeVolatileStatusDuration(
+                            ChangeVolatileStatusDurationInstruction {
+                                side_ref: *side_ref,
+                                volatile_status: *pkmn_volatile_status,
+                                amount: -1 * side.volatile_status_durations.confusion,
+                            },
+                        ));
+                        side.volatile_status_durations.confusion = 0;
+                    }
+                    false
+                }
                 PokemonVolatileStatus::YAWN => {
                     instructions.push(Instruction::ChangeVolatileStatusDuration(
                         ChangeVolatileStatusDurationInstruction {
@@ -39,6 +39,55 @@ impl StateInstructions {
     }
 }
 
+/// Removes the branches with a percentage below `threshold` and scales the remaining ones up so
+/// the percentages add up to the same total as before. The most likely branch is never removed.
+/// Returns the percentage that was removed
+pub fn prune_state_instructions(
+    state_instructions: &mut Vec<StateInstructions>,
+    threshold: f32,
+) -> f32 {
+    if threshold <= 0.0 || state_instructions.len() < 2 {
+        return 0.0;
+    }
+    let most_likely = state_instructions
+        .iter()
+        .map(|si| si.percentage)
+        .fold(f32::MIN, f32::max);
+    let total: f32 = state_instructions.iter().map(|si| si.percentage).sum();
+    state_instructions.retain(|si| si.percentage >= threshold || si.percentage == most_likely);
+
+    let remaining: f32 = state_instructions.iter().map(|si| si.percentage).sum();
+    let pruned = total - remaining;
+    if pruned > 0.0 {
+        for si in state_instructions.iter_mut() {
+            si.update_percentage(total / remaining);
+        }
+    }
+    pruned
+}
+
+/// How much probability was removed by `prune_state_instructions` over a search
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PruningStats {
+    // the number of times instructions were generated for a pair of moves
+    pub move_pairs: u64,
+    pub pruned_percentage: f64,
+}
+
+impl PruningStats {
+    pub fn record(&mut self, pruned_percentage: f32) {
+        self.move_pairs += 1;
+        self.pruned_percentage += pruned_percentage as f64;
+    }
+
+    pub fn average_pruned_percentage(&self) -> f32 {
+        if self.move_pairs == 0 {
+            return 0.0;
+        }
+        (self.pruned_percentage / self.move_pairs as f64) as f32
+    }
+}
+
 // https://stackoverflow.com/questions/50686411/whats-the-usual-way-to-create-a-vector-of-different-structs
 #[derive(PartialEq, Clone)]
 pub enum Instruction {
@@ -57,6 +106,7 @@ pub enum Instruction {
     DecrementTerrainTurnsRemaining,
     ChangeType(ChangeType),
     ChangeAbility(ChangeAbilityInstruction),
+    ChangeBaseAbility(ChangeAbilityInstruction),
     ChangeItem(ChangeItemInstruction),
     ChangeAttack(ChangeStatInstruction),
     ChangeDefense(ChangeStatInstruction),
@@ -89,6 +139,8 @@ pub enum Instruction {
     ToggleSideOneForceSwitch,
     ToggleSideTwoForceSwitch,
     ToggleTerastallized(ToggleTerastallizedInstruction),
+    ToggleMegaEvolved(ToggleMegaEvolvedInstruction),
+    ToggleZMoveUsed(ToggleZMoveUsedInstruction),
 }
 
 impl fmt::Debug for Instruction {
@@ -181,6 +233,13 @@ impl fmt::Debug for Instruction {
             Instruction::ChangeAbility(c) => {
                 write!(f, "ChangeAbility {:?}: {:?}", c.side_ref, c.ability_change)
             }
+            Instruction::ChangeBaseAbility(c) => {
+                write!(
+                    f,
+                    "ChangeBaseAbility {:?}: {:?}",
+                    c.side_ref, c.ability_change
+                )
+            }
             Instruction::ChangeItem(c) => {
                 write!(
                     f,
@@ -282,6 +341,12 @@ impl fmt::Debug for Instruction {
             Instruction::ToggleTerastallized(s) => {
                 write!(f, "ToggleTerastallized {:?}", s.side_ref)
             }
+            Instruction::ToggleMegaEvolved(s) => {
+                write!(f, "ToggleMegaEvolved {:?}", s.side_ref)
+            }
+            Instruction::ToggleZMoveUsed(s) => {
+                write!(f, "ToggleZMoveUsed {:?}", s.side_ref)
+            }
             Instruction::SetLastUsedMove(s) => {
                 write!(
                     f,
@@ -550,6 +615,16 @@ pub struct ToggleTerastallizedInstruction {
     pub side_ref: SideReference,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct ToggleMegaEvolvedInstruction {
+    pub side_ref: SideReference,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ToggleZMoveUsedInstruction {
+    pub side_ref: SideReference,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ChangeType {
     pub side_ref: SideReference,
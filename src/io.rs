@@ -1,11 +1,13 @@
 use crate::choices::{Choice, Choices, MoveCategory, MOVES};
+use crate::damage_branching::{DamageBranching, DamageBranchingSchedule};
 use crate::engine::evaluate::evaluate;
 use crate::engine::generate_instructions::{
     calculate_both_damage_rolls, generate_instructions_from_move_pair,
+    generate_instructions_from_move_pair_with_pruning_stats,
 };
 use crate::engine::state::MoveChoice;
-use crate::instruction::{Instruction, StateInstructions};
-use crate::mcts::{perform_mcts, MctsResult};
+use crate::instruction::{Instruction, PruningStats, StateInstructions};
+use crate::mcts::{default_damage_branching, perform_mcts, MctsResult};
 use crate::search::{expectiminimax_search, iterative_deepen_expectiminimax, pick_safest};
 use crate::state::State;
 use clap::Parser;
@@ -37,6 +39,20 @@ enum SubCommand {
     MonteCarloTreeSearch(MonteCarloTreeSearch),
     CalculateDamage(CalculateDamage),
     GenerateInstructions(GenerateInstructions),
+    Validate(Validate),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    ImportTeam(ImportTeam),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    ExportTeam(ExportTeam),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    AnalyzeReplay(AnalyzeReplay),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    GenerateStates(GenerateStates),
+    Simulate(Simulate),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    Tournament(Tournament),
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    GenerateTrainingData(GenerateTrainingData),
 }
 
 #[derive(Parser)]
@@ -49,6 +65,13 @@ struct Expectiminimax {
 
     #[clap(short, long, default_value_t = 2)]
     depth: i8,
+
+    // the damage branching of each ply, i.e. "kill-no-kill,none"
+    #[clap(long, default_value = "none")]
+    damage_branching: String,
+    // drop branches less likely than this percentage
+    #[clap(long, default_value_t = 0.0)]
+    prune_threshold: f32,
 }
 
 #[derive(Parser)]
@@ -58,6 +81,13 @@ struct IterativeDeepening {
 
     #[clap(short, long, default_value_t = 5000)]
     time_to_search_ms: u64,
+
+    // the damage branching of each ply, i.e. "kill-no-kill,none"
+    #[clap(long, default_value = "none")]
+    damage_branching: String,
+    // drop branches less likely than this percentage
+    #[clap(long, default_value_t = 0.0)]
+    prune_threshold: f32,
 }
 
 #[derive(Parser)]
@@ -67,6 +97,13 @@ struct MonteCarloTreeSearch {
 
     #[clap(short, long, default_value_t = 5000)]
     time_to_search_ms: u64,
+
+    // the damage branching of each ply, i.e. "kill-no-kill,none"
+    #[clap(long, default_value = "kill-no-kill,kill-no-kill,none")]
+    damage_branching: String,
+    // drop branches less likely than this percentage
+    #[clap(long, default_value_t = 0.0)]
+    prune_threshold: f32,
 }
 
 #[derive(Parser)]
@@ -94,6 +131,174 @@ struct GenerateInstructions {
 
     #[clap(short = 't', long, required = true)]
     side_two_move: String,
+
+    // none, kill-no-kill, min-avg-max or all-rolls
+    #[clap(long, default_value = "kill-no-kill")]
+    damage_branching: String,
+    // drop branches less likely than this percentage
+    #[clap(long, default_value_t = 0.0)]
+    prune_threshold: f32,
+}
+
+#[derive(Parser)]
+struct Validate {
+    #[clap(short, long, required = true)]
+    state: String,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct ImportTeam {
+    // a file containing the team, or a team in Showdown's packed format
+    #[clap(short = 'o', long, required = true)]
+    side_one_team: String,
+
+    #[clap(short = 't', long, required = true)]
+    side_two_team: String,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct ExportTeam {
+    #[clap(short, long, required = true)]
+    state: String,
+
+    #[clap(long, required = false, default_value_t = false)]
+    side_two: bool,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct AnalyzeReplay {
+    // a Showdown replay saved as a .log, .json or .html file
+    #[clap(short, long, required = true)]
+    file: String,
+
+    #[clap(short, long, default_value = "p1")]
+    player: String,
+
+    // one of expectiminimax, iterative-deepening or mcts
+    #[clap(long, default_value = "expectiminimax")]
+    search: String,
+
+    #[clap(short, long, default_value_t = 2)]
+    depth: i8,
+
+    #[clap(short, long, default_value_t = 1000)]
+    time_to_search_ms: u64,
+
+    // defaults to 10 for expectiminimax and iterative-deepening, and 0.1 for mcts
+    #[clap(long)]
+    threshold: Option<f32>,
+
+    // the damage branching of each ply, i.e. "kill-no-kill,none". Defaults to none for
+    // expectiminimax and iterative-deepening, and kill-no-kill,kill-no-kill,none for mcts
+    #[clap(long)]
+    damage_branching: Option<String>,
+    // drop branches less likely than this percentage
+    #[clap(long, default_value_t = 0.0)]
+    prune_threshold: f32,
+
+    #[clap(long, default_value_t = false)]
+    json: bool,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct GenerateStates {
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    #[clap(short = 'n', long, default_value_t = 1)]
+    count: usize,
+
+    // randomize hp, statuses, boosts, side conditions, weather, terrain and trick room
+    #[clap(short, long, default_value_t = false)]
+    mid_battle: bool,
+}
+
+#[derive(Parser)]
+struct Simulate {
+    #[clap(short, long, required = true)]
+    state: String,
+
+    // random, greedy-damage, expectiminimax:<depth>, iterative-deepening:<ms> or mcts:<ms>
+    #[clap(long, default_value = "random")]
+    side_one: String,
+
+    #[clap(long, default_value = "random")]
+    side_two: String,
+
+    #[clap(long, default_value_t = 100)]
+    max_turns: u16,
+
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    #[clap(long, default_value_t = false)]
+    json: bool,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct Tournament {
+    // comma separated, e.g. "mcts:500,expectiminimax:2,random"
+    #[clap(short, long, required = true)]
+    agents: String,
+
+    // a file of Showdown teams, each starting with a "=== [format] name ===" header
+    #[clap(short, long, required = true)]
+    teams: String,
+
+    #[clap(short, long, default_value_t = 10)]
+    games: usize,
+
+    #[clap(long, default_value_t = 100)]
+    max_turns: u16,
+
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    #[clap(long, default_value_t = false)]
+    json: bool,
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+#[derive(Parser)]
+struct GenerateTrainingData {
+    #[clap(short, long, required = true)]
+    output_dir: String,
+
+    #[clap(short = 'n', long, default_value_t = 1)]
+    games: usize,
+
+    // start every game from this state, otherwise from a random state or from --teams
+    #[clap(short, long)]
+    state: Option<String>,
+
+    // a file of Showdown teams, each starting with a "=== [format] name ===" header
+    #[clap(short, long)]
+    teams: Option<String>,
+
+    #[clap(long, default_value_t = 100)]
+    search_time_ms: u64,
+
+    // 0 always plays the most visited move, 1 samples moves in proportion to their visits
+    #[clap(long, default_value_t = 1.0)]
+    temperature: f32,
+
+    #[clap(long, default_value_t = 100)]
+    max_turns: u16,
+
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    // jsonl or binary
+    #[clap(short, long, default_value = "jsonl")]
+    format: String,
+
+    #[clap(long, default_value_t = 10000)]
+    examples_per_shard: usize,
 }
 
 impl Default for IOData {
@@ -246,6 +451,24 @@ fn print_subcommand_result(
     println!("evaluation: {}", safest.1);
 }
 
+fn print_pruning_stats(pruning_stats: &PruningStats) {
+    println!(
+        "pruned: {:.4}% on average over {} move pairs",
+        pruning_stats.average_pruned_percentage(),
+        pruning_stats.move_pairs
+    );
+}
+
+fn parse_damage_branching(damage_branching: &str) -> DamageBranchingSchedule {
+    match DamageBranchingSchedule::from_str(damage_branching) {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    }
+}
+
 pub fn main() {
     let args = Cli::parse();
     let mut io_data = IOData::default();
@@ -259,6 +482,7 @@ pub fn main() {
     let mut state;
     let mut side_one_options;
     let mut side_two_options;
+    let mut pruning_stats = PruningStats::default();
     match args.subcmd {
         None => {
             command_loop(io_data);
@@ -275,19 +499,31 @@ pub fn main() {
                     side_two_options.clone(),
                     expectiminimax.ab_prune,
                     &Arc::new(Mutex::new(true)),
+                    &parse_damage_branching(&expectiminimax.damage_branching),
+                    expectiminimax.prune_threshold,
+                    &mut pruning_stats,
                 );
                 print_subcommand_result(&result, &side_one_options, &side_two_options, &state);
+                if expectiminimax.prune_threshold > 0.0 {
+                    print_pruning_stats(&pruning_stats);
+                }
             }
             SubCommand::IterativeDeepening(iterative_deepending) => {
                 state = State::deserialize(iterative_deepending.state.as_str());
                 (side_one_options, side_two_options) = state.root_get_all_options();
-                (side_one_options, side_two_options, result, _) = iterative_deepen_expectiminimax(
-                    &mut state,
-                    side_one_options.clone(),
-                    side_two_options.clone(),
-                    std::time::Duration::from_millis(iterative_deepending.time_to_search_ms),
-                );
+                (side_one_options, side_two_options, result, _, pruning_stats) =
+                    iterative_deepen_expectiminimax(
+                        &mut state,
+                        side_one_options.clone(),
+                        side_two_options.clone(),
+                        std::time::Duration::from_millis(iterative_deepending.time_to_search_ms),
+                        &parse_damage_branching(&iterative_deepending.damage_branching),
+                        iterative_deepending.prune_threshold,
+                    );
                 print_subcommand_result(&result, &side_one_options, &side_two_options, &state);
+                if iterative_deepending.prune_threshold > 0.0 {
+                    print_pruning_stats(&pruning_stats);
+                }
             }
             SubCommand::MonteCarloTreeSearch(mcts) => {
                 state = State::deserialize(mcts.state.as_str());
@@ -297,7 +533,12 @@ pub fn main() {
                     side_one_options.clone(),
                     side_two_options.clone(),
                     std::time::Duration::from_millis(mcts.time_to_search_ms),
+                    &parse_damage_branching(&mcts.damage_branching),
+                    mcts.prune_threshold,
                 );
+                if mcts.prune_threshold > 0.0 {
+                    print_pruning_stats(&result.pruning_stats);
+                }
                 print_mcts_result(&state, result);
             }
             SubCommand::CalculateDamage(calculate_damage) => {
@@ -321,6 +562,14 @@ pub fn main() {
             }
             SubCommand::GenerateInstructions(generate_instructions) => {
                 state = State::deserialize(generate_instructions.state.as_str());
+                let damage_branching =
+                    match DamageBranching::from_str(&generate_instructions.damage_branching) {
+                        Ok(damage_branching) => damage_branching,
+                        Err(e) => {
+                            println!("{}", e);
+                            exit(1);
+                        }
+                    };
                 let (s1_movechoice, s2_movechoice);
                 match MoveChoice::from_string(
                     generate_instructions.side_one_move.as_str(),
@@ -348,13 +597,78 @@ pub fn main() {
                     }
                     Some(v) => s2_movechoice = v,
                 }
-                let instructions = generate_instructions_from_move_pair(
+                let instructions = generate_instructions_from_move_pair_with_pruning_stats(
                     &mut state,
                     &s1_movechoice,
                     &s2_movechoice,
-                    true,
+                    damage_branching,
+                    generate_instructions.prune_threshold,
+                    &mut pruning_stats,
                 );
                 pprint_state_instruction_vector(&instructions);
+                if generate_instructions.prune_threshold > 0.0 {
+                    print_pruning_stats(&pruning_stats);
+                }
+            }
+            SubCommand::Validate(validate) => {
+                state = State::deserialize(validate.state.as_str());
+                if print_validation_issues(&state) {
+                    exit(1);
+                }
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::ImportTeam(import_team) => {
+                state = State::default();
+                for (side, team) in [
+                    (&mut state.side_one, &import_team.side_one_team),
+                    (&mut state.side_two, &import_team.side_two_team),
+                ] {
+                    let team = std::fs::read_to_string(team).unwrap_or(team.to_string());
+                    match crate::state::Side::from_showdown_team(&team) {
+                        Ok(s) => *side = s,
+                        Err(e) => {
+                            println!("Invalid team: {}", e);
+                            exit(1);
+                        }
+                    }
+                }
+                state.set_conditional_mechanics();
+                state.refresh_zobrist_hash();
+                println!("{}", state.serialize());
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::AnalyzeReplay(analyze_replay) => {
+                analyze_replay_io(analyze_replay);
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::GenerateStates(generate_states) => {
+                let mut generator = crate::random_state::RandomStateGenerator::new(
+                    generate_states.seed,
+                    generate_states.mid_battle,
+                );
+                for _ in 0..generate_states.count {
+                    println!("{}", generator.random_state().serialize());
+                }
+            }
+            SubCommand::Simulate(simulate) => {
+                simulate_io(simulate);
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::Tournament(tournament) => {
+                tournament_io(tournament);
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::GenerateTrainingData(generate_training_data) => {
+                generate_training_data_io(generate_training_data);
+            }
+            #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+            SubCommand::ExportTeam(export_team) => {
+                state = State::deserialize(export_team.state.as_str());
+                if export_team.side_two {
+                    println!("{}", state.side_two.to_showdown_team());
+                } else {
+                    println!("{}", state.side_one.to_showdown_team());
+                }
             }
         },
     }
@@ -362,6 +676,253 @@ pub fn main() {
     exit(0);
 }
 
+fn simulate_io(args: Simulate) {
+    let result = match crate::simulator::simulate_battle_from_str(
+        State::deserialize(args.state.as_str()),
+        &args.side_one,
+        &args.side_two,
+        args.max_turns,
+        args.seed,
+    ) {
+        Ok(result) => result,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    };
+
+    if args.json {
+        println!("{}", result.to_json());
+        return;
+    }
+    for turn in result.turns.iter() {
+        println!("{}", turn);
+    }
+    match result.winner {
+        Some(crate::state::SideReference::SideOne) => println!("side one won"),
+        Some(crate::state::SideReference::SideTwo) => println!("side two won"),
+        None => println!("no winner after {} turns", args.max_turns),
+    }
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+fn tournament_io(args: Tournament) {
+    use crate::state::Side;
+
+    let teams = match std::fs::read_to_string(&args.teams)
+        .map_err(|e| format!("could not read {}: {}", args.teams, e))
+        .and_then(|contents| Side::from_showdown_teams(&contents))
+    {
+        Ok(teams) => teams,
+        Err(e) => {
+            println!("Invalid teams: {}", e);
+            exit(1);
+        }
+    };
+    let mut tournament = crate::tournament::Tournament::new(
+        args.agents
+            .split(',')
+            .map(|a| a.trim().to_string())
+            .collect(),
+        teams,
+    );
+    tournament.games_per_matchup = args.games;
+    tournament.max_turns = args.max_turns;
+    tournament.seed = args.seed;
+
+    let result = tournament.play(|game| {
+        if !args.json {
+            println!(
+                "{} vs {}: {}",
+                tournament.agents[game.side_one_agent],
+                tournament.agents[game.side_two_agent],
+                match game.winner {
+                    Some(crate::state::SideReference::SideOne) => "side one won",
+                    Some(crate::state::SideReference::SideTwo) => "side two won",
+                    None => "draw",
+                }
+            );
+        }
+    });
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    };
+
+    if args.json {
+        println!("{}", result.to_json());
+        return;
+    }
+    println!(
+        "\n{: <24}{: >8}{: >8}{: >8}{: >8}{: >11}  {: <16}{: >8}",
+        "agent", "games", "wins", "losses", "draws", "win rate", "95% CI", "elo"
+    );
+    for standing in result.standings.iter() {
+        println!("{}", standing);
+    }
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+fn generate_training_data_io(args: GenerateTrainingData) {
+    use crate::random_state::RandomStateGenerator;
+    use crate::state::Side;
+    use crate::training_data::{self_play, ShardedWriter, TrainingDataFormat};
+    use rand::SeedableRng;
+
+    let teams = match &args.teams {
+        Some(file) => match std::fs::read_to_string(file)
+            .map_err(|e| format!("could not read {}: {}", file, e))
+            .and_then(|contents| Side::from_showdown_teams(&contents))
+        {
+            Ok(teams) => teams,
+            Err(e) => {
+                println!("Invalid teams: {}", e);
+                exit(1);
+            }
+        },
+        None => vec![],
+    };
+    let mut writer = match TrainingDataFormat::from_str(&args.format)
+        .and_then(|format| ShardedWriter::new(&args.output_dir, format, args.examples_per_shard))
+    {
+        Ok(writer) => writer,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    };
+
+    let mut generator = RandomStateGenerator::new(args.seed, false);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(args.seed);
+    let mut example_count = 0;
+    for game in 0..args.games {
+        let state = match (&args.state, teams.len()) {
+            (Some(state), _) => State::deserialize(state),
+            (None, 0) => generator.random_state(),
+            (None, team_count) => {
+                let mut state = State {
+                    side_one: teams[game % team_count].clone(),
+                    side_two: teams[(game + game / team_count + 1) % team_count].clone(),
+                    ..State::default()
+                };
+                state.set_conditional_mechanics();
+                state.refresh_zobrist_hash();
+                state
+            }
+        };
+        let examples = self_play(
+            state,
+            std::time::Duration::from_millis(args.search_time_ms),
+            args.max_turns,
+            args.temperature,
+            &mut rng,
+        );
+        for example in examples.iter() {
+            if let Err(e) = writer.write(example) {
+                println!("{}", e);
+                exit(1);
+            }
+        }
+        example_count += examples.len();
+        println!(
+            "game {}: {} examples, outcome {}",
+            game + 1,
+            examples.len(),
+            examples.first().map_or(0.0, |e| e.outcome)
+        );
+    }
+    if let Err(e) = writer.flush() {
+        println!("{}", e);
+        exit(1);
+    }
+    println!(
+        "{} examples written to {} shards in {}",
+        example_count,
+        writer.shards.len(),
+        args.output_dir
+    );
+}
+
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+fn analyze_replay_io(args: AnalyzeReplay) {
+    use crate::replay::{analyze_replay, replay_log, ReplaySearch};
+
+    let search = match args.search.as_str() {
+        "expectiminimax" => ReplaySearch::Expectiminimax(args.depth),
+        "iterative-deepening" => ReplaySearch::IterativeDeepening(
+            std::time::Duration::from_millis(args.time_to_search_ms),
+        ),
+        "mcts" => ReplaySearch::MonteCarloTreeSearch(std::time::Duration::from_millis(
+            args.time_to_search_ms,
+        )),
+        _ => {
+            println!("Invalid search: {}", args.search);
+            exit(1);
+        }
+    };
+    let threshold = args.threshold.unwrap_or(search.default_threshold());
+    let damage_branching = match &args.damage_branching {
+        Some(damage_branching) => parse_damage_branching(damage_branching),
+        None => search.default_damage_branching(),
+    };
+    let reports = match std::fs::read_to_string(&args.file)
+        .map_err(|e| format!("could not read {}: {}", args.file, e))
+        .and_then(|contents| replay_log(&contents))
+        .and_then(|log| {
+            analyze_replay(
+                &log,
+                &args.player,
+                &search,
+                &damage_branching,
+                args.prune_threshold,
+                threshold,
+            )
+        }) {
+        Ok(reports) => reports,
+        Err(e) => {
+            println!("Invalid replay: {}", e);
+            exit(1);
+        }
+    };
+
+    if args.json {
+        let turns: Vec<serde_json::Value> = reports.iter().map(|r| r.to_json()).collect();
+        println!(
+            "{}",
+            serde_json::json!({
+                "player": args.player,
+                "search": args.search,
+                "threshold": threshold,
+                "turns": turns,
+            })
+        );
+        return;
+    }
+    for report in reports.iter() {
+        println!("{}", report);
+    }
+    println!(
+        "{} turns analyzed, {} blunders",
+        reports.len(),
+        reports.iter().filter(|r| r.blunder).count()
+    );
+}
+
+// returns true if any of the issues found are errors
+fn print_validation_issues(state: &State) -> bool {
+    let issues = state.validate();
+    if issues.is_empty() {
+        println!("state is valid");
+    }
+    for issue in issues.iter() {
+        println!("{}", issue);
+    }
+    issues.iter().any(|issue| issue.is_error())
+}
+
 fn calculate_damage_io(
     state: &State,
     s1_choice: Choice,
@@ -424,6 +985,9 @@ fn command_loop(mut io_data: IOData) {
             "serialize" | "ser" => {
                 println!("{}", io_data.state.serialize());
             }
+            "validate" | "v" => {
+                print_validation_issues(&io_data.state);
+            }
             "matchup" | "m" => {
                 println!("{}", io_data.state.pprint());
             }
@@ -440,7 +1004,7 @@ fn command_loop(mut io_data: IOData) {
                         }
                     },
                     None => {
-                        println!("Usage: generate-instructions <side-1 move> <side-2 move>");
+                        println!("Usage: generate-instructions <side-1 move> <side-2 move> [damage-branching]");
                         continue;
                     }
                 }
@@ -455,15 +1019,26 @@ fn command_loop(mut io_data: IOData) {
                         }
                     },
                     None => {
-                        println!("Usage: generate-instructions <side-1 choice> <side-2 choice>");
+                        println!("Usage: generate-instructions <side-1 choice> <side-2 choice> [damage-branching]");
                         continue;
                     }
                 }
+                let damage_branching = match args.next() {
+                    Some(s) => match DamageBranching::from_str(s) {
+                        Ok(damage_branching) => damage_branching,
+                        Err(e) => {
+                            println!("{}", e);
+                            continue;
+                        }
+                    },
+                    None => DamageBranching::KillNoKill,
+                };
                 let instructions = generate_instructions_from_move_pair(
                     &mut io_data.state,
                     &s1_move,
                     &s2_move,
-                    true,
+                    damage_branching,
+                    0.0,
                 );
                 pprint_state_instruction_vector(&instructions);
                 io_data.last_instructions_generated = instructions;
@@ -524,12 +1099,14 @@ fn command_loop(mut io_data: IOData) {
                     let (side_one_options, side_two_options) = io_data.state.root_get_all_options();
 
                     let start_time = std::time::Instant::now();
-                    let (s1_moves, s2_moves, result, depth_searched) =
+                    let (s1_moves, s2_moves, result, depth_searched, _) =
                         iterative_deepen_expectiminimax(
                             &mut io_data.state,
                             side_one_options.clone(),
                             side_two_options.clone(),
                             std::time::Duration::from_millis(max_time_ms),
+                            &DamageBranching::None.into(),
+                            0.0,
                         );
                     let elapsed = start_time.elapsed();
 
@@ -561,6 +1138,8 @@ fn command_loop(mut io_data: IOData) {
                         side_one_options.clone(),
                         side_two_options.clone(),
                         std::time::Duration::from_millis(max_time_ms),
+                        &default_damage_branching(),
+                        0.0,
                     );
                     let elapsed = start_time.elapsed();
                     pprint_mcts_result(&io_data.state, result);
@@ -621,6 +1200,9 @@ fn command_loop(mut io_data: IOData) {
                         side_two_options.clone(),
                         ab_prune,
                         &Arc::new(Mutex::new(true)),
+                        &DamageBranching::None.into(),
+                        0.0,
+                        &mut PruningStats::default(),
                     );
                     let elapsed = start_time.elapsed();
 
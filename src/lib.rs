@@ -15,13 +15,37 @@ pub mod engine;
 #[path = "genx/mod.rs"]
 pub mod engine;
 
+pub mod agent;
 pub mod choices;
+pub mod damage_branching;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod doubles;
 pub mod instruction;
 pub mod io;
 pub mod mcts;
 pub mod pokemon;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod protocol;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod random_state;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod replay;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod reverse_damage_calc;
 pub mod search;
+#[cfg(not(feature = "gen1"))]
+pub mod showdown_names;
+#[cfg(not(feature = "gen1"))]
+pub mod showdown_team;
+pub mod simulator;
 pub mod state;
+pub mod state_diff;
+pub mod state_validation;
+pub mod tournament;
+pub mod training_data;
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub mod usage_statistics;
+pub mod zobrist;
 
 #[macro_export]
 macro_rules! assert_unique_feature {
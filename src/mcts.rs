@@ -1,7 +1,8 @@
+use crate::damage_branching::{DamageBranching, DamageBranchingSchedule};
 use crate::engine::evaluate::evaluate;
-use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+use crate::engine::generate_instructions::generate_instructions_from_move_pair_with_pruning_stats;
 use crate::engine::state::MoveChoice;
-use crate::instruction::StateInstructions;
+use crate::instruction::{PruningStats, StateInstructions};
 use crate::state::State;
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
@@ -14,10 +15,20 @@ fn sigmoid(x: f32) -> f32 {
     1.0 / (1.0 + (-0.0125 * x).exp())
 }
 
+// Branching on damage is only worth it close to the root where nodes are visited many times
+pub fn default_damage_branching() -> DamageBranchingSchedule {
+    DamageBranchingSchedule::new(vec![
+        DamageBranching::KillNoKill,
+        DamageBranching::KillNoKill,
+        DamageBranching::None,
+    ])
+}
+
 #[derive(Debug)]
 pub struct Node {
     pub root: bool,
     pub parent: *mut Node,
+    pub depth: usize,
     pub children: HashMap<(usize, usize), Vec<Node>>,
     pub times_visited: i64,
 
@@ -54,6 +65,7 @@ impl Node {
         Node {
             root: false,
             parent: std::ptr::null_mut(),
+            depth: 0,
             instructions: StateInstructions::default(),
             times_visited: 0,
             children: HashMap::new(),
@@ -101,7 +113,7 @@ impl Node {
             .map(|x| x.instructions.percentage as f64)
             .collect();
         let dist = WeightedIndex::new(weights).unwrap();
-        let chosen_node = &mut (*move_vector)[dist.sample(&mut rng)];
+        let chosen_node = &mut (&mut *move_vector)[dist.sample(&mut rng)];
         let chosen_node_ptr = chosen_node as *mut Node;
         chosen_node_ptr
     }
@@ -111,6 +123,9 @@ impl Node {
         state: &mut State,
         s1_move_index: usize,
         s2_move_index: usize,
+        damage_branching: &DamageBranchingSchedule,
+        prune_threshold: f32,
+        pruning_stats: &mut PruningStats,
     ) -> *mut Node {
         let s1_move = &self.s1_options[s1_move_index].move_choice;
         let s2_move = &self.s2_options[s2_move_index].move_choice;
@@ -120,9 +135,14 @@ impl Node {
         {
             return self as *mut Node;
         }
-        let should_branch_on_damage = self.root || (*self.parent).root;
-        let mut new_instructions =
-            generate_instructions_from_move_pair(state, s1_move, s2_move, should_branch_on_damage);
+        let mut new_instructions = generate_instructions_from_move_pair_with_pruning_stats(
+            state,
+            s1_move,
+            s2_move,
+            damage_branching.at_ply(self.depth),
+            prune_threshold,
+            pruning_stats,
+        );
         let mut this_pair_vec = Vec::with_capacity(new_instructions.len());
         for state_instructions in new_instructions.drain(..) {
             state.apply_instructions(&state_instructions.instruction_list);
@@ -131,6 +151,7 @@ impl Node {
 
             let mut new_node = Node::new(s1_options, s2_options);
             new_node.parent = self;
+            new_node.depth = self.depth + 1;
             new_node.instructions = state_instructions;
             new_node.s1_choice = s1_move_index;
             new_node.s2_choice = s2_move_index;
@@ -153,11 +174,11 @@ impl Node {
             return;
         }
 
-        let parent_s1_movenode = &mut (*self.parent).s1_options[self.s1_choice];
+        let parent_s1_movenode = &mut (&mut (*self.parent).s1_options)[self.s1_choice];
         parent_s1_movenode.total_score += score;
         parent_s1_movenode.visits += 1;
 
-        let parent_s2_movenode = &mut (*self.parent).s2_options[self.s2_choice];
+        let parent_s2_movenode = &mut (&mut (*self.parent).s2_options)[self.s2_choice];
         parent_s2_movenode.total_score += 1.0 - score;
         parent_s2_movenode.visits += 1;
 
@@ -223,11 +244,28 @@ pub struct MctsResult {
     pub s1: Vec<MctsSideResult>,
     pub s2: Vec<MctsSideResult>,
     pub iteration_count: i64,
+    pub pruning_stats: PruningStats,
 }
 
-fn do_mcts(root_node: &mut Node, state: &mut State, root_eval: &f32) {
+fn do_mcts(
+    root_node: &mut Node,
+    state: &mut State,
+    root_eval: &f32,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
+) {
     let (mut new_node, s1_move, s2_move) = unsafe { root_node.selection(state) };
-    new_node = unsafe { (*new_node).expand(state, s1_move, s2_move) };
+    new_node = unsafe {
+        (*new_node).expand(
+            state,
+            s1_move,
+            s2_move,
+            damage_branching,
+            prune_threshold,
+            pruning_stats,
+        )
+    };
     let rollout_result = unsafe { (*new_node).rollout(state, root_eval) };
     unsafe { (*new_node).backpropagate(rollout_result, state) }
 }
@@ -237,7 +275,10 @@ pub fn perform_mcts(
     side_one_options: Vec<MoveChoice>,
     side_two_options: Vec<MoveChoice>,
     max_time: Duration,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
 ) -> MctsResult {
+    let mut pruning_stats = PruningStats::default();
     let mut root_node = Node::new(side_one_options, side_two_options);
     root_node.root = true;
 
@@ -245,7 +286,14 @@ pub fn perform_mcts(
     let start_time = std::time::Instant::now();
     while start_time.elapsed() < max_time {
         for _ in 0..1000 {
-            do_mcts(&mut root_node, state, &root_eval);
+            do_mcts(
+                &mut root_node,
+                state,
+                &root_eval,
+                damage_branching,
+                prune_threshold,
+                &mut pruning_stats,
+            );
         }
 
         /*
@@ -285,6 +333,7 @@ pub fn perform_mcts(
             })
             .collect(),
         iteration_count: root_node.times_visited,
+        pruning_stats,
     };
 
     result
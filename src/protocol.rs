@@ -0,0 +1,1016 @@
+use crate::choices::Choices;
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::engine::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
+use crate::pokemon::PokemonName;
+use crate::showdown_team::{
+    parse_ability, parse_item, parse_move, parse_species, parse_type, to_id,
+};
+use crate::state::{
+    pokemon_index_iter, LastUsedMove, Move, Pokemon, PokemonBoostableStat, PokemonIndex,
+    PokemonMoveIndex, PokemonNature, PokemonSideCondition, PokemonStatus, PokemonType, Side,
+    SideReference, State, VolatileStatusDurations,
+};
+use serde_json::Value;
+use std::str::FromStr;
+
+// Volatile statuses that Showdown removes at the end of the turn without a message
+const SINGLE_TURN_VOLATILE_STATUSES: [PokemonVolatileStatus; 14] = [
+    PokemonVolatileStatus::BANEFULBUNKER,
+    PokemonVolatileStatus::BURNINGBULWARK,
+    PokemonVolatileStatus::ENDURE,
+    PokemonVolatileStatus::FLINCH,
+    PokemonVolatileStatus::FOLLOWME,
+    PokemonVolatileStatus::HELPINGHAND,
+    PokemonVolatileStatus::KINGSSHIELD,
+    PokemonVolatileStatus::MAGICCOAT,
+    PokemonVolatileStatus::MAXGUARD,
+    PokemonVolatileStatus::PROTECT,
+    PokemonVolatileStatus::RAGEPOWDER,
+    PokemonVolatileStatus::ROOST,
+    PokemonVolatileStatus::SILKTRAP,
+    PokemonVolatileStatus::SPIKYSHIELD,
+];
+
+// Side conditions that last a number of turns rather than having layers
+const TIMED_SIDE_CONDITIONS: [PokemonSideCondition; 7] = [
+    PokemonSideCondition::AuroraVeil,
+    PokemonSideCondition::LightScreen,
+    PokemonSideCondition::LuckyChant,
+    PokemonSideCondition::Mist,
+    PokemonSideCondition::Reflect,
+    PokemonSideCondition::Safeguard,
+    PokemonSideCondition::Tailwind,
+];
+
+/// A battle seen from one player's perspective, kept up to date from
+/// Pokemon Showdown's battle protocol
+///
+/// `state.side_one` is always the player and `state.side_two` is their opponent.
+///
+/// The opponent's pokemon are added as they are revealed with a standard spread,
+/// their first legal ability and an unknown item. Their moves, items and abilities
+/// are filled in as they are revealed and their HP is converted from the displayed
+/// percentage to the pokemon's calculated max HP
+///
+/// ```
+/// use poke_engine::choices::Choices;
+/// use poke_engine::engine::items::Items;
+/// use poke_engine::protocol::ShowdownBattle;
+///
+/// let mut battle = ShowdownBattle::new("p1");
+/// battle.parse_message(
+///     "|switch|p2a: Chomp|Garchomp, L100, F|100/100
+///     |move|p2a: Chomp|Earthquake|p1a: Pikachu
+///     |-damage|p2a: Chomp|90/100|[from] item: Life Orb",
+/// ).unwrap();
+///
+/// let garchomp = battle.state.side_two.get_active_immutable();
+/// assert_eq!(Choices::EARTHQUAKE, garchomp.moves.m0.id);
+/// assert_eq!(Items::LIFEORB, garchomp.item);
+/// assert_eq!(340, garchomp.hp); // 90% of 378
+/// ```
+pub struct ShowdownBattle {
+    pub state: State,
+    pub player_id: String,
+    pub turn: u16,
+    pub ended: bool,
+    pub winner: Option<String>,
+    pub request: Option<ShowdownRequest>,
+    // Showdown identifies pokemon by nickname. Indexed in the same order as `SidePokemon`
+    side_one_nicknames: [String; 6],
+    side_two_nicknames: [String; 6],
+}
+
+fn empty_side() -> Side {
+    let mut side = Side::default();
+    for pkmn_index in pokemon_index_iter() {
+        side.pokemon[pkmn_index] = Pokemon {
+            hp: 0,
+            ..Pokemon::default()
+        };
+    }
+    side
+}
+
+fn new_pokemon(species: PokemonName, level: i8) -> Pokemon {
+    let mut pkmn = Pokemon::from_species(
+        species,
+        level,
+        (85, 85, 85, 85, 85, 85),
+        PokemonNature::SERIOUS,
+    );
+    pkmn.item = Items::UNKNOWNITEM;
+    // moves that have not been revealed cannot be chosen
+    for mv in [
+        &mut pkmn.moves.m0,
+        &mut pkmn.moves.m1,
+        &mut pkmn.moves.m2,
+        &mut pkmn.moves.m3,
+    ] {
+        mv.disabled = true;
+        mv.pp = 0;
+    }
+    pkmn
+}
+
+// e.g. "Garchomp, L78, F, shiny" or "Urshifu-*"
+fn parse_details(details: &str) -> Result<(PokemonName, i8), String> {
+    let mut split = details.split(", ");
+    let species = parse_species(split.next().unwrap_or(""))?;
+    let mut level = 100;
+    for detail in split {
+        if let Some(lvl) = detail.strip_prefix('L') {
+            level = lvl
+                .parse::<i8>()
+                .map_err(|_| format!("invalid level: {}", detail))?;
+        }
+    }
+    Ok((species, level))
+}
+
+// e.g. "58/100 par", "0 fnt"
+fn parse_hp_status(hp_status: &str) -> Result<(i16, i16, Option<PokemonStatus>), String> {
+    let mut split = hp_status.split_whitespace();
+    let hp = split.next().unwrap_or("");
+    let (current, max) = match hp.split_once('/') {
+        Some((current, max)) => (current, max),
+        None => (hp, "100"),
+    };
+    let current = current
+        .parse::<i16>()
+        .map_err(|_| format!("invalid hp: {}", hp_status))?;
+    let max = max
+        .parse::<i16>()
+        .map_err(|_| format!("invalid hp: {}", hp_status))?;
+    let status = match split.next() {
+        Some("fnt") => Some(PokemonStatus::NONE),
+        Some(status) => Some(parse_status(status)?),
+        None => None,
+    };
+    Ok((current, max, status))
+}
+
+fn parse_status(status: &str) -> Result<PokemonStatus, String> {
+    match status {
+        "brn" => Ok(PokemonStatus::BURN),
+        "slp" => Ok(PokemonStatus::SLEEP),
+        "frz" => Ok(PokemonStatus::FREEZE),
+        "par" => Ok(PokemonStatus::PARALYZE),
+        "psn" => Ok(PokemonStatus::POISON),
+        "tox" => Ok(PokemonStatus::TOXIC),
+        _ => Err(format!("unknown status: {}", status)),
+    }
+}
+
+fn parse_boostable_stat(stat: &str) -> Result<PokemonBoostableStat, String> {
+    match stat {
+        "atk" => Ok(PokemonBoostableStat::Attack),
+        "def" => Ok(PokemonBoostableStat::Defense),
+        "spa" => Ok(PokemonBoostableStat::SpecialAttack),
+        "spd" => Ok(PokemonBoostableStat::SpecialDefense),
+        "spe" => Ok(PokemonBoostableStat::Speed),
+        "accuracy" => Ok(PokemonBoostableStat::Accuracy),
+        "evasion" => Ok(PokemonBoostableStat::Evasion),
+        _ => Err(format!("unknown stat: {}", stat)),
+    }
+}
+
+fn boost_mut(side: &mut Side, stat: PokemonBoostableStat) -> &mut i8 {
+    match stat {
+        PokemonBoostableStat::Attack => &mut side.attack_boost,
+        PokemonBoostableStat::Defense => &mut side.defense_boost,
+        PokemonBoostableStat::SpecialAttack => &mut side.special_attack_boost,
+        PokemonBoostableStat::SpecialDefense => &mut side.special_defense_boost,
+        PokemonBoostableStat::Speed => &mut side.speed_boost,
+        PokemonBoostableStat::Accuracy => &mut side.accuracy_boost,
+        PokemonBoostableStat::Evasion => &mut side.evasion_boost,
+    }
+}
+
+fn all_boosts_mut(side: &mut Side) -> [&mut i8; 7] {
+    [
+        &mut side.attack_boost,
+        &mut side.defense_boost,
+        &mut side.special_attack_boost,
+        &mut side.special_defense_boost,
+        &mut side.speed_boost,
+        &mut side.accuracy_boost,
+        &mut side.evasion_boost,
+    ]
+}
+
+// Effects are given as "move: Stealth Rock", "ability: Flash Fire", "Substitute", etc.
+fn effect_id(effect: &str) -> String {
+    match effect.split_once(": ") {
+        Some((_, name)) => to_id(name),
+        None => to_id(effect),
+    }
+}
+
+fn parse_volatile_status(effect: &str) -> Option<PokemonVolatileStatus> {
+    match PokemonVolatileStatus::from_str(&effect_id(effect)) {
+        Ok(PokemonVolatileStatus::NONE) | Err(_) => None,
+        Ok(volatile_status) => Some(volatile_status),
+    }
+}
+
+// (side condition, amount added when it starts, maximum)
+fn parse_side_condition(effect: &str) -> Option<(PokemonSideCondition, i8, i8)> {
+    match effect_id(effect).as_str() {
+        "auroraveil" => Some((PokemonSideCondition::AuroraVeil, 5, 5)),
+        "lightscreen" => Some((PokemonSideCondition::LightScreen, 5, 5)),
+        "luckychant" => Some((PokemonSideCondition::LuckyChant, 5, 5)),
+        "mist" => Some((PokemonSideCondition::Mist, 5, 5)),
+        "reflect" => Some((PokemonSideCondition::Reflect, 5, 5)),
+        "safeguard" => Some((PokemonSideCondition::Safeguard, 5, 5)),
+        "spikes" => Some((PokemonSideCondition::Spikes, 1, 3)),
+        "stealthrock" => Some((PokemonSideCondition::Stealthrock, 1, 1)),
+        "stickyweb" => Some((PokemonSideCondition::StickyWeb, 1, 1)),
+        "tailwind" => Some((PokemonSideCondition::Tailwind, 4, 4)),
+        "toxicspikes" => Some((PokemonSideCondition::ToxicSpikes, 1, 2)),
+        _ => None,
+    }
+}
+
+fn parse_weather(weather: &str) -> Option<Weather> {
+    match to_id(weather).as_str() {
+        "none" => Some(Weather::NONE),
+        "sunnyday" => Some(Weather::SUN),
+        "raindance" => Some(Weather::RAIN),
+        "sandstorm" => Some(Weather::SAND),
+        "hail" => Some(Weather::HAIL),
+        "snow" | "snowscape" => Some(Weather::SNOW),
+        "desolateland" => Some(Weather::HARSHSUN),
+        "primordialsea" => Some(Weather::HEAVYRAIN),
+        _ => None,
+    }
+}
+
+fn parse_terrain(effect: &str) -> Option<Terrain> {
+    match effect_id(effect).as_str() {
+        "electricterrain" => Some(Terrain::ELECTRICTERRAIN),
+        "psychicterrain" => Some(Terrain::PSYCHICTERRAIN),
+        "mistyterrain" => Some(Terrain::MISTYTERRAIN),
+        "grassyterrain" => Some(Terrain::GRASSYTERRAIN),
+        _ => None,
+    }
+}
+
+// Keyword arguments look like "[from] item: Leftovers" or "[of] p2a: Garchomp"
+fn kwarg<'a>(kwargs: &[&'a str], name: &str) -> Option<&'a str> {
+    kwargs
+        .iter()
+        .find_map(|kwarg| kwarg.strip_prefix(name))
+        .map(|value| value.trim())
+}
+
+impl ShowdownBattle {
+    /// Creates a battle for `player_id` ("p1" or "p2") where neither side's pokemon are known yet
+    pub fn new(player_id: &str) -> ShowdownBattle {
+        ShowdownBattle::with_team(player_id, empty_side())
+    }
+
+    /// Creates a battle for `player_id` ("p1" or "p2") where the player's team is already known,
+    /// e.g. from `Side::from_showdown_team`
+    pub fn with_team(player_id: &str, team: Side) -> ShowdownBattle {
+        let mut state = State {
+            side_one: team,
+            side_two: empty_side(),
+            ..State::default()
+        };
+        state.refresh_zobrist_hash();
+        ShowdownBattle {
+            state,
+            player_id: player_id.to_string(),
+            turn: 0,
+            ended: false,
+            winner: None,
+            request: None,
+            side_one_nicknames: Default::default(),
+            side_two_nicknames: Default::default(),
+        }
+    }
+
+    /// Applies every protocol message in `message`, one per line
+    ///
+    /// Lines that are not protocol messages (such as the ">battle-gen9ou-1" room id)
+    /// and messages that do not affect the state are ignored
+    pub fn parse_message(&mut self, message: &str) -> Result<(), String> {
+        for line in message.lines() {
+            self.parse_line(line.trim())?;
+        }
+        Ok(())
+    }
+
+    /// Applies a single protocol message, e.g. "|-boost|p2a: Garchomp|atk|2"
+    pub fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let line = match line.strip_prefix('|') {
+            Some(line) => line,
+            None => return Ok(()),
+        };
+        // the request's JSON may contain '|'
+        if let Some(json) = line.strip_prefix("request|") {
+            if !json.is_empty() {
+                self.request = Some(ShowdownRequest::from_json(json)?);
+            }
+            return Ok(());
+        }
+        let (args, kwargs): (Vec<&str>, Vec<&str>) =
+            line.split('|').partition(|arg| !arg.starts_with('['));
+        let arg = |index: usize| {
+            args.get(index)
+                .copied()
+                .ok_or_else(|| format!("invalid message: |{}", line))
+        };
+
+        match arg(0)? {
+            "poke" => {
+                self.add_pokemon(self.side_reference(arg(1)?)?, "", arg(2)?)?;
+            }
+            "switch" | "drag" | "replace" => self.switch(arg(1)?, arg(2)?, arg(3)?)?,
+            "detailschange" | "-formechange" => {
+                let (side_ref, pkmn_index) = self.pokemon_index(arg(1)?)?;
+                let (species, _) = parse_details(arg(2)?)?;
+                self.change_species(side_ref, pkmn_index, species);
+            }
+            "move" => self.use_move(arg(1)?, arg(2)?, &kwargs)?,
+            "faint" => {
+                let pkmn = self.pokemon_mut(arg(1)?)?;
+                pkmn.hp = 0;
+                pkmn.status = PokemonStatus::NONE;
+            }
+            "-damage" | "-heal" | "-sethp" => self.set_hp(arg(1)?, arg(2)?)?,
+            "-status" => {
+                let status = parse_status(arg(2)?)?;
+                let (side_ref, pkmn_index) = self.pokemon_index(arg(1)?)?;
+                let side = self.state.get_side(&side_ref);
+                let pkmn = &mut side.pokemon[pkmn_index];
+                pkmn.status = status;
+                pkmn.sleep_turns = 0;
+                if kwarg(&kwargs, "[from]").map(effect_id).as_deref() == Some("rest") {
+                    pkmn.rest_turns = 3;
+                }
+                if status == PokemonStatus::TOXIC && pkmn_index == side.active_index {
+                    side.side_conditions.toxic_count = 0;
+                }
+            }
+            "-curestatus" => {
+                let pkmn = self.pokemon_mut(arg(1)?)?;
+                pkmn.status = PokemonStatus::NONE;
+                pkmn.sleep_turns = 0;
+                pkmn.rest_turns = 0;
+            }
+            "-cureteam" => {
+                let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                let side = self.state.get_side(&side_ref);
+                for pkmn_index in pokemon_index_iter() {
+                    side.pokemon[pkmn_index].status = PokemonStatus::NONE;
+                }
+            }
+            "-boost" | "-unboost" | "-setboost" => {
+                let stat = parse_boostable_stat(arg(2)?)?;
+                let amount = arg(3)?
+                    .parse::<i8>()
+                    .map_err(|_| format!("invalid boost: |{}", line))?;
+                let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                let boost = boost_mut(self.state.get_side(&side_ref), stat);
+                *boost = match arg(0)? {
+                    "-boost" => (*boost + amount).min(6),
+                    "-unboost" => (*boost - amount).max(-6),
+                    _ => amount,
+                };
+            }
+            "-clearboost" => {
+                let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                for boost in all_boosts_mut(self.state.get_side(&side_ref)) {
+                    *boost = 0;
+                }
+            }
+            "-clearnegativeboost" => {
+                let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                for boost in all_boosts_mut(self.state.get_side(&side_ref)) {
+                    *boost = (*boost).max(0);
+                }
+            }
+            "-clearallboost" => {
+                for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+                    for boost in all_boosts_mut(self.state.get_side(&side_ref)) {
+                        *boost = 0;
+                    }
+                }
+            }
+            "-weather" => self.set_weather(arg(1)?, &kwargs),
+            "-fieldstart" => self.set_field(arg(1)?, true),
+            "-fieldend" => self.set_field(arg(1)?, false),
+            "-sidestart" => {
+                let side = self.state.get_side(&self.side_reference(arg(1)?)?);
+                if let Some((side_condition, amount, max)) = parse_side_condition(arg(2)?) {
+                    let current = side.get_side_condition(side_condition);
+                    side.update_side_condition(
+                        side_condition,
+                        (current + amount).min(max) - current,
+                    );
+                }
+            }
+            "-sideend" => {
+                let side = self.state.get_side(&self.side_reference(arg(1)?)?);
+                if let Some((side_condition, _, _)) = parse_side_condition(arg(2)?) {
+                    let current = side.get_side_condition(side_condition);
+                    side.update_side_condition(side_condition, -current);
+                }
+            }
+            "-start" | "-singleturn" => {
+                self.start_volatile_status(arg(1)?, arg(2)?, args.get(3))?
+            }
+            "-end" => {
+                if let Some(volatile_status) = parse_volatile_status(arg(2)?) {
+                    let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                    let side = self.state.get_side(&side_ref);
+                    side.volatile_statuses.remove(&volatile_status);
+                    if volatile_status == PokemonVolatileStatus::SUBSTITUTE {
+                        side.substitute_health = 0;
+                    }
+                }
+            }
+            "-item" => {
+                let item = parse_item(arg(2)?)?;
+                self.pokemon_mut(arg(1)?)?.item = item;
+            }
+            "-enditem" => self.pokemon_mut(arg(1)?)?.item = Items::NONE,
+            "-ability" => {
+                let ability = parse_ability(arg(2)?)?;
+                let pkmn = self.pokemon_mut(arg(1)?)?;
+                pkmn.ability = ability;
+                // e.g. "|-ability|p2a: Porygon2|Intimidate|[from] ability: Trace|[of] p1a: Gyarados"
+                pkmn.base_ability = match kwarg(&kwargs, "[from]") {
+                    Some(from) if from.starts_with("ability:") => {
+                        parse_ability(&from["ability:".len()..])?
+                    }
+                    Some(_) => pkmn.base_ability,
+                    None => ability,
+                };
+            }
+            "-terastallize" => {
+                let tera_type = parse_type(arg(2)?)?;
+                let pkmn = self.pokemon_mut(arg(1)?)?;
+                pkmn.terastallized = true;
+                pkmn.tera_type = tera_type;
+            }
+            // e.g. "|-mega|p2a: Charizard|Charizard|Charizardite Y", after the "detailschange"
+            // to the mega forme
+            "-mega" => {
+                let (side_ref, pkmn_index) = self.pokemon_index(arg(1)?)?;
+                let side = self.state.get_side(&side_ref);
+                side.mega_evolved = true;
+                let pkmn = &mut side.pokemon[pkmn_index];
+                pkmn.ability = pkmn
+                    .id
+                    .abilities()
+                    .first()
+                    .copied()
+                    .unwrap_or(Abilities::NONE);
+                pkmn.base_ability = pkmn.ability;
+            }
+            // e.g. "|-zpower|p1a: Snorlax", before the z-move is used
+            "-zpower" => {
+                let (side_ref, _) = self.pokemon_index(arg(1)?)?;
+                self.state.get_side(&side_ref).z_move_used = true;
+            }
+            "turn" => {
+                self.turn = arg(1)?
+                    .parse::<u16>()
+                    .map_err(|_| format!("invalid turn: |{}", line))?;
+                self.end_turn();
+            }
+            "win" => {
+                self.ended = true;
+                self.winner = Some(arg(1)?.to_string());
+            }
+            "tie" => self.ended = true,
+            _ => {}
+        }
+
+        // the pokemon that the revealing item or ability belongs to
+        let revealed_by = match arg(0)? {
+            "-damage" | "-item" => Some(kwarg(&kwargs, "[of]").unwrap_or(arg(1)?)),
+            "-heal" | "-immune" => Some(arg(1)?),
+            "-weather" | "-fieldstart" => kwarg(&kwargs, "[of]"),
+            _ => None,
+        };
+        if let Some(ident) = revealed_by {
+            self.reveal_from_kwargs(ident, &kwargs)?;
+        }
+        // messages change the state directly rather than through instructions
+        self.state.refresh_zobrist_hash();
+        Ok(())
+    }
+
+    // "p1" is always side one from the player's perspective
+    fn side_reference(&self, side_id: &str) -> Result<SideReference, String> {
+        match side_id.get(0..2) {
+            Some(side_id) if side_id == self.player_id => Ok(SideReference::SideOne),
+            Some("p1") | Some("p2") => Ok(SideReference::SideTwo),
+            _ => Err(format!("invalid side: {}", side_id)),
+        }
+    }
+
+    fn nicknames_mut(&mut self, side_ref: SideReference) -> &mut [String; 6] {
+        match side_ref {
+            SideReference::SideOne => &mut self.side_one_nicknames,
+            SideReference::SideTwo => &mut self.side_two_nicknames,
+        }
+    }
+
+    // e.g. "p2a: Chomp"
+    fn pokemon_index(&mut self, ident: &str) -> Result<(SideReference, PokemonIndex), String> {
+        let (side_id, nickname) = ident
+            .split_once(": ")
+            .ok_or_else(|| format!("invalid pokemon: {}", ident))?;
+        let side_ref = self.side_reference(side_id)?;
+        match self
+            .nicknames_mut(side_ref)
+            .iter()
+            .position(|name| name == nickname)
+        {
+            Some(index) => Ok((side_ref, PokemonIndex::deserialize(&index.to_string()))),
+            None => Err(format!("unknown pokemon: {}", ident)),
+        }
+    }
+
+    fn pokemon_mut(&mut self, ident: &str) -> Result<&mut Pokemon, String> {
+        let (side_ref, pkmn_index) = self.pokemon_index(ident)?;
+        Ok(&mut self.state.get_side(&side_ref).pokemon[pkmn_index])
+    }
+
+    // Finds the slot for a pokemon, adding it to the side if it has not been seen before.
+    // Pokemon from team preview or a known team have no nickname until they switch in
+    fn add_pokemon(
+        &mut self,
+        side_ref: SideReference,
+        nickname: &str,
+        details: &str,
+    ) -> Result<PokemonIndex, String> {
+        let (species, level) = parse_details(details)?;
+        let nicknames = self.nicknames_mut(side_ref).clone();
+        if let Some(index) = nicknames
+            .iter()
+            .position(|name| !nickname.is_empty() && name == nickname)
+        {
+            return Ok(PokemonIndex::deserialize(&index.to_string()));
+        }
+
+        let side = self.state.get_side_immutable(&side_ref);
+        let unnamed: Vec<PokemonIndex> = pokemon_index_iter()
+            .filter(|pkmn_index| {
+                nicknames[*pkmn_index as usize].is_empty()
+                    && side.pokemon[*pkmn_index].id != PokemonName::NONE
+            })
+            .collect();
+        let species_id = species.to_string();
+        // team preview hides some formes, e.g. "Urshifu-*"
+        let known_index = unnamed
+            .iter()
+            .find(|pkmn_index| side.pokemon[**pkmn_index].id == species)
+            .or_else(|| {
+                unnamed.iter().find(|pkmn_index| {
+                    species_id.starts_with(&side.pokemon[**pkmn_index].id.to_string())
+                })
+            })
+            .copied();
+        let pkmn_index = match known_index {
+            Some(pkmn_index) => {
+                if side.pokemon[pkmn_index].id != species {
+                    self.change_species(side_ref, pkmn_index, species);
+                }
+                pkmn_index
+            }
+            None => {
+                let pkmn_index = pokemon_index_iter()
+                    .find(|pkmn_index| side.pokemon[*pkmn_index].id == PokemonName::NONE)
+                    .ok_or_else(|| format!("too many pokemon: {}", details))?;
+                self.state.get_side(&side_ref).pokemon[pkmn_index] = new_pokemon(species, level);
+                pkmn_index
+            }
+        };
+        self.nicknames_mut(side_ref)[pkmn_index as usize] = nickname.to_string();
+        Ok(pkmn_index)
+    }
+
+    fn change_species(
+        &mut self,
+        side_ref: SideReference,
+        pkmn_index: PokemonIndex,
+        species: PokemonName,
+    ) {
+        let pkmn = &mut self.state.get_side(&side_ref).pokemon[pkmn_index];
+        pkmn.id = species;
+        pkmn.types = species.types();
+        pkmn.base_types = pkmn.types;
+        pkmn.weight_kg = species.weight_kg();
+        let stats = pkmn.calculate_stats_from_base_stats();
+        pkmn.attack = stats.1;
+        pkmn.defense = stats.2;
+        pkmn.special_attack = stats.3;
+        pkmn.special_defense = stats.4;
+        pkmn.speed = stats.5;
+    }
+
+    fn switch(&mut self, ident: &str, details: &str, hp_status: &str) -> Result<(), String> {
+        let (side_id, nickname) = ident
+            .split_once(": ")
+            .ok_or_else(|| format!("invalid pokemon: {}", ident))?;
+        let side_ref = self.side_reference(side_id)?;
+        let pkmn_index = self.add_pokemon(side_ref, nickname, details)?;
+
+        let side = self.state.get_side(&side_ref);
+        if side.active_index != pkmn_index {
+            let active = side.get_active();
+            active.types = active.base_types;
+            active.ability = active.base_ability;
+            for boost in all_boosts_mut(side) {
+                *boost = 0;
+            }
+            side.volatile_statuses.clear();
+            side.volatile_status_durations = VolatileStatusDurations::default();
+            side.substitute_health = 0;
+            side.side_conditions.toxic_count = 0;
+            side.active_index = pkmn_index;
+        }
+        side.last_used_move = LastUsedMove::Switch(pkmn_index);
+        self.set_hp(ident, hp_status)
+    }
+
+    // The player sees their own exact HP. The opponent's HP is a percentage of its max HP,
+    // as is the player's own HP in a replay
+    fn set_hp(&mut self, ident: &str, hp_status: &str) -> Result<(), String> {
+        let (current, max, status) = parse_hp_status(hp_status)?;
+        let (side_ref, pkmn_index) = self.pokemon_index(ident)?;
+        let pkmn = &mut self.state.get_side(&side_ref).pokemon[pkmn_index];
+        match side_ref {
+            SideReference::SideOne if max != 0 && (max != 100 || pkmn.maxhp == 100) => {
+                pkmn.hp = current;
+                pkmn.maxhp = max;
+            }
+            _ if max != 0 => {
+                pkmn.hp = ((current as f32 * pkmn.maxhp as f32) / max as f32).round() as i16;
+                // a pokemon that is not fainted always has at least 1 HP
+                if current > 0 {
+                    pkmn.hp = pkmn.hp.max(1);
+                }
+            }
+            _ => pkmn.hp = current,
+        }
+        if let Some(status) = status {
+            pkmn.status = status;
+        }
+        Ok(())
+    }
+
+    fn use_move(&mut self, ident: &str, move_name: &str, kwargs: &[&str]) -> Result<(), String> {
+        // moves called by another move (e.g. Metronome) are not the pokemon's own
+        // but a locked move (e.g. Outrage) is
+        let from = kwarg(kwargs, "[from]");
+        if from.is_some() && from != Some("lockedmove") {
+            return Ok(());
+        }
+        let mut choice = parse_move(move_name)?;
+        if choice == Choices::STRUGGLE {
+            return Ok(());
+        }
+        let (side_ref, pkmn_index) = self.pokemon_index(ident)?;
+        let side = self.state.get_side(&side_ref);
+        let pkmn = &mut side.pokemon[pkmn_index];
+        // a damaging z-move is named after its type rather than the move it was made from.
+        // Species-exclusive z-moves are always made from the same move
+        if let Some(base_move) = choice.exclusive_z_move_base() {
+            choice = base_move;
+        } else if choice.is_z_move() {
+            match pkmn
+                .moves
+                .into_iter()
+                .find(|m| m.choice.to_z_move().move_id == choice)
+            {
+                Some(m) => choice = m.id,
+                None => return Ok(()),
+            }
+        }
+        let move_index = match pkmn.moves.into_iter().position(|m| m.id == choice) {
+            Some(index) => index,
+            None => match pkmn.moves.into_iter().position(|m| m.id == Choices::NONE) {
+                Some(index) => {
+                    let move_index = PokemonMoveIndex::deserialize(&index.to_string());
+                    pkmn.moves[&move_index] = Move::default();
+                    pkmn.replace_move(move_index, choice);
+                    index
+                }
+                // e.g. a transformed pokemon using its target's moves
+                None => return Ok(()),
+            },
+        };
+        let move_index = PokemonMoveIndex::deserialize(&move_index.to_string());
+        if from.is_none() {
+            pkmn.moves[&move_index].pp -= 1;
+        }
+        side.last_used_move = LastUsedMove::Move(move_index);
+        Ok(())
+    }
+
+    fn start_volatile_status(
+        &mut self,
+        ident: &str,
+        effect: &str,
+        extra: Option<&&str>,
+    ) -> Result<(), String> {
+        let (side_ref, _) = self.pokemon_index(ident)?;
+        let volatile_status = match parse_volatile_status(effect) {
+            Some(volatile_status) => volatile_status,
+            None => return Ok(()),
+        };
+        let side = self.state.get_side(&side_ref);
+        side.volatile_statuses.insert(volatile_status);
+        match volatile_status {
+            PokemonVolatileStatus::SUBSTITUTE => {
+                side.substitute_health = side.get_active_immutable().maxhp / 4;
+            }
+            PokemonVolatileStatus::CONFUSION => side.volatile_status_durations.confusion = 0,
+            PokemonVolatileStatus::ENCORE => side.volatile_status_durations.encore = 0,
+            PokemonVolatileStatus::TAUNT => side.volatile_status_durations.taunt = 0,
+            PokemonVolatileStatus::YAWN => side.volatile_status_durations.yawn = 0,
+            // e.g. "|-start|p2a: Chomp|typechange|Fire/Water"
+            PokemonVolatileStatus::TYPECHANGE => {
+                if let Some(types) = extra {
+                    let mut types = types.split('/');
+                    let first = parse_type(types.next().unwrap_or(""))?;
+                    let second = match types.next() {
+                        Some(second) => parse_type(second)?,
+                        None => PokemonType::TYPELESS,
+                    };
+                    side.get_active().types = (first, second);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn set_weather(&mut self, weather: &str, kwargs: &[&str]) {
+        let weather_type = match parse_weather(weather) {
+            Some(weather_type) => weather_type,
+            None => return,
+        };
+        if kwargs.contains(&"[upkeep]") {
+            if self.state.weather.turns_remaining > 1 {
+                self.state.weather.turns_remaining -= 1;
+            }
+            return;
+        }
+        let from_ability =
+            matches!(kwarg(kwargs, "[from]"), Some(from) if from.starts_with("ability:"));
+        self.state.weather.weather_type = weather_type;
+        self.state.weather.turns_remaining = match weather_type {
+            Weather::NONE | Weather::HARSHSUN | Weather::HEAVYRAIN => -1,
+            _ if from_ability && cfg!(any(feature = "gen4", feature = "gen5")) => -1,
+            _ => 5,
+        };
+    }
+
+    fn set_field(&mut self, effect: &str, started: bool) {
+        if effect_id(effect) == "trickroom" {
+            self.state.trick_room.active = started;
+            self.state.trick_room.turns_remaining = if started { 5 } else { 0 };
+        } else if let Some(terrain) = parse_terrain(effect) {
+            if started {
+                self.state.terrain.terrain_type = terrain;
+                self.state.terrain.turns_remaining = 5;
+            } else if self.state.terrain.terrain_type == terrain {
+                self.state.terrain.terrain_type = Terrain::NONE;
+                self.state.terrain.turns_remaining = 0;
+            }
+        }
+    }
+
+    // Effects that last a number of turns end with their own message, so their
+    // remaining turns never go below 1 here
+    fn end_turn(&mut self) {
+        if self.state.terrain.turns_remaining > 1 {
+            self.state.terrain.turns_remaining -= 1;
+        }
+        if self.state.trick_room.turns_remaining > 1 {
+            self.state.trick_room.turns_remaining -= 1;
+        }
+        for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+            let side = self.state.get_side(&side_ref);
+            for side_condition in TIMED_SIDE_CONDITIONS {
+                if side.get_side_condition(side_condition) > 1 {
+                    side.update_side_condition(side_condition, -1);
+                }
+            }
+            for volatile_status in SINGLE_TURN_VOLATILE_STATUSES {
+                side.volatile_statuses.remove(&volatile_status);
+            }
+        }
+    }
+
+    // e.g. "|-heal|p2a: Chomp|60/100|[from] item: Leftovers" reveals Leftovers, and
+    // "|-damage|p1a: Pikachu|50/100|[from] ability: Rough Skin|[of] p2a: Chomp" reveals Rough Skin
+    fn reveal_from_kwargs(&mut self, owner: &str, kwargs: &[&str]) -> Result<(), String> {
+        match kwarg(kwargs, "[from]") {
+            Some(from) if from.starts_with("item:") => {
+                let item = parse_item(&from["item:".len()..])?;
+                self.pokemon_mut(owner)?.item = item;
+            }
+            Some(from) if from.starts_with("ability:") => {
+                let ability: Abilities = parse_ability(&from["ability:".len()..])?;
+                let pkmn = self.pokemon_mut(owner)?;
+                pkmn.ability = ability;
+                pkmn.base_ability = ability;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// A move the active pokemon can use according to a request
+#[derive(Debug, Clone)]
+pub struct RequestMove {
+    pub id: Choices,
+    pub pp: i8,
+    pub disabled: bool,
+    pub can_z_move: bool,
+}
+
+/// A pokemon on the player's side according to a request
+#[derive(Debug, Clone)]
+pub struct RequestPokemon {
+    pub species: PokemonName,
+    pub active: bool,
+    pub fainted: bool,
+}
+
+/// The choice Pokemon Showdown is asking a player to make, sent as "|request|{json}"
+///
+/// Showdown keeps the active pokemon first in `pokemon`, so the order changes as
+/// pokemon switch. Pokemon and moves are matched to a `Side` by species and move
+#[derive(Debug, Clone, Default)]
+pub struct ShowdownRequest {
+    pub moves: Vec<RequestMove>,
+    pub can_terastallize: bool,
+    pub can_mega_evolve: bool,
+    pub trapped: bool,
+    pub force_switch: bool,
+    pub team_preview: bool,
+    pub wait: bool,
+    pub pokemon: Vec<RequestPokemon>,
+}
+
+// A request's "hiddenpower" is the same move as any of the engine's Hidden Power variants
+fn same_move(request_move: Choices, side_move: Choices) -> bool {
+    request_move == side_move
+        || (request_move == Choices::HIDDENPOWER
+            && side_move.to_string().starts_with("HIDDENPOWER"))
+}
+
+fn side_index(species: PokemonName, side: &Side) -> Option<PokemonIndex> {
+    pokemon_index_iter().find(|pkmn_index| side.pokemon[*pkmn_index].id == species)
+}
+
+impl ShowdownRequest {
+    pub fn from_json(json: &str) -> Result<ShowdownRequest, String> {
+        let request: Value =
+            serde_json::from_str(json).map_err(|e| format!("invalid request: {}", e))?;
+        let active = &request["active"][0];
+
+        let mut moves = vec![];
+        for (i, mv) in active["moves"].as_array().into_iter().flatten().enumerate() {
+            let id = mv["id"]
+                .as_str()
+                .ok_or_else(|| format!("invalid request move: {}", mv))?;
+            moves.push(RequestMove {
+                // e.g. "recharge", which is not a move the engine knows
+                id: parse_move(id).unwrap_or(Choices::NONE),
+                // a locked move (e.g. Outrage) has no pp
+                pp: mv["pp"].as_i64().unwrap_or(1) as i8,
+                // either `true` or the source of the disable
+                disabled: match &mv["disabled"] {
+                    Value::Bool(disabled) => *disabled,
+                    Value::String(_) => true,
+                    _ => false,
+                },
+                // "canZMove" has the z-move for each move, or null if it cannot become one
+                can_z_move: !active["canZMove"][i].is_null(),
+            });
+        }
+
+        let mut pokemon = vec![];
+        for pkmn in request["side"]["pokemon"].as_array().into_iter().flatten() {
+            let details = pkmn["details"]
+                .as_str()
+                .ok_or_else(|| format!("invalid request pokemon: {}", pkmn))?;
+            pokemon.push(RequestPokemon {
+                species: parse_details(details)?.0,
+                active: pkmn["active"].as_bool().unwrap_or(false),
+                fainted: pkmn["condition"].as_str().unwrap_or("").ends_with("fnt"),
+            });
+        }
+
+        Ok(ShowdownRequest {
+            moves,
+            can_terastallize: !matches!(
+                active["canTerastallize"],
+                Value::Null | Value::Bool(false)
+            ),
+            can_mega_evolve: active["canMegaEvo"].as_bool().unwrap_or(false),
+            trapped: active["trapped"].as_bool().unwrap_or(false),
+            force_switch: request["forceSwitch"][0].as_bool().unwrap_or(false),
+            team_preview: request["teamPreview"].as_bool().unwrap_or(false),
+            wait: request["wait"].as_bool().unwrap_or(false),
+            pokemon,
+        })
+    }
+
+    /// The choices that this request allows for `side`
+    ///
+    /// A side that must wait, or whose active pokemon can only use a move the engine
+    /// does not know (e.g. recharging), has `MoveChoice::None`
+    pub fn move_choices(&self, side: &Side) -> Vec<MoveChoice> {
+        if self.wait {
+            return vec![MoveChoice::None];
+        }
+        let switches = self
+            .pokemon
+            .iter()
+            .filter(|pkmn| !pkmn.fainted && (self.team_preview || !pkmn.active))
+            .filter_map(|pkmn| side_index(pkmn.species, side))
+            .filter(|pkmn_index| self.team_preview || *pkmn_index != side.active_index)
+            .map(MoveChoice::Switch);
+        if self.team_preview || self.force_switch {
+            return switches.collect();
+        }
+
+        let mut choices = vec![];
+        let active = side.get_active_immutable();
+        for request_move in self.moves.iter().filter(|m| !m.disabled && m.pp > 0) {
+            let mut move_iter = active.moves.into_iter();
+            while let Some(mv) = move_iter.next() {
+                if same_move(request_move.id, mv.id) {
+                    choices.push(MoveChoice::Move(move_iter.pokemon_move_index));
+                    if self.can_terastallize {
+                        choices.push(MoveChoice::MoveTera(move_iter.pokemon_move_index));
+                    }
+                    if self.can_mega_evolve {
+                        choices.push(MoveChoice::MoveMega(move_iter.pokemon_move_index));
+                    }
+                    if request_move.can_z_move {
+                        choices.push(MoveChoice::MoveZ(move_iter.pokemon_move_index));
+                    }
+                    break;
+                }
+            }
+        }
+        if choices.is_empty() {
+            choices.push(MoveChoice::None);
+        }
+        if !self.trapped {
+            choices.extend(switches);
+        }
+        choices
+    }
+
+    /// The `/choose` command for `choice`, with moves and pokemon numbered in this request's order
+    pub fn to_showdown_choice(&self, choice: &MoveChoice, side: &Side) -> String {
+        let move_number = |move_index: &PokemonMoveIndex| {
+            let side_move = side.get_active_immutable().moves[move_index].id;
+            match self.moves.iter().position(|m| same_move(m.id, side_move)) {
+                Some(position) => position + 1,
+                None => *move_index as usize + 1,
+            }
+        };
+        let pokemon_number = |pkmn_index: &PokemonIndex| {
+            let species = side.pokemon[*pkmn_index].id;
+            match self.pokemon.iter().position(|p| p.species == species) {
+                Some(position) => position + 1,
+                None => *pkmn_index as usize + 1,
+            }
+        };
+        match choice {
+            MoveChoice::MoveTera(move_index) => {
+                format!("move {} terastallize", move_number(move_index))
+            }
+            MoveChoice::MoveMega(move_index) => format!("move {} mega", move_number(move_index)),
+            MoveChoice::MoveZ(move_index) => format!("move {} zmove", move_number(move_index)),
+            MoveChoice::Move(move_index) => format!("move {}", move_number(move_index)),
+            MoveChoice::Switch(pkmn_index) if self.team_preview => {
+                let lead = pokemon_number(pkmn_index);
+                let order: String = std::iter::once(lead)
+                    .chain((1..=self.pokemon.len()).filter(|number| *number != lead))
+                    .map(|number| number.to_string())
+                    .collect();
+                format!("team {}", order)
+            }
+            MoveChoice::Switch(pkmn_index) => format!("switch {}", pokemon_number(pkmn_index)),
+            MoveChoice::None => "default".to_string(),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use crate::choices::{Choices, MOVES};
+use crate::engine::items::Items;
+use crate::engine::state::{Terrain, Weather};
+use crate::pokemon::PokemonName;
+use crate::showdown_team::ShowdownSet;
+use crate::state::{
+    pokemon_index_iter, PokemonIndex, PokemonNature, PokemonStatus, PokemonType, Side, SidePokemon,
+    State,
+};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+// Types that exist in the current generation
+fn type_exists(pkmn_type: PokemonType) -> bool {
+    match pkmn_type {
+        PokemonType::FAIRY => !cfg!(any(feature = "gen4", feature = "gen5")),
+        PokemonType::STELLAR | PokemonType::TYPELESS => false,
+        _ => true,
+    }
+}
+
+// Mega evolutions only exist in gen6 and gen7, gigantamax forms only in gen8
+fn form_exists(species: PokemonName) -> bool {
+    let name = species.to_string();
+    let gigantamax = name.ends_with("GMAX");
+    let mega = name.ends_with("MEGA") || name.ends_with("MEGAX") || name.ends_with("MEGAY");
+    (!gigantamax || cfg!(feature = "gen8"))
+        && (!mega || cfg!(any(feature = "gen6", feature = "gen7")))
+}
+
+// PokemonName is in national dex order, so these are where gen2 to gen9 begin
+const FIRST_SPECIES_OF_GENERATION: [PokemonName; 8] = [
+    PokemonName::CHIKORITA,
+    PokemonName::TREECKO,
+    PokemonName::TURTWIG,
+    PokemonName::VICTINI,
+    PokemonName::CHESPIN,
+    PokemonName::ROWLET,
+    PokemonName::GROOKEY,
+    PokemonName::SPRIGATITO,
+];
+
+fn generation() -> usize {
+    if cfg!(feature = "gen1") {
+        1
+    } else if cfg!(feature = "gen2") {
+        2
+    } else if cfg!(feature = "gen3") {
+        3
+    } else if cfg!(feature = "gen4") {
+        4
+    } else if cfg!(feature = "gen5") {
+        5
+    } else if cfg!(feature = "gen6") {
+        6
+    } else if cfg!(feature = "gen7") {
+        7
+    } else if cfg!(feature = "gen8") {
+        8
+    } else {
+        9
+    }
+}
+
+// Species from a later generation, including the regional forms of older species
+fn species_exists(species: PokemonName) -> bool {
+    let name = species.to_string();
+    let regional_form_generation = if name.contains("ALOLA") {
+        7
+    } else if name.contains("GALAR") || name.contains("HISUI") {
+        8
+    } else if name.contains("PALDEA") {
+        9
+    } else {
+        1
+    };
+    regional_form_generation <= generation()
+        && FIRST_SPECIES_OF_GENERATION
+            .get(generation() - 1)
+            .is_none_or(|first| (species as i16) < (*first as i16))
+}
+
+// The engine does not know which generation introduced each move, item and the
+// other alternate forms, so of those only the ones with a type or form that does not
+// exist yet are left out
+fn legal_species() -> Vec<PokemonName> {
+    (1..)
+        .map(PokemonName::from)
+        .take_while(|species| *species != PokemonName::NONE)
+        .filter(|species| {
+            let (type_one, type_two) = species.types();
+            !species.standard_abilities().is_empty()
+                && species.base_stats().0 > 0
+                && type_exists(type_one)
+                && (type_two == PokemonType::TYPELESS || type_exists(type_two))
+                && species_exists(*species)
+                && form_exists(*species)
+        })
+        .collect()
+}
+
+// Hidden Power is only generated without a fixed type so that its type comes from the IVs
+fn legal_moves() -> Vec<Choices> {
+    let mut moves: Vec<Choices> = MOVES
+        .iter()
+        .filter(|(id, choice)| {
+            **id != Choices::NONE
+                && **id != Choices::STRUGGLE
+                && (**id == Choices::HIDDENPOWER || !id.is_hiddenpower())
+                && !id.is_z_move()
+                && (choice.move_type == PokemonType::TYPELESS || type_exists(choice.move_type))
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    // MOVES is a HashMap, so the order must be fixed for the same seed to give the same state
+    moves.sort_by_key(|id| *id as u16);
+    moves
+}
+
+fn legal_items() -> Vec<Items> {
+    (0..=u8::MAX)
+        .filter_map(|i| {
+            let item = Items::from(i);
+            (item as u8 == i).then_some(item)
+        })
+        .filter(|item| *item != Items::NONE && *item != Items::UNKNOWNITEM)
+        .collect()
+}
+
+fn random_evs<R: Rng>(rng: &mut R) -> (u8, u8, u8, u8, u8, u8) {
+    // like most competitive spreads: 252 in two stats and 4 in another
+    let mut stats: Vec<usize> = (0..6).collect();
+    stats.shuffle(rng);
+    let mut evs = [0; 6];
+    evs[stats[0]] = 252;
+    evs[stats[1]] = 252;
+    evs[stats[2]] = 4;
+    (evs[0], evs[1], evs[2], evs[3], evs[4], evs[5])
+}
+
+/// Generates random states from a seed. The same seed always generates the same states
+///
+/// Species are from the generation being built. Moves are only left out when their
+/// type does not exist yet and items are not checked, so both can be from a later
+/// generation
+///
+/// With `mid_battle` the states are randomized as if the battle had been going on
+/// for a while: hp, statuses, boosts, side conditions, weather, terrain and trick room
+pub struct RandomStateGenerator {
+    rng: StdRng,
+    pub mid_battle: bool,
+    species: Vec<PokemonName>,
+    moves: Vec<Choices>,
+    items: Vec<Items>,
+}
+
+impl RandomStateGenerator {
+    pub fn new(seed: u64, mid_battle: bool) -> RandomStateGenerator {
+        RandomStateGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            mid_battle,
+            species: legal_species(),
+            moves: legal_moves(),
+            items: legal_items(),
+        }
+    }
+
+    /// A random set for `species` with 4 different moves, one of its abilities, an item,
+    /// a random nature and a competitive EV spread at a level between 70 and 100
+    pub fn random_set(&mut self, species: PokemonName) -> ShowdownSet {
+        let abilities = species.abilities();
+        let types = [
+            PokemonType::NORMAL,
+            PokemonType::FIRE,
+            PokemonType::WATER,
+            PokemonType::ELECTRIC,
+            PokemonType::GRASS,
+            PokemonType::ICE,
+            PokemonType::FIGHTING,
+            PokemonType::POISON,
+            PokemonType::GROUND,
+            PokemonType::FLYING,
+            PokemonType::PSYCHIC,
+            PokemonType::BUG,
+            PokemonType::ROCK,
+            PokemonType::GHOST,
+            PokemonType::DRAGON,
+            PokemonType::DARK,
+            PokemonType::STEEL,
+            PokemonType::FAIRY,
+        ];
+        ShowdownSet {
+            species,
+            item: *self.items.choose(&mut self.rng).unwrap(),
+            ability: abilities.choose(&mut self.rng).copied(),
+            level: self.rng.gen_range(70..=100),
+            evs: random_evs(&mut self.rng),
+            ivs: (31, 31, 31, 31, 31, 31),
+            nature: PokemonNature::from(self.rng.gen_range(0..=PokemonNature::SERIOUS as u8)),
+            moves: self
+                .moves
+                .choose_multiple(&mut self.rng, 4)
+                .copied()
+                .collect(),
+            tera_type: Some(*types.choose(&mut self.rng).unwrap()),
+        }
+    }
+
+    /// 6 pokemon of different species
+    pub fn random_side(&mut self) -> Side {
+        let species: Vec<PokemonName> = self
+            .species
+            .choose_multiple(&mut self.rng, 6)
+            .copied()
+            .collect();
+        let mut pokemon = species.into_iter().map(|s| self.random_set(s).to_pokemon());
+        let mut side = Side {
+            pokemon: SidePokemon {
+                p0: pokemon.next().unwrap(),
+                p1: pokemon.next().unwrap(),
+                p2: pokemon.next().unwrap(),
+                p3: pokemon.next().unwrap(),
+                p4: pokemon.next().unwrap(),
+                p5: pokemon.next().unwrap(),
+            },
+            ..Side::default()
+        };
+        if self.mid_battle {
+            self.randomize_side(&mut side);
+        }
+        side
+    }
+
+    fn randomize_side(&mut self, side: &mut Side) {
+        let rng = &mut self.rng;
+        for pkmn_index in pokemon_index_iter() {
+            let pkmn = &mut side.pokemon[pkmn_index];
+            pkmn.hp = match rng.gen_bool(0.15) {
+                true => 0,
+                false => rng.gen_range(1..=pkmn.maxhp),
+            };
+            if pkmn.hp > 0 && rng.gen_bool(0.2) {
+                pkmn.status = *[
+                    PokemonStatus::BURN,
+                    PokemonStatus::PARALYZE,
+                    PokemonStatus::POISON,
+                    PokemonStatus::TOXIC,
+                    PokemonStatus::SLEEP,
+                    PokemonStatus::FREEZE,
+                ]
+                .choose(rng)
+                .unwrap();
+                if pkmn.status == PokemonStatus::SLEEP {
+                    pkmn.sleep_turns = rng.gen_range(0..=2);
+                }
+            }
+            for mv in [
+                &mut pkmn.moves.m0,
+                &mut pkmn.moves.m1,
+                &mut pkmn.moves.m2,
+                &mut pkmn.moves.m3,
+            ] {
+                if mv.pp > 0 {
+                    mv.pp = rng.gen_range(1..=mv.pp);
+                }
+            }
+        }
+
+        // the active pokemon is always alive
+        let alive: Vec<PokemonIndex> = pokemon_index_iter()
+            .filter(|i| side.pokemon[*i].hp > 0)
+            .collect();
+        side.active_index = match alive.choose(rng) {
+            Some(index) => *index,
+            None => {
+                side.pokemon.p0.hp = side.pokemon.p0.maxhp;
+                PokemonIndex::P0
+            }
+        };
+
+        for boost in [
+            &mut side.attack_boost,
+            &mut side.defense_boost,
+            &mut side.special_attack_boost,
+            &mut side.special_defense_boost,
+            &mut side.speed_boost,
+        ] {
+            if rng.gen_bool(0.2) {
+                *boost = rng.gen_range(-2..=2);
+            }
+        }
+
+        let side_conditions = &mut side.side_conditions;
+        if rng.gen_bool(0.3) {
+            side_conditions.stealth_rock = 1;
+        }
+        if rng.gen_bool(0.2) {
+            side_conditions.spikes = rng.gen_range(1..=3);
+        }
+        if rng.gen_bool(0.1) {
+            side_conditions.toxic_spikes = rng.gen_range(1..=2);
+        }
+        if rng.gen_bool(0.05) {
+            side_conditions.sticky_web = 1;
+        }
+        if rng.gen_bool(0.1) {
+            side_conditions.reflect = rng.gen_range(1..=5);
+        }
+        if rng.gen_bool(0.1) {
+            side_conditions.light_screen = rng.gen_range(1..=5);
+        }
+        if rng.gen_bool(0.05) {
+            side_conditions.tailwind = rng.gen_range(1..=4);
+        }
+    }
+
+    pub fn random_state(&mut self) -> State {
+        let mut state = State {
+            side_one: self.random_side(),
+            side_two: self.random_side(),
+            ..State::default()
+        };
+        if self.mid_battle {
+            let rng = &mut self.rng;
+            if rng.gen_bool(0.25) {
+                state.weather.weather_type = *[
+                    Weather::SUN,
+                    Weather::RAIN,
+                    Weather::SAND,
+                    if cfg!(feature = "gen9") {
+                        Weather::SNOW
+                    } else {
+                        Weather::HAIL
+                    },
+                ]
+                .choose(rng)
+                .unwrap();
+                state.weather.turns_remaining = rng.gen_range(1..=5);
+            }
+            // terrains were introduced in gen6
+            if !cfg!(any(feature = "gen4", feature = "gen5")) && rng.gen_bool(0.15) {
+                state.terrain.terrain_type = *[
+                    Terrain::ELECTRICTERRAIN,
+                    Terrain::GRASSYTERRAIN,
+                    Terrain::MISTYTERRAIN,
+                    Terrain::PSYCHICTERRAIN,
+                ]
+                .choose(rng)
+                .unwrap();
+                state.terrain.turns_remaining = rng.gen_range(1..=5);
+            }
+            if rng.gen_bool(0.05) {
+                state.trick_room.active = true;
+                state.trick_room.turns_remaining = rng.gen_range(1..=4);
+            }
+        }
+        state.set_conditional_mechanics();
+        state.refresh_zobrist_hash();
+        state
+    }
+}
@@ -0,0 +1,359 @@
+use crate::choices::Choices;
+use crate::damage_branching::{DamageBranching, DamageBranchingSchedule};
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::engine::state::MoveChoice;
+use crate::instruction::PruningStats;
+use crate::mcts::{default_damage_branching, perform_mcts};
+use crate::pokemon::PokemonName;
+use crate::protocol::ShowdownBattle;
+use crate::search::{expectiminimax_search, iterative_deepen_expectiminimax};
+use crate::showdown_team::parse_move;
+use crate::state::{pokemon_index_iter, Move, PokemonStatus, Side, State};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The search used to score the player's options on each turn of a replay
+pub enum ReplaySearch {
+    Expectiminimax(i8),
+    IterativeDeepening(Duration),
+    MonteCarloTreeSearch(Duration),
+}
+
+impl ReplaySearch {
+    /// Scores are evaluations for expectiminimax searches and average scores between
+    /// 0 and 1 for MCTS, so a sensible blunder threshold depends on the search
+    pub fn default_threshold(&self) -> f32 {
+        match self {
+            ReplaySearch::Expectiminimax(_) | ReplaySearch::IterativeDeepening(_) => 10.0,
+            ReplaySearch::MonteCarloTreeSearch(_) => 0.1,
+        }
+    }
+
+    /// The damage branching the search uses when none is given
+    pub fn default_damage_branching(&self) -> DamageBranchingSchedule {
+        match self {
+            ReplaySearch::Expectiminimax(_) | ReplaySearch::IterativeDeepening(_) => {
+                DamageBranching::None.into()
+            }
+            ReplaySearch::MonteCarloTreeSearch(_) => default_damage_branching(),
+        }
+    }
+}
+
+/// The player's choice on one turn of a replay compared to the engine's choice
+pub struct TurnReport {
+    pub turn: u16,
+    pub state: State,
+    pub played: MoveChoice,
+    pub played_score: f32,
+    pub engine_choice: MoveChoice,
+    pub engine_score: f32,
+    pub blunder: bool,
+}
+
+impl TurnReport {
+    pub fn score_difference(&self) -> f32 {
+        self.engine_score - self.played_score
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "turn": self.turn,
+            "played": self.played.to_string(&self.state.side_one),
+            "played_score": self.played_score,
+            "engine_choice": self.engine_choice.to_string(&self.state.side_one),
+            "engine_score": self.engine_score,
+            "difference": self.score_difference(),
+            "blunder": self.blunder,
+            "state": self.state.serialize(),
+        })
+    }
+}
+
+impl std::fmt::Display for TurnReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "turn {}: played {} ({:.2}), engine {} ({:.2}), difference {:.2}",
+            self.turn,
+            self.played.to_string(&self.state.side_one),
+            self.played_score,
+            self.engine_choice.to_string(&self.state.side_one),
+            self.engine_score,
+            self.score_difference()
+        )?;
+        if self.blunder {
+            write!(f, " BLUNDER")?;
+        }
+        Ok(())
+    }
+}
+
+/// Extracts the battle log from a replay saved from Pokemon Showdown as
+/// a .log, .json or .html file
+pub fn replay_log(contents: &str) -> Result<String, String> {
+    if contents.trim_start().starts_with('{') {
+        let replay: Value =
+            serde_json::from_str(contents).map_err(|e| format!("invalid replay: {}", e))?;
+        return replay["log"]
+            .as_str()
+            .map(|log| log.to_string())
+            .ok_or("invalid replay: no log".to_string());
+    }
+    if let Some(start) = contents.find("class=\"battle-log-data\">") {
+        let log = &contents[start + "class=\"battle-log-data\">".len()..];
+        return match log.find("</script>") {
+            Some(end) => Ok(log[..end].to_string()),
+            None => Err("invalid replay: unterminated battle log".to_string()),
+        };
+    }
+    Ok(contents.to_string())
+}
+
+// The player's pokemon with every move, item and ability revealed anywhere in the
+// replay, as the player knew their own team from the start of the battle
+fn revealed_team(log: &str, player_id: &str) -> Result<Side, String> {
+    let mut battle = ShowdownBattle::new(player_id);
+    battle.parse_message(log)?;
+    let mut side = battle.state.side_one;
+    side.mega_evolved = false;
+    side.z_move_used = false;
+    for pkmn_index in pokemon_index_iter() {
+        let pkmn = &mut side.pokemon[pkmn_index];
+        if pkmn.id == PokemonName::NONE {
+            continue;
+        }
+        // a pokemon that mega evolved starts the battle in its base forme
+        if let Some((base_forme, mega_forme)) = pkmn.item.mega_evolution() {
+            if pkmn.id == mega_forme {
+                pkmn.id = base_forme;
+                pkmn.base_types = base_forme.types();
+                pkmn.base_ability = base_forme
+                    .abilities()
+                    .first()
+                    .copied()
+                    .unwrap_or(Abilities::NONE);
+                pkmn.weight_kg = base_forme.weight_kg();
+                let stats = pkmn.calculate_stats_from_base_stats();
+                pkmn.attack = stats.1;
+                pkmn.defense = stats.2;
+                pkmn.special_attack = stats.3;
+                pkmn.special_defense = stats.4;
+                pkmn.speed = stats.5;
+            }
+        }
+        pkmn.hp = pkmn.maxhp;
+        pkmn.status = PokemonStatus::NONE;
+        pkmn.rest_turns = 0;
+        pkmn.sleep_turns = 0;
+        pkmn.types = pkmn.base_types;
+        pkmn.ability = pkmn.base_ability;
+        pkmn.terastallized = false;
+        // an item that was used up or removed is not known
+        if pkmn.item == Items::NONE {
+            pkmn.item = Items::UNKNOWNITEM;
+        }
+        for mv in [
+            &mut pkmn.moves.m0,
+            &mut pkmn.moves.m1,
+            &mut pkmn.moves.m2,
+            &mut pkmn.moves.m3,
+        ] {
+            if mv.id != Choices::NONE {
+                mv.pp = Move::default().pp;
+            }
+        }
+    }
+    Ok(side)
+}
+
+// The state at the start of each turn and the choice the player made on that turn.
+// Turns where the player's choice is not shown (e.g. they were fully paralyzed or
+// fainted before moving) are skipped
+fn played_choices(log: &str, player_id: &str) -> Result<Vec<(u16, State, MoveChoice)>, String> {
+    let mut battle = ShowdownBattle::with_team(player_id, revealed_team(log, player_id)?);
+    let active_prefix = format!("{}a: ", player_id);
+    let mut choices = vec![];
+    let mut turn_start: Option<State> = None;
+    let mut terastallized = false;
+    let mut mega_evolved = false;
+    let mut z_move = false;
+
+    for line in log.lines() {
+        let line = line.trim();
+        battle.parse_line(line)?;
+        if line.starts_with("|turn|") {
+            let mut state = battle.state.clone();
+            state.set_conditional_mechanics();
+            turn_start = Some(state);
+            terastallized = false;
+            mega_evolved = false;
+            z_move = false;
+            continue;
+        }
+        let state = match turn_start.as_ref() {
+            Some(state) => state,
+            None => continue,
+        };
+        let (message, rest) = match line.strip_prefix('|').and_then(|l| l.split_once('|')) {
+            Some(split) => split,
+            None => continue,
+        };
+        if !rest.starts_with(&active_prefix) {
+            continue;
+        }
+        let played = match message {
+            "-terastallize" => {
+                terastallized = true;
+                continue;
+            }
+            "-mega" => {
+                mega_evolved = true;
+                continue;
+            }
+            "-zpower" => {
+                z_move = true;
+                continue;
+            }
+            "move" if !line.contains("|[from]") || line.contains("|[from]lockedmove") => {
+                let choice = parse_move(rest.split('|').nth(1).unwrap_or(""))?;
+                let mut move_iter = state.side_one.get_active_immutable().moves.into_iter();
+                let mut played = None;
+                while let Some(mv) = move_iter.next() {
+                    // a damaging z-move is named after its type rather than the move it was made from
+                    if mv.id == choice
+                        || (z_move
+                            && (mv.choice.to_z_move().move_id == choice
+                                || choice.exclusive_z_move_base() == Some(mv.id)))
+                    {
+                        played = Some(if z_move {
+                            MoveChoice::MoveZ(move_iter.pokemon_move_index)
+                        } else if terastallized {
+                            MoveChoice::MoveTera(move_iter.pokemon_move_index)
+                        } else if mega_evolved {
+                            MoveChoice::MoveMega(move_iter.pokemon_move_index)
+                        } else {
+                            MoveChoice::Move(move_iter.pokemon_move_index)
+                        });
+                        break;
+                    }
+                }
+                played
+            }
+            "switch" => Some(MoveChoice::Switch(battle.state.side_one.active_index)),
+            "cant" | "faint" => None,
+            _ => continue,
+        };
+        if let Some(played) = played {
+            choices.push((battle.turn, state.clone(), played));
+        }
+        turn_start = None;
+    }
+    Ok(choices)
+}
+
+// The score of each of side one's options. Expectiminimax scores are the worst case
+// over side two's options
+fn score_options(
+    state: &State,
+    search: &ReplaySearch,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+) -> Vec<(MoveChoice, f32, i64)> {
+    let mut state = state.clone();
+    let (side_one_options, side_two_options) = state.root_get_all_options();
+    let (side_one_options, side_two_count, matrix) = match search {
+        ReplaySearch::MonteCarloTreeSearch(time) => {
+            let result = perform_mcts(
+                &mut state,
+                side_one_options,
+                side_two_options,
+                *time,
+                damage_branching,
+                prune_threshold,
+            );
+            return result
+                .s1
+                .iter()
+                .map(|r| (r.move_choice, r.average_score(), r.visits))
+                .collect();
+        }
+        ReplaySearch::Expectiminimax(depth) => {
+            let side_two_count = side_two_options.len();
+            let matrix = expectiminimax_search(
+                &mut state,
+                *depth,
+                side_one_options.clone(),
+                side_two_options,
+                true,
+                &Arc::new(Mutex::new(true)),
+                damage_branching,
+                prune_threshold,
+                &mut PruningStats::default(),
+            );
+            (side_one_options, side_two_count, matrix)
+        }
+        ReplaySearch::IterativeDeepening(time) => {
+            let (side_one_options, side_two_options, matrix, _, _) =
+                iterative_deepen_expectiminimax(
+                    &mut state,
+                    side_one_options,
+                    side_two_options,
+                    *time,
+                    damage_branching,
+                    prune_threshold,
+                );
+            (side_one_options, side_two_options.len(), matrix)
+        }
+    };
+    side_one_options
+        .iter()
+        .zip(matrix.chunks(side_two_count))
+        .map(|(choice, row)| (*choice, row.iter().cloned().fold(f32::MAX, f32::min), 0))
+        .collect()
+}
+
+/// Rebuilds the state at the start of each turn of a replay from `player_id`'s
+/// perspective and compares the player's choice with the engine's
+///
+/// The engine's choice is the safest option for expectiminimax searches and the most
+/// visited option for MCTS. A turn is a blunder when the engine's choice scores more
+/// than `threshold` higher than the choice that was played
+///
+/// `damage_branching` and `prune_threshold` are passed on to the search, see
+/// `ReplaySearch::default_damage_branching`
+pub fn analyze_replay(
+    log: &str,
+    player_id: &str,
+    search: &ReplaySearch,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+    threshold: f32,
+) -> Result<Vec<TurnReport>, String> {
+    let mut reports = vec![];
+    for (turn, state, played) in played_choices(log, player_id)? {
+        let scores = score_options(&state, search, damage_branching, prune_threshold);
+        let played_score = match scores.iter().find(|(choice, _, _)| *choice == played) {
+            Some((_, score, _)) => *score,
+            // e.g. a move the engine does not think is available
+            None => continue,
+        };
+        let engine = match search {
+            ReplaySearch::MonteCarloTreeSearch(_) => scores.iter().max_by_key(|s| s.2),
+            _ => scores.iter().max_by(|a, b| a.1.total_cmp(&b.1)),
+        };
+        let (engine_choice, engine_score, _) = *engine.unwrap();
+        reports.push(TurnReport {
+            turn,
+            state,
+            played,
+            played_score,
+            engine_choice,
+            engine_score,
+            blunder: engine_score - played_score > threshold,
+        });
+    }
+    Ok(reports)
+}
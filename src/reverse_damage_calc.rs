@@ -0,0 +1,313 @@
+use crate::choices::{Choices, MoveCategory, MOVES};
+use crate::damage_branching::damage_multiples;
+use crate::engine::generate_instructions::calculate_damage_rolls;
+use crate::engine::items::Items;
+use crate::state::{Pokemon, PokemonBoostableStat, PokemonNature, SideReference, State};
+
+/// Whose spread and item are being inferred from a hit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InferenceTarget {
+    Attacker,
+    Defender,
+}
+
+/// The damage a hit was seen to deal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObservedDamage {
+    // hp lost, e.g. by your own pokemon
+    Exact(i16),
+    // percentage of max hp lost, as Showdown shows for the opponent's pokemon
+    Percent(f32),
+}
+
+/// A nature, EV spread and item that might explain the observed damage.
+/// `Items::NONE` stands for any item that does not change the damage
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadCandidate {
+    pub nature: PokemonNature,
+    pub evs: (u8, u8, u8, u8, u8, u8),
+    pub item: Items,
+}
+
+/// A hit seen during a battle. `state` is the state before the move was used
+#[derive(Debug, Clone)]
+pub struct DamageObservation {
+    pub state: State,
+    pub attacking_side: SideReference,
+    pub choice: Choices,
+    pub damage: ObservedDamage,
+    pub critical_hit: bool,
+}
+
+impl DamageObservation {
+    pub fn new(
+        state: State,
+        attacking_side: SideReference,
+        choice: Choices,
+        damage: ObservedDamage,
+    ) -> DamageObservation {
+        DamageObservation {
+            state,
+            attacking_side,
+            choice,
+            damage,
+            critical_hit: false,
+        }
+    }
+
+    fn target_side(&self, target: InferenceTarget) -> SideReference {
+        match target {
+            InferenceTarget::Attacker => self.attacking_side,
+            InferenceTarget::Defender => self.attacking_side.get_other_side(),
+        }
+    }
+
+    // Gives the target `candidate`'s spread and item in `state`, a copy of `self.state`,
+    // and returns the damage of each roll along with the defender's maxhp. The target
+    // is put back afterwards so that the same state can be reused for every candidate
+    fn damage_rolls_in(
+        &self,
+        state: &mut State,
+        target: InferenceTarget,
+        candidate: &SpreadCandidate,
+    ) -> Option<(Vec<i16>, i16)> {
+        let target_side = self.target_side(target);
+        let pkmn = state.get_side(&target_side).get_active();
+        let original = pkmn.clone();
+        pkmn.item = candidate.item;
+        pkmn.set_nature_and_evs(candidate.nature.clone(), candidate.evs);
+
+        let defender = state
+            .get_side_immutable(&self.attacking_side.get_other_side())
+            .get_active_immutable();
+        let (defender_hp, defender_maxhp) = (defender.hp, defender.maxhp);
+        // calculate_damage_rolls takes its own copy of the state
+        let damage = MOVES.get(&self.choice).and_then(|choice| {
+            calculate_damage_rolls(
+                state.clone(),
+                &self.attacking_side,
+                choice.clone(),
+                MOVES.get(&Choices::NONE).unwrap(),
+            )
+        });
+        *state.get_side(&target_side).get_active() = original;
+
+        let damage = damage?;
+        // the damage of fixed-damage moves does not roll
+        let max_damage = match damage.len() {
+            1 => return Some((vec![damage[0].min(defender_hp)], defender_maxhp)),
+            _ if self.critical_hit => damage[1],
+            _ => damage[0],
+        };
+        let rolls = damage_multiples(max_damage)
+            .iter()
+            .map(|d| (*d as i16).min(defender_hp))
+            .collect();
+        Some((rolls, defender_maxhp))
+    }
+
+    /// The damage of each of the 16 rolls if the target had `candidate`'s spread and item,
+    /// capped at the defender's remaining hp. None if the move does no damage
+    pub fn damage_rolls(
+        &self,
+        target: InferenceTarget,
+        candidate: &SpreadCandidate,
+    ) -> Option<Vec<i16>> {
+        self.damage_rolls_in(&mut self.state.clone(), target, candidate)
+            .map(|(rolls, _)| rolls)
+    }
+
+    fn is_explained_in(
+        &self,
+        state: &mut State,
+        target: InferenceTarget,
+        candidate: &SpreadCandidate,
+    ) -> bool {
+        let (rolls, defender_maxhp) = match self.damage_rolls_in(state, target, candidate) {
+            Some(rolls) => rolls,
+            None => return false,
+        };
+        match self.damage {
+            ObservedDamage::Exact(damage) => rolls.iter().any(|r| (r - damage).abs() <= 1),
+            ObservedDamage::Percent(percent) => {
+                let maxhp = defender_maxhp as f32;
+                rolls
+                    .iter()
+                    .any(|r| (*r as f32 * 100.0 / maxhp - percent).abs() <= 1.0)
+            }
+        }
+    }
+
+    /// Whether one of the damage rolls matches the observed damage. Showdown rounds the
+    /// percentages it shows and the engine's rolls can be 1hp off, so both allow a small error
+    pub fn is_explained_by(&self, target: InferenceTarget, candidate: &SpreadCandidate) -> bool {
+        self.is_explained_in(&mut self.state.clone(), target, candidate)
+    }
+}
+
+// Whether a nature raises, does not change or lowers a stat
+#[derive(Clone, Copy)]
+enum NatureEffect {
+    Raised,
+    Neutral,
+    Lowered,
+}
+
+// A nature with each effect on `stat`. Only the effect on the stat used by the move
+// matters, so the other stat the nature changes is Attack, or Special Attack for
+// Attack itself, like the natures used in competitive play
+fn natures_for_stat(stat: PokemonBoostableStat) -> Vec<PokemonNature> {
+    let other = match stat {
+        PokemonBoostableStat::Attack => PokemonBoostableStat::SpecialAttack,
+        _ => PokemonBoostableStat::Attack,
+    };
+    let with_modifiers = |modifiers| {
+        (0..=PokemonNature::SERIOUS as u8)
+            .map(PokemonNature::from)
+            .find(|nature| nature.stat_modifiers() == Some(modifiers))
+    };
+    [
+        NatureEffect::Raised,
+        NatureEffect::Neutral,
+        NatureEffect::Lowered,
+    ]
+    .iter()
+    .filter_map(|effect| match effect {
+        NatureEffect::Raised => with_modifiers((stat, other)),
+        NatureEffect::Neutral => Some(PokemonNature::SERIOUS),
+        NatureEffect::Lowered => with_modifiers((other, stat)),
+    })
+    .collect()
+}
+
+fn set_ev(evs: &mut (u8, u8, u8, u8, u8, u8), stat: PokemonBoostableStat, amount: u8) {
+    match stat {
+        PokemonBoostableStat::Attack => evs.1 = amount,
+        PokemonBoostableStat::Defense => evs.2 = amount,
+        PokemonBoostableStat::SpecialAttack => evs.3 = amount,
+        PokemonBoostableStat::SpecialDefense => evs.4 = amount,
+        _ => evs.5 = amount,
+    }
+}
+
+fn ev_amounts() -> impl Iterator<Item = u8> + Clone {
+    (0..=252).step_by(4)
+}
+
+/// Every nature, EV and item combination that changes how much damage `choice` does
+/// when `pkmn` is the attacker or the defender. EVs go up in steps of 4 and EVs in
+/// stats that do not affect the damage are left as they are. A revealed item is kept,
+/// otherwise the common items that change the damage are tried
+pub fn candidate_spreads(
+    pkmn: &Pokemon,
+    choice: Choices,
+    target: InferenceTarget,
+) -> Vec<SpreadCandidate> {
+    let category = MOVES
+        .get(&choice)
+        .map_or(MoveCategory::Status, |c| c.category);
+    let (stat, items) = match (target, category) {
+        (InferenceTarget::Attacker, MoveCategory::Special) => (
+            PokemonBoostableStat::SpecialAttack,
+            vec![
+                Items::NONE,
+                Items::CHOICESPECS,
+                Items::LIFEORB,
+                Items::EXPERTBELT,
+            ],
+        ),
+        (InferenceTarget::Attacker, _) => (
+            PokemonBoostableStat::Attack,
+            vec![
+                Items::NONE,
+                Items::CHOICEBAND,
+                Items::LIFEORB,
+                Items::EXPERTBELT,
+            ],
+        ),
+        (InferenceTarget::Defender, MoveCategory::Special) => (
+            PokemonBoostableStat::SpecialDefense,
+            vec![Items::NONE, Items::ASSAULTVEST, Items::EVIOLITE],
+        ),
+        (InferenceTarget::Defender, _) => (
+            PokemonBoostableStat::Defense,
+            vec![Items::NONE, Items::EVIOLITE],
+        ),
+    };
+    let items = match pkmn.item {
+        Items::UNKNOWNITEM => items,
+        item => vec![item],
+    };
+    // a defender's hp EVs also change the damage it takes as a percentage
+    let hp_amounts: Vec<u8> = match target {
+        InferenceTarget::Attacker => vec![pkmn.evs.0],
+        InferenceTarget::Defender => ev_amounts().collect(),
+    };
+
+    let mut candidates = vec![];
+    for item in items.iter() {
+        for nature in natures_for_stat(stat) {
+            for hp in hp_amounts.iter() {
+                for amount in ev_amounts() {
+                    let mut evs = pkmn.evs;
+                    evs.0 = *hp;
+                    set_ev(&mut evs, stat, amount);
+                    candidates.push(SpreadCandidate {
+                        nature: nature.clone(),
+                        evs,
+                        item: *item,
+                    });
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Narrows down the spreads and items of one pokemon that could explain the damage
+/// of every hit observed so far
+#[derive(Debug, Clone)]
+pub struct ReverseDamageCalc {
+    pub target: InferenceTarget,
+    pub candidates: Vec<SpreadCandidate>,
+}
+
+impl ReverseDamageCalc {
+    pub fn new(target: InferenceTarget, candidates: Vec<SpreadCandidate>) -> ReverseDamageCalc {
+        ReverseDamageCalc { target, candidates }
+    }
+
+    /// Starts from every candidate in `candidate_spreads` for the observed pokemon
+    /// and keeps the ones that explain the hit
+    pub fn from_observation(
+        observation: &DamageObservation,
+        target: InferenceTarget,
+    ) -> ReverseDamageCalc {
+        let pkmn = observation
+            .state
+            .get_side_immutable(&observation.target_side(target))
+            .get_active_immutable();
+        let mut reverse_calc =
+            ReverseDamageCalc::new(target, candidate_spreads(pkmn, observation.choice, target));
+        reverse_calc.observe(observation);
+        reverse_calc
+    }
+
+    /// Removes the candidates that do not explain the observed hit
+    pub fn observe(&mut self, observation: &DamageObservation) {
+        let target = self.target;
+        self.candidates
+            .retain(|candidate| observation.is_explained_by(target, candidate));
+    }
+
+    /// The items that could explain every hit observed so far
+    pub fn possible_items(&self) -> Vec<Items> {
+        let mut items = vec![];
+        for candidate in self.candidates.iter() {
+            if !items.contains(&candidate.item) {
+                items.push(candidate.item);
+            }
+        }
+        items
+    }
+}
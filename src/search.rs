@@ -1,6 +1,8 @@
+use crate::damage_branching::DamageBranchingSchedule;
 use crate::engine::evaluate::evaluate;
-use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+use crate::engine::generate_instructions::generate_instructions_from_move_pair_with_pruning_stats;
 use crate::engine::state::MoveChoice;
+use crate::instruction::PruningStats;
 use crate::state::State;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, Mutex};
@@ -8,16 +10,49 @@ use std::thread;
 use std::time::Duration;
 
 enum IterativeDeependingThreadMessage {
-    Stop((Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8)),
+    Stop((Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8, PruningStats)),
 }
 
+// Branches less likely than `prune_threshold` percent are dropped, and how much was dropped
+// is added to `pruning_stats`
+#[allow(clippy::too_many_arguments)]
 pub fn expectiminimax_search(
+    state: &mut State,
+    depth: i8,
+    side_one_options: Vec<MoveChoice>,
+    side_two_options: Vec<MoveChoice>,
+    ab_prune: bool,
+    mtx: &Arc<Mutex<bool>>,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
+) -> Vec<f32> {
+    expectiminimax_search_at_ply(
+        state,
+        depth,
+        side_one_options,
+        side_two_options,
+        ab_prune,
+        mtx,
+        damage_branching,
+        prune_threshold,
+        pruning_stats,
+        0,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expectiminimax_search_at_ply(
     state: &mut State,
     mut depth: i8,
     side_one_options: Vec<MoveChoice>,
     side_two_options: Vec<MoveChoice>,
     ab_prune: bool,
     mtx: &Arc<Mutex<bool>>,
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+    pruning_stats: &mut PruningStats,
+    ply: usize,
 ) -> Vec<f32> {
     depth -= 1;
     let num_s1_moves = side_one_options.len();
@@ -52,8 +87,14 @@ pub fn expectiminimax_search(
             }
 
             let mut score = 0.0;
-            let instructions =
-                generate_instructions_from_move_pair(state, &side_one_move, &side_two_move, false);
+            let instructions = generate_instructions_from_move_pair_with_pruning_stats(
+                state,
+                &side_one_move,
+                &side_two_move,
+                damage_branching.at_ply(ply),
+                prune_threshold,
+                pruning_stats,
+            );
             if depth == 0 {
                 for instruction in instructions.iter() {
                     state.apply_instructions(&instruction.instruction_list);
@@ -69,13 +110,17 @@ pub fn expectiminimax_search(
                     let next_turn_side_one_options_len = next_turn_side_one_options.len();
                     let next_turn_side_two_options_len = next_turn_side_two_options.len();
                     let (_, safest) = pick_safest(
-                        &expectiminimax_search(
+                        &expectiminimax_search_at_ply(
                             state,
                             depth,
                             next_turn_side_one_options,
                             next_turn_side_two_options,
                             true, // until there is something better than `pick_safest` for evaluating a sub-game, there is no point in this being anything other than `true`
                             &mtx,
+                            damage_branching,
+                            prune_threshold,
+                            pruning_stats,
+                            ply + 1,
                         ),
                         next_turn_side_one_options_len,
                         next_turn_side_two_options_len,
@@ -163,9 +208,13 @@ pub fn iterative_deepen_expectiminimax(
     side_one_options: Vec<MoveChoice>,
     side_two_options: Vec<MoveChoice>,
     max_time: Duration,
-) -> (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8) {
+    damage_branching: &DamageBranchingSchedule,
+    prune_threshold: f32,
+) -> (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8, PruningStats) {
     let mut state_clone = state.clone();
+    let damage_branching_clone = damage_branching.clone();
 
+    let mut pruning_stats = PruningStats::default();
     let mut result = expectiminimax_search(
         state,
         1,
@@ -173,6 +222,9 @@ pub fn iterative_deepen_expectiminimax(
         side_two_options.clone(),
         true,
         &Arc::new(Mutex::new(true)),
+        damage_branching,
+        prune_threshold,
+        &mut pruning_stats,
     );
     let (mut re_ordered_s1_options, mut re_ordered_s2_options) =
         re_order_moves_for_iterative_deepening(&result, side_one_options, side_two_options);
@@ -190,6 +242,8 @@ pub fn iterative_deepen_expectiminimax(
         let mut previous_turn_s2_options = re_ordered_s2_options.clone();
         loop {
             let previous_result = result;
+            let previous_pruning_stats = pruning_stats;
+            pruning_stats = PruningStats::default();
             i += 1;
             result = expectiminimax_search(
                 &mut state_clone,
@@ -198,6 +252,9 @@ pub fn iterative_deepen_expectiminimax(
                 re_ordered_s2_options.clone(),
                 true,
                 &running_clone,
+                &damage_branching_clone,
+                prune_threshold,
+                &mut pruning_stats,
             );
 
             // when we are told to stop, return the *previous* result.
@@ -209,6 +266,7 @@ pub fn iterative_deepen_expectiminimax(
                         previous_turn_s2_options,
                         previous_result,
                         i - 1,
+                        previous_pruning_stats,
                     )))
                     .unwrap();
                 break;
@@ -0,0 +1,1533 @@
+use crate::choices::Choices;
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::pokemon::PokemonName;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+// Display names that Pokemon Showdown exports, e.g. GARCHOMPMEGA is "Garchomp-Mega"
+// Names that are a single capitalized word, e.g. "Garchomp" or "Leftovers", are not listed
+lazy_static! {
+    static ref SPECIES_NAMES: HashMap<&'static str, &'static str> = HashMap::from([
+        ("ABOMASNOWMEGA", "Abomasnow-Mega"),
+        ("ABSOLMEGA", "Absol-Mega"),
+        ("AEGISLASHBLADE", "Aegislash-Blade"),
+        ("AERODACTYLMEGA", "Aerodactyl-Mega"),
+        ("AGGRONMEGA", "Aggron-Mega"),
+        ("ALAKAZAMMEGA", "Alakazam-Mega"),
+        ("ALCREMIECARAMELSWIRL", "Alcremie-Caramel-Swirl"),
+        ("ALCREMIEGMAX", "Alcremie-Gmax"),
+        ("ALCREMIELEMONCREAM", "Alcremie-Lemon-Cream"),
+        ("ALCREMIEMATCHACREAM", "Alcremie-Matcha-Cream"),
+        ("ALCREMIEMINTCREAM", "Alcremie-Mint-Cream"),
+        ("ALCREMIERAINBOWSWIRL", "Alcremie-Rainbow-Swirl"),
+        ("ALCREMIERUBYCREAM", "Alcremie-Ruby-Cream"),
+        ("ALCREMIERUBYSWIRL", "Alcremie-Ruby-Swirl"),
+        ("ALCREMIESALTEDCREAM", "Alcremie-Salted-Cream"),
+        ("ALTARIAMEGA", "Altaria-Mega"),
+        ("AMPHAROSMEGA", "Ampharos-Mega"),
+        ("APPLETUNGMAX", "Appletun-Gmax"),
+        ("ARAQUANIDTOTEM", "Araquanid-Totem"),
+        ("ARCANINEHISUI", "Arcanine-Hisui"),
+        ("ARCEUSBUG", "Arceus-Bug"),
+        ("ARCEUSDARK", "Arceus-Dark"),
+        ("ARCEUSDRAGON", "Arceus-Dragon"),
+        ("ARCEUSELECTRIC", "Arceus-Electric"),
+        ("ARCEUSFAIRY", "Arceus-Fairy"),
+        ("ARCEUSFIGHTING", "Arceus-Fighting"),
+        ("ARCEUSFIRE", "Arceus-Fire"),
+        ("ARCEUSFLYING", "Arceus-Flying"),
+        ("ARCEUSGHOST", "Arceus-Ghost"),
+        ("ARCEUSGRASS", "Arceus-Grass"),
+        ("ARCEUSGROUND", "Arceus-Ground"),
+        ("ARCEUSICE", "Arceus-Ice"),
+        ("ARCEUSPOISON", "Arceus-Poison"),
+        ("ARCEUSPSYCHIC", "Arceus-Psychic"),
+        ("ARCEUSROCK", "Arceus-Rock"),
+        ("ARCEUSSTEEL", "Arceus-Steel"),
+        ("ARCEUSWATER", "Arceus-Water"),
+        ("ARTICUNOGALAR", "Articuno-Galar"),
+        ("AUDINOMEGA", "Audino-Mega"),
+        ("AVALUGGHISUI", "Avalugg-Hisui"),
+        ("BANETTEMEGA", "Banette-Mega"),
+        ("BASCULEGIONF", "Basculegion-F"),
+        ("BASCULINBLUESTRIPED", "Basculin-Blue-Striped"),
+        ("BASCULINWHITESTRIPED", "Basculin-White-Striped"),
+        ("BEEDRILLMEGA", "Beedrill-Mega"),
+        ("BLASTOISEGMAX", "Blastoise-Gmax"),
+        ("BLASTOISEMEGA", "Blastoise-Mega"),
+        ("BLAZIKENMEGA", "Blaziken-Mega"),
+        ("BRAVIARYHISUI", "Braviary-Hisui"),
+        ("BRUTEBONNET", "Brute Bonnet"),
+        ("BURMYSANDY", "Burmy-Sandy"),
+        ("BURMYTRASH", "Burmy-Trash"),
+        ("BUTTERFREEGMAX", "Butterfree-Gmax"),
+        ("CALYREXICE", "Calyrex-Ice"),
+        ("CALYREXSHADOW", "Calyrex-Shadow"),
+        ("CAMERUPTMEGA", "Camerupt-Mega"),
+        ("CASTFORMRAINY", "Castform-Rainy"),
+        ("CASTFORMSNOWY", "Castform-Snowy"),
+        ("CASTFORMSUNNY", "Castform-Sunny"),
+        ("CENTISKORCHGMAX", "Centiskorch-Gmax"),
+        ("CHARIZARDGMAX", "Charizard-Gmax"),
+        ("CHARIZARDMEGAX", "Charizard-Mega-X"),
+        ("CHARIZARDMEGAY", "Charizard-Mega-Y"),
+        ("CHERRIMSUNSHINE", "Cherrim-Sunshine"),
+        ("CHIENPAO", "Chien-Pao"),
+        ("CHIYU", "Chi-Yu"),
+        ("CINDERACEGMAX", "Cinderace-Gmax"),
+        ("COALOSSALGMAX", "Coalossal-Gmax"),
+        ("COPPERAJAHGMAX", "Copperajah-Gmax"),
+        ("CORSOLAGALAR", "Corsola-Galar"),
+        ("CORVIKNIGHTGMAX", "Corviknight-Gmax"),
+        ("CRAMORANTGORGING", "Cramorant-Gorging"),
+        ("CRAMORANTGULPING", "Cramorant-Gulping"),
+        ("DARMANITANGALAR", "Darmanitan-Galar"),
+        ("DARMANITANGALARZEN", "Darmanitan-Galar-Zen"),
+        ("DARMANITANZEN", "Darmanitan-Zen"),
+        ("DARUMAKAGALAR", "Darumaka-Galar"),
+        ("DECIDUEYEHISUI", "Decidueye-Hisui"),
+        ("DEERLINGAUTUMN", "Deerling-Autumn"),
+        ("DEERLINGSUMMER", "Deerling-Summer"),
+        ("DEERLINGWINTER", "Deerling-Winter"),
+        ("DEOXYSATTACK", "Deoxys-Attack"),
+        ("DEOXYSDEFENSE", "Deoxys-Defense"),
+        ("DEOXYSSPEED", "Deoxys-Speed"),
+        ("DIALGAORIGIN", "Dialga-Origin"),
+        ("DIANCIEMEGA", "Diancie-Mega"),
+        ("DIGLETTALOLA", "Diglett-Alola"),
+        ("DREDNAWGMAX", "Drednaw-Gmax"),
+        ("DUDUNSPARCETHREESEGMENT", "Dudunsparce-Three-Segment"),
+        ("DUGTRIOALOLA", "Dugtrio-Alola"),
+        ("DURALUDONGMAX", "Duraludon-Gmax"),
+        ("EEVEEGMAX", "Eevee-Gmax"),
+        ("EEVEESTARTER", "Eevee-Starter"),
+        ("EISCUENOICE", "Eiscue-Noice"),
+        ("ELECTRODEHISUI", "Electrode-Hisui"),
+        ("ENAMORUSTHERIAN", "Enamorus-Therian"),
+        ("ETERNATUSETERNAMAX", "Eternatus-Eternamax"),
+        ("EXEGGUTORALOLA", "Exeggutor-Alola"),
+        ("FARFETCHD", "Farfetch’d"),
+        ("FARFETCHDGALAR", "Farfetch’d-Galar"),
+        ("FLABEBE", "Flabébé"),
+        ("FLABEBEBLUE", "Flabébé-Blue"),
+        ("FLABEBEORANGE", "Flabébé-Orange"),
+        ("FLABEBEWHITE", "Flabébé-White"),
+        ("FLABEBEYELLOW", "Flabébé-Yellow"),
+        ("FLAPPLEGMAX", "Flapple-Gmax"),
+        ("FLOETTEBLUE", "Floette-Blue"),
+        ("FLOETTEETERNAL", "Floette-Eternal"),
+        ("FLOETTEORANGE", "Floette-Orange"),
+        ("FLOETTEWHITE", "Floette-White"),
+        ("FLOETTEYELLOW", "Floette-Yellow"),
+        ("FLORGESBLUE", "Florges-Blue"),
+        ("FLORGESORANGE", "Florges-Orange"),
+        ("FLORGESWHITE", "Florges-White"),
+        ("FLORGESYELLOW", "Florges-Yellow"),
+        ("FLUTTERMANE", "Flutter Mane"),
+        ("FURFROUDANDY", "Furfrou-Dandy"),
+        ("FURFROUDEBUTANTE", "Furfrou-Debutante"),
+        ("FURFROUDIAMOND", "Furfrou-Diamond"),
+        ("FURFROUHEART", "Furfrou-Heart"),
+        ("FURFROUKABUKI", "Furfrou-Kabuki"),
+        ("FURFROULAREINE", "Furfrou-La Reine"),
+        ("FURFROUMATRON", "Furfrou-Matron"),
+        ("FURFROUPHARAOH", "Furfrou-Pharaoh"),
+        ("FURFROUSTAR", "Furfrou-Star"),
+        ("GALLADEMEGA", "Gallade-Mega"),
+        ("GARBODORGMAX", "Garbodor-Gmax"),
+        ("GARCHOMPMEGA", "Garchomp-Mega"),
+        ("GARDEVOIRMEGA", "Gardevoir-Mega"),
+        ("GASTRODONEAST", "Gastrodon-East"),
+        ("GENESECTBURN", "Genesect-Burn"),
+        ("GENESECTCHILL", "Genesect-Chill"),
+        ("GENESECTDOUSE", "Genesect-Douse"),
+        ("GENESECTSHOCK", "Genesect-Shock"),
+        ("GENGARGMAX", "Gengar-Gmax"),
+        ("GENGARMEGA", "Gengar-Mega"),
+        ("GEODUDEALOLA", "Geodude-Alola"),
+        ("GIMMIGHOULROAMING", "Gimmighoul-Roaming"),
+        ("GIRATINAORIGIN", "Giratina-Origin"),
+        ("GLALIEMEGA", "Glalie-Mega"),
+        ("GOLEMALOLA", "Golem-Alola"),
+        ("GOODRAHISUI", "Goodra-Hisui"),
+        ("GOUGINGFIRE", "Gouging Fire"),
+        ("GOURGEISTLARGE", "Gourgeist-Large"),
+        ("GOURGEISTSMALL", "Gourgeist-Small"),
+        ("GOURGEISTSUPER", "Gourgeist-Super"),
+        ("GRAVELERALOLA", "Graveler-Alola"),
+        ("GREATTUSK", "Great Tusk"),
+        ("GRENINJAASH", "Greninja-Ash"),
+        ("GRENINJABOND", "Greninja-Bond"),
+        ("GRIMERALOLA", "Grimer-Alola"),
+        ("GRIMMSNARLGMAX", "Grimmsnarl-Gmax"),
+        ("GROUDONPRIMAL", "Groudon-Primal"),
+        ("GROWLITHEHISUI", "Growlithe-Hisui"),
+        ("GUMSHOOSTOTEM", "Gumshoos-Totem"),
+        ("GYARADOSMEGA", "Gyarados-Mega"),
+        ("HAKAMOO", "Hakamo-o"),
+        ("HATTERENEGMAX", "Hatterene-Gmax"),
+        ("HERACROSSMEGA", "Heracross-Mega"),
+        ("HOOH", "Ho-Oh"),
+        ("HOOPAUNBOUND", "Hoopa-Unbound"),
+        ("HOUNDOOMMEGA", "Houndoom-Mega"),
+        ("INDEEDEEF", "Indeedee-F"),
+        ("INTELEONGMAX", "Inteleon-Gmax"),
+        ("IRONBOULDER", "Iron Boulder"),
+        ("IRONBUNDLE", "Iron Bundle"),
+        ("IRONCROWN", "Iron Crown"),
+        ("IRONHANDS", "Iron Hands"),
+        ("IRONJUGULIS", "Iron Jugulis"),
+        ("IRONLEAVES", "Iron Leaves"),
+        ("IRONMOTH", "Iron Moth"),
+        ("IRONTHORNS", "Iron Thorns"),
+        ("IRONTREADS", "Iron Treads"),
+        ("IRONVALIANT", "Iron Valiant"),
+        ("JANGMOO", "Jangmo-o"),
+        ("KANGASKHANMEGA", "Kangaskhan-Mega"),
+        ("KELDEORESOLUTE", "Keldeo-Resolute"),
+        ("KINGLERGMAX", "Kingler-Gmax"),
+        ("KOMMOO", "Kommo-o"),
+        ("KOMMOOTOTEM", "Kommo-o-Totem"),
+        ("KYOGREPRIMAL", "Kyogre-Primal"),
+        ("KYUREMBLACK", "Kyurem-Black"),
+        ("KYUREMWHITE", "Kyurem-White"),
+        ("LANDORUSTHERIAN", "Landorus-Therian"),
+        ("LAPRASGMAX", "Lapras-Gmax"),
+        ("LATIASMEGA", "Latias-Mega"),
+        ("LATIOSMEGA", "Latios-Mega"),
+        ("LILLIGANTHISUI", "Lilligant-Hisui"),
+        ("LINOONEGALAR", "Linoone-Galar"),
+        ("LOPUNNYMEGA", "Lopunny-Mega"),
+        ("LUCARIOMEGA", "Lucario-Mega"),
+        ("LURANTISTOTEM", "Lurantis-Totem"),
+        ("LYCANROCDUSK", "Lycanroc-Dusk"),
+        ("LYCANROCMIDNIGHT", "Lycanroc-Midnight"),
+        ("MACHAMPGMAX", "Machamp-Gmax"),
+        ("MAGEARNAORIGINAL", "Magearna-Original"),
+        ("MANECTRICMEGA", "Manectric-Mega"),
+        ("MAROWAKALOLA", "Marowak-Alola"),
+        ("MAROWAKALOLATOTEM", "Marowak-Alola-Totem"),
+        ("MAUSHOLDFOUR", "Maushold-Four"),
+        ("MAWILEMEGA", "Mawile-Mega"),
+        ("MEDICHAMMEGA", "Medicham-Mega"),
+        ("MELMETALGMAX", "Melmetal-Gmax"),
+        ("MELOETTAPIROUETTE", "Meloetta-Pirouette"),
+        ("MEOWSTICF", "Meowstic-F"),
+        ("MEOWTHALOLA", "Meowth-Alola"),
+        ("MEOWTHGALAR", "Meowth-Galar"),
+        ("MEOWTHGMAX", "Meowth-Gmax"),
+        ("METAGROSSMEGA", "Metagross-Mega"),
+        ("MEWTWOMEGAX", "Mewtwo-Mega-X"),
+        ("MEWTWOMEGAY", "Mewtwo-Mega-Y"),
+        ("MIMEJR", "Mime Jr."),
+        ("MIMIKYUBUSTED", "Mimikyu-Busted"),
+        ("MIMIKYUBUSTEDTOTEM", "Mimikyu-Busted-Totem"),
+        ("MIMIKYUTOTEM", "Mimikyu-Totem"),
+        ("MINIORBLUE", "Minior-Blue"),
+        ("MINIORGREEN", "Minior-Green"),
+        ("MINIORINDIGO", "Minior-Indigo"),
+        ("MINIORMETEOR", "Minior-Meteor"),
+        ("MINIORORANGE", "Minior-Orange"),
+        ("MINIORVIOLET", "Minior-Violet"),
+        ("MINIORYELLOW", "Minior-Yellow"),
+        ("MOLTRESGALAR", "Moltres-Galar"),
+        ("MORPEKOHANGRY", "Morpeko-Hangry"),
+        ("MRMIME", "Mr. Mime"),
+        ("MRMIMEGALAR", "Mr. Mime-Galar"),
+        ("MRRIME", "Mr. Rime"),
+        ("MUKALOLA", "Muk-Alola"),
+        ("NECROZMADAWNWINGS", "Necrozma-Dawn-Wings"),
+        ("NECROZMADUSKMANE", "Necrozma-Dusk-Mane"),
+        ("NECROZMAULTRA", "Necrozma-Ultra"),
+        ("NIDORANF", "Nidoran-F"),
+        ("NIDORANM", "Nidoran-M"),
+        ("NINETALESALOLA", "Ninetales-Alola"),
+        ("OGERPONCORNERSTONE", "Ogerpon-Cornerstone"),
+        ("OGERPONCORNERSTONETERA", "Ogerpon-Cornerstone-Tera"),
+        ("OGERPONHEARTHFLAME", "Ogerpon-Hearthflame"),
+        ("OGERPONHEARTHFLAMETERA", "Ogerpon-Hearthflame-Tera"),
+        ("OGERPONTEALTERA", "Ogerpon-Teal-Tera"),
+        ("OGERPONWELLSPRING", "Ogerpon-Wellspring"),
+        ("OGERPONWELLSPRINGTERA", "Ogerpon-Wellspring-Tera"),
+        ("OINKOLOGNEF", "Oinkologne-F"),
+        ("ORBEETLEGMAX", "Orbeetle-Gmax"),
+        ("ORICORIOPAU", "Oricorio-Pa'u"),
+        ("ORICORIOPOMPOM", "Oricorio-Pom-Pom"),
+        ("ORICORIOSENSU", "Oricorio-Sensu"),
+        ("PALAFINHERO", "Palafin-Hero"),
+        ("PALKIAORIGIN", "Palkia-Origin"),
+        ("PERSIANALOLA", "Persian-Alola"),
+        ("PICHUSPIKYEARED", "Pichu-Spiky-eared"),
+        ("PIDGEOTMEGA", "Pidgeot-Mega"),
+        ("PIKACHUALOLA", "Pikachu-Alola"),
+        ("PIKACHUBELLE", "Pikachu-Belle"),
+        ("PIKACHUCOSPLAY", "Pikachu-Cosplay"),
+        ("PIKACHUGMAX", "Pikachu-Gmax"),
+        ("PIKACHUHOENN", "Pikachu-Hoenn"),
+        ("PIKACHUKALOS", "Pikachu-Kalos"),
+        ("PIKACHULIBRE", "Pikachu-Libre"),
+        ("PIKACHUORIGINAL", "Pikachu-Original"),
+        ("PIKACHUPARTNER", "Pikachu-Partner"),
+        ("PIKACHUPHD", "Pikachu-PhD"),
+        ("PIKACHUPOPSTAR", "Pikachu-Pop-Star"),
+        ("PIKACHUROCKSTAR", "Pikachu-Rock-Star"),
+        ("PIKACHUSINNOH", "Pikachu-Sinnoh"),
+        ("PIKACHUSTARTER", "Pikachu-Starter"),
+        ("PIKACHUUNOVA", "Pikachu-Unova"),
+        ("PIKACHUWORLD", "Pikachu-World"),
+        ("PINSIRMEGA", "Pinsir-Mega"),
+        ("POLTCHAGEISTARTISAN", "Poltchageist-Artisan"),
+        ("POLTEAGEISTANTIQUE", "Polteageist-Antique"),
+        ("PONYTAGALAR", "Ponyta-Galar"),
+        ("PORYGONZ", "Porygon-Z"),
+        ("PUMPKABOOLARGE", "Pumpkaboo-Large"),
+        ("PUMPKABOOSMALL", "Pumpkaboo-Small"),
+        ("PUMPKABOOSUPER", "Pumpkaboo-Super"),
+        ("QWILFISHHISUI", "Qwilfish-Hisui"),
+        ("RAGINGBOLT", "Raging Bolt"),
+        ("RAICHUALOLA", "Raichu-Alola"),
+        ("RAPIDASHGALAR", "Rapidash-Galar"),
+        ("RATICATEALOLA", "Raticate-Alola"),
+        ("RATICATEALOLATOTEM", "Raticate-Alola-Totem"),
+        ("RATTATAALOLA", "Rattata-Alola"),
+        ("RAYQUAZAMEGA", "Rayquaza-Mega"),
+        ("RIBOMBEETOTEM", "Ribombee-Totem"),
+        ("RILLABOOMGMAX", "Rillaboom-Gmax"),
+        ("ROARINGMOON", "Roaring Moon"),
+        ("ROTOMFAN", "Rotom-Fan"),
+        ("ROTOMFROST", "Rotom-Frost"),
+        ("ROTOMHEAT", "Rotom-Heat"),
+        ("ROTOMMOW", "Rotom-Mow"),
+        ("ROTOMWASH", "Rotom-Wash"),
+        ("SABLEYEMEGA", "Sableye-Mega"),
+        ("SALAMENCEMEGA", "Salamence-Mega"),
+        ("SALAZZLETOTEM", "Salazzle-Totem"),
+        ("SAMUROTTHISUI", "Samurott-Hisui"),
+        ("SANDACONDAGMAX", "Sandaconda-Gmax"),
+        ("SANDSHREWALOLA", "Sandshrew-Alola"),
+        ("SANDSLASHALOLA", "Sandslash-Alola"),
+        ("SANDYSHOCKS", "Sandy Shocks"),
+        ("SAWSBUCKAUTUMN", "Sawsbuck-Autumn"),
+        ("SAWSBUCKSUMMER", "Sawsbuck-Summer"),
+        ("SAWSBUCKWINTER", "Sawsbuck-Winter"),
+        ("SCEPTILEMEGA", "Sceptile-Mega"),
+        ("SCIZORMEGA", "Scizor-Mega"),
+        ("SCREAMTAIL", "Scream Tail"),
+        ("SHARPEDOMEGA", "Sharpedo-Mega"),
+        ("SHAYMINSKY", "Shaymin-Sky"),
+        ("SHELLOSEAST", "Shellos-East"),
+        ("SILVALLYBUG", "Silvally-Bug"),
+        ("SILVALLYDARK", "Silvally-Dark"),
+        ("SILVALLYDRAGON", "Silvally-Dragon"),
+        ("SILVALLYELECTRIC", "Silvally-Electric"),
+        ("SILVALLYFAIRY", "Silvally-Fairy"),
+        ("SILVALLYFIGHTING", "Silvally-Fighting"),
+        ("SILVALLYFIRE", "Silvally-Fire"),
+        ("SILVALLYFLYING", "Silvally-Flying"),
+        ("SILVALLYGHOST", "Silvally-Ghost"),
+        ("SILVALLYGRASS", "Silvally-Grass"),
+        ("SILVALLYGROUND", "Silvally-Ground"),
+        ("SILVALLYICE", "Silvally-Ice"),
+        ("SILVALLYPOISON", "Silvally-Poison"),
+        ("SILVALLYPSYCHIC", "Silvally-Psychic"),
+        ("SILVALLYROCK", "Silvally-Rock"),
+        ("SILVALLYSTEEL", "Silvally-Steel"),
+        ("SILVALLYWATER", "Silvally-Water"),
+        ("SINISTCHAMASTERPIECE", "Sinistcha-Masterpiece"),
+        ("SINISTEAANTIQUE", "Sinistea-Antique"),
+        ("SIRFETCHD", "Sirfetch’d"),
+        ("SLIGGOOHISUI", "Sliggoo-Hisui"),
+        ("SLITHERWING", "Slither Wing"),
+        ("SLOWBROGALAR", "Slowbro-Galar"),
+        ("SLOWBROMEGA", "Slowbro-Mega"),
+        ("SLOWKINGGALAR", "Slowking-Galar"),
+        ("SLOWPOKEGALAR", "Slowpoke-Galar"),
+        ("SNEASELHISUI", "Sneasel-Hisui"),
+        ("SNORLAXGMAX", "Snorlax-Gmax"),
+        ("SQUAWKABILLYBLUE", "Squawkabilly-Blue"),
+        ("SQUAWKABILLYWHITE", "Squawkabilly-White"),
+        ("SQUAWKABILLYYELLOW", "Squawkabilly-Yellow"),
+        ("STEELIXMEGA", "Steelix-Mega"),
+        ("STUNFISKGALAR", "Stunfisk-Galar"),
+        ("SWAMPERTMEGA", "Swampert-Mega"),
+        ("TAPUBULU", "Tapu Bulu"),
+        ("TAPUFINI", "Tapu Fini"),
+        ("TAPUKOKO", "Tapu Koko"),
+        ("TAPULELE", "Tapu Lele"),
+        ("TATSUGIRIDROOPY", "Tatsugiri-Droopy"),
+        ("TATSUGIRISTRETCHY", "Tatsugiri-Stretchy"),
+        ("TAUROSPALDEAAQUA", "Tauros-Paldea-Aqua"),
+        ("TAUROSPALDEABLAZE", "Tauros-Paldea-Blaze"),
+        ("TAUROSPALDEACOMBAT", "Tauros-Paldea-Combat"),
+        ("TERAPAGOSSTELLAR", "Terapagos-Stellar"),
+        ("TERAPAGOSTERASTAL", "Terapagos-Terastal"),
+        ("THUNDURUSTHERIAN", "Thundurus-Therian"),
+        ("TINGLU", "Ting-Lu"),
+        ("TOGEDEMARUTOTEM", "Togedemaru-Totem"),
+        ("TORNADUSTHERIAN", "Tornadus-Therian"),
+        ("TOXTRICITYGMAX", "Toxtricity-Gmax"),
+        ("TOXTRICITYLOWKEY", "Toxtricity-Low-Key"),
+        ("TOXTRICITYLOWKEYGMAX", "Toxtricity-Low-Key-Gmax"),
+        ("TYPENULL", "Type: Null"),
+        ("TYPHLOSIONHISUI", "Typhlosion-Hisui"),
+        ("TYRANITARMEGA", "Tyranitar-Mega"),
+        ("UNOWNB", "Unown-B"),
+        ("UNOWNC", "Unown-C"),
+        ("UNOWND", "Unown-D"),
+        ("UNOWNE", "Unown-E"),
+        ("UNOWNEXCLAMATION", "Unown-Exclamation"),
+        ("UNOWNF", "Unown-F"),
+        ("UNOWNG", "Unown-G"),
+        ("UNOWNH", "Unown-H"),
+        ("UNOWNI", "Unown-I"),
+        ("UNOWNJ", "Unown-J"),
+        ("UNOWNK", "Unown-K"),
+        ("UNOWNL", "Unown-L"),
+        ("UNOWNM", "Unown-M"),
+        ("UNOWNN", "Unown-N"),
+        ("UNOWNO", "Unown-O"),
+        ("UNOWNP", "Unown-P"),
+        ("UNOWNQ", "Unown-Q"),
+        ("UNOWNQUESTION", "Unown-Question"),
+        ("UNOWNR", "Unown-R"),
+        ("UNOWNS", "Unown-S"),
+        ("UNOWNT", "Unown-T"),
+        ("UNOWNU", "Unown-U"),
+        ("UNOWNV", "Unown-V"),
+        ("UNOWNW", "Unown-W"),
+        ("UNOWNX", "Unown-X"),
+        ("UNOWNY", "Unown-Y"),
+        ("UNOWNZ", "Unown-Z"),
+        ("URSALUNABLOODMOON", "Ursaluna-Bloodmoon"),
+        ("URSHIFUGMAX", "Urshifu-Gmax"),
+        ("URSHIFURAPIDSTRIKE", "Urshifu-Rapid-Strike"),
+        ("URSHIFURAPIDSTRIKEGMAX", "Urshifu-Rapid-Strike-Gmax"),
+        ("VENUSAURGMAX", "Venusaur-Gmax"),
+        ("VENUSAURMEGA", "Venusaur-Mega"),
+        ("VIKAVOLTTOTEM", "Vikavolt-Totem"),
+        ("VIVILLONARCHIPELAGO", "Vivillon-Archipelago"),
+        ("VIVILLONCONTINENTAL", "Vivillon-Continental"),
+        ("VIVILLONELEGANT", "Vivillon-Elegant"),
+        ("VIVILLONFANCY", "Vivillon-Fancy"),
+        ("VIVILLONGARDEN", "Vivillon-Garden"),
+        ("VIVILLONHIGHPLAINS", "Vivillon-High Plains"),
+        ("VIVILLONICYSNOW", "Vivillon-Icy Snow"),
+        ("VIVILLONJUNGLE", "Vivillon-Jungle"),
+        ("VIVILLONMARINE", "Vivillon-Marine"),
+        ("VIVILLONMODERN", "Vivillon-Modern"),
+        ("VIVILLONMONSOON", "Vivillon-Monsoon"),
+        ("VIVILLONOCEAN", "Vivillon-Ocean"),
+        ("VIVILLONPOKEBALL", "Vivillon-Pokeball"),
+        ("VIVILLONPOLAR", "Vivillon-Polar"),
+        ("VIVILLONRIVER", "Vivillon-River"),
+        ("VIVILLONSANDSTORM", "Vivillon-Sandstorm"),
+        ("VIVILLONSAVANNA", "Vivillon-Savanna"),
+        ("VIVILLONSUN", "Vivillon-Sun"),
+        ("VIVILLONTUNDRA", "Vivillon-Tundra"),
+        ("VOLTORBHISUI", "Voltorb-Hisui"),
+        ("VULPIXALOLA", "Vulpix-Alola"),
+        ("WALKINGWAKE", "Walking Wake"),
+        ("WEEZINGGALAR", "Weezing-Galar"),
+        ("WISHIWASHISCHOOL", "Wishiwashi-School"),
+        ("WOCHIEN", "Wo-Chien"),
+        ("WOOPERPALDEA", "Wooper-Paldea"),
+        ("WORMADAMSANDY", "Wormadam-Sandy"),
+        ("WORMADAMTRASH", "Wormadam-Trash"),
+        ("XERNEASNEUTRAL", "Xerneas-Neutral"),
+        ("YAMASKGALAR", "Yamask-Galar"),
+        ("ZACIANCROWNED", "Zacian-Crowned"),
+        ("ZAMAZENTACROWNED", "Zamazenta-Crowned"),
+        ("ZAPDOSGALAR", "Zapdos-Galar"),
+        ("ZARUDEDADA", "Zarude-Dada"),
+        ("ZIGZAGOONGALAR", "Zigzagoon-Galar"),
+        ("ZOROARKHISUI", "Zoroark-Hisui"),
+        ("ZORUAHISUI", "Zorua-Hisui"),
+        ("ZYGARDE10", "Zygarde-10%"),
+        ("ZYGARDECOMPLETE", "Zygarde-Complete"),
+    ]);
+    static ref MOVE_NAMES: HashMap<&'static str, &'static str> = HashMap::from([
+        ("ACIDARMOR", "Acid Armor"),
+        ("ACIDDOWNPOUR", "Acid Downpour"),
+        ("ACIDSPRAY", "Acid Spray"),
+        ("AERIALACE", "Aerial Ace"),
+        ("AFTERYOU", "After You"),
+        ("AIRCUTTER", "Air Cutter"),
+        ("AIRSLASH", "Air Slash"),
+        ("ALLOUTPUMMELING", "All-Out Pummeling"),
+        ("ALLURINGVOICE", "Alluring Voice"),
+        ("ALLYSWITCH", "Ally Switch"),
+        ("ANCHORSHOT", "Anchor Shot"),
+        ("ANCIENTPOWER", "Ancient Power"),
+        ("APPLEACID", "Apple Acid"),
+        ("AQUACUTTER", "Aqua Cutter"),
+        ("AQUAJET", "Aqua Jet"),
+        ("AQUARING", "Aqua Ring"),
+        ("AQUASTEP", "Aqua Step"),
+        ("AQUATAIL", "Aqua Tail"),
+        ("ARMORCANNON", "Armor Cannon"),
+        ("ARMTHRUST", "Arm Thrust"),
+        ("AROMATICMIST", "Aromatic Mist"),
+        ("ASTRALBARRAGE", "Astral Barrage"),
+        ("ATTACKORDER", "Attack Order"),
+        ("AURASPHERE", "Aura Sphere"),
+        ("AURAWHEEL", "Aura Wheel"),
+        ("AURORABEAM", "Aurora Beam"),
+        ("AURORAVEIL", "Aurora Veil"),
+        ("AXEKICK", "Axe Kick"),
+        ("BABYDOLLEYES", "Baby-Doll Eyes"),
+        ("BADDYBAD", "Baddy Bad"),
+        ("BANEFULBUNKER", "Baneful Bunker"),
+        ("BARBBARRAGE", "Barb Barrage"),
+        ("BATONPASS", "Baton Pass"),
+        ("BEAKBLAST", "Beak Blast"),
+        ("BEATUP", "Beat Up"),
+        ("BEHEMOTHBASH", "Behemoth Bash"),
+        ("BEHEMOTHBLADE", "Behemoth Blade"),
+        ("BELLYDRUM", "Belly Drum"),
+        ("BITTERBLADE", "Bitter Blade"),
+        ("BITTERMALICE", "Bitter Malice"),
+        ("BLACKHOLEECLIPSE", "Black Hole Eclipse"),
+        ("BLASTBURN", "Blast Burn"),
+        ("BLAZEKICK", "Blaze Kick"),
+        ("BLAZINGTORQUE", "Blazing Torque"),
+        ("BLEAKWINDSTORM", "Bleakwind Storm"),
+        ("BLOODMOON", "Blood Moon"),
+        ("BLOOMDOOM", "Bloom Doom"),
+        ("BLUEFLARE", "Blue Flare"),
+        ("BODYPRESS", "Body Press"),
+        ("BODYSLAM", "Body Slam"),
+        ("BOLTBEAK", "Bolt Beak"),
+        ("BOLTSTRIKE", "Bolt Strike"),
+        ("BONECLUB", "Bone Club"),
+        ("BONERUSH", "Bone Rush"),
+        ("BOUNCYBUBBLE", "Bouncy Bubble"),
+        ("BRANCHPOKE", "Branch Poke"),
+        ("BRAVEBIRD", "Brave Bird"),
+        ("BREAKINGSWIPE", "Breaking Swipe"),
+        ("BREAKNECKBLITZ", "Breakneck Blitz"),
+        ("BRICKBREAK", "Brick Break"),
+        ("BRUTALSWING", "Brutal Swing"),
+        ("BUBBLEBEAM", "Bubble Beam"),
+        ("BUGBITE", "Bug Bite"),
+        ("BUGBUZZ", "Bug Buzz"),
+        ("BULKUP", "Bulk Up"),
+        ("BULLETPUNCH", "Bullet Punch"),
+        ("BULLETSEED", "Bullet Seed"),
+        ("BURNINGBULWARK", "Burning Bulwark"),
+        ("BURNINGJEALOUSY", "Burning Jealousy"),
+        ("BURNUP", "Burn Up"),
+        ("BUZZYBUZZ", "Buzzy Buzz"),
+        ("CALMMIND", "Calm Mind"),
+        ("CEASELESSEDGE", "Ceaseless Edge"),
+        ("CHARGEBEAM", "Charge Beam"),
+        ("CHILLINGWATER", "Chilling Water"),
+        ("CHILLYRECEPTION", "Chilly Reception"),
+        ("CHIPAWAY", "Chip Away"),
+        ("CIRCLETHROW", "Circle Throw"),
+        ("CLANGINGSCALES", "Clanging Scales"),
+        ("CLANGOROUSSOUL", "Clangorous Soul"),
+        ("CLANGOROUSSOULBLAZE", "Clangorous Soulblaze"),
+        ("CLEARSMOG", "Clear Smog"),
+        ("CLOSECOMBAT", "Close Combat"),
+        ("COLLISIONCOURSE", "Collision Course"),
+        ("COMBATTORQUE", "Combat Torque"),
+        ("COMETPUNCH", "Comet Punch"),
+        ("CONFUSERAY", "Confuse Ray"),
+        ("CONTINENTALCRUSH", "Continental Crush"),
+        ("CONVERSION2", "Conversion 2"),
+        ("COREENFORCER", "Core Enforcer"),
+        ("CORKSCREWCRASH", "Corkscrew Crash"),
+        ("CORROSIVEGAS", "Corrosive Gas"),
+        ("COSMICPOWER", "Cosmic Power"),
+        ("COTTONGUARD", "Cotton Guard"),
+        ("COTTONSPORE", "Cotton Spore"),
+        ("COURTCHANGE", "Court Change"),
+        ("CRAFTYSHIELD", "Crafty Shield"),
+        ("CROSSCHOP", "Cross Chop"),
+        ("CROSSPOISON", "Cross Poison"),
+        ("CRUSHCLAW", "Crush Claw"),
+        ("CRUSHGRIP", "Crush Grip"),
+        ("DARKESTLARIAT", "Darkest Lariat"),
+        ("DARKPULSE", "Dark Pulse"),
+        ("DARKVOID", "Dark Void"),
+        ("DAZZLINGGLEAM", "Dazzling Gleam"),
+        ("DEFENDORDER", "Defend Order"),
+        ("DEFENSECURL", "Defense Curl"),
+        ("DESTINYBOND", "Destiny Bond"),
+        ("DEVASTATINGDRAKE", "Devastating Drake"),
+        ("DIAMONDSTORM", "Diamond Storm"),
+        ("DIRECLAW", "Dire Claw"),
+        ("DISARMINGVOICE", "Disarming Voice"),
+        ("DIZZYPUNCH", "Dizzy Punch"),
+        ("DOOMDESIRE", "Doom Desire"),
+        ("DOUBLEEDGE", "Double-Edge"),
+        ("DOUBLEHIT", "Double Hit"),
+        ("DOUBLEIRONBASH", "Double Iron Bash"),
+        ("DOUBLEKICK", "Double Kick"),
+        ("DOUBLESHOCK", "Double Shock"),
+        ("DOUBLESLAP", "Double Slap"),
+        ("DOUBLETEAM", "Double Team"),
+        ("DRACOMETEOR", "Draco Meteor"),
+        ("DRAGONASCENT", "Dragon Ascent"),
+        ("DRAGONBREATH", "Dragon Breath"),
+        ("DRAGONCHEER", "Dragon Cheer"),
+        ("DRAGONCLAW", "Dragon Claw"),
+        ("DRAGONDANCE", "Dragon Dance"),
+        ("DRAGONDARTS", "Dragon Darts"),
+        ("DRAGONENERGY", "Dragon Energy"),
+        ("DRAGONHAMMER", "Dragon Hammer"),
+        ("DRAGONPULSE", "Dragon Pulse"),
+        ("DRAGONRAGE", "Dragon Rage"),
+        ("DRAGONRUSH", "Dragon Rush"),
+        ("DRAGONTAIL", "Dragon Tail"),
+        ("DRAININGKISS", "Draining Kiss"),
+        ("DRAINPUNCH", "Drain Punch"),
+        ("DREAMEATER", "Dream Eater"),
+        ("DRILLPECK", "Drill Peck"),
+        ("DRILLRUN", "Drill Run"),
+        ("DRUMBEATING", "Drum Beating"),
+        ("DUALCHOP", "Dual Chop"),
+        ("DUALWINGBEAT", "Dual Wingbeat"),
+        ("DYNAMAXCANNON", "Dynamax Cannon"),
+        ("DYNAMICPUNCH", "Dynamic Punch"),
+        ("EARTHPOWER", "Earth Power"),
+        ("ECHOEDVOICE", "Echoed Voice"),
+        ("EERIEIMPULSE", "Eerie Impulse"),
+        ("EERIESPELL", "Eerie Spell"),
+        ("EGGBOMB", "Egg Bomb"),
+        ("ELECTRICTERRAIN", "Electric Terrain"),
+        ("ELECTROBALL", "Electro Ball"),
+        ("ELECTRODRIFT", "Electro Drift"),
+        ("ELECTROSHOT", "Electro Shot"),
+        ("ENERGYBALL", "Energy Ball"),
+        ("ESPERWING", "Esper Wing"),
+        ("EXPANDINGFORCE", "Expanding Force"),
+        ("EXTREMEEVOBOOST", "Extreme Evoboost"),
+        ("EXTREMESPEED", "Extreme Speed"),
+        ("FAIRYLOCK", "Fairy Lock"),
+        ("FAIRYWIND", "Fairy Wind"),
+        ("FAKEOUT", "Fake Out"),
+        ("FAKETEARS", "Fake Tears"),
+        ("FALSESURRENDER", "False Surrender"),
+        ("FALSESWIPE", "False Swipe"),
+        ("FEATHERDANCE", "Feather Dance"),
+        ("FEINTATTACK", "Feint Attack"),
+        ("FELLSTINGER", "Fell Stinger"),
+        ("FICKLEBEAM", "Fickle Beam"),
+        ("FIERYDANCE", "Fiery Dance"),
+        ("FIERYWRATH", "Fiery Wrath"),
+        ("FILLETAWAY", "Fillet Away"),
+        ("FINALGAMBIT", "Final Gambit"),
+        ("FIREBLAST", "Fire Blast"),
+        ("FIREFANG", "Fire Fang"),
+        ("FIRELASH", "Fire Lash"),
+        ("FIREPLEDGE", "Fire Pledge"),
+        ("FIREPUNCH", "Fire Punch"),
+        ("FIRESPIN", "Fire Spin"),
+        ("FIRSTIMPRESSION", "First Impression"),
+        ("FISHIOUSREND", "Fishious Rend"),
+        ("FLAMEBURST", "Flame Burst"),
+        ("FLAMECHARGE", "Flame Charge"),
+        ("FLAMEWHEEL", "Flame Wheel"),
+        ("FLAREBLITZ", "Flare Blitz"),
+        ("FLASHCANNON", "Flash Cannon"),
+        ("FLEURCANNON", "Fleur Cannon"),
+        ("FLIPTURN", "Flip Turn"),
+        ("FLOATYFALL", "Floaty Fall"),
+        ("FLORALHEALING", "Floral Healing"),
+        ("FLOWERSHIELD", "Flower Shield"),
+        ("FLOWERTRICK", "Flower Trick"),
+        ("FLYINGPRESS", "Flying Press"),
+        ("FOCUSBLAST", "Focus Blast"),
+        ("FOCUSENERGY", "Focus Energy"),
+        ("FOCUSPUNCH", "Focus Punch"),
+        ("FOLLOWME", "Follow Me"),
+        ("FORCEPALM", "Force Palm"),
+        ("FORESTSCURSE", "Forest's Curse"),
+        ("FOULPLAY", "Foul Play"),
+        ("FREEZEDRY", "Freeze-Dry"),
+        ("FREEZESHOCK", "Freeze Shock"),
+        ("FREEZINGGLARE", "Freezing Glare"),
+        ("FREEZYFROST", "Freezy Frost"),
+        ("FRENZYPLANT", "Frenzy Plant"),
+        ("FROSTBREATH", "Frost Breath"),
+        ("FURYATTACK", "Fury Attack"),
+        ("FURYCUTTER", "Fury Cutter"),
+        ("FURYSWIPES", "Fury Swipes"),
+        ("FUSIONBOLT", "Fusion Bolt"),
+        ("FUSIONFLARE", "Fusion Flare"),
+        ("FUTURESIGHT", "Future Sight"),
+        ("GASTROACID", "Gastro Acid"),
+        ("GEARGRIND", "Gear Grind"),
+        ("GEARUP", "Gear Up"),
+        ("GENESISSUPERNOVA", "Genesis Supernova"),
+        ("GIGADRAIN", "Giga Drain"),
+        ("GIGAIMPACT", "Giga Impact"),
+        ("GIGATONHAMMER", "Gigaton Hammer"),
+        ("GIGAVOLTHAVOC", "Gigavolt Havoc"),
+        ("GLACIALLANCE", "Glacial Lance"),
+        ("GLAIVERUSH", "Glaive Rush"),
+        ("GLITZYGLOW", "Glitzy Glow"),
+        ("GRASSKNOT", "Grass Knot"),
+        ("GRASSPLEDGE", "Grass Pledge"),
+        ("GRASSWHISTLE", "Grass Whistle"),
+        ("GRASSYGLIDE", "Grassy Glide"),
+        ("GRASSYTERRAIN", "Grassy Terrain"),
+        ("GRAVAPPLE", "Grav Apple"),
+        ("GUARDIANOFALOLA", "Guardian of Alola"),
+        ("GUARDSPLIT", "Guard Split"),
+        ("GUARDSWAP", "Guard Swap"),
+        ("GUNKSHOT", "Gunk Shot"),
+        ("GYROBALL", "Gyro Ball"),
+        ("HAMMERARM", "Hammer Arm"),
+        ("HAPPYHOUR", "Happy Hour"),
+        ("HARDPRESS", "Hard Press"),
+        ("HEADCHARGE", "Head Charge"),
+        ("HEADLONGRUSH", "Headlong Rush"),
+        ("HEADSMASH", "Head Smash"),
+        ("HEALBELL", "Heal Bell"),
+        ("HEALBLOCK", "Heal Block"),
+        ("HEALINGWISH", "Healing Wish"),
+        ("HEALORDER", "Heal Order"),
+        ("HEALPULSE", "Heal Pulse"),
+        ("HEARTSTAMP", "Heart Stamp"),
+        ("HEARTSWAP", "Heart Swap"),
+        ("HEATCRASH", "Heat Crash"),
+        ("HEATWAVE", "Heat Wave"),
+        ("HEAVYSLAM", "Heavy Slam"),
+        ("HELPINGHAND", "Helping Hand"),
+        ("HIGHHORSEPOWER", "High Horsepower"),
+        ("HIGHJUMPKICK", "High Jump Kick"),
+        ("HOLDBACK", "Hold Back"),
+        ("HOLDHANDS", "Hold Hands"),
+        ("HONECLAWS", "Hone Claws"),
+        ("HORNATTACK", "Horn Attack"),
+        ("HORNDRILL", "Horn Drill"),
+        ("HORNLEECH", "Horn Leech"),
+        ("HYDROCANNON", "Hydro Cannon"),
+        ("HYDROPUMP", "Hydro Pump"),
+        ("HYDROSTEAM", "Hydro Steam"),
+        ("HYDROVORTEX", "Hydro Vortex"),
+        ("HYPERBEAM", "Hyper Beam"),
+        ("HYPERDRILL", "Hyper Drill"),
+        ("HYPERFANG", "Hyper Fang"),
+        ("HYPERSPACEFURY", "Hyperspace Fury"),
+        ("HYPERSPACEHOLE", "Hyperspace Hole"),
+        ("HYPERVOICE", "Hyper Voice"),
+        ("ICEBALL", "Ice Ball"),
+        ("ICEBEAM", "Ice Beam"),
+        ("ICEBURN", "Ice Burn"),
+        ("ICEFANG", "Ice Fang"),
+        ("ICEHAMMER", "Ice Hammer"),
+        ("ICEPUNCH", "Ice Punch"),
+        ("ICESHARD", "Ice Shard"),
+        ("ICESPINNER", "Ice Spinner"),
+        ("ICICLECRASH", "Icicle Crash"),
+        ("ICICLESPEAR", "Icicle Spear"),
+        ("ICYWIND", "Icy Wind"),
+        ("INFERNALPARADE", "Infernal Parade"),
+        ("INFERNOOVERDRIVE", "Inferno Overdrive"),
+        ("IONDELUGE", "Ion Deluge"),
+        ("IRONDEFENSE", "Iron Defense"),
+        ("IRONHEAD", "Iron Head"),
+        ("IRONTAIL", "Iron Tail"),
+        ("IVYCUDGEL", "Ivy Cudgel"),
+        ("JAWLOCK", "Jaw Lock"),
+        ("JETPUNCH", "Jet Punch"),
+        ("JUMPKICK", "Jump Kick"),
+        ("JUNGLEHEALING", "Jungle Healing"),
+        ("KARATECHOP", "Karate Chop"),
+        ("KINGSSHIELD", "King's Shield"),
+        ("KNOCKOFF", "Knock Off"),
+        ("KOWTOWCLEAVE", "Kowtow Cleave"),
+        ("LANDSWRATH", "Land's Wrath"),
+        ("LASERFOCUS", "Laser Focus"),
+        ("LASHOUT", "Lash Out"),
+        ("LASTRESORT", "Last Resort"),
+        ("LASTRESPECTS", "Last Respects"),
+        ("LAVAPLUME", "Lava Plume"),
+        ("LEAFBLADE", "Leaf Blade"),
+        ("LEAFSTORM", "Leaf Storm"),
+        ("LEAFTORNADO", "Leaf Tornado"),
+        ("LEECHLIFE", "Leech Life"),
+        ("LEECHSEED", "Leech Seed"),
+        ("LETSSNUGGLEFOREVER", "Let's Snuggle Forever"),
+        ("LIFEDEW", "Life Dew"),
+        ("LIGHTOFRUIN", "Light of Ruin"),
+        ("LIGHTSCREEN", "Light Screen"),
+        ("LIGHTTHATBURNSTHESKY", "Light That Burns the Sky"),
+        ("LOCKON", "Lock-On"),
+        ("LOVELYKISS", "Lovely Kiss"),
+        ("LOWKICK", "Low Kick"),
+        ("LOWSWEEP", "Low Sweep"),
+        ("LUCKYCHANT", "Lucky Chant"),
+        ("LUMINACRASH", "Lumina Crash"),
+        ("LUNARBLESSING", "Lunar Blessing"),
+        ("LUNARDANCE", "Lunar Dance"),
+        ("LUSTERPURGE", "Luster Purge"),
+        ("MACHPUNCH", "Mach Punch"),
+        ("MAGICALLEAF", "Magical Leaf"),
+        ("MAGICALTORQUE", "Magical Torque"),
+        ("MAGICCOAT", "Magic Coat"),
+        ("MAGICPOWDER", "Magic Powder"),
+        ("MAGICROOM", "Magic Room"),
+        ("MAGMASTORM", "Magma Storm"),
+        ("MAGNETBOMB", "Magnet Bomb"),
+        ("MAGNETICFLUX", "Magnetic Flux"),
+        ("MAGNETRISE", "Magnet Rise"),
+        ("MAKEITRAIN", "Make It Rain"),
+        ("MALICIOUSMOONSAULT", "Malicious Moonsault"),
+        ("MALIGNANTCHAIN", "Malignant Chain"),
+        ("MATBLOCK", "Mat Block"),
+        ("MATCHAGOTCHA", "Matcha Gotcha"),
+        ("MEANLOOK", "Mean Look"),
+        ("MEFIRST", "Me First"),
+        ("MEGADRAIN", "Mega Drain"),
+        ("MEGAKICK", "Mega Kick"),
+        ("MEGAPUNCH", "Mega Punch"),
+        ("MENACINGMOONRAZEMAELSTROM", "Menacing Moonraze Maelstrom"),
+        ("METALBURST", "Metal Burst"),
+        ("METALCLAW", "Metal Claw"),
+        ("METALSOUND", "Metal Sound"),
+        ("METEORASSAULT", "Meteor Assault"),
+        ("METEORBEAM", "Meteor Beam"),
+        ("METEORMASH", "Meteor Mash"),
+        ("MIGHTYCLEAVE", "Mighty Cleave"),
+        ("MILKDRINK", "Milk Drink"),
+        ("MINDBLOWN", "Mind Blown"),
+        ("MINDREADER", "Mind Reader"),
+        ("MIRACLEEYE", "Miracle Eye"),
+        ("MIRRORCOAT", "Mirror Coat"),
+        ("MIRRORMOVE", "Mirror Move"),
+        ("MIRRORSHOT", "Mirror Shot"),
+        ("MISTBALL", "Mist Ball"),
+        ("MISTYEXPLOSION", "Misty Explosion"),
+        ("MISTYTERRAIN", "Misty Terrain"),
+        ("MOONGEISTBEAM", "Moongeist Beam"),
+        ("MORNINGSUN", "Morning Sun"),
+        ("MORTALSPIN", "Mortal Spin"),
+        ("MOUNTAINGALE", "Mountain Gale"),
+        ("MUDBOMB", "Mud Bomb"),
+        ("MUDDYWATER", "Muddy Water"),
+        ("MUDSHOT", "Mud Shot"),
+        ("MUDSLAP", "Mud-Slap"),
+        ("MUDSPORT", "Mud Sport"),
+        ("MYSTICALFIRE", "Mystical Fire"),
+        ("MYSTICALPOWER", "Mystical Power"),
+        ("NASTYPLOT", "Nasty Plot"),
+        ("NATURALGIFT", "Natural Gift"),
+        ("NATUREPOWER", "Nature Power"),
+        ("NATURESMADNESS", "Nature's Madness"),
+        ("NEEDLEARM", "Needle Arm"),
+        ("NEVERENDINGNIGHTMARE", "Never-Ending Nightmare"),
+        ("NIGHTDAZE", "Night Daze"),
+        ("NIGHTSHADE", "Night Shade"),
+        ("NIGHTSLASH", "Night Slash"),
+        ("NOBLEROAR", "Noble Roar"),
+        ("NORETREAT", "No Retreat"),
+        ("NOXIOUSTORQUE", "Noxious Torque"),
+        ("OBLIVIONWING", "Oblivion Wing"),
+        ("OCEANICOPERETTA", "Oceanic Operetta"),
+        ("ODORSLEUTH", "Odor Sleuth"),
+        ("OMINOUSWIND", "Ominous Wind"),
+        ("ORDERUP", "Order Up"),
+        ("ORIGINPULSE", "Origin Pulse"),
+        ("PAINSPLIT", "Pain Split"),
+        ("PALEOWAVE", "Paleo Wave"),
+        ("PARABOLICCHARGE", "Parabolic Charge"),
+        ("PARTINGSHOT", "Parting Shot"),
+        ("PAYDAY", "Pay Day"),
+        ("PERISHSONG", "Perish Song"),
+        ("PETALBLIZZARD", "Petal Blizzard"),
+        ("PETALDANCE", "Petal Dance"),
+        ("PHANTOMFORCE", "Phantom Force"),
+        ("PHOTONGEYSER", "Photon Geyser"),
+        ("PIKAPAPOW", "Pika Papow"),
+        ("PINMISSILE", "Pin Missile"),
+        ("PLASMAFISTS", "Plasma Fists"),
+        ("PLAYNICE", "Play Nice"),
+        ("PLAYROUGH", "Play Rough"),
+        ("POISONFANG", "Poison Fang"),
+        ("POISONGAS", "Poison Gas"),
+        ("POISONJAB", "Poison Jab"),
+        ("POISONPOWDER", "Poison Powder"),
+        ("POISONSTING", "Poison Sting"),
+        ("POISONTAIL", "Poison Tail"),
+        ("POLLENPUFF", "Pollen Puff"),
+        ("POPULATIONBOMB", "Population Bomb"),
+        ("POWDERSNOW", "Powder Snow"),
+        ("POWERGEM", "Power Gem"),
+        ("POWERSHIFT", "Power Shift"),
+        ("POWERSPLIT", "Power Split"),
+        ("POWERSWAP", "Power Swap"),
+        ("POWERTRICK", "Power Trick"),
+        ("POWERTRIP", "Power Trip"),
+        ("POWERUPPUNCH", "Power-Up Punch"),
+        ("POWERWHIP", "Power Whip"),
+        ("PRECIPICEBLADES", "Precipice Blades"),
+        ("PRISMATICLASER", "Prismatic Laser"),
+        ("PSYCHICFANGS", "Psychic Fangs"),
+        ("PSYCHICNOISE", "Psychic Noise"),
+        ("PSYCHICTERRAIN", "Psychic Terrain"),
+        ("PSYCHOBOOST", "Psycho Boost"),
+        ("PSYCHOCUT", "Psycho Cut"),
+        ("PSYCHOSHIFT", "Psycho Shift"),
+        ("PSYCHUP", "Psych Up"),
+        ("PSYSHIELDBASH", "Psyshield Bash"),
+        ("PULVERIZINGPANCAKE", "Pulverizing Pancake"),
+        ("PYROBALL", "Pyro Ball"),
+        ("QUICKATTACK", "Quick Attack"),
+        ("QUICKGUARD", "Quick Guard"),
+        ("QUIVERDANCE", "Quiver Dance"),
+        ("RAGEFIST", "Rage Fist"),
+        ("RAGEPOWDER", "Rage Powder"),
+        ("RAGINGBULL", "Raging Bull"),
+        ("RAGINGFURY", "Raging Fury"),
+        ("RAINDANCE", "Rain Dance"),
+        ("RAPIDSPIN", "Rapid Spin"),
+        ("RAZORLEAF", "Razor Leaf"),
+        ("RAZORSHELL", "Razor Shell"),
+        ("RAZORWIND", "Razor Wind"),
+        ("REFLECTTYPE", "Reflect Type"),
+        ("RELICSONG", "Relic Song"),
+        ("RETURN102", "Return"),
+        ("REVELATIONDANCE", "Revelation Dance"),
+        ("REVIVALBLESSING", "Revival Blessing"),
+        ("RISINGVOLTAGE", "Rising Voltage"),
+        ("ROAROFTIME", "Roar of Time"),
+        ("ROCKBLAST", "Rock Blast"),
+        ("ROCKCLIMB", "Rock Climb"),
+        ("ROCKPOLISH", "Rock Polish"),
+        ("ROCKSLIDE", "Rock Slide"),
+        ("ROCKSMASH", "Rock Smash"),
+        ("ROCKTHROW", "Rock Throw"),
+        ("ROCKTOMB", "Rock Tomb"),
+        ("ROCKWRECKER", "Rock Wrecker"),
+        ("ROLEPLAY", "Role Play"),
+        ("ROLLINGKICK", "Rolling Kick"),
+        ("SACREDFIRE", "Sacred Fire"),
+        ("SACREDSWORD", "Sacred Sword"),
+        ("SALTCURE", "Salt Cure"),
+        ("SANDATTACK", "Sand Attack"),
+        ("SANDSEARSTORM", "Sandsear Storm"),
+        ("SANDTOMB", "Sand Tomb"),
+        ("SAPPYSEED", "Sappy Seed"),
+        ("SAVAGESPINOUT", "Savage Spin-Out"),
+        ("SCALESHOT", "Scale Shot"),
+        ("SCARYFACE", "Scary Face"),
+        ("SCORCHINGSANDS", "Scorching Sands"),
+        ("SEARINGSHOT", "Searing Shot"),
+        ("SEARINGSUNRAZESMASH", "Searing Sunraze Smash"),
+        ("SECRETPOWER", "Secret Power"),
+        ("SECRETSWORD", "Secret Sword"),
+        ("SEEDBOMB", "Seed Bomb"),
+        ("SEEDFLARE", "Seed Flare"),
+        ("SEISMICTOSS", "Seismic Toss"),
+        ("SELFDESTRUCT", "Self-Destruct"),
+        ("SHADOWBALL", "Shadow Ball"),
+        ("SHADOWBONE", "Shadow Bone"),
+        ("SHADOWCLAW", "Shadow Claw"),
+        ("SHADOWFORCE", "Shadow Force"),
+        ("SHADOWPUNCH", "Shadow Punch"),
+        ("SHADOWSNEAK", "Shadow Sneak"),
+        ("SHADOWSTRIKE", "Shadow Strike"),
+        ("SHATTEREDPSYCHE", "Shattered Psyche"),
+        ("SHEDTAIL", "Shed Tail"),
+        ("SHEERCOLD", "Sheer Cold"),
+        ("SHELLSIDEARM", "Shell Side Arm"),
+        ("SHELLSMASH", "Shell Smash"),
+        ("SHELLTRAP", "Shell Trap"),
+        ("SHIFTGEAR", "Shift Gear"),
+        ("SHOCKWAVE", "Shock Wave"),
+        ("SHOREUP", "Shore Up"),
+        ("SIGNALBEAM", "Signal Beam"),
+        ("SILKTRAP", "Silk Trap"),
+        ("SILVERWIND", "Silver Wind"),
+        ("SIMPLEBEAM", "Simple Beam"),
+        ("SINISTERARROWRAID", "Sinister Arrow Raid"),
+        ("SIZZLYSLIDE", "Sizzly Slide"),
+        ("SKILLSWAP", "Skill Swap"),
+        ("SKITTERSMACK", "Skitter Smack"),
+        ("SKULLBASH", "Skull Bash"),
+        ("SKYATTACK", "Sky Attack"),
+        ("SKYDROP", "Sky Drop"),
+        ("SKYUPPERCUT", "Sky Uppercut"),
+        ("SLACKOFF", "Slack Off"),
+        ("SLEEPPOWDER", "Sleep Powder"),
+        ("SLEEPTALK", "Sleep Talk"),
+        ("SLUDGEBOMB", "Sludge Bomb"),
+        ("SLUDGEWAVE", "Sludge Wave"),
+        ("SMACKDOWN", "Smack Down"),
+        ("SMARTSTRIKE", "Smart Strike"),
+        ("SMELLINGSALTS", "Smelling Salts"),
+        ("SNAPTRAP", "Snap Trap"),
+        ("SNIPESHOT", "Snipe Shot"),
+        ("SOFTBOILED", "Soft-Boiled"),
+        ("SOLARBEAM", "Solar Beam"),
+        ("SOLARBLADE", "Solar Blade"),
+        ("SONICBOOM", "Sonic Boom"),
+        ("SOULSTEALING7STARSTRIKE", "Soul-Stealing 7-Star Strike"),
+        ("SPACIALREND", "Spacial Rend"),
+        ("SPARKLINGARIA", "Sparkling Aria"),
+        ("SPARKLYSWIRL", "Sparkly Swirl"),
+        ("SPECTRALTHIEF", "Spectral Thief"),
+        ("SPEEDSWAP", "Speed Swap"),
+        ("SPICYEXTRACT", "Spicy Extract"),
+        ("SPIDERWEB", "Spider Web"),
+        ("SPIKECANNON", "Spike Cannon"),
+        ("SPIKYSHIELD", "Spiky Shield"),
+        ("SPINOUT", "Spin Out"),
+        ("SPIRITBREAK", "Spirit Break"),
+        ("SPIRITSHACKLE", "Spirit Shackle"),
+        ("SPITUP", "Spit Up"),
+        ("SPLINTEREDSTORMSHARDS", "Splintered Stormshards"),
+        ("SPLISHYSPLASH", "Splishy Splash"),
+        ("SPRINGTIDESTORM", "Springtide Storm"),
+        ("STEALTHROCK", "Stealth Rock"),
+        ("STEAMERUPTION", "Steam Eruption"),
+        ("STEELBEAM", "Steel Beam"),
+        ("STEELROLLER", "Steel Roller"),
+        ("STEELWING", "Steel Wing"),
+        ("STICKYWEB", "Sticky Web"),
+        ("STOKEDSPARKSURFER", "Stoked Sparksurfer"),
+        ("STOMPINGTANTRUM", "Stomping Tantrum"),
+        ("STONEAXE", "Stone Axe"),
+        ("STONEEDGE", "Stone Edge"),
+        ("STOREDPOWER", "Stored Power"),
+        ("STORMTHROW", "Storm Throw"),
+        ("STRANGESTEAM", "Strange Steam"),
+        ("STRENGTHSAP", "Strength Sap"),
+        ("STRINGSHOT", "String Shot"),
+        ("STRUGGLEBUG", "Struggle Bug"),
+        ("STUFFCHEEKS", "Stuff Cheeks"),
+        ("STUNSPORE", "Stun Spore"),
+        ("SUBZEROSLAMMER", "Subzero Slammer"),
+        ("SUCKERPUNCH", "Sucker Punch"),
+        ("SUNNYDAY", "Sunny Day"),
+        ("SUNSTEELSTRIKE", "Sunsteel Strike"),
+        ("SUPERCELLSLAM", "Supercell Slam"),
+        ("SUPERFANG", "Super Fang"),
+        ("SUPERSONICSKYSTRIKE", "Supersonic Skystrike"),
+        ("SURGINGSTRIKES", "Surging Strikes"),
+        ("SWEETKISS", "Sweet Kiss"),
+        ("SWEETSCENT", "Sweet Scent"),
+        ("SWORDSDANCE", "Swords Dance"),
+        ("SYRUPBOMB", "Syrup Bomb"),
+        ("TACHYONCUTTER", "Tachyon Cutter"),
+        ("TAILGLOW", "Tail Glow"),
+        ("TAILSLAP", "Tail Slap"),
+        ("TAILWHIP", "Tail Whip"),
+        ("TAKEDOWN", "Take Down"),
+        ("TAKEHEART", "Take Heart"),
+        ("TARSHOT", "Tar Shot"),
+        ("TEARFULLOOK", "Tearful Look"),
+        ("TECHNOBLAST", "Techno Blast"),
+        ("TECTONICRAGE", "Tectonic Rage"),
+        ("TEETERDANCE", "Teeter Dance"),
+        ("TEMPERFLARE", "Temper Flare"),
+        ("TENMILLIONVOLTTHUNDERBOLT", "10,000,000 Volt Thunderbolt"),
+        ("TERABLAST", "Tera Blast"),
+        ("TERASTARSTORM", "Tera Starstorm"),
+        ("TERRAINPULSE", "Terrain Pulse"),
+        ("THOUSANDARROWS", "Thousand Arrows"),
+        ("THOUSANDWAVES", "Thousand Waves"),
+        ("THROATCHOP", "Throat Chop"),
+        ("THUNDERCAGE", "Thunder Cage"),
+        ("THUNDERFANG", "Thunder Fang"),
+        ("THUNDEROUSKICK", "Thunderous Kick"),
+        ("THUNDERPUNCH", "Thunder Punch"),
+        ("THUNDERSHOCK", "Thunder Shock"),
+        ("THUNDERWAVE", "Thunder Wave"),
+        ("TIDYUP", "Tidy Up"),
+        ("TOPSYTURVY", "Topsy-Turvy"),
+        ("TORCHSONG", "Torch Song"),
+        ("TOXICSPIKES", "Toxic Spikes"),
+        ("TOXICTHREAD", "Toxic Thread"),
+        ("TRIATTACK", "Tri Attack"),
+        ("TRICKORTREAT", "Trick-or-Treat"),
+        ("TRICKROOM", "Trick Room"),
+        ("TRIPLEARROWS", "Triple Arrows"),
+        ("TRIPLEAXEL", "Triple Axel"),
+        ("TRIPLEDIVE", "Triple Dive"),
+        ("TRIPLEKICK", "Triple Kick"),
+        ("TROPKICK", "Trop Kick"),
+        ("TRUMPCARD", "Trump Card"),
+        ("TWINBEAM", "Twin Beam"),
+        ("TWINKLETACKLE", "Twinkle Tackle"),
+        ("UPPERHAND", "Upper Hand"),
+        ("UTURN", "U-turn"),
+        ("VACUUMWAVE", "Vacuum Wave"),
+        ("VCREATE", "V-create"),
+        ("VEEVEEVOLLEY", "Veevee Volley"),
+        ("VENOMDRENCH", "Venom Drench"),
+        ("VICTORYDANCE", "Victory Dance"),
+        ("VINEWHIP", "Vine Whip"),
+        ("VISEGRIP", "Vise Grip"),
+        ("VITALTHROW", "Vital Throw"),
+        ("VOLTSWITCH", "Volt Switch"),
+        ("VOLTTACKLE", "Volt Tackle"),
+        ("WAKEUPSLAP", "Wake-Up Slap"),
+        ("WATERGUN", "Water Gun"),
+        ("WATERPLEDGE", "Water Pledge"),
+        ("WATERPULSE", "Water Pulse"),
+        ("WATERSHURIKEN", "Water Shuriken"),
+        ("WATERSPORT", "Water Sport"),
+        ("WATERSPOUT", "Water Spout"),
+        ("WAVECRASH", "Wave Crash"),
+        ("WEATHERBALL", "Weather Ball"),
+        ("WICKEDBLOW", "Wicked Blow"),
+        ("WICKEDTORQUE", "Wicked Torque"),
+        ("WIDEGUARD", "Wide Guard"),
+        ("WILDBOLTSTORM", "Wildbolt Storm"),
+        ("WILDCHARGE", "Wild Charge"),
+        ("WILLOWISP", "Will-O-Wisp"),
+        ("WINGATTACK", "Wing Attack"),
+        ("WONDERROOM", "Wonder Room"),
+        ("WOODHAMMER", "Wood Hammer"),
+        ("WORKUP", "Work Up"),
+        ("WORRYSEED", "Worry Seed"),
+        ("WRINGOUT", "Wring Out"),
+        ("XSCISSOR", "X-Scissor"),
+        ("ZAPCANNON", "Zap Cannon"),
+        ("ZENHEADBUTT", "Zen Headbutt"),
+        ("ZINGZAP", "Zing Zap"),
+        ("ZIPPYZAP", "Zippy Zap"),
+    ]);
+    static ref ITEM_NAMES: HashMap<&'static str, &'static str> = HashMap::from([
+        ("ABSORBBULB", "Absorb Bulb"),
+        ("ADAMANTCRYSTAL", "Adamant Crystal"),
+        ("ADAMANTORB", "Adamant Orb"),
+        ("ADRENALINEORB", "Adrenaline Orb"),
+        ("AIRBALLOON", "Air Balloon"),
+        ("ALORAICHIUMZ", "Aloraichium Z"),
+        ("ASSAULTVEST", "Assault Vest"),
+        ("BABIRIBERRY", "Babiri Berry"),
+        ("BLACKBELT", "Black Belt"),
+        ("BLACKGLASSES", "Black Glasses"),
+        ("BLACKSLUDGE", "Black Sludge"),
+        ("BLANKPLATE", "Blank Plate"),
+        ("BLUNDERPOLICY", "Blunder Policy"),
+        ("BOOSTERENERGY", "Booster Energy"),
+        ("BUGGEM", "Bug Gem"),
+        ("BUGINIUMZ", "Buginium Z"),
+        ("BUGMEMORY", "Bug Memory"),
+        ("CELLBATTERY", "Cell Battery"),
+        ("CHARIZARDITEX", "Charizardite X"),
+        ("CHARIZARDITEY", "Charizardite Y"),
+        ("CHARTIBERRY", "Charti Berry"),
+        ("CHESTOBERRY", "Chesto Berry"),
+        ("CHILANBERRY", "Chilan Berry"),
+        ("CHOICEBAND", "Choice Band"),
+        ("CHOICESCARF", "Choice Scarf"),
+        ("CHOICESPECS", "Choice Specs"),
+        ("CHOPLEBERRY", "Chople Berry"),
+        ("CLEARAMULET", "Clear Amulet"),
+        ("COBABERRY", "Coba Berry"),
+        ("COLBURBERRY", "Colbur Berry"),
+        ("CORNERSTONEMASK", "Cornerstone Mask"),
+        ("CUSTAPBERRY", "Custap Berry"),
+        ("DARKGEM", "Dark Gem"),
+        ("DARKINIUMZ", "Darkinium Z"),
+        ("DARKMEMORY", "Dark Memory"),
+        ("DECIDIUMZ", "Decidium Z"),
+        ("DRACOPLATE", "Draco Plate"),
+        ("DRAGONFANG", "Dragon Fang"),
+        ("DRAGONGEM", "Dragon Gem"),
+        ("DRAGONIUMZ", "Dragonium Z"),
+        ("DRAGONMEMORY", "Dragon Memory"),
+        ("DRAGONSCALE", "Dragon Scale"),
+        ("DREADPLATE", "Dread Plate"),
+        ("EARTHPLATE", "Earth Plate"),
+        ("EEVIUMZ", "Eevium Z"),
+        ("ELECTRICGEM", "Electric Gem"),
+        ("ELECTRICMEMORY", "Electric Memory"),
+        ("ELECTRICSEED", "Electric Seed"),
+        ("ELECTRIUMZ", "Electrium Z"),
+        ("EXPERTBELT", "Expert Belt"),
+        ("FAIRIUMZ", "Fairium Z"),
+        ("FAIRYFEATHER", "Fairy Feather"),
+        ("FAIRYGEM", "Fairy Gem"),
+        ("FAIRYMEMORY", "Fairy Memory"),
+        ("FIGHTINGGEM", "Fighting Gem"),
+        ("FIGHTINGMEMORY", "Fighting Memory"),
+        ("FIGHTINIUMZ", "Fightinium Z"),
+        ("FIREGEM", "Fire Gem"),
+        ("FIREMEMORY", "Fire Memory"),
+        ("FIRIUMZ", "Firium Z"),
+        ("FISTPLATE", "Fist Plate"),
+        ("FLAMEORB", "Flame Orb"),
+        ("FLAMEPLATE", "Flame Plate"),
+        ("FLYINGGEM", "Flying Gem"),
+        ("FLYINGMEMORY", "Flying Memory"),
+        ("FLYINIUMZ", "Flyinium Z"),
+        ("FOCUSSASH", "Focus Sash"),
+        ("GHOSTGEM", "Ghost Gem"),
+        ("GHOSTIUMZ", "Ghostium Z"),
+        ("GHOSTMEMORY", "Ghost Memory"),
+        ("GRASSGEM", "Grass Gem"),
+        ("GRASSIUMZ", "Grassium Z"),
+        ("GRASSMEMORY", "Grass Memory"),
+        ("GRASSYSEED", "Grassy Seed"),
+        ("GRISEOUSCORE", "Griseous Core"),
+        ("GRISEOUSORB", "Griseous Orb"),
+        ("GROUNDGEM", "Ground Gem"),
+        ("GROUNDIUMZ", "Groundium Z"),
+        ("GROUNDMEMORY", "Ground Memory"),
+        ("HABANBERRY", "Haban Berry"),
+        ("HARDSTONE", "Hard Stone"),
+        ("HEARTHFLAMEMASK", "Hearthflame Mask"),
+        ("HEAVYDUTYBOOTS", "Heavy-Duty Boots"),
+        ("ICEGEM", "Ice Gem"),
+        ("ICEMEMORY", "Ice Memory"),
+        ("ICICLEPLATE", "Icicle Plate"),
+        ("ICIUMZ", "Icium Z"),
+        ("INCINIUMZ", "Incinium Z"),
+        ("INSECTPLATE", "Insect Plate"),
+        ("IRONBALL", "Iron Ball"),
+        ("IRONPLATE", "Iron Plate"),
+        ("KASIBBERRY", "Kasib Berry"),
+        ("KEBIABERRY", "Kebia Berry"),
+        ("KOMMONIUMZ", "Kommonium Z"),
+        ("LIECHIBERRY", "Liechi Berry"),
+        ("LIFEORB", "Life Orb"),
+        ("LIGHTBALL", "Light Ball"),
+        ("LOADEDDICE", "Loaded Dice"),
+        ("LUMBERRY", "Lum Berry"),
+        ("LUNALIUMZ", "Lunalium Z"),
+        ("LUSTROUSGLOBE", "Lustrous Globe"),
+        ("LUSTROUSORB", "Lustrous Orb"),
+        ("LYCANIUMZ", "Lycanium Z"),
+        ("MARSHADIUMZ", "Marshadium Z"),
+        ("MEADOWPLATE", "Meadow Plate"),
+        ("METALCOAT", "Metal Coat"),
+        ("METALPOWDER", "Metal Powder"),
+        ("MEWNIUMZ", "Mewnium Z"),
+        ("MEWTWONITEX", "Mewtwonite X"),
+        ("MEWTWONITEY", "Mewtwonite Y"),
+        ("MIMIKIUMZ", "Mimikium Z"),
+        ("MINDPLATE", "Mind Plate"),
+        ("MINTBERRY", "Mint Berry"),
+        ("MIRACLEBERRY", "Miracle Berry"),
+        ("MIRACLESEED", "Miracle Seed"),
+        ("MISTYSEED", "Misty Seed"),
+        ("MUSCLEBAND", "Muscle Band"),
+        ("MYSTICWATER", "Mystic Water"),
+        ("NEVERMELTICE", "Never-Melt Ice"),
+        ("NORMALGEM", "Normal Gem"),
+        ("NORMALIUMZ", "Normalium Z"),
+        ("OCCABERRY", "Occa Berry"),
+        ("ODDINCENSE", "Odd Incense"),
+        ("PASSHOBERRY", "Passho Berry"),
+        ("PAYAPABERRY", "Payapa Berry"),
+        ("PETAYABERRY", "Petaya Berry"),
+        ("PIKANIUMZ", "Pikanium Z"),
+        ("PIKASHUNIUMZ", "Pikashunium Z"),
+        ("PINKBOW", "Pink Bow"),
+        ("PIXIEPLATE", "Pixie Plate"),
+        ("POISONBARB", "Poison Barb"),
+        ("POISONGEM", "Poison Gem"),
+        ("POISONIUMZ", "Poisonium Z"),
+        ("POISONMEMORY", "Poison Memory"),
+        ("POLKADOTBOW", "Polkadot Bow"),
+        ("POWERHERB", "Power Herb"),
+        ("PRIMARIUMZ", "Primarium Z"),
+        ("PROTECTIVEPADS", "Protective Pads"),
+        ("PSYCHICGEM", "Psychic Gem"),
+        ("PSYCHICMEMORY", "Psychic Memory"),
+        ("PSYCHICSEED", "Psychic Seed"),
+        ("PSYCHIUMZ", "Psychium Z"),
+        ("PUNCHINGGLOVE", "Punching Glove"),
+        ("RINDOBERRY", "Rindo Berry"),
+        ("ROCKGEM", "Rock Gem"),
+        ("ROCKIUMZ", "Rockium Z"),
+        ("ROCKMEMORY", "Rock Memory"),
+        ("ROCKYHELMET", "Rocky Helmet"),
+        ("ROSELIBERRY", "Roseli Berry"),
+        ("RUSTEDSHIELD", "Rusted Shield"),
+        ("RUSTEDSWORD", "Rusted Sword"),
+        ("SALACBERRY", "Salac Berry"),
+        ("SEAINCENSE", "Sea Incense"),
+        ("SHARPBEAK", "Sharp Beak"),
+        ("SHEDSHELL", "Shed Shell"),
+        ("SHELLBELL", "Shell Bell"),
+        ("SHUCABERRY", "Shuca Berry"),
+        ("SILKSCARF", "Silk Scarf"),
+        ("SILVERPOWDER", "Silver Powder"),
+        ("SITRUSBERRY", "Sitrus Berry"),
+        ("SKYPLATE", "Sky Plate"),
+        ("SNORLIUMZ", "Snorlium Z"),
+        ("SOFTSAND", "Soft Sand"),
+        ("SOLGANIUMZ", "Solganium Z"),
+        ("SOULDEW", "Soul Dew"),
+        ("SPELLTAG", "Spell Tag"),
+        ("SPLASHPLATE", "Splash Plate"),
+        ("SPOOKYPLATE", "Spooky Plate"),
+        ("STEELGEM", "Steel Gem"),
+        ("STEELIUMZ", "Steelium Z"),
+        ("STEELMEMORY", "Steel Memory"),
+        ("STONEPLATE", "Stone Plate"),
+        ("TANGABERRY", "Tanga Berry"),
+        ("TAPUNIUMZ", "Tapunium Z"),
+        ("THICKCLUB", "Thick Club"),
+        ("THROATSPRAY", "Throat Spray"),
+        ("TOXICORB", "Toxic Orb"),
+        ("TOXICPLATE", "Toxic Plate"),
+        ("TWISTEDSPOON", "Twisted Spoon"),
+        ("ULTRANECROZIUMZ", "Ultranecrozium Z"),
+        ("WACANBERRY", "Wacan Berry"),
+        ("WATERGEM", "Water Gem"),
+        ("WATERIUMZ", "Waterium Z"),
+        ("WATERMEMORY", "Water Memory"),
+        ("WAVEINCENSE", "Wave Incense"),
+        ("WEAKNESSPOLICY", "Weakness Policy"),
+        ("WELLSPRINGMASK", "Wellspring Mask"),
+        ("WIDELENS", "Wide Lens"),
+        ("WISEGLASSES", "Wise Glasses"),
+        ("YACHEBERRY", "Yache Berry"),
+        ("ZAPPLATE", "Zap Plate"),
+    ]);
+    static ref ABILITY_NAMES: HashMap<&'static str, &'static str> = HashMap::from([
+        ("AIRLOCK", "Air Lock"),
+        ("ANGERPOINT", "Anger Point"),
+        ("ANGERSHELL", "Anger Shell"),
+        ("ARENATRAP", "Arena Trap"),
+        ("ARMORTAIL", "Armor Tail"),
+        ("AROMAVEIL", "Aroma Veil"),
+        ("ASONEGLASTRIER", "As One (Glastrier)"),
+        ("ASONESPECTRIER", "As One (Spectrier)"),
+        ("AURABREAK", "Aura Break"),
+        ("BADDREAMS", "Bad Dreams"),
+        ("BALLFETCH", "Ball Fetch"),
+        ("BATTLEARMOR", "Battle Armor"),
+        ("BATTLEBOND", "Battle Bond"),
+        ("BEADSOFRUIN", "Beads of Ruin"),
+        ("BEASTBOOST", "Beast Boost"),
+        ("BIGPECKS", "Big Pecks"),
+        ("CHEEKPOUCH", "Cheek Pouch"),
+        ("CHILLINGNEIGH", "Chilling Neigh"),
+        ("CLEARBODY", "Clear Body"),
+        ("CLOUDNINE", "Cloud Nine"),
+        ("COLORCHANGE", "Color Change"),
+        ("COMPOUNDEYES", "Compound Eyes"),
+        ("COTTONDOWN", "Cotton Down"),
+        ("CUDCHEW", "Cud Chew"),
+        ("CURIOUSMEDICINE", "Curious Medicine"),
+        ("CURSEDBODY", "Cursed Body"),
+        ("CUTECHARM", "Cute Charm"),
+        ("DARKAURA", "Dark Aura"),
+        ("DAUNTLESSSHIELD", "Dauntless Shield"),
+        ("DELTASTREAM", "Delta Stream"),
+        ("DESOLATELAND", "Desolate Land"),
+        ("DRAGONSMAW", "Dragon's Maw"),
+        ("DRYSKIN", "Dry Skin"),
+        ("EARLYBIRD", "Early Bird"),
+        ("EARTHEATER", "Earth Eater"),
+        ("EFFECTSPORE", "Effect Spore"),
+        ("ELECTRICSURGE", "Electric Surge"),
+        ("EMBODYASPECT", "Embody Aspect"),
+        ("EMBODYASPECTCORNERSTONE", "Embody Aspect (Cornerstone)"),
+        ("EMBODYASPECTHEARTHFLAME", "Embody Aspect (Hearthflame)"),
+        ("EMBODYASPECTTEAL", "Embody Aspect (Teal)"),
+        ("EMBODYASPECTWELLSPRING", "Embody Aspect (Wellspring)"),
+        ("EMERGENCYEXIT", "Emergency Exit"),
+        ("FAIRYAURA", "Fairy Aura"),
+        ("FLAMEBODY", "Flame Body"),
+        ("FLAREBOOST", "Flare Boost"),
+        ("FLASHFIRE", "Flash Fire"),
+        ("FLOWERGIFT", "Flower Gift"),
+        ("FLOWERVEIL", "Flower Veil"),
+        ("FRIENDGUARD", "Friend Guard"),
+        ("FULLMETALBODY", "Full Metal Body"),
+        ("FURCOAT", "Fur Coat"),
+        ("GALEWINGS", "Gale Wings"),
+        ("GOODASGOLD", "Good as Gold"),
+        ("GORILLATACTICS", "Gorilla Tactics"),
+        ("GRASSPELT", "Grass Pelt"),
+        ("GRASSYSURGE", "Grassy Surge"),
+        ("GRIMNEIGH", "Grim Neigh"),
+        ("GUARDDOG", "Guard Dog"),
+        ("GULPMISSILE", "Gulp Missile"),
+        ("HADRONENGINE", "Hadron Engine"),
+        ("HEAVYMETAL", "Heavy Metal"),
+        ("HONEYGATHER", "Honey Gather"),
+        ("HUGEPOWER", "Huge Power"),
+        ("HUNGERSWITCH", "Hunger Switch"),
+        ("HYPERCUTTER", "Hyper Cutter"),
+        ("ICEBODY", "Ice Body"),
+        ("ICEFACE", "Ice Face"),
+        ("ICESCALES", "Ice Scales"),
+        ("INNARDSOUT", "Innards Out"),
+        ("INNERFOCUS", "Inner Focus"),
+        ("INTREPIDSWORD", "Intrepid Sword"),
+        ("IRONBARBS", "Iron Barbs"),
+        ("IRONFIST", "Iron Fist"),
+        ("KEENEYE", "Keen Eye"),
+        ("LEAFGUARD", "Leaf Guard"),
+        ("LIGHTMETAL", "Light Metal"),
+        ("LIGHTNINGROD", "Lightning Rod"),
+        ("LINGERINGAROMA", "Lingering Aroma"),
+        ("LIQUIDOOZE", "Liquid Ooze"),
+        ("LIQUIDVOICE", "Liquid Voice"),
+        ("LONGREACH", "Long Reach"),
+        ("MAGICBOUNCE", "Magic Bounce"),
+        ("MAGICGUARD", "Magic Guard"),
+        ("MAGMAARMOR", "Magma Armor"),
+        ("MAGNETPULL", "Magnet Pull"),
+        ("MARVELSCALE", "Marvel Scale"),
+        ("MEGALAUNCHER", "Mega Launcher"),
+        ("MINDSEYE", "Mind's Eye"),
+        ("MIRRORARMOR", "Mirror Armor"),
+        ("MISTYSURGE", "Misty Surge"),
+        ("MOLDBREAKER", "Mold Breaker"),
+        ("MOTORDRIVE", "Motor Drive"),
+        ("MYCELIUMMIGHT", "Mycelium Might"),
+        ("NATURALCURE", "Natural Cure"),
+        ("NEUTRALIZINGGAS", "Neutralizing Gas"),
+        ("NOABILITY", "No Ability"),
+        ("NOGUARD", "No Guard"),
+        ("ORICHALCUMPULSE", "Orichalcum Pulse"),
+        ("OWNTEMPO", "Own Tempo"),
+        ("PARENTALBOND", "Parental Bond"),
+        ("PASTELVEIL", "Pastel Veil"),
+        ("PERISHBODY", "Perish Body"),
+        ("POISONHEAL", "Poison Heal"),
+        ("POISONPOINT", "Poison Point"),
+        ("POISONPUPPETEER", "Poison Puppeteer"),
+        ("POISONTOUCH", "Poison Touch"),
+        ("POWERCONSTRUCT", "Power Construct"),
+        ("POWEROFALCHEMY", "Power of Alchemy"),
+        ("POWERSPOT", "Power Spot"),
+        ("PRIMORDIALSEA", "Primordial Sea"),
+        ("PRISMARMOR", "Prism Armor"),
+        ("PROPELLERTAIL", "Propeller Tail"),
+        ("PSYCHICSURGE", "Psychic Surge"),
+        ("PUNKROCK", "Punk Rock"),
+        ("PUREPOWER", "Pure Power"),
+        ("PURIFYINGSALT", "Purifying Salt"),
+        ("QUARKDRIVE", "Quark Drive"),
+        ("QUEENLYMAJESTY", "Queenly Majesty"),
+        ("QUICKDRAW", "Quick Draw"),
+        ("QUICKFEET", "Quick Feet"),
+        ("RAINDISH", "Rain Dish"),
+        ("RKSSYSTEM", "RKS System"),
+        ("ROCKHEAD", "Rock Head"),
+        ("ROCKYPAYLOAD", "Rocky Payload"),
+        ("ROUGHSKIN", "Rough Skin"),
+        ("RUNAWAY", "Run Away"),
+        ("SANDFORCE", "Sand Force"),
+        ("SANDRUSH", "Sand Rush"),
+        ("SANDSPIT", "Sand Spit"),
+        ("SANDSTREAM", "Sand Stream"),
+        ("SANDVEIL", "Sand Veil"),
+        ("SAPSIPPER", "Sap Sipper"),
+        ("SCREENCLEANER", "Screen Cleaner"),
+        ("SEEDSOWER", "Seed Sower"),
+        ("SERENEGRACE", "Serene Grace"),
+        ("SHADOWSHIELD", "Shadow Shield"),
+        ("SHADOWTAG", "Shadow Tag"),
+        ("SHEDSKIN", "Shed Skin"),
+        ("SHEERFORCE", "Sheer Force"),
+        ("SHELLARMOR", "Shell Armor"),
+        ("SHIELDDUST", "Shield Dust"),
+        ("SHIELDSDOWN", "Shields Down"),
+        ("SKILLLINK", "Skill Link"),
+        ("SLOWSTART", "Slow Start"),
+        ("SLUSHRUSH", "Slush Rush"),
+        ("SNOWCLOAK", "Snow Cloak"),
+        ("SNOWWARNING", "Snow Warning"),
+        ("SOLARPOWER", "Solar Power"),
+        ("SOLIDROCK", "Solid Rock"),
+        ("SOULHEART", "Soul-Heart"),
+        ("SPEEDBOOST", "Speed Boost"),
+        ("STANCECHANGE", "Stance Change"),
+        ("STEAMENGINE", "Steam Engine"),
+        ("STEELYSPIRIT", "Steely Spirit"),
+        ("STICKYHOLD", "Sticky Hold"),
+        ("STORMDRAIN", "Storm Drain"),
+        ("STRONGJAW", "Strong Jaw"),
+        ("SUCTIONCUPS", "Suction Cups"),
+        ("SUPERLUCK", "Super Luck"),
+        ("SUPERSWEETSYRUP", "Supersweet Syrup"),
+        ("SUPREMEOVERLORD", "Supreme Overlord"),
+        ("SURGESURFER", "Surge Surfer"),
+        ("SWEETVEIL", "Sweet Veil"),
+        ("SWIFTSWIM", "Swift Swim"),
+        ("SWORDOFRUIN", "Sword of Ruin"),
+        ("TABLETSOFRUIN", "Tablets of Ruin"),
+        ("TANGLEDFEET", "Tangled Feet"),
+        ("TANGLINGHAIR", "Tangling Hair"),
+        ("TERAFORMZERO", "Teraform Zero"),
+        ("TERASHELL", "Tera Shell"),
+        ("TERASHIFT", "Tera Shift"),
+        ("THERMALEXCHANGE", "Thermal Exchange"),
+        ("THICKFAT", "Thick Fat"),
+        ("TINTEDLENS", "Tinted Lens"),
+        ("TOUGHCLAWS", "Tough Claws"),
+        ("TOXICBOOST", "Toxic Boost"),
+        ("TOXICCHAIN", "Toxic Chain"),
+        ("TOXICDEBRIS", "Toxic Debris"),
+        ("UNSEENFIST", "Unseen Fist"),
+        ("VESSELOFRUIN", "Vessel of Ruin"),
+        ("VICTORYSTAR", "Victory Star"),
+        ("VITALSPIRIT", "Vital Spirit"),
+        ("VOLTABSORB", "Volt Absorb"),
+        ("WANDERINGSPIRIT", "Wandering Spirit"),
+        ("WATERABSORB", "Water Absorb"),
+        ("WATERBUBBLE", "Water Bubble"),
+        ("WATERCOMPACTION", "Water Compaction"),
+        ("WATERVEIL", "Water Veil"),
+        ("WEAKARMOR", "Weak Armor"),
+        ("WELLBAKEDBODY", "Well-Baked Body"),
+        ("WHITESMOKE", "White Smoke"),
+        ("WIMPOUT", "Wimp Out"),
+        ("WINDPOWER", "Wind Power"),
+        ("WINDRIDER", "Wind Rider"),
+        ("WONDERGUARD", "Wonder Guard"),
+        ("WONDERSKIN", "Wonder Skin"),
+        ("ZENMODE", "Zen Mode"),
+        ("ZEROTOHERO", "Zero to Hero"),
+    ]);
+}
+
+// e.g. JOLLY as Jolly
+pub(crate) fn capitalized(name: &str) -> String {
+    let name = name.to_lowercase();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => name,
+    }
+}
+
+fn display_name(names: &HashMap<&'static str, &'static str>, id: &str) -> String {
+    match names.get(id) {
+        Some(name) => name.to_string(),
+        None => capitalized(id),
+    }
+}
+
+pub fn species_name(species: PokemonName) -> String {
+    display_name(&SPECIES_NAMES, &species.to_string())
+}
+
+// Hidden Power's type is written in brackets, e.g. "Hidden Power [Fire]"
+pub fn move_name(choice: Choices) -> String {
+    let id = choice.to_string();
+    match id.strip_prefix("HIDDENPOWER") {
+        Some("") => "Hidden Power".to_string(),
+        Some(hidden_power) => format!(
+            "Hidden Power [{}]",
+            capitalized(hidden_power.trim_end_matches(|c: char| c.is_ascii_digit()))
+        ),
+        None => display_name(&MOVE_NAMES, &id),
+    }
+}
+
+pub fn item_name(item: Items) -> String {
+    display_name(&ITEM_NAMES, &item.to_string())
+}
+
+pub fn ability_name(ability: Abilities) -> String {
+    display_name(&ABILITY_NAMES, &ability.to_string())
+}
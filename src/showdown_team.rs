@@ -0,0 +1,597 @@
+use crate::choices::Choices;
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::pokemon::PokemonName;
+use crate::showdown_names::{ability_name, capitalized, item_name, move_name, species_name};
+use crate::state::{Pokemon, PokemonNature, PokemonType, Side};
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+use crate::state::{PokemonMoveIndex, SidePokemon};
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+const STAT_NAMES: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+// A single pokemon of a Pokemon Showdown team
+#[derive(Debug, PartialEq, Clone)]
+pub struct ShowdownSet {
+    pub species: PokemonName,
+    pub item: Items,
+    // None uses the first legal ability of the species
+    pub ability: Option<Abilities>,
+    pub level: i8,
+    pub evs: (u8, u8, u8, u8, u8, u8),
+    pub ivs: (u8, u8, u8, u8, u8, u8),
+    pub nature: PokemonNature,
+    pub moves: Vec<Choices>,
+    // None uses the primary type of the species
+    pub tera_type: Option<PokemonType>,
+}
+
+impl Default for ShowdownSet {
+    fn default() -> ShowdownSet {
+        ShowdownSet {
+            species: PokemonName::NONE,
+            item: Items::NONE,
+            ability: None,
+            level: 100,
+            evs: (0, 0, 0, 0, 0, 0),
+            ivs: (31, 31, 31, 31, 31, 31),
+            nature: PokemonNature::SERIOUS,
+            moves: vec![],
+            tera_type: None,
+        }
+    }
+}
+
+// Showdown's toID: lowercase and only alphanumeric characters
+// e.g. "Farfetch’d" -> "farfetchd", "U-turn" -> "uturn", "Flabébé" -> "flabebe"
+pub(crate) fn to_id(name: &str) -> String {
+    name.chars()
+        .filter_map(|c| match c {
+            'é' | 'É' => Some('e'),
+            c if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn stats_to_array(stats: (u8, u8, u8, u8, u8, u8)) -> [u8; 6] {
+    [stats.0, stats.1, stats.2, stats.3, stats.4, stats.5]
+}
+
+fn array_to_stats(stats: [u8; 6]) -> (u8, u8, u8, u8, u8, u8) {
+    (stats[0], stats[1], stats[2], stats[3], stats[4], stats[5])
+}
+
+fn parse_stat_amount(amount: &str, default: u8) -> Result<u8, String> {
+    if amount.is_empty() {
+        return Ok(default);
+    }
+    amount
+        .parse::<u8>()
+        .map_err(|_| format!("invalid stat value: {}", amount))
+}
+
+// e.g. "252 Atk / 4 SpD / 252 Spe"
+fn parse_stat_spread(spread: &str, default: u8) -> Result<(u8, u8, u8, u8, u8, u8), String> {
+    let mut stats = [default; 6];
+    for part in spread.split('/') {
+        let mut split = part.split_whitespace();
+        let (amount, stat) = match (split.next(), split.next()) {
+            (Some(amount), Some(stat)) => (amount, stat),
+            _ => return Err(format!("invalid stat spread: {}", spread)),
+        };
+        let index = match STAT_NAMES.iter().position(|s| s.eq_ignore_ascii_case(stat)) {
+            Some(index) => index,
+            None => return Err(format!("invalid stat: {}", stat)),
+        };
+        stats[index] = parse_stat_amount(amount, default)?;
+    }
+    Ok(array_to_stats(stats))
+}
+
+// e.g. "252,,,,4,252" where an empty value is the default
+fn parse_packed_stat_spread(spread: &str, default: u8) -> Result<(u8, u8, u8, u8, u8, u8), String> {
+    let mut stats = [default; 6];
+    if spread.is_empty() {
+        return Ok(array_to_stats(stats));
+    }
+    let amounts: Vec<&str> = spread.split(',').collect();
+    if amounts.len() != 6 {
+        return Err(format!("invalid stat spread: {}", spread));
+    }
+    for (index, amount) in amounts.iter().enumerate() {
+        stats[index] = parse_stat_amount(amount, default)?;
+    }
+    Ok(array_to_stats(stats))
+}
+
+fn stat_spread_to_string(stats: (u8, u8, u8, u8, u8, u8), default: u8) -> String {
+    stats_to_array(stats)
+        .iter()
+        .zip(STAT_NAMES.iter())
+        .filter(|(amount, _)| **amount != default)
+        .map(|(amount, name)| format!("{} {}", amount, name))
+        .collect::<Vec<String>>()
+        .join(" / ")
+}
+
+pub(crate) fn parse_species(name: &str) -> Result<PokemonName, String> {
+    match PokemonName::from_str(&to_id(name)) {
+        Ok(PokemonName::NONE) | Err(_) => Err(format!("unknown species: {}", name)),
+        Ok(species) => Ok(species),
+    }
+}
+
+pub(crate) fn parse_item(name: &str) -> Result<Items, String> {
+    let id = to_id(name);
+    if id.is_empty() || id == "noitem" {
+        return Ok(Items::NONE);
+    }
+    match Items::from_str(&id) {
+        Ok(Items::UNKNOWNITEM) | Err(_) => Err(format!("unknown item: {}", name)),
+        Ok(item) => Ok(item),
+    }
+}
+
+pub(crate) fn parse_ability(name: &str) -> Result<Abilities, String> {
+    let id = to_id(name);
+    if id == "noability" || id == "none" {
+        return Ok(Abilities::NONE);
+    }
+    match Abilities::from_str(&id) {
+        Ok(Abilities::NONE) | Err(_) => Err(format!("unknown ability: {}", name)),
+        Ok(ability) => Ok(ability),
+    }
+}
+
+pub(crate) fn parse_move(name: &str) -> Result<Choices, String> {
+    let mut id = to_id(name);
+    // e.g. "Hidden Power [Fire]". See `resolve_hidden_power` for how the type is used
+    if id.starts_with("hiddenpower")
+        && id != "hiddenpower"
+        && !id.ends_with(|c: char| c.is_ascii_digit())
+    {
+        id.push_str("60");
+    }
+    // move names can not start with a digit
+    if id == "10000000voltthunderbolt" {
+        id = "tenmillionvoltthunderbolt".to_string();
+    }
+    match Choices::from_str(&id) {
+        Ok(Choices::NONE) | Err(_) => Err(format!("unknown move: {}", name)),
+        Ok(choice) => Ok(choice),
+    }
+}
+
+pub(crate) fn parse_nature(name: &str) -> Result<PokemonNature, String> {
+    let id = to_id(name).to_uppercase();
+    (0..=PokemonNature::SERIOUS as u8)
+        .map(PokemonNature::from)
+        .find(|nature| nature.to_string() == id)
+        .ok_or(format!("unknown nature: {}", name))
+}
+
+pub(crate) fn parse_type(name: &str) -> Result<PokemonType, String> {
+    let id = to_id(name);
+    match PokemonType::from_str(&id) {
+        Ok(pkmn_type) if pkmn_type != PokemonType::TYPELESS || id == "typeless" => Ok(pkmn_type),
+        _ => Err(format!("unknown type: {}", name)),
+    }
+}
+
+// Gen2 takes Hidden Power's type from the attack and defense DVs. Like Showdown, those are
+// lowered to 12-15 and every other DV is 15, which is an IV of 30 (DV = IV / 2)
+#[cfg(feature = "gen2")]
+fn hidden_power_ivs(hidden_power_type: PokemonType) -> (u8, u8, u8, u8, u8, u8) {
+    let types = crate::state::HIDDEN_POWER_TYPES;
+    let type_index = types
+        .iter()
+        .position(|t| *t == hidden_power_type)
+        .unwrap_or(types.len() - 1) as u8;
+    (
+        30,
+        2 * (12 + type_index / 4),
+        2 * (12 + type_index % 4),
+        30,
+        30,
+        30,
+    )
+}
+
+// IVs that Showdown gives a pokemon with perfect IVs to get each Hidden Power type
+#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
+fn hidden_power_ivs(hidden_power_type: PokemonType) -> (u8, u8, u8, u8, u8, u8) {
+    match hidden_power_type {
+        PokemonType::BUG => (31, 30, 30, 31, 30, 31),
+        PokemonType::DRAGON => (31, 30, 31, 31, 31, 31),
+        PokemonType::ELECTRIC => (31, 31, 31, 30, 31, 31),
+        PokemonType::FIGHTING => (31, 31, 30, 30, 30, 30),
+        PokemonType::FIRE => (31, 30, 31, 30, 31, 30),
+        PokemonType::FLYING => (30, 30, 30, 30, 30, 31),
+        PokemonType::GHOST => (31, 31, 30, 31, 30, 31),
+        PokemonType::GRASS => (31, 30, 31, 30, 31, 31),
+        PokemonType::GROUND => (31, 31, 31, 30, 30, 31),
+        PokemonType::ICE => (31, 30, 30, 31, 31, 31),
+        PokemonType::POISON => (31, 31, 30, 30, 30, 31),
+        PokemonType::PSYCHIC => (31, 30, 31, 31, 31, 30),
+        PokemonType::ROCK => (31, 31, 30, 31, 30, 30),
+        PokemonType::STEEL => (31, 31, 31, 31, 30, 31),
+        PokemonType::WATER => (31, 30, 30, 30, 31, 31),
+        _ => (31, 31, 31, 31, 31, 31),
+    }
+}
+
+// Before gen7 Hidden Power's type comes from the IVs. Like Showdown, a typed Hidden Power
+// on a pokemon with perfect IVs changes its IVs to ones that give that type.
+// From gen7 Hyper Training allows any Hidden Power type with perfect IVs
+#[cfg(any(
+    feature = "gen2",
+    feature = "gen3",
+    feature = "gen4",
+    feature = "gen5",
+    feature = "gen6"
+))]
+fn resolve_hidden_power(set: &mut ShowdownSet) {
+    for choice in set.moves.iter_mut() {
+        if choice.is_hiddenpower() && *choice != Choices::HIDDENPOWER {
+            if set.ivs == (31, 31, 31, 31, 31, 31) {
+                set.ivs = hidden_power_ivs(crate::choices::MOVES.get(choice).unwrap().move_type);
+            }
+            *choice = Choices::HIDDENPOWER;
+        }
+    }
+}
+
+// e.g. "Nickname (Garchomp) (M) @ Choice Scarf"
+fn parse_species_line(line: &str, set: &mut ShowdownSet) -> Result<(), String> {
+    let (name, item) = match line.split_once(" @ ") {
+        Some((name, item)) => (name.trim(), item.trim()),
+        None => (line.trim(), ""),
+    };
+    let name = name
+        .strip_suffix(" (M)")
+        .or_else(|| name.strip_suffix(" (F)"))
+        .unwrap_or(name);
+    let species = match (name.rfind('('), name.strip_suffix(')')) {
+        (Some(start), Some(name_without_paren)) => &name_without_paren[start + 1..],
+        _ => name,
+    };
+    set.species = parse_species(species)?;
+    set.item = parse_item(item)?;
+    Ok(())
+}
+
+fn parse_showdown_set(block: &str) -> Result<ShowdownSet, String> {
+    let mut set = ShowdownSet::default();
+    let mut lines = block.lines().map(|l| l.trim()).filter(|l| !l.is_empty());
+    match lines.next() {
+        Some(line) => parse_species_line(line, &mut set)?,
+        None => return Err("empty pokemon".to_string()),
+    }
+    for line in lines {
+        if let Some(ability) = line.strip_prefix("Ability:") {
+            set.ability = Some(parse_ability(ability)?);
+        } else if let Some(level) = line.strip_prefix("Level:") {
+            set.level = level
+                .trim()
+                .parse::<i8>()
+                .map_err(|_| format!("invalid level: {}", level.trim()))?;
+        } else if let Some(tera_type) = line.strip_prefix("Tera Type:") {
+            set.tera_type = Some(parse_type(tera_type)?);
+        } else if let Some(evs) = line.strip_prefix("EVs:") {
+            set.evs = parse_stat_spread(evs, 0)?;
+        } else if let Some(ivs) = line.strip_prefix("IVs:") {
+            set.ivs = parse_stat_spread(ivs, 31)?;
+        } else if let Some(nature) = line.strip_suffix(" Nature") {
+            set.nature = parse_nature(nature)?;
+        } else if let Some(move_name) = line.strip_prefix('-') {
+            // sample sets may list alternatives, e.g. "- Earthquake / Stone Edge"
+            let move_name = move_name.split(" / ").next().unwrap_or(move_name);
+            set.moves.push(parse_move(move_name)?);
+        }
+        // other lines such as "Shiny: Yes" or "Happiness: 0" do not affect the battle
+    }
+    if set.moves.len() > 4 {
+        return Err(format!("{} has more than 4 moves", set.species));
+    }
+    #[cfg(any(
+        feature = "gen2",
+        feature = "gen3",
+        feature = "gen4",
+        feature = "gen5",
+        feature = "gen6"
+    ))]
+    resolve_hidden_power(&mut set);
+    Ok(set)
+}
+
+// NICKNAME|SPECIES|ITEM|ABILITY|MOVES|NATURE|EVS|GENDER|IVS|SHINY|LEVEL|HAPPINESS,POKEBALL,HIDDENPOWERTYPE,GIGANTAMAX,DYNAMAXLEVEL,TERATYPE
+fn parse_packed_showdown_set(packed: &str) -> Result<ShowdownSet, String> {
+    let mut set = ShowdownSet::default();
+    let fields: Vec<&str> = packed.split('|').collect();
+    let field = |index: usize| -> &str { fields.get(index).copied().unwrap_or("") };
+    if fields.len() < 7 {
+        return Err(format!("invalid packed pokemon: {}", packed));
+    }
+
+    set.species = if field(1).is_empty() {
+        parse_species(field(0))?
+    } else {
+        parse_species(field(1))?
+    };
+    set.item = parse_item(field(2))?;
+    set.ability = match field(3) {
+        "" => None,
+        // gen2 and gen3 have no pokedex to look up the ability in
+        #[cfg(any(feature = "gen2", feature = "gen3"))]
+        "0" | "1" | "H" => None,
+        #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+        "0" => set.species.standard_abilities().first().copied(),
+        #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+        "1" => set
+            .species
+            .standard_abilities()
+            .get(1)
+            .or(set.species.standard_abilities().first())
+            .copied(),
+        #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+        "H" => set
+            .species
+            .hidden_ability()
+            .or(set.species.standard_abilities().first().copied()),
+        ability => Some(parse_ability(ability)?),
+    };
+    for move_name in field(4).split(',').filter(|m| !m.is_empty()) {
+        set.moves.push(parse_move(move_name)?);
+    }
+    if !field(5).is_empty() {
+        set.nature = parse_nature(field(5))?;
+    }
+    set.evs = parse_packed_stat_spread(field(6), 0)?;
+    set.ivs = parse_packed_stat_spread(field(8), 31)?;
+    if !field(10).is_empty() {
+        set.level = field(10)
+            .parse::<i8>()
+            .map_err(|_| format!("invalid level: {}", field(10)))?;
+    }
+    if let Some(tera_type) = field(11).split(',').nth(5) {
+        if !tera_type.is_empty() {
+            set.tera_type = Some(parse_type(tera_type)?);
+        }
+    }
+    if set.moves.len() > 4 {
+        return Err(format!("{} has more than 4 moves", set.species));
+    }
+    #[cfg(any(
+        feature = "gen2",
+        feature = "gen3",
+        feature = "gen4",
+        feature = "gen5",
+        feature = "gen6"
+    ))]
+    resolve_hidden_power(&mut set);
+    Ok(set)
+}
+
+/// Parses a team in either Pokemon Showdown's export format or its packed format
+///
+/// Nicknames, genders and other details that do not affect a battle are ignored
+pub fn parse_showdown_team(team: &str) -> Result<Vec<ShowdownSet>, String> {
+    let team = team.trim();
+    let sets = if !team.contains('\n') && team.contains('|') {
+        team.split(']')
+            .filter(|packed| !packed.is_empty())
+            .map(parse_packed_showdown_set)
+            .collect::<Result<Vec<ShowdownSet>, String>>()?
+    } else {
+        let mut blocks = vec![];
+        let mut block = String::new();
+        for line in team.lines() {
+            let line = line.trim();
+            // "=== [gen9ou] Team Name ===" headers separate teams in a Showdown teambuilder backup
+            if line.is_empty() || line.starts_with("===") {
+                if !block.is_empty() {
+                    blocks.push(block);
+                    block = String::new();
+                }
+                continue;
+            }
+            block.push_str(line);
+            block.push('\n');
+        }
+        if !block.is_empty() {
+            blocks.push(block);
+        }
+        blocks
+            .iter()
+            .map(|block| parse_showdown_set(block))
+            .collect::<Result<Vec<ShowdownSet>, String>>()?
+    };
+
+    if sets.is_empty() {
+        return Err("no pokemon found in team".to_string());
+    }
+    if sets.len() > 6 {
+        return Err(format!(
+            "team has {} pokemon, at most 6 are allowed",
+            sets.len()
+        ));
+    }
+    Ok(sets)
+}
+
+impl ShowdownSet {
+    // gen2 and gen3 have no base stats to build a pokemon from
+    #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+    pub fn to_pokemon(&self) -> Pokemon {
+        let mut pkmn =
+            Pokemon::from_species(self.species, self.level, self.evs, self.nature.clone());
+        if let Some(ability) = self.ability {
+            pkmn.ability = ability;
+            pkmn.base_ability = ability;
+        }
+        if let Some(tera_type) = self.tera_type {
+            pkmn.tera_type = tera_type;
+        }
+        pkmn.item = self.item;
+        pkmn.ivs = self.ivs;
+
+        let stats = pkmn.calculate_stats_from_base_stats();
+        pkmn.hp = stats.0;
+        pkmn.maxhp = stats.0;
+        pkmn.attack = stats.1;
+        pkmn.defense = stats.2;
+        pkmn.special_attack = stats.3;
+        pkmn.special_defense = stats.4;
+        pkmn.speed = stats.5;
+
+        for (move_index, move_slot) in [
+            PokemonMoveIndex::M0,
+            PokemonMoveIndex::M1,
+            PokemonMoveIndex::M2,
+            PokemonMoveIndex::M3,
+        ]
+        .iter()
+        .enumerate()
+        {
+            match self.moves.get(move_index) {
+                Some(choice) => pkmn.replace_move(*move_slot, *choice),
+                None => {
+                    pkmn.moves[move_slot].disabled = true;
+                    pkmn.moves[move_slot].pp = 0;
+                }
+            }
+        }
+        pkmn
+    }
+
+    pub fn from_pokemon(pkmn: &Pokemon) -> ShowdownSet {
+        ShowdownSet {
+            species: pkmn.id,
+            item: pkmn.item,
+            ability: Some(pkmn.base_ability),
+            level: pkmn.level,
+            evs: pkmn.evs,
+            ivs: pkmn.ivs,
+            nature: pkmn.nature.clone(),
+            moves: pkmn
+                .moves
+                .into_iter()
+                .filter(|m| m.id != Choices::NONE)
+                .map(|m| m.id)
+                .collect(),
+            tera_type: Some(pkmn.tera_type),
+        }
+    }
+}
+
+impl fmt::Display for ShowdownSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", species_name(self.species))?;
+        if self.item != Items::NONE {
+            write!(f, " @ {}", item_name(self.item))?;
+        }
+        writeln!(f)?;
+        if let Some(ability) = self.ability {
+            writeln!(f, "Ability: {}", ability_name(ability))?;
+        }
+        if self.level != 100 {
+            writeln!(f, "Level: {}", self.level)?;
+        }
+        if cfg!(feature = "gen9") {
+            if let Some(tera_type) = self.tera_type {
+                writeln!(f, "Tera Type: {}", capitalized(&tera_type.to_string()))?;
+            }
+        }
+        let evs = stat_spread_to_string(self.evs, 0);
+        if !evs.is_empty() {
+            writeln!(f, "EVs: {}", evs)?;
+        }
+        writeln!(f, "{} Nature", capitalized(&self.nature.to_string()))?;
+        let ivs = stat_spread_to_string(self.ivs, 31);
+        if !ivs.is_empty() {
+            writeln!(f, "IVs: {}", ivs)?;
+        }
+        for choice in self.moves.iter() {
+            writeln!(f, "- {}", move_name(*choice))?;
+        }
+        Ok(())
+    }
+}
+
+impl Side {
+    /// Builds a side from a team in Pokemon Showdown's export or packed format
+    ///
+    /// Empty team slots are filled with fainted pokemon
+    #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+    pub fn from_showdown_team(team: &str) -> Result<Side, String> {
+        let sets = parse_showdown_team(team)?;
+        let mut pokemon: Vec<Pokemon> = sets.iter().map(|set| set.to_pokemon()).collect();
+        while pokemon.len() < 6 {
+            pokemon.push(Pokemon {
+                hp: 0,
+                ..Pokemon::default()
+            });
+        }
+        let mut pokemon = pokemon.into_iter();
+        Ok(Side {
+            pokemon: SidePokemon {
+                p0: pokemon.next().unwrap(),
+                p1: pokemon.next().unwrap(),
+                p2: pokemon.next().unwrap(),
+                p3: pokemon.next().unwrap(),
+                p4: pokemon.next().unwrap(),
+                p5: pokemon.next().unwrap(),
+            },
+            ..Side::default()
+        })
+    }
+
+    /// Parses several teams, e.g. a Showdown teambuilder backup where each team starts with
+    /// a "=== [gen9ou] Team Name ===" header. A line in the packed format is a team of its own
+    #[cfg(not(any(feature = "gen2", feature = "gen3")))]
+    pub fn from_showdown_teams(teams: &str) -> Result<Vec<Side>, String> {
+        let mut blocks = vec![];
+        let mut block = String::new();
+        for line in teams.lines() {
+            let packed = line.contains('|');
+            if packed || line.trim().starts_with("===") {
+                if !block.trim().is_empty() {
+                    blocks.push(block);
+                }
+                block = String::new();
+            }
+            if packed {
+                blocks.push(line.to_string());
+                continue;
+            }
+            block.push_str(line);
+            block.push('\n');
+        }
+        if !block.trim().is_empty() {
+            blocks.push(block);
+        }
+        if blocks.is_empty() {
+            return Err("no teams found".to_string());
+        }
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(index, team)| {
+                Side::from_showdown_team(team).map_err(|e| format!("team {}: {}", index + 1, e))
+            })
+            .collect()
+    }
+
+    /// Exports the side's pokemon in Pokemon Showdown's export format
+    pub fn to_showdown_team(&self) -> String {
+        self.pokemon
+            .into_iter()
+            .filter(|pkmn| pkmn.id != PokemonName::NONE)
+            .map(|pkmn| ShowdownSet::from_pokemon(pkmn).to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,233 @@
+use crate::agent::{agent_from_str, Agent};
+use crate::damage_branching::DamageBranching;
+use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+use crate::engine::state::MoveChoice;
+use crate::instruction::Instruction;
+use crate::state::{SideReference, State};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde_json::{json, Value};
+
+/// One step of a simulated battle: the state before it, the choices both sides made and the
+/// instructions of the outcome that was sampled
+pub struct TurnLog {
+    pub turn: u16,
+    // true when the step only replaced a fainted or switched-out pokemon
+    pub forced_switch: bool,
+    pub state: State,
+    pub side_one_choice: MoveChoice,
+    pub side_two_choice: MoveChoice,
+    pub percentage: f32,
+    pub instructions: Vec<Instruction>,
+}
+
+impl TurnLog {
+    pub fn side_one_choice_string(&self) -> String {
+        self.side_one_choice.to_string(&self.state.side_one)
+    }
+
+    pub fn side_two_choice_string(&self) -> String {
+        self.side_two_choice.to_string(&self.state.side_two)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "turn": self.turn,
+            "forced_switch": self.forced_switch,
+            "side_one_choice": self.side_one_choice_string(),
+            "side_two_choice": self.side_two_choice_string(),
+            "percentage": self.percentage,
+            "instructions": self
+                .instructions
+                .iter()
+                .map(|i| format!("{:?}", i))
+                .collect::<Vec<String>>(),
+            "state": self.state.serialize(),
+        })
+    }
+}
+
+impl std::fmt::Display for TurnLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "turn {}: {} vs {} ({:.2}%)",
+            self.turn,
+            self.side_one_choice_string(),
+            self.side_two_choice_string(),
+            self.percentage
+        )?;
+        if self.forced_switch {
+            write!(f, " forced switch")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct BattleResult {
+    pub turns: Vec<TurnLog>,
+    pub final_state: State,
+    // None when the turn limit was reached first
+    pub winner: Option<SideReference>,
+}
+
+impl BattleResult {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "winner": match self.winner {
+                Some(SideReference::SideOne) => "side_one",
+                Some(SideReference::SideTwo) => "side_two",
+                None => "none",
+            },
+            "turns": self.turns.iter().map(|t| t.to_json()).collect::<Vec<Value>>(),
+            "final_state": self.final_state.serialize(),
+        })
+    }
+}
+
+// A side with a single option, e.g. waiting while the other side replaces a fainted
+// pokemon, does not ask its agent
+fn choose(
+    state: &State,
+    side_ref: SideReference,
+    options: &[MoveChoice],
+    agent: &mut dyn Agent,
+) -> MoveChoice {
+    match options {
+        [] => MoveChoice::None,
+        [only_option] => *only_option,
+        _ => agent.choose_move(state, side_ref, options),
+    }
+}
+
+/// Plays a battle between two agents until one side has no pokemon left or `max_turns`
+/// turns have been played. Each step both agents choose a move, one outcome of
+/// `generate_instructions_from_move_pair`, with every damage roll as its own branch, is sampled by
+/// its percentage and applied
+///
+/// Steps where a side only replaces a fainted pokemon do not count as turns
+pub fn simulate_battle<R: Rng>(
+    state: State,
+    side_one: &mut dyn Agent,
+    side_two: &mut dyn Agent,
+    max_turns: u16,
+    rng: &mut R,
+) -> BattleResult {
+    simulate_battle_with(
+        state,
+        |state, side_one_options, side_two_options| {
+            (
+                choose(state, SideReference::SideOne, side_one_options, side_one),
+                choose(state, SideReference::SideTwo, side_two_options, side_two),
+            )
+        },
+        max_turns,
+        rng,
+    )
+}
+
+/// Like `simulate_battle`, but both sides' moves are chosen together by `choose_moves`,
+/// e.g. from one search that gives the choices of both sides
+pub fn simulate_battle_with<R, F>(
+    mut state: State,
+    mut choose_moves: F,
+    max_turns: u16,
+    rng: &mut R,
+) -> BattleResult
+where
+    R: Rng,
+    F: FnMut(&State, &[MoveChoice], &[MoveChoice]) -> (MoveChoice, MoveChoice),
+{
+    let mut turns = vec![];
+    let mut turn = 0;
+
+    if state.team_preview {
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        let (side_one_lead, side_two_lead) =
+            choose_moves(&state, &side_one_options, &side_two_options);
+        for (side_ref, lead) in [
+            (SideReference::SideOne, side_one_lead),
+            (SideReference::SideTwo, side_two_lead),
+        ] {
+            if let MoveChoice::Switch(index) = lead {
+                state.get_side(&side_ref).active_index = index;
+            }
+        }
+        state.team_preview = false;
+    }
+
+    while state.battle_is_over() == 0.0 {
+        let forced_switch = state.side_one.force_switch
+            || state.side_two.force_switch
+            || state.side_one.get_active_immutable().hp <= 0
+            || state.side_two.get_active_immutable().hp <= 0;
+        if !forced_switch {
+            if turn == max_turns {
+                break;
+            }
+            turn += 1;
+        }
+
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        let (side_one_choice, side_two_choice) =
+            choose_moves(&state, &side_one_options, &side_two_options);
+
+        let before = state.clone();
+        let mut outcomes = generate_instructions_from_move_pair(
+            &mut state,
+            &side_one_choice,
+            &side_two_choice,
+            DamageBranching::AllRolls,
+            0.0,
+        );
+        let index = WeightedIndex::new(outcomes.iter().map(|o| o.percentage))
+            .map(|dist| dist.sample(rng))
+            .unwrap_or(0);
+        let outcome = outcomes.swap_remove(index);
+        state.apply_instructions(&outcome.instruction_list);
+
+        turns.push(TurnLog {
+            turn,
+            forced_switch,
+            state: before,
+            side_one_choice,
+            side_two_choice,
+            percentage: outcome.percentage,
+            instructions: outcome.instruction_list,
+        });
+    }
+
+    let winner = match state.battle_is_over() {
+        x if x > 0.0 => Some(SideReference::SideOne),
+        x if x < 0.0 => Some(SideReference::SideTwo),
+        _ => None,
+    };
+    BattleResult {
+        turns,
+        final_state: state,
+        winner,
+    }
+}
+
+/// Plays a battle between two agents described as in `agent_from_str`. The same seed
+/// always samples the same outcomes, although searches that are limited by time may not
+/// choose the same moves every time
+pub fn simulate_battle_from_str(
+    state: State,
+    side_one: &str,
+    side_two: &str,
+    max_turns: u16,
+    seed: u64,
+) -> Result<BattleResult, String> {
+    // each agent gets its own seed so that changing one agent does not change the other
+    let mut side_one = agent_from_str(side_one, seed)?;
+    let mut side_two = agent_from_str(side_two, seed.wrapping_add(1))?;
+    Ok(simulate_battle(
+        state,
+        side_one.as_mut(),
+        side_two.as_mut(),
+        max_turns,
+        &mut StdRng::seed_from_u64(seed),
+    ))
+}
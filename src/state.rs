@@ -170,6 +170,48 @@ define_enum_with_from_str! {
     }
 }
 
+impl PokemonNature {
+    // (raised stat, lowered stat), or None for neutral natures
+    pub fn stat_modifiers(&self) -> Option<(PokemonBoostableStat, PokemonBoostableStat)> {
+        use PokemonBoostableStat::{Attack, Defense, SpecialAttack, SpecialDefense, Speed};
+        match self {
+            PokemonNature::LONELY => Some((Attack, Defense)),
+            PokemonNature::ADAMANT => Some((Attack, SpecialAttack)),
+            PokemonNature::NAUGHTY => Some((Attack, SpecialDefense)),
+            PokemonNature::BRAVE => Some((Attack, Speed)),
+            PokemonNature::BOLD => Some((Defense, Attack)),
+            PokemonNature::IMPISH => Some((Defense, SpecialAttack)),
+            PokemonNature::LAX => Some((Defense, SpecialDefense)),
+            PokemonNature::RELAXED => Some((Defense, Speed)),
+            PokemonNature::MODEST => Some((SpecialAttack, Attack)),
+            PokemonNature::MILD => Some((SpecialAttack, Defense)),
+            PokemonNature::RASH => Some((SpecialAttack, SpecialDefense)),
+            PokemonNature::QUIET => Some((SpecialAttack, Speed)),
+            PokemonNature::CALM => Some((SpecialDefense, Attack)),
+            PokemonNature::GENTLE => Some((SpecialDefense, Defense)),
+            PokemonNature::CAREFUL => Some((SpecialDefense, SpecialAttack)),
+            PokemonNature::SASSY => Some((SpecialDefense, Speed)),
+            PokemonNature::TIMID => Some((Speed, Attack)),
+            PokemonNature::HASTY => Some((Speed, Defense)),
+            PokemonNature::JOLLY => Some((Speed, SpecialAttack)),
+            PokemonNature::NAIVE => Some((Speed, SpecialDefense)),
+            PokemonNature::HARDY
+            | PokemonNature::DOCILE
+            | PokemonNature::BASHFUL
+            | PokemonNature::QUIRKY
+            | PokemonNature::SERIOUS => None,
+        }
+    }
+
+    pub fn modify_stat(&self, stat: u16, stat_type: PokemonBoostableStat) -> u16 {
+        match self.stat_modifiers() {
+            Some((raised, _)) if raised == stat_type => stat * 11 / 10,
+            Some((_, lowered)) if lowered == stat_type => stat * 9 / 10,
+            _ => stat,
+        }
+    }
+}
+
 define_enum_with_from_str! {
     #[repr(u8)]
     #[derive(Debug, Clone, Copy, PartialEq)]
@@ -505,6 +547,8 @@ impl Default for Side {
             damage_dealt: DamageDealt::default(),
             switch_out_move_second_saved_move: Choices::NONE,
             evasion_boost: 0,
+            mega_evolved: false,
+            z_move_used: false,
         }
     }
 }
@@ -833,6 +877,7 @@ pub struct Pokemon {
     pub item: Items,
     pub nature: PokemonNature,
     pub evs: (u8, u8, u8, u8, u8, u8),
+    pub ivs: (u8, u8, u8, u8, u8, u8),
     pub attack: i16,
     pub defense: i16,
     pub special_attack: i16,
@@ -861,6 +906,7 @@ impl Default for Pokemon {
             item: Items::NONE,
             nature: PokemonNature::SERIOUS,
             evs: (85, 85, 85, 85, 85, 85),
+            ivs: (31, 31, 31, 31, 31, 31),
             attack: 100,
             defense: 100,
             special_attack: 100,
@@ -886,6 +932,29 @@ impl Pokemon {
     pub fn replace_move(&mut self, move_index: PokemonMoveIndex, new_move_name: Choices) {
         self.moves[&move_index].choice = MOVES.get(&new_move_name).unwrap().to_owned();
         self.moves[&move_index].id = new_move_name;
+        self.refresh_hidden_power();
+    }
+
+    // Type and base power of Hidden Power for this pokemon's IVs
+    pub fn hidden_power(&self) -> (PokemonType, f32) {
+        hidden_power_from_ivs(self.ivs)
+    }
+
+    // The generic HIDDENPOWER move takes its type and base power from the IVs.
+    // Variants with a fixed type and base power (i.e. HIDDENPOWERFIRE70) are unaffected
+    pub fn refresh_hidden_power(&mut self) {
+        let (move_type, base_power) = self.hidden_power();
+        for mv in [
+            &mut self.moves.m0,
+            &mut self.moves.m1,
+            &mut self.moves.m2,
+            &mut self.moves.m3,
+        ] {
+            if mv.id == Choices::HIDDENPOWER {
+                mv.choice.move_type = move_type;
+                mv.choice.base_power = base_power;
+            }
+        }
     }
     pub fn get_sleep_talk_choices(&self) -> Vec<Choice> {
         let mut vec = Vec::with_capacity(4);
@@ -933,8 +1002,12 @@ impl Pokemon {
             "{};{};{};{};{};{}",
             self.evs.0, self.evs.1, self.evs.2, self.evs.3, self.evs.4, self.evs.5
         );
+        let ivs_str = format!(
+            "{};{};{};{};{};{}",
+            self.ivs.0, self.ivs.1, self.ivs.2, self.ivs.3, self.ivs.4, self.ivs.5
+        );
         format!(
-            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
             self.id,
             self.level,
             self.types.0.to_string(),
@@ -948,6 +1021,7 @@ impl Pokemon {
             self.item.to_string(),
             self.nature.to_string(),
             evs_str,
+            ivs_str,
             self.attack,
             self.defense,
             self.special_attack,
@@ -968,20 +1042,9 @@ impl Pokemon {
 
     pub fn deserialize(serialized: &str) -> Pokemon {
         let split: Vec<&str> = serialized.split(",").collect();
-        let evs = if split[12] != "" {
-            let mut ev_iter = split[12].split(";");
-            (
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-            )
-        } else {
-            (85, 85, 85, 85, 85, 85)
-        };
-        Pokemon {
+        let evs = deserialize_stat_spread(split[12], 85);
+        let ivs = deserialize_stat_spread(split[13], 31);
+        let mut pkmn = Pokemon {
             id: PokemonName::from_str(split[0]).unwrap(),
             level: split[1].parse::<i8>().unwrap(),
             types: (
@@ -999,27 +1062,100 @@ impl Pokemon {
             item: Items::from_str(split[10]).unwrap(),
             nature: PokemonNature::from_str(split[11]).unwrap(),
             evs,
-            attack: split[13].parse::<i16>().unwrap(),
-            defense: split[14].parse::<i16>().unwrap(),
-            special_attack: split[15].parse::<i16>().unwrap(),
-            special_defense: split[16].parse::<i16>().unwrap(),
-            speed: split[17].parse::<i16>().unwrap(),
-            status: PokemonStatus::from_str(split[18]).unwrap(),
-            rest_turns: split[19].parse::<i8>().unwrap(),
-            sleep_turns: split[20].parse::<i8>().unwrap(),
-            weight_kg: split[21].parse::<f32>().unwrap(),
+            ivs,
+            attack: split[14].parse::<i16>().unwrap(),
+            defense: split[15].parse::<i16>().unwrap(),
+            special_attack: split[16].parse::<i16>().unwrap(),
+            special_defense: split[17].parse::<i16>().unwrap(),
+            speed: split[18].parse::<i16>().unwrap(),
+            status: PokemonStatus::from_str(split[19]).unwrap(),
+            rest_turns: split[20].parse::<i8>().unwrap(),
+            sleep_turns: split[21].parse::<i8>().unwrap(),
+            weight_kg: split[22].parse::<f32>().unwrap(),
             moves: PokemonMoves {
-                m0: Move::deserialize(split[22]),
-                m1: Move::deserialize(split[23]),
-                m2: Move::deserialize(split[24]),
-                m3: Move::deserialize(split[25]),
+                m0: Move::deserialize(split[23]),
+                m1: Move::deserialize(split[24]),
+                m2: Move::deserialize(split[25]),
+                m3: Move::deserialize(split[26]),
             },
-            terastallized: split[26].parse::<bool>().unwrap(),
-            tera_type: PokemonType::from_str(split[27]).unwrap(),
-        }
+            terastallized: split[27].parse::<bool>().unwrap(),
+            tera_type: PokemonType::from_str(split[28]).unwrap(),
+        };
+        pkmn.refresh_hidden_power();
+        pkmn
     }
 }
 
+pub(crate) const HIDDEN_POWER_TYPES: [PokemonType; 16] = [
+    PokemonType::FIGHTING,
+    PokemonType::FLYING,
+    PokemonType::POISON,
+    PokemonType::GROUND,
+    PokemonType::ROCK,
+    PokemonType::BUG,
+    PokemonType::GHOST,
+    PokemonType::STEEL,
+    PokemonType::FIRE,
+    PokemonType::WATER,
+    PokemonType::GRASS,
+    PokemonType::ELECTRIC,
+    PokemonType::PSYCHIC,
+    PokemonType::ICE,
+    PokemonType::DRAGON,
+    PokemonType::DARK,
+];
+
+// Gen2 uses DVs (0-15), which are represented as IVs the same way Showdown does: DV = IV / 2
+#[cfg(feature = "gen2")]
+pub fn hidden_power_from_ivs(ivs: (u8, u8, u8, u8, u8, u8)) -> (PokemonType, f32) {
+    let attack = ivs.1 / 2;
+    let defense = ivs.2 / 2;
+    let special = ivs.3 / 2;
+    let speed = ivs.5 / 2;
+    let type_index = 4 * (attack % 4) + defense % 4;
+    let high_bits = (special >> 3) + 2 * (speed >> 3) + 4 * (defense >> 3) + 8 * (attack >> 3);
+    let base_power = (5 * high_bits as u16 + (special % 4) as u16) / 2 + 31;
+    (HIDDEN_POWER_TYPES[type_index as usize], base_power as f32)
+}
+
+// Gen3 onwards uses the lowest bit of each IV for the type
+// and, before gen6, the second lowest bit of each IV for the base power
+#[cfg(not(feature = "gen2"))]
+pub fn hidden_power_from_ivs(ivs: (u8, u8, u8, u8, u8, u8)) -> (PokemonType, f32) {
+    // Showdown's order: hp, attack, defense, speed, special attack, special defense
+    let ivs = [ivs.0, ivs.1, ivs.2, ivs.5, ivs.3, ivs.4];
+    let bits = |shift: u8| -> u16 {
+        ivs.iter()
+            .enumerate()
+            .map(|(i, iv)| (((iv >> shift) & 1) as u16) << i)
+            .sum()
+    };
+    let type_index = bits(0) * 15 / 63;
+
+    #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
+    let base_power = (bits(1) * 40 / 63 + 30) as f32;
+    #[cfg(not(any(feature = "gen3", feature = "gen4", feature = "gen5")))]
+    let base_power = 60.0;
+
+    (HIDDEN_POWER_TYPES[type_index as usize], base_power)
+}
+
+// EVs or IVs split by `;`. Blank uses the default for every stat
+fn deserialize_stat_spread(serialized: &str, default: u8) -> (u8, u8, u8, u8, u8, u8) {
+    if serialized == "" {
+        return (default, default, default, default, default, default);
+    }
+    let mut iter = serialized.split(";");
+    (
+        iter.next().unwrap().parse::<u8>().unwrap(),
+        iter.next().unwrap().parse::<u8>().unwrap(),
+        iter.next().unwrap().parse::<u8>().unwrap(),
+        iter.next().unwrap().parse::<u8>().unwrap(),
+        iter.next().unwrap().parse::<u8>().unwrap(),
+        iter.next().unwrap().parse::<u8>().unwrap(),
+    )
+}
+
 #[derive(Debug, Clone)]
 pub struct Side {
     pub active_index: PokemonIndex,
@@ -1045,6 +1181,10 @@ pub struct Side {
     pub last_used_move: LastUsedMove,
     pub damage_dealt: DamageDealt,
     pub switch_out_move_second_saved_move: Choices,
+    // a pokemon on this side has mega evolved. Only one can per battle
+    pub mega_evolved: bool,
+    // a pokemon on this side has used a z-move. Only one can be used per battle
+    pub z_move_used: bool,
 }
 impl Side {
     fn io_conditional_print(&self) -> String {
@@ -1052,6 +1192,12 @@ impl Side {
         if self.baton_passing {
             output.push_str("\n  baton_passing: true");
         }
+        if self.mega_evolved {
+            output.push_str("\n  mega_evolved: true");
+        }
+        if self.z_move_used {
+            output.push_str("\n  z_move_used: true");
+        }
         if self.wish.0 != 0 {
             output.push_str(&format!("\n  wish: ({}, {})", self.wish.0, self.wish.1));
         }
@@ -1113,13 +1259,16 @@ impl Side {
         )
     }
     pub fn serialize(&self) -> String {
-        let mut vs_string = String::new();
-        for vs in &self.volatile_statuses {
-            vs_string.push_str(&vs.to_string());
-            vs_string.push_str(":");
-        }
+        // sorted so that the output does not depend on the HashSet's iteration order
+        let mut volatile_statuses = self
+            .volatile_statuses
+            .iter()
+            .map(|vs| vs.to_string())
+            .collect::<Vec<String>>();
+        volatile_statuses.sort();
+        let vs_string = volatile_statuses.join(":");
         format!(
-            "{}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}",
+            "{}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}={}",
             self.pokemon.p0.serialize(),
             self.pokemon.p1.serialize(),
             self.pokemon.p2.serialize(),
@@ -1149,16 +1298,16 @@ impl Side {
             self.force_trapped,
             self.last_used_move.serialize(),
             self.slow_uturn_move,
+            self.mega_evolved,
+            self.z_move_used,
         )
     }
     pub fn deserialize(serialized: &str) -> Side {
         let split: Vec<&str> = serialized.split("=").collect();
 
         let mut vs_hashset = HashSet::new();
-        if split[8] != "" {
-            for item in split[8].split(":") {
-                vs_hashset.insert(PokemonVolatileStatus::from_str(item).unwrap());
-            }
+        for item in split[8].split(":").filter(|item| !item.is_empty()) {
+            vs_hashset.insert(PokemonVolatileStatus::from_str(item).unwrap());
         }
         Side {
             pokemon: SidePokemon {
@@ -1197,10 +1346,30 @@ impl Side {
             last_used_move: LastUsedMove::deserialize(split[27]),
             damage_dealt: DamageDealt::default(),
             slow_uturn_move: split[28].parse::<bool>().unwrap(),
+            mega_evolved: split[29].parse::<bool>().unwrap(),
+            z_move_used: split[30].parse::<bool>().unwrap(),
         }
     }
 }
 impl Side {
+    /// Resets values that have no effect on the battle to their defaults
+    /// so that equivalent sides serialize identically
+    pub fn normalize(&mut self) {
+        self.volatile_statuses.remove(&PokemonVolatileStatus::NONE);
+        if !self
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::SUBSTITUTE)
+        {
+            self.substitute_health = 0;
+        }
+        if self.wish.0 == 0 {
+            self.wish.1 = 0;
+        }
+        if self.future_sight.0 == 0 {
+            self.future_sight.1 = PokemonIndex::P0;
+        }
+    }
+
     pub fn visible_alive_pkmn(&self) -> i8 {
         let mut count = 0;
         for p in self.pokemon.into_iter() {
@@ -1289,6 +1458,7 @@ pub struct State {
     pub team_preview: bool,
     pub use_last_used_move: bool,
     pub use_damage_dealt: bool,
+    pub zobrist_hash: u64,
 }
 impl Default for State {
     fn default() -> State {
@@ -1310,10 +1480,12 @@ impl Default for State {
             team_preview: false,
             use_damage_dealt: false,
             use_last_used_move: false,
+            zobrist_hash: 0,
         };
 
         // many tests rely on the speed of side 2's active pokemon being greater than side_one's
         s.side_two.get_active().speed += 1;
+        s.refresh_zobrist_hash();
         s
     }
 }
@@ -1754,6 +1926,12 @@ impl State {
     }
 
     pub fn apply_one_instruction(&mut self, instruction: &Instruction) {
+        self.update_zobrist_hash_with(instruction, |state| {
+            state.apply_instruction_to_fields(instruction)
+        });
+    }
+
+    fn apply_instruction_to_fields(&mut self, instruction: &Instruction) {
         match instruction {
             Instruction::Damage(instruction) => {
                 self.damage(&instruction.side_ref, instruction.damage_amount)
@@ -1810,6 +1988,11 @@ impl State {
                 active.ability =
                     Abilities::from(active.ability as i16 + instruction.ability_change);
             }
+            Instruction::ChangeBaseAbility(instruction) => {
+                let active = self.get_side(&instruction.side_ref).get_active();
+                active.base_ability =
+                    Abilities::from(active.base_ability as i16 + instruction.ability_change);
+            }
             Instruction::Heal(instruction) => {
                 self.heal(&instruction.side_ref, instruction.heal_amount)
             }
@@ -1906,6 +2089,12 @@ impl State {
                 SideReference::SideOne => self.side_one.get_active().terastallized ^= true,
                 SideReference::SideTwo => self.side_two.get_active().terastallized ^= true,
             },
+            Instruction::ToggleMegaEvolved(instruction) => {
+                self.get_side(&instruction.side_ref).mega_evolved ^= true
+            }
+            Instruction::ToggleZMoveUsed(instruction) => {
+                self.get_side(&instruction.side_ref).z_move_used ^= true
+            }
             Instruction::SetLastUsedMove(instruction) => {
                 self.set_last_used_move(&instruction.side_ref, instruction.last_used_move)
             }
@@ -1941,6 +2130,12 @@ impl State {
     }
 
     pub fn reverse_one_instruction(&mut self, instruction: &Instruction) {
+        self.update_zobrist_hash_with(instruction, |state| {
+            state.reverse_instruction_to_fields(instruction)
+        });
+    }
+
+    fn reverse_instruction_to_fields(&mut self, instruction: &Instruction) {
         match instruction {
             Instruction::Damage(instruction) => {
                 self.heal(&instruction.side_ref, instruction.damage_amount)
@@ -1999,6 +2194,11 @@ impl State {
                 active.ability =
                     Abilities::from(active.ability as i16 - instruction.ability_change);
             }
+            Instruction::ChangeBaseAbility(instruction) => {
+                let active = self.get_side(&instruction.side_ref).get_active();
+                active.base_ability =
+                    Abilities::from(active.base_ability as i16 - instruction.ability_change);
+            }
             Instruction::EnableMove(instruction) => {
                 self.disable_move(&instruction.side_ref, &instruction.move_index)
             }
@@ -2093,6 +2293,12 @@ impl State {
                 SideReference::SideOne => self.side_one.get_active().terastallized ^= true,
                 SideReference::SideTwo => self.side_two.get_active().terastallized ^= true,
             },
+            Instruction::ToggleMegaEvolved(instruction) => {
+                self.get_side(&instruction.side_ref).mega_evolved ^= true
+            }
+            Instruction::ToggleZMoveUsed(instruction) => {
+                self.get_side(&instruction.side_ref).z_move_used ^= true
+            }
             Instruction::SetLastUsedMove(instruction) => {
                 self.set_last_used_move(&instruction.side_ref, instruction.previous_last_used_move)
             }
@@ -2148,9 +2354,41 @@ impl State {
         )
     }
 
+    /// Resets values that have no effect on the battle to their defaults
+    /// so that equivalent states serialize identically
+    pub fn normalize(&mut self) {
+        self.side_one.normalize();
+        self.side_two.normalize();
+        if self.weather.weather_type == Weather::NONE {
+            self.weather.turns_remaining = -1;
+        }
+        if self.terrain.terrain_type == Terrain::NONE {
+            self.terrain.turns_remaining = 0;
+        }
+        if !self.trick_room.active {
+            self.trick_room.turns_remaining = 0;
+        }
+        self.refresh_zobrist_hash();
+    }
+
+    /// Compares two states after normalizing them, e.g. states
+    /// deserialized from strings produced by different sources
+    pub fn canonical_eq(&self, other: &State) -> bool {
+        self.canonical_serialize() == other.canonical_serialize()
+    }
+
+    /// Serializes a normalized copy of the state, so that equivalent states
+    /// produce the same string
+    pub fn canonical_serialize(&self) -> String {
+        let mut state = self.clone();
+        state.normalize();
+        state.serialize()
+    }
+
     pub fn serialize(&self) -> String {
         format!(
-            "{}/{}/{}/{}/{}/{}",
+            "v{}/{}/{}/{}/{}/{}/{}",
+            SERIALIZED_STATE_VERSION,
             self.side_one.serialize(),
             self.side_two.serialize(),
             self.weather.serialize(),
@@ -2166,10 +2404,13 @@ impl State {
     /// This doctest does its best to show the format of the serialized state.
     ///
     /// Roughly, the format for a state is:
-    ///     side1/side2/weather/terrain/trick_room/team_preview
+    ///     version/side1/side2/weather/terrain/trick_room/team_preview
+    ///
+    /// The leading version (i.e. `v7`) is optional. Strings without it, or with an older version,
+    /// are upgraded to the current layout before being parsed. See `SERIALIZED_STATE_VERSION`.
     ///
     /// Where the format for a side is:
-    ///     p0=p1=p2=p3=p4=p5=active_index=side_conditions=wish0=wish1=force_switch=switch_out_move_second_saved_move=baton_passing=shed_tailing=force_trapped=last_used_move=slow_uturn_move
+    ///     p0=p1=p2=p3=p4=p5=active_index=side_conditions=wish0=wish1=force_switch=switch_out_move_second_saved_move=baton_passing=shed_tailing=force_trapped=last_used_move=slow_uturn_move=mega_evolved=z_move_used
     ///
     /// And the format for a pokemon is:
     ///    id,level,type1,type2,hp,maxhp,ability,item,attack,defense,special_attack,special_defense,speed,attack_boost,defense_boost,special_attack_boost,special_defense_boost,speed_boost,accuracy_boost,evasion_boost,status,substitute_health,rest_turns,weight_kg,volatile_statuses,m0,m1,m2,m3
@@ -2188,6 +2429,9 @@ impl State {
     ///
     /// let serialized_state = concat!(
     ///
+    /// // version of the serialization layout
+    /// "v7/",
+    ///
     /// // SIDE 1
     ///
     /// // POKEMON 1
@@ -2230,6 +2474,9 @@ impl State {
     /// "252;0;252;0;4;0,",
     /// // ",", left blank for default EVs
     ///
+    /// // IVs split by `;`. Leave blank for default IVs (31 in all)
+    /// "31;31;31;31;31;31,",
+    ///
     /// // attack,defense,special attack,special defense,speed
     /// // note these are final stats, not base stats
     /// "121,148,353,206,365,",
@@ -2256,11 +2503,11 @@ impl State {
     /// "Normal=",
     ///
     /// // all remaining Pokémon shown in 1 line for brevity
-    /// "skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=",
-    /// "tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=",
-    /// "mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=",
-    /// "jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=",
-    /// "excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=",
+    /// "skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=",
+    /// "tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=",
+    /// "mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=",
+    /// "jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=",
+    /// "excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=",
     ///
     /// // active-index. This is the index of the active Pokémon in the side's Pokémon array
     /// "0=",
@@ -2309,10 +2556,16 @@ impl State {
     ///
     /// // a boolean representing if the side is slow uturning.
     /// // This is only ever set externally. It is used to know if the opposing side has a stored move to use after uturn.
+    /// "false=",
+    ///
+    /// // a boolean representing if a pokemon on the side has mega evolved
+    /// "false=",
+    ///
+    /// // a boolean representing if a pokemon on the side has used a z-move
     /// "false/",
     ///
     /// // SIDE 2, all in one line for brevity
-    /// "terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/",
+    /// "terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false=false=false/",
     ///
     /// // weather is a string representing the weather type and the number of turns remaining
     /// "none;5/",
@@ -2345,7 +2598,8 @@ impl State {
     ///
     /// ```
     pub fn deserialize(serialized: &str) -> State {
-        let split: Vec<&str> = serialized.split("/").collect();
+        let upgraded = upgrade_serialized_state(serialized);
+        let split: Vec<&str> = upgraded.split("/").collect();
         let mut state = State {
             side_one: Side::deserialize(split[0]),
             side_two: Side::deserialize(split[1]),
@@ -2355,8 +2609,110 @@ impl State {
             team_preview: split[5].parse::<bool>().unwrap(),
             use_damage_dealt: false,
             use_last_used_move: false,
+            zobrist_hash: 0,
         };
         state.set_conditional_mechanics();
+        state.refresh_zobrist_hash();
         state
     }
 }
+
+/// Version of the layout produced by `State::serialize`
+///
+/// Older layouts can still be given to `State::deserialize`:
+///     1: pokemon without nature, evs or base_types, sides without shed_tailing or slow_uturn_move
+///     2: pokemon have nature & evs after the item
+///     3: pokemon have base_types after the types, sides have shed_tailing after baton_passing
+///     4: sides have slow_uturn_move at the end, states are prefixed with the version
+///     5: pokemon have ivs after the evs
+///     6: sides have mega_evolved at the end
+///     7: sides have z_move_used at the end
+pub const SERIALIZED_STATE_VERSION: u8 = 7;
+
+fn serialized_state_version(serialized: &str) -> (u8, &str) {
+    if let Some((prefix, rest)) = serialized.split_once("/") {
+        // the version is `v` followed only by digits. Un-versioned strings start with the first
+        // pokemon of side one, which can also start with `v` (e.g. venusaur)
+        if let Some(version) = prefix
+            .strip_prefix("v")
+            .filter(|v| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+        {
+            match version.parse::<u8>() {
+                Ok(v) if v > 0 && v <= SERIALIZED_STATE_VERSION => return (v, rest),
+                _ => panic!("Invalid serialized state version: {}", prefix),
+            }
+        }
+    }
+
+    // Un-versioned strings pre-date the version prefix
+    // The layout they use can be inferred from the number of fields
+    let side_one = serialized.split("/").next().unwrap();
+    let num_side_fields = side_one.split("=").count();
+    let num_pokemon_fields = side_one.split("=").next().unwrap().split(",").count();
+    let version = match (num_pokemon_fields, num_side_fields) {
+        (24, 27) => 1,
+        (26, 27) => 2,
+        (28, 28) => 3,
+        (28, 29) => 4,
+        (29, 29) => 5,
+        (29, 30) => 6,
+        (29, 31) => 7,
+        _ => panic!(
+            "Unrecognized serialized state layout: {} pokemon fields, {} side fields",
+            num_pokemon_fields, num_side_fields
+        ),
+    };
+    (version, serialized)
+}
+
+fn upgrade_serialized_pokemon(serialized: &str, version: u8) -> String {
+    let mut split: Vec<&str> = serialized.split(",").collect();
+    if version < 2 {
+        // nature & evs were added after the item. Blank evs are the defaults
+        split.insert(9, "SERIOUS");
+        split.insert(10, "");
+    }
+    if version < 3 {
+        // base_types were added after the types, they start as the current types
+        split.insert(4, split[2]);
+        split.insert(5, split[3]);
+    }
+    if version < 5 {
+        // ivs were added after the evs. Blank ivs are the defaults
+        split.insert(13, "");
+    }
+    split.join(",")
+}
+
+fn upgrade_serialized_side(serialized: &str, version: u8) -> String {
+    let mut split: Vec<String> = serialized.split("=").map(|s| s.to_string()).collect();
+    for pkmn in split.iter_mut().take(6) {
+        *pkmn = upgrade_serialized_pokemon(pkmn, version);
+    }
+    if version < 3 {
+        split.insert(25, "false".to_string());
+    }
+    if version < 4 {
+        split.push("false".to_string());
+    }
+    if version < 6 {
+        split.push("false".to_string());
+    }
+    if version < 7 {
+        split.push("false".to_string());
+    }
+    split.join("=")
+}
+
+/// Upgrades a serialized state of any known version to the current layout,
+/// stripping the version prefix
+fn upgrade_serialized_state(serialized: &str) -> String {
+    let (version, body) = serialized_state_version(serialized);
+    if version == SERIALIZED_STATE_VERSION {
+        return body.to_string();
+    }
+    let mut split: Vec<String> = body.split("/").map(|s| s.to_string()).collect();
+    split[0] = upgrade_serialized_side(&split[0], version);
+    split[1] = upgrade_serialized_side(&split[1], version);
+    split.join("/")
+}
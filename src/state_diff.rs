@@ -0,0 +1,752 @@
+use crate::engine::state::PokemonVolatileStatus;
+use crate::instruction::{
+    ApplyVolatileStatusInstruction, BoostInstruction, ChangeAbilityInstruction,
+    ChangeDamageDealtDamageInstruction, ChangeDamageDealtMoveCategoryInstruction,
+    ChangeItemInstruction, ChangeSideConditionInstruction, ChangeStatInstruction,
+    ChangeStatusInstruction, ChangeSubsituteHealthInstruction, ChangeTerrain, ChangeType,
+    ChangeVolatileStatusDurationInstruction, ChangeWeather, ChangeWishInstruction,
+    DamageInstruction, DecrementFutureSightInstruction, DecrementPPInstruction,
+    DecrementWishInstruction, DisableMoveInstruction, EnableMoveInstruction,
+    FormeChangeInstruction, HealInstruction, Instruction, RemoveVolatileStatusInstruction,
+    SetFutureSightInstruction, SetLastUsedMoveInstruction, SetSecondMoveSwitchOutMoveInstruction,
+    SetSleepTurnsInstruction, SwitchInstruction, ToggleBatonPassingInstruction,
+    ToggleDamageDealtHitSubstituteInstruction, ToggleMegaEvolvedInstruction,
+    ToggleShedTailingInstruction, ToggleTerastallizedInstruction, ToggleTrickRoomInstruction,
+    ToggleZMoveUsedInstruction,
+};
+use crate::state::{
+    pokemon_index_iter, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, Side, SideReference, State,
+};
+use std::fmt::Debug;
+
+const BOOSTABLE_STATS: [PokemonBoostableStat; 7] = [
+    PokemonBoostableStat::Attack,
+    PokemonBoostableStat::Defense,
+    PokemonBoostableStat::SpecialAttack,
+    PokemonBoostableStat::SpecialDefense,
+    PokemonBoostableStat::Speed,
+    PokemonBoostableStat::Accuracy,
+    PokemonBoostableStat::Evasion,
+];
+
+const SIDE_CONDITIONS: [PokemonSideCondition; 19] = [
+    PokemonSideCondition::AuroraVeil,
+    PokemonSideCondition::CraftyShield,
+    PokemonSideCondition::HealingWish,
+    PokemonSideCondition::LightScreen,
+    PokemonSideCondition::LuckyChant,
+    PokemonSideCondition::LunarDance,
+    PokemonSideCondition::MatBlock,
+    PokemonSideCondition::Mist,
+    PokemonSideCondition::Protect,
+    PokemonSideCondition::QuickGuard,
+    PokemonSideCondition::Reflect,
+    PokemonSideCondition::Safeguard,
+    PokemonSideCondition::Spikes,
+    PokemonSideCondition::Stealthrock,
+    PokemonSideCondition::StickyWeb,
+    PokemonSideCondition::Tailwind,
+    PokemonSideCondition::ToxicCount,
+    PokemonSideCondition::ToxicSpikes,
+    PokemonSideCondition::WideGuard,
+];
+
+const MOVE_INDICES: [PokemonMoveIndex; 4] = [
+    PokemonMoveIndex::M0,
+    PokemonMoveIndex::M1,
+    PokemonMoveIndex::M2,
+    PokemonMoveIndex::M3,
+];
+
+fn boost(side: &Side, stat: &PokemonBoostableStat) -> i8 {
+    match stat {
+        PokemonBoostableStat::Attack => side.attack_boost,
+        PokemonBoostableStat::Defense => side.defense_boost,
+        PokemonBoostableStat::SpecialAttack => side.special_attack_boost,
+        PokemonBoostableStat::SpecialDefense => side.special_defense_boost,
+        PokemonBoostableStat::Speed => side.speed_boost,
+        PokemonBoostableStat::Accuracy => side.accuracy_boost,
+        PokemonBoostableStat::Evasion => side.evasion_boost,
+    }
+}
+
+fn volatile_status_durations(side: &Side) -> [(PokemonVolatileStatus, i8); 6] {
+    let durations = &side.volatile_status_durations;
+    [
+        (PokemonVolatileStatus::CONFUSION, durations.confusion),
+        (PokemonVolatileStatus::ENCORE, durations.encore),
+        (PokemonVolatileStatus::LOCKEDMOVE, durations.lockedmove),
+        (PokemonVolatileStatus::SLOWSTART, durations.slowstart),
+        (PokemonVolatileStatus::TAUNT, durations.taunt),
+        (PokemonVolatileStatus::YAWN, durations.yawn),
+    ]
+}
+
+fn sorted_volatile_status_difference(from: &Side, to: &Side) -> Vec<PokemonVolatileStatus> {
+    let mut difference: Vec<PokemonVolatileStatus> = from
+        .volatile_statuses
+        .difference(&to.volatile_statuses)
+        .cloned()
+        .collect();
+    difference.sort_by_key(|vs| *vs as u8);
+    difference
+}
+
+// Instructions for the changes that can only be made to the active pokemon
+fn active_pokemon_instructions(
+    side_ref: SideReference,
+    from: &Pokemon,
+    to: &Pokemon,
+) -> Vec<Instruction> {
+    let mut instructions = vec![];
+    if from.id != to.id {
+        instructions.push(Instruction::FormeChange(FormeChangeInstruction {
+            side_ref,
+            name_change: to.id as i16 - from.id as i16,
+        }));
+    }
+    if from.hp > to.hp {
+        instructions.push(Instruction::Damage(DamageInstruction {
+            side_ref,
+            damage_amount: from.hp - to.hp,
+        }));
+    } else if from.hp < to.hp {
+        instructions.push(Instruction::Heal(HealInstruction {
+            side_ref,
+            heal_amount: to.hp - from.hp,
+        }));
+    }
+    if from.types != to.types {
+        instructions.push(Instruction::ChangeType(ChangeType {
+            side_ref,
+            new_types: to.types,
+            old_types: from.types,
+        }));
+    }
+    if from.ability != to.ability {
+        instructions.push(Instruction::ChangeAbility(ChangeAbilityInstruction {
+            side_ref,
+            ability_change: to.ability as i16 - from.ability as i16,
+        }));
+    }
+    if from.base_ability != to.base_ability {
+        instructions.push(Instruction::ChangeBaseAbility(ChangeAbilityInstruction {
+            side_ref,
+            ability_change: to.base_ability as i16 - from.base_ability as i16,
+        }));
+    }
+    if from.item != to.item {
+        instructions.push(Instruction::ChangeItem(ChangeItemInstruction {
+            side_ref,
+            current_item: from.item,
+            new_item: to.item,
+        }));
+    }
+    for (from_stat, to_stat, instruction) in [
+        (
+            from.attack,
+            to.attack,
+            Instruction::ChangeAttack as fn(_) -> _,
+        ),
+        (from.defense, to.defense, Instruction::ChangeDefense),
+        (
+            from.special_attack,
+            to.special_attack,
+            Instruction::ChangeSpecialAttack,
+        ),
+        (
+            from.special_defense,
+            to.special_defense,
+            Instruction::ChangeSpecialDefense,
+        ),
+        (from.speed, to.speed, Instruction::ChangeSpeed),
+    ] {
+        if from_stat != to_stat {
+            instructions.push(instruction(ChangeStatInstruction {
+                side_ref,
+                amount: to_stat - from_stat,
+            }));
+        }
+    }
+    for move_index in MOVE_INDICES {
+        let from_move = &from.moves[&move_index];
+        let to_move = &to.moves[&move_index];
+        if !from_move.disabled && to_move.disabled {
+            instructions.push(Instruction::DisableMove(DisableMoveInstruction {
+                side_ref,
+                move_index,
+            }));
+        } else if from_move.disabled && !to_move.disabled {
+            instructions.push(Instruction::EnableMove(EnableMoveInstruction {
+                side_ref,
+                move_index,
+            }));
+        }
+        if from_move.pp != to_move.pp {
+            instructions.push(Instruction::DecrementPP(DecrementPPInstruction {
+                side_ref,
+                move_index,
+                amount: from_move.pp - to_move.pp,
+            }));
+        }
+    }
+    if from.terastallized != to.terastallized {
+        instructions.push(Instruction::ToggleTerastallized(
+            ToggleTerastallizedInstruction { side_ref },
+        ));
+    }
+    instructions
+}
+
+fn pokemon_instructions(
+    side_ref: SideReference,
+    pokemon_index: PokemonIndex,
+    from: &Pokemon,
+    to: &Pokemon,
+) -> Vec<Instruction> {
+    let mut instructions = vec![];
+    if from.status != to.status {
+        instructions.push(Instruction::ChangeStatus(ChangeStatusInstruction {
+            side_ref,
+            pokemon_index,
+            old_status: from.status,
+            new_status: to.status,
+        }));
+    }
+    if from.rest_turns != to.rest_turns {
+        instructions.push(Instruction::SetRestTurns(SetSleepTurnsInstruction {
+            side_ref,
+            pokemon_index,
+            new_turns: to.rest_turns,
+            previous_turns: from.rest_turns,
+        }));
+    }
+    if from.sleep_turns != to.sleep_turns {
+        instructions.push(Instruction::SetSleepTurns(SetSleepTurnsInstruction {
+            side_ref,
+            pokemon_index,
+            new_turns: to.sleep_turns,
+            previous_turns: from.sleep_turns,
+        }));
+    }
+    instructions
+}
+
+fn side_instructions(side_ref: SideReference, from: &Side, to: &Side) -> Vec<Instruction> {
+    let mut instructions = vec![];
+
+    // changes to a reserve pokemon that only have instructions for the active pokemon
+    // are made by switching the reserve pokemon in and back out again
+    for pokemon_index in pokemon_index_iter() {
+        let from_pkmn = &from.pokemon[pokemon_index];
+        let to_pkmn = &to.pokemon[pokemon_index];
+        let active_instructions = active_pokemon_instructions(side_ref, from_pkmn, to_pkmn);
+        if !active_instructions.is_empty() {
+            if pokemon_index == from.active_index {
+                instructions.extend(active_instructions);
+            } else {
+                instructions.push(Instruction::Switch(SwitchInstruction {
+                    side_ref,
+                    previous_index: from.active_index,
+                    next_index: pokemon_index,
+                }));
+                instructions.extend(active_instructions);
+                instructions.push(Instruction::Switch(SwitchInstruction {
+                    side_ref,
+                    previous_index: pokemon_index,
+                    next_index: from.active_index,
+                }));
+            }
+        }
+        instructions.extend(pokemon_instructions(
+            side_ref,
+            pokemon_index,
+            from_pkmn,
+            to_pkmn,
+        ));
+    }
+    if from.active_index != to.active_index {
+        instructions.push(Instruction::Switch(SwitchInstruction {
+            side_ref,
+            previous_index: from.active_index,
+            next_index: to.active_index,
+        }));
+    }
+
+    for volatile_status in sorted_volatile_status_difference(from, to) {
+        instructions.push(Instruction::RemoveVolatileStatus(
+            RemoveVolatileStatusInstruction {
+                side_ref,
+                volatile_status,
+            },
+        ));
+    }
+    for volatile_status in sorted_volatile_status_difference(to, from) {
+        instructions.push(Instruction::ApplyVolatileStatus(
+            ApplyVolatileStatusInstruction {
+                side_ref,
+                volatile_status,
+            },
+        ));
+    }
+    for (&(volatile_status, from_duration), &(_, to_duration)) in volatile_status_durations(from)
+        .iter()
+        .zip(volatile_status_durations(to).iter())
+    {
+        if from_duration != to_duration {
+            instructions.push(Instruction::ChangeVolatileStatusDuration(
+                ChangeVolatileStatusDurationInstruction {
+                    side_ref,
+                    volatile_status,
+                    amount: to_duration - from_duration,
+                },
+            ));
+        }
+    }
+    for stat in BOOSTABLE_STATS {
+        let amount = boost(to, &stat) - boost(from, &stat);
+        if amount != 0 {
+            instructions.push(Instruction::Boost(BoostInstruction {
+                side_ref,
+                stat,
+                amount,
+            }));
+        }
+    }
+    for side_condition in SIDE_CONDITIONS {
+        let amount =
+            to.get_side_condition(side_condition) - from.get_side_condition(side_condition);
+        if amount != 0 {
+            instructions.push(Instruction::ChangeSideCondition(
+                ChangeSideConditionInstruction {
+                    side_ref,
+                    side_condition,
+                    amount,
+                },
+            ));
+        }
+    }
+    if from.substitute_health != to.substitute_health {
+        instructions.push(Instruction::ChangeSubstituteHealth(
+            ChangeSubsituteHealthInstruction {
+                side_ref,
+                health_change: to.substitute_health - from.substitute_health,
+            },
+        ));
+    }
+
+    // ChangeWish always sets the wish counter to 2, so higher counters cannot be reached
+    if from.wish != to.wish && to.wish.0 <= 2 {
+        instructions.push(Instruction::ChangeWish(ChangeWishInstruction {
+            side_ref,
+            wish_amount_change: to.wish.1 - from.wish.1,
+        }));
+        for _ in to.wish.0..2 {
+            instructions.push(Instruction::DecrementWish(DecrementWishInstruction {
+                side_ref,
+            }));
+        }
+    }
+    // SetFutureSight always sets the future sight counter to 3
+    if from.future_sight != to.future_sight && to.future_sight.0 <= 3 {
+        instructions.push(Instruction::SetFutureSight(SetFutureSightInstruction {
+            side_ref,
+            pokemon_index: to.future_sight.1,
+            previous_pokemon_index: from.future_sight.1,
+        }));
+        for _ in to.future_sight.0..3 {
+            instructions.push(Instruction::DecrementFutureSight(
+                DecrementFutureSightInstruction { side_ref },
+            ));
+        }
+    }
+
+    if from.force_switch != to.force_switch {
+        instructions.push(match side_ref {
+            SideReference::SideOne => Instruction::ToggleSideOneForceSwitch,
+            SideReference::SideTwo => Instruction::ToggleSideTwoForceSwitch,
+        });
+    }
+    if from.baton_passing != to.baton_passing {
+        instructions.push(Instruction::ToggleBatonPassing(
+            ToggleBatonPassingInstruction { side_ref },
+        ));
+    }
+    if from.shed_tailing != to.shed_tailing {
+        instructions.push(Instruction::ToggleShedTailing(
+            ToggleShedTailingInstruction { side_ref },
+        ));
+    }
+    if from.last_used_move != to.last_used_move {
+        instructions.push(Instruction::SetLastUsedMove(SetLastUsedMoveInstruction {
+            side_ref,
+            last_used_move: to.last_used_move,
+            previous_last_used_move: from.last_used_move,
+        }));
+    }
+    if from.damage_dealt.damage != to.damage_dealt.damage {
+        instructions.push(Instruction::ChangeDamageDealtDamage(
+            ChangeDamageDealtDamageInstruction {
+                side_ref,
+                damage_change: to.damage_dealt.damage - from.damage_dealt.damage,
+            },
+        ));
+    }
+    if from.damage_dealt.move_category != to.damage_dealt.move_category {
+        instructions.push(Instruction::ChangeDamageDealtMoveCatagory(
+            ChangeDamageDealtMoveCategoryInstruction {
+                side_ref,
+                move_category: to.damage_dealt.move_category,
+                previous_move_category: from.damage_dealt.move_category,
+            },
+        ));
+    }
+    if from.damage_dealt.hit_substitute != to.damage_dealt.hit_substitute {
+        instructions.push(Instruction::ToggleDamageDealtHitSubstitute(
+            ToggleDamageDealtHitSubstituteInstruction { side_ref },
+        ));
+    }
+    if from.switch_out_move_second_saved_move != to.switch_out_move_second_saved_move {
+        let instruction = SetSecondMoveSwitchOutMoveInstruction {
+            new_choice: to.switch_out_move_second_saved_move,
+            previous_choice: from.switch_out_move_second_saved_move,
+        };
+        instructions.push(match side_ref {
+            SideReference::SideOne => Instruction::SetSideOneMoveSecondSwitchOutMove(instruction),
+            SideReference::SideTwo => Instruction::SetSideTwoMoveSecondSwitchOutMove(instruction),
+        });
+    }
+    if from.mega_evolved != to.mega_evolved {
+        instructions.push(Instruction::ToggleMegaEvolved(
+            ToggleMegaEvolvedInstruction { side_ref },
+        ));
+    }
+    if from.z_move_used != to.z_move_used {
+        instructions.push(Instruction::ToggleZMoveUsed(ToggleZMoveUsedInstruction {
+            side_ref,
+        }));
+    }
+
+    instructions
+}
+
+struct DiffReport {
+    lines: Vec<String>,
+}
+
+impl DiffReport {
+    fn compare<T: PartialEq + Debug>(&mut self, name: &str, from: T, to: T) {
+        if from != to {
+            self.lines.push(format!("{}: {:?} -> {:?}", name, from, to));
+        }
+    }
+
+    // Fields that no instruction can change
+    fn compare_no_instruction<T: PartialEq + Debug>(&mut self, name: &str, from: T, to: T) {
+        if from != to {
+            self.lines
+                .push(format!("{}: {:?} -> {:?} (no instruction)", name, from, to));
+        }
+    }
+
+    fn compare_pokemon(&mut self, name: &str, from: &Pokemon, to: &Pokemon) {
+        let name = format!("{} {}", name, from.id);
+        self.compare(&format!("{} id", name), from.id, to.id);
+        self.compare(&format!("{} hp", name), from.hp, to.hp);
+        self.compare(&format!("{} types", name), from.types, to.types);
+        self.compare(&format!("{} ability", name), from.ability, to.ability);
+        self.compare(&format!("{} item", name), from.item, to.item);
+        self.compare(&format!("{} attack", name), from.attack, to.attack);
+        self.compare(&format!("{} defense", name), from.defense, to.defense);
+        self.compare(
+            &format!("{} special_attack", name),
+            from.special_attack,
+            to.special_attack,
+        );
+        self.compare(
+            &format!("{} special_defense", name),
+            from.special_defense,
+            to.special_defense,
+        );
+        self.compare(&format!("{} speed", name), from.speed, to.speed);
+        self.compare(&format!("{} status", name), from.status, to.status);
+        self.compare(
+            &format!("{} rest_turns", name),
+            from.rest_turns,
+            to.rest_turns,
+        );
+        self.compare(
+            &format!("{} sleep_turns", name),
+            from.sleep_turns,
+            to.sleep_turns,
+        );
+        self.compare(
+            &format!("{} terastallized", name),
+            from.terastallized,
+            to.terastallized,
+        );
+        for move_index in MOVE_INDICES {
+            let from_move = &from.moves[&move_index];
+            let to_move = &to.moves[&move_index];
+            let move_name = format!("{} {:?} {:?}", name, move_index, from_move.id);
+            self.compare_no_instruction(&format!("{} id", move_name), from_move.id, to_move.id);
+            self.compare(
+                &format!("{} disabled", move_name),
+                from_move.disabled,
+                to_move.disabled,
+            );
+            self.compare(&format!("{} pp", move_name), from_move.pp, to_move.pp);
+        }
+        self.compare_no_instruction(&format!("{} level", name), from.level, to.level);
+        self.compare_no_instruction(
+            &format!("{} base_types", name),
+            from.base_types,
+            to.base_types,
+        );
+        self.compare_no_instruction(&format!("{} maxhp", name), from.maxhp, to.maxhp);
+        self.compare(
+            &format!("{} base_ability", name),
+            from.base_ability,
+            to.base_ability,
+        );
+        self.compare_no_instruction(
+            &format!("{} nature", name),
+            from.nature.clone(),
+            to.nature.clone(),
+        );
+        self.compare_no_instruction(&format!("{} evs", name), from.evs, to.evs);
+        self.compare_no_instruction(&format!("{} ivs", name), from.ivs, to.ivs);
+        self.compare_no_instruction(&format!("{} weight_kg", name), from.weight_kg, to.weight_kg);
+        self.compare_no_instruction(&format!("{} tera_type", name), from.tera_type, to.tera_type);
+    }
+
+    fn compare_side(&mut self, name: &str, from: &Side, to: &Side) {
+        for pokemon_index in pokemon_index_iter() {
+            self.compare_pokemon(
+                &format!("{} {:?}", name, pokemon_index),
+                &from.pokemon[pokemon_index],
+                &to.pokemon[pokemon_index],
+            );
+        }
+        self.compare(
+            &format!("{} active_index", name),
+            from.active_index,
+            to.active_index,
+        );
+        self.compare(
+            &format!("{} volatile_statuses removed", name),
+            vec![],
+            sorted_volatile_status_difference(from, to),
+        );
+        self.compare(
+            &format!("{} volatile_statuses added", name),
+            vec![],
+            sorted_volatile_status_difference(to, from),
+        );
+        for (&(volatile_status, from_duration), &(_, to_duration)) in
+            volatile_status_durations(from)
+                .iter()
+                .zip(volatile_status_durations(to).iter())
+        {
+            self.compare(
+                &format!("{} {:?} duration", name, volatile_status),
+                from_duration,
+                to_duration,
+            );
+        }
+        for stat in BOOSTABLE_STATS {
+            self.compare(
+                &format!("{} {:?} boost", name, stat),
+                boost(from, &stat),
+                boost(to, &stat),
+            );
+        }
+        for side_condition in SIDE_CONDITIONS {
+            self.compare(
+                &format!("{} {:?}", name, side_condition),
+                from.get_side_condition(side_condition),
+                to.get_side_condition(side_condition),
+            );
+        }
+        self.compare(
+            &format!("{} substitute_health", name),
+            from.substitute_health,
+            to.substitute_health,
+        );
+        if to.wish.0 <= 2 {
+            self.compare(&format!("{} wish", name), from.wish, to.wish);
+        } else {
+            self.compare_no_instruction(&format!("{} wish", name), from.wish, to.wish);
+        }
+        if to.future_sight.0 <= 3 {
+            self.compare(
+                &format!("{} future_sight", name),
+                from.future_sight,
+                to.future_sight,
+            );
+        } else {
+            self.compare_no_instruction(
+                &format!("{} future_sight", name),
+                from.future_sight,
+                to.future_sight,
+            );
+        }
+        self.compare(
+            &format!("{} force_switch", name),
+            from.force_switch,
+            to.force_switch,
+        );
+        self.compare(
+            &format!("{} baton_passing", name),
+            from.baton_passing,
+            to.baton_passing,
+        );
+        self.compare(
+            &format!("{} shed_tailing", name),
+            from.shed_tailing,
+            to.shed_tailing,
+        );
+        self.compare(
+            &format!("{} last_used_move", name),
+            from.last_used_move,
+            to.last_used_move,
+        );
+        self.compare(
+            &format!("{} damage_dealt damage", name),
+            from.damage_dealt.damage,
+            to.damage_dealt.damage,
+        );
+        self.compare(
+            &format!("{} damage_dealt move_category", name),
+            from.damage_dealt.move_category,
+            to.damage_dealt.move_category,
+        );
+        self.compare(
+            &format!("{} damage_dealt hit_substitute", name),
+            from.damage_dealt.hit_substitute,
+            to.damage_dealt.hit_substitute,
+        );
+        self.compare(
+            &format!("{} switch_out_move_second_saved_move", name),
+            from.switch_out_move_second_saved_move,
+            to.switch_out_move_second_saved_move,
+        );
+        self.compare(
+            &format!("{} mega_evolved", name),
+            from.mega_evolved,
+            to.mega_evolved,
+        );
+        self.compare(
+            &format!("{} z_move_used", name),
+            from.z_move_used,
+            to.z_move_used,
+        );
+        self.compare_no_instruction(
+            &format!("{} force_trapped", name),
+            from.force_trapped,
+            to.force_trapped,
+        );
+        self.compare_no_instruction(
+            &format!("{} slow_uturn_move", name),
+            from.slow_uturn_move,
+            to.slow_uturn_move,
+        );
+    }
+}
+
+impl State {
+    /// Instructions that turn this state into `other` when applied to it
+    ///
+    /// Fields that no instruction can change (e.g. a pokemon's level or evs) are ignored,
+    /// `diff_report` lists those differences as well
+    pub fn diff_instructions(&self, other: &State) -> Vec<Instruction> {
+        let mut instructions =
+            side_instructions(SideReference::SideOne, &self.side_one, &other.side_one);
+        instructions.extend(side_instructions(
+            SideReference::SideTwo,
+            &self.side_two,
+            &other.side_two,
+        ));
+
+        if self.weather != other.weather {
+            instructions.push(Instruction::ChangeWeather(ChangeWeather {
+                new_weather: other.weather.weather_type,
+                new_weather_turns_remaining: other.weather.turns_remaining,
+                previous_weather: self.weather.weather_type,
+                previous_weather_turns_remaining: self.weather.turns_remaining,
+            }));
+        }
+        if self.terrain != other.terrain {
+            instructions.push(Instruction::ChangeTerrain(ChangeTerrain {
+                new_terrain: other.terrain.terrain_type,
+                new_terrain_turns_remaining: other.terrain.turns_remaining,
+                previous_terrain: self.terrain.terrain_type,
+                previous_terrain_turns_remaining: self.terrain.turns_remaining,
+            }));
+        }
+        if self.trick_room.active != other.trick_room.active {
+            instructions.push(Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
+                currently_active: self.trick_room.active,
+                new_trickroom_turns_remaining: other.trick_room.turns_remaining,
+                previous_trickroom_turns_remaining: self.trick_room.turns_remaining,
+            }));
+        } else if self.trick_room.turns_remaining != other.trick_room.turns_remaining {
+            // toggling twice changes only the turns remaining
+            instructions.push(Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
+                currently_active: self.trick_room.active,
+                new_trickroom_turns_remaining: self.trick_room.turns_remaining,
+                previous_trickroom_turns_remaining: self.trick_room.turns_remaining,
+            }));
+            instructions.push(Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
+                currently_active: !self.trick_room.active,
+                new_trickroom_turns_remaining: other.trick_room.turns_remaining,
+                previous_trickroom_turns_remaining: self.trick_room.turns_remaining,
+            }));
+        }
+
+        instructions
+    }
+
+    /// A human-readable list of the differences between this state and `other`, one per line
+    ///
+    /// Differences that `diff_instructions` cannot produce an instruction for are marked with
+    /// "(no instruction)"
+    pub fn diff_report(&self, other: &State) -> String {
+        let mut report = DiffReport { lines: vec![] };
+        report.compare_side("side_one", &self.side_one, &other.side_one);
+        report.compare_side("side_two", &self.side_two, &other.side_two);
+        report.compare(
+            "weather",
+            self.weather.weather_type,
+            other.weather.weather_type,
+        );
+        report.compare(
+            "weather turns_remaining",
+            self.weather.turns_remaining,
+            other.weather.turns_remaining,
+        );
+        report.compare(
+            "terrain",
+            self.terrain.terrain_type,
+            other.terrain.terrain_type,
+        );
+        report.compare(
+            "terrain turns_remaining",
+            self.terrain.turns_remaining,
+            other.terrain.turns_remaining,
+        );
+        report.compare(
+            "trick_room",
+            self.trick_room.active,
+            other.trick_room.active,
+        );
+        report.compare(
+            "trick_room turns_remaining",
+            self.trick_room.turns_remaining,
+            other.trick_room.turns_remaining,
+        );
+        report.compare_no_instruction("team_preview", self.team_preview, other.team_preview);
+        report.lines.join("\n")
+    }
+}
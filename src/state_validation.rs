@@ -0,0 +1,231 @@
+use crate::engine::state::PokemonVolatileStatus;
+use crate::pokemon::PokemonName;
+use crate::state::{
+    pokemon_index_iter, PokemonBoostableStat, PokemonIndex, PokemonSideCondition, Side,
+    SideReference, State,
+};
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum StateValidationSeverity {
+    // the engine may panic or produce nonsense when searching this state
+    Error,
+    // the state is unlikely to come from a real battle
+    Warning,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum StateValidationIssue {
+    HpAboveMaxHp {
+        side_ref: SideReference,
+        pokemon_index: PokemonIndex,
+        hp: i16,
+        maxhp: i16,
+    },
+    NegativeHp {
+        side_ref: SideReference,
+        pokemon_index: PokemonIndex,
+        hp: i16,
+    },
+    BoostOutOfRange {
+        side_ref: SideReference,
+        stat: PokemonBoostableStat,
+        boost: i8,
+    },
+    SubstituteHealthWithoutSubstitute {
+        side_ref: SideReference,
+        substitute_health: i16,
+    },
+    SubstituteWithoutSubstituteHealth {
+        side_ref: SideReference,
+    },
+    SideConditionOutOfRange {
+        side_ref: SideReference,
+        side_condition: PokemonSideCondition,
+        amount: i8,
+    },
+    DuplicateSpecies {
+        side_ref: SideReference,
+        pokemon: PokemonName,
+    },
+}
+
+impl StateValidationIssue {
+    pub fn severity(&self) -> StateValidationSeverity {
+        match self {
+            StateValidationIssue::HpAboveMaxHp { .. }
+            | StateValidationIssue::NegativeHp { .. }
+            | StateValidationIssue::BoostOutOfRange { .. } => StateValidationSeverity::Error,
+            StateValidationIssue::SubstituteHealthWithoutSubstitute { .. }
+            | StateValidationIssue::SubstituteWithoutSubstituteHealth { .. }
+            | StateValidationIssue::SideConditionOutOfRange { .. }
+            | StateValidationIssue::DuplicateSpecies { .. } => StateValidationSeverity::Warning,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity() == StateValidationSeverity::Error
+    }
+}
+
+impl fmt::Display for StateValidationIssue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.severity() {
+            StateValidationSeverity::Error => write!(f, "error: ")?,
+            StateValidationSeverity::Warning => write!(f, "warning: ")?,
+        }
+        match self {
+            StateValidationIssue::HpAboveMaxHp {
+                side_ref,
+                pokemon_index,
+                hp,
+                maxhp,
+            } => write!(
+                f,
+                "{:?} {:?} hp ({}) is greater than maxhp ({})",
+                side_ref, pokemon_index, hp, maxhp
+            ),
+            StateValidationIssue::NegativeHp {
+                side_ref,
+                pokemon_index,
+                hp,
+            } => write!(
+                f,
+                "{:?} {:?} hp ({}) is negative",
+                side_ref, pokemon_index, hp
+            ),
+            StateValidationIssue::BoostOutOfRange {
+                side_ref,
+                stat,
+                boost,
+            } => write!(
+                f,
+                "{:?} {:?} boost ({}) is outside of -6 to 6",
+                side_ref, stat, boost
+            ),
+            StateValidationIssue::SubstituteHealthWithoutSubstitute {
+                side_ref,
+                substitute_health,
+            } => write!(
+                f,
+                "{:?} has substitute_health ({}) without the SUBSTITUTE volatile status",
+                side_ref, substitute_health
+            ),
+            StateValidationIssue::SubstituteWithoutSubstituteHealth { side_ref } => write!(
+                f,
+                "{:?} has the SUBSTITUTE volatile status without substitute_health",
+                side_ref
+            ),
+            StateValidationIssue::SideConditionOutOfRange {
+                side_ref,
+                side_condition,
+                amount,
+            } => write!(
+                f,
+                "{:?} {:?} ({}) is outside of its possible range",
+                side_ref, side_condition, amount
+            ),
+            StateValidationIssue::DuplicateSpecies { side_ref, pokemon } => {
+                write!(f, "{:?} has more than one {}", side_ref, pokemon)
+            }
+        }
+    }
+}
+
+fn validate_side(side_ref: SideReference, side: &Side, issues: &mut Vec<StateValidationIssue>) {
+    let mut species_seen = vec![];
+    for pokemon_index in pokemon_index_iter() {
+        let pkmn = &side.pokemon[pokemon_index];
+        if pkmn.hp > pkmn.maxhp {
+            issues.push(StateValidationIssue::HpAboveMaxHp {
+                side_ref,
+                pokemon_index,
+                hp: pkmn.hp,
+                maxhp: pkmn.maxhp,
+            });
+        }
+        if pkmn.hp < 0 {
+            issues.push(StateValidationIssue::NegativeHp {
+                side_ref,
+                pokemon_index,
+                hp: pkmn.hp,
+            });
+        }
+        if pkmn.id != PokemonName::NONE {
+            if species_seen.contains(&pkmn.id) {
+                issues.push(StateValidationIssue::DuplicateSpecies {
+                    side_ref,
+                    pokemon: pkmn.id,
+                });
+            } else {
+                species_seen.push(pkmn.id);
+            }
+        }
+    }
+
+    for (stat, boost) in [
+        (PokemonBoostableStat::Attack, side.attack_boost),
+        (PokemonBoostableStat::Defense, side.defense_boost),
+        (
+            PokemonBoostableStat::SpecialAttack,
+            side.special_attack_boost,
+        ),
+        (
+            PokemonBoostableStat::SpecialDefense,
+            side.special_defense_boost,
+        ),
+        (PokemonBoostableStat::Speed, side.speed_boost),
+        (PokemonBoostableStat::Accuracy, side.accuracy_boost),
+        (PokemonBoostableStat::Evasion, side.evasion_boost),
+    ] {
+        if !(-6..=6).contains(&boost) {
+            issues.push(StateValidationIssue::BoostOutOfRange {
+                side_ref,
+                stat,
+                boost,
+            });
+        }
+    }
+
+    let has_substitute = side
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::SUBSTITUTE);
+    if !has_substitute && side.substitute_health != 0 {
+        issues.push(StateValidationIssue::SubstituteHealthWithoutSubstitute {
+            side_ref,
+            substitute_health: side.substitute_health,
+        });
+    } else if has_substitute && side.substitute_health <= 0 {
+        issues.push(StateValidationIssue::SubstituteWithoutSubstituteHealth { side_ref });
+    }
+
+    for (side_condition, maximum) in [
+        (PokemonSideCondition::Spikes, 3),
+        (PokemonSideCondition::ToxicSpikes, 2),
+        (PokemonSideCondition::Stealthrock, 1),
+        (PokemonSideCondition::StickyWeb, 1),
+    ] {
+        let amount = side.get_side_condition(side_condition);
+        if !(0..=maximum).contains(&amount) {
+            issues.push(StateValidationIssue::SideConditionOutOfRange {
+                side_ref,
+                side_condition,
+                amount,
+            });
+        }
+    }
+}
+
+impl State {
+    /// Checks the state for values that cannot happen in a battle
+    ///
+    /// Errors are states the engine cannot search correctly,
+    /// warnings are states that are suspicious but usable
+    pub fn validate(&self) -> Vec<StateValidationIssue> {
+        let mut issues = vec![];
+        validate_side(SideReference::SideOne, &self.side_one, &mut issues);
+        validate_side(SideReference::SideTwo, &self.side_two, &mut issues);
+        issues
+    }
+}
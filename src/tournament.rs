@@ -0,0 +1,311 @@
+use crate::agent::agent_from_str;
+use crate::simulator::simulate_battle_from_str;
+use crate::state::{Side, SideReference, State};
+use serde_json::{json, Value};
+
+/// One game of a tournament. Agents and teams are indices into the tournament's lists
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameResult {
+    pub side_one_agent: usize,
+    pub side_two_agent: usize,
+    pub side_one_team: usize,
+    pub side_two_team: usize,
+    // None when the turn limit was reached first
+    pub winner: Option<SideReference>,
+    pub turns: u16,
+}
+
+/// Every game between two agents, from the first agent's perspective
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matchup {
+    pub agents: (usize, usize),
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl Matchup {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    // draws count as half a win
+    pub fn score(&self) -> f32 {
+        self.wins as f32 + self.draws as f32 / 2.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub agent: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub win_rate: f32,
+    // 95% confidence interval of the win rate
+    pub confidence_interval: (f32, f32),
+    pub elo: f32,
+}
+
+impl Standing {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "agent": self.agent,
+            "games": self.games(),
+            "wins": self.wins,
+            "losses": self.losses,
+            "draws": self.draws,
+            "win_rate": self.win_rate,
+            "confidence_interval": [self.confidence_interval.0, self.confidence_interval.1],
+            "elo": self.elo,
+        })
+    }
+}
+
+impl std::fmt::Display for Standing {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let confidence_interval = format!(
+            "[{:.1}%, {:.1}%]",
+            self.confidence_interval.0 * 100.0,
+            self.confidence_interval.1 * 100.0
+        );
+        write!(
+            f,
+            "{: <24}{: >8}{: >8}{: >8}{: >8}{: >10.1}%  {: <16}{: >8.0}",
+            self.agent,
+            self.games(),
+            self.wins,
+            self.losses,
+            self.draws,
+            self.win_rate * 100.0,
+            confidence_interval,
+            self.elo
+        )
+    }
+}
+
+pub struct TournamentResult {
+    pub agents: Vec<String>,
+    pub games: Vec<GameResult>,
+    pub matchups: Vec<Matchup>,
+    // sorted by elo, best first
+    pub standings: Vec<Standing>,
+}
+
+impl TournamentResult {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "standings": self.standings.iter().map(|s| s.to_json()).collect::<Vec<Value>>(),
+            "matchups": self.matchups.iter().map(|m| json!({
+                "agents": [self.agents[m.agents.0], self.agents[m.agents.1]],
+                "wins": m.wins,
+                "losses": m.losses,
+                "draws": m.draws,
+            })).collect::<Vec<Value>>(),
+        })
+    }
+}
+
+/// The 95% Wilson score interval of a win rate over `games` games
+pub fn wilson_interval(win_rate: f32, games: u32) -> (f32, f32) {
+    if games == 0 {
+        return (0.0, 1.0);
+    }
+    let z = 1.96;
+    let n = games as f32;
+    let denominator = 1.0 + z * z / n;
+    let center = (win_rate + z * z / (2.0 * n)) / denominator;
+    let margin = z * (win_rate * (1.0 - win_rate) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+/// Elo ratings that best explain the results of every matchup (a Bradley-Terry model),
+/// scaled so that the average rating is 1500
+///
+/// Every matchup counts one extra draw so that an agent that won every game does not
+/// get an infinite rating
+pub fn elo_ratings(agent_count: usize, matchups: &[Matchup]) -> Vec<f32> {
+    let mut strengths = vec![1.0_f64; agent_count];
+    for _ in 0..1000 {
+        let mut next = strengths.clone();
+        for agent in 0..agent_count {
+            let mut score = 0.0;
+            let mut denominator = 0.0;
+            for m in matchups.iter() {
+                let (games, agent_score, opponent) = if m.agents.0 == agent {
+                    (m.games() as f64 + 1.0, m.score() as f64 + 0.5, m.agents.1)
+                } else if m.agents.1 == agent {
+                    let games = m.games() as f64 + 1.0;
+                    (games, games - m.score() as f64 - 0.5, m.agents.0)
+                } else {
+                    continue;
+                };
+                score += agent_score;
+                denominator += games / (strengths[agent] + strengths[opponent]);
+            }
+            if denominator > 0.0 {
+                next[agent] = score / denominator;
+            }
+        }
+        strengths = next;
+    }
+
+    let ratings: Vec<f64> = strengths.iter().map(|s| 400.0 * s.log10()).collect();
+    let average = ratings.iter().sum::<f64>() / agent_count.max(1) as f64;
+    ratings
+        .iter()
+        .map(|r| (r - average + 1500.0) as f32)
+        .collect()
+}
+
+/// Plays every pair of agents against each other with the given teams
+///
+/// Agents are described as in `agent_from_str`. Each pair of agents plays `games_per_matchup`
+/// games, going through every pairing of the teams. Every other game the agents swap sides
+/// and keep the teams, so both agents play every position from both sides. Games are played
+/// one at a time so that searches limited by time are not slowed down by each other
+pub struct Tournament {
+    pub agents: Vec<String>,
+    pub teams: Vec<Side>,
+    pub games_per_matchup: usize,
+    pub max_turns: u16,
+    pub seed: u64,
+}
+
+impl Tournament {
+    pub fn new(agents: Vec<String>, teams: Vec<Side>) -> Tournament {
+        Tournament {
+            agents,
+            teams,
+            games_per_matchup: 10,
+            max_turns: 100,
+            seed: 0,
+        }
+    }
+
+    fn teams_for_game(&self, game: usize) -> (usize, usize) {
+        let position = game / 2;
+        let team_count = self.teams.len();
+        (
+            position % team_count,
+            (position % team_count + position / team_count) % team_count,
+        )
+    }
+
+    /// Plays the tournament, calling `on_game` after every game
+    pub fn play<F: FnMut(&GameResult)>(&self, mut on_game: F) -> Result<TournamentResult, String> {
+        if self.agents.len() < 2 {
+            return Err("a tournament needs at least 2 agents".to_string());
+        }
+        if self.teams.is_empty() {
+            return Err("a tournament needs at least 1 team".to_string());
+        }
+        for agent in self.agents.iter() {
+            agent_from_str(agent, 0)?;
+        }
+
+        let mut games = vec![];
+        let mut matchups = vec![];
+        for first in 0..self.agents.len() {
+            for second in first + 1..self.agents.len() {
+                let mut matchup = Matchup {
+                    agents: (first, second),
+                    wins: 0,
+                    losses: 0,
+                    draws: 0,
+                };
+                for game in 0..self.games_per_matchup {
+                    let (side_one_agent, side_two_agent) = match game % 2 {
+                        0 => (first, second),
+                        _ => (second, first),
+                    };
+                    let (side_one_team, side_two_team) = self.teams_for_game(game);
+                    let mut state = State {
+                        side_one: self.teams[side_one_team].clone(),
+                        side_two: self.teams[side_two_team].clone(),
+                        ..State::default()
+                    };
+                    state.set_conditional_mechanics();
+                    state.refresh_zobrist_hash();
+
+                    let result = simulate_battle_from_str(
+                        state,
+                        &self.agents[side_one_agent],
+                        &self.agents[side_two_agent],
+                        self.max_turns,
+                        self.seed.wrapping_add(2 * games.len() as u64),
+                    )?;
+                    let game_result = GameResult {
+                        side_one_agent,
+                        side_two_agent,
+                        side_one_team,
+                        side_two_team,
+                        winner: result.winner,
+                        turns: result.turns.last().map_or(0, |t| t.turn),
+                    };
+                    match game_result.winner {
+                        None => matchup.draws += 1,
+                        Some(SideReference::SideOne) if side_one_agent == first => {
+                            matchup.wins += 1
+                        }
+                        Some(SideReference::SideTwo) if side_two_agent == first => {
+                            matchup.wins += 1
+                        }
+                        Some(_) => matchup.losses += 1,
+                    }
+                    on_game(&game_result);
+                    games.push(game_result);
+                }
+                matchups.push(matchup);
+            }
+        }
+
+        let elo = elo_ratings(self.agents.len(), &matchups);
+        let mut standings: Vec<Standing> = self
+            .agents
+            .iter()
+            .enumerate()
+            .map(|(index, agent)| {
+                let (mut wins, mut losses, mut draws) = (0, 0, 0);
+                for m in matchups.iter() {
+                    if m.agents.0 == index {
+                        wins += m.wins;
+                        losses += m.losses;
+                    } else if m.agents.1 == index {
+                        wins += m.losses;
+                        losses += m.wins;
+                    } else {
+                        continue;
+                    }
+                    draws += m.draws;
+                }
+                let games = wins + losses + draws;
+                let win_rate = match games {
+                    0 => 0.0,
+                    _ => (wins as f32 + draws as f32 / 2.0) / games as f32,
+                };
+                Standing {
+                    agent: agent.clone(),
+                    wins,
+                    losses,
+                    draws,
+                    win_rate,
+                    confidence_interval: wilson_interval(win_rate, games),
+                    elo: elo[index],
+                }
+            })
+            .collect();
+        standings.sort_by(|a, b| b.elo.total_cmp(&a.elo));
+
+        Ok(TournamentResult {
+            agents: self.agents.clone(),
+            games,
+            matchups,
+            standings,
+        })
+    }
+}
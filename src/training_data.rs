@@ -0,0 +1,319 @@
+use crate::engine::state::MoveChoice;
+use crate::mcts::{default_damage_branching, perform_mcts, MctsSideResult};
+use crate::simulator::simulate_battle_with;
+use crate::state::{PokemonIndex, PokemonMoveIndex, State};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde_json::{json, Value};
+use std::convert::TryInto;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A state seen during self-play, how often the search visited each option of both sides,
+/// and how the game ended: 1.0 if side one won, -1.0 if side two won and 0.0 for a draw
+#[derive(Debug, Clone)]
+pub struct TrainingExample {
+    pub state: State,
+    pub side_one: Vec<(MoveChoice, i64)>,
+    pub side_two: Vec<(MoveChoice, i64)>,
+    pub outcome: f32,
+}
+
+/// A compact code for each option, used in the binary format:
+/// 0-3 for moves, 4-7 for moves with terastallization, 8-13 for switches,
+/// 14-17 for moves with mega evolution, 18-21 for z-moves and 255 for none
+pub fn move_choice_code(move_choice: &MoveChoice) -> u8 {
+    match move_choice {
+        MoveChoice::Move(index) => *index as u8,
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        MoveChoice::MoveTera(index) => 4 + *index as u8,
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        MoveChoice::MoveMega(index) => 14 + *index as u8,
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        MoveChoice::MoveZ(index) => 18 + *index as u8,
+        MoveChoice::Switch(index) => 8 + *index as u8,
+        MoveChoice::None => 255,
+    }
+}
+
+pub fn move_choice_from_code(code: u8) -> Option<MoveChoice> {
+    let move_index = |index| match index {
+        0 => PokemonMoveIndex::M0,
+        1 => PokemonMoveIndex::M1,
+        2 => PokemonMoveIndex::M2,
+        _ => PokemonMoveIndex::M3,
+    };
+    match code {
+        0..=3 => Some(MoveChoice::Move(move_index(code))),
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        4..=7 => Some(MoveChoice::MoveTera(move_index(code - 4))),
+        8..=13 => Some(MoveChoice::Switch(PokemonIndex::deserialize(
+            &(code - 8).to_string(),
+        ))),
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        14..=17 => Some(MoveChoice::MoveMega(move_index(code - 14))),
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        18..=21 => Some(MoveChoice::MoveZ(move_index(code - 18))),
+        255 => Some(MoveChoice::None),
+        _ => None,
+    }
+}
+
+impl TrainingExample {
+    pub fn to_json(&self) -> Value {
+        let policy = |options: &Vec<(MoveChoice, i64)>, side| {
+            json!({
+                "moves": options
+                    .iter()
+                    .map(|(m, _)| m.to_string(side))
+                    .collect::<Vec<String>>(),
+                "visits": options.iter().map(|(_, v)| *v).collect::<Vec<i64>>(),
+            })
+        };
+        json!({
+            "state": self.state.serialize(),
+            "side_one": policy(&self.side_one, &self.state.side_one),
+            "side_two": policy(&self.side_two, &self.state.side_two),
+            "outcome": self.outcome,
+        })
+    }
+
+    /// One record of the binary format, with every number little endian:
+    /// the length of the serialized state as a u32 followed by the state itself,
+    /// then for each side the number of options as a u8 followed by the
+    /// `move_choice_code` (u8) and visits (u32) of each option,
+    /// and finally the outcome as an i8
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let state = self.state.serialize();
+        let mut bytes = Vec::with_capacity(state.len() + 64);
+        bytes.extend_from_slice(&(state.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(state.as_bytes());
+        for options in [&self.side_one, &self.side_two] {
+            bytes.push(options.len() as u8);
+            for (move_choice, visits) in options.iter() {
+                bytes.push(move_choice_code(move_choice));
+                bytes.extend_from_slice(&(*visits as u32).to_le_bytes());
+            }
+        }
+        bytes.push(self.outcome as i8 as u8);
+        bytes
+    }
+
+    /// Reads every record of a binary shard
+    pub fn from_bytes(bytes: &[u8]) -> Result<Vec<TrainingExample>, String> {
+        let mut examples = vec![];
+        let mut position = 0;
+        while position < bytes.len() {
+            let length = read_u32(bytes, &mut position)? as usize;
+            let state = std::str::from_utf8(take(bytes, &mut position, length)?)
+                .map_err(|e| e.to_string())?;
+            let mut sides = vec![];
+            for _ in 0..2 {
+                let count = take(bytes, &mut position, 1)?[0];
+                let mut options = vec![];
+                for _ in 0..count {
+                    let code = take(bytes, &mut position, 1)?[0];
+                    let move_choice = move_choice_from_code(code)
+                        .ok_or(format!("invalid move choice code: {}", code))?;
+                    let visits = read_u32(bytes, &mut position)?;
+                    options.push((move_choice, visits as i64));
+                }
+                sides.push(options);
+            }
+            let outcome = take(bytes, &mut position, 1)?[0] as i8 as f32;
+            let side_two = sides.pop().unwrap();
+            let side_one = sides.pop().unwrap();
+            examples.push(TrainingExample {
+                state: State::deserialize(state),
+                side_one,
+                side_two,
+                outcome,
+            });
+        }
+        Ok(examples)
+    }
+}
+
+fn take<'a>(bytes: &'a [u8], position: &mut usize, count: usize) -> Result<&'a [u8], String> {
+    let taken = bytes
+        .get(*position..*position + count)
+        .ok_or("unexpected end of data")?;
+    *position += count;
+    Ok(taken)
+}
+
+fn read_u32(bytes: &[u8], position: &mut usize) -> Result<u32, String> {
+    Ok(u32::from_le_bytes(
+        take(bytes, position, 4)?.try_into().unwrap(),
+    ))
+}
+
+fn visits(results: &[MctsSideResult]) -> Vec<(MoveChoice, i64)> {
+    results.iter().map(|r| (r.move_choice, r.visits)).collect()
+}
+
+// With a temperature of 0 the most visited option is picked, otherwise options are
+// sampled in proportion to their visits to the power of 1 / temperature
+fn pick<R: Rng>(options: &[(MoveChoice, i64)], temperature: f32, rng: &mut R) -> MoveChoice {
+    if temperature <= 0.0 {
+        return options
+            .iter()
+            .max_by_key(|(_, visits)| *visits)
+            .map_or(MoveChoice::None, |(m, _)| *m);
+    }
+    let weights = options
+        .iter()
+        .map(|(_, visits)| (*visits as f64).powf(1.0 / temperature as f64));
+    match WeightedIndex::new(weights) {
+        Ok(dist) => options[dist.sample(rng)].0,
+        Err(_) => options.first().map_or(MoveChoice::None, |(m, _)| *m),
+    }
+}
+
+/// Plays a game where both sides choose their moves from one monte carlo tree search
+/// of `search_time` each turn, and returns an example for every turn where either side
+/// had more than one option
+pub fn self_play<R: Rng>(
+    state: State,
+    search_time: Duration,
+    max_turns: u16,
+    temperature: f32,
+    rng: &mut R,
+) -> Vec<TrainingExample> {
+    let mut move_rng = StdRng::seed_from_u64(rng.gen());
+    let mut examples = vec![];
+    let result = simulate_battle_with(
+        state,
+        |state, side_one_options, side_two_options| {
+            if side_one_options.len() <= 1 && side_two_options.len() <= 1 {
+                return (
+                    side_one_options
+                        .first()
+                        .copied()
+                        .unwrap_or(MoveChoice::None),
+                    side_two_options
+                        .first()
+                        .copied()
+                        .unwrap_or(MoveChoice::None),
+                );
+            }
+            let mcts_result = perform_mcts(
+                &mut state.clone(),
+                side_one_options.to_vec(),
+                side_two_options.to_vec(),
+                search_time,
+                &default_damage_branching(),
+                0.0,
+            );
+            let example = TrainingExample {
+                state: state.clone(),
+                side_one: visits(&mcts_result.s1),
+                side_two: visits(&mcts_result.s2),
+                outcome: 0.0,
+            };
+            let choices = (
+                pick(&example.side_one, temperature, &mut move_rng),
+                pick(&example.side_two, temperature, &mut move_rng),
+            );
+            examples.push(example);
+            choices
+        },
+        max_turns,
+        rng,
+    );
+
+    let outcome = result.final_state.battle_is_over();
+    for example in examples.iter_mut() {
+        example.outcome = outcome;
+    }
+    examples
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrainingDataFormat {
+    Jsonl,
+    Binary,
+}
+
+impl std::str::FromStr for TrainingDataFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" => Ok(TrainingDataFormat::Jsonl),
+            "binary" => Ok(TrainingDataFormat::Binary),
+            _ => Err(format!("invalid format: {}", s)),
+        }
+    }
+}
+
+/// Writes examples to `shard-00000.jsonl`, `shard-00001.jsonl`, ... (or `.bin`) in
+/// a directory, starting a new file every `examples_per_shard` examples
+pub struct ShardedWriter {
+    directory: PathBuf,
+    format: TrainingDataFormat,
+    examples_per_shard: usize,
+    examples_in_shard: usize,
+    file: Option<BufWriter<File>>,
+    pub shards: Vec<PathBuf>,
+}
+
+impl ShardedWriter {
+    pub fn new(
+        directory: &str,
+        format: TrainingDataFormat,
+        examples_per_shard: usize,
+    ) -> Result<ShardedWriter, String> {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("could not create {}: {}", directory, e))?;
+        Ok(ShardedWriter {
+            directory: PathBuf::from(directory),
+            format,
+            examples_per_shard: examples_per_shard.max(1),
+            examples_in_shard: 0,
+            file: None,
+            shards: vec![],
+        })
+    }
+
+    fn next_shard(&mut self) -> Result<(), String> {
+        self.flush()?;
+        let extension = match self.format {
+            TrainingDataFormat::Jsonl => "jsonl",
+            TrainingDataFormat::Binary => "bin",
+        };
+        let path = self
+            .directory
+            .join(format!("shard-{:05}.{}", self.shards.len(), extension));
+        let file = File::create(&path)
+            .map_err(|e| format!("could not create {}: {}", path.display(), e))?;
+        self.file = Some(BufWriter::new(file));
+        self.shards.push(path);
+        self.examples_in_shard = 0;
+        Ok(())
+    }
+
+    pub fn write(&mut self, example: &TrainingExample) -> Result<(), String> {
+        if self.file.is_none() || self.examples_in_shard == self.examples_per_shard {
+            self.next_shard()?;
+        }
+        let file = self.file.as_mut().unwrap();
+        let written = match self.format {
+            TrainingDataFormat::Jsonl => writeln!(file, "{}", example.to_json()),
+            TrainingDataFormat::Binary => file.write_all(&example.to_bytes()),
+        };
+        written.map_err(|e| e.to_string())?;
+        self.examples_in_shard += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), String> {
+        match self.file.as_mut() {
+            Some(file) => file.flush().map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use crate::choices::Choices;
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::pokemon::PokemonName;
+use crate::showdown_team::{
+    parse_ability, parse_item, parse_move, parse_nature, parse_species, parse_type, to_id,
+};
+use crate::state::{
+    pokemon_index_iter, Move, Pokemon, PokemonMoveIndex, PokemonNature, PokemonType, Side,
+};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// The usage of each ability, item, EV spread, move and tera type of one pokemon.
+/// Weights are probabilities: a move's weight is the chance that a set has it, every
+/// other weight is the chance that a set uses that option. Each list is sorted from
+/// the most to the least likely option
+#[derive(Debug, Clone, Default)]
+pub struct PokemonUsage {
+    pub abilities: Vec<(Abilities, f32)>,
+    pub items: Vec<(Items, f32)>,
+    pub spreads: Vec<(Spread, f32)>,
+    pub moves: Vec<(Choices, f32)>,
+    pub tera_types: Vec<(PokemonType, f32)>,
+}
+
+/// A nature and EV spread
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spread {
+    pub nature: PokemonNature,
+    pub evs: (u8, u8, u8, u8, u8, u8),
+}
+
+/// A full set for a pokemon inferred from usage statistics
+#[derive(Debug, Clone, PartialEq)]
+pub struct PokemonSet {
+    pub ability: Abilities,
+    pub item: Items,
+    pub nature: PokemonNature,
+    pub evs: (u8, u8, u8, u8, u8, u8),
+    pub moves: Vec<Choices>,
+    pub tera_type: PokemonType,
+    // the probability of this set, treating each part of the set as independent
+    pub weight: f32,
+}
+
+impl PokemonSet {
+    /// Gives `pkmn` this set. Revealed moves are kept and the inferred moves fill the
+    /// unrevealed move slots. Stats are recalculated from the EV spread and nature
+    pub fn apply(&self, pkmn: &mut Pokemon) {
+        if pkmn.ability == pkmn.base_ability {
+            pkmn.ability = self.ability;
+        }
+        pkmn.base_ability = self.ability;
+        pkmn.item = self.item;
+        if !pkmn.terastallized {
+            pkmn.tera_type = self.tera_type;
+        }
+        pkmn.set_nature_and_evs(self.nature.clone(), self.evs);
+
+        let revealed = revealed_moves(pkmn);
+        let mut inferred_moves = self
+            .moves
+            .iter()
+            .filter(|choice| !revealed.contains(choice));
+        for move_index in [
+            PokemonMoveIndex::M0,
+            PokemonMoveIndex::M1,
+            PokemonMoveIndex::M2,
+            PokemonMoveIndex::M3,
+        ] {
+            if pkmn.moves[&move_index].id != Choices::NONE {
+                continue;
+            }
+            if let Some(choice) = inferred_moves.next() {
+                pkmn.moves[&move_index] = Move::default();
+                pkmn.replace_move(move_index, *choice);
+            }
+        }
+    }
+}
+
+/// Usage statistics loaded from a Smogon "chaos" JSON file,
+/// e.g. https://www.smogon.com/stats/2024-01/chaos/gen9ou-1695.json
+#[derive(Debug, Clone, Default)]
+pub struct UsageStatistics {
+    pub pokemon: HashMap<PokemonName, PokemonUsage>,
+}
+
+// Each option's share of the total weight, most likely first.
+// Options that this engine does not know about are skipped
+fn parse_weights<T>(
+    weights: Option<&Map<String, Value>>,
+    total: f32,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Vec<(T, f32)> {
+    let mut parsed: Vec<(T, f32)> = match weights {
+        Some(weights) => weights
+            .iter()
+            .filter_map(|(name, weight)| {
+                let weight = weight.as_f64()? as f32;
+                match parse(name) {
+                    Ok(option) if weight > 0.0 => Some((option, weight / total)),
+                    _ => None,
+                }
+            })
+            .collect(),
+        None => vec![],
+    };
+    parsed.sort_by(|a, b| b.1.total_cmp(&a.1));
+    parsed
+}
+
+fn total_weight(weights: Option<&Map<String, Value>>) -> f32 {
+    weights.map_or(0.0, |w| {
+        w.values().filter_map(|v| v.as_f64()).sum::<f64>() as f32
+    })
+}
+
+// e.g. "Jolly:0/252/0/0/4/252"
+fn parse_spread(spread: &str) -> Result<Spread, String> {
+    let (nature, evs) = spread
+        .split_once(':')
+        .ok_or(format!("invalid spread: {}", spread))?;
+    let evs = evs
+        .split('/')
+        .map(|ev| ev.parse::<u8>())
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| format!("invalid spread: {}", spread))?;
+    match evs.as_slice() {
+        [hp, atk, def, spa, spd, spe] => Ok(Spread {
+            nature: parse_nature(nature)?,
+            evs: (*hp, *atk, *def, *spa, *spd, *spe),
+        }),
+        _ => Err(format!("invalid spread: {}", spread)),
+    }
+}
+
+impl PokemonUsage {
+    fn from_json(usage: &Value) -> PokemonUsage {
+        let abilities = usage["Abilities"].as_object();
+        // every set has one ability, so the ability weights add up to the total
+        let total = total_weight(abilities);
+        if total <= 0.0 {
+            return PokemonUsage::default();
+        }
+        let item_parser = |name: &str| match to_id(name).as_str() {
+            "nothing" => Ok(Items::NONE),
+            _ => parse_item(name),
+        };
+        let move_parser = |name: &str| match name {
+            "" => Err("no move".to_string()),
+            _ => parse_move(name),
+        };
+        let mut moves = parse_weights(usage["Moves"].as_object(), total, move_parser);
+        for (_, weight) in moves.iter_mut() {
+            *weight = weight.min(1.0);
+        }
+        let items = usage["Items"].as_object();
+        let spreads = usage["Spreads"].as_object();
+        let tera_types = usage["Tera Types"].as_object();
+        PokemonUsage {
+            abilities: parse_weights(abilities, total, parse_ability),
+            items: parse_weights(items, total_weight(items), item_parser),
+            spreads: parse_weights(spreads, total_weight(spreads), parse_spread),
+            moves,
+            tera_types: parse_weights(tera_types, total_weight(tera_types), parse_type),
+        }
+    }
+}
+
+// Only keeps the options allowed by what has been revealed, scaled back up to add up to 1.
+// If nothing is left then the current value is the only option
+fn conditioned<T: Clone + PartialEq>(
+    options: &[(T, f32)],
+    current: T,
+    allowed: impl Fn(&T) -> bool,
+) -> Vec<(T, f32)> {
+    let options: Vec<(T, f32)> = options.iter().filter(|o| allowed(&o.0)).cloned().collect();
+    let total: f32 = options.iter().map(|o| o.1).sum();
+    if options.is_empty() || total <= 0.0 {
+        return vec![(current, 1.0)];
+    }
+    options.into_iter().map(|(o, w)| (o, w / total)).collect()
+}
+
+// Combines each of the sets with each of the options, keeping the `count` most likely
+fn extend_sets<T>(
+    sets: Vec<PokemonSet>,
+    options: &[(T, f32)],
+    count: usize,
+    set_option: impl Fn(&mut PokemonSet, &T),
+) -> Vec<PokemonSet> {
+    let mut extended = vec![];
+    for set in sets.iter() {
+        for (option, weight) in options.iter().take(count) {
+            let mut new_set = set.clone();
+            set_option(&mut new_set, option);
+            new_set.weight *= weight;
+            extended.push(new_set);
+        }
+    }
+    extended.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    extended.truncate(count);
+    extended
+}
+
+// Every way to choose `size` of the options with at most one Hidden Power, along with
+// the product of their weights
+fn move_combinations(options: &[(Choices, f32)], size: usize) -> Vec<(Vec<Choices>, f32)> {
+    if size == 0 {
+        return vec![(vec![], 1.0)];
+    }
+    let mut combinations = vec![];
+    for (index, (choice, weight)) in options.iter().enumerate() {
+        for (mut rest, rest_weight) in move_combinations(&options[index + 1..], size - 1) {
+            if choice.is_hiddenpower() && rest.iter().any(|m| m.is_hiddenpower()) {
+                continue;
+            }
+            rest.insert(0, *choice);
+            combinations.push((rest, weight * rest_weight));
+        }
+    }
+    combinations
+}
+
+fn revealed_moves(pkmn: &Pokemon) -> Vec<Choices> {
+    pkmn.moves
+        .into_iter()
+        .filter(|m| m.id != Choices::NONE)
+        .map(|m| m.id)
+        .collect()
+}
+
+// The moves that could fill the unrevealed move slots.
+// A set can only have one Hidden Power
+fn unrevealed_moves(usage: &PokemonUsage, revealed: &[Choices]) -> Vec<(Choices, f32)> {
+    let hidden_power_revealed = revealed.iter().any(|m| m.is_hiddenpower());
+    usage
+        .moves
+        .iter()
+        .filter(|(choice, _)| {
+            !(revealed.contains(choice) || hidden_power_revealed && choice.is_hiddenpower())
+        })
+        .cloned()
+        .collect()
+}
+
+fn sample_index<R: Rng>(weights: impl Iterator<Item = f32>, rng: &mut R) -> Option<usize> {
+    WeightedIndex::new(weights)
+        .ok()
+        .map(|dist| dist.sample(rng))
+}
+
+impl UsageStatistics {
+    pub fn from_json(json: &str) -> Result<UsageStatistics, String> {
+        let stats: Value =
+            serde_json::from_str(json).map_err(|e| format!("invalid usage statistics: {}", e))?;
+        let data = stats["data"]
+            .as_object()
+            .ok_or("invalid usage statistics: no data".to_string())?;
+        let mut pokemon = HashMap::new();
+        for (species, usage) in data.iter() {
+            // pokemon that this engine does not know about are skipped
+            if let Ok(species) = parse_species(species) {
+                pokemon.insert(species, PokemonUsage::from_json(usage));
+            }
+        }
+        Ok(UsageStatistics { pokemon })
+    }
+
+    pub fn from_file(path: &str) -> Result<UsageStatistics, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+        UsageStatistics::from_json(&json)
+    }
+
+    // An ability that is not the first legal ability of the species cannot have come
+    // from `Pokemon::from_species`, so it must have been revealed
+    fn ability_revealed(pkmn: &Pokemon) -> bool {
+        pkmn.id.abilities().first() != Some(&pkmn.base_ability)
+    }
+
+    // The options for each part of a set that are consistent with what has been
+    // revealed about the pokemon
+    fn conditioned_usage(&self, pkmn: &Pokemon) -> Option<PokemonUsage> {
+        let usage = self.pokemon.get(&pkmn.id)?;
+        let ability_revealed = UsageStatistics::ability_revealed(pkmn);
+        let current_spread = Spread {
+            nature: pkmn.nature.clone(),
+            evs: pkmn.evs,
+        };
+        Some(PokemonUsage {
+            abilities: conditioned(&usage.abilities, pkmn.base_ability, |a| {
+                !ability_revealed || *a == pkmn.base_ability
+            }),
+            items: conditioned(&usage.items, pkmn.item, |i| {
+                pkmn.item == Items::UNKNOWNITEM || *i == pkmn.item
+            }),
+            spreads: conditioned(&usage.spreads, current_spread, |_| true),
+            moves: unrevealed_moves(usage, &revealed_moves(pkmn)),
+            tera_types: conditioned(&usage.tera_types, pkmn.tera_type, |t| {
+                !pkmn.terastallized || *t == pkmn.tera_type
+            }),
+        })
+    }
+
+    /// The `count` most likely sets for `pkmn` given its revealed moves, item, ability
+    /// and tera type, most likely first. An item of `Items::UNKNOWNITEM` is unrevealed,
+    /// as is an ability that is the first legal ability of the species.
+    /// Empty if there are no statistics for the species
+    pub fn likely_sets(&self, pkmn: &Pokemon, count: usize) -> Vec<PokemonSet> {
+        let usage = match self.conditioned_usage(pkmn) {
+            Some(usage) => usage,
+            None => return vec![],
+        };
+        let revealed = revealed_moves(pkmn);
+        let unrevealed_slots = 4usize.saturating_sub(revealed.len());
+        let options = &usage.moves[..usage.moves.len().min(unrevealed_slots + count)];
+        // the Hidden Power variants can only fill one slot between them
+        let distinct_options = options.iter().filter(|m| !m.0.is_hiddenpower()).count()
+            + options.iter().any(|m| m.0.is_hiddenpower()) as usize;
+        let mut move_sets = move_combinations(options, unrevealed_slots.min(distinct_options));
+        move_sets.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut sets = vec![PokemonSet {
+            ability: pkmn.base_ability,
+            item: pkmn.item,
+            nature: pkmn.nature.clone(),
+            evs: pkmn.evs,
+            moves: revealed.clone(),
+            tera_type: pkmn.tera_type,
+            weight: 1.0,
+        }];
+        sets = extend_sets(sets, &usage.abilities, count, |s, a| s.ability = *a);
+        sets = extend_sets(sets, &usage.items, count, |s, i| s.item = *i);
+        sets = extend_sets(sets, &usage.spreads, count, |s, spread| {
+            s.nature = spread.nature.clone();
+            s.evs = spread.evs;
+        });
+        sets = extend_sets(sets, &usage.tera_types, count, |s, t| s.tera_type = *t);
+        extend_sets(sets, &move_sets, count, |s, moves| {
+            s.moves.extend(moves.iter().cloned())
+        })
+    }
+
+    /// Samples a set for `pkmn` from the usage statistics given what has been revealed.
+    /// None if there are no statistics for the species
+    pub fn sample_set<R: Rng>(&self, pkmn: &Pokemon, rng: &mut R) -> Option<PokemonSet> {
+        let usage = self.conditioned_usage(pkmn)?;
+        let mut weight = 1.0;
+        let mut sample = |options_len: usize, weights: Vec<f32>| {
+            let index = sample_index(weights.iter().cloned(), rng).unwrap_or(0);
+            weight *= weights.get(index).cloned().unwrap_or(1.0);
+            index.min(options_len - 1)
+        };
+        let ability = usage.abilities[sample(
+            usage.abilities.len(),
+            usage.abilities.iter().map(|a| a.1).collect(),
+        )]
+        .0;
+        let item =
+            usage.items[sample(usage.items.len(), usage.items.iter().map(|i| i.1).collect())].0;
+        let spread = usage.spreads[sample(
+            usage.spreads.len(),
+            usage.spreads.iter().map(|s| s.1).collect(),
+        )]
+        .0
+        .clone();
+        let tera_type = usage.tera_types[sample(
+            usage.tera_types.len(),
+            usage.tera_types.iter().map(|t| t.1).collect(),
+        )]
+        .0;
+
+        let mut moves = revealed_moves(pkmn);
+        let mut candidates = usage.moves;
+        while moves.len() < 4 && !candidates.is_empty() {
+            let index = sample(candidates.len(), candidates.iter().map(|m| m.1).collect());
+            let (choice, _) = candidates.remove(index);
+            if choice.is_hiddenpower() {
+                candidates.retain(|m| !m.0.is_hiddenpower());
+            }
+            moves.push(choice);
+        }
+
+        Some(PokemonSet {
+            ability,
+            item,
+            nature: spread.nature,
+            evs: spread.evs,
+            moves,
+            tera_type,
+            weight,
+        })
+    }
+
+    /// Gives each pokemon on `side` its most likely set
+    pub fn fill_side(&self, side: &mut Side) {
+        for pkmn_index in pokemon_index_iter() {
+            let pkmn = &mut side.pokemon[pkmn_index];
+            if let Some(set) = self.likely_sets(pkmn, 1).first() {
+                set.apply(pkmn);
+            }
+        }
+    }
+
+    /// Gives each pokemon on `side` a set sampled from the usage statistics
+    pub fn sample_side<R: Rng>(&self, side: &mut Side, rng: &mut R) {
+        for pkmn_index in pokemon_index_iter() {
+            let pkmn = &mut side.pokemon[pkmn_index];
+            if let Some(set) = self.sample_set(pkmn, rng) {
+                set.apply(pkmn);
+            }
+        }
+    }
+}
@@ -0,0 +1,641 @@
+use crate::engine::state::PokemonVolatileStatus;
+use crate::instruction::Instruction;
+use crate::state::{
+    pokemon_index_iter, LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, SideReference, State,
+};
+
+/*
+A State's zobrist hash is the XOR of one key per feature of the state, where a feature is a
+single field (or a small group of fields that always change together) and the key is derived from
+the feature's identity and its current value.
+
+Every Instruction changes exactly one feature, so `apply_one_instruction` and
+`reverse_one_instruction` can keep the hash up to date by XOR-ing out the feature's key before
+the change and XOR-ing in the new key after.
+
+Volatile statuses only contribute a key while present, which makes the hash independent
+of the iteration order of the HashSet they are stored in.
+
+Instruction generation modifies the state directly while it branches, so the hash is only valid
+between calls to `generate_instructions_from_move_pair`, which restores it before returning.
+The `debug_zobrist_hash` feature asserts every incremental update against a full recomputation.
+*/
+
+#[derive(Debug, Clone, Copy)]
+enum PokemonFeature {
+    Id,
+    Level,
+    Types,
+    BaseTypes,
+    Hp,
+    MaxHp,
+    Ability,
+    BaseAbility,
+    Item,
+    Nature,
+    Evs,
+    Ivs,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+    Status,
+    RestTurns,
+    SleepTurns,
+    WeightKg,
+    Terastallized,
+    TeraType,
+    Move(PokemonMoveIndex),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SideFeature {
+    ActiveIndex,
+    BatonPassing,
+    ShedTailing,
+    ForceSwitch,
+    ForceTrapped,
+    SlowUturnMove,
+    SubstituteHealth,
+    Wish,
+    FutureSight,
+    LastUsedMove,
+    DamageDealtDamage,
+    DamageDealtMoveCategory,
+    DamageDealtHitSubstitute,
+    SwitchOutMoveSecondSavedMove,
+    MegaEvolved,
+    ZMoveUsed,
+    Boost(PokemonBoostableStat),
+    SideCondition(PokemonSideCondition),
+    VolatileStatusDuration(PokemonVolatileStatus),
+    VolatileStatus(PokemonVolatileStatus),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ZobristFeature {
+    Weather,
+    Terrain,
+    TrickRoom,
+    TeamPreview,
+    Side(SideReference, SideFeature),
+    Pokemon(SideReference, PokemonIndex, PokemonFeature),
+}
+
+const POKEMON_FEATURES: [PokemonFeature; 27] = [
+    PokemonFeature::Id,
+    PokemonFeature::Level,
+    PokemonFeature::Types,
+    PokemonFeature::BaseTypes,
+    PokemonFeature::Hp,
+    PokemonFeature::MaxHp,
+    PokemonFeature::Ability,
+    PokemonFeature::BaseAbility,
+    PokemonFeature::Item,
+    PokemonFeature::Nature,
+    PokemonFeature::Evs,
+    PokemonFeature::Ivs,
+    PokemonFeature::Attack,
+    PokemonFeature::Defense,
+    PokemonFeature::SpecialAttack,
+    PokemonFeature::SpecialDefense,
+    PokemonFeature::Speed,
+    PokemonFeature::Status,
+    PokemonFeature::RestTurns,
+    PokemonFeature::SleepTurns,
+    PokemonFeature::WeightKg,
+    PokemonFeature::Terastallized,
+    PokemonFeature::TeraType,
+    PokemonFeature::Move(PokemonMoveIndex::M0),
+    PokemonFeature::Move(PokemonMoveIndex::M1),
+    PokemonFeature::Move(PokemonMoveIndex::M2),
+    PokemonFeature::Move(PokemonMoveIndex::M3),
+];
+
+const SIDE_FEATURES: [SideFeature; 48] = [
+    SideFeature::ActiveIndex,
+    SideFeature::BatonPassing,
+    SideFeature::ShedTailing,
+    SideFeature::ForceSwitch,
+    SideFeature::ForceTrapped,
+    SideFeature::SlowUturnMove,
+    SideFeature::SubstituteHealth,
+    SideFeature::Wish,
+    SideFeature::FutureSight,
+    SideFeature::LastUsedMove,
+    SideFeature::DamageDealtDamage,
+    SideFeature::DamageDealtMoveCategory,
+    SideFeature::DamageDealtHitSubstitute,
+    SideFeature::SwitchOutMoveSecondSavedMove,
+    SideFeature::MegaEvolved,
+    SideFeature::ZMoveUsed,
+    SideFeature::Boost(PokemonBoostableStat::Attack),
+    SideFeature::Boost(PokemonBoostableStat::Defense),
+    SideFeature::Boost(PokemonBoostableStat::SpecialAttack),
+    SideFeature::Boost(PokemonBoostableStat::SpecialDefense),
+    SideFeature::Boost(PokemonBoostableStat::Speed),
+    SideFeature::Boost(PokemonBoostableStat::Evasion),
+    SideFeature::Boost(PokemonBoostableStat::Accuracy),
+    SideFeature::SideCondition(PokemonSideCondition::AuroraVeil),
+    SideFeature::SideCondition(PokemonSideCondition::CraftyShield),
+    SideFeature::SideCondition(PokemonSideCondition::HealingWish),
+    SideFeature::SideCondition(PokemonSideCondition::LightScreen),
+    SideFeature::SideCondition(PokemonSideCondition::LuckyChant),
+    SideFeature::SideCondition(PokemonSideCondition::LunarDance),
+    SideFeature::SideCondition(PokemonSideCondition::MatBlock),
+    SideFeature::SideCondition(PokemonSideCondition::Mist),
+    SideFeature::SideCondition(PokemonSideCondition::Protect),
+    SideFeature::SideCondition(PokemonSideCondition::QuickGuard),
+    SideFeature::SideCondition(PokemonSideCondition::Reflect),
+    SideFeature::SideCondition(PokemonSideCondition::Safeguard),
+    SideFeature::SideCondition(PokemonSideCondition::Spikes),
+    SideFeature::SideCondition(PokemonSideCondition::Stealthrock),
+    SideFeature::SideCondition(PokemonSideCondition::StickyWeb),
+    SideFeature::SideCondition(PokemonSideCondition::Tailwind),
+    SideFeature::SideCondition(PokemonSideCondition::ToxicCount),
+    SideFeature::SideCondition(PokemonSideCondition::ToxicSpikes),
+    SideFeature::SideCondition(PokemonSideCondition::WideGuard),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::CONFUSION),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::ENCORE),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::LOCKEDMOVE),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::SLOWSTART),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::TAUNT),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::YAWN),
+];
+
+// the side features that belong to the side's active pokemon rather than to the whole side,
+// along with its volatile statuses
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+const ACTIVE_SLOT_FEATURES: [SideFeature; 20] = [
+    SideFeature::ActiveIndex,
+    SideFeature::SubstituteHealth,
+    SideFeature::LastUsedMove,
+    SideFeature::DamageDealtDamage,
+    SideFeature::DamageDealtMoveCategory,
+    SideFeature::DamageDealtHitSubstitute,
+    SideFeature::Boost(PokemonBoostableStat::Attack),
+    SideFeature::Boost(PokemonBoostableStat::Defense),
+    SideFeature::Boost(PokemonBoostableStat::SpecialAttack),
+    SideFeature::Boost(PokemonBoostableStat::SpecialDefense),
+    SideFeature::Boost(PokemonBoostableStat::Speed),
+    SideFeature::Boost(PokemonBoostableStat::Evasion),
+    SideFeature::Boost(PokemonBoostableStat::Accuracy),
+    SideFeature::SideCondition(PokemonSideCondition::Protect),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::CONFUSION),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::ENCORE),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::LOCKEDMOVE),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::SLOWSTART),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::TAUNT),
+    SideFeature::VolatileStatusDuration(PokemonVolatileStatus::YAWN),
+];
+
+// mixed into the hash of a doubles slot B so that it cannot cancel out the key of a side feature
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+const SLOT_B_SALT: u64 = 0x5B;
+
+const STATE_FEATURES: [ZobristFeature; 4] = [
+    ZobristFeature::Weather,
+    ZobristFeature::Terrain,
+    ZobristFeature::TrickRoom,
+    ZobristFeature::TeamPreview,
+];
+
+// https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+fn zobrist_key(parts: &[u64]) -> u64 {
+    parts.iter().fold(0, |key, part| splitmix64(key ^ part))
+}
+
+fn pack_i8(value: i8) -> u64 {
+    value as u8 as u64
+}
+
+fn pack_i16(value: i16) -> u64 {
+    value as u16 as u64
+}
+
+impl PokemonFeature {
+    fn id(&self) -> u64 {
+        match self {
+            PokemonFeature::Id => 0,
+            PokemonFeature::Level => 1,
+            PokemonFeature::Types => 2,
+            PokemonFeature::BaseTypes => 3,
+            PokemonFeature::Hp => 4,
+            PokemonFeature::MaxHp => 5,
+            PokemonFeature::Ability => 6,
+            PokemonFeature::BaseAbility => 7,
+            PokemonFeature::Item => 8,
+            PokemonFeature::Nature => 9,
+            PokemonFeature::Evs => 10,
+            PokemonFeature::Attack => 11,
+            PokemonFeature::Defense => 12,
+            PokemonFeature::SpecialAttack => 13,
+            PokemonFeature::SpecialDefense => 14,
+            PokemonFeature::Speed => 15,
+            PokemonFeature::Status => 16,
+            PokemonFeature::RestTurns => 17,
+            PokemonFeature::SleepTurns => 18,
+            PokemonFeature::WeightKg => 19,
+            PokemonFeature::Terastallized => 20,
+            PokemonFeature::TeraType => 21,
+            PokemonFeature::Ivs => 22,
+            PokemonFeature::Move(move_index) => 32 + *move_index as u64,
+        }
+    }
+}
+
+impl SideFeature {
+    fn id(&self) -> u64 {
+        match self {
+            SideFeature::ActiveIndex => 0,
+            SideFeature::BatonPassing => 1,
+            SideFeature::ShedTailing => 2,
+            SideFeature::ForceSwitch => 3,
+            SideFeature::ForceTrapped => 4,
+            SideFeature::SlowUturnMove => 5,
+            SideFeature::SubstituteHealth => 6,
+            SideFeature::Wish => 7,
+            SideFeature::FutureSight => 8,
+            SideFeature::LastUsedMove => 9,
+            SideFeature::DamageDealtDamage => 10,
+            SideFeature::DamageDealtMoveCategory => 11,
+            SideFeature::DamageDealtHitSubstitute => 12,
+            SideFeature::SwitchOutMoveSecondSavedMove => 13,
+            SideFeature::MegaEvolved => 14,
+            SideFeature::ZMoveUsed => 15,
+            SideFeature::Boost(stat) => 0x100 + *stat as u64,
+            SideFeature::SideCondition(side_condition) => 0x200 + *side_condition as u64,
+            SideFeature::VolatileStatusDuration(volatile_status) => 0x300 + *volatile_status as u64,
+            SideFeature::VolatileStatus(volatile_status) => 0x400 + *volatile_status as u64,
+        }
+    }
+}
+
+impl ZobristFeature {
+    fn id(&self) -> [u64; 4] {
+        match self {
+            ZobristFeature::Weather => [0, 0, 0, 0],
+            ZobristFeature::Terrain => [1, 0, 0, 0],
+            ZobristFeature::TrickRoom => [2, 0, 0, 0],
+            ZobristFeature::TeamPreview => [3, 0, 0, 0],
+            ZobristFeature::Side(side_ref, side_feature) => {
+                [4, *side_ref as u64, side_feature.id(), 0]
+            }
+            ZobristFeature::Pokemon(side_ref, pokemon_index, pokemon_feature) => [
+                5,
+                *side_ref as u64,
+                *pokemon_index as u64,
+                pokemon_feature.id(),
+            ],
+        }
+    }
+}
+
+impl State {
+    fn zobrist_feature_value(&self, feature: ZobristFeature) -> u64 {
+        match feature {
+            ZobristFeature::Weather => {
+                (self.weather.weather_type as u64) << 8 | pack_i8(self.weather.turns_remaining)
+            }
+            ZobristFeature::Terrain => {
+                (self.terrain.terrain_type as u64) << 8 | pack_i8(self.terrain.turns_remaining)
+            }
+            ZobristFeature::TrickRoom => {
+                (self.trick_room.active as u64) << 8 | pack_i8(self.trick_room.turns_remaining)
+            }
+            ZobristFeature::TeamPreview => self.team_preview as u64,
+            ZobristFeature::Side(side_ref, side_feature) => {
+                let side = self.get_side_immutable(&side_ref);
+                match side_feature {
+                    SideFeature::ActiveIndex => side.active_index as u64,
+                    SideFeature::BatonPassing => side.baton_passing as u64,
+                    SideFeature::ShedTailing => side.shed_tailing as u64,
+                    SideFeature::ForceSwitch => side.force_switch as u64,
+                    SideFeature::ForceTrapped => side.force_trapped as u64,
+                    SideFeature::SlowUturnMove => side.slow_uturn_move as u64,
+                    SideFeature::SubstituteHealth => pack_i16(side.substitute_health),
+                    SideFeature::Wish => pack_i8(side.wish.0) << 16 | pack_i16(side.wish.1),
+                    SideFeature::FutureSight => {
+                        pack_i8(side.future_sight.0) << 8 | side.future_sight.1 as u64
+                    }
+                    SideFeature::LastUsedMove => match side.last_used_move {
+                        LastUsedMove::Move(move_index) => 0x100 | move_index as u64,
+                        LastUsedMove::Switch(pokemon_index) => 0x200 | pokemon_index as u64,
+                        LastUsedMove::None => 0,
+                    },
+                    SideFeature::DamageDealtDamage => pack_i16(side.damage_dealt.damage),
+                    SideFeature::DamageDealtMoveCategory => side.damage_dealt.move_category as u64,
+                    SideFeature::DamageDealtHitSubstitute => {
+                        side.damage_dealt.hit_substitute as u64
+                    }
+                    SideFeature::SwitchOutMoveSecondSavedMove => {
+                        side.switch_out_move_second_saved_move as u64
+                    }
+                    SideFeature::MegaEvolved => side.mega_evolved as u64,
+                    SideFeature::ZMoveUsed => side.z_move_used as u64,
+                    SideFeature::Boost(stat) => pack_i8(match stat {
+                        PokemonBoostableStat::Attack => side.attack_boost,
+                        PokemonBoostableStat::Defense => side.defense_boost,
+                        PokemonBoostableStat::SpecialAttack => side.special_attack_boost,
+                        PokemonBoostableStat::SpecialDefense => side.special_defense_boost,
+                        PokemonBoostableStat::Speed => side.speed_boost,
+                        PokemonBoostableStat::Evasion => side.evasion_boost,
+                        PokemonBoostableStat::Accuracy => side.accuracy_boost,
+                    }),
+                    SideFeature::SideCondition(side_condition) => {
+                        pack_i8(side.get_side_condition(side_condition))
+                    }
+                    SideFeature::VolatileStatusDuration(volatile_status) => {
+                        let durations = &side.volatile_status_durations;
+                        pack_i8(match volatile_status {
+                            PokemonVolatileStatus::CONFUSION => durations.confusion,
+                            PokemonVolatileStatus::ENCORE => durations.encore,
+                            PokemonVolatileStatus::LOCKEDMOVE => durations.lockedmove,
+                            PokemonVolatileStatus::SLOWSTART => durations.slowstart,
+                            PokemonVolatileStatus::TAUNT => durations.taunt,
+                            PokemonVolatileStatus::YAWN => durations.yawn,
+                            _ => panic!(
+                                "Invalid volatile status for a duration: {:?}",
+                                volatile_status
+                            ),
+                        })
+                    }
+                    SideFeature::VolatileStatus(volatile_status) => {
+                        side.volatile_statuses.contains(&volatile_status) as u64
+                    }
+                }
+            }
+            ZobristFeature::Pokemon(side_ref, pokemon_index, pokemon_feature) => {
+                let pkmn = &self.get_side_immutable(&side_ref).pokemon[pokemon_index];
+                match pokemon_feature {
+                    PokemonFeature::Id => pack_i16(pkmn.id as i16),
+                    PokemonFeature::Level => pack_i8(pkmn.level),
+                    PokemonFeature::Types => (pkmn.types.0 as u64) << 8 | pkmn.types.1 as u64,
+                    PokemonFeature::BaseTypes => {
+                        (pkmn.base_types.0 as u64) << 8 | pkmn.base_types.1 as u64
+                    }
+                    PokemonFeature::Hp => pack_i16(pkmn.hp),
+                    PokemonFeature::MaxHp => pack_i16(pkmn.maxhp),
+                    PokemonFeature::Ability => pack_i16(pkmn.ability as i16),
+                    PokemonFeature::BaseAbility => pack_i16(pkmn.base_ability as i16),
+                    PokemonFeature::Item => pkmn.item as u64,
+                    PokemonFeature::Nature => pkmn.nature.clone() as u64,
+                    PokemonFeature::Evs => {
+                        let evs = pkmn.evs;
+                        [evs.0, evs.1, evs.2, evs.3, evs.4, evs.5]
+                            .iter()
+                            .fold(0, |packed, ev| packed << 8 | *ev as u64)
+                    }
+                    PokemonFeature::Ivs => {
+                        let ivs = pkmn.ivs;
+                        [ivs.0, ivs.1, ivs.2, ivs.3, ivs.4, ivs.5]
+                            .iter()
+                            .fold(0, |packed, iv| packed << 8 | *iv as u64)
+                    }
+                    PokemonFeature::Attack => pack_i16(pkmn.attack),
+                    PokemonFeature::Defense => pack_i16(pkmn.defense),
+                    PokemonFeature::SpecialAttack => pack_i16(pkmn.special_attack),
+                    PokemonFeature::SpecialDefense => pack_i16(pkmn.special_defense),
+                    PokemonFeature::Speed => pack_i16(pkmn.speed),
+                    PokemonFeature::Status => pkmn.status as u64,
+                    PokemonFeature::RestTurns => pack_i8(pkmn.rest_turns),
+                    PokemonFeature::SleepTurns => pack_i8(pkmn.sleep_turns),
+                    PokemonFeature::WeightKg => pkmn.weight_kg.to_bits() as u64,
+                    PokemonFeature::Terastallized => pkmn.terastallized as u64,
+                    PokemonFeature::TeraType => pkmn.tera_type as u64,
+                    PokemonFeature::Move(move_index) => {
+                        let m = &pkmn.moves[&move_index];
+                        (m.id as u64) << 16 | (m.disabled as u64) << 8 | pack_i8(m.pp)
+                    }
+                }
+            }
+        }
+    }
+
+    fn zobrist_feature_key(&self, feature: ZobristFeature) -> u64 {
+        let value = self.zobrist_feature_value(feature);
+        match feature {
+            // absent volatile statuses do not contribute to the hash
+            ZobristFeature::Side(_, SideFeature::VolatileStatus(_)) if value == 0 => 0,
+            _ => {
+                let id = feature.id();
+                zobrist_key(&[id[0], id[1], id[2], id[3], value])
+            }
+        }
+    }
+
+    fn zobrist_feature_of_instruction(&self, instruction: &Instruction) -> ZobristFeature {
+        let active = |side_ref: &SideReference, pokemon_feature: PokemonFeature| {
+            ZobristFeature::Pokemon(
+                *side_ref,
+                self.get_side_immutable(side_ref).active_index,
+                pokemon_feature,
+            )
+        };
+        match instruction {
+            Instruction::Switch(i) => ZobristFeature::Side(i.side_ref, SideFeature::ActiveIndex),
+            Instruction::ApplyVolatileStatus(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::VolatileStatus(i.volatile_status))
+            }
+            Instruction::RemoveVolatileStatus(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::VolatileStatus(i.volatile_status))
+            }
+            Instruction::ChangeStatus(i) => {
+                ZobristFeature::Pokemon(i.side_ref, i.pokemon_index, PokemonFeature::Status)
+            }
+            Instruction::Heal(i) => active(&i.side_ref, PokemonFeature::Hp),
+            Instruction::Damage(i) => active(&i.side_ref, PokemonFeature::Hp),
+            Instruction::Boost(i) => ZobristFeature::Side(i.side_ref, SideFeature::Boost(i.stat)),
+            Instruction::ChangeSideCondition(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::SideCondition(i.side_condition))
+            }
+            Instruction::ChangeVolatileStatusDuration(i) => ZobristFeature::Side(
+                i.side_ref,
+                SideFeature::VolatileStatusDuration(i.volatile_status),
+            ),
+            Instruction::ChangeWeather(_) | Instruction::DecrementWeatherTurnsRemaining => {
+                ZobristFeature::Weather
+            }
+            Instruction::ChangeTerrain(_) | Instruction::DecrementTerrainTurnsRemaining => {
+                ZobristFeature::Terrain
+            }
+            Instruction::ChangeType(i) => active(&i.side_ref, PokemonFeature::Types),
+            Instruction::ChangeAbility(i) => active(&i.side_ref, PokemonFeature::Ability),
+            Instruction::ChangeBaseAbility(i) => active(&i.side_ref, PokemonFeature::BaseAbility),
+            Instruction::ChangeItem(i) => active(&i.side_ref, PokemonFeature::Item),
+            Instruction::ChangeAttack(i) => active(&i.side_ref, PokemonFeature::Attack),
+            Instruction::ChangeDefense(i) => active(&i.side_ref, PokemonFeature::Defense),
+            Instruction::ChangeSpecialAttack(i) => {
+                active(&i.side_ref, PokemonFeature::SpecialAttack)
+            }
+            Instruction::ChangeSpecialDefense(i) => {
+                active(&i.side_ref, PokemonFeature::SpecialDefense)
+            }
+            Instruction::ChangeSpeed(i) => active(&i.side_ref, PokemonFeature::Speed),
+            Instruction::DisableMove(i) => active(&i.side_ref, PokemonFeature::Move(i.move_index)),
+            Instruction::EnableMove(i) => active(&i.side_ref, PokemonFeature::Move(i.move_index)),
+            Instruction::ChangeWish(i) => ZobristFeature::Side(i.side_ref, SideFeature::Wish),
+            Instruction::DecrementWish(i) => ZobristFeature::Side(i.side_ref, SideFeature::Wish),
+            Instruction::SetFutureSight(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::FutureSight)
+            }
+            Instruction::DecrementFutureSight(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::FutureSight)
+            }
+            Instruction::DamageSubstitute(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::SubstituteHealth)
+            }
+            Instruction::ChangeSubstituteHealth(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::SubstituteHealth)
+            }
+            Instruction::DecrementRestTurns(i) => active(&i.side_ref, PokemonFeature::RestTurns),
+            Instruction::SetRestTurns(i) => {
+                ZobristFeature::Pokemon(i.side_ref, i.pokemon_index, PokemonFeature::RestTurns)
+            }
+            Instruction::SetSleepTurns(i) => {
+                ZobristFeature::Pokemon(i.side_ref, i.pokemon_index, PokemonFeature::SleepTurns)
+            }
+            Instruction::FormeChange(i) => active(&i.side_ref, PokemonFeature::Id),
+            Instruction::SetSideOneMoveSecondSwitchOutMove(_) => ZobristFeature::Side(
+                SideReference::SideOne,
+                SideFeature::SwitchOutMoveSecondSavedMove,
+            ),
+            Instruction::SetSideTwoMoveSecondSwitchOutMove(_) => ZobristFeature::Side(
+                SideReference::SideTwo,
+                SideFeature::SwitchOutMoveSecondSavedMove,
+            ),
+            Instruction::ToggleBatonPassing(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::BatonPassing)
+            }
+            Instruction::ToggleShedTailing(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::ShedTailing)
+            }
+            Instruction::SetLastUsedMove(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::LastUsedMove)
+            }
+            Instruction::ChangeDamageDealtDamage(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::DamageDealtDamage)
+            }
+            Instruction::ChangeDamageDealtMoveCatagory(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::DamageDealtMoveCategory)
+            }
+            Instruction::ToggleDamageDealtHitSubstitute(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::DamageDealtHitSubstitute)
+            }
+            Instruction::DecrementPP(i) => active(&i.side_ref, PokemonFeature::Move(i.move_index)),
+            Instruction::ToggleTrickRoom(_) | Instruction::DecrementTrickRoomTurnsRemaining => {
+                ZobristFeature::TrickRoom
+            }
+            Instruction::ToggleSideOneForceSwitch => {
+                ZobristFeature::Side(SideReference::SideOne, SideFeature::ForceSwitch)
+            }
+            Instruction::ToggleSideTwoForceSwitch => {
+                ZobristFeature::Side(SideReference::SideTwo, SideFeature::ForceSwitch)
+            }
+            Instruction::ToggleTerastallized(i) => {
+                active(&i.side_ref, PokemonFeature::Terastallized)
+            }
+            Instruction::ToggleMegaEvolved(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::MegaEvolved)
+            }
+            Instruction::ToggleZMoveUsed(i) => {
+                ZobristFeature::Side(i.side_ref, SideFeature::ZMoveUsed)
+            }
+        }
+    }
+
+    /// Computes the zobrist hash of the state from scratch
+    ///
+    /// `State.zobrist_hash` is kept up to date by `apply_one_instruction` and
+    /// `reverse_one_instruction`. Use `refresh_zobrist_hash` after modifying the state's fields directly
+    pub fn compute_zobrist_hash(&self) -> u64 {
+        let mut hash = 0;
+        for feature in STATE_FEATURES {
+            hash ^= self.zobrist_feature_key(feature);
+        }
+        for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+            for side_feature in SIDE_FEATURES {
+                hash ^= self.zobrist_feature_key(ZobristFeature::Side(side_ref, side_feature));
+            }
+            for volatile_status in self.get_side_immutable(&side_ref).volatile_statuses.iter() {
+                hash ^= self.zobrist_feature_key(ZobristFeature::Side(
+                    side_ref,
+                    SideFeature::VolatileStatus(*volatile_status),
+                ));
+            }
+            for pokemon_index in pokemon_index_iter() {
+                for pokemon_feature in POKEMON_FEATURES {
+                    hash ^= self.zobrist_feature_key(ZobristFeature::Pokemon(
+                        side_ref,
+                        pokemon_index,
+                        pokemon_feature,
+                    ));
+                }
+            }
+        }
+        hash
+    }
+
+    // The part of the zobrist hash that comes from the fields of `side_ref` that belong to its
+    // active pokemon. Swapping those fields for another pokemon's changes the hash by this value
+    // before the swap XOR this value after it
+    #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+    pub(crate) fn active_slot_zobrist_hash(&self, side_ref: &SideReference) -> u64 {
+        let mut hash = 0;
+        for side_feature in ACTIVE_SLOT_FEATURES {
+            hash ^= self.zobrist_feature_key(ZobristFeature::Side(*side_ref, side_feature));
+        }
+        for volatile_status in self.get_side_immutable(side_ref).volatile_statuses.iter() {
+            hash ^= self.zobrist_feature_key(ZobristFeature::Side(
+                *side_ref,
+                SideFeature::VolatileStatus(*volatile_status),
+            ));
+        }
+        hash
+    }
+
+    pub fn refresh_zobrist_hash(&mut self) {
+        self.zobrist_hash = self.compute_zobrist_hash();
+    }
+
+    // Runs `modify_state`, which must only change the feature touched by `instruction`,
+    // and updates the zobrist hash accordingly
+    pub(crate) fn update_zobrist_hash_with<F: FnOnce(&mut State)>(
+        &mut self,
+        instruction: &Instruction,
+        modify_state: F,
+    ) {
+        let feature = self.zobrist_feature_of_instruction(instruction);
+        let key_before = self.zobrist_feature_key(feature);
+
+        #[cfg(feature = "debug_zobrist_hash")]
+        let full_hash_before = self.compute_zobrist_hash();
+
+        modify_state(self);
+        let key_after = self.zobrist_feature_key(feature);
+        self.zobrist_hash ^= key_before ^ key_after;
+
+        #[cfg(feature = "debug_zobrist_hash")]
+        assert_eq!(
+            full_hash_before ^ self.compute_zobrist_hash(),
+            key_before ^ key_after,
+            "zobrist hash update does not match a full recompute for {:?}",
+            instruction
+        );
+    }
+}
+
+// The zobrist key of a doubles slot B whose fields have the `active_slot_zobrist_hash` given
+#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+pub(crate) fn slot_b_zobrist_key(side_ref: &SideReference, active_slot_hash: u64) -> u64 {
+    zobrist_key(&[SLOT_B_SALT, *side_ref as u64, active_slot_hash])
+}
@@ -0,0 +1,105 @@
+use poke_engine::agent::{agent_from_str, Agent, GreedyDamageAgent, RandomAgent};
+use poke_engine::choices::Choices;
+#[cfg(feature = "gen7")]
+use poke_engine::engine::items::Items;
+use poke_engine::engine::state::MoveChoice;
+#[cfg(feature = "terastallization")]
+use poke_engine::state::PokemonType;
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, SideReference, State};
+
+fn state_with_moves(moves: [Choices; 2]) -> State {
+    let mut state = State::default();
+    let active = state.side_one.get_active();
+    active.replace_move(PokemonMoveIndex::M0, moves[0]);
+    active.replace_move(PokemonMoveIndex::M1, moves[1]);
+    state
+}
+
+#[test]
+fn test_greedy_damage_agent_picks_the_strongest_move() {
+    let state = state_with_moves([Choices::TACKLE, Choices::BODYSLAM]);
+    let options = [
+        MoveChoice::Move(PokemonMoveIndex::M0),
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        MoveChoice::Switch(PokemonIndex::P1),
+    ];
+    assert_eq!(
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        GreedyDamageAgent.choose_move(&state, SideReference::SideOne, &options)
+    );
+}
+
+#[test]
+fn test_greedy_damage_agent_picks_its_first_option_without_damaging_moves() {
+    let state = state_with_moves([Choices::SPLASH, Choices::GROWL]);
+    let options = [
+        MoveChoice::Switch(PokemonIndex::P1),
+        MoveChoice::Move(PokemonMoveIndex::M0),
+        MoveChoice::Move(PokemonMoveIndex::M1),
+    ];
+    assert_eq!(
+        MoveChoice::Switch(PokemonIndex::P1),
+        GreedyDamageAgent.choose_move(&state, SideReference::SideOne, &options)
+    );
+}
+
+#[cfg(feature = "terastallization")]
+#[test]
+fn test_greedy_damage_agent_scores_terastallizing() {
+    let mut state = state_with_moves([Choices::TACKLE, Choices::EMBER]);
+    state.side_one.get_active().tera_type = PokemonType::FIRE;
+    let options = [
+        MoveChoice::Move(PokemonMoveIndex::M0),
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        MoveChoice::MoveTera(PokemonMoveIndex::M1),
+    ];
+    assert_eq!(
+        MoveChoice::MoveTera(PokemonMoveIndex::M1),
+        GreedyDamageAgent.choose_move(&state, SideReference::SideOne, &options)
+    );
+}
+
+#[cfg(feature = "gen7")]
+#[test]
+fn test_greedy_damage_agent_scores_z_moves() {
+    let mut state = state_with_moves([Choices::TACKLE, Choices::BODYSLAM]);
+    state.side_one.get_active().item = Items::NORMALIUMZ;
+    let options = [
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        MoveChoice::MoveZ(PokemonMoveIndex::M0),
+    ];
+    assert_eq!(
+        MoveChoice::MoveZ(PokemonMoveIndex::M0),
+        GreedyDamageAgent.choose_move(&state, SideReference::SideOne, &options)
+    );
+}
+
+#[test]
+fn test_random_agent_only_picks_from_its_options() {
+    let state = State::default();
+    let options = [
+        MoveChoice::Switch(PokemonIndex::P2),
+        MoveChoice::Switch(PokemonIndex::P4),
+    ];
+    let mut agent = RandomAgent::new(0);
+    for _ in 0..20 {
+        assert!(options.contains(&agent.choose_move(&state, SideReference::SideTwo, &options)));
+    }
+}
+
+#[test]
+fn test_agent_from_str() {
+    for agent in [
+        "random",
+        "greedy-damage",
+        "expectiminimax",
+        "expectiminimax:3",
+        "iterative-deepening:100",
+        "mcts:100",
+    ] {
+        assert!(agent_from_str(agent, 0).is_ok(), "{}", agent);
+    }
+    for agent in ["", "minimax", "mcts:fast", "mcts:-1"] {
+        assert!(agent_from_str(agent, 0).is_err(), "{}", agent);
+    }
+}
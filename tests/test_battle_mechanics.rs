@@ -1,6 +1,7 @@
 #![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
 
 use poke_engine::choices::{Choices, MOVES};
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::abilities::{Abilities, WEATHER_ABILITY_TURNS};
 use poke_engine::engine::damage_calc::CRIT_MULTIPLIER;
 use poke_engine::engine::generate_instructions::{
@@ -31,6 +32,11 @@ use poke_engine::state::{
 #[cfg(feature = "terastallization")]
 use poke_engine::instruction::ToggleTerastallizedInstruction;
 
+#[cfg(feature = "gen9")]
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair_with_pruning_stats;
+#[cfg(feature = "gen9")]
+use poke_engine::instruction::PruningStats;
+
 #[cfg(not(feature = "terastallization"))]
 use poke_engine::state::LastUsedMove;
 
@@ -40,8 +46,13 @@ pub fn generate_instructions_with_state_assertion(
     side_two_move: &MoveChoice,
 ) -> Vec<StateInstructions> {
     let before_state_string = format!("{:?}", state);
-    let instructions =
-        generate_instructions_from_move_pair(state, side_one_move, side_two_move, false);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
     let after_state_string = format!("{:?}", state);
     assert_eq!(before_state_string, after_state_string);
     instructions
@@ -111,7 +122,8 @@ fn test_branch_on_crit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_damage = 32;
@@ -154,7 +166,8 @@ fn test_highcrit_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_damage = 44;
@@ -202,7 +215,8 @@ fn test_wickedblow_always_crits_without_a_branch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     assert_eq!(1, vec_of_instructions.len());
@@ -230,7 +244,8 @@ fn test_wickedblow_always_ignores_defensive_boost_on_opponent_because_of_crit()
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     assert_eq!(1, vec_of_instructions.len());
@@ -258,7 +273,8 @@ fn test_wickedblow_cannot_crit_on_shellarmor() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     assert_eq!(1, vec_of_instructions.len());
@@ -285,7 +301,8 @@ fn test_surgingstrikes_always_crits_without_a_branch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     assert_eq!(1, vec_of_instructions.len());
@@ -311,7 +328,8 @@ fn test_crit_does_not_overkill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_damage = 48;
@@ -498,7 +516,8 @@ fn test_branch_when_a_roll_can_kill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -544,7 +563,8 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -568,6 +588,180 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+#[cfg(feature = "gen9")]
+fn test_no_damage_branching_uses_the_average_roll() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 50;
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+
+    let vec_of_instructions = generate_instructions_from_move_pair(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        DamageBranching::None,
+        0.0,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 48,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_min_avg_max_damage_branching() {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+
+    let vec_of_instructions = generate_instructions_from_move_pair(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        DamageBranching::MinAvgMax,
+        0.0,
+    );
+
+    let damage_and_percentages: Vec<(i16, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| match i.instruction_list.as_slice() {
+            [Instruction::Damage(d)] => (d.damage_amount, i.percentage),
+            _ => panic!("unexpected instructions: {:?}", i),
+        })
+        .collect();
+    let expected = vec![
+        (44, 31.944445),
+        (48, 31.944445),
+        (52, 31.944445),
+        (66, 1.388889),
+        (72, 1.388889),
+        (78, 1.388889),
+    ];
+    assert_eq!(expected, damage_and_percentages);
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_all_rolls_damage_branching_merges_rolls_that_kill() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 50;
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+
+    let vec_of_instructions = generate_instructions_from_move_pair(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        DamageBranching::AllRolls,
+        0.0,
+    );
+
+    let damage_and_percentages: Vec<(i16, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| match i.instruction_list.as_slice() {
+            [Instruction::Damage(d)] => (d.damage_amount, i.percentage),
+            _ => panic!("unexpected instructions: {:?}", i),
+        })
+        .collect();
+    // rolls of 50 or more and every crit kill, and are merged into one branch
+    let expected = vec![
+        (44, 11.979166),
+        (45, 11.979166),
+        (46, 11.979166),
+        (47, 11.979166),
+        (48, 11.979166),
+        (49, 11.979166),
+        (50, 28.124989),
+    ];
+    assert_eq!(expected, damage_and_percentages);
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_prune_threshold_removes_crit_branches() {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+
+    let vec_of_instructions = generate_instructions_from_move_pair(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        DamageBranching::MinAvgMax,
+        5.0,
+    );
+
+    let damage_and_percentages: Vec<(i16, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| match i.instruction_list.as_slice() {
+            [Instruction::Damage(d)] => (d.damage_amount, i.percentage),
+            _ => panic!("unexpected instructions: {:?}", i),
+        })
+        .collect();
+    let expected = vec![(44, 33.33333), (48, 33.33333), (52, 33.33333)];
+    assert_eq!(expected, damage_and_percentages);
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_pruned_percentage_is_recorded() {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+
+    let mut pruning_stats = PruningStats::default();
+    let vec_of_instructions = generate_instructions_from_move_pair_with_pruning_stats(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        DamageBranching::MinAvgMax,
+        5.0,
+        &mut pruning_stats,
+    );
+
+    assert_eq!(3, vec_of_instructions.len());
+    assert_eq!(1, pruning_stats.move_pairs);
+    // the crit branches
+    assert_eq!(4.1666565, pruning_stats.average_pruned_percentage());
+}
+
 #[test]
 fn test_min_damage_killing_does_not_branch() {
     let mut state = State::default();
@@ -588,7 +782,8 @@ fn test_min_damage_killing_does_not_branch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -2829,121 +3024,404 @@ fn test_outrage_fatigue_causing_confusion() {
                 side_ref: SideReference::SideOne,
                 volatile_status: PokemonVolatileStatus::CONFUSION,
             }),
+            Instruction::ChangeVolatileStatusDuration(ChangeVolatileStatusDurationInstruction {
+                side_ref: SideReference::SideOne,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+                amount: 1,
+            }),
         ],
     }];
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
 #[test]
-fn test_judgement_typechange_with_arceus_multitype() {
+fn test_outrage_can_end_after_two_turns() {
     let mut state = State::default();
-    state.side_one.get_active().item = Items::SPOOKYPLATE;
-    state.side_two.get_active().types.0 = PokemonType::NORMAL;
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::LOCKEDMOVE);
+    state.side_one.volatile_status_durations.lockedmove = 1;
+    state.side_one.get_active().moves.m1.disabled = true;
+    state.side_one.get_active().moves.m2.disabled = true;
+    state.side_one.get_active().moves.m3.disabled = true;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
-        Choices::JUDGMENT,
+        Choices::OUTRAGE,
         Choices::SPLASH,
     );
 
-    let expected_instructions = vec![StateInstructions {
-        percentage: 100.0,
-        instruction_list: vec![],
-    }];
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 50.0,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 94,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                        amount: 1,
+                    },
+                ),
+            ],
+        },
+        StateInstructions {
+            percentage: 50.0,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 94,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                        amount: 1,
+                    },
+                ),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                        amount: -2,
+                    },
+                ),
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::LOCKEDMOVE,
+                }),
+                Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: 1,
+                    },
+                ),
+            ],
+        },
+    ];
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
 #[test]
-fn test_multiattack_typechange_with_silvally_drive() {
+#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
+fn test_confusion_does_not_end_after_one_turn() {
     let mut state = State::default();
-    state.side_one.get_active().item = Items::GHOSTMEMORY;
-    state.side_one.get_active().id = PokemonName::SILVALLYGHOST;
-    state.side_one.get_active().types.0 = PokemonType::GHOST;
-    state.side_two.get_active().types.0 = PokemonType::NORMAL;
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+    state.side_one.volatile_status_durations.confusion = 0;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
-        Choices::MULTIATTACK,
         Choices::SPLASH,
-    );
-
-    let expected_instructions = vec![StateInstructions {
-        percentage: 100.0,
-        instruction_list: vec![],
-    }];
-    assert_eq!(expected_instructions, vec_of_instructions);
-}
-
-#[test]
-fn test_haze_resets_both_side_boosts() {
-    let mut state = State::default();
-    state.side_one.attack_boost = 3;
-    state.side_one.defense_boost = -3;
-    state.side_two.special_attack_boost = 2;
-    state.side_two.special_defense_boost = -2;
-
-    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
-        &mut state,
-        Choices::HAZE,
         Choices::SPLASH,
     );
 
-    let expected_instructions = vec![StateInstructions {
-        percentage: 100.0,
-        instruction_list: vec![
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideOne,
-                stat: PokemonBoostableStat::Attack,
-                amount: -3,
-            }),
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideOne,
-                stat: PokemonBoostableStat::Defense,
-                amount: 3,
-            }),
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideTwo,
-                stat: PokemonBoostableStat::SpecialAttack,
-                amount: -2,
-            }),
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideTwo,
-                stat: PokemonBoostableStat::SpecialDefense,
-                amount: 2,
-            }),
-        ],
-    }];
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 33.333336,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideOne,
+                    damage_amount: 35,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: 1,
+                    },
+                ),
+            ],
+        },
+        StateInstructions {
+            percentage: 66.666664,
+            instruction_list: vec![Instruction::ChangeVolatileStatusDuration(
+                ChangeVolatileStatusDurationInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                    amount: 1,
+                },
+            )],
+        },
+    ];
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
 #[test]
-fn test_clearsmog_removes_boosts_on_target() {
+#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
+fn test_confusion_can_end_early() {
     let mut state = State::default();
-    state.side_one.attack_boost = 3;
-    state.side_one.defense_boost = -3;
-    state.side_two.special_attack_boost = -2;
-    state.side_two.special_defense_boost = 2;
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+    state.side_one.volatile_status_durations.confusion = 2;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
-        Choices::CLEARSMOG,
+        Choices::SPLASH,
         Choices::SPLASH,
     );
 
-    let expected_instructions = vec![StateInstructions {
-        percentage: 100.0,
-        instruction_list: vec![
-            Instruction::Damage(DamageInstruction {
-                side_ref: SideReference::SideTwo,
-                damage_amount: 21,
-            }),
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideTwo,
-                stat: PokemonBoostableStat::SpecialAttack,
-                amount: 2,
-            }),
-            Instruction::Boost(BoostInstruction {
-                side_ref: SideReference::SideTwo,
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 22.222223,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideOne,
+                    damage_amount: 35,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: 1,
+                    },
+                ),
+            ],
+        },
+        StateInstructions {
+            percentage: 44.44444,
+            instruction_list: vec![Instruction::ChangeVolatileStatusDuration(
+                ChangeVolatileStatusDurationInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                    amount: 1,
+                },
+            )],
+        },
+        StateInstructions {
+            percentage: 11.111113,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideOne,
+                    damage_amount: 35,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: 1,
+                    },
+                ),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: -3,
+                    },
+                ),
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 22.222221,
+            instruction_list: vec![
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: 1,
+                    },
+                ),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: -3,
+                    },
+                ),
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
+fn test_confusion_always_ends_after_5_turns() {
+    let mut state = State::default();
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+    state.side_one.volatile_status_durations.confusion = 4;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 33.333336,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideOne,
+                    damage_amount: 35,
+                }),
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: -4,
+                    },
+                ),
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 66.666664,
+            instruction_list: vec![
+                Instruction::ChangeVolatileStatusDuration(
+                    ChangeVolatileStatusDurationInstruction {
+                        side_ref: SideReference::SideOne,
+                        volatile_status: PokemonVolatileStatus::CONFUSION,
+                        amount: -4,
+                    },
+                ),
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: SideReference::SideOne,
+                    volatile_status: PokemonVolatileStatus::CONFUSION,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_judgement_typechange_with_arceus_multitype() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::SPOOKYPLATE;
+    state.side_two.get_active().types.0 = PokemonType::NORMAL;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::JUDGMENT,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_multiattack_typechange_with_silvally_drive() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::GHOSTMEMORY;
+    state.side_one.get_active().id = PokemonName::SILVALLYGHOST;
+    state.side_one.get_active().types.0 = PokemonType::GHOST;
+    state.side_two.get_active().types.0 = PokemonType::NORMAL;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::MULTIATTACK,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_haze_resets_both_side_boosts() {
+    let mut state = State::default();
+    state.side_one.attack_boost = 3;
+    state.side_one.defense_boost = -3;
+    state.side_two.special_attack_boost = 2;
+    state.side_two.special_defense_boost = -2;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::HAZE,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Attack,
+                amount: -3,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Defense,
+                amount: 3,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideTwo,
+                stat: PokemonBoostableStat::SpecialAttack,
+                amount: -2,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideTwo,
+                stat: PokemonBoostableStat::SpecialDefense,
+                amount: 2,
+            }),
+        ],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_clearsmog_removes_boosts_on_target() {
+    let mut state = State::default();
+    state.side_one.attack_boost = 3;
+    state.side_one.defense_boost = -3;
+    state.side_two.special_attack_boost = -2;
+    state.side_two.special_defense_boost = 2;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::CLEARSMOG,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![
+            Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 21,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideTwo,
+                stat: PokemonBoostableStat::SpecialAttack,
+                amount: 2,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideTwo,
                 stat: PokemonBoostableStat::SpecialDefense,
                 amount: -2,
             }),
@@ -6220,6 +6698,43 @@ fn test_switching_out_with_lockedmove_turns_resets() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_switching_out_with_confusion_turns_resets() {
+    let mut state = State::default();
+    state.side_one.volatile_status_durations.confusion = 2;
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+
+    let vec_of_instructions = generate_instructions_with_state_assertion(
+        &mut state,
+        &MoveChoice::Switch(PokemonIndex::P1),
+        &MoveChoice::None,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![
+            Instruction::ChangeVolatileStatusDuration(ChangeVolatileStatusDurationInstruction {
+                side_ref: SideReference::SideOne,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+                amount: -2,
+            }),
+            Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                side_ref: SideReference::SideOne,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+            }),
+            Instruction::Switch(SwitchInstruction {
+                side_ref: SideReference::SideOne,
+                previous_index: PokemonIndex::P0,
+                next_index: PokemonIndex::P1,
+            }),
+        ],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
 #[test]
 fn test_switching_from_batonpass_with_boosts() {
     let mut state = State::default();
@@ -14722,60 +15237,452 @@ fn test_skilllink_always_has_5_hits() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
-fn test_population_bomb_with_widelens() {
+#[cfg(not(feature = "gen4"))]
+fn test_two_to_five_hit_move_branches_on_number_of_hits() {
     let mut state = State::default();
-    state.side_one.get_active().item = Items::WIDELENS;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
-        Choices::POPULATIONBOMB,
+        Choices::BULLETSEED,
         Choices::SPLASH,
     );
 
-    let expected_instructions = vec![
-        StateInstructions {
-            percentage: 10.000002,
-            instruction_list: vec![],
-        },
-        StateInstructions {
-            percentage: 90.0,
-            instruction_list: vec![
-                Instruction::Damage(DamageInstruction {
-                    side_ref: SideReference::SideTwo,
-                    damage_amount: 24,
-                }),
-                Instruction::Damage(DamageInstruction {
-                    side_ref: SideReference::SideTwo,
-                    damage_amount: 24,
-                }),
-                Instruction::Damage(DamageInstruction {
-                    side_ref: SideReference::SideTwo,
-                    damage_amount: 24,
-                }),
-                Instruction::Damage(DamageInstruction {
-                    side_ref: SideReference::SideTwo,
-                    damage_amount: 24,
-                }),
-                Instruction::Damage(DamageInstruction {
-                    side_ref: SideReference::SideTwo,
-                    damage_amount: 4, // 24 * 4 = 96, 4 remaining on last hit
-                }),
-            ],
-        },
-    ];
-    assert_eq!(expected_instructions, vec_of_instructions);
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![(2, 35.0), (3, 35.0), (4, 15.000001), (5, 15.000001)],
+        hits_and_percentages
+    );
 }
 
 #[test]
-fn test_triple_multihit_move_versus_substitute_and_rockyhelmet() {
+#[cfg(feature = "gen4")]
+fn test_two_to_five_hit_move_branches_on_number_of_hits_in_gen4() {
     let mut state = State::default();
-    state.side_two.get_active().item = Items::ROCKYHELMET;
-    state.side_two.substitute_health = 25;
-    state
-        .side_two
-        .volatile_statuses
-        .insert(PokemonVolatileStatus::SUBSTITUTE);
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::BULLETSEED,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![(2, 37.5), (3, 37.5), (4, 12.5), (5, 12.5)],
+        hits_and_percentages
+    );
+}
+
+#[test]
+fn test_loadeddice_two_to_five_hit_move_hits_4_or_5_times() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::LOADEDDICE;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::BULLETSEED,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(vec![(4, 50.0), (5, 50.0)], hits_and_percentages);
+}
+
+#[test]
+#[cfg(not(feature = "gen4"))]
+fn test_number_of_hits_decides_whether_substitute_breaks() {
+    let mut state = State::default();
+    state.side_two.substitute_health = 50;
+    state
+        .side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::SUBSTITUTE);
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::BULLETSEED,
+        Choices::SPLASH,
+    );
+
+    let two_hits = vec![
+        Instruction::DamageSubstitute(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 21,
+        }),
+        Instruction::DamageSubstitute(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 21,
+        }),
+    ];
+    let mut three_hits = two_hits.clone();
+    three_hits.extend(vec![
+        Instruction::DamageSubstitute(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 8,
+        }),
+        Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+            side_ref: SideReference::SideTwo,
+            volatile_status: PokemonVolatileStatus::SUBSTITUTE,
+        }),
+    ]);
+    let mut four_hits = three_hits.clone();
+    four_hits.push(Instruction::Damage(DamageInstruction {
+        side_ref: SideReference::SideTwo,
+        damage_amount: 21,
+    }));
+    let mut five_hits = four_hits.clone();
+    five_hits.push(Instruction::Damage(DamageInstruction {
+        side_ref: SideReference::SideTwo,
+        damage_amount: 21,
+    }));
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 35.0,
+            instruction_list: two_hits,
+        },
+        StateInstructions {
+            percentage: 35.0,
+            instruction_list: three_hits,
+        },
+        StateInstructions {
+            percentage: 15.000001,
+            instruction_list: four_hits,
+        },
+        StateInstructions {
+            percentage: 15.000001,
+            instruction_list: five_hits,
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+#[test]
+fn test_multi_hit_branches_that_knock_out_the_defender_are_combined() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 5;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::BULLETSEED,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 5,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_widelens_boosts_accuracy() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::WIDELENS;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::FOCUSBLAST,
+        Choices::SPLASH,
+    );
+
+    // 70% accuracy becomes 77%
+    assert_eq!(2, vec_of_instructions.len());
+    assert!((vec_of_instructions[0].percentage - 23.0).abs() < 0.001);
+    assert!((vec_of_instructions[1].percentage - 77.0).abs() < 0.001);
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_population_bomb_with_widelens() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::WIDELENS;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::POPULATIONBOMB,
+        Choices::SPLASH,
+    );
+
+    // every hit has a 99% chance to hit, the 5th hit knocks out the defender
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, 0.99999905),
+            (1, 0.98999906),
+            (2, 0.9800991),
+            (3, 0.9702981),
+            (4, 0.9605952),
+            (5, 95.099014),
+        ],
+        hits_and_percentages
+    );
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_population_bomb_stops_at_the_first_miss() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::POPULATIONBOMB,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, 10.000002),
+            (1, 9.000002),
+            (2, 8.100001),
+            (3, 7.2900014),
+            (4, 6.5610013),
+            (5, 5.9049006),
+            (6, 5.3144107),
+            (7, 4.7829695),
+            (8, 4.3046722),
+            (9, 3.8742049),
+            (10, 34.867836),
+        ],
+        hits_and_percentages
+    );
+}
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_loadeddice_population_bomb_hits_4_to_10_times() {
+    let mut state = State::default();
+    state.side_one.get_active().item = Items::LOADEDDICE;
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::POPULATIONBOMB,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, 10.000002),
+            (4, 12.857143),
+            (5, 12.857143),
+            (6, 12.857143),
+            (7, 12.857143),
+            (8, 12.857143),
+            (9, 12.857143),
+            (10, 12.857143),
+        ],
+        hits_and_percentages
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen8", feature = "gen9"))]
+fn test_tripleaxel_gains_base_power_with_every_hit() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEAXEL,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 10.000002,
+            instruction_list: vec![],
+        },
+        StateInstructions {
+            percentage: 9.000002,
+            instruction_list: vec![Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 16,
+            })],
+        },
+        StateInstructions {
+            percentage: 8.100001,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 16,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 32,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 72.899994,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 16,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 32,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 48,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+#[cfg(any(feature = "gen8", feature = "gen9"))]
+fn test_tripleaxel_three_hits_do_as_much_damage_as_three_hits_of_40_base_power() {
+    // triple axel used to be three hits of 40 base power, like three hits of ice shard
+    fn damage_dealt(instructions: &StateInstructions) -> Vec<i16> {
+        instructions
+            .instruction_list
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Damage(damage) => Some(damage.damage_amount),
+                _ => None,
+            })
+            .collect()
+    }
+    let mut state = State::default();
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let ice_shard = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state.clone(),
+        Choices::ICESHARD,
+        Choices::SPLASH,
+    );
+    let triple_axel = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEAXEL,
+        Choices::SPLASH,
+    );
+
+    let ice_shard_damage = damage_dealt(&ice_shard[0]);
+    let three_hits_damage = damage_dealt(triple_axel.last().unwrap());
+    assert_eq!(1, ice_shard_damage.len());
+    assert_eq!(3, three_hits_damage.len());
+    assert_eq!(
+        3 * ice_shard_damage[0],
+        three_hits_damage.iter().sum::<i16>()
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen8", feature = "gen9"))]
+fn test_skilllink_tripleaxel_only_checks_accuracy_once() {
+    let mut state = State::default();
+    state.side_one.get_active().ability = Abilities::SKILLLINK;
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEAXEL,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(vec![(0, 10.000002), (3, 90.0),], hits_and_percentages);
+}
+
+#[test]
+fn test_triplekick_gains_base_power_with_every_hit() {
+    let mut state = State::default();
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEKICK,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 10.000002,
+            instruction_list: vec![],
+        },
+        StateInstructions {
+            percentage: 9.000002,
+            instruction_list: vec![Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 18,
+            })],
+        },
+        StateInstructions {
+            percentage: 8.100001,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 72.899994,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 49,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+#[test]
+fn test_triple_multihit_move_versus_substitute_and_rockyhelmet() {
+    let mut state = State::default();
+    state.side_two.get_active().item = Items::ROCKYHELMET;
+    state.side_two.substitute_health = 25;
+    state
+        .side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::SUBSTITUTE);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -14820,6 +15727,11 @@ fn test_scaleshot_only_boosts_once() {
         Choices::SCALESHOT,
         Choices::SPLASH,
     );
+    // gen4 uses the 3/8, 3/8, 1/8, 1/8 chances for 2 to 5 hits
+    #[cfg(feature = "gen4")]
+    let hit_count_percentages = [33.75, 33.75, 11.25, 11.25];
+    #[cfg(not(feature = "gen4"))]
+    let hit_count_percentages = [31.5, 31.5, 13.500001, 13.500001];
 
     let expected_instructions = vec![
         StateInstructions {
@@ -14827,7 +15739,30 @@ fn test_scaleshot_only_boosts_once() {
             instruction_list: vec![],
         },
         StateInstructions {
-            percentage: 90.0,
+            percentage: hit_count_percentages[0],
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Defense,
+                    amount: -1,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Speed,
+                    amount: 1,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: hit_count_percentages[1],
             instruction_list: vec![
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideTwo,
@@ -14853,6 +15788,72 @@ fn test_scaleshot_only_boosts_once() {
                 }),
             ],
         },
+        StateInstructions {
+            percentage: hit_count_percentages[2],
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Defense,
+                    amount: -1,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Speed,
+                    amount: 1,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: hit_count_percentages[3],
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 21,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 16,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Defense,
+                    amount: -1,
+                }),
+                Instruction::Boost(BoostInstruction {
+                    side_ref: SideReference::SideOne,
+                    stat: PokemonBoostableStat::Speed,
+                    amount: 1,
+                }),
+            ],
+        },
     ];
     assert_eq!(expected_instructions, vec_of_instructions);
 }
@@ -0,0 +1,23 @@
+use poke_engine::damage_branching::{DamageBranching, DamageBranchingSchedule};
+use std::str::FromStr;
+
+#[test]
+fn test_damage_branching_schedule_repeats_the_last_ply() {
+    let schedule = DamageBranchingSchedule::from_str("all-rolls,kill-no-kill").unwrap();
+
+    assert_eq!(DamageBranching::AllRolls, schedule.at_ply(0));
+    assert_eq!(DamageBranching::KillNoKill, schedule.at_ply(1));
+    assert_eq!(DamageBranching::KillNoKill, schedule.at_ply(5));
+}
+
+#[test]
+fn test_empty_damage_branching_schedule_does_not_branch() {
+    let schedule = DamageBranchingSchedule::new(vec![]);
+
+    assert_eq!(DamageBranching::None, schedule.at_ply(0));
+}
+
+#[test]
+fn test_invalid_damage_branching_schedule() {
+    assert!(DamageBranchingSchedule::from_str("kill-no-kill,some-rolls").is_err());
+}
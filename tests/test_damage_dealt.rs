@@ -1,6 +1,7 @@
 #![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
 
 use poke_engine::choices::{Choices, MoveCategory};
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Weather};
 use poke_engine::instruction::{
@@ -29,7 +30,8 @@ fn test_previous_damage_dealt_resets_and_then_goes_to_a_new_value() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -74,7 +76,8 @@ fn test_counter_after_physical_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -116,7 +119,8 @@ fn test_counter_cannot_hit_ghost_type() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -154,7 +158,8 @@ fn test_counter_reflects_special_hiddenpower() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -198,7 +203,8 @@ fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -237,7 +243,8 @@ fn test_metalburst_after_physical_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -278,7 +285,8 @@ fn test_comeuppance_after_physical_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -319,7 +327,8 @@ fn test_metalburst_after_special_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -370,7 +379,8 @@ fn test_metalburst_after_substitute_being_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -418,7 +428,8 @@ fn test_metalburst_fails_moving_first() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -455,7 +466,8 @@ fn test_metalburst_after_status_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -483,7 +495,8 @@ fn test_counter_after_special_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -525,7 +538,8 @@ fn test_mirrorcoat_after_special_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -571,7 +585,8 @@ fn test_mirrorcoat_after_physical_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -609,7 +624,8 @@ fn test_focuspunch_after_getting_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -651,7 +667,8 @@ fn test_focuspunch_after_substitute_getting_hit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -705,7 +722,8 @@ fn test_focuspunch_after_status_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -0,0 +1,666 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::doubles::{
+    generate_doubles_instructions, DoublesMoveChoice, DoublesSideChoice, DoublesSlot, DoublesState,
+    DoublesStateInstructions, DoublesTarget,
+};
+use poke_engine::engine::abilities::Abilities;
+use poke_engine::engine::items::Items;
+use poke_engine::engine::state::PokemonVolatileStatus;
+use poke_engine::instruction::Instruction;
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, SideReference, State};
+
+// side one's pokemon are faster than side two's, and slot A is faster than slot B
+fn doubles_state() -> DoublesState {
+    let mut state = State::default();
+    state.side_one.pokemon[PokemonIndex::P0].speed = 150;
+    state.side_one.pokemon[PokemonIndex::P1].speed = 140;
+    state.side_two.pokemon[PokemonIndex::P0].speed = 130;
+    state.side_two.pokemon[PokemonIndex::P1].speed = 120;
+    state.refresh_zobrist_hash();
+    DoublesState::new(state, PokemonIndex::P1, PokemonIndex::P1)
+}
+
+fn set_move(
+    doubles_state: &mut DoublesState,
+    side_ref: SideReference,
+    pokemon_index: PokemonIndex,
+    move_name: Choices,
+) {
+    doubles_state.state.get_side(&side_ref).pokemon[pokemon_index]
+        .replace_move(PokemonMoveIndex::M0, move_name);
+    doubles_state.state.refresh_zobrist_hash();
+}
+
+fn use_move(target: DoublesTarget) -> DoublesMoveChoice {
+    DoublesMoveChoice::Move(PokemonMoveIndex::M0, target)
+}
+
+fn generate_with_state_assertion(
+    doubles_state: &mut DoublesState,
+    side_one_choice: DoublesSideChoice,
+    side_two_choice: DoublesSideChoice,
+) -> Vec<DoublesStateInstructions> {
+    let before_state_string = format!("{:?}", doubles_state);
+    let instructions = generate_doubles_instructions(
+        doubles_state,
+        &side_one_choice,
+        &side_two_choice,
+        DamageBranching::None,
+    )
+    .unwrap();
+    assert_eq!(before_state_string, format!("{:?}", doubles_state));
+    instructions
+}
+
+// the (side, slot, damage) of every damage instruction in the only branch
+fn damage_dealt(
+    instructions: &[DoublesStateInstructions],
+) -> Vec<(SideReference, DoublesSlot, i16)> {
+    assert_eq!(1, instructions.len());
+    instructions[0]
+        .instruction_list
+        .iter()
+        .filter_map(|i| match &i.instruction {
+            Instruction::Damage(d) => {
+                let slot = match d.side_ref {
+                    SideReference::SideOne => i.focus.side_one,
+                    SideReference::SideTwo => i.focus.side_two,
+                };
+                Some((d.side_ref, slot, d.damage_amount))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_spread_move_hits_both_foes_for_reduced_damage() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::HYPERVOICE,
+    );
+    for pokemon_index in [PokemonIndex::P0, PokemonIndex::P1] {
+        let pokemon = &mut doubles_state.state.side_two.pokemon[pokemon_index];
+        pokemon.hp = 500;
+        pokemon.maxhp = 500;
+    }
+    let mut single_target_state = doubles_state.clone();
+    single_target_state.state.side_two.pokemon[PokemonIndex::P1].hp = 0;
+
+    let spread = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+        DoublesSideChoice::new(DoublesMoveChoice::None, DoublesMoveChoice::None),
+    );
+    let single = generate_with_state_assertion(
+        &mut single_target_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+        DoublesSideChoice::new(DoublesMoveChoice::None, DoublesMoveChoice::None),
+    );
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::A, 79),
+            (SideReference::SideTwo, DoublesSlot::B, 79)
+        ],
+        damage_dealt(&spread)
+    );
+    assert_eq!(
+        vec![(SideReference::SideTwo, DoublesSlot::A, 106)],
+        damage_dealt(&single)
+    );
+}
+
+#[test]
+fn test_protect_blocks_a_single_target_move() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P0,
+        Choices::PROTECT,
+    );
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+    );
+
+    assert_eq!(
+        Vec::<(SideReference, DoublesSlot, i16)>::new(),
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_protect_only_blocks_the_spread_move_for_the_protected_pokemon() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::HYPERVOICE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P0,
+        Choices::PROTECT,
+    );
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+    );
+
+    assert_eq!(
+        vec![(SideReference::SideTwo, DoublesSlot::B, 79)],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_wide_guard_blocks_spread_moves() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::HYPERVOICE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P0,
+        Choices::WIDEGUARD,
+    );
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+    );
+
+    assert_eq!(
+        Vec::<(SideReference, DoublesSlot, i16)>::new(),
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_follow_me_redirects_single_target_moves() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P1,
+        Choices::FOLLOWME,
+    );
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+        DoublesSideChoice::new(DoublesMoveChoice::None, use_move(DoublesTarget::Auto)),
+    );
+
+    assert_eq!(
+        vec![(SideReference::SideTwo, DoublesSlot::B, 48)],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_move_at_a_fainted_foe_hits_the_other_foe() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    doubles_state.state.side_two.pokemon[PokemonIndex::P0].hp = 0;
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+        DoublesSideChoice::new(DoublesMoveChoice::None, DoublesMoveChoice::None),
+    );
+
+    assert_eq!(
+        vec![(SideReference::SideTwo, DoublesSlot::B, 48)],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_faster_pokemon_moves_first_across_both_sides() {
+    let mut doubles_state = doubles_state();
+    for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+        for pokemon_index in [PokemonIndex::P0, PokemonIndex::P1] {
+            set_move(&mut doubles_state, side_ref, pokemon_index, Choices::TACKLE);
+        }
+    }
+    doubles_state.state.side_two.pokemon[PokemonIndex::P1].speed = 200;
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+        ),
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::B)),
+            use_move(DoublesTarget::Foe(DoublesSlot::B)),
+        ),
+    );
+
+    assert_eq!(
+        vec![
+            (SideReference::SideOne, DoublesSlot::B, 48),
+            (SideReference::SideTwo, DoublesSlot::A, 48),
+            (SideReference::SideTwo, DoublesSlot::A, 48),
+            (SideReference::SideOne, DoublesSlot::B, 48),
+        ],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_speed_tie_branches_on_every_order() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    doubles_state.state.side_two.pokemon[PokemonIndex::P0].speed = 150;
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+    );
+
+    let percentages: Vec<f32> = instructions.iter().map(|i| i.percentage).collect();
+    assert_eq!(vec![50.0, 50.0], percentages);
+}
+
+#[test]
+fn test_options_include_every_target_and_switch() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::TACKLE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P1,
+        Choices::HELPINGHAND,
+    );
+
+    let (side_one_options, _) = doubles_state.get_all_options();
+
+    assert_eq!(128, side_one_options.len());
+    assert!(side_one_options.contains(&DoublesSideChoice::new(
+        use_move(DoublesTarget::Foe(DoublesSlot::B)),
+        use_move(DoublesTarget::Ally),
+    )));
+    assert!(!side_one_options.contains(&DoublesSideChoice::new(
+        DoublesMoveChoice::Switch(PokemonIndex::P2),
+        DoublesMoveChoice::Switch(PokemonIndex::P2),
+    )));
+}
+
+#[test]
+fn test_only_fainted_pokemon_choose_on_a_replacement_turn() {
+    let mut doubles_state = doubles_state();
+    doubles_state.state.side_one.pokemon[PokemonIndex::P1].hp = 0;
+
+    let (side_one_options, side_two_options) = doubles_state.get_all_options();
+
+    assert_eq!(
+        vec![
+            DoublesSideChoice::new(
+                DoublesMoveChoice::None,
+                DoublesMoveChoice::Switch(PokemonIndex::P2)
+            ),
+            DoublesSideChoice::new(
+                DoublesMoveChoice::None,
+                DoublesMoveChoice::Switch(PokemonIndex::P3)
+            ),
+            DoublesSideChoice::new(
+                DoublesMoveChoice::None,
+                DoublesMoveChoice::Switch(PokemonIndex::P4)
+            ),
+            DoublesSideChoice::new(
+                DoublesMoveChoice::None,
+                DoublesMoveChoice::Switch(PokemonIndex::P5)
+            ),
+        ],
+        side_one_options
+    );
+    assert_eq!(
+        vec![DoublesSideChoice::new(
+            DoublesMoveChoice::None,
+            DoublesMoveChoice::None
+        )],
+        side_two_options
+    );
+}
+
+#[test]
+fn test_applying_and_reversing_instructions_restores_the_state() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::SWORDSDANCE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P1,
+        Choices::EARTHQUAKE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P0,
+        Choices::THUNDERWAVE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P1,
+        Choices::ICYWIND,
+    );
+    let before_state_string = format!("{:?}", doubles_state);
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), use_move(DoublesTarget::Auto)),
+        DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::B)),
+            use_move(DoublesTarget::Auto),
+        ),
+    );
+
+    for state_instructions in instructions.iter() {
+        doubles_state.apply_instructions(&state_instructions.instruction_list);
+        doubles_state.reverse_instructions(&state_instructions.instruction_list);
+        assert_eq!(before_state_string, format!("{:?}", doubles_state));
+    }
+    let total_percentage: f32 = instructions.iter().map(|i| i.percentage).sum();
+    assert!((total_percentage - 100.0).abs() < 0.001);
+}
+
+#[test]
+fn test_pivot_moves_are_not_options() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::UTURN,
+    );
+
+    let (side_one_options, _) = doubles_state.get_all_options();
+
+    assert!(!side_one_options
+        .iter()
+        .any(
+            |option| option.get(DoublesSlot::A) == use_move(DoublesTarget::Foe(DoublesSlot::A))
+                || option.get(DoublesSlot::A) == use_move(DoublesTarget::Foe(DoublesSlot::B))
+        ));
+}
+
+#[test]
+fn test_using_a_pivot_move_is_an_error() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::UTURN,
+    );
+
+    let result = generate_doubles_instructions(
+        &mut doubles_state,
+        &DoublesSideChoice::new(
+            use_move(DoublesTarget::Foe(DoublesSlot::A)),
+            DoublesMoveChoice::None,
+        ),
+        &DoublesSideChoice::new(DoublesMoveChoice::None, DoublesMoveChoice::None),
+        DamageBranching::None,
+    );
+
+    assert_eq!(
+        Err("UTURN switches the user out, which is not supported in doubles".to_string()),
+        result
+    );
+}
+
+// side one's slot A uses earthquake, which hits both foes and its ally in slot B
+fn earthquake_state() -> DoublesState {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P0,
+        Choices::EARTHQUAKE,
+    );
+    for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+        for pokemon_index in [PokemonIndex::P0, PokemonIndex::P1] {
+            let pokemon = &mut doubles_state.state.get_side(&side_ref).pokemon[pokemon_index];
+            pokemon.hp = 500;
+            pokemon.maxhp = 500;
+        }
+    }
+    doubles_state.state.refresh_zobrist_hash();
+    doubles_state
+}
+
+fn use_earthquake(doubles_state: &mut DoublesState) -> Vec<DoublesStateInstructions> {
+    generate_with_state_assertion(
+        doubles_state,
+        DoublesSideChoice::new(use_move(DoublesTarget::Auto), DoublesMoveChoice::None),
+        DoublesSideChoice::new(DoublesMoveChoice::None, DoublesMoveChoice::None),
+    )
+}
+
+#[test]
+fn test_spread_move_hits_the_ally_like_a_foe() {
+    let mut doubles_state = earthquake_state();
+
+    let instructions = use_earthquake(&mut doubles_state);
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::A, 59),
+            (SideReference::SideTwo, DoublesSlot::B, 59),
+            (SideReference::SideOne, DoublesSlot::B, 59),
+        ],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_spread_move_does_not_hit_an_ally_immune_to_it() {
+    let mut doubles_state = earthquake_state();
+    doubles_state.state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::LEVITATE;
+    doubles_state.state.refresh_zobrist_hash();
+
+    let instructions = use_earthquake(&mut doubles_state);
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::A, 59),
+            (SideReference::SideTwo, DoublesSlot::B, 59),
+        ],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_spread_move_hits_the_other_targets_when_the_first_foe_is_immune() {
+    let mut doubles_state = earthquake_state();
+    doubles_state.state.side_two.pokemon[PokemonIndex::P0].ability = Abilities::LEVITATE;
+    doubles_state.state.refresh_zobrist_hash();
+
+    let instructions = use_earthquake(&mut doubles_state);
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::B, 59),
+            (SideReference::SideOne, DoublesSlot::B, 59),
+        ],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_ally_focus_sash_survives_a_spread_move() {
+    let mut doubles_state = earthquake_state();
+    let ally = &mut doubles_state.state.side_one.pokemon[PokemonIndex::P1];
+    ally.hp = 50;
+    ally.maxhp = 50;
+    ally.item = Items::FOCUSSASH;
+    doubles_state.state.refresh_zobrist_hash();
+
+    let instructions = use_earthquake(&mut doubles_state);
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::A, 59),
+            (SideReference::SideTwo, DoublesSlot::B, 59),
+            (SideReference::SideOne, DoublesSlot::B, 49),
+        ],
+        damage_dealt(&instructions)
+    );
+}
+
+#[test]
+fn test_ally_substitute_takes_the_spread_move() {
+    let mut doubles_state = earthquake_state();
+    doubles_state
+        .side_one_slot_b
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::SUBSTITUTE);
+    doubles_state.side_one_slot_b.substitute_health = 125;
+
+    let instructions = use_earthquake(&mut doubles_state);
+
+    assert_eq!(
+        vec![
+            (SideReference::SideTwo, DoublesSlot::A, 59),
+            (SideReference::SideTwo, DoublesSlot::B, 59),
+        ],
+        damage_dealt(&instructions)
+    );
+    assert!(instructions[0]
+        .instruction_list
+        .iter()
+        .any(|i| i.focus.side_one == DoublesSlot::B
+            && matches!(
+                &i.instruction,
+                Instruction::DamageSubstitute(d)
+                    if d.side_ref == SideReference::SideOne && d.damage_amount == 59
+            )));
+}
+
+#[test]
+fn test_doubles_zobrist_hash_covers_slot_b() {
+    let mut doubles_state = doubles_state();
+    let mut boosted_slot_b = doubles_state.clone();
+    boosted_slot_b.side_one_slot_b.attack_boost = 2;
+
+    assert_ne!(doubles_state.zobrist_hash(), boosted_slot_b.zobrist_hash());
+    assert_eq!(
+        doubles_state.state.zobrist_hash,
+        boosted_slot_b.state.zobrist_hash
+    );
+}
+
+// run with the `debug_zobrist_hash` feature to check every incremental update as well
+#[test]
+fn test_zobrist_hash_matches_the_state_after_applying_slot_b_instructions() {
+    let mut doubles_state = doubles_state();
+    set_move(
+        &mut doubles_state,
+        SideReference::SideOne,
+        PokemonIndex::P1,
+        Choices::SWORDSDANCE,
+    );
+    set_move(
+        &mut doubles_state,
+        SideReference::SideTwo,
+        PokemonIndex::P1,
+        Choices::TACKLE,
+    );
+    let initial_hash = doubles_state.state.zobrist_hash;
+
+    let instructions = generate_with_state_assertion(
+        &mut doubles_state,
+        DoublesSideChoice::new(DoublesMoveChoice::None, use_move(DoublesTarget::Auto)),
+        DoublesSideChoice::new(
+            DoublesMoveChoice::None,
+            use_move(DoublesTarget::Foe(DoublesSlot::B)),
+        ),
+    );
+
+    for state_instructions in instructions.iter() {
+        doubles_state.apply_instructions(&state_instructions.instruction_list);
+        assert_eq!(
+            doubles_state.state.compute_zobrist_hash(),
+            doubles_state.state.zobrist_hash
+        );
+        doubles_state.reverse_instructions(&state_instructions.instruction_list);
+        assert_eq!(initial_hash, doubles_state.state.zobrist_hash);
+    }
+}
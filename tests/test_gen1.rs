@@ -1,6 +1,7 @@
 #![cfg(feature = "gen1")]
 
 use poke_engine::choices::{Choices, MOVES};
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::generate_instructions::{
     generate_instructions_from_move_pair, moves_first, MAX_SLEEP_TURNS,
 };
@@ -23,8 +24,13 @@ pub fn generate_instructions_with_state_assertion(
     side_two_move: &MoveChoice,
 ) -> Vec<StateInstructions> {
     let before_state_string = format!("{:?}", state);
-    let instructions =
-        generate_instructions_from_move_pair(state, side_one_move, side_two_move, false);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
     let after_state_string = format!("{:?}", state);
     assert_eq!(before_state_string, after_state_string);
     instructions
@@ -1065,7 +1071,8 @@ fn test_counter_hits_ghost_type() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -1327,3 +1334,29 @@ fn test_mustrecharge_move_only_allows_none() {
     );
     assert_eq!(expected_options, options);
 }
+
+#[test]
+fn test_two_to_five_hit_move_branches_on_number_of_hits() {
+    let mut state = State::default();
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::FURYATTACK,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, 14.999998),
+            (2, 31.875),
+            (3, 31.875),
+            (4, 10.625),
+            (5, 10.625)
+        ],
+        hits_and_percentages
+    );
+}
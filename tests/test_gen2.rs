@@ -1,6 +1,7 @@
 #![cfg(feature = "gen2")]
 
 use poke_engine::choices::{Choices, MoveCategory};
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::items::Items;
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus};
@@ -22,8 +23,13 @@ pub fn generate_instructions_with_state_assertion(
     side_two_move: &MoveChoice,
 ) -> Vec<StateInstructions> {
     let before_state_string = format!("{:?}", state);
-    let instructions =
-        generate_instructions_from_move_pair(state, side_one_move, side_two_move, false);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
     let after_state_string = format!("{:?}", state);
     assert_eq!(before_state_string, after_state_string);
     instructions
@@ -71,7 +77,8 @@ fn test_branch_when_a_roll_can_kill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -115,7 +122,8 @@ fn test_branch_on_crit() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_instructions = vec![
@@ -157,7 +165,8 @@ fn test_highcrit_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_instructions = vec![
@@ -203,7 +212,8 @@ fn test_crit_does_not_overkill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_instructions = vec![
@@ -245,7 +255,8 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -291,7 +302,8 @@ fn test_min_damage_killing_does_not_branch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -1343,7 +1355,8 @@ fn test_counter_cannot_hit_ghost_type() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -1380,7 +1393,8 @@ fn test_counter_reflects_special_hiddenpower() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -1426,7 +1440,8 @@ fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -1548,3 +1563,87 @@ fn test_mustrecharge_move_only_allows_none() {
     );
     assert_eq!(expected_options, options);
 }
+
+#[test]
+fn test_two_to_five_hit_move_branches_on_number_of_hits() {
+    let mut state = State::default();
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::FURYATTACK,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, 14.999998),
+            (2, 31.875),
+            (3, 31.875),
+            (4, 10.625),
+            (5, 10.625)
+        ],
+        hits_and_percentages
+    );
+}
+
+#[test]
+fn test_triplekick_stops_at_the_first_miss() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEKICK,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 10.000002,
+            instruction_list: vec![],
+        },
+        StateInstructions {
+            percentage: 9.000002,
+            instruction_list: vec![Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 18,
+            })],
+        },
+        StateInstructions {
+            percentage: 8.100001,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 72.899994,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 49,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
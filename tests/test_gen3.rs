@@ -1,6 +1,7 @@
 #![cfg(feature = "gen3")]
 
 use poke_engine::choices::{Choices, MOVES};
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::abilities::Abilities;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::items::Items;
@@ -21,8 +22,13 @@ pub fn generate_instructions_with_state_assertion(
     side_two_move: &MoveChoice,
 ) -> Vec<StateInstructions> {
     let before_state_string = format!("{:?}", state);
-    let instructions =
-        generate_instructions_from_move_pair(state, side_one_move, side_two_move, false);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
     let after_state_string = format!("{:?}", state);
     assert_eq!(before_state_string, after_state_string);
     instructions
@@ -377,7 +383,8 @@ fn test_branch_when_a_roll_can_kill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -548,7 +555,8 @@ fn test_gen3_branch_when_a_roll_can_kill() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        true,
+        DamageBranching::KillNoKill,
+        0.0,
     );
 
     // This damage roll is 44-52, so it can kill
@@ -572,3 +580,113 @@ fn test_gen3_branch_when_a_roll_can_kill() {
     ];
     assert_eq!(expected_instructions, vec_of_instructions);
 }
+
+#[test]
+fn test_two_to_five_hit_move_branches_on_number_of_hits() {
+    let mut state = State::default();
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::BULLETSEED,
+        Choices::SPLASH,
+    );
+
+    let hits_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(
+        vec![(2, 37.5), (3, 37.5), (4, 12.5), (5, 12.5)],
+        hits_and_percentages
+    );
+}
+
+#[test]
+fn test_triplekick_stops_at_the_first_miss() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = 500;
+    state.side_two.get_active().maxhp = 500;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::TRIPLEKICK,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![
+        StateInstructions {
+            percentage: 10.000002,
+            instruction_list: vec![],
+        },
+        StateInstructions {
+            percentage: 9.000002,
+            instruction_list: vec![Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 18,
+            })],
+        },
+        StateInstructions {
+            percentage: 8.100001,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+            ],
+        },
+        StateInstructions {
+            percentage: 72.899994,
+            instruction_list: vec![
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 18,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 33,
+                }),
+                Instruction::Damage(DamageInstruction {
+                    side_ref: SideReference::SideTwo,
+                    damage_amount: 49,
+                }),
+            ],
+        },
+    ];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_outrage_can_end_after_two_turns() {
+    let mut state = State::default();
+    state
+        .side_one
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::LOCKEDMOVE);
+    state.side_one.volatile_status_durations.lockedmove = 1;
+    state.side_one.get_active().moves.m1.disabled = true;
+    state.side_one.get_active().moves.m2.disabled = true;
+    state.side_one.get_active().moves.m3.disabled = true;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::OUTRAGE,
+        Choices::SPLASH,
+    );
+
+    let lengths_and_percentages: Vec<(usize, f32)> = vec_of_instructions
+        .iter()
+        .map(|i| (i.instruction_list.len(), i.percentage))
+        .collect();
+    assert_eq!(vec![(2, 50.0), (6, 50.0)], lengths_and_percentages);
+    assert_eq!(
+        Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+            side_ref: SideReference::SideOne,
+            volatile_status: PokemonVolatileStatus::CONFUSION,
+        }),
+        vec_of_instructions[1].instruction_list[4]
+    );
+}
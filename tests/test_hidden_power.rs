@@ -0,0 +1,97 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{
+    hidden_power_from_ivs, Pokemon, PokemonMoveIndex, PokemonNature, PokemonType, State,
+};
+
+#[test]
+fn test_perfect_ivs_give_hidden_power_dark() {
+    let (hidden_power_type, base_power) = hidden_power_from_ivs((31, 31, 31, 31, 31, 31));
+    assert_eq!(PokemonType::DARK, hidden_power_type);
+    #[cfg(any(feature = "gen4", feature = "gen5"))]
+    assert_eq!(70.0, base_power);
+    #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+    assert_eq!(60.0, base_power);
+}
+
+#[test]
+fn test_hidden_power_type_from_ivs() {
+    assert_eq!(
+        PokemonType::FIRE,
+        hidden_power_from_ivs((31, 30, 31, 30, 31, 30)).0
+    );
+    assert_eq!(
+        PokemonType::ICE,
+        hidden_power_from_ivs((31, 30, 30, 31, 31, 31)).0
+    );
+    assert_eq!(
+        PokemonType::FIGHTING,
+        hidden_power_from_ivs((31, 31, 30, 30, 30, 30)).0
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen4", feature = "gen5"))]
+fn test_hidden_power_base_power_from_ivs() {
+    // the second lowest bit of every IV is unset
+    assert_eq!(30.0, hidden_power_from_ivs((0, 0, 0, 0, 0, 0)).1);
+    assert_eq!(68.0, hidden_power_from_ivs((31, 0, 31, 31, 31, 31)).1);
+}
+
+#[test]
+fn test_generic_hidden_power_move_uses_the_pokemons_ivs() {
+    let mut pkmn = Pokemon::default();
+    pkmn.ivs = (31, 30, 30, 31, 31, 31);
+    pkmn.replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWER);
+    assert_eq!(PokemonType::ICE, pkmn.moves.m0.choice.move_type);
+
+    pkmn.ivs = (31, 30, 31, 30, 31, 30);
+    pkmn.refresh_hidden_power();
+    assert_eq!(PokemonType::FIRE, pkmn.moves.m0.choice.move_type);
+}
+
+#[test]
+fn test_fixed_hidden_power_variants_ignore_ivs() {
+    let mut pkmn = Pokemon::default();
+    pkmn.ivs = (31, 30, 30, 31, 31, 31);
+    pkmn.replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWERFIRE70);
+    assert_eq!(PokemonType::FIRE, pkmn.moves.m0.choice.move_type);
+    assert_eq!(70.0, pkmn.moves.m0.choice.base_power);
+}
+
+#[test]
+fn test_deserialized_hidden_power_uses_the_ivs() {
+    let mut state = State::default();
+    state.side_one.get_active().ivs = (31, 30, 30, 31, 31, 31);
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWER);
+
+    let deserialized = State::deserialize(&state.serialize());
+    assert_eq!(
+        PokemonType::ICE,
+        deserialized
+            .side_one
+            .get_active_immutable()
+            .moves
+            .m0
+            .choice
+            .move_type
+    );
+}
+
+#[test]
+fn test_zero_speed_ivs_lower_the_speed_stat() {
+    let mut pkmn = Pokemon::from_species(
+        PokemonName::CONKELDURR,
+        100,
+        (252, 252, 0, 0, 4, 0),
+        PokemonNature::BRAVE,
+    );
+    assert_eq!(113, pkmn.speed);
+    pkmn.ivs = (31, 31, 31, 31, 31, 0);
+    assert_eq!(85, pkmn.calculate_stats_from_base_stats().5);
+}
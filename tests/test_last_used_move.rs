@@ -1,6 +1,7 @@
 #![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
 
 use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::instruction::{
     ApplyVolatileStatusInstruction, DamageInstruction, Instruction,
@@ -32,7 +33,8 @@ fn test_last_used_move_is_set_on_switch() {
         &mut state,
         &MoveChoice::Switch(PokemonIndex::P1),
         &MoveChoice::None,
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -70,7 +72,8 @@ fn test_last_used_move_is_set_on_move() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -123,7 +126,8 @@ fn test_last_used_move_overwritten_when_dragged_out() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -235,7 +239,8 @@ fn test_encore_slow() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -303,7 +308,8 @@ fn test_encore_slow_into_substitute() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -380,7 +386,8 @@ fn test_encore_fast_fails_with_lastusedmove_equal_to_switch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -429,7 +436,8 @@ fn test_encore_fast_fails_with_lastusedmove_equal_to_none() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -470,7 +478,8 @@ fn test_encore_second_fails_when_opponent_switches() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Switch(PokemonIndex::P1),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -522,7 +531,8 @@ fn test_fast_encore_into_using_a_different_move_from_lum() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -584,7 +594,8 @@ fn test_encore_expires_at_2_turns() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -641,7 +652,8 @@ fn test_encore_counter_increment() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -684,7 +696,8 @@ fn test_fakeout_first_turn_switched_in() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -734,7 +747,8 @@ fn test_fakeout_with_last_used_move_of_non_switch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -775,7 +789,8 @@ fn test_firstimpression_first_turn_switched_in() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
@@ -825,7 +840,8 @@ fn test_firstimpression_with_last_used_move_of_non_switch() {
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M0),
         &MoveChoice::Move(PokemonMoveIndex::M0),
-        false,
+        DamageBranching::None,
+        0.0,
     );
 
     let expected_instructions = vec![StateInstructions {
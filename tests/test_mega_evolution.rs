@@ -0,0 +1,202 @@
+#![cfg(any(feature = "gen6", feature = "gen7"))]
+
+use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::abilities::Abilities;
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
+use poke_engine::engine::items::Items;
+use poke_engine::engine::state::{MoveChoice, Weather};
+use poke_engine::instruction::{
+    ChangeAbilityInstruction, ChangeType, FormeChangeInstruction, Instruction, StateInstructions,
+    ToggleMegaEvolvedInstruction,
+};
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{
+    Pokemon, PokemonIndex, PokemonMoveIndex, PokemonNature, PokemonType, SideReference, State,
+};
+
+fn state_with_side_one_active(id: PokemonName, item: Items) -> State {
+    let mut state = State::default();
+    let mut pkmn = Pokemon::from_species(id, 100, (85, 85, 85, 85, 85, 85), PokemonNature::SERIOUS);
+    pkmn.item = item;
+    pkmn.replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    pkmn.replace_move(PokemonMoveIndex::M1, Choices::SPLASH);
+    state.side_one.pokemon[PokemonIndex::P0] = pkmn;
+    state
+}
+
+fn generate_instructions(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+) -> Vec<StateInstructions> {
+    let before_state_string = format!("{:?}", state);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
+    assert_eq!(before_state_string, format!("{:?}", state));
+    instructions
+}
+
+// the side that the first damaging move of the turn hits
+fn first_damaged_side(instructions: &StateInstructions) -> SideReference {
+    instructions
+        .instruction_list
+        .iter()
+        .find_map(|instruction| match instruction {
+            Instruction::Damage(damage) => Some(damage.side_ref),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_mega_evolution_options_for_a_pokemon_holding_its_mega_stone() {
+    let state = state_with_side_one_active(PokemonName::CHARIZARD, Items::CHARIZARDITEX);
+    let (side_one_options, side_two_options) = state.get_all_options();
+
+    assert!(side_one_options.contains(&MoveChoice::MoveMega(PokemonMoveIndex::M0)));
+    assert!(side_one_options.contains(&MoveChoice::MoveMega(PokemonMoveIndex::M1)));
+    assert!(!side_two_options
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveMega(_))));
+}
+
+#[test]
+fn test_no_mega_evolution_options_without_the_matching_mega_stone() {
+    let state = state_with_side_one_active(PokemonName::CHARIZARD, Items::VENUSAURITE);
+    let (side_one_options, _) = state.get_all_options();
+
+    assert!(!side_one_options
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveMega(_))));
+}
+
+#[test]
+fn test_no_mega_evolution_options_after_the_side_has_mega_evolved() {
+    let mut state = state_with_side_one_active(PokemonName::CHARIZARD, Items::CHARIZARDITEX);
+    state.side_one.mega_evolved = true;
+    let (side_one_options, _) = state.get_all_options();
+
+    assert!(!side_one_options
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveMega(_))));
+}
+
+#[test]
+fn test_mega_evolving_changes_forme_types_and_ability() {
+    let mut state = state_with_side_one_active(PokemonName::CHARIZARD, Items::CHARIZARDITEX);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveMega(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+    );
+    assert_eq!(1, instructions.len());
+
+    let instruction_list = &instructions[0].instruction_list;
+    assert_eq!(
+        Instruction::ToggleMegaEvolved(ToggleMegaEvolvedInstruction {
+            side_ref: SideReference::SideOne,
+        }),
+        instruction_list[0]
+    );
+    assert_eq!(
+        Instruction::FormeChange(FormeChangeInstruction {
+            side_ref: SideReference::SideOne,
+            name_change: PokemonName::CHARIZARDMEGAX as i16 - PokemonName::CHARIZARD as i16,
+        }),
+        instruction_list[1]
+    );
+    assert!(
+        instruction_list.contains(&Instruction::ChangeType(ChangeType {
+            side_ref: SideReference::SideOne,
+            new_types: (PokemonType::FIRE, PokemonType::DRAGON),
+            old_types: (PokemonType::FIRE, PokemonType::FLYING),
+        }))
+    );
+    assert!(
+        instruction_list.contains(&Instruction::ChangeBaseAbility(ChangeAbilityInstruction {
+            side_ref: SideReference::SideOne,
+            ability_change: Abilities::TOUGHCLAWS as i16 - Abilities::BLAZE as i16,
+        }))
+    );
+
+    state.apply_instructions(instruction_list);
+    let active = state.side_one.get_active_immutable();
+    assert!(state.side_one.mega_evolved);
+    assert_eq!(PokemonName::CHARIZARDMEGAX, active.id);
+    assert_eq!(Abilities::TOUGHCLAWS, active.ability);
+    assert_eq!(Abilities::TOUGHCLAWS, active.base_ability);
+    assert_eq!(active.calculate_stats_from_base_stats().1, active.attack);
+}
+
+#[test]
+fn test_mega_evolving_activates_the_switch_in_ability_of_the_mega_forme() {
+    let mut state = state_with_side_one_active(PokemonName::CHARIZARD, Items::CHARIZARDITEY);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveMega(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+
+    state.apply_instructions(&instructions[0].instruction_list);
+    assert_eq!(
+        Abilities::DROUGHT,
+        state.side_one.get_active_immutable().ability
+    );
+    assert_eq!(Weather::SUN, state.weather.weather_type);
+}
+
+#[test]
+fn test_rayquaza_mega_evolves_with_dragon_ascent_instead_of_a_mega_stone() {
+    let mut state = state_with_side_one_active(PokemonName::RAYQUAZA, Items::LEFTOVERS);
+    assert!(!state.side_one.can_mega_evolve());
+
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M2, Choices::DRAGONASCENT);
+    assert!(state.side_one.can_mega_evolve());
+}
+
+#[test]
+#[cfg(feature = "gen6")]
+fn test_gen6_turn_order_uses_the_speed_from_before_mega_evolving() {
+    // mega lopunny is faster than the opponent, lopunny is not
+    let mut state = state_with_side_one_active(PokemonName::LOPUNNY, Items::LOPUNNITE);
+    state.side_two.get_active().speed = 300;
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveMega(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+    );
+
+    assert_eq!(SideReference::SideOne, first_damaged_side(&instructions[0]));
+}
+
+#[test]
+#[cfg(feature = "gen7")]
+fn test_gen7_turn_order_uses_the_speed_of_the_mega_forme() {
+    // mega lopunny is faster than the opponent, lopunny is not
+    let mut state = state_with_side_one_active(PokemonName::LOPUNNY, Items::LOPUNNITE);
+    state.side_two.get_active().speed = 300;
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveMega(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+    );
+
+    assert_eq!(SideReference::SideTwo, first_damaged_side(&instructions[0]));
+}
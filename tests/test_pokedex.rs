@@ -0,0 +1,123 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::engine::abilities::Abilities;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{Pokemon, PokemonBoostableStat, PokemonNature, PokemonType};
+
+#[test]
+fn test_species_types_and_weight() {
+    assert_eq!(
+        (PokemonType::DRAGON, PokemonType::GROUND),
+        PokemonName::GARCHOMP.types()
+    );
+    assert_eq!(
+        (PokemonType::WATER, PokemonType::TYPELESS),
+        PokemonName::VAPOREON.types()
+    );
+    assert_eq!(95.0, PokemonName::GARCHOMP.weight_kg());
+}
+
+#[test]
+#[cfg(any(feature = "gen4", feature = "gen5"))]
+fn test_fairy_type_does_not_exist_before_gen6() {
+    assert_eq!(
+        (PokemonType::NORMAL, PokemonType::TYPELESS),
+        PokemonName::CLEFABLE.types()
+    );
+    assert_eq!((95, 70, 73, 85, 90, 60), PokemonName::CLEFABLE.base_stats());
+}
+
+#[test]
+#[cfg(not(any(feature = "gen4", feature = "gen5")))]
+fn test_fairy_type_from_gen6() {
+    assert_eq!(
+        (PokemonType::FAIRY, PokemonType::TYPELESS),
+        PokemonName::CLEFABLE.types()
+    );
+    assert_eq!((95, 70, 73, 95, 90, 60), PokemonName::CLEFABLE.base_stats());
+}
+
+#[test]
+fn test_species_abilities() {
+    let abilities = PokemonName::GARCHOMP.abilities();
+    assert_eq!(Abilities::SANDVEIL, abilities[0]);
+    assert_eq!(
+        Some(Abilities::ROUGHSKIN),
+        PokemonName::GARCHOMP.hidden_ability()
+    );
+
+    #[cfg(feature = "gen4")]
+    assert_eq!(vec![Abilities::SANDVEIL], abilities);
+    #[cfg(not(feature = "gen4"))]
+    assert_eq!(vec![Abilities::SANDVEIL, Abilities::ROUGHSKIN], abilities);
+}
+
+#[test]
+fn test_nature_stat_modifiers() {
+    assert_eq!(
+        Some((
+            PokemonBoostableStat::Speed,
+            PokemonBoostableStat::SpecialAttack
+        )),
+        PokemonNature::JOLLY.stat_modifiers()
+    );
+    assert_eq!(None, PokemonNature::SERIOUS.stat_modifiers());
+    assert_eq!(
+        333,
+        PokemonNature::JOLLY.modify_stat(303, PokemonBoostableStat::Speed)
+    );
+    assert_eq!(
+        176,
+        PokemonNature::JOLLY.modify_stat(196, PokemonBoostableStat::SpecialAttack)
+    );
+    assert_eq!(
+        359,
+        PokemonNature::JOLLY.modify_stat(359, PokemonBoostableStat::Attack)
+    );
+}
+
+#[test]
+fn test_pokemon_from_species() {
+    let pkmn = Pokemon::from_species(
+        PokemonName::GARCHOMP,
+        100,
+        (4, 252, 0, 0, 0, 252),
+        PokemonNature::JOLLY,
+    );
+    assert_eq!(358, pkmn.hp);
+    assert_eq!(358, pkmn.maxhp);
+    assert_eq!(359, pkmn.attack);
+    assert_eq!(226, pkmn.defense);
+    assert_eq!(176, pkmn.special_attack);
+    assert_eq!(206, pkmn.special_defense);
+    assert_eq!(333, pkmn.speed);
+    assert_eq!((PokemonType::DRAGON, PokemonType::GROUND), pkmn.types);
+    assert_eq!(pkmn.types, pkmn.base_types);
+    assert_eq!(Abilities::SANDVEIL, pkmn.ability);
+    assert_eq!(95.0, pkmn.weight_kg);
+}
+
+#[test]
+fn test_pokemon_from_species_at_a_lower_level() {
+    let pkmn = Pokemon::from_species(
+        PokemonName::PIKACHU,
+        50,
+        (0, 0, 0, 0, 0, 0),
+        PokemonNature::HARDY,
+    );
+    #[cfg(any(feature = "gen4", feature = "gen5"))]
+    assert_eq!((110, 75, 50, 70, 60, 110), stats(&pkmn));
+    #[cfg(not(any(feature = "gen4", feature = "gen5")))]
+    assert_eq!((110, 75, 60, 70, 70, 110), stats(&pkmn));
+}
+
+fn stats(pkmn: &Pokemon) -> (i16, i16, i16, i16, i16, i16) {
+    (
+        pkmn.maxhp,
+        pkmn.attack,
+        pkmn.defense,
+        pkmn.special_attack,
+        pkmn.special_defense,
+        pkmn.speed,
+    )
+}
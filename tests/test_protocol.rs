@@ -0,0 +1,606 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::engine::abilities::Abilities;
+use poke_engine::engine::items::Items;
+use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
+use poke_engine::pokemon::PokemonName;
+use poke_engine::protocol::{ShowdownBattle, ShowdownRequest};
+use poke_engine::state::{
+    pokemon_index_iter, LastUsedMove, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType,
+    Side,
+};
+
+const TEAM: &str = "Garchomp @ Choice Scarf
+Ability: Rough Skin
+EVs: 252 Atk / 4 SpD / 252 Spe
+Jolly Nature
+- Earthquake
+- Outrage
+- Stone Edge
+- Fire Fang
+
+Skarmory @ Leftovers
+Ability: Sturdy
+EVs: 252 HP / 4 Def / 252 SpD
+Careful Nature
+- Spikes
+- Roost
+- Whirlwind
+- Brave Bird
+";
+
+const BATTLE_START: &str = ">battle-gen9ou-1
+|
+|t:|1700000000
+|player|p1|me|1|
+|player|p2|them|2|
+|teamsize|p1|2
+|teamsize|p2|3
+|gen|9
+|tier|[Gen 9] OU
+|
+|start
+|switch|p1a: Chomp|Garchomp, F|357/357
+|switch|p2a: Dragon|Dragonite, L82, M|100/100
+|turn|1";
+
+fn battle() -> ShowdownBattle {
+    let mut battle = ShowdownBattle::with_team("p1", Side::from_showdown_team(TEAM).unwrap());
+    battle.parse_message(BATTLE_START).unwrap();
+    battle
+}
+
+#[test]
+fn test_switch_in_matches_known_team_and_adds_opponent() {
+    let battle = battle();
+    let side_one = &battle.state.side_one;
+    let side_two = &battle.state.side_two;
+
+    assert_eq!(1, battle.turn);
+    assert_eq!(PokemonName::GARCHOMP, side_one.get_active_immutable().id);
+    assert_eq!(Items::CHOICESCARF, side_one.get_active_immutable().item);
+
+    let dragonite = side_two.get_active_immutable();
+    assert_eq!(PokemonName::DRAGONITE, dragonite.id);
+    assert_eq!(82, dragonite.level);
+    assert_eq!(dragonite.maxhp, dragonite.hp);
+    assert_eq!(Items::UNKNOWNITEM, dragonite.item);
+    assert_eq!(Choices::NONE, dragonite.moves[&PokemonMoveIndex::M0].id);
+    assert_eq!(PokemonName::NONE, side_two.pokemon[PokemonIndex::P1].id);
+    assert_eq!(0, side_two.pokemon[PokemonIndex::P1].hp);
+}
+
+#[test]
+fn test_opponent_hp_is_converted_from_percentage() {
+    let mut battle = battle();
+    battle.parse_message("|-damage|p2a: Dragon|37/100").unwrap();
+
+    let dragonite = battle.state.side_two.get_active_immutable();
+    assert_eq!((dragonite.maxhp as f32 * 0.37).round() as i16, dragonite.hp);
+
+    battle
+        .parse_message("|-damage|p2a: Dragon|1/100 brn")
+        .unwrap();
+    let dragonite = battle.state.side_two.get_active_immutable();
+    assert_eq!(PokemonStatus::BURN, dragonite.status);
+    assert!(dragonite.hp >= 1);
+}
+
+#[test]
+fn test_own_hp_is_exact() {
+    let mut battle = battle();
+    battle
+        .parse_message("|-damage|p1a: Chomp|200/357 par")
+        .unwrap();
+
+    let garchomp = battle.state.side_one.get_active_immutable();
+    assert_eq!(200, garchomp.hp);
+    assert_eq!(357, garchomp.maxhp);
+    assert_eq!(PokemonStatus::PARALYZE, garchomp.status);
+}
+
+#[test]
+fn test_revealed_moves_items_and_abilities() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|move|p2a: Dragon|Extreme Speed|p1a: Chomp
+|-damage|p1a: Chomp|250/357
+|-damage|p2a: Dragon|94/100|[from] ability: Rough Skin|[of] p1a: Chomp
+|move|p1a: Chomp|Stone Edge|p2a: Dragon
+|-damage|p2a: Dragon|60/100
+|-enditem|p2a: Dragon|Heavy-Duty Boots|[from] move: Knock Off
+|-heal|p2a: Dragon|66/100|[from] item: Leftovers
+|-ability|p2a: Dragon|Multiscale",
+        )
+        .unwrap();
+
+    let dragonite = battle.state.side_two.get_active_immutable();
+    assert_eq!(
+        Choices::EXTREMESPEED,
+        dragonite.moves[&PokemonMoveIndex::M0].id
+    );
+    assert_eq!(Items::LEFTOVERS, dragonite.item);
+    assert_eq!(Abilities::MULTISCALE, dragonite.ability);
+    assert_eq!(
+        LastUsedMove::Move(PokemonMoveIndex::M0),
+        battle.state.side_two.last_used_move
+    );
+
+    let garchomp = battle.state.side_one.get_active_immutable();
+    assert_eq!(Abilities::ROUGHSKIN, garchomp.ability);
+    // Stone Edge is already the third move of the known team
+    assert_eq!(Choices::STONEEDGE, garchomp.moves[&PokemonMoveIndex::M2].id);
+    assert_eq!(
+        LastUsedMove::Move(PokemonMoveIndex::M2),
+        battle.state.side_one.last_used_move
+    );
+}
+
+#[test]
+fn test_moves_called_by_other_moves_are_not_revealed() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|move|p2a: Dragon|Sleep Talk|p2a: Dragon
+|move|p2a: Dragon|Outrage|p1a: Chomp|[from] move: Sleep Talk",
+        )
+        .unwrap();
+
+    let dragonite = battle.state.side_two.get_active_immutable();
+    assert_eq!(
+        Choices::SLEEPTALK,
+        dragonite.moves[&PokemonMoveIndex::M0].id
+    );
+    assert_eq!(Choices::NONE, dragonite.moves[&PokemonMoveIndex::M1].id);
+}
+
+#[test]
+fn test_boosts_are_tracked_and_reset_on_switch() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|move|p2a: Dragon|Dragon Dance|p2a: Dragon
+|-boost|p2a: Dragon|atk|1
+|-boost|p2a: Dragon|spe|1
+|-unboost|p1a: Chomp|atk|1
+|-boost|p2a: Dragon|atk|6",
+        )
+        .unwrap();
+    assert_eq!(6, battle.state.side_two.attack_boost);
+    assert_eq!(1, battle.state.side_two.speed_boost);
+    assert_eq!(-1, battle.state.side_one.attack_boost);
+
+    battle
+        .parse_message("|switch|p2a: Tusk|Great Tusk|100/100")
+        .unwrap();
+    assert_eq!(0, battle.state.side_two.attack_boost);
+    assert_eq!(PokemonIndex::P1, battle.state.side_two.active_index);
+    assert_eq!(
+        PokemonName::GREATTUSK,
+        battle.state.side_two.get_active_immutable().id
+    );
+    assert_eq!(
+        LastUsedMove::Switch(PokemonIndex::P1),
+        battle.state.side_two.last_used_move
+    );
+
+    battle
+        .parse_message("|switch|p2a: Dragon|Dragonite, L82, M|100/100")
+        .unwrap();
+    assert_eq!(PokemonIndex::P0, battle.state.side_two.active_index);
+}
+
+#[test]
+fn test_zobrist_hash_follows_the_messages() {
+    let mut battle = battle();
+    assert_eq!(
+        battle.state.compute_zobrist_hash(),
+        battle.state.zobrist_hash
+    );
+    let hash_before = battle.state.zobrist_hash;
+
+    battle
+        .parse_message(
+            "|-boost|p2a: Dragon|atk|1
+|-damage|p1a: Chomp|200/357",
+        )
+        .unwrap();
+    assert_ne!(hash_before, battle.state.zobrist_hash);
+    assert_eq!(
+        battle.state.compute_zobrist_hash(),
+        battle.state.zobrist_hash
+    );
+}
+
+#[test]
+fn test_weather_terrain_and_side_conditions() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|-weather|RainDance
+|-fieldstart|move: Electric Terrain|[from] ability: Electric Surge|[of] p2a: Dragon
+|-sidestart|p1: me|move: Stealth Rock
+|-sidestart|p1: me|Spikes
+|-sidestart|p1: me|Spikes
+|-sidestart|p2: them|Reflect
+|turn|2
+|-weather|RainDance|[upkeep]",
+        )
+        .unwrap();
+
+    assert_eq!(Weather::RAIN, battle.state.weather.weather_type);
+    assert_eq!(4, battle.state.weather.turns_remaining);
+    assert_eq!(Terrain::ELECTRICTERRAIN, battle.state.terrain.terrain_type);
+    assert_eq!(
+        Abilities::ELECTRICSURGE,
+        battle.state.side_two.get_active_immutable().ability
+    );
+    assert_eq!(1, battle.state.side_one.side_conditions.stealth_rock);
+    assert_eq!(2, battle.state.side_one.side_conditions.spikes);
+    assert_eq!(4, battle.state.side_two.side_conditions.reflect);
+
+    battle
+        .parse_message(
+            "|-weather|none
+|-sideend|p1: me|Stealth Rock|[from] move: Rapid Spin|[of] p1a: Chomp
+|-fieldend|move: Electric Terrain",
+        )
+        .unwrap();
+    assert_eq!(Weather::NONE, battle.state.weather.weather_type);
+    assert_eq!(Terrain::NONE, battle.state.terrain.terrain_type);
+    assert_eq!(0, battle.state.side_one.side_conditions.stealth_rock);
+    assert_eq!(2, battle.state.side_one.side_conditions.spikes);
+}
+
+#[test]
+fn test_volatile_statuses() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|move|p2a: Dragon|Substitute|p2a: Dragon
+|-start|p2a: Dragon|Substitute
+|-start|p1a: Chomp|confusion
+|-singleturn|p1a: Chomp|Protect",
+        )
+        .unwrap();
+
+    let side_two = &battle.state.side_two;
+    assert!(side_two
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::SUBSTITUTE));
+    assert_eq!(
+        side_two.get_active_immutable().maxhp / 4,
+        side_two.substitute_health
+    );
+    assert!(battle
+        .state
+        .side_one
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::CONFUSION));
+
+    battle
+        .parse_message(
+            "|-end|p2a: Dragon|Substitute
+|turn|2",
+        )
+        .unwrap();
+    assert!(!battle
+        .state
+        .side_two
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::SUBSTITUTE));
+    assert!(!battle
+        .state
+        .side_one
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::PROTECT));
+    assert!(battle
+        .state
+        .side_one
+        .volatile_statuses
+        .contains(&PokemonVolatileStatus::CONFUSION));
+}
+
+#[test]
+fn test_faint_and_win() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|-damage|p2a: Dragon|0 fnt
+|faint|p2a: Dragon
+|win|me",
+        )
+        .unwrap();
+
+    assert_eq!(0, battle.state.side_two.get_active_immutable().hp);
+    assert!(battle.ended);
+    assert_eq!(Some("me".to_string()), battle.winner);
+}
+
+#[test]
+fn test_team_preview_and_player_two_perspective() {
+    let mut battle = ShowdownBattle::new("p2");
+    battle
+        .parse_message(
+            "|poke|p1|Urshifu-*, L100|
+|poke|p1|Toxapex, F|
+|poke|p2|Pikachu, L50, M|
+|teampreview
+|switch|p1a: Fish|Urshifu-Rapid-Strike, M|100/100
+|switch|p2a: Pika|Pikachu, L50, M|110/110",
+        )
+        .unwrap();
+
+    let side_two = &battle.state.side_two;
+    assert_eq!(PokemonIndex::P0, side_two.active_index);
+    assert_eq!(
+        PokemonName::URSHIFURAPIDSTRIKE,
+        side_two.get_active_immutable().id
+    );
+    assert_eq!(
+        (PokemonType::FIGHTING, PokemonType::WATER),
+        side_two.get_active_immutable().types
+    );
+    assert_eq!(PokemonName::TOXAPEX, side_two.pokemon[PokemonIndex::P1].id);
+
+    let pikachu = battle.state.side_one.get_active_immutable();
+    assert_eq!(PokemonName::PIKACHU, pikachu.id);
+    assert_eq!(110, pikachu.hp);
+}
+
+#[test]
+fn test_invalid_messages_are_errors() {
+    let mut battle = battle();
+    assert!(battle.parse_line("|-damage|p2a: Nobody|50/100").is_err());
+    assert!(battle.parse_line("|-boost|p2a: Dragon|atk").is_err());
+    assert!(battle
+        .parse_line("|switch|p2a: Mon|NotAPokemon|100/100")
+        .is_err());
+    assert!(battle.parse_line("|-status|p2a: Dragon|zzz").is_err());
+
+    // messages that do not affect the state are ignored
+    assert!(battle.parse_line("|c|me|gl hf").is_ok());
+    assert!(battle.parse_line("not a protocol message").is_ok());
+}
+
+const MOVE_REQUEST: &str = r#"{"active":[{"moves":[
+    {"move":"Earthquake","id":"earthquake","pp":15,"maxpp":16,"target":"allAdjacent","disabled":false},
+    {"move":"Outrage","id":"outrage","pp":0,"maxpp":16,"target":"randomNormal","disabled":true},
+    {"move":"Stone Edge","id":"stoneedge","pp":8,"maxpp":8,"target":"normal","disabled":"Disable"},
+    {"move":"Fire Fang","id":"firefang","pp":24,"maxpp":24,"target":"normal","disabled":false}],
+    "canTerastallize":"Ground"}],
+  "side":{"name":"me","id":"p1","pokemon":[
+    {"ident":"p1: Skarm","details":"Skarmory, F","condition":"334/334","active":true},
+    {"ident":"p1: Chomp","details":"Garchomp, F","condition":"357/357","active":false}]},
+  "rqid":3}"#;
+
+#[test]
+fn test_move_choice_to_showdown_choice() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    assert_eq!(
+        "move 2",
+        MoveChoice::Move(PokemonMoveIndex::M1).to_showdown_choice(&side, false)
+    );
+    assert_eq!(
+        "move 4 terastallize",
+        MoveChoice::MoveTera(PokemonMoveIndex::M3).to_showdown_choice(&side, false)
+    );
+    assert_eq!(
+        "switch 2",
+        MoveChoice::Switch(PokemonIndex::P1).to_showdown_choice(&side, false)
+    );
+    assert_eq!(
+        "team 21",
+        MoveChoice::Switch(PokemonIndex::P1).to_showdown_choice(&side, true)
+    );
+
+    let mut full_side = Side::default();
+    for pkmn_index in pokemon_index_iter() {
+        full_side.pokemon[pkmn_index].id = PokemonName::PIKACHU;
+    }
+    assert_eq!(
+        "team 312456",
+        MoveChoice::Switch(PokemonIndex::P2).to_showdown_choice(&full_side, true)
+    );
+}
+
+#[test]
+fn test_request_move_choices_skip_disabled_moves() {
+    let mut side = Side::from_showdown_team(TEAM).unwrap();
+    side.active_index = PokemonIndex::P1;
+    side.get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::EARTHQUAKE);
+    side.get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::OUTRAGE);
+    side.get_active()
+        .replace_move(PokemonMoveIndex::M2, Choices::STONEEDGE);
+    side.get_active()
+        .replace_move(PokemonMoveIndex::M3, Choices::FIREFANG);
+
+    let request = ShowdownRequest::from_json(MOVE_REQUEST).unwrap();
+    assert_eq!(
+        vec![
+            MoveChoice::Move(PokemonMoveIndex::M0),
+            MoveChoice::MoveTera(PokemonMoveIndex::M0),
+            MoveChoice::Move(PokemonMoveIndex::M3),
+            MoveChoice::MoveTera(PokemonMoveIndex::M3),
+            MoveChoice::Switch(PokemonIndex::P0),
+        ],
+        request.move_choices(&side)
+    );
+}
+
+#[test]
+fn test_request_choices_use_the_requests_order() {
+    // Skarmory has switched in, so Showdown lists it first
+    let mut side = Side::from_showdown_team(TEAM).unwrap();
+    side.active_index = PokemonIndex::P1;
+    let request = ShowdownRequest::from_json(MOVE_REQUEST).unwrap();
+
+    assert_eq!(
+        "switch 2",
+        request.to_showdown_choice(&MoveChoice::Switch(PokemonIndex::P0), &side)
+    );
+    side.get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::FIREFANG);
+    assert_eq!(
+        "move 4 terastallize",
+        request.to_showdown_choice(&MoveChoice::MoveTera(PokemonMoveIndex::M0), &side)
+    );
+}
+
+#[test]
+fn test_forced_switch_request() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let request = ShowdownRequest::from_json(
+        r#"{"forceSwitch":[true],"side":{"name":"me","id":"p1","pokemon":[
+            {"ident":"p1: Chomp","details":"Garchomp, F","condition":"0 fnt","active":true},
+            {"ident":"p1: Skarm","details":"Skarmory, F","condition":"334/334","active":false}]},
+            "noCancel":true,"rqid":5}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![MoveChoice::Switch(PokemonIndex::P1)],
+        request.move_choices(&side)
+    );
+}
+
+#[test]
+fn test_trapped_and_wait_requests() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let trapped = ShowdownRequest::from_json(
+        r#"{"active":[{"moves":[{"move":"Outrage","id":"outrage"}],"trapped":true}],
+            "side":{"pokemon":[
+            {"ident":"p1: Chomp","details":"Garchomp, F","condition":"200/357","active":true},
+            {"ident":"p1: Skarm","details":"Skarmory, F","condition":"334/334","active":false}]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![MoveChoice::Move(PokemonMoveIndex::M1)],
+        trapped.move_choices(&side)
+    );
+    assert_eq!(
+        "move 1",
+        trapped.to_showdown_choice(&MoveChoice::Move(PokemonMoveIndex::M1), &side)
+    );
+
+    let wait = ShowdownRequest::from_json(r#"{"wait":true,"side":{"pokemon":[]}}"#).unwrap();
+    assert_eq!(vec![MoveChoice::None], wait.move_choices(&side));
+    assert!(ShowdownRequest::from_json("{not json").is_err());
+}
+
+#[test]
+fn test_battle_keeps_the_latest_request() {
+    let mut battle = battle();
+    battle
+        .parse_message(&format!("|request|{}", MOVE_REQUEST.replace('\n', "")))
+        .unwrap();
+    assert!(battle.request.as_ref().unwrap().can_terastallize);
+}
+
+#[test]
+fn test_mega_evolution_message_and_request() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|switch|p2a: Zard|Charizard, L80, M|100/100
+|detailschange|p2a: Zard|Charizard-Mega-Y, L80, M
+|-mega|p2a: Zard|Charizard|Charizardite Y",
+        )
+        .unwrap();
+    let side_two = &battle.state.side_two;
+    let zard = side_two.get_active_immutable();
+    assert!(side_two.mega_evolved);
+    assert_eq!(PokemonName::CHARIZARDMEGAY, zard.id);
+    assert_eq!(Abilities::DROUGHT, zard.ability);
+    assert_eq!(Abilities::DROUGHT, zard.base_ability);
+
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let request = ShowdownRequest::from_json(
+        r#"{"active":[{"moves":[{"move":"Earthquake","id":"earthquake","pp":16}],"canMegaEvo":true}],
+            "side":{"pokemon":[
+            {"ident":"p1: Chomp","details":"Garchomp, F","condition":"357/357","active":true}]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            MoveChoice::Move(PokemonMoveIndex::M0),
+            MoveChoice::MoveMega(PokemonMoveIndex::M0),
+        ],
+        request.move_choices(&side)
+    );
+    assert_eq!(
+        "move 1 mega",
+        request.to_showdown_choice(&MoveChoice::MoveMega(PokemonMoveIndex::M0), &side)
+    );
+}
+
+#[test]
+fn test_z_move_messages_and_request() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|-zpower|p1a: Chomp
+|move|p1a: Chomp|Tectonic Rage|p2a: Dragon|[zeffect]",
+        )
+        .unwrap();
+    let side_one = &battle.state.side_one;
+    assert!(side_one.z_move_used);
+    assert!(!battle.state.side_two.z_move_used);
+    // the z-move is matched to the move it was made from instead of being added as a new move
+    assert_eq!(
+        LastUsedMove::Move(PokemonMoveIndex::M0),
+        side_one.last_used_move
+    );
+    assert!(!side_one
+        .get_active_immutable()
+        .moves
+        .into_iter()
+        .any(|m| m.id == Choices::TECTONICRAGE));
+
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let request = ShowdownRequest::from_json(
+        r#"{"active":[{"moves":[{"move":"Earthquake","id":"earthquake","pp":16},
+            {"move":"Outrage","id":"outrage","pp":16}],
+            "canZMove":[{"move":"Tectonic Rage","target":"normal"},null]}],
+            "side":{"pokemon":[
+            {"ident":"p1: Chomp","details":"Garchomp, F","condition":"357/357","active":true}]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            MoveChoice::Move(PokemonMoveIndex::M0),
+            MoveChoice::MoveZ(PokemonMoveIndex::M0),
+            MoveChoice::Move(PokemonMoveIndex::M1),
+        ],
+        request.move_choices(&side)
+    );
+    assert_eq!(
+        "move 1 zmove",
+        request.to_showdown_choice(&MoveChoice::MoveZ(PokemonMoveIndex::M0), &side)
+    );
+}
+
+#[test]
+fn test_species_exclusive_z_move_is_matched_to_its_base_move() {
+    let mut battle = battle();
+    battle
+        .parse_message(
+            "|-zpower|p2a: Dragon
+|move|p2a: Dragon|10,000,000 Volt Thunderbolt|p1a: Chomp|[zeffect]",
+        )
+        .unwrap();
+    let side_two = &battle.state.side_two;
+    assert!(side_two.z_move_used);
+    assert_eq!(
+        Choices::THUNDERBOLT,
+        side_two.get_active_immutable().moves[&PokemonMoveIndex::M0].id
+    );
+    assert!(!side_two
+        .get_active_immutable()
+        .moves
+        .into_iter()
+        .any(|m| m.id == Choices::TENMILLIONVOLTTHUNDERBOLT));
+}
@@ -0,0 +1,57 @@
+use poke_engine::instruction::{prune_state_instructions, PruningStats, StateInstructions};
+
+fn state_instructions_with_percentages(percentages: &[f32]) -> Vec<StateInstructions> {
+    percentages
+        .iter()
+        .map(|percentage| StateInstructions {
+            percentage: *percentage,
+            instruction_list: vec![],
+        })
+        .collect()
+}
+
+fn percentages(state_instructions: &[StateInstructions]) -> Vec<f32> {
+    state_instructions.iter().map(|si| si.percentage).collect()
+}
+
+#[test]
+fn test_pruning_renormalizes_the_remaining_branches() {
+    let mut state_instructions = state_instructions_with_percentages(&[60.0, 30.0, 10.0]);
+
+    let pruned = prune_state_instructions(&mut state_instructions, 20.0);
+
+    assert_eq!(10.0, pruned);
+    assert_eq!(vec![66.66667, 33.333336], percentages(&state_instructions));
+}
+
+#[test]
+fn test_pruning_keeps_the_most_likely_branch() {
+    let mut state_instructions = state_instructions_with_percentages(&[10.0, 15.0, 5.0]);
+
+    let pruned = prune_state_instructions(&mut state_instructions, 50.0);
+
+    assert_eq!(15.0, pruned);
+    assert_eq!(vec![30.0], percentages(&state_instructions));
+}
+
+#[test]
+fn test_zero_threshold_does_not_prune() {
+    let mut state_instructions = state_instructions_with_percentages(&[99.0, 1.0]);
+
+    let pruned = prune_state_instructions(&mut state_instructions, 0.0);
+
+    assert_eq!(0.0, pruned);
+    assert_eq!(vec![99.0, 1.0], percentages(&state_instructions));
+}
+
+#[test]
+fn test_pruning_stats_average_over_move_pairs() {
+    let mut pruning_stats = PruningStats::default();
+    assert_eq!(0.0, pruning_stats.average_pruned_percentage());
+
+    pruning_stats.record(10.0);
+    pruning_stats.record(0.0);
+
+    assert_eq!(2, pruning_stats.move_pairs);
+    assert_eq!(5.0, pruning_stats.average_pruned_percentage());
+}
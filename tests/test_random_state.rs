@@ -0,0 +1,96 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::random_state::RandomStateGenerator;
+use poke_engine::state::{pokemon_index_iter, State};
+
+#[test]
+fn test_same_seed_generates_the_same_states() {
+    let mut first = RandomStateGenerator::new(42, true);
+    let mut second = RandomStateGenerator::new(42, true);
+    for _ in 0..5 {
+        assert_eq!(
+            first.random_state().serialize(),
+            second.random_state().serialize()
+        );
+    }
+    let mut different_seed = RandomStateGenerator::new(43, true);
+    assert_ne!(
+        RandomStateGenerator::new(42, true)
+            .random_state()
+            .serialize(),
+        different_seed.random_state().serialize()
+    );
+}
+
+#[test]
+fn test_generated_states_are_valid() {
+    for mid_battle in [false, true] {
+        let mut generator = RandomStateGenerator::new(0, mid_battle);
+        for _ in 0..50 {
+            let state = generator.random_state();
+            let issues: Vec<String> = state.validate().iter().map(|i| i.to_string()).collect();
+            assert_eq!(Vec::<String>::new(), issues);
+            for side in [&state.side_one, &state.side_two] {
+                assert!(side.get_active_immutable().hp > 0);
+                for pkmn_index in pokemon_index_iter() {
+                    let pkmn = &side.pokemon[pkmn_index];
+                    assert_ne!(PokemonName::NONE, pkmn.id);
+                    assert!(pkmn.id.abilities().contains(&pkmn.base_ability));
+                    let moves: Vec<Choices> = pkmn.moves.into_iter().map(|m| m.id).collect();
+                    assert!(!moves.contains(&Choices::NONE));
+                    for (index, choice) in moves.iter().enumerate() {
+                        assert!(!moves[index + 1..].contains(choice));
+                    }
+                    if !mid_battle {
+                        assert_eq!(pkmn.maxhp, pkmn.hp);
+                    }
+                }
+            }
+            assert_eq!(
+                state.serialize(),
+                State::deserialize(&state.serialize()).serialize()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_instructions_can_be_generated_from_random_states() {
+    let mut generator = RandomStateGenerator::new(7, true);
+    for _ in 0..20 {
+        let mut state = generator.random_state();
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        for side_one_option in side_one_options.iter() {
+            for side_two_option in side_two_options.iter() {
+                let instructions = generate_instructions_from_move_pair(
+                    &mut state,
+                    side_one_option,
+                    side_two_option,
+                    DamageBranching::KillNoKill,
+                    0.0,
+                );
+                assert!(!instructions.is_empty());
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "gen4")]
+fn test_only_species_from_the_generation_are_generated() {
+    let mut generator = RandomStateGenerator::new(0, false);
+    for _ in 0..50 {
+        let state = generator.random_state();
+        for side in [&state.side_one, &state.side_two] {
+            for pkmn_index in pokemon_index_iter() {
+                let species = side.pokemon[pkmn_index].id;
+                assert!((species as i16) < (PokemonName::VICTINI as i16));
+                assert!(!species.to_string().contains("HISUI"));
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::state::MoveChoice;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::replay::{analyze_replay, replay_log, ReplaySearch, TurnReport};
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex};
+
+// Garchomp uses a resisted Dragon Claw on turn 1 when Earthquake would have won
+const REPLAY: &str = "|j|☆alice
+|j|☆bob
+|player|p1|alice|1|
+|player|p2|bob|2|
+|teamsize|p1|1
+|teamsize|p2|1
+|gen|9
+|
+|start
+|switch|p1a: Chomp|Garchomp, F|100/100
+|switch|p2a: Mag|Magnezone|100/100
+|turn|1
+|
+|move|p1a: Chomp|Dragon Claw|p2a: Mag
+|-resisted|p2a: Mag
+|-damage|p2a: Mag|85/100
+|move|p2a: Mag|Flash Cannon|p1a: Chomp
+|-resisted|p1a: Chomp
+|-damage|p1a: Chomp|90/100
+|
+|upkeep
+|turn|2
+|move|p1a: Chomp|Earthquake|p2a: Mag
+|-supereffective|p2a: Mag
+|-damage|p2a: Mag|0 fnt
+|faint|p2a: Mag
+|
+|win|alice";
+
+fn analyze(log: &str, player_id: &str) -> Result<Vec<TurnReport>, String> {
+    let search = ReplaySearch::Expectiminimax(1);
+    analyze_replay(
+        log,
+        player_id,
+        &search,
+        &search.default_damage_branching(),
+        0.0,
+        10.0,
+    )
+}
+
+#[test]
+fn test_blunder_is_reported() {
+    let reports = analyze(REPLAY, "p1").unwrap();
+    assert_eq!(2, reports.len());
+
+    assert_eq!(1, reports[0].turn);
+    assert_eq!(MoveChoice::Move(PokemonMoveIndex::M0), reports[0].played);
+    assert_eq!(
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        reports[0].engine_choice
+    );
+    assert!(reports[0].score_difference() > 10.0);
+    assert!(reports[0].blunder);
+
+    assert_eq!(2, reports[1].turn);
+    assert_eq!(reports[1].engine_choice, reports[1].played);
+    assert!(!reports[1].blunder);
+}
+
+#[test]
+fn test_players_moves_are_known_from_the_start() {
+    let reports = analyze(REPLAY, "p1").unwrap();
+    let state = &reports[0].state;
+    let garchomp = state.side_one.get_active_immutable();
+    assert_eq!(PokemonName::GARCHOMP, garchomp.id);
+    assert!(!garchomp.moves[&PokemonMoveIndex::M1].disabled);
+    assert_eq!(
+        PokemonName::NONE,
+        state.side_one.pokemon[PokemonIndex::P1].id
+    );
+    assert_eq!(0, state.side_one.pokemon[PokemonIndex::P1].hp);
+
+    // the opponent's moves are only known once they are used
+    let magnezone = state.side_two.get_active_immutable();
+    assert!(magnezone.moves[&PokemonMoveIndex::M0].disabled);
+}
+
+#[test]
+fn test_replay_from_player_two_perspective() {
+    let reports = analyze(REPLAY, "p2").unwrap();
+    assert_eq!(1, reports.len());
+    assert_eq!(
+        PokemonName::MAGNEZONE,
+        reports[0].state.side_one.get_active_immutable().id
+    );
+    assert_eq!(MoveChoice::Move(PokemonMoveIndex::M0), reports[0].played);
+}
+
+#[test]
+fn test_blunder_is_reported_when_branching_on_damage() {
+    let reports = analyze_replay(
+        REPLAY,
+        "p1",
+        &ReplaySearch::Expectiminimax(1),
+        &DamageBranching::KillNoKill.into(),
+        0.0,
+        10.0,
+    )
+    .unwrap();
+    assert_eq!(
+        MoveChoice::Move(PokemonMoveIndex::M1),
+        reports[0].engine_choice
+    );
+    assert!(reports[0].blunder);
+}
+
+#[test]
+fn test_report_json() {
+    let reports = analyze(REPLAY, "p1").unwrap();
+    let json = reports[0].to_json();
+    assert_eq!(1, json["turn"]);
+    assert_eq!("dragonclaw", json["played"]);
+    assert_eq!("earthquake", json["engine_choice"]);
+    assert_eq!(true, json["blunder"]);
+}
+
+#[test]
+fn test_replay_log_from_saved_replays() {
+    let json = format!(
+        "{{\"id\":\"gen9ou-1\",\"log\":{}}}",
+        serde_json::to_string(REPLAY).unwrap()
+    );
+    assert_eq!(REPLAY, replay_log(&json).unwrap());
+
+    let html = format!(
+        "<div class=\"wrapper\"><script type=\"text/plain\" class=\"battle-log-data\">{}</script>",
+        REPLAY
+    );
+    assert_eq!(REPLAY, replay_log(&html).unwrap());
+    assert_eq!(REPLAY, replay_log(REPLAY).unwrap());
+    assert!(replay_log("{\"id\":\"gen9ou-1\"}").is_err());
+}
+
+#[test]
+fn test_invalid_replay_is_an_error() {
+    let replay = REPLAY.replace("Magnezone", "NotAPokemon");
+    assert!(analyze(&replay, "p1").is_err());
+}
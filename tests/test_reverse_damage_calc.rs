@@ -0,0 +1,231 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::engine::items::Items;
+use poke_engine::reverse_damage_calc::{
+    candidate_spreads, DamageObservation, InferenceTarget, ObservedDamage, ReverseDamageCalc,
+    SpreadCandidate,
+};
+use poke_engine::state::{PokemonNature, Side, SideReference, State};
+
+const MY_TEAM: &str = "Heatran @ Leftovers
+Ability: Flash Fire
+EVs: 252 HP / 4 SpA / 252 SpD
+Calm Nature
+- Magma Storm
+- Earth Power
+- Stealth Rock
+- Protect
+";
+
+const OPPONENT_TEAM: &str = "Garchomp @ Choice Band
+Ability: Rough Skin
+EVs: 4 HP / 252 Atk / 252 Spe
+Adamant Nature
+- Earthquake
+- Outrage
+- Stone Edge
+- Fire Fang
+";
+
+fn state() -> State {
+    State {
+        side_one: Side::from_showdown_team(MY_TEAM).unwrap(),
+        side_two: Side::from_showdown_team(OPPONENT_TEAM).unwrap(),
+        ..State::default()
+    }
+}
+
+fn true_spread() -> SpreadCandidate {
+    SpreadCandidate {
+        nature: PokemonNature::ADAMANT,
+        evs: (4, 252, 0, 0, 0, 252),
+        item: Items::CHOICEBAND,
+    }
+}
+
+// the opponent's pokemon as it looks before anything about it is known
+fn unknown_opponent(mut state: State) -> State {
+    let pkmn = state.side_two.get_active();
+    pkmn.item = Items::UNKNOWNITEM;
+    pkmn.set_nature_and_evs(PokemonNature::SERIOUS, (85, 85, 85, 85, 85, 85));
+    state
+}
+
+// a damage roll the opponent's real set does
+fn observed_damage(choice: Choices, roll: usize) -> i16 {
+    let observation = DamageObservation::new(
+        state(),
+        SideReference::SideTwo,
+        choice,
+        ObservedDamage::Exact(0),
+    );
+    observation
+        .damage_rolls(InferenceTarget::Attacker, &true_spread())
+        .unwrap()[roll]
+}
+
+#[test]
+fn test_damage_rolls_go_from_85_to_100_percent() {
+    let observation = DamageObservation::new(
+        state(),
+        SideReference::SideTwo,
+        Choices::STONEEDGE,
+        ObservedDamage::Exact(0),
+    );
+    let rolls = observation
+        .damage_rolls(InferenceTarget::Attacker, &true_spread())
+        .unwrap();
+    assert_eq!(16, rolls.len());
+    assert!(rolls.windows(2).all(|w| w[0] <= w[1]));
+    assert!((rolls[0] as f32 - rolls[15] as f32 * 0.85).abs() <= 1.0);
+}
+
+#[test]
+fn test_infer_attacker_spread_and_item_from_exact_damage() {
+    let observation = DamageObservation::new(
+        unknown_opponent(state()),
+        SideReference::SideTwo,
+        Choices::STONEEDGE,
+        ObservedDamage::Exact(observed_damage(Choices::STONEEDGE, 8)),
+    );
+    let reverse_calc = ReverseDamageCalc::from_observation(&observation, InferenceTarget::Attacker);
+
+    let all_candidates = candidate_spreads(
+        observation.state.side_two.get_active_immutable(),
+        Choices::STONEEDGE,
+        InferenceTarget::Attacker,
+    );
+    assert!(!reverse_calc.candidates.is_empty());
+    assert!(reverse_calc.candidates.len() < all_candidates.len());
+    assert!(reverse_calc.candidates.contains(&SpreadCandidate {
+        evs: (85, 252, 85, 85, 85, 85),
+        ..true_spread()
+    }));
+    assert!(reverse_calc.possible_items().contains(&Items::CHOICEBAND));
+    // an uninvested attacker without an item cannot do this much damage
+    assert!(!reverse_calc.candidates.contains(&SpreadCandidate {
+        nature: PokemonNature::SERIOUS,
+        evs: (85, 0, 85, 85, 85, 85),
+        item: Items::NONE,
+    }));
+}
+
+#[test]
+fn test_more_hits_narrow_the_candidates() {
+    let first = DamageObservation::new(
+        unknown_opponent(state()),
+        SideReference::SideTwo,
+        Choices::STONEEDGE,
+        ObservedDamage::Exact(observed_damage(Choices::STONEEDGE, 0)),
+    );
+    let mut reverse_calc = ReverseDamageCalc::from_observation(&first, InferenceTarget::Attacker);
+    let candidates_after_one_hit = reverse_calc.candidates.clone();
+
+    let second = DamageObservation::new(
+        unknown_opponent(state()),
+        SideReference::SideTwo,
+        Choices::STONEEDGE,
+        ObservedDamage::Exact(observed_damage(Choices::STONEEDGE, 15)),
+    );
+    reverse_calc.observe(&second);
+    assert!(reverse_calc.candidates.len() < candidates_after_one_hit.len());
+    assert!(reverse_calc
+        .candidates
+        .iter()
+        .all(|c| candidates_after_one_hit.contains(c)));
+    assert!(reverse_calc.candidates.contains(&SpreadCandidate {
+        evs: (85, 252, 85, 85, 85, 85),
+        ..true_spread()
+    }));
+}
+
+#[test]
+fn test_infer_defender_spread_from_percent_damage() {
+    let mut state = state();
+    // the opponent is the defender
+    state.side_two.get_active().item = Items::UNKNOWNITEM;
+    let observation = DamageObservation::new(
+        state.clone(),
+        SideReference::SideOne,
+        Choices::EARTHPOWER,
+        ObservedDamage::Percent(0.0),
+    );
+    let defender_spread = SpreadCandidate {
+        nature: PokemonNature::ADAMANT,
+        evs: (4, 252, 0, 0, 0, 252),
+        item: Items::NONE,
+    };
+    let roll = observation
+        .damage_rolls(InferenceTarget::Defender, &defender_spread)
+        .unwrap()[10];
+    let maxhp = state.side_two.get_active_immutable().maxhp;
+
+    let observation = DamageObservation::new(
+        state,
+        SideReference::SideOne,
+        Choices::EARTHPOWER,
+        ObservedDamage::Percent(roll as f32 * 100.0 / maxhp as f32),
+    );
+    let reverse_calc = ReverseDamageCalc::from_observation(&observation, InferenceTarget::Defender);
+    assert!(reverse_calc.candidates.contains(&SpreadCandidate {
+        nature: PokemonNature::SERIOUS,
+        ..defender_spread
+    }));
+    // Assault Vest would take much less damage
+    assert!(!reverse_calc.possible_items().contains(&Items::ASSAULTVEST));
+}
+
+#[test]
+fn test_candidates_raise_keep_and_lower_the_stat_used_by_the_move() {
+    let state = unknown_opponent(state());
+    for (choice, target, natures) in [
+        (
+            Choices::STONEEDGE,
+            InferenceTarget::Attacker,
+            [
+                PokemonNature::ADAMANT,
+                PokemonNature::SERIOUS,
+                PokemonNature::MODEST,
+            ],
+        ),
+        (
+            Choices::STONEEDGE,
+            InferenceTarget::Defender,
+            [
+                PokemonNature::BOLD,
+                PokemonNature::SERIOUS,
+                PokemonNature::LONELY,
+            ],
+        ),
+        (
+            Choices::SHADOWBALL,
+            InferenceTarget::Defender,
+            [
+                PokemonNature::CALM,
+                PokemonNature::SERIOUS,
+                PokemonNature::NAUGHTY,
+            ],
+        ),
+    ] {
+        let mut candidate_natures = vec![];
+        for candidate in candidate_spreads(state.side_two.get_active_immutable(), choice, target) {
+            if !candidate_natures.contains(&candidate.nature) {
+                candidate_natures.push(candidate.nature);
+            }
+        }
+        assert_eq!(natures.to_vec(), candidate_natures);
+    }
+}
+
+#[test]
+fn test_status_moves_explain_no_damage() {
+    let observation = DamageObservation::new(
+        unknown_opponent(state()),
+        SideReference::SideOne,
+        Choices::STEALTHROCK,
+        ObservedDamage::Exact(50),
+    );
+    let reverse_calc = ReverseDamageCalc::from_observation(&observation, InferenceTarget::Defender);
+    assert!(reverse_calc.candidates.is_empty());
+}
@@ -0,0 +1,351 @@
+#![cfg(not(feature = "gen1"))]
+
+use poke_engine::choices::Choices;
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+use poke_engine::engine::abilities::Abilities;
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+use poke_engine::engine::items::Items;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::showdown_team::{parse_showdown_team, ShowdownSet};
+#[cfg(any(feature = "gen2", feature = "gen3"))]
+use poke_engine::state::hidden_power_from_ivs;
+use poke_engine::state::PokemonType;
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, PokemonNature, Side};
+
+// gen2 and gen3 can parse teams but have no base stats to build a side from
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+const TEAM: &str = "=== [gen9ou] Test ===
+
+Chompy (Garchomp) (M) @ Choice Scarf
+Ability: Rough Skin
+Tera Type: Fire
+EVs: 4 HP / 252 Atk / 252 Spe
+Jolly Nature
+- Earthquake
+- Outrage
+- Stone Edge
+- Fire Fang
+
+Rotom-Wash @ Leftovers
+Ability: Levitate
+Level: 50
+EVs: 252 HP / 212 Def / 44 Spe
+Bold Nature
+IVs: 0 Atk
+- Hydro Pump
+- Volt Switch
+- Hidden Power [Fire]
+";
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_parse_showdown_export_format() {
+    let sets = parse_showdown_team(TEAM).unwrap();
+    assert_eq!(2, sets.len());
+    assert_eq!(
+        ShowdownSet {
+            species: PokemonName::GARCHOMP,
+            item: Items::CHOICESCARF,
+            ability: Some(Abilities::ROUGHSKIN),
+            level: 100,
+            evs: (4, 252, 0, 0, 0, 252),
+            ivs: (31, 31, 31, 31, 31, 31),
+            nature: PokemonNature::JOLLY,
+            moves: vec![
+                Choices::EARTHQUAKE,
+                Choices::OUTRAGE,
+                Choices::STONEEDGE,
+                Choices::FIREFANG,
+            ],
+            tera_type: Some(PokemonType::FIRE),
+        },
+        sets[0]
+    );
+    assert_eq!(PokemonName::ROTOMWASH, sets[1].species);
+    assert_eq!(50, sets[1].level);
+    assert_eq!((31, 0, 31, 31, 31, 31), sets[1].ivs);
+    assert_eq!(PokemonNature::BOLD, sets[1].nature);
+}
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_parse_showdown_packed_format() {
+    let packed = "Chompy|Garchomp|choicescarf|H|earthquake,outrage|Jolly|4,252,,,,252||,0,,,,||50|,,,,,Fire]\
+                  |ferrothorn|leftovers|0|stealthrock,leechseed|Relaxed|252,,88,,168,|||||";
+    let sets = parse_showdown_team(packed).unwrap();
+    assert_eq!(2, sets.len());
+    assert_eq!(PokemonName::GARCHOMP, sets[0].species);
+    assert_eq!(Some(Abilities::ROUGHSKIN), sets[0].ability);
+    assert_eq!((4, 252, 0, 0, 0, 252), sets[0].evs);
+    assert_eq!((31, 0, 31, 31, 31, 31), sets[0].ivs);
+    assert_eq!(50, sets[0].level);
+    assert_eq!(Some(PokemonType::FIRE), sets[0].tera_type);
+    assert_eq!(PokemonName::FERROTHORN, sets[1].species);
+    assert_eq!(Some(Abilities::IRONBARBS), sets[1].ability);
+    assert_eq!(PokemonNature::RELAXED, sets[1].nature);
+    assert_eq!(100, sets[1].level);
+}
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_side_from_showdown_team() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+
+    let garchomp = &side.pokemon[PokemonIndex::P0];
+    assert_eq!(PokemonName::GARCHOMP, garchomp.id);
+    assert_eq!(Items::CHOICESCARF, garchomp.item);
+    assert_eq!(Abilities::ROUGHSKIN, garchomp.ability);
+    assert_eq!(358, garchomp.hp);
+    assert_eq!(359, garchomp.attack);
+    assert_eq!(333, garchomp.speed);
+    assert_eq!(PokemonType::FIRE, garchomp.tera_type);
+    assert_eq!(Choices::FIREFANG, garchomp.moves[&PokemonMoveIndex::M3].id);
+
+    let rotom = &side.pokemon[PokemonIndex::P1];
+    #[cfg(feature = "gen4")]
+    assert_eq!((PokemonType::ELECTRIC, PokemonType::GHOST), rotom.types);
+    #[cfg(not(feature = "gen4"))]
+    assert_eq!((PokemonType::ELECTRIC, PokemonType::WATER), rotom.types);
+    #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+    assert_eq!(Choices::HIDDENPOWER, rotom.moves[&PokemonMoveIndex::M2].id);
+    #[cfg(not(any(feature = "gen4", feature = "gen5", feature = "gen6")))]
+    assert_eq!(
+        Choices::HIDDENPOWERFIRE60,
+        rotom.moves[&PokemonMoveIndex::M2].id
+    );
+    assert!(rotom.moves[&PokemonMoveIndex::M3].disabled);
+    assert_eq!(0, rotom.moves[&PokemonMoveIndex::M3].pp);
+
+    for index in [
+        PokemonIndex::P2,
+        PokemonIndex::P3,
+        PokemonIndex::P4,
+        PokemonIndex::P5,
+    ] {
+        assert_eq!(0, side.pokemon[index].hp);
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_ivs_are_used_when_calculating_stats() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let rotom = &side.pokemon[PokemonIndex::P1];
+    // level 50, 0 IVs, Bold
+    assert_eq!(63, rotom.attack);
+}
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_exported_team_imports_to_the_same_side() {
+    let side = Side::from_showdown_team(TEAM).unwrap();
+    let exported = side.to_showdown_team();
+    assert!(exported.starts_with("Garchomp @ Choice Scarf\nAbility: Rough Skin\n"));
+    assert!(exported.contains("\nJolly Nature\n"));
+    assert!(exported.contains("\n- Hidden Power"));
+    let reimported = Side::from_showdown_team(&exported).unwrap();
+    for index in [PokemonIndex::P0, PokemonIndex::P1] {
+        let original = &side.pokemon[index];
+        let pkmn = &reimported.pokemon[index];
+        assert_eq!(original.id, pkmn.id);
+        assert_eq!(original.item, pkmn.item);
+        assert_eq!(original.ability, pkmn.ability);
+        assert_eq!(original.level, pkmn.level);
+        assert_eq!(original.evs, pkmn.evs);
+        assert_eq!(original.nature, pkmn.nature);
+        assert_eq!(original.speed, pkmn.speed);
+        assert_eq!(
+            original.moves[&PokemonMoveIndex::M2].id,
+            pkmn.moves[&PokemonMoveIndex::M2].id
+        );
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "gen2", feature = "gen3")))]
+fn test_several_teams_are_separated_by_headers() {
+    let teams = format!(
+        "{}\n=== [gen9ou] Second ===\n\nGarchomp\n- Earthquake\n",
+        TEAM
+    );
+    let sides = Side::from_showdown_teams(&teams).unwrap();
+    assert_eq!(2, sides.len());
+    assert_eq!(PokemonName::ROTOMWASH, sides[0].pokemon.p1.id);
+    assert_eq!(PokemonName::GARCHOMP, sides[1].pokemon.p0.id);
+    assert_eq!(PokemonName::NONE, sides[1].pokemon.p1.id);
+
+    let error =
+        Side::from_showdown_teams(&format!("{}\n=== Bad ===\nGarchomp\n- Not A Move", TEAM));
+    assert!(error.unwrap_err().starts_with("team 2:"));
+}
+
+#[test]
+fn test_invalid_teams_are_errors() {
+    assert!(parse_showdown_team("").is_err());
+    assert!(parse_showdown_team("Notapokemon @ Leftovers\n- Tackle").is_err());
+    assert!(parse_showdown_team("Garchomp @ Not An Item\n- Tackle").is_err());
+    assert!(parse_showdown_team("Garchomp\n- Not A Move").is_err());
+    assert!(parse_showdown_team("Garchomp\nEVs: 300 Atk\n- Tackle").is_err());
+    assert!(parse_showdown_team("Garchomp\nAngry Nature\n- Tackle").is_err());
+}
+
+#[test]
+#[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+fn test_typed_hidden_power_with_perfect_ivs_uses_the_ivs_for_that_type() {
+    let side = Side::from_showdown_team("Starmie\n- Hidden Power [Fire]\n").unwrap();
+    let starmie = &side.pokemon[PokemonIndex::P0];
+    assert_eq!((31, 30, 31, 30, 31, 30), starmie.ivs);
+    let hidden_power = &starmie.moves[&PokemonMoveIndex::M0];
+    assert_eq!(Choices::HIDDENPOWER, hidden_power.id);
+    assert_eq!(PokemonType::FIRE, hidden_power.choice.move_type);
+}
+
+#[test]
+#[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
+fn test_typed_hidden_power_type_comes_from_the_ivs_when_they_are_given() {
+    // 0 Attack IVs give Hidden Power Dragon, not Fire
+    let side = Side::from_showdown_team("Starmie\nIVs: 0 Atk\n- Hidden Power [Fire]\n").unwrap();
+    let hidden_power = &side.pokemon[PokemonIndex::P0].moves[&PokemonMoveIndex::M0];
+    assert_eq!(Choices::HIDDENPOWER, hidden_power.id);
+    assert_eq!(PokemonType::DRAGON, hidden_power.choice.move_type);
+}
+
+#[test]
+#[cfg(feature = "gen2")]
+fn test_typed_hidden_power_in_gen2_uses_the_dvs_for_that_type() {
+    let set = &parse_showdown_team("Snorlax\n- Hidden Power [Fire]\n").unwrap()[0];
+    // attack DV 14 and defense DV 12, with every other DV 15
+    assert_eq!((30, 28, 24, 30, 30, 30), set.ivs);
+    assert_eq!(vec![Choices::HIDDENPOWER], set.moves);
+    assert_eq!((PokemonType::FIRE, 70.0), hidden_power_from_ivs(set.ivs));
+}
+
+#[test]
+#[cfg(feature = "gen3")]
+fn test_typed_hidden_power_in_gen3_uses_the_ivs_for_that_type() {
+    let set = &parse_showdown_team("Starmie\n- Hidden Power [Fire]\n").unwrap()[0];
+    assert_eq!((31, 30, 31, 30, 31, 30), set.ivs);
+    assert_eq!(vec![Choices::HIDDENPOWER], set.moves);
+
+    // 0 Attack IVs give Hidden Power Dragon, not Fire
+    let set = &parse_showdown_team("Starmie\nIVs: 0 Atk\n- Hidden Power [Fire]\n").unwrap()[0];
+    assert_eq!((31, 0, 31, 31, 31, 31), set.ivs);
+    assert_eq!(PokemonType::DRAGON, hidden_power_from_ivs(set.ivs).0);
+}
+
+#[test]
+#[cfg(any(feature = "gen2", feature = "gen3"))]
+fn test_every_typed_hidden_power_gets_that_type_with_the_most_power() {
+    for (name, hidden_power_type) in [
+        ("Bug", PokemonType::BUG),
+        ("Dark", PokemonType::DARK),
+        ("Dragon", PokemonType::DRAGON),
+        ("Electric", PokemonType::ELECTRIC),
+        ("Fighting", PokemonType::FIGHTING),
+        ("Fire", PokemonType::FIRE),
+        ("Flying", PokemonType::FLYING),
+        ("Ghost", PokemonType::GHOST),
+        ("Grass", PokemonType::GRASS),
+        ("Ground", PokemonType::GROUND),
+        ("Ice", PokemonType::ICE),
+        ("Poison", PokemonType::POISON),
+        ("Psychic", PokemonType::PSYCHIC),
+        ("Rock", PokemonType::ROCK),
+        ("Steel", PokemonType::STEEL),
+        ("Water", PokemonType::WATER),
+    ] {
+        let team = format!("Snorlax\n- Hidden Power [{}]\n", name);
+        let set = &parse_showdown_team(&team).unwrap()[0];
+        assert_eq!(
+            (hidden_power_type, 70.0),
+            hidden_power_from_ivs(set.ivs),
+            "{}",
+            name
+        );
+    }
+}
+
+// exported from Pokemon Showdown's teambuilder, which ends every line with two spaces
+#[cfg(feature = "gen9")]
+const SHOWDOWN_EXPORT: &str = "Great Tusk @ Heavy-Duty Boots  
+Ability: Protosynthesis  
+Tera Type: Steel  
+EVs: 252 HP / 4 Atk / 252 Spe  
+Jolly Nature  
+- Headlong Rush  
+- Ice Spinner  
+- Knock Off  
+- Rapid Spin  
+
+Landorus-Therian @ Rocky Helmet  
+Ability: Intimidate  
+Tera Type: Water  
+EVs: 252 HP / 240 Def / 16 Spe  
+Impish Nature  
+- Stealth Rock  
+- Earthquake  
+- U-turn  
+- Stone Edge  
+
+Gholdengo @ Choice Specs  
+Ability: Good as Gold  
+Tera Type: Steel  
+EVs: 252 SpA / 4 SpD / 252 Spe  
+Timid Nature  
+IVs: 0 Atk  
+- Make It Rain  
+- Shadow Ball  
+- Trick  
+- Focus Blast  
+
+Kingambit @ Leftovers  
+Ability: Supreme Overlord  
+Tera Type: Dark  
+EVs: 252 HP / 252 Atk / 4 SpD  
+Adamant Nature  
+- Swords Dance  
+- Kowtow Cleave  
+- Sucker Punch  
+- Iron Head  
+";
+
+#[test]
+#[cfg(feature = "gen9")]
+fn test_showdown_export_round_trips() {
+    let side = Side::from_showdown_team(SHOWDOWN_EXPORT).unwrap();
+    let expected: Vec<&str> = SHOWDOWN_EXPORT
+        .lines()
+        .map(|line| line.trim_end())
+        .collect();
+    let exported = side.to_showdown_team();
+    assert_eq!(expected, exported.lines().collect::<Vec<&str>>());
+}
+
+#[test]
+fn test_display_names_import_as_the_same_species_and_move() {
+    let mut species = PokemonName::from(1);
+    while species != PokemonName::NONE {
+        let set = ShowdownSet {
+            species,
+            moves: vec![Choices::TACKLE],
+            ..Default::default()
+        };
+        assert_eq!(set, parse_showdown_team(&set.to_string()).unwrap()[0]);
+        species = PokemonName::from(species as i16 + 1);
+    }
+
+    let mut choice = Choices::from(1);
+    while choice != Choices::NONE {
+        // the base power of Hidden Power and Return is not part of their name
+        if !choice.is_hiddenpower() && choice != Choices::RETURN102 {
+            let set = ShowdownSet {
+                species: PokemonName::PIKACHU,
+                moves: vec![choice],
+                ..Default::default()
+            };
+            assert_eq!(set, parse_showdown_team(&set.to_string()).unwrap()[0]);
+        }
+        choice = Choices::from(choice as u16 + 1);
+    }
+}
@@ -0,0 +1,118 @@
+use poke_engine::agent::RandomAgent;
+use poke_engine::choices::Choices;
+use poke_engine::engine::state::MoveChoice;
+use poke_engine::simulator::{simulate_battle, simulate_battle_from_str};
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, SideReference, State};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// side two only has one pokemon left and both actives can attack
+fn state() -> State {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_two.pokemon.p1.hp = 0;
+    state.side_two.pokemon.p2.hp = 0;
+    state.side_two.pokemon.p3.hp = 0;
+    state.side_two.pokemon.p4.hp = 0;
+    state.side_two.pokemon.p5.hp = 0;
+    state
+}
+
+#[test]
+fn test_same_seed_plays_the_same_battle() {
+    let first = simulate_battle_from_str(state(), "random", "random", 100, 5).unwrap();
+    let second = simulate_battle_from_str(state(), "random", "random", 100, 5).unwrap();
+    assert_eq!(
+        first.final_state.serialize(),
+        second.final_state.serialize()
+    );
+    assert_eq!(first.turns.len(), second.turns.len());
+}
+
+#[test]
+fn test_battle_ends_when_a_side_has_no_pokemon_left() {
+    let result = simulate_battle_from_str(state(), "random", "random", 1000, 0).unwrap();
+    assert!(result.winner.is_some());
+    assert_ne!(0.0, result.final_state.battle_is_over());
+}
+
+#[test]
+fn test_battle_stops_at_the_turn_limit() {
+    let result = simulate_battle_from_str(State::default(), "random", "random", 3, 0).unwrap();
+    assert_eq!(None, result.winner);
+    assert_eq!(3, result.turns.iter().filter(|t| !t.forced_switch).count());
+    assert_eq!(3, result.turns.last().unwrap().turn);
+}
+
+#[test]
+fn test_fainted_pokemon_are_replaced_without_counting_a_turn() {
+    let mut state = State::default();
+    state.side_one.get_active().hp = 0;
+    let result = simulate_battle(
+        state,
+        &mut RandomAgent::new(0),
+        &mut RandomAgent::new(1),
+        1,
+        &mut StdRng::seed_from_u64(0),
+    );
+
+    let forced_switch = &result.turns[0];
+    assert!(forced_switch.forced_switch);
+    assert_eq!(0, forced_switch.turn);
+    assert!(matches!(
+        forced_switch.side_one_choice,
+        MoveChoice::Switch(_)
+    ));
+    assert_eq!(MoveChoice::None, forced_switch.side_two_choice);
+    assert_eq!(1, result.turns[1].turn);
+    assert!(!result.turns[1].forced_switch);
+}
+
+#[test]
+fn test_team_preview_asks_both_agents_for_a_lead() {
+    let state = State {
+        team_preview: true,
+        ..State::default()
+    };
+    let mut side_one = |_: &State, side_ref: SideReference, options: &[MoveChoice]| {
+        assert_eq!(SideReference::SideOne, side_ref);
+        options[options.len() - 1]
+    };
+    let mut side_two = |_: &State, _: SideReference, options: &[MoveChoice]| options[0];
+    let result = simulate_battle(
+        state,
+        &mut side_one,
+        &mut side_two,
+        0,
+        &mut StdRng::seed_from_u64(0),
+    );
+    assert!(!result.final_state.team_preview);
+    assert_eq!(PokemonIndex::P5, result.final_state.side_one.active_index);
+    assert_eq!(PokemonIndex::P0, result.final_state.side_two.active_index);
+}
+
+#[test]
+fn test_every_agent_can_play() {
+    for agent in [
+        "greedy-damage",
+        "expectiminimax:1",
+        "iterative-deepening:10",
+        "mcts:10",
+    ] {
+        let result = simulate_battle_from_str(state(), agent, "random", 2, 0).unwrap();
+        assert!(!result.turns.is_empty());
+    }
+}
+
+#[test]
+fn test_invalid_agent_is_an_error() {
+    assert!(simulate_battle_from_str(State::default(), "minimax", "random", 1, 0).is_err());
+    assert!(simulate_battle_from_str(State::default(), "mcts:fast", "random", 1, 0).is_err());
+}
@@ -0,0 +1,168 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
+use poke_engine::engine::state::{PokemonVolatileStatus, Weather};
+use poke_engine::instruction::{
+    ApplyVolatileStatusInstruction, BoostInstruction, DamageInstruction, Instruction,
+    SwitchInstruction,
+};
+use poke_engine::state::{
+    PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonStatus, SideReference, State,
+};
+
+fn assert_diff_instructions_reach_the_other_state(from: &State, to: &State) {
+    let mut state = from.clone();
+    state.apply_instructions(&from.diff_instructions(to));
+    assert!(
+        state.canonical_eq(to),
+        "diff instructions did not produce the expected state:\n{}",
+        state.diff_report(to)
+    );
+}
+
+#[test]
+fn test_identical_states_have_no_differences() {
+    let state = State::default();
+    assert!(state.diff_instructions(&state.clone()).is_empty());
+    assert_eq!("", state.diff_report(&state.clone()));
+}
+
+#[test]
+fn test_diff_of_active_pokemon_and_side_changes() {
+    let from = State::default();
+    let mut to = State::default();
+    to.side_one.get_active().hp -= 20;
+    to.side_one.attack_boost = 2;
+    to.side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+
+    assert_eq!(
+        vec![
+            Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideOne,
+                damage_amount: 20,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Attack,
+                amount: 2,
+            }),
+            Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+                side_ref: SideReference::SideTwo,
+                volatile_status: PokemonVolatileStatus::CONFUSION,
+            }),
+        ],
+        from.diff_instructions(&to)
+    );
+}
+
+#[test]
+fn test_reserve_pokemon_hp_change_switches_the_pokemon_in_and_out() {
+    let from = State::default();
+    let mut to = State::default();
+    to.side_one.pokemon[PokemonIndex::P2].hp -= 10;
+
+    assert_eq!(
+        vec![
+            Instruction::Switch(SwitchInstruction {
+                side_ref: SideReference::SideOne,
+                previous_index: PokemonIndex::P0,
+                next_index: PokemonIndex::P2,
+            }),
+            Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideOne,
+                damage_amount: 10,
+            }),
+            Instruction::Switch(SwitchInstruction {
+                side_ref: SideReference::SideOne,
+                previous_index: PokemonIndex::P2,
+                next_index: PokemonIndex::P0,
+            }),
+        ],
+        from.diff_instructions(&to)
+    );
+    assert_diff_instructions_reach_the_other_state(&from, &to);
+}
+
+#[test]
+fn test_diff_instructions_reach_the_other_state() {
+    let from = State::default();
+    let mut to = State::default();
+    to.side_one.active_index = PokemonIndex::P1;
+    to.side_one.pokemon[PokemonIndex::P0].hp = 0;
+    to.side_one.pokemon[PokemonIndex::P3].status = PokemonStatus::BURN;
+    to.side_one.side_conditions.stealth_rock = 1;
+    to.side_one.wish = (1, 50);
+    to.side_two.future_sight = (2, PokemonIndex::P4);
+    to.side_two.get_active().moves.m1.pp -= 1;
+    to.side_two.get_active().moves.m2.disabled = true;
+    to.side_two.force_switch = true;
+    to.side_two.volatile_status_durations.confusion = 3;
+    to.weather.weather_type = Weather::RAIN;
+    to.weather.turns_remaining = 5;
+    to.trick_room.active = true;
+    to.trick_room.turns_remaining = 4;
+
+    assert_diff_instructions_reach_the_other_state(&from, &to);
+    assert_diff_instructions_reach_the_other_state(&to, &from);
+}
+
+#[test]
+fn test_diff_instructions_reproduce_generated_instructions() {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::UTURN);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::WILLOWISP);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::STEALTHROCK);
+    let (side_one_options, side_two_options) = state.get_all_options();
+
+    for side_one_move in side_one_options.iter() {
+        for side_two_move in side_two_options.iter() {
+            let generated = generate_instructions_from_move_pair(
+                &mut state,
+                side_one_move,
+                side_two_move,
+                DamageBranching::KillNoKill,
+                0.0,
+            );
+            for state_instructions in generated.iter() {
+                let mut expected = state.clone();
+                expected.apply_instructions(&state_instructions.instruction_list);
+                assert_diff_instructions_reach_the_other_state(&state, &expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_diff_report_lists_differences() {
+    let from = State::default();
+    let mut to = State::default();
+    to.side_one.get_active().hp -= 20;
+    to.side_one.get_active().level = 50;
+    to.side_two.speed_boost = -1;
+    to.weather.weather_type = Weather::SUN;
+
+    let report = from.diff_report(&to);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(
+        vec![
+            "side_one P0 NONE hp: 100 -> 80",
+            "side_one P0 NONE level: 100 -> 50 (no instruction)",
+            "side_two Speed boost: 0 -> -1",
+            "weather: NONE -> SUN",
+        ],
+        lines
+    );
+}
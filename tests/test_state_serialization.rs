@@ -0,0 +1,367 @@
+use poke_engine::choices::Choices;
+use poke_engine::engine::state::PokemonVolatileStatus;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{
+    PokemonIndex, PokemonNature, PokemonType, State, SERIALIZED_STATE_VERSION,
+};
+use std::collections::HashSet;
+
+const MOVES: &str = "TACKLE;false;32,TACKLE;false;32,TACKLE;false;32,TACKLE;false;32";
+const FIELD_CONDITIONS: &str = "none;0/none;0/false;0/false";
+
+fn side_string(pokemon: &str, side_tail: &str) -> String {
+    let mut side = [pokemon; 6].join("=");
+    side.push('=');
+    side.push_str(side_tail);
+    side
+}
+
+fn state_string(pokemon: &str, side_tail: &str) -> String {
+    let side = side_string(pokemon, side_tail);
+    format!("{}/{}/{}", side, side, FIELD_CONDITIONS)
+}
+
+// no nature, evs or base_types
+fn v1_pokemon() -> String {
+    format!(
+        "pikachu,100,Electric,Typeless,200,250,NONE,NONE,NONE,150,120,160,130,250,None,0,0,6,{},false,Normal",
+        MOVES
+    )
+}
+
+// nature & evs after the item
+fn v2_pokemon() -> String {
+    format!(
+        "pikachu,100,Electric,Typeless,200,250,NONE,NONE,NONE,TIMID,0;0;4;252;0;252,150,120,160,130,250,None,0,0,6,{},false,Normal",
+        MOVES
+    )
+}
+
+// base_types after the types
+fn v3_pokemon() -> String {
+    format!(
+        "pikachu,100,Electric,Typeless,Electric,Typeless,200,250,NONE,NONE,NONE,TIMID,0;0;4;252;0;252,150,120,160,130,250,None,0,0,6,{},false,Normal",
+        MOVES
+    )
+}
+
+// ivs after the evs
+fn v5_pokemon() -> String {
+    v3_pokemon().replace("0;0;4;252;0;252,", "0;0;4;252;0;252,31;0;31;31;31;31,")
+}
+
+// no shed_tailing or slow_uturn_move
+const V1_SIDE_TAIL: &str = "1=0;0;0;0;0;0;0;0;0;0;0;0;1;0;0;0;0;0;0;==0;0;0;0;0;0=50=1=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=switch:1";
+
+// shed_tailing after baton_passing
+const V3_SIDE_TAIL: &str = "1=0;0;0;0;0;0;0;0;0;0;0;0;1;0;0;0;0;0;0;==0;0;0;0;0;0=50=1=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:1";
+
+// slow_uturn_move at the end
+const V4_SIDE_TAIL: &str = "1=0;0;0;0;0;0;0;0;0;0;0;0;1;0;0;0;0;0;0;==0;0;0;0;0;0=50=1=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:1=false";
+
+// mega_evolved at the end
+const V6_SIDE_TAIL: &str = "1=0;0;0;0;0;0;0;0;0;0;0;0;1;0;0;0;0;0;0;==0;0;0;0;0;0=50=1=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:1=false=true";
+
+// z_move_used at the end
+const V7_SIDE_TAIL: &str = "1=0;0;0;0;0;0;0;0;0;0;0;0;1;0;0;0;0;0;0;==0;0;0;0;0;0=50=1=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:1=false=false=true";
+
+fn assert_common_fields(state: &State) {
+    for side in [&state.side_one, &state.side_two] {
+        assert_eq!(PokemonIndex::P1, side.active_index);
+        assert_eq!(1, side.side_conditions.spikes);
+        assert_eq!(50, side.substitute_health);
+        assert_eq!(1, side.attack_boost);
+        assert!(!side.shed_tailing);
+        assert!(!side.slow_uturn_move);
+
+        let active = side.get_active_immutable();
+        assert_eq!(PokemonName::PIKACHU, active.id);
+        assert_eq!(200, active.hp);
+        assert_eq!(250, active.maxhp);
+        assert_eq!(150, active.attack);
+        assert_eq!(250, active.speed);
+        assert_eq!(
+            (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            active.base_types
+        );
+        assert_eq!(Choices::TACKLE, active.moves.m0.id);
+    }
+}
+
+#[test]
+fn test_serialize_is_prefixed_with_the_current_version() {
+    let state = State::default();
+    assert!(state
+        .serialize()
+        .starts_with(&format!("v{}/", SERIALIZED_STATE_VERSION)));
+}
+
+#[test]
+fn test_serialized_state_round_trips() {
+    let state = State::deserialize(&state_string(&v3_pokemon(), V4_SIDE_TAIL));
+    let serialized = state.serialize();
+    assert_eq!(serialized, State::deserialize(&serialized).serialize());
+}
+
+#[test]
+fn test_deserialize_unversioned_v1_layout() {
+    let state = State::deserialize(&state_string(&v1_pokemon(), V1_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert_eq!(
+        PokemonNature::SERIOUS,
+        state.side_one.get_active_immutable().nature
+    );
+    assert_eq!(
+        (85, 85, 85, 85, 85, 85),
+        state.side_one.get_active_immutable().evs
+    );
+}
+
+#[test]
+fn test_deserialize_unversioned_v2_layout() {
+    let state = State::deserialize(&state_string(&v2_pokemon(), V1_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert_eq!(
+        PokemonNature::TIMID,
+        state.side_one.get_active_immutable().nature
+    );
+    assert_eq!(
+        (0, 0, 4, 252, 0, 252),
+        state.side_one.get_active_immutable().evs
+    );
+}
+
+#[test]
+fn test_deserialize_unversioned_v3_layout() {
+    let state = State::deserialize(&state_string(&v3_pokemon(), V3_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert_eq!(
+        PokemonNature::TIMID,
+        state.side_one.get_active_immutable().nature
+    );
+}
+
+#[test]
+fn test_deserialize_unversioned_v4_layout() {
+    let state = State::deserialize(&state_string(&v3_pokemon(), V4_SIDE_TAIL));
+    assert_common_fields(&state);
+}
+
+#[test]
+fn test_deserialize_explicit_older_version() {
+    let serialized = format!("v1/{}", state_string(&v1_pokemon(), V1_SIDE_TAIL));
+    let state = State::deserialize(&serialized);
+    assert_common_fields(&state);
+}
+
+#[test]
+fn test_upgraded_state_serializes_to_the_current_layout() {
+    let from_v1 = State::deserialize(&state_string(&v1_pokemon(), V1_SIDE_TAIL));
+    let expected = State::deserialize(&format!(
+        "v4/{}",
+        state_string(
+            &v3_pokemon().replace("TIMID,0;0;4;252;0;252", "SERIOUS,85;85;85;85;85;85"),
+            V4_SIDE_TAIL
+        )
+    ));
+    assert_eq!(expected.serialize(), from_v1.serialize());
+}
+
+#[test]
+#[should_panic]
+fn test_deserialize_unknown_version_panics() {
+    State::deserialize(&format!(
+        "v{}/{}",
+        SERIALIZED_STATE_VERSION + 1,
+        state_string(&v3_pokemon(), V4_SIDE_TAIL)
+    ));
+}
+
+#[test]
+fn test_volatile_statuses_serialize_in_sorted_order() {
+    let mut state = State::default();
+    for volatile_status in [
+        PokemonVolatileStatus::SUBSTITUTE,
+        PokemonVolatileStatus::CONFUSION,
+        PokemonVolatileStatus::LEECHSEED,
+    ] {
+        state.side_one.volatile_statuses.insert(volatile_status);
+    }
+    let mut reversed = State::default();
+    for volatile_status in [
+        PokemonVolatileStatus::LEECHSEED,
+        PokemonVolatileStatus::CONFUSION,
+        PokemonVolatileStatus::SUBSTITUTE,
+    ] {
+        reversed.side_one.volatile_statuses.insert(volatile_status);
+    }
+
+    assert!(state
+        .side_one
+        .serialize()
+        .contains("=CONFUSION:LEECHSEED:SUBSTITUTE="));
+    assert_eq!(state.serialize(), reversed.serialize());
+}
+
+#[test]
+fn test_trailing_volatile_status_separator_does_not_add_none() {
+    let state = State::deserialize(&state_string(
+        &v3_pokemon(),
+        &V4_SIDE_TAIL.replace("==0;0;0;0;0;0=", "=SUBSTITUTE:=0;0;0;0;0;0="),
+    ));
+    assert_eq!(
+        HashSet::from([PokemonVolatileStatus::SUBSTITUTE]),
+        state.side_one.volatile_statuses
+    );
+    assert_eq!(
+        state.serialize(),
+        State::deserialize(&state.serialize()).serialize()
+    );
+}
+
+#[test]
+fn test_states_differing_only_in_irrelevant_values_are_canonically_equal() {
+    let state = State::default();
+    let mut other = State::default();
+    other.weather.turns_remaining = 0;
+    other.trick_room.turns_remaining = 3;
+    other.side_one.substitute_health = 25;
+    other.side_two.wish = (0, 100);
+    other.side_two.future_sight = (0, PokemonIndex::P3);
+    other
+        .side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::NONE);
+
+    assert!(state.canonical_eq(&other));
+    assert_eq!(state.canonical_serialize(), other.canonical_serialize());
+    assert_ne!(state.serialize(), other.serialize());
+}
+
+#[test]
+fn test_serialize_keeps_values_that_normalize_would_reset() {
+    let mut state = State::default();
+    state.side_one.substitute_health = 25;
+    state.side_two.wish = (0, 100);
+    state.side_two.future_sight = (0, PokemonIndex::P3);
+
+    let deserialized = State::deserialize(&state.serialize());
+    assert_eq!(25, deserialized.side_one.substitute_health);
+    assert_eq!((0, 100), deserialized.side_two.wish);
+    assert_eq!((0, PokemonIndex::P3), deserialized.side_two.future_sight);
+}
+
+#[test]
+fn test_states_with_different_relevant_values_are_not_canonically_equal() {
+    let state = State::default();
+    let mut other = State::default();
+    other.side_one.get_active().hp -= 1;
+    assert!(!state.canonical_eq(&other));
+
+    let mut other = State::default();
+    other.side_one.wish = (2, 100);
+    assert!(!state.canonical_eq(&other));
+}
+
+#[test]
+fn test_normalize_resets_irrelevant_values() {
+    let mut state = State::default();
+    state.weather.turns_remaining = 0;
+    state.side_one.substitute_health = 25;
+    state.side_one.wish = (0, 100);
+    state.normalize();
+
+    assert_eq!(-1, state.weather.turns_remaining);
+    assert_eq!(0, state.side_one.substitute_health);
+    assert_eq!((0, 0), state.side_one.wish);
+    assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+}
+
+#[test]
+fn test_deserialize_v5_layout_with_ivs() {
+    let state = State::deserialize(&format!("v5/{}", state_string(&v5_pokemon(), V4_SIDE_TAIL)));
+    assert_common_fields(&state);
+    assert_eq!(
+        (31, 0, 31, 31, 31, 31),
+        state.side_one.get_active_immutable().ivs
+    );
+}
+
+#[test]
+fn test_deserialize_unversioned_v5_layout() {
+    let state = State::deserialize(&state_string(&v5_pokemon(), V4_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert_eq!(
+        (31, 0, 31, 31, 31, 31),
+        state.side_two.get_active_immutable().ivs
+    );
+}
+
+#[test]
+fn test_older_layouts_have_perfect_ivs() {
+    let state = State::deserialize(&state_string(&v3_pokemon(), V4_SIDE_TAIL));
+    assert_eq!(
+        (31, 31, 31, 31, 31, 31),
+        state.side_one.get_active_immutable().ivs
+    );
+    assert!(state.serialize().contains(",31;31;31;31;31;31,"));
+}
+
+#[test]
+fn test_deserialize_v6_layout_with_mega_evolved() {
+    let state = State::deserialize(&format!("v6/{}", state_string(&v5_pokemon(), V6_SIDE_TAIL)));
+    assert_common_fields(&state);
+    assert!(state.side_one.mega_evolved);
+    assert!(state.side_two.mega_evolved);
+    assert!(state.serialize().contains("=switch:1=false=true=false/"));
+}
+
+#[test]
+fn test_deserialize_unversioned_v6_layout() {
+    let state = State::deserialize(&state_string(&v5_pokemon(), V6_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert!(state.side_two.mega_evolved);
+}
+
+#[test]
+fn test_older_layouts_have_not_mega_evolved() {
+    let state = State::deserialize(&format!("v5/{}", state_string(&v5_pokemon(), V4_SIDE_TAIL)));
+    assert!(!state.side_one.mega_evolved);
+    assert!(!state.side_two.mega_evolved);
+}
+
+#[test]
+fn test_deserialize_v7_layout_with_z_move_used() {
+    let state = State::deserialize(&format!("v7/{}", state_string(&v5_pokemon(), V7_SIDE_TAIL)));
+    assert_common_fields(&state);
+    assert!(!state.side_one.mega_evolved);
+    assert!(state.side_one.z_move_used);
+    assert!(state.side_two.z_move_used);
+    assert!(state.serialize().contains("=switch:1=false=false=true/"));
+}
+
+#[test]
+fn test_deserialize_unversioned_v7_layout() {
+    let state = State::deserialize(&state_string(&v5_pokemon(), V7_SIDE_TAIL));
+    assert_common_fields(&state);
+    assert!(state.side_two.z_move_used);
+}
+
+#[test]
+fn test_older_layouts_have_not_used_a_z_move() {
+    let state = State::deserialize(&format!("v6/{}", state_string(&v5_pokemon(), V6_SIDE_TAIL)));
+    assert!(state.side_one.mega_evolved);
+    assert!(!state.side_one.z_move_used);
+    assert!(!state.side_two.z_move_used);
+}
+
+#[test]
+fn test_deserialize_unversioned_layout_starting_with_a_v_pokemon() {
+    let pokemon = v5_pokemon().replacen("pikachu", "venusaur", 1);
+    let state = State::deserialize(&state_string(&pokemon, V7_SIDE_TAIL));
+    assert_eq!(
+        PokemonName::VENUSAUR,
+        state.side_one.get_active_immutable().id
+    );
+    assert!(state.side_one.z_move_used);
+}
@@ -0,0 +1,149 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::engine::state::PokemonVolatileStatus;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{
+    PokemonBoostableStat, PokemonIndex, PokemonSideCondition, SideReference, State,
+};
+use poke_engine::state_validation::{StateValidationIssue, StateValidationSeverity};
+
+#[test]
+fn test_default_state_is_valid() {
+    assert_eq!(
+        Vec::<StateValidationIssue>::new(),
+        State::default().validate()
+    );
+}
+
+#[test]
+fn test_hp_above_maxhp_is_an_error() {
+    let mut state = State::default();
+    state.side_two.pokemon[PokemonIndex::P3].hp = 150;
+
+    let issues = state.validate();
+    assert_eq!(
+        vec![StateValidationIssue::HpAboveMaxHp {
+            side_ref: SideReference::SideTwo,
+            pokemon_index: PokemonIndex::P3,
+            hp: 150,
+            maxhp: 100,
+        }],
+        issues
+    );
+    assert_eq!(StateValidationSeverity::Error, issues[0].severity());
+}
+
+#[test]
+fn test_boost_out_of_range_is_an_error() {
+    let mut state = State::default();
+    state.side_one.attack_boost = 7;
+    state.side_one.evasion_boost = -8;
+
+    assert_eq!(
+        vec![
+            StateValidationIssue::BoostOutOfRange {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Attack,
+                boost: 7,
+            },
+            StateValidationIssue::BoostOutOfRange {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Evasion,
+                boost: -8,
+            },
+        ],
+        state.validate()
+    );
+}
+
+#[test]
+fn test_fainted_active_between_turns_is_valid() {
+    // the engine expects a fainted active to be switched out on the next turn
+    let mut state = State::default();
+    state.side_two.get_active().hp = 0;
+    assert!(state.validate().is_empty());
+
+    state.side_two.force_switch = true;
+    assert!(state.validate().is_empty());
+}
+
+#[test]
+fn test_negative_hp_active_is_an_error() {
+    let mut state = State::default();
+    state.side_two.get_active().hp = -5;
+
+    let issues = state.validate();
+    assert_eq!(
+        vec![StateValidationIssue::NegativeHp {
+            side_ref: SideReference::SideTwo,
+            pokemon_index: PokemonIndex::P0,
+            hp: -5,
+        }],
+        issues
+    );
+    assert!(issues[0].is_error());
+}
+
+#[test]
+fn test_substitute_health_must_match_substitute_volatile() {
+    let mut state = State::default();
+    state.side_one.substitute_health = 25;
+    state
+        .side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::SUBSTITUTE);
+
+    assert_eq!(
+        vec![
+            StateValidationIssue::SubstituteHealthWithoutSubstitute {
+                side_ref: SideReference::SideOne,
+                substitute_health: 25,
+            },
+            StateValidationIssue::SubstituteWithoutSubstituteHealth {
+                side_ref: SideReference::SideTwo,
+            },
+        ],
+        state.validate()
+    );
+}
+
+#[test]
+fn test_duplicate_species_and_side_conditions_are_warnings() {
+    let mut state = State::default();
+    state.side_one.pokemon[PokemonIndex::P0].id = PokemonName::PIKACHU;
+    state.side_one.pokemon[PokemonIndex::P4].id = PokemonName::PIKACHU;
+    state.side_one.side_conditions.spikes = 4;
+
+    let issues = state.validate();
+    assert_eq!(
+        vec![
+            StateValidationIssue::DuplicateSpecies {
+                side_ref: SideReference::SideOne,
+                pokemon: PokemonName::PIKACHU,
+            },
+            StateValidationIssue::SideConditionOutOfRange {
+                side_ref: SideReference::SideOne,
+                side_condition: PokemonSideCondition::Spikes,
+                amount: 4,
+            },
+        ],
+        issues
+    );
+    assert!(issues.iter().all(|issue| !issue.is_error()));
+}
+
+#[test]
+fn test_issues_display_their_severity() {
+    let mut state = State::default();
+    state.side_one.attack_boost = 7;
+    state.side_two.substitute_health = 25;
+
+    let lines: Vec<String> = state.validate().iter().map(|i| i.to_string()).collect();
+    assert_eq!(
+        vec![
+            "error: SideOne Attack boost (7) is outside of -6 to 6",
+            "warning: SideTwo has substitute_health (25) without the SUBSTITUTE volatile status",
+        ],
+        lines
+    );
+}
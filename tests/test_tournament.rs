@@ -0,0 +1,133 @@
+use poke_engine::choices::Choices;
+use poke_engine::state::{PokemonMoveIndex, Side, State};
+use poke_engine::tournament::{elo_ratings, wilson_interval, Matchup, Tournament};
+
+// a team whose active pokemon can attack
+fn team(choice: Choices) -> Side {
+    let mut side = State::default().side_one;
+    side.get_active().replace_move(PokemonMoveIndex::M0, choice);
+    side
+}
+
+#[test]
+fn test_wilson_interval_contains_the_win_rate_and_narrows_with_more_games() {
+    let (low, high) = wilson_interval(0.6, 10);
+    assert!(low < 0.6 && 0.6 < high);
+    let (more_low, more_high) = wilson_interval(0.6, 1000);
+    assert!(low < more_low && more_high < high);
+    assert_eq!((0.0, 1.0), wilson_interval(0.0, 0));
+    assert_eq!(1.0, wilson_interval(1.0, 10).1);
+}
+
+#[test]
+fn test_even_results_give_equal_elo() {
+    let matchups = [Matchup {
+        agents: (0, 1),
+        wins: 5,
+        losses: 5,
+        draws: 2,
+    }];
+    let elo = elo_ratings(2, &matchups);
+    assert!((elo[0] - 1500.0).abs() < 0.1);
+    assert!((elo[1] - 1500.0).abs() < 0.1);
+}
+
+#[test]
+fn test_elo_follows_the_results() {
+    let matchups = [
+        Matchup {
+            agents: (0, 1),
+            wins: 9,
+            losses: 1,
+            draws: 0,
+        },
+        Matchup {
+            agents: (1, 2),
+            wins: 9,
+            losses: 1,
+            draws: 0,
+        },
+        Matchup {
+            agents: (0, 2),
+            wins: 10,
+            losses: 0,
+            draws: 0,
+        },
+    ];
+    let elo = elo_ratings(3, &matchups);
+    assert!(elo[0] > elo[1] && elo[1] > elo[2]);
+    assert!(elo[0].is_finite() && elo[2].is_finite());
+    assert!((elo.iter().sum::<f32>() / 3.0 - 1500.0).abs() < 0.1);
+}
+
+#[test]
+fn test_agents_alternate_sides_and_go_through_every_pairing_of_teams() {
+    let mut tournament = Tournament::new(
+        vec!["random".to_string(), "greedy-damage".to_string()],
+        vec![team(Choices::TACKLE), team(Choices::BODYSLAM)],
+    );
+    tournament.games_per_matchup = 8;
+    let mut games_seen = 0;
+    let result = tournament.play(|_| games_seen += 1).unwrap();
+
+    assert_eq!(8, games_seen);
+    assert_eq!(8, result.games.len());
+    for (index, game) in result.games.iter().enumerate() {
+        assert_eq!(index % 2, game.side_one_agent);
+        assert_eq!(1 - index % 2, game.side_two_agent);
+    }
+    for pair in result.games.chunks(2) {
+        assert_eq!(pair[0].side_one_team, pair[1].side_one_team);
+        assert_eq!(pair[0].side_two_team, pair[1].side_two_team);
+    }
+    let mut pairings: Vec<(usize, usize)> = result
+        .games
+        .iter()
+        .map(|g| (g.side_one_team, g.side_two_team))
+        .collect();
+    pairings.sort();
+    pairings.dedup();
+    assert_eq!(vec![(0, 0), (0, 1), (1, 0), (1, 1)], pairings);
+}
+
+#[test]
+fn test_standings_add_up_to_the_games_played() {
+    let mut tournament = Tournament::new(
+        vec![
+            "random".to_string(),
+            "greedy-damage".to_string(),
+            "expectiminimax:1".to_string(),
+        ],
+        vec![team(Choices::TACKLE)],
+    );
+    tournament.games_per_matchup = 4;
+    tournament.max_turns = 20;
+    let result = tournament.play(|_| {}).unwrap();
+
+    assert_eq!(3, result.matchups.len());
+    assert_eq!(12, result.games.len());
+    for standing in result.standings.iter() {
+        assert_eq!(8, standing.games());
+        assert!(standing.confidence_interval.0 <= standing.win_rate);
+        assert!(standing.win_rate <= standing.confidence_interval.1);
+    }
+    assert!(result.standings.windows(2).all(|s| s[0].elo >= s[1].elo));
+}
+
+#[test]
+fn test_invalid_tournaments_are_errors() {
+    let teams = vec![team(Choices::TACKLE)];
+    assert!(Tournament::new(vec!["random".to_string()], teams.clone())
+        .play(|_| {})
+        .is_err());
+    assert!(
+        Tournament::new(vec!["random".to_string(), "minimax".to_string()], teams)
+            .play(|_| {})
+            .is_err()
+    );
+    assert!(
+        Tournament::new(vec!["random".to_string(), "random".to_string()], vec![])
+            .play(|_| {})
+            .is_err()
+    );
+}
@@ -0,0 +1,173 @@
+use poke_engine::choices::Choices;
+use poke_engine::engine::state::MoveChoice;
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, State};
+use poke_engine::training_data::{
+    move_choice_code, move_choice_from_code, self_play, ShardedWriter, TrainingDataFormat,
+    TrainingExample,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::time::Duration;
+
+// side two only has one pokemon left and both actives can attack
+fn state() -> State {
+    let mut state = State::default();
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::BODYSLAM);
+    state
+        .side_two
+        .get_active()
+        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_two.pokemon.p1.hp = 0;
+    state.side_two.pokemon.p2.hp = 0;
+    state.side_two.pokemon.p3.hp = 0;
+    state.side_two.pokemon.p4.hp = 0;
+    state.side_two.pokemon.p5.hp = 0;
+    state
+}
+
+fn example() -> TrainingExample {
+    TrainingExample {
+        state: state(),
+        side_one: vec![
+            (MoveChoice::Move(PokemonMoveIndex::M0), 120),
+            (MoveChoice::Move(PokemonMoveIndex::M1), 880),
+            (MoveChoice::Switch(PokemonIndex::P5), 0),
+        ],
+        side_two: vec![(MoveChoice::Move(PokemonMoveIndex::M0), 1000)],
+        outcome: -1.0,
+    }
+}
+
+fn temporary_directory(name: &str) -> String {
+    let directory =
+        std::env::temp_dir().join(format!("poke-engine-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    directory.to_str().unwrap().to_string()
+}
+
+#[test]
+fn test_self_play_records_the_outcome_in_every_example() {
+    let examples = self_play(
+        state(),
+        Duration::from_millis(5),
+        100,
+        1.0,
+        &mut StdRng::seed_from_u64(0),
+    );
+    assert!(!examples.is_empty());
+    let outcome = examples[0].outcome;
+    assert!(outcome == 1.0 || outcome == -1.0);
+    for example in examples.iter() {
+        assert_eq!(outcome, example.outcome);
+        assert!(example.side_one.iter().map(|(_, v)| v).sum::<i64>() > 0);
+        assert!(!example.side_two.is_empty());
+    }
+}
+
+#[test]
+fn test_move_choice_codes_round_trip() {
+    let mut choices = vec![MoveChoice::None];
+    for move_index in [
+        PokemonMoveIndex::M0,
+        PokemonMoveIndex::M1,
+        PokemonMoveIndex::M2,
+        PokemonMoveIndex::M3,
+    ] {
+        choices.push(MoveChoice::Move(move_index));
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        choices.push(MoveChoice::MoveTera(move_index));
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        choices.push(MoveChoice::MoveMega(move_index));
+        #[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+        choices.push(MoveChoice::MoveZ(move_index));
+    }
+    for pkmn_index in [PokemonIndex::P0, PokemonIndex::P3, PokemonIndex::P5] {
+        choices.push(MoveChoice::Switch(pkmn_index));
+    }
+    for choice in choices {
+        assert_eq!(
+            Some(choice),
+            move_choice_from_code(move_choice_code(&choice))
+        );
+    }
+    assert_eq!(None, move_choice_from_code(100));
+}
+
+#[test]
+fn test_move_discriminants_do_not_change() {
+    // data written before the z-moves were added must read back the same moves
+    assert_eq!(0, Choices::NONE as u16);
+    assert_eq!(1, Choices::ABSORB as u16);
+    assert_eq!(782, Choices::TACKLE as u16);
+    assert_eq!(884, Choices::ZIPPYZAP as u16);
+    assert_eq!(885, Choices::ACIDDOWNPOUR as u16);
+    assert_eq!(Choices::TACKLE, Choices::from(782));
+}
+
+#[test]
+fn test_binary_examples_round_trip() {
+    let mut bytes = example().to_bytes();
+    bytes.extend(example().to_bytes());
+    let examples = TrainingExample::from_bytes(&bytes).unwrap();
+    assert_eq!(2, examples.len());
+    for read in examples {
+        assert_eq!(example().state.serialize(), read.state.serialize());
+        assert_eq!(example().side_one, read.side_one);
+        assert_eq!(example().side_two, read.side_two);
+        assert_eq!(-1.0, read.outcome);
+    }
+
+    bytes.pop();
+    assert!(TrainingExample::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_examples_are_sharded_across_files() {
+    let directory = temporary_directory("jsonl-shards");
+    let mut writer = ShardedWriter::new(&directory, TrainingDataFormat::Jsonl, 2).unwrap();
+    for _ in 0..5 {
+        writer.write(&example()).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(3, writer.shards.len());
+    let line_counts: Vec<usize> = writer
+        .shards
+        .iter()
+        .map(|path| std::fs::read_to_string(path).unwrap().lines().count())
+        .collect();
+    assert_eq!(vec![2, 2, 1], line_counts);
+    let first = std::fs::read_to_string(&writer.shards[0]).unwrap();
+    let json: serde_json::Value = serde_json::from_str(first.lines().next().unwrap()).unwrap();
+    assert_eq!(-1.0, json["outcome"]);
+    assert_eq!("tackle", json["side_one"]["moves"][0]);
+    assert_eq!("bodyslam", json["side_one"]["moves"][1]);
+    assert_eq!(serde_json::json!([120, 880, 0]), json["side_one"]["visits"]);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_binary_shards_can_be_read_back() {
+    let directory = temporary_directory("binary-shards");
+    let mut writer = ShardedWriter::new(&directory, TrainingDataFormat::Binary, 10).unwrap();
+    for _ in 0..3 {
+        writer.write(&example()).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(1, writer.shards.len());
+    assert!(writer.shards[0]
+        .to_str()
+        .unwrap()
+        .ends_with("shard-00000.bin"));
+    let bytes = std::fs::read(&writer.shards[0]).unwrap();
+    assert_eq!(3, TrainingExample::from_bytes(&bytes).unwrap().len());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
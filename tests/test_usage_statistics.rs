@@ -0,0 +1,218 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::engine::abilities::Abilities;
+use poke_engine::engine::items::Items;
+use poke_engine::pokemon::PokemonName;
+use poke_engine::protocol::ShowdownBattle;
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, PokemonNature};
+use poke_engine::usage_statistics::{Spread, UsageStatistics};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const CHAOS: &str = r#"{
+    "info": {"metagame": "gen9ou", "cutoff": 1695, "number of battles": 1000},
+    "data": {
+        "Garchomp": {
+            "Raw count": 1000,
+            "Abilities": {"roughskin": 900.0, "sandveil": 100.0},
+            "Items": {"choicescarf": 500.0, "lifeorb": 300.0, "rockyhelmet": 150.0, "nothing": 50.0},
+            "Spreads": {
+                "Jolly:0/252/0/0/4/252": 600.0,
+                "Adamant:0/252/0/0/4/252": 300.0,
+                "Impish:252/0/252/0/4/0": 100.0
+            },
+            "Moves": {
+                "earthquake": 1000.0,
+                "outrage": 800.0,
+                "stoneedge": 700.0,
+                "firefang": 500.0,
+                "swordsdance": 400.0,
+                "stealthrock": 300.0,
+                "notarealmove": 200.0,
+                "": 100.0
+            },
+            "Tera Types": {"fire": 400.0, "steel": 350.0, "ground": 250.0}
+        },
+        "Notarealpokemon": {
+            "Abilities": {"roughskin": 1.0}
+        }
+    }
+}"#;
+
+fn stats() -> UsageStatistics {
+    UsageStatistics::from_json(CHAOS).unwrap()
+}
+
+fn opponent_garchomp(extra: &str) -> ShowdownBattle {
+    let mut battle = ShowdownBattle::new("p1");
+    battle
+        .parse_message(&format!(
+            "|switch|p2a: Garchomp|Garchomp, M|50/100\n{}",
+            extra
+        ))
+        .unwrap();
+    battle
+}
+
+#[test]
+fn test_parse_chaos_json() {
+    let stats = stats();
+    assert_eq!(1, stats.pokemon.len());
+    let usage = &stats.pokemon[&PokemonName::GARCHOMP];
+    assert_eq!(
+        vec![(Abilities::ROUGHSKIN, 0.9), (Abilities::SANDVEIL, 0.1)],
+        usage.abilities
+    );
+    assert_eq!((Items::NONE, 0.05), usage.items[3]);
+    assert_eq!(
+        (
+            Spread {
+                nature: PokemonNature::JOLLY,
+                evs: (0, 252, 0, 0, 4, 252)
+            },
+            0.6
+        ),
+        usage.spreads[0]
+    );
+    // moves the engine does not know about and empty move slots are skipped
+    assert_eq!(6, usage.moves.len());
+    assert_eq!((Choices::EARTHQUAKE, 1.0), usage.moves[0]);
+    assert_eq!((Choices::STEALTHROCK, 0.3), usage.moves[5]);
+}
+
+#[test]
+fn test_invalid_chaos_json() {
+    assert!(UsageStatistics::from_json("not json").is_err());
+    assert!(UsageStatistics::from_json("{\"info\": {}}").is_err());
+}
+
+#[test]
+fn test_most_likely_set_with_nothing_revealed() {
+    let battle = opponent_garchomp("");
+    let sets = stats().likely_sets(battle.state.side_two.get_active_immutable(), 3);
+    assert_eq!(3, sets.len());
+    let set = &sets[0];
+    assert_eq!(Abilities::ROUGHSKIN, set.ability);
+    assert_eq!(Items::CHOICESCARF, set.item);
+    assert_eq!(PokemonNature::JOLLY, set.nature);
+    assert_eq!(
+        vec![
+            Choices::EARTHQUAKE,
+            Choices::OUTRAGE,
+            Choices::STONEEDGE,
+            Choices::FIREFANG
+        ],
+        set.moves
+    );
+    assert!(sets[0].weight >= sets[1].weight && sets[1].weight >= sets[2].weight);
+    #[cfg(feature = "gen9")]
+    assert_eq!(poke_engine::state::PokemonType::FIRE, set.tera_type);
+}
+
+#[test]
+fn test_likely_sets_respect_revealed_information() {
+    let battle = opponent_garchomp(
+        "|move|p2a: Garchomp|Swords Dance|p2a: Garchomp\n\
+         |-damage|p2a: Garchomp|40/100|[from] item: Life Orb\n\
+         |-ability|p2a: Garchomp|Rough Skin",
+    );
+    let sets = stats().likely_sets(battle.state.side_two.get_active_immutable(), 10);
+    assert_eq!(10, sets.len());
+    for set in sets.iter() {
+        assert_eq!(Abilities::ROUGHSKIN, set.ability);
+        assert_eq!(Items::LIFEORB, set.item);
+        assert_eq!(Choices::SWORDSDANCE, set.moves[0]);
+        assert_eq!(4, set.moves.len());
+    }
+    assert_eq!(
+        vec![
+            Choices::SWORDSDANCE,
+            Choices::EARTHQUAKE,
+            Choices::OUTRAGE,
+            Choices::STONEEDGE
+        ],
+        sets[0].moves
+    );
+}
+
+#[test]
+fn test_likely_sets_have_at_most_one_hidden_power() {
+    let stats = UsageStatistics::from_json(
+        r#"{"data": {"Magnezone": {
+            "Raw count": 1000,
+            "Abilities": {"magnetpull": 1000.0},
+            "Items": {"choicespecs": 1000.0},
+            "Spreads": {"Modest:0/0/0/252/4/252": 1000.0},
+            "Moves": {
+                "hiddenpowerfire": 900.0,
+                "hiddenpowerground": 800.0,
+                "thunderbolt": 700.0
+            }
+        }}}"#,
+    )
+    .unwrap();
+    let mut battle = ShowdownBattle::new("p1");
+    battle
+        .parse_message("|switch|p2a: Magnezone|Magnezone|100/100")
+        .unwrap();
+    let sets = stats.likely_sets(battle.state.side_two.get_active_immutable(), 3);
+    assert_eq!(2, sets.len());
+    assert_eq!(
+        vec![Choices::HIDDENPOWERFIRE60, Choices::THUNDERBOLT],
+        sets[0].moves
+    );
+    assert_eq!(
+        vec![Choices::HIDDENPOWERGROUND60, Choices::THUNDERBOLT],
+        sets[1].moves
+    );
+}
+
+#[test]
+fn test_fill_side_with_most_likely_sets() {
+    let mut battle = opponent_garchomp("|move|p2a: Garchomp|Stealth Rock|p1a: Pikachu");
+    stats().fill_side(&mut battle.state.side_two);
+    let pkmn = battle.state.side_two.get_active_immutable();
+    assert_eq!(Items::CHOICESCARF, pkmn.item);
+    assert_eq!(Abilities::ROUGHSKIN, pkmn.ability);
+    assert_eq!(PokemonNature::JOLLY, pkmn.nature);
+    assert_eq!((0, 252, 0, 0, 4, 252), pkmn.evs);
+    assert_eq!(357, pkmn.maxhp);
+    assert_eq!(179, pkmn.hp);
+    assert_eq!(359, pkmn.attack);
+    assert_eq!(333, pkmn.speed);
+
+    // the revealed move keeps its slot and the unrevealed slots can be used
+    assert_eq!(Choices::STEALTHROCK, pkmn.moves[&PokemonMoveIndex::M0].id);
+    assert_eq!(Choices::EARTHQUAKE, pkmn.moves[&PokemonMoveIndex::M1].id);
+    assert_eq!(Choices::OUTRAGE, pkmn.moves[&PokemonMoveIndex::M2].id);
+    assert_eq!(Choices::STONEEDGE, pkmn.moves[&PokemonMoveIndex::M3].id);
+    assert!(!pkmn.moves[&PokemonMoveIndex::M3].disabled);
+    assert!(pkmn.moves[&PokemonMoveIndex::M3].pp > 0);
+
+    // pokemon without usage statistics are unchanged
+    assert_eq!(
+        PokemonName::NONE,
+        battle.state.side_two.pokemon[PokemonIndex::P1].id
+    );
+}
+
+#[test]
+fn test_sample_side_is_consistent_with_revealed_information() {
+    let stats = stats();
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..50 {
+        let mut battle = opponent_garchomp("|-item|p2a: Garchomp|Rocky Helmet");
+        stats.sample_side(&mut battle.state.side_two, &mut rng);
+        let pkmn = battle.state.side_two.get_active_immutable();
+        assert_eq!(Items::ROCKYHELMET, pkmn.item);
+        assert!(
+            pkmn.base_ability == Abilities::ROUGHSKIN || pkmn.base_ability == Abilities::SANDVEIL
+        );
+        let moves: Vec<Choices> = pkmn.moves.into_iter().map(|m| m.id).collect();
+        assert!(!moves.contains(&Choices::NONE));
+        for (index, choice) in moves.iter().enumerate() {
+            assert!(!moves[index + 1..].contains(choice));
+        }
+    }
+}
@@ -0,0 +1,264 @@
+#![cfg(feature = "gen7")]
+
+use poke_engine::choices::{Choices, MoveCategory, MOVES};
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
+use poke_engine::engine::items::Items;
+use poke_engine::engine::state::MoveChoice;
+use poke_engine::instruction::{
+    BoostInstruction, Instruction, StateInstructions, ToggleZMoveUsedInstruction,
+};
+use poke_engine::pokemon::PokemonName;
+use poke_engine::state::{
+    Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonNature, SideReference,
+    State,
+};
+
+fn snorlax() -> Pokemon {
+    let mut pkmn = Pokemon::from_species(
+        PokemonName::SNORLAX,
+        100,
+        (85, 85, 85, 85, 85, 85),
+        PokemonNature::SERIOUS,
+    );
+    pkmn.replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    pkmn.replace_move(PokemonMoveIndex::M1, Choices::SPLASH);
+    pkmn.replace_move(PokemonMoveIndex::M2, Choices::EARTHQUAKE);
+    pkmn.replace_move(PokemonMoveIndex::M3, Choices::PROTECT);
+    pkmn
+}
+
+fn state_with_z_crystal(item: Items) -> State {
+    let mut state = State::default();
+    state.side_one.pokemon[PokemonIndex::P0] = snorlax();
+    state.side_one.pokemon[PokemonIndex::P0].item = item;
+    state.side_two.pokemon[PokemonIndex::P0] = snorlax();
+    state
+}
+
+fn generate_instructions(
+    state: &mut State,
+    side_one_move: &MoveChoice,
+    side_two_move: &MoveChoice,
+) -> Vec<StateInstructions> {
+    let before_state_string = format!("{:?}", state);
+    let instructions = generate_instructions_from_move_pair(
+        state,
+        side_one_move,
+        side_two_move,
+        DamageBranching::None,
+        0.0,
+    );
+    assert_eq!(before_state_string, format!("{:?}", state));
+    instructions
+}
+
+// the damage side one's move does to side two
+fn side_two_damage(instructions: &StateInstructions) -> i16 {
+    instructions
+        .instruction_list
+        .iter()
+        .find_map(|instruction| match instruction {
+            Instruction::Damage(damage) if damage.side_ref == SideReference::SideTwo => {
+                Some(damage.damage_amount)
+            }
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_z_move_options_are_only_for_moves_of_the_z_crystal_type() {
+    let state = state_with_z_crystal(Items::NORMALIUMZ);
+    let (side_one_options, side_two_options) = state.get_all_options();
+
+    assert!(side_one_options.contains(&MoveChoice::MoveZ(PokemonMoveIndex::M0)));
+    assert!(side_one_options.contains(&MoveChoice::MoveZ(PokemonMoveIndex::M1)));
+    assert!(side_one_options.contains(&MoveChoice::MoveZ(PokemonMoveIndex::M3)));
+    assert!(!side_one_options.contains(&MoveChoice::MoveZ(PokemonMoveIndex::M2)));
+    assert!(!side_two_options
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveZ(_))));
+}
+
+#[test]
+fn test_no_z_move_options_after_the_side_has_used_a_z_move() {
+    let mut state = state_with_z_crystal(Items::GROUNDIUMZ);
+    assert!(state
+        .get_all_options()
+        .0
+        .contains(&MoveChoice::MoveZ(PokemonMoveIndex::M2)));
+
+    state.side_one.z_move_used = true;
+    assert!(!state
+        .get_all_options()
+        .0
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveZ(_))));
+}
+
+#[test]
+fn test_z_move_base_power_conversion() {
+    let z_base_power = |move_id: Choices| MOVES.get(&move_id).unwrap().z_move_base_power();
+    assert_eq!(100.0, z_base_power(Choices::TACKLE));
+    assert_eq!(120.0, z_base_power(Choices::BITE));
+    assert_eq!(175.0, z_base_power(Choices::FLAMETHROWER));
+    assert_eq!(180.0, z_base_power(Choices::EARTHQUAKE));
+    assert_eq!(185.0, z_base_power(Choices::HYDROPUMP));
+    assert_eq!(190.0, z_base_power(Choices::CLOSECOMBAT));
+    assert_eq!(200.0, z_base_power(Choices::GIGAIMPACT));
+    // multi-hit moves count three hits
+    assert_eq!(140.0, z_base_power(Choices::BULLETSEED));
+    // moves with variable or fixed damage have their own z-move base power
+    assert_eq!(160.0, z_base_power(Choices::LOWKICK));
+    assert_eq!(100.0, z_base_power(Choices::SEISMICTOSS));
+}
+
+#[test]
+fn test_damaging_moves_become_the_z_move_of_their_type() {
+    let z_move = MOVES.get(&Choices::THUNDERBOLT).unwrap().to_z_move();
+    assert_eq!(Choices::GIGAVOLTHAVOC, z_move.move_id);
+    assert_eq!(MoveCategory::Special, z_move.category);
+    assert_eq!(175.0, z_move.base_power);
+    assert!(z_move.z_move);
+
+    let z_status_move = MOVES.get(&Choices::SWORDSDANCE).unwrap().to_z_move();
+    assert_eq!(Choices::SWORDSDANCE, z_status_move.move_id);
+    assert!(z_status_move.z_move);
+}
+
+#[test]
+fn test_using_a_z_move_sets_the_once_per_battle_flag() {
+    let mut state = state_with_z_crystal(Items::NORMALIUMZ);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+    let tackle_instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+    assert_eq!(
+        Instruction::ToggleZMoveUsed(ToggleZMoveUsedInstruction {
+            side_ref: SideReference::SideOne,
+        }),
+        instructions[0].instruction_list[0]
+    );
+    // breakneck blitz has 100 base power instead of tackle's 40
+    assert!(side_two_damage(&instructions[0]) > 2 * side_two_damage(&tackle_instructions[0]));
+
+    state.apply_instructions(&instructions[0].instruction_list);
+    assert!(state.side_one.z_move_used);
+    assert!(!state.side_two.z_move_used);
+}
+
+#[test]
+fn test_z_splash_boosts_attack_by_three() {
+    let mut state = state_with_z_crystal(Items::NORMALIUMZ);
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+    assert!(instructions[0]
+        .instruction_list
+        .contains(&Instruction::Boost(BoostInstruction {
+            side_ref: SideReference::SideOne,
+            stat: PokemonBoostableStat::Attack,
+            amount: 3,
+        })));
+}
+
+#[test]
+fn test_z_swords_dance_clears_negative_boosts_first() {
+    let mut state = state_with_z_crystal(Items::NORMALIUMZ);
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::SWORDSDANCE);
+    state.side_one.attack_boost = -2;
+    state.side_one.speed_boost = -1;
+    let instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+
+    state.apply_instructions(&instructions[0].instruction_list);
+    assert_eq!(2, state.side_one.attack_boost);
+    assert_eq!(0, state.side_one.speed_boost);
+}
+
+#[test]
+fn test_protect_lets_a_quarter_of_a_z_move_through() {
+    let mut state = state_with_z_crystal(Items::NORMALIUMZ);
+    let unprotected = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+    let protected = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M0),
+        &MoveChoice::Move(PokemonMoveIndex::M3),
+    );
+    let unprotected_damage = side_two_damage(&unprotected[0]);
+    let protected_damage = side_two_damage(&protected[0]);
+
+    assert!(protected_damage > 0);
+    assert!((4 * protected_damage - unprotected_damage).abs() <= 4);
+}
+
+#[test]
+fn test_species_exclusive_z_crystal_only_works_for_its_species_and_move() {
+    let mut state = state_with_z_crystal(Items::SNORLIUMZ);
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::GIGAIMPACT);
+    let (side_one_options, _) = state.get_all_options();
+    assert_eq!(
+        vec![&MoveChoice::MoveZ(PokemonMoveIndex::M1)],
+        side_one_options
+            .iter()
+            .filter(|option| matches!(option, MoveChoice::MoveZ(_)))
+            .collect::<Vec<&MoveChoice>>()
+    );
+
+    state.side_one.get_active().id = PokemonName::MUNCHLAX;
+    assert!(!state
+        .get_all_options()
+        .0
+        .iter()
+        .any(|option| matches!(option, MoveChoice::MoveZ(_))));
+}
+
+#[test]
+fn test_species_exclusive_z_move_replaces_its_base_move() {
+    let mut state = state_with_z_crystal(Items::SNORLIUMZ);
+    state
+        .side_one
+        .get_active()
+        .replace_move(PokemonMoveIndex::M1, Choices::GIGAIMPACT);
+    let z_move_instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::MoveZ(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+    let giga_impact_instructions = generate_instructions(
+        &mut state,
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+        &MoveChoice::Move(PokemonMoveIndex::M1),
+    );
+
+    // pulverizing pancake has 210 base power and does not need a recharge turn
+    assert!(
+        side_two_damage(&z_move_instructions[0]) > side_two_damage(&giga_impact_instructions[0])
+    );
+    assert!(!z_move_instructions[0]
+        .instruction_list
+        .iter()
+        .any(|instruction| matches!(instruction, Instruction::ApplyVolatileStatus(_))));
+}
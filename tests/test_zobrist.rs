@@ -0,0 +1,205 @@
+#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+
+use poke_engine::choices::Choices;
+use poke_engine::damage_branching::DamageBranching;
+use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
+use poke_engine::engine::state::PokemonVolatileStatus;
+use poke_engine::instruction::{
+    ApplyVolatileStatusInstruction, DamageInstruction, Instruction, SwitchInstruction,
+};
+use poke_engine::state::{PokemonIndex, PokemonMoveIndex, SideReference, State};
+
+fn state_with_moves(side_one_moves: [Choices; 4], side_two_moves: [Choices; 4]) -> State {
+    let mut state = State::default();
+    let move_indices = [
+        PokemonMoveIndex::M0,
+        PokemonMoveIndex::M1,
+        PokemonMoveIndex::M2,
+        PokemonMoveIndex::M3,
+    ];
+    for i in 0..4 {
+        state
+            .side_one
+            .get_active()
+            .replace_move(move_indices[i], side_one_moves[i]);
+        state
+            .side_two
+            .get_active()
+            .replace_move(move_indices[i], side_two_moves[i]);
+    }
+    state.refresh_zobrist_hash();
+    state
+}
+
+#[test]
+fn test_default_state_hash_matches_full_recompute() {
+    let state = State::default();
+    assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+}
+
+#[test]
+fn test_deserialized_state_has_the_same_hash_as_the_original() {
+    let state = State::default();
+    let deserialized = State::deserialize(&state.serialize());
+    assert_eq!(state.zobrist_hash, deserialized.zobrist_hash);
+}
+
+#[test]
+fn test_hash_changes_when_applying_an_instruction_and_is_restored_by_reversing_it() {
+    let mut state = State::default();
+    let initial_hash = state.zobrist_hash;
+    let instruction = Instruction::Damage(DamageInstruction {
+        side_ref: SideReference::SideOne,
+        damage_amount: 10,
+    });
+
+    state.apply_one_instruction(&instruction);
+    assert_ne!(initial_hash, state.zobrist_hash);
+    assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+
+    state.reverse_one_instruction(&instruction);
+    assert_eq!(initial_hash, state.zobrist_hash);
+}
+
+#[test]
+fn test_same_state_reached_by_different_instruction_orders_has_the_same_hash() {
+    let apply_confusion = Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+        side_ref: SideReference::SideOne,
+        volatile_status: PokemonVolatileStatus::CONFUSION,
+    });
+    let apply_leechseed = Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+        side_ref: SideReference::SideOne,
+        volatile_status: PokemonVolatileStatus::LEECHSEED,
+    });
+    let switch = Instruction::Switch(SwitchInstruction {
+        side_ref: SideReference::SideTwo,
+        previous_index: PokemonIndex::P0,
+        next_index: PokemonIndex::P1,
+    });
+
+    let mut state_one = State::default();
+    state_one.apply_instructions(&vec![
+        apply_confusion.clone(),
+        apply_leechseed.clone(),
+        switch.clone(),
+    ]);
+
+    let mut state_two = State::default();
+    state_two.apply_instructions(&vec![switch, apply_leechseed, apply_confusion]);
+
+    assert_eq!(state_one.zobrist_hash, state_two.zobrist_hash);
+    assert_eq!(state_one.compute_zobrist_hash(), state_one.zobrist_hash);
+}
+
+#[test]
+fn test_hash_differs_between_sides() {
+    let mut state_one = State::default();
+    state_one.apply_one_instruction(&Instruction::Damage(DamageInstruction {
+        side_ref: SideReference::SideOne,
+        damage_amount: 10,
+    }));
+
+    let mut state_two = State::default();
+    state_two.apply_one_instruction(&Instruction::Damage(DamageInstruction {
+        side_ref: SideReference::SideTwo,
+        damage_amount: 10,
+    }));
+
+    assert_ne!(state_one.zobrist_hash, state_two.zobrist_hash);
+}
+
+#[test]
+fn test_generated_instructions_keep_the_hash_in_sync() {
+    let mut state = state_with_moves(
+        [
+            Choices::UTURN,
+            Choices::WILLOWISP,
+            Choices::SUBSTITUTE,
+            Choices::SWORDSDANCE,
+        ],
+        [
+            Choices::TRIPLEAXEL,
+            Choices::TOXICSPIKES,
+            Choices::TRICKROOM,
+            Choices::LEECHSEED,
+        ],
+    );
+    let initial_hash = state.zobrist_hash;
+    let (side_one_options, side_two_options) = state.get_all_options();
+
+    for side_one_move in side_one_options.iter() {
+        for side_two_move in side_two_options.iter() {
+            let instructions = generate_instructions_from_move_pair(
+                &mut state,
+                side_one_move,
+                side_two_move,
+                DamageBranching::KillNoKill,
+                0.0,
+            );
+            assert_eq!(initial_hash, state.zobrist_hash);
+
+            for state_instructions in instructions.iter() {
+                state.apply_instructions(&state_instructions.instruction_list);
+                assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+                state.reverse_instructions(&state_instructions.instruction_list);
+                assert_eq!(initial_hash, state.zobrist_hash);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "debug_zobrist_hash")]
+#[test]
+fn test_hash_is_valid_between_instruction_generation_calls() {
+    let mut state = state_with_moves(
+        [
+            Choices::UTURN,
+            Choices::KNOCKOFF,
+            Choices::SUBSTITUTE,
+            Choices::SPIKES,
+        ],
+        [
+            Choices::THUNDERWAVE,
+            Choices::STEALTHROCK,
+            Choices::PROTECT,
+            Choices::TAILWIND,
+        ],
+    );
+    let initial_serialized = state.serialize();
+    let (side_one_options, side_two_options) = state.get_all_options();
+
+    for side_one_move in side_one_options.iter() {
+        for side_two_move in side_two_options.iter() {
+            let instructions = generate_instructions_from_move_pair(
+                &mut state,
+                side_one_move,
+                side_two_move,
+                DamageBranching::KillNoKill,
+                0.0,
+            );
+            assert_eq!(initial_serialized, state.serialize());
+            assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+
+            // every apply and reverse asserts the incremental update, including when
+            // generating again from a state reached through generated instructions
+            for state_instructions in instructions.iter() {
+                state.apply_instructions(&state_instructions.instruction_list);
+                let (next_side_one_options, next_side_two_options) = state.get_all_options();
+                let next_instructions = generate_instructions_from_move_pair(
+                    &mut state,
+                    &next_side_one_options[0],
+                    &next_side_two_options[0],
+                    DamageBranching::KillNoKill,
+                    0.0,
+                );
+                assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+                for next_state_instructions in next_instructions.iter() {
+                    state.apply_instructions(&next_state_instructions.instruction_list);
+                    state.reverse_instructions(&next_state_instructions.instruction_list);
+                }
+                state.reverse_instructions(&state_instructions.instruction_list);
+            }
+            assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+        }
+    }
+}